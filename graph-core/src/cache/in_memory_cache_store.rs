@@ -1,39 +1,351 @@
 use crate::cache::cache_store::CacheStore;
 use parking_lot::RwLock;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+struct CacheEntry<Value> {
+    value: Value,
+    stored_at: Instant,
+    /// The stamp of the store of the value, used to tell whether an entry in the
+    /// expiry queue is for this value or a value that was replaced.
+    stored: u64,
+    /// The key of the entry in the least recently used order.
+    lru_stamp: u64,
+    /// The stamp of the last time the value was stored or read. Updated while
+    /// holding the read lock and applied to the least recently used order when
+    /// the entry reaches the front of the order.
+    last_accessed: AtomicU64,
+}
+
+struct Entries<Value> {
+    values: HashMap<String, CacheEntry<Value>>,
+    /// Cache ids by stamp, oldest first.
+    lru: BTreeMap<u64, String>,
+    /// Cache ids in the order they were stored, used when values have a time to live.
+    expiry: VecDeque<(Instant, u64, String)>,
+    clock: AtomicU64,
+}
+
+impl<Value> Default for Entries<Value> {
+    fn default() -> Self {
+        Entries {
+            values: HashMap::new(),
+            lru: BTreeMap::new(),
+            expiry: VecDeque::new(),
+            clock: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<Value> Entries<Value> {
+    fn stamp(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn remove(&mut self, cache_id: &str) -> Option<CacheEntry<Value>> {
+        let entry = self.values.remove(cache_id)?;
+        self.lru.remove(&entry.lru_stamp);
+        Some(entry)
+    }
+}
+
+#[derive(Debug, Default)]
+struct CacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+/// Size and usage of an [InMemoryCacheStore]. The counts are shared by all
+/// clones of the store.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CacheMetrics {
+    /// The number of values in the cache.
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+    /// The number of values removed because the cache was full or the value expired.
+    /// Values removed using [CacheStore::evict] are not counted.
+    pub evictions: u64,
+}
+
+impl CacheMetrics {
+    /// The fraction of lookups that found a value, or 0 when there were no lookups.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// Cache store that keeps values in memory. Clones of the store share the same values.
+///
+/// The store grows without bound by default. Use [InMemoryCacheStore::with_max_entries]
+/// to remove the least recently used values when the store is full and
+/// [InMemoryCacheStore::with_time_to_live] to remove values some time after they
+/// were stored, such as when tokens are stored for many tenants.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use graph_core::cache::{CacheStore, InMemoryCacheStore};
+///
+/// let mut cache = InMemoryCacheStore::new()
+///     .with_max_entries(1000)
+///     .with_time_to_live(Duration::from_secs(60 * 60));
+///
+/// cache.store("tenant", "token".to_string());
+/// assert_eq!(Some("token".to_string()), cache.get("tenant"));
+/// assert_eq!(1, cache.metrics().hits);
+/// ```
 #[derive(Clone, Default)]
 pub struct InMemoryCacheStore<Value: Clone> {
-    store: Arc<RwLock<HashMap<String, Value>>>,
+    store: Arc<RwLock<Entries<Value>>>,
+    max_entries: Option<usize>,
+    time_to_live: Option<Duration>,
+    counters: Arc<CacheCounters>,
 }
 
 impl<Value: Clone> InMemoryCacheStore<Value> {
     pub fn new() -> InMemoryCacheStore<Value> {
         InMemoryCacheStore {
             store: Default::default(),
+            max_entries: None,
+            time_to_live: None,
+            counters: Default::default(),
+        }
+    }
+
+    /// The maximum number of values in the store. The least recently used value is
+    /// removed when a value is stored in a full store.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// How long a value is kept after it is stored.
+    pub fn with_time_to_live(mut self, time_to_live: Duration) -> Self {
+        self.time_to_live = Some(time_to_live);
+        self
+    }
+
+    /// The number of values in the store, including expired values that have
+    /// not been removed yet.
+    pub fn len(&self) -> usize {
+        self.store.read().values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all values from the store.
+    pub fn clear(&self) {
+        let mut entries = self.store.write();
+        entries.values.clear();
+        entries.lru.clear();
+        entries.expiry.clear();
+    }
+
+    pub fn metrics(&self) -> CacheMetrics {
+        CacheMetrics {
+            entries: self.len(),
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+            evictions: self.counters.evictions.load(Ordering::Relaxed),
+        }
+    }
+
+    fn is_expired(&self, entry: &CacheEntry<Value>, now: Instant) -> bool {
+        self.time_to_live
+            .map(|time_to_live| now.duration_since(entry.stored_at) >= time_to_live)
+            .unwrap_or_default()
+    }
+
+    // Values expire in the order they were stored so only the front of the expiry
+    // queue is checked. Queue entries of values that were replaced or evicted are skipped.
+    fn remove_expired(&self, entries: &mut Entries<Value>, now: Instant) {
+        let Some(time_to_live) = self.time_to_live else {
+            return;
+        };
+        while let Some((stored_at, stored, _)) = entries.expiry.front() {
+            if now.duration_since(*stored_at) < time_to_live {
+                break;
+            }
+            let stored = *stored;
+            if let Some((_, _, cache_id)) = entries.expiry.pop_front() {
+                if entries.values.get(&cache_id).map(|entry| entry.stored) == Some(stored) {
+                    entries.remove(&cache_id);
+                    self.record_evictions(1);
+                }
+            }
+        }
+    }
+
+    // Values read since they were placed in the least recently used order are moved
+    // to the back of the order when they reach the front instead of being removed.
+    fn remove_least_recently_used(&self, entries: &mut Entries<Value>) {
+        let Some(max_entries) = self.max_entries else {
+            return;
+        };
+        while entries.values.len() > max_entries {
+            let Some((lru_stamp, cache_id)) = entries.lru.pop_first() else {
+                break;
+            };
+            let Some(entry) = entries.values.get_mut(&cache_id) else {
+                continue;
+            };
+            let last_accessed = *entry.last_accessed.get_mut();
+            if last_accessed > lru_stamp {
+                entry.lru_stamp = last_accessed;
+                entries.lru.insert(last_accessed, cache_id);
+            } else {
+                entries.values.remove(&cache_id);
+                self.record_evictions(1);
+            }
+        }
+    }
+
+    fn record_evictions(&self, evictions: usize) {
+        if evictions > 0 {
+            self.counters
+                .evictions
+                .fetch_add(evictions as u64, Ordering::Relaxed);
         }
     }
 }
 
 impl<Value: Clone> CacheStore<Value> for InMemoryCacheStore<Value> {
     fn store<T: Into<String>>(&mut self, cache_id: T, token: Value) {
+        let now = Instant::now();
+        let cache_id = cache_id.into();
         let mut write_lock = self.store.write();
-        write_lock.insert(cache_id.into(), token);
+        self.remove_expired(&mut write_lock, now);
+        write_lock.remove(&cache_id);
+
+        let stamp = write_lock.stamp();
+        write_lock.lru.insert(stamp, cache_id.clone());
+        if self.time_to_live.is_some() {
+            write_lock.expiry.push_back((now, stamp, cache_id.clone()));
+        }
+        write_lock.values.insert(
+            cache_id,
+            CacheEntry {
+                value: token,
+                stored_at: now,
+                stored: stamp,
+                lru_stamp: stamp,
+                last_accessed: AtomicU64::new(stamp),
+            },
+        );
+        self.remove_least_recently_used(&mut write_lock);
         drop(write_lock);
     }
 
     fn get(&self, cache_id: &str) -> Option<Value> {
+        let now = Instant::now();
         let read_lock = self.store.read();
-        let token = read_lock.get(cache_id).cloned();
+        let (token, expired) = match read_lock.values.get(cache_id) {
+            Some(entry) if self.is_expired(entry, now) => (None, Some(entry.stored)),
+            Some(entry) => {
+                entry
+                    .last_accessed
+                    .store(read_lock.stamp(), Ordering::Relaxed);
+                (Some(entry.value.clone()), None)
+            }
+            None => (None, None),
+        };
         drop(read_lock);
+
+        if let Some(stored) = expired {
+            let mut write_lock = self.store.write();
+            if write_lock.values.get(cache_id).map(|entry| entry.stored) == Some(stored) {
+                write_lock.remove(cache_id);
+                self.record_evictions(1);
+            }
+        }
+
+        let counter = match token {
+            Some(_) => &self.counters.hits,
+            None => &self.counters.misses,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
         token
     }
 
     fn evict(&self, cache_id: &str) -> Option<Value> {
         let mut write_lock = self.store.write();
-        let token = write_lock.remove(cache_id);
+        let token = write_lock.remove(cache_id).map(|entry| entry.value);
         drop(write_lock);
         token
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn evict_least_recently_used() {
+        let mut cache = InMemoryCacheStore::new().with_max_entries(2);
+        cache.store("a", 1);
+        cache.store("b", 2);
+        assert_eq!(Some(1), cache.get("a"));
+        cache.store("c", 3);
+
+        assert_eq!(None, cache.get("b"));
+        assert_eq!(Some(1), cache.get("a"));
+        assert_eq!(Some(3), cache.get("c"));
+        assert_eq!(
+            CacheMetrics {
+                entries: 2,
+                hits: 3,
+                misses: 1,
+                evictions: 1,
+            },
+            cache.metrics()
+        );
+        assert_eq!(0.75, cache.metrics().hit_rate());
+    }
+
+    #[test]
+    fn evict_expired() {
+        let mut cache = InMemoryCacheStore::new().with_time_to_live(Duration::ZERO);
+        cache.store("a", 1);
+        assert_eq!(None, cache.get("a"));
+        assert!(cache.is_empty());
+        assert_eq!(1, cache.metrics().evictions);
+
+        let mut cache = InMemoryCacheStore::new().with_time_to_live(Duration::from_secs(60));
+        cache.store("a", 1);
+        assert_eq!(Some(1), cache.get("a"));
+    }
+
+    #[test]
+    fn replace_and_evict_values() {
+        let mut cache = InMemoryCacheStore::new().with_max_entries(2);
+        cache.store("a", 1);
+        cache.store("b", 2);
+        cache.store("a", 3);
+        cache.store("c", 4);
+
+        assert_eq!(None, cache.get("b"));
+        assert_eq!(Some(3), cache.get("a"));
+        assert_eq!(Some(4), cache.evict("c"));
+        cache.store("d", 5);
+        cache.store("e", 6);
+        assert_eq!(2, cache.len());
+        assert_eq!(None, cache.get("a"));
+
+        let mut cache = InMemoryCacheStore::new().with_time_to_live(Duration::ZERO);
+        cache.store("a", 1);
+        cache.store("a", 2);
+        cache.store("b", 3);
+        assert_eq!(1, cache.len());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::http::response_builder_ext::HttpExtUrl;
+use async_trait::async_trait;
+use bytes::Bytes;
+use graph_error::{GraphFailure, GraphResult};
+
+/// Convert a response into an [`http::Response<Bytes>`] to use the response with
+/// libraries built on the `http` crate, such as tower middleware.
+///
+/// Use `reqwest::Response::from` to convert the [`http::Response<Bytes>`] back. The
+/// url of the response is kept as an extension and returned by
+/// [`HttpResponseExt::url`](crate::http::HttpResponseExt::url).
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::http::AsyncBytesResponseExt;
+///
+/// let response = client.users().list_user().send().await?;
+/// let response: http::Response<bytes::Bytes> = response.into_bytes_response().await?;
+/// let response = reqwest::Response::from(response);
+/// ```
+#[async_trait]
+pub trait AsyncBytesResponseExt {
+    /// Read the body and convert the response into an [`http::Response<Bytes>`].
+    async fn into_bytes_response(self) -> reqwest::Result<http::Response<Bytes>>;
+}
+
+#[async_trait]
+impl AsyncBytesResponseExt for reqwest::Response {
+    async fn into_bytes_response(self) -> reqwest::Result<http::Response<Bytes>> {
+        let url = self.url().clone();
+        let status = self.status();
+        let version = self.version();
+        let headers = self.headers().clone();
+        let body = self.bytes().await?;
+
+        let mut response = http::Response::new(body);
+        *response.status_mut() = status;
+        *response.version_mut() = version;
+        *response.headers_mut() = headers;
+        response.extensions_mut().insert(HttpExtUrl(url));
+        Ok(response)
+    }
+}
+
+/// Convert a blocking response into an [`http::Response<Bytes>`].
+/// See [`AsyncBytesResponseExt`].
+pub trait BytesResponseExt {
+    /// Read the body and convert the response into an [`http::Response<Bytes>`].
+    fn into_bytes_response(self) -> reqwest::Result<http::Response<Bytes>>;
+}
+
+impl BytesResponseExt for reqwest::blocking::Response {
+    fn into_bytes_response(self) -> reqwest::Result<http::Response<Bytes>> {
+        let url = self.url().clone();
+        let status = self.status();
+        let version = self.version();
+        let headers = self.headers().clone();
+        let body = self.bytes()?;
+
+        let mut response = http::Response::new(body);
+        *response.status_mut() = status;
+        *response.version_mut() = version;
+        *response.headers_mut() = headers;
+        response.extensions_mut().insert(HttpExtUrl(url));
+        Ok(response)
+    }
+}
+
+/// Convert a request into an [`http::Request<Bytes>`].
+///
+/// Use `reqwest::Request::try_from` to convert the [`http::Request<Bytes>`] back.
+pub trait BytesRequestExt {
+    /// Convert the request into an [`http::Request<Bytes>`]. Fails when the body
+    /// of the request is a stream, such as a file upload, which cannot be read
+    /// without sending the request.
+    #[allow(clippy::result_large_err)]
+    fn try_into_bytes_request(self) -> GraphResult<http::Request<Bytes>>;
+}
+
+impl BytesRequestExt for reqwest::Request {
+    fn try_into_bytes_request(self) -> GraphResult<http::Request<Bytes>> {
+        let body = match self.body() {
+            Some(body) => Bytes::copy_from_slice(body.as_bytes().ok_or_else(|| {
+                GraphFailure::invalid("request body - a streaming body cannot be converted")
+            })?),
+            None => Bytes::new(),
+        };
+
+        let mut request = http::Request::builder()
+            .method(self.method().clone())
+            .uri(self.url().as_str())
+            .version(self.version())
+            .body(body)?;
+        *request.headers_mut() = self.headers().clone();
+        Ok(request)
+    }
+}
+
+impl BytesRequestExt for reqwest::blocking::Request {
+    fn try_into_bytes_request(self) -> GraphResult<http::Request<Bytes>> {
+        let body = match self.body() {
+            Some(body) => Bytes::copy_from_slice(body.as_bytes().ok_or_else(|| {
+                GraphFailure::invalid("request body - a streaming body cannot be converted")
+            })?),
+            None => Bytes::new(),
+        };
+
+        let mut request = http::Request::builder()
+            .method(self.method().clone())
+            .uri(self.url().as_str())
+            .version(self.version())
+            .body(body)?;
+        *request.headers_mut() = self.headers().clone();
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http::HttpResponseExt;
+
+    #[tokio::test]
+    async fn bytes_response_round_trip() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .status(201)
+                .header("request-id", "1")
+                .body(r#"{"id":"1"}"#)
+                .unwrap(),
+        );
+
+        let response = response.into_bytes_response().await.unwrap();
+        assert_eq!(201, response.status().as_u16());
+        assert_eq!("1", response.headers()["request-id"]);
+        assert_eq!(Bytes::from_static(br#"{"id":"1"}"#), response.body());
+        assert!(response.url().is_some());
+
+        let response = reqwest::Response::from(response);
+        assert_eq!(201, response.status().as_u16());
+    }
+
+    #[test]
+    fn bytes_request_round_trip() {
+        let request = reqwest::Client::new()
+            .post("https://graph.microsoft.com/v1.0/users")
+            .bearer_auth("token")
+            .body(r#"{"displayName":"name"}"#)
+            .build()
+            .unwrap();
+
+        let request = request.try_into_bytes_request().unwrap();
+        assert_eq!(http::Method::POST, request.method());
+        assert_eq!("Bearer token", request.headers()["authorization"]);
+        assert_eq!(
+            Bytes::from_static(br#"{"displayName":"name"}"#),
+            request.body()
+        );
+
+        let request = reqwest::Request::try_from(request).unwrap();
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/users",
+            request.url().as_str()
+        );
+    }
+}
@@ -1,5 +1,9 @@
+mod bytes_converter;
 mod response_builder_ext;
 mod response_converter;
+mod trace_context;
 
+pub use bytes_converter::*;
 pub use response_builder_ext::*;
 pub use response_converter::*;
+pub use trace_context::*;
@@ -0,0 +1,50 @@
+use http::HeaderMap;
+
+/// The W3C Trace Context header that identifies the trace and parent span of a request.
+pub const TRACEPARENT: &str = "traceparent";
+/// The W3C Trace Context header with vendor specific trace information.
+pub const TRACESTATE: &str = "tracestate";
+
+/// Add the W3C `traceparent` and `tracestate` headers of the current tracing span
+/// so that requests can be correlated with the trace of the caller. Requires the
+/// `opentelemetry` feature, the `tracing-opentelemetry` layer, and a global text
+/// map propagator, such as `TraceContextPropagator`. Headers that are already
+/// set are not replaced.
+///
+/// # Example
+/// ```rust,ignore
+/// opentelemetry::global::set_text_map_propagator(
+///     opentelemetry_sdk::propagation::TraceContextPropagator::new(),
+/// );
+/// ```
+#[cfg(feature = "opentelemetry")]
+pub fn inject_trace_context(headers: &mut HeaderMap) {
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    if headers.contains_key(TRACEPARENT) {
+        return;
+    }
+
+    let context = tracing::Span::current().context();
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut HeaderInjector(headers))
+    });
+}
+
+#[cfg(not(feature = "opentelemetry"))]
+pub fn inject_trace_context(_headers: &mut HeaderMap) {}
+
+#[cfg(feature = "opentelemetry")]
+struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+#[cfg(feature = "opentelemetry")]
+impl opentelemetry::propagation::Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(name), Ok(value)) = (
+            http::header::HeaderName::from_bytes(key.as_bytes()),
+            http::HeaderValue::from_str(&value),
+        ) {
+            self.0.insert(name, value);
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use async_trait::async_trait;
 use dyn_clone::DynClone;
 use graph_error::AuthExecutionResult;
+use std::time::Duration;
 
+/// Policy for when a cached token is used and when a new token is requested.
+///
+/// The policy can be set on a client application, on a GraphClient using
+/// `with_force_token_refresh`, or on a single request using `force_token_refresh`.
 #[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum ForceTokenRefresh {
     /// Always use the token cache first to when returning tokens.
@@ -15,6 +20,22 @@ pub enum ForceTokenRefresh {
     Once,
     /// Always make an authorization request regardless of any tokens in cache.
     Always,
+    /// Use the token cache but request a new token when the cached token expires
+    /// within the given duration. ForceTokenRefresh::Never uses a duration of 5 minutes.
+    WhenExpiredWithin(Duration),
+    /// Only use the token cache and never make an authorization request. Returns
+    /// an error when there is no unexpired token in the cache.
+    CacheOnly,
+}
+
+impl ForceTokenRefresh {
+    /// The duration before a cached token expires at which a new token is requested.
+    pub fn refresh_margin(&self) -> Duration {
+        match self {
+            ForceTokenRefresh::WhenExpiredWithin(duration) => *duration,
+            _ => Duration::from_secs(300),
+        }
+    }
 }
 
 dyn_clone::clone_trait_object!(ClientApplication);
@@ -26,6 +47,33 @@ pub trait ClientApplication: DynClone + Send + Sync {
     async fn get_token_silent_async(&mut self) -> AuthExecutionResult<String>;
 
     fn with_force_token_refresh(&mut self, force_token_refresh: ForceTokenRefresh);
+
+    /// Use the given http client when requesting tokens instead of creating a new
+    /// client for each request.
+    fn with_http_client(&mut self, _http_client: reqwest::Client) {}
+
+    /// Use the given blocking http client when requesting tokens instead of creating
+    /// a new client for each request.
+    fn with_blocking_http_client(&mut self, _http_client: reqwest::blocking::Client) {}
+
+    /// Returns true if an http client for token requests was set, such as a client
+    /// set by the user with [ClientApplication::with_http_client]. The Graph client
+    /// does not replace a client that was already set.
+    fn has_http_client(&self) -> bool {
+        false
+    }
+
+    /// Returns true if a blocking http client for token requests was set.
+    fn has_blocking_http_client(&self) -> bool {
+        false
+    }
+
+    /// Returns a copy of the client application that requests tokens from the
+    /// given tenant. Returns None if the client application does not support
+    /// changing the tenant, such as when using an access token.
+    fn for_tenant(&self, _tenant_id: &str) -> Option<Box<dyn ClientApplication>> {
+        None
+    }
 }
 
 #[async_trait]
@@ -40,3 +88,56 @@ impl ClientApplication for String {
 
     fn with_force_token_refresh(&mut self, _force_token_refresh: ForceTokenRefresh) {}
 }
+
+/// A client application chosen at runtime, such as a client secret or certificate
+/// credential depending on configuration, can be used wherever a client application
+/// is accepted.
+#[async_trait]
+impl ClientApplication for Box<dyn ClientApplication> {
+    fn get_token_silent(&mut self) -> AuthExecutionResult<String> {
+        self.as_mut().get_token_silent()
+    }
+
+    async fn get_token_silent_async(&mut self) -> AuthExecutionResult<String> {
+        self.as_mut().get_token_silent_async().await
+    }
+
+    fn with_force_token_refresh(&mut self, force_token_refresh: ForceTokenRefresh) {
+        self.as_mut().with_force_token_refresh(force_token_refresh)
+    }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.as_mut().with_http_client(http_client)
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.as_mut().with_blocking_http_client(http_client)
+    }
+
+    fn has_http_client(&self) -> bool {
+        self.as_ref().has_http_client()
+    }
+
+    fn has_blocking_http_client(&self) -> bool {
+        self.as_ref().has_blocking_http_client()
+    }
+
+    fn for_tenant(&self, tenant_id: &str) -> Option<Box<dyn ClientApplication>> {
+        self.as_ref().for_tenant(tenant_id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn boxed_client_application_delegates() {
+        let mut client_app: Box<dyn ClientApplication> = Box::new("token".to_string());
+        assert_eq!(
+            "token",
+            ClientApplication::get_token_silent(&mut client_app).unwrap()
+        );
+        assert!(ClientApplication::for_tenant(&client_app, "tenant").is_none());
+    }
+}
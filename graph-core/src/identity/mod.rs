@@ -1,7 +1,11 @@
 mod client_application;
 mod jwk;
 mod jwks;
+mod token_permissions;
+mod token_validation;
 
 pub use client_application::*;
 pub use jwk::*;
 pub use jwks::*;
+pub use token_permissions::*;
+pub use token_validation::*;
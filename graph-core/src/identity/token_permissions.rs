@@ -0,0 +1,159 @@
+use crate::identity::token_validation::decode_claims;
+use crate::resource::ResourceIdentity;
+use http::Method;
+
+/// The permissions granted to an access token: the delegated permissions in the
+/// `scp` claim and the application permissions in the `roles` claim.
+///
+/// The signature of the token is not validated.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_core::identity::TokenPermissions;
+/// use graph_core::resource::ResourceIdentity;
+///
+/// let permissions = TokenPermissions::from_access_token(&access_token).unwrap();
+/// let required = permissions.missing_permissions(
+///     ResourceIdentity::UsersMessages,
+///     &Method::POST,
+///     "/v1.0/me/messages",
+/// );
+/// if let Some(required) = required {
+///     println!("token needs one of {required:?}");
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TokenPermissions {
+    scopes: Vec<String>,
+    roles: Vec<String>,
+}
+
+impl TokenPermissions {
+    /// The permissions of the access token, or `None` when the access token is not
+    /// a JWT, such as tokens for personal Microsoft accounts.
+    pub fn from_access_token(access_token: &str) -> Option<TokenPermissions> {
+        let claims = decode_claims(access_token)?;
+        let scopes = claims["scp"]
+            .as_str()
+            .map(|scp| scp.split_whitespace().map(String::from).collect())
+            .unwrap_or_default();
+        let roles = claims["roles"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|role| role.as_str().map(String::from))
+            .collect();
+
+        Some(TokenPermissions { scopes, roles })
+    }
+
+    /// The delegated permissions of the `scp` claim.
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+
+    /// The application permissions of the `roles` claim.
+    pub fn roles(&self) -> &[String] {
+        &self.roles
+    }
+
+    /// Returns true if the token has the delegated or application permission.
+    /// Permission names are not case sensitive.
+    pub fn contains(&self, permission: &str) -> bool {
+        self.scopes
+            .iter()
+            .chain(self.roles.iter())
+            .any(|granted| granted.eq_ignore_ascii_case(permission))
+    }
+
+    /// The permissions required by a request with the method and url path on the
+    /// resource when the token has none of them. Returns `None` when the token has
+    /// one of the permissions or the permissions of the request are not known.
+    pub fn missing_permissions(
+        &self,
+        resource_identity: ResourceIdentity,
+        method: &Method,
+        path: &str,
+    ) -> Option<&'static [&'static str]> {
+        let required = resource_identity.required_permissions(method, path);
+        if required.is_empty() || required.iter().any(|permission| self.contains(permission)) {
+            return None;
+        }
+        Some(required)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use base64::Engine;
+
+    fn access_token(claims: serde_json::Value) -> String {
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .encode(serde_json::to_vec(&claims).unwrap());
+        format!("eyJhbGciOiJub25lIn0.{payload}.signature")
+    }
+
+    #[test]
+    fn missing_permissions() {
+        let delegated = TokenPermissions::from_access_token(&access_token(serde_json::json!({
+            "scp": "User.Read Mail.Read"
+        })))
+        .unwrap();
+        assert_eq!(vec!["User.Read", "Mail.Read"], delegated.scopes());
+        assert!(delegated.contains("mail.read"));
+        assert_eq!(
+            None,
+            delegated.missing_permissions(
+                ResourceIdentity::UsersMessages,
+                &Method::GET,
+                "/v1.0/me/messages"
+            )
+        );
+        assert_eq!(
+            Some(ResourceIdentity::UsersMessages.write_permissions()),
+            delegated.missing_permissions(
+                ResourceIdentity::UsersMessages,
+                &Method::POST,
+                "/v1.0/me/messages"
+            )
+        );
+        assert_eq!(
+            None,
+            delegated.missing_permissions(ResourceIdentity::Me, &Method::POST, "/v1.0/me/events")
+        );
+        assert_eq!(
+            Some(&["Mail.Send", "Mail.Send.Shared"][..]),
+            delegated.missing_permissions(ResourceIdentity::Me, &Method::POST, "/v1.0/me/sendMail")
+        );
+
+        let application = TokenPermissions::from_access_token(&access_token(serde_json::json!({
+            "roles": ["Calendars.ReadWrite"]
+        })))
+        .unwrap();
+        assert_eq!(
+            None,
+            application.missing_permissions(
+                ResourceIdentity::Events,
+                &Method::GET,
+                "/v1.0/users/1/events"
+            )
+        );
+        assert_eq!(
+            None,
+            application.missing_permissions(
+                ResourceIdentity::Calendars,
+                &Method::POST,
+                "/v1.0/users/1/calendar/microsoft.graph.getSchedule"
+            )
+        );
+        assert!(application
+            .missing_permissions(
+                ResourceIdentity::DirectoryObjects,
+                &Method::POST,
+                "/v1.0/directoryObjects/getByIds"
+            )
+            .is_some());
+        assert!(TokenPermissions::from_access_token("opaque-token").is_none());
+    }
+}
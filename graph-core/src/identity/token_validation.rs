@@ -0,0 +1,219 @@
+use crate::identity::{ClientApplication, ForceTokenRefresh};
+use async_trait::async_trait;
+use base64::Engine;
+use graph_error::{AuthExecutionError, AuthExecutionResult, AF};
+
+/// The application id of Microsoft Graph. Access tokens for Microsoft Graph may use
+/// the application id instead of the Microsoft Graph host as the audience in every cloud.
+pub const MICROSOFT_GRAPH_APP_ID: &str = "00000003-0000-0000-c000-000000000000";
+
+/// Validates the `aud` and `tid` claims of access tokens before they are used so
+/// that a token for a different cloud, resource or tenant returns a descriptive
+/// error instead of a 401 Unauthorized response from Microsoft Graph.
+///
+/// The signature of the token is not validated. Access tokens that are not a
+/// JWT, such as tokens for personal Microsoft accounts, are not validated.
+///
+/// # Example
+/// ```rust,ignore
+/// let client = GraphClientConfiguration::new()
+///     .client_application(confidential_client)
+///     .validate_token(TokenValidation::new("https://graph.microsoft.us").tenant_id(TENANT_ID))
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TokenValidation {
+    graph_host: String,
+    tenant_id: Option<String>,
+}
+
+impl TokenValidation {
+    /// The Microsoft Graph host the token must be for, such as `https://graph.microsoft.us`.
+    pub fn new(graph_host: impl ToString) -> TokenValidation {
+        TokenValidation {
+            graph_host: graph_host.to_string(),
+            tenant_id: None,
+        }
+    }
+
+    /// The id of the tenant the token must be issued by.
+    pub fn tenant_id(mut self, tenant_id: impl ToString) -> Self {
+        self.tenant_id = Some(tenant_id.to_string());
+        self
+    }
+
+    /// Returns an error if the access token is for a different audience or tenant.
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self, access_token: &str) -> AuthExecutionResult<()> {
+        let claims = match decode_claims(access_token) {
+            Some(claims) => claims,
+            None => return Ok(()),
+        };
+
+        if let Some(audience) = claims["aud"].as_str() {
+            if !self.is_graph_audience(audience) {
+                return Err(validation_error(
+                    "aud",
+                    format!(
+                        "access token audience {audience} is not the Microsoft Graph host {} of the client",
+                        self.graph_host
+                    ),
+                ));
+            }
+        }
+
+        match (self.tenant_id.as_deref(), claims["tid"].as_str()) {
+            (Some(expected), Some(tenant_id)) if !expected.eq_ignore_ascii_case(tenant_id) => {
+                Err(validation_error(
+                    "tid",
+                    format!(
+                        "access token was issued by tenant {tenant_id} instead of tenant {expected}"
+                    ),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Wrap the client application so that each new access token is validated
+    /// before it is returned.
+    pub fn client_application(
+        self,
+        client_application: Box<dyn ClientApplication>,
+    ) -> Box<dyn ClientApplication> {
+        Box::new(ValidatingClientApplication {
+            inner: client_application,
+            validation: self,
+            validated_token: None,
+        })
+    }
+
+    fn is_graph_audience(&self, audience: &str) -> bool {
+        if audience.eq_ignore_ascii_case(MICROSOFT_GRAPH_APP_ID) {
+            return true;
+        }
+        match (url::Url::parse(audience), url::Url::parse(&self.graph_host)) {
+            (Ok(audience), Ok(graph_host)) => {
+                audience.scheme() == graph_host.scheme()
+                    && audience.host_str() == graph_host.host_str()
+            }
+            _ => false,
+        }
+    }
+}
+
+fn validation_error(claim: &str, message: String) -> AuthExecutionError {
+    AuthExecutionError::Authorization(AF::msg_err(claim, message.as_str()))
+}
+
+// The payload of the token when the token is a JWT.
+pub(crate) fn decode_claims(access_token: &str) -> Option<serde_json::Value> {
+    let mut segments = access_token.split('.');
+    let payload = match (segments.next(), segments.next(), segments.next()) {
+        (Some(_), Some(payload), Some(_)) => payload,
+        _ => return None,
+    };
+    let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .ok()?;
+    serde_json::from_slice(&decoded).ok()
+}
+
+#[derive(Clone)]
+struct ValidatingClientApplication {
+    inner: Box<dyn ClientApplication>,
+    validation: TokenValidation,
+    validated_token: Option<String>,
+}
+
+impl ValidatingClientApplication {
+    #[allow(clippy::result_large_err)]
+    fn validate(&mut self, access_token: String) -> AuthExecutionResult<String> {
+        if self.validated_token.as_deref() != Some(access_token.as_str()) {
+            self.validation.validate(&access_token)?;
+            self.validated_token = Some(access_token.clone());
+        }
+        Ok(access_token)
+    }
+}
+
+#[async_trait]
+impl ClientApplication for ValidatingClientApplication {
+    fn get_token_silent(&mut self) -> AuthExecutionResult<String> {
+        let access_token = self.inner.get_token_silent()?;
+        self.validate(access_token)
+    }
+
+    async fn get_token_silent_async(&mut self) -> AuthExecutionResult<String> {
+        let access_token = self.inner.get_token_silent_async().await?;
+        self.validate(access_token)
+    }
+
+    fn with_force_token_refresh(&mut self, force_token_refresh: ForceTokenRefresh) {
+        self.inner.with_force_token_refresh(force_token_refresh);
+    }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.inner.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.inner.with_blocking_http_client(http_client);
+    }
+
+    fn has_http_client(&self) -> bool {
+        self.inner.has_http_client()
+    }
+
+    fn has_blocking_http_client(&self) -> bool {
+        self.inner.has_blocking_http_client()
+    }
+
+    fn for_tenant(&self, tenant_id: &str) -> Option<Box<dyn ClientApplication>> {
+        let client_application = self.inner.for_tenant(tenant_id)?;
+        let validation = self.validation.clone();
+        let validation = match validation.tenant_id {
+            Some(_) => validation.tenant_id(tenant_id),
+            None => validation,
+        };
+        Some(validation.client_application(client_application))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn token(claims: serde_json::Value) -> String {
+        let payload =
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(claims.to_string().as_bytes());
+        format!("eyJhbGciOiJub25lIn0.{payload}.signature")
+    }
+
+    #[test]
+    fn validate_audience_and_tenant() {
+        let validation = TokenValidation::new("https://graph.microsoft.us").tenant_id("tenant");
+
+        assert!(validation
+            .validate(&token(serde_json::json!({
+                "aud": "https://graph.microsoft.us/",
+                "tid": "TENANT"
+            })))
+            .is_ok());
+        assert!(validation
+            .validate(&token(serde_json::json!({ "aud": MICROSOFT_GRAPH_APP_ID })))
+            .is_ok());
+        assert!(validation
+            .validate(&token(
+                serde_json::json!({ "aud": "https://graph.microsoft.com" })
+            ))
+            .is_err());
+        assert!(validation
+            .validate(&token(serde_json::json!({
+                "aud": "https://graph.microsoft.us",
+                "tid": "other"
+            })))
+            .is_err());
+        assert!(validation.validate("EwBwA8l6BAAU opaque").is_ok());
+    }
+}
@@ -10,4 +10,5 @@ pub mod cache;
 pub mod crypto;
 pub mod http;
 pub mod identity;
+pub mod metrics;
 pub mod resource;
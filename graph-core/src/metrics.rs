@@ -0,0 +1,96 @@
+//! Metrics for requests to Graph and token requests. Metrics are recorded using
+//! the [metrics](https://docs.rs/metrics) facade when the `metrics` feature is
+//! enabled and are exported by the recorder installed by the application, such
+//! as `metrics-exporter-prometheus`. Nothing is recorded when the feature is
+//! not enabled.
+//!
+//! | Name | Type | Labels |
+//! |---|---|---|
+//! | `graph_requests_total` | counter | `method`, `resource`, `status` |
+//! | `graph_request_duration_seconds` | histogram | `method`, `resource` |
+//! | `graph_request_retries_total` | counter | `resource` |
+//! | `graph_throttled_requests_total` | counter | `resource` |
+//! | `graph_token_requests_total` | counter | `status` |
+//!
+//! The `status` label is the status code of the response, or `error` when no
+//! response was received.
+
+use std::time::Duration;
+
+use http::{Method, StatusCode};
+
+/// The number of requests sent to Graph, including retries.
+pub const GRAPH_REQUESTS: &str = "graph_requests_total";
+/// The time from sending a request to receiving the response headers.
+pub const GRAPH_REQUEST_DURATION: &str = "graph_request_duration_seconds";
+/// The number of requests sent again after a throttled response.
+pub const GRAPH_REQUEST_RETRIES: &str = "graph_request_retries_total";
+/// The number of `429 Too Many Requests` and `503 Service Unavailable` responses.
+pub const GRAPH_THROTTLED_REQUESTS: &str = "graph_throttled_requests_total";
+/// The number of requests sent to the identity platform for an access token.
+/// Tokens returned from the token cache are not counted.
+pub const GRAPH_TOKEN_REQUESTS: &str = "graph_token_requests_total";
+
+#[cfg(feature = "metrics")]
+fn status_label(status: Option<StatusCode>) -> String {
+    status
+        .map(|status| status.as_u16().to_string())
+        .unwrap_or_else(|| "error".to_owned())
+}
+
+/// Record a request to Graph and, when the response is throttled, a throttled request.
+#[cfg(feature = "metrics")]
+pub fn record_request(
+    method: &Method,
+    resource: &str,
+    status: Option<StatusCode>,
+    duration: Duration,
+) {
+    metrics::counter!(
+        GRAPH_REQUESTS,
+        "method" => method.to_string(),
+        "resource" => resource.to_owned(),
+        "status" => status_label(status)
+    )
+    .increment(1);
+    metrics::histogram!(
+        GRAPH_REQUEST_DURATION,
+        "method" => method.to_string(),
+        "resource" => resource.to_owned()
+    )
+    .record(duration.as_secs_f64());
+
+    if matches!(
+        status,
+        Some(StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE)
+    ) {
+        metrics::counter!(GRAPH_THROTTLED_REQUESTS, "resource" => resource.to_owned()).increment(1);
+    }
+}
+
+#[cfg(not(feature = "metrics"))]
+pub fn record_request(
+    _method: &Method,
+    _resource: &str,
+    _status: Option<StatusCode>,
+    _duration: Duration,
+) {
+}
+
+/// Record a request that is sent again after a throttled response.
+#[cfg(feature = "metrics")]
+pub fn record_retry(resource: &str) {
+    metrics::counter!(GRAPH_REQUEST_RETRIES, "resource" => resource.to_owned()).increment(1);
+}
+
+#[cfg(not(feature = "metrics"))]
+pub fn record_retry(_resource: &str) {}
+
+/// Record a request for an access token.
+#[cfg(feature = "metrics")]
+pub fn record_token_request(status: Option<StatusCode>) {
+    metrics::counter!(GRAPH_TOKEN_REQUESTS, "status" => status_label(status)).increment(1);
+}
+
+#[cfg(not(feature = "metrics"))]
+pub fn record_token_request(_status: Option<StatusCode>) {}
@@ -1,3 +1,4 @@
 mod resource_identity;
+mod resource_permissions;
 
 pub use resource_identity::*;
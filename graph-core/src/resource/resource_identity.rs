@@ -109,6 +109,10 @@ pub enum ResourceIdentity {
     DrivesItems,
     DrivesList,
     DrivesListContentTypes,
+    EdiscoveryCases,
+    EdiscoveryCasesCustodians,
+    EdiscoveryCasesReviewSets,
+    EdiscoveryCasesSearches,
     Education,
     EducationAssignments,
     EducationAssignmentsSubmissions,
@@ -124,6 +128,8 @@ pub enum ResourceIdentity {
     ExtendedProperties,
     Extensions,
     External,
+    ExternalConnections,
+    ExternalConnectionsItems,
     FollowedSites,
     GroupLifecyclePolicies,
     GroupSettings,
@@ -145,6 +151,8 @@ pub enum ResourceIdentity {
     JoinedTeams,
     LastModifiedByUser,
     LicenseDetails,
+    LifecycleWorkflows,
+    LifecycleWorkflowsWorkflows,
     List,
     Localizations,
     MailboxSettings,
@@ -191,17 +199,25 @@ pub enum ResourceIdentity {
     Presence,
     PrimaryChannel,
     Print,
+    PrintJobs,
+    PrintPrinters,
+    PrintShares,
     Privacy,
+    Profile,
     RegisteredDevices,
     Reports,
     RoleDefinitions,
     RoleManagement,
+    RoleManagementDirectory,
     Schedule,
     SchemaExtensions,
     ScopedRoleMemberOf,
     ScopedRoleMemberships,
     Search,
     Security,
+    SecurityAlertsV2,
+    SecurityCases,
+    SecurityIncidents,
     ServicePrincipals,
     ServicePrincipalsOwners,
     Services,
@@ -247,6 +263,9 @@ pub enum ResourceIdentity {
     UsersAttachments,
     UsersManagedDevices,
     UsersMessages,
+    VirtualEndpoint,
+    VirtualEndpointCloudPcs,
+    VirtualEndpointProvisioningPolicies,
     VirtualEvents,
     VirtualEventsEvents,
     VirtualEventsSessions,
@@ -367,6 +386,15 @@ impl ToString for ResourceIdentity {
             ResourceIdentity::EducationMe => "me".to_string(),
             ResourceIdentity::EducationUsers => "users".to_string(),
             ResourceIdentity::EducationSchools => "schools".to_string(),
+            ResourceIdentity::SecurityAlertsV2 => "alerts_v2".to_string(),
+            ResourceIdentity::SecurityCases => "cases".to_string(),
+            ResourceIdentity::ExternalConnections => "connections".to_string(),
+            ResourceIdentity::LifecycleWorkflowsWorkflows => "workflows".to_string(),
+            ResourceIdentity::ExternalConnectionsItems => "items".to_string(),
+            ResourceIdentity::SecurityIncidents => "incidents".to_string(),
+            ResourceIdentity::EdiscoveryCasesCustodians => "custodians".to_string(),
+            ResourceIdentity::EdiscoveryCasesReviewSets => "reviewSets".to_string(),
+            ResourceIdentity::EdiscoveryCasesSearches => "searches".to_string(),
             ResourceIdentity::TodoLists => "lists".to_string(),
             ResourceIdentity::TodoListsTasks => "tasks".to_string(),
             ResourceIdentity::UsersMessages => "messages".into(),
@@ -374,6 +402,10 @@ impl ToString for ResourceIdentity {
             ResourceIdentity::EventsInstances => "instances".into(),
             ResourceIdentity::PlannerTasks => "tasks".into(),
             ResourceIdentity::OnenoteSections => "sections".into(),
+            ResourceIdentity::RoleManagementDirectory => "directory".into(),
+            ResourceIdentity::PrintJobs => "jobs".into(),
+            ResourceIdentity::PrintPrinters => "printers".into(),
+            ResourceIdentity::PrintShares => "shares".into(),
             ResourceIdentity::OnenoteSectionGroups => "sectionGroups".into(),
             ResourceIdentity::OnenoteNotebooks => "notebooks".into(),
             ResourceIdentity::OnenotePages => "pages".into(),
@@ -383,6 +415,8 @@ impl ToString for ResourceIdentity {
             ResourceIdentity::TermStoreSetsParentGroup => "parentGroup".into(),
             ResourceIdentity::TermStoreSetsRelations => "relations".into(),
             ResourceIdentity::TermStoreSetsTerms => "terms".into(),
+            ResourceIdentity::VirtualEndpointCloudPcs => "cloudPCs".into(),
+            ResourceIdentity::VirtualEndpointProvisioningPolicies => "provisioningPolicies".into(),
             ResourceIdentity::VirtualEvents => "virtualEvents".into(),
             ResourceIdentity::VirtualEventsEvents => "events".into(),
             ResourceIdentity::VirtualEventsSessions => "sessions".into(),
@@ -0,0 +1,293 @@
+use crate::resource::ResourceIdentity;
+use http::Method;
+
+macro_rules! resource_permissions {
+    ($($resource:ident { read: [$($read:literal),*], write: [$($write:literal),*] })+) => {
+        impl ResourceIdentity {
+            /// The Microsoft Graph permissions that allow reading the resource. A
+            /// request only needs one of them. Empty when the permissions of the
+            /// resource are not known.
+            pub fn read_permissions(&self) -> &'static [&'static str] {
+                match self {
+                    $(ResourceIdentity::$resource => &[$($read,)* $($write,)*],)+
+                    _ => &[],
+                }
+            }
+
+            /// The Microsoft Graph permissions that allow creating, updating, and
+            /// deleting the resource. A request only needs one of them. Empty when
+            /// the permissions of the resource are not known.
+            pub fn write_permissions(&self) -> &'static [&'static str] {
+                match self {
+                    $(ResourceIdentity::$resource => &[$($write,)*],)+
+                    _ => &[],
+                }
+            }
+        }
+    };
+}
+
+// Delegated and application permissions, including the broader permissions that
+// also grant access, from the Microsoft Graph permissions reference. Resources
+// such as users and me are not listed because their requests span many permissions.
+//
+// The OpenApi configs that the api clients are generated from do not include
+// permissions, so these tables are not written by graph-codegen and are kept
+// up to date by hand:
+//
+// - When an api client is generated for a new resource, add the resource with the
+//   permissions listed in the "Permissions" section of the Microsoft Graph API
+//   reference of its list and update requests. Resources that are not listed
+//   have no known permissions and are never warned about.
+// - When a permission is added to or removed from the API reference of a listed
+//   resource, update its entry. Read permissions only include the permissions
+//   that grant read access; the write permissions are always added to them.
+// - Renaming or removing a ResourceIdentity variant fails to compile until its
+//   entry is updated, and the `permission_tables` test checks that every entry
+//   is a well formed permission name listed once.
+resource_permissions! {
+    Applications {
+        read: ["Application.Read.All", "Directory.Read.All"],
+        write: ["Application.ReadWrite.All", "Application.ReadWrite.OwnedBy", "Directory.ReadWrite.All"]
+    }
+    AuditLogs {
+        read: ["AuditLog.Read.All"],
+        write: []
+    }
+    CalendarGroups {
+        read: ["Calendars.Read", "Calendars.Read.Shared"],
+        write: ["Calendars.ReadWrite", "Calendars.ReadWrite.Shared"]
+    }
+    CalendarView {
+        read: ["Calendars.ReadBasic", "Calendars.Read", "Calendars.Read.Shared"],
+        write: ["Calendars.ReadWrite", "Calendars.ReadWrite.Shared"]
+    }
+    Calendars {
+        read: ["Calendars.ReadBasic", "Calendars.Read", "Calendars.Read.Shared"],
+        write: ["Calendars.ReadWrite", "Calendars.ReadWrite.Shared"]
+    }
+    Chats {
+        read: ["Chat.ReadBasic", "Chat.Read", "Chat.ReadBasic.All", "Chat.Read.All"],
+        write: ["Chat.Create", "Chat.ReadWrite", "Chat.ReadWrite.All"]
+    }
+    ChatsMessages {
+        read: ["Chat.Read", "Chat.Read.All", "ChatMessage.Read", "ChatMessage.Read.All"],
+        write: ["ChatMessage.Send", "Chat.ReadWrite", "Chat.ReadWrite.All"]
+    }
+    ContactFolders {
+        read: ["Contacts.Read", "Contacts.Read.Shared"],
+        write: ["Contacts.ReadWrite", "Contacts.ReadWrite.Shared"]
+    }
+    Contacts {
+        read: ["Contacts.Read", "Contacts.Read.Shared"],
+        write: ["Contacts.ReadWrite", "Contacts.ReadWrite.Shared"]
+    }
+    DefaultCalendar {
+        read: ["Calendars.ReadBasic", "Calendars.Read", "Calendars.Read.Shared"],
+        write: ["Calendars.ReadWrite", "Calendars.ReadWrite.Shared"]
+    }
+    Devices {
+        read: ["Device.Read", "Device.Read.All", "Directory.Read.All"],
+        write: ["Device.ReadWrite.All", "Directory.ReadWrite.All"]
+    }
+    Drive {
+        read: ["Files.Read", "Files.Read.All", "Files.Read.Selected", "Sites.Read.All"],
+        write: ["Files.ReadWrite", "Files.ReadWrite.All", "Files.ReadWrite.AppFolder", "Files.ReadWrite.Selected", "Sites.ReadWrite.All", "Sites.Manage.All", "Sites.FullControl.All"]
+    }
+    Drives {
+        read: ["Files.Read", "Files.Read.All", "Files.Read.Selected", "Sites.Read.All"],
+        write: ["Files.ReadWrite", "Files.ReadWrite.All", "Files.ReadWrite.AppFolder", "Files.ReadWrite.Selected", "Sites.ReadWrite.All", "Sites.Manage.All", "Sites.FullControl.All"]
+    }
+    DrivesItems {
+        read: ["Files.Read", "Files.Read.All", "Files.Read.Selected", "Sites.Read.All"],
+        write: ["Files.ReadWrite", "Files.ReadWrite.All", "Files.ReadWrite.AppFolder", "Files.ReadWrite.Selected", "Sites.ReadWrite.All", "Sites.Manage.All", "Sites.FullControl.All"]
+    }
+    Events {
+        read: ["Calendars.ReadBasic", "Calendars.Read", "Calendars.Read.Shared"],
+        write: ["Calendars.ReadWrite", "Calendars.ReadWrite.Shared"]
+    }
+    Groups {
+        read: ["GroupMember.Read.All", "Group.Read.All", "Directory.Read.All"],
+        write: ["Group.Create", "Group.ReadWrite.All", "GroupMember.ReadWrite.All", "Directory.ReadWrite.All"]
+    }
+    MailboxSettings {
+        read: ["MailboxSettings.Read"],
+        write: ["MailboxSettings.ReadWrite"]
+    }
+    MailFolders {
+        read: ["Mail.ReadBasic", "Mail.Read", "Mail.ReadBasic.All", "Mail.Read.Shared"],
+        write: ["Mail.ReadWrite", "Mail.ReadWrite.Shared"]
+    }
+    Onenote {
+        read: ["Notes.Read", "Notes.Read.All"],
+        write: ["Notes.Create", "Notes.ReadWrite", "Notes.ReadWrite.All"]
+    }
+    OnlineMeetings {
+        read: ["OnlineMeetings.Read", "OnlineMeetings.Read.All"],
+        write: ["OnlineMeetings.ReadWrite", "OnlineMeetings.ReadWrite.All"]
+    }
+    People {
+        read: ["People.Read", "People.Read.All"],
+        write: []
+    }
+    Places {
+        read: ["Place.Read.All"],
+        write: ["Place.ReadWrite.All"]
+    }
+    Planner {
+        read: ["Tasks.Read", "Tasks.Read.All", "Group.Read.All"],
+        write: ["Tasks.ReadWrite", "Tasks.ReadWrite.All", "Group.ReadWrite.All"]
+    }
+    Presence {
+        read: ["Presence.Read", "Presence.Read.All"],
+        write: ["Presence.ReadWrite", "Presence.ReadWrite.All"]
+    }
+    Reports {
+        read: ["Reports.Read.All"],
+        write: []
+    }
+    ServicePrincipals {
+        read: ["Application.Read.All", "Directory.Read.All"],
+        write: ["Application.ReadWrite.All", "Application.ReadWrite.OwnedBy", "Directory.ReadWrite.All"]
+    }
+    Sites {
+        read: ["Sites.Read.All", "Sites.Selected"],
+        write: ["Sites.ReadWrite.All", "Sites.Manage.All", "Sites.FullControl.All"]
+    }
+    SubscribedSkus {
+        read: ["LicenseAssignment.Read.All", "Organization.Read.All", "Directory.Read.All"],
+        write: ["Organization.ReadWrite.All", "Directory.ReadWrite.All"]
+    }
+    Teams {
+        read: ["Team.ReadBasic.All", "TeamSettings.Read.All", "Group.Read.All", "Directory.Read.All"],
+        write: ["Team.Create", "TeamSettings.ReadWrite.All", "Group.ReadWrite.All", "Directory.ReadWrite.All"]
+    }
+    Todo {
+        read: ["Tasks.Read", "Tasks.Read.All"],
+        write: ["Tasks.ReadWrite", "Tasks.ReadWrite.All"]
+    }
+    TodoLists {
+        read: ["Tasks.Read", "Tasks.Read.All"],
+        write: ["Tasks.ReadWrite", "Tasks.ReadWrite.All"]
+    }
+    TodoListsTasks {
+        read: ["Tasks.Read", "Tasks.Read.All"],
+        write: ["Tasks.ReadWrite", "Tasks.ReadWrite.All"]
+    }
+    UsersAttachments {
+        read: ["Mail.Read", "Mail.Read.Shared", "Calendars.Read", "Calendars.Read.Shared"],
+        write: ["Mail.ReadWrite", "Mail.ReadWrite.Shared", "Calendars.ReadWrite", "Calendars.ReadWrite.Shared"]
+    }
+    UsersMessages {
+        read: ["Mail.ReadBasic", "Mail.Read", "Mail.ReadBasic.All", "Mail.Read.Shared"],
+        write: ["Mail.ReadWrite", "Mail.ReadWrite.Shared", "Mail.Send", "Mail.Send.Shared"]
+    }
+}
+
+macro_rules! action_permissions {
+    ($($action:literal: [$($permission:literal),*])+) => {
+        // The permissions of an action by the name of the action, the last segment
+        // of the request path.
+        fn action_permissions(action: &str) -> Option<&'static [&'static str]> {
+            match action {
+                $($action => Some(&[$($permission,)*]),)+
+                _ => None,
+            }
+        }
+
+        #[cfg(test)]
+        const ACTIONS: &[&str] = &[$($action,)+];
+    };
+}
+
+// Actions whose documented permissions differ from the permissions of the resource
+// they are called on, such as `POST` actions that only read.
+action_permissions! {
+    "getSchedule": ["Calendars.ReadBasic", "Calendars.Read", "Calendars.ReadWrite"]
+    "findMeetingTimes": ["Calendars.Read.Shared", "Calendars.ReadWrite.Shared"]
+    "getByIds": ["Directory.Read.All", "Directory.ReadWrite.All"]
+    "getMemberGroups": ["User.ReadBasic.All", "User.Read.All", "GroupMember.Read.All", "Group.Read.All", "Directory.Read.All", "Directory.ReadWrite.All"]
+    "getMemberObjects": ["User.ReadBasic.All", "User.Read.All", "GroupMember.Read.All", "Group.Read.All", "Directory.Read.All", "Directory.ReadWrite.All"]
+    "checkMemberGroups": ["User.ReadBasic.All", "User.Read.All", "GroupMember.Read.All", "Group.Read.All", "Directory.Read.All", "Directory.ReadWrite.All"]
+    "checkMemberObjects": ["User.ReadBasic.All", "User.Read.All", "GroupMember.Read.All", "Group.Read.All", "Directory.Read.All", "Directory.ReadWrite.All"]
+    "sendMail": ["Mail.Send", "Mail.Send.Shared"]
+}
+
+impl ResourceIdentity {
+    /// The permissions that allow a request with the method and url path on the
+    /// resource, any one of which is enough. Actions with documented permissions,
+    /// such as `getSchedule`, use those permissions. Otherwise the read permissions
+    /// of the resource are used for `GET` and `HEAD` requests and the write
+    /// permissions for other requests.
+    ///
+    /// # Example
+    /// ```rust
+    /// use graph_core::resource::ResourceIdentity;
+    /// use http::Method;
+    ///
+    /// let permissions = ResourceIdentity::Calendars
+    ///     .required_permissions(&Method::PATCH, "/v1.0/me/calendars/1");
+    /// assert!(permissions.contains(&"Calendars.ReadWrite"));
+    /// assert!(!permissions.contains(&"Calendars.Read"));
+    ///
+    /// let permissions = ResourceIdentity::DefaultCalendar
+    ///     .required_permissions(&Method::POST, "/v1.0/me/calendar/getSchedule");
+    /// assert!(permissions.contains(&"Calendars.Read"));
+    /// ```
+    pub fn required_permissions(&self, method: &Method, path: &str) -> &'static [&'static str] {
+        let action = path
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or_default();
+        let action = action.trim_start_matches("microsoft.graph.");
+        if let Some(permissions) = action_permissions(action) {
+            permissions
+        } else if method == Method::GET || method == Method::HEAD {
+            self.read_permissions()
+        } else {
+            self.write_permissions()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+    use strum::IntoEnumIterator;
+
+    fn assert_permission_names(permissions: &[&str]) {
+        let mut names = HashSet::new();
+        for permission in permissions {
+            let segments: Vec<&str> = permission.split('.').collect();
+            assert!(
+                segments.len() >= 2
+                    && segments
+                        .iter()
+                        .all(|segment| !segment.is_empty()
+                            && segment.chars().all(char::is_alphanumeric)),
+                "malformed permission {permission}"
+            );
+            assert!(
+                names.insert(permission),
+                "duplicate permission {permission}"
+            );
+        }
+    }
+
+    #[test]
+    fn permission_tables() {
+        for resource_identity in ResourceIdentity::iter() {
+            let read = resource_identity.read_permissions();
+            let write = resource_identity.write_permissions();
+            assert_permission_names(read);
+            assert_permission_names(write);
+            assert!(write.iter().all(|permission| read.contains(permission)));
+        }
+
+        for action in ACTIONS {
+            assert_permission_names(action_permissions(action).unwrap());
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt::{Display, Formatter};
+
+/// Microsoft Entra (AADSTS) error codes returned in the `error_codes` array of a token
+/// endpoint error response and at the start of the `error_description` of token and
+/// authorization error responses, such as `AADSTS65004: User declined to consent to access the app.`
+///
+/// Branch on the cause of a failed request instead of matching the text
+/// of the error description. Codes that do not have a variant are returned
+/// as [AadstsError::Other].
+///
+/// [Azure AD authentication and authorization error codes](https://learn.microsoft.com/en-us/entra/identity-platform/reference-error-codes)
+///
+/// # Example
+/// ```rust
+/// # use graph_error::AadstsError;
+/// let body = serde_json::json!({
+///     "error": "invalid_client",
+///     "error_description": "AADSTS7000215: Invalid client secret provided.",
+///     "error_codes": [7000215]
+/// });
+///
+/// let errors = AadstsError::from_response_body(&body);
+/// assert_eq!(vec![AadstsError::InvalidClientSecret], errors);
+/// assert!(!errors[0].requires_interaction());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum AadstsError {
+    /// AADSTS50011: The redirect uri in the request does not match the redirect
+    /// uris configured for the application.
+    RedirectUriMismatch,
+    /// AADSTS50034: The user account does not exist in the directory.
+    UserAccountNotFound,
+    /// AADSTS50053: The account is locked after too many sign in attempts.
+    AccountLocked,
+    /// AADSTS50055: The password of the user is expired.
+    PasswordExpired,
+    /// AADSTS50057: The user account is disabled.
+    UserDisabled,
+    /// AADSTS50058: A silent sign-in request was sent but no user is signed in.
+    UserNotSignedIn,
+    /// AADSTS50076 and AADSTS50079: The user is required to use multi-factor authentication.
+    MfaRequired,
+    /// AADSTS50126: The username or password is invalid.
+    InvalidCredentials,
+    /// AADSTS50173: The grant expired because the user changed their password
+    /// or the grant was revoked.
+    FreshTokenRequired,
+    /// AADSTS53000: The device has to be managed or compliant to access the resource.
+    DeviceNotCompliant,
+    /// AADSTS53003: Access was blocked by a Conditional Access policy.
+    BlockedByConditionalAccess,
+    /// AADSTS54005: The authorization code was already redeemed.
+    AuthorizationCodeRedeemed,
+    /// AADSTS65001: The user or administrator has not consented to the application.
+    ConsentRequired,
+    /// AADSTS65004: The user declined to consent to the application.
+    ConsentDeclined,
+    /// AADSTS70000: The grant is invalid, expired or for a different client.
+    InvalidGrant,
+    /// AADSTS70008: The refresh token or authorization code expired or was revoked.
+    ExpiredOrRevokedGrant,
+    /// AADSTS70011: The scope requested is invalid.
+    InvalidScope,
+    /// AADSTS70016: The user has not finished signing in with the device code.
+    AuthorizationPending,
+    /// AADSTS70019: The device code expired.
+    DeviceCodeExpired,
+    /// AADSTS700016: The application was not found in the directory of the tenant.
+    ApplicationNotFound,
+    /// AADSTS700027: The signature or certificate of the client assertion is invalid.
+    InvalidClientAssertion,
+    /// AADSTS700082: The refresh token expired due to inactivity.
+    RefreshTokenExpired,
+    /// AADSTS7000215: The client secret is invalid.
+    InvalidClientSecret,
+    /// AADSTS7000222: The client secret is expired.
+    ClientSecretExpired,
+    /// AADSTS90002: The tenant was not found.
+    TenantNotFound,
+    /// AADSTS90014: A required field for a protocol message was missing.
+    MissingRequiredField,
+    /// AADSTS90094: An administrator has to consent to the application.
+    AdminConsentRequired,
+    /// An error code that does not have a variant.
+    Other(u32),
+}
+
+impl AadstsError {
+    pub fn from_code(code: u32) -> AadstsError {
+        match code {
+            50011 => AadstsError::RedirectUriMismatch,
+            50034 => AadstsError::UserAccountNotFound,
+            50053 => AadstsError::AccountLocked,
+            50055 => AadstsError::PasswordExpired,
+            50057 => AadstsError::UserDisabled,
+            50058 => AadstsError::UserNotSignedIn,
+            50076 | 50079 => AadstsError::MfaRequired,
+            50126 => AadstsError::InvalidCredentials,
+            50173 => AadstsError::FreshTokenRequired,
+            53000 => AadstsError::DeviceNotCompliant,
+            53003 => AadstsError::BlockedByConditionalAccess,
+            54005 => AadstsError::AuthorizationCodeRedeemed,
+            65001 => AadstsError::ConsentRequired,
+            65004 => AadstsError::ConsentDeclined,
+            70000 => AadstsError::InvalidGrant,
+            70008 => AadstsError::ExpiredOrRevokedGrant,
+            70011 => AadstsError::InvalidScope,
+            70016 => AadstsError::AuthorizationPending,
+            70019 => AadstsError::DeviceCodeExpired,
+            700016 => AadstsError::ApplicationNotFound,
+            700027 => AadstsError::InvalidClientAssertion,
+            700082 => AadstsError::RefreshTokenExpired,
+            7000215 => AadstsError::InvalidClientSecret,
+            7000222 => AadstsError::ClientSecretExpired,
+            90002 => AadstsError::TenantNotFound,
+            90014 => AadstsError::MissingRequiredField,
+            90094 => AadstsError::AdminConsentRequired,
+            code => AadstsError::Other(code),
+        }
+    }
+
+    /// The numeric error code, such as `7000215` for [AadstsError::InvalidClientSecret].
+    /// [AadstsError::MfaRequired] returns `50076`.
+    pub fn code(&self) -> u32 {
+        match self {
+            AadstsError::RedirectUriMismatch => 50011,
+            AadstsError::UserAccountNotFound => 50034,
+            AadstsError::AccountLocked => 50053,
+            AadstsError::PasswordExpired => 50055,
+            AadstsError::UserDisabled => 50057,
+            AadstsError::UserNotSignedIn => 50058,
+            AadstsError::MfaRequired => 50076,
+            AadstsError::InvalidCredentials => 50126,
+            AadstsError::FreshTokenRequired => 50173,
+            AadstsError::DeviceNotCompliant => 53000,
+            AadstsError::BlockedByConditionalAccess => 53003,
+            AadstsError::AuthorizationCodeRedeemed => 54005,
+            AadstsError::ConsentRequired => 65001,
+            AadstsError::ConsentDeclined => 65004,
+            AadstsError::InvalidGrant => 70000,
+            AadstsError::ExpiredOrRevokedGrant => 70008,
+            AadstsError::InvalidScope => 70011,
+            AadstsError::AuthorizationPending => 70016,
+            AadstsError::DeviceCodeExpired => 70019,
+            AadstsError::ApplicationNotFound => 700016,
+            AadstsError::InvalidClientAssertion => 700027,
+            AadstsError::RefreshTokenExpired => 700082,
+            AadstsError::InvalidClientSecret => 7000215,
+            AadstsError::ClientSecretExpired => 7000222,
+            AadstsError::TenantNotFound => 90002,
+            AadstsError::MissingRequiredField => 90014,
+            AadstsError::AdminConsentRequired => 90094,
+            AadstsError::Other(code) => *code,
+        }
+    }
+
+    /// Returns true if the user has to sign in or consent interactively, such as with
+    /// the authorization code flow, before a token can be acquired. Token requests that
+    /// failed for these reasons will not succeed when retried silently.
+    pub fn requires_interaction(&self) -> bool {
+        matches!(
+            self,
+            AadstsError::PasswordExpired
+                | AadstsError::UserNotSignedIn
+                | AadstsError::MfaRequired
+                | AadstsError::FreshTokenRequired
+                | AadstsError::DeviceNotCompliant
+                | AadstsError::ConsentRequired
+                | AadstsError::ExpiredOrRevokedGrant
+                | AadstsError::RefreshTokenExpired
+                | AadstsError::AdminConsentRequired
+        )
+    }
+
+    /// The error codes of a token endpoint error response body. The `error_codes` array
+    /// is used when present, otherwise the `AADSTS` codes in the `error_description`.
+    pub fn from_response_body(body: &serde_json::Value) -> Vec<AadstsError> {
+        if let Some(error_codes) = body["error_codes"].as_array() {
+            return error_codes
+                .iter()
+                .filter_map(|code| code.as_u64())
+                .filter_map(|code| u32::try_from(code).ok())
+                .map(AadstsError::from_code)
+                .collect();
+        }
+
+        body["error_description"]
+            .as_str()
+            .and_then(AadstsError::from_error_description)
+            .into_iter()
+            .collect()
+    }
+
+    /// Parse the first AADSTS error code in an error description, such as
+    /// `AADSTS65004: User declined to consent to access the app.`
+    pub fn from_error_description(error_description: &str) -> Option<AadstsError> {
+        let (_, code) = error_description.split_once("AADSTS")?;
+        let code: String = code.chars().take_while(|c| c.is_ascii_digit()).collect();
+        code.parse().ok().map(AadstsError::from_code)
+    }
+}
+
+impl From<u32> for AadstsError {
+    fn from(code: u32) -> Self {
+        AadstsError::from_code(code)
+    }
+}
+
+impl Display for AadstsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AADSTS{}", self.code())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_error_codes() {
+        let body = serde_json::json!({
+            "error": "invalid_grant",
+            "error_description": "AADSTS65001: The user or administrator has not consented to use the application.",
+            "error_codes": [65001, 12345]
+        });
+        let errors = AadstsError::from_response_body(&body);
+        assert_eq!(
+            vec![AadstsError::ConsentRequired, AadstsError::Other(12345)],
+            errors
+        );
+        assert!(errors[0].requires_interaction());
+        assert!(!errors[1].requires_interaction());
+        assert_eq!("AADSTS12345", errors[1].to_string());
+
+        let body = serde_json::json!({
+            "error": "invalid_client",
+            "error_description": "AADSTS7000222: The provided client secret keys are expired.\r\nTrace ID: 1"
+        });
+        assert_eq!(
+            vec![AadstsError::ClientSecretExpired],
+            AadstsError::from_response_body(&body)
+        );
+
+        for code in [50011, 50076, 90014, 700082, 7000215, 90094] {
+            assert_eq!(code, AadstsError::from(code).code());
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ErrorMessage, IdentityResult, WebViewDeviceCodeError};
+use crate::{AadstsError, ErrorMessage, IdentityResult, WebViewDeviceCodeError};
 use tokio::sync::mpsc::error::SendTimeoutError;
 use url::ParseError;
 
@@ -25,6 +25,11 @@ pub enum AuthorizationFailure {
 
     #[error("{0:#?}")]
     SerdeJson(#[from] serde_json::Error),
+
+    /// Two parameters were set that cannot be used together, such as
+    /// `prompt=select_account` and `login_hint` in an authorization url.
+    #[error("{name} cannot be used with {other}")]
+    MutuallyExclusive { name: String, other: String },
 }
 
 impl AuthorizationFailure {
@@ -78,6 +83,13 @@ impl AuthorizationFailure {
         }
     }
 
+    pub fn mutually_exclusive(name: impl ToString, other: impl ToString) -> AuthorizationFailure {
+        AuthorizationFailure::MutuallyExclusive {
+            name: name.to_string(),
+            other: other.to_string(),
+        }
+    }
+
     pub fn x509(message: impl ToString) -> AuthorizationFailure {
         AuthorizationFailure::Openssl(message.to_string())
     }
@@ -120,6 +132,84 @@ impl AuthExecutionError {
             response,
         }
     }
+
+    /// An error response from a service other than the token endpoint that is called
+    /// by a credential, such as Azure Key Vault. The body of the response is kept.
+    pub fn error_response(
+        message: impl ToString,
+        response: http::Response<Result<serde_json::Value, ErrorMessage>>,
+    ) -> AuthExecutionError {
+        AuthExecutionError::SilentTokenAuth {
+            message: message.to_string(),
+            response,
+        }
+    }
+
+    /// Whether the token request failed because the user has to sign in or consent
+    /// interactively, such as when requesting scopes the user has not consented to.
+    pub fn is_interaction_required(&self) -> bool {
+        match self {
+            AuthExecutionError::SilentTokenAuth { response, .. } => {
+                matches!(
+                    response
+                        .body()
+                        .as_ref()
+                        .ok()
+                        .and_then(|body| body["error"].as_str()),
+                    Some(
+                        "interaction_required"
+                            | "consent_required"
+                            | "login_required"
+                            | "invalid_grant"
+                    )
+                ) || self
+                    .aadsts_errors()
+                    .iter()
+                    .any(AadstsError::requires_interaction)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the token request failed because the token service is unavailable, such as
+    /// a 5xx response, a `temporarily_unavailable` error or a timeout.
+    pub fn is_service_unavailable(&self) -> bool {
+        match self {
+            AuthExecutionError::Request(err) => {
+                err.is_timeout()
+                    || err.is_connect()
+                    || err
+                        .status()
+                        .map(|status| status.is_server_error())
+                        .unwrap_or_default()
+            }
+            AuthExecutionError::SilentTokenAuth { response, .. } => {
+                response.status().is_server_error()
+                    || matches!(
+                        response
+                            .body()
+                            .as_ref()
+                            .ok()
+                            .and_then(|body| body["error"].as_str()),
+                        Some("temporarily_unavailable" | "server_error")
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    /// The AADSTS error codes of a failed token request, parsed from the `error_codes`
+    /// of the error response. Empty if the error is not from the token endpoint.
+    pub fn aadsts_errors(&self) -> Vec<AadstsError> {
+        match self {
+            AuthExecutionError::SilentTokenAuth { response, .. } => response
+                .body()
+                .as_ref()
+                .map(AadstsError::from_response_body)
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl From<serde_json::error::Error> for AuthExecutionError {
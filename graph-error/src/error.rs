@@ -8,6 +8,9 @@ pub struct InnerError {
     #[serde(rename = "request-id")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<String>,
+    #[serde(rename = "client-request-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_request_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub date: Option<String>,
 }
@@ -69,14 +72,55 @@ impl ErrorMessage {
         self.error.inner_error.as_ref()?.request_id.clone()
     }
 
+    pub fn client_request_id(&self) -> Option<String> {
+        self.error.inner_error.as_ref()?.client_request_id.clone()
+    }
+
     pub fn date(&self) -> Option<String> {
         self.error.inner_error.as_ref()?.date.clone()
     }
+
+    /// Set the `request-id` and `client-request-id` from the response headers
+    /// when they are not included in the inner error of the response body.
+    pub fn set_request_ids(
+        &mut self,
+        request_id: Option<String>,
+        client_request_id: Option<String>,
+    ) {
+        let inner_error = self.error.inner_error.get_or_insert_with(Default::default);
+        if inner_error.request_id.is_none() {
+            inner_error.request_id = request_id;
+        }
+        if inner_error.client_request_id.is_none() {
+            inner_error.client_request_id = client_request_id;
+        }
+    }
 }
 
+/// Writes the error on a single line with the ids needed to open a support case,
+/// such as `Request_ResourceNotFound: Resource 'x' does not exist. (request-id: 1, client-request-id: 2, date: 2024-03-20T08:00:00)`.
 impl Display for ErrorMessage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({:#?})", self.error)
+        write!(
+            f,
+            "{}: {}",
+            self.error.code.as_deref().unwrap_or("UnknownError"),
+            self.error.message.as_deref().unwrap_or_default()
+        )?;
+        if let Some(inner_error) = self.error.inner_error.as_ref() {
+            let ids: Vec<String> = [
+                ("request-id", inner_error.request_id.as_ref()),
+                ("client-request-id", inner_error.client_request_id.as_ref()),
+                ("date", inner_error.date.as_ref()),
+            ]
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| format!("{name}: {value}")))
+            .collect();
+            if !ids.is_empty() {
+                write!(f, " ({})", ids.join(", "))?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -176,6 +220,21 @@ impl ErrorType {
     pub fn is_error(status: u16) -> bool {
         ErrorType::from_u16(status).is_some()
     }
+
+    /// Returns true for 429 Too Many Requests and 503 Service Unavailable, the status
+    /// codes of throttled requests that are retried after the Retry-After header.
+    pub fn is_throttled(status: u16) -> bool {
+        matches!(
+            ErrorType::from_u16(status),
+            Some(ErrorType::TooManyRequests | ErrorType::ServiceUnavailable)
+        )
+    }
+
+    /// Returns true for status codes of requests that may succeed when sent again
+    /// after a delay, throttled requests and 502 Bad Gateway or 504 Gateway Timeout.
+    pub fn is_retryable(status: u16) -> bool {
+        ErrorType::is_throttled(status) || status == 502 || status == 504
+    }
 }
 
 impl ToString for ErrorType {
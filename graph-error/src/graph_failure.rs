@@ -1,7 +1,8 @@
 use crate::download::AsyncDownloadError;
 use crate::internal::GraphRsError;
-use crate::{AuthExecutionError, AuthorizationFailure, ErrorMessage};
+use crate::{AadstsError, AuthExecutionError, AuthorizationFailure, ErrorMessage, ErrorType};
 use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
 use std::cell::BorrowMutError;
 use std::io;
 use std::io::ErrorKind;
@@ -71,7 +72,7 @@ pub enum GraphFailure {
         message: String,
     },
 
-    #[error("{0:#?}")]
+    #[error("{0}")]
     ErrorMessage(#[from] ErrorMessage),
 
     #[error("message: {0:#?}, response: {1:#?}", message, response)]
@@ -101,6 +102,121 @@ impl GraphFailure {
     pub fn invalid(msg: &str) -> Self {
         GraphFailure::internal(GraphRsError::InvalidOrMissing { msg: msg.into() })
     }
+
+    /// The status code of the response that caused the error, if any.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            GraphFailure::ReqwestError(e) => e.status(),
+            GraphFailure::SilentTokenAuth { response, .. } => Some(response.status()),
+            GraphFailure::PreFlightError {
+                error: Some(error), ..
+            } => error.status(),
+            _ => None,
+        }
+    }
+
+    /// The `request-id` of the failed request returned by Graph, if any. Include it
+    /// with the [client_request_id](Self::client_request_id) when opening a support case.
+    pub fn request_id(&self) -> Option<String> {
+        match self {
+            GraphFailure::ErrorMessage(message) => message.request_id(),
+            GraphFailure::PreFlightError {
+                error: Some(error), ..
+            } => error.request_id(),
+            _ => None,
+        }
+    }
+
+    /// The `client-request-id` sent with the failed request, if any.
+    pub fn client_request_id(&self) -> Option<String> {
+        match self {
+            GraphFailure::ErrorMessage(message) => message.client_request_id(),
+            GraphFailure::PreFlightError {
+                error: Some(error), ..
+            } => error.client_request_id(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the request was throttled, a 429 Too Many Requests or
+    /// 503 Service Unavailable response. These are the responses retried by the
+    /// client after waiting for the Retry-After header.
+    pub fn is_throttled(&self) -> bool {
+        match self {
+            GraphFailure::ErrorMessage(message) => matches!(
+                message.code_property().as_deref(),
+                Some("activityLimitReached" | "TooManyRequests" | "serviceNotAvailable")
+            ),
+            _ => self
+                .status()
+                .map(|status| ErrorType::is_throttled(status.as_u16()))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns true if sending the request again after a delay may succeed. This
+    /// includes throttled requests, 502 Bad Gateway and 504 Gateway Timeout responses
+    /// and timeouts or failed connections before a response was received.
+    pub fn is_retryable(&self) -> bool {
+        if self.is_throttled() {
+            return true;
+        }
+        match self {
+            GraphFailure::ReqwestError(e) if e.is_timeout() || e.is_connect() => true,
+            GraphFailure::Io(e) => matches!(
+                e.kind(),
+                ErrorKind::TimedOut
+                    | ErrorKind::Interrupted
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+            ),
+            GraphFailure::ErrorMessage(message) => matches!(
+                message.code_property().as_deref(),
+                Some("generalException" | "timeout" | "UnknownError")
+            ),
+            _ => self
+                .status()
+                .map(|status| ErrorType::is_retryable(status.as_u16()))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns true if the access token was rejected because it expired or the
+    /// token endpoint was temporarily unavailable. The request may succeed after
+    /// requesting a new access token, such as with `ForceTokenRefresh::Once`.
+    pub fn is_transient_auth(&self) -> bool {
+        match self {
+            GraphFailure::ErrorMessage(message) => matches!(
+                message.code_property().as_deref(),
+                Some("InvalidAuthenticationToken")
+            ),
+            GraphFailure::SilentTokenAuth { response, .. } => {
+                let status = response.status().as_u16();
+                let error = response
+                    .body()
+                    .as_ref()
+                    .ok()
+                    .and_then(|body| body["error"].as_str());
+                ErrorType::is_retryable(status)
+                    || status == 500
+                    || matches!(error, Some("temporarily_unavailable" | "server_error"))
+            }
+            _ => self.status() == Some(StatusCode::UNAUTHORIZED),
+        }
+    }
+
+    /// The AADSTS error codes of a failed token request, such as
+    /// [AadstsError::ConsentRequired]. Empty if the error is not from the token endpoint.
+    pub fn aadsts_errors(&self) -> Vec<AadstsError> {
+        match self {
+            GraphFailure::SilentTokenAuth { response, .. } => response
+                .body()
+                .as_ref()
+                .map(AadstsError::from_response_body)
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl Default for GraphFailure {
@@ -150,6 +266,14 @@ impl From<AuthExecutionError> for GraphFailure {
                     message,
                 },
                 AuthorizationFailure::SerdeJson(error) => GraphFailure::SerdeJson(error),
+                AuthorizationFailure::MutuallyExclusive { name, other } => {
+                    GraphFailure::PreFlightError {
+                        url: None,
+                        headers: None,
+                        error: None,
+                        message: format!("{name} cannot be used with {other}"),
+                    }
+                }
             },
             AuthExecutionError::Request(e) => GraphFailure::ReqwestError(e),
             AuthExecutionError::Http(e) => GraphFailure::HttpError(e),
@@ -160,3 +284,55 @@ impl From<AuthExecutionError> for GraphFailure {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn graph_error(code: &str) -> GraphFailure {
+        let message: ErrorMessage = serde_json::from_value(serde_json::json!({
+            "error": { "code": code, "message": "" }
+        }))
+        .unwrap();
+        GraphFailure::from(message)
+    }
+
+    #[test]
+    fn retry_classification() {
+        assert!(graph_error("TooManyRequests").is_throttled());
+        assert!(graph_error("serviceNotAvailable").is_retryable());
+        assert!(graph_error("timeout").is_retryable());
+        assert!(!graph_error("timeout").is_throttled());
+        assert!(!graph_error("invalidRequest").is_retryable());
+        assert!(!graph_error("accessDenied").is_transient_auth());
+        assert!(GraphFailure::error_kind(ErrorKind::TimedOut, "timed out").is_retryable());
+        assert!(!GraphFailure::invalid("id").is_retryable());
+
+        let message: ErrorMessage = serde_json::from_value(serde_json::json!({
+            "error": { "code": "InvalidAuthenticationToken", "message": "Access token has expired." }
+        }))
+        .unwrap();
+        assert!(GraphFailure::from(message).is_transient_auth());
+    }
+
+    #[test]
+    fn error_message_request_ids() {
+        let mut message: ErrorMessage = serde_json::from_value(serde_json::json!({
+            "error": {
+                "code": "Request_ResourceNotFound",
+                "message": "Resource 'x' does not exist.",
+                "innerError": { "date": "2024-03-20T08:00:00" }
+            }
+        }))
+        .unwrap();
+        message.set_request_ids(Some("1".into()), Some("2".into()));
+
+        let failure = GraphFailure::from(message);
+        assert_eq!(Some("1".into()), failure.request_id());
+        assert_eq!(Some("2".into()), failure.client_request_id());
+        assert_eq!(
+            "Request_ResourceNotFound: Resource 'x' does not exist. (request-id: 1, client-request-id: 2, date: 2024-03-20T08:00:00)",
+            failure.to_string()
+        );
+    }
+}
@@ -3,6 +3,7 @@
 #[macro_use]
 extern crate serde;
 
+mod aadsts_error;
 mod authorization_failure;
 pub mod download;
 mod error;
@@ -11,6 +12,7 @@ mod internal;
 pub mod io_error;
 mod webview_error;
 
+pub use aadsts_error::*;
 pub use authorization_failure::*;
 pub use error::*;
 pub use graph_failure::*;
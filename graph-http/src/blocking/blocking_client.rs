@@ -10,6 +10,7 @@ pub struct BlockingClient {
     pub(crate) inner: reqwest::blocking::Client,
     pub(crate) client_application: Box<dyn ClientApplication>,
     pub(crate) headers: HeaderMap,
+    pub(crate) validate_permissions: bool,
 }
 
 impl BlockingClient {
@@ -1,10 +1,16 @@
 use crate::blocking::blocking_client::BlockingClient;
 use crate::internal::*;
+use bytes::Bytes;
+use graph_core::http::inject_trace_context;
+use graph_core::identity::ForceTokenRefresh;
+use graph_core::metrics::{record_request, record_retry};
+use graph_core::resource::ResourceIdentity;
 use graph_error::{ErrorMessage, GraphFailure, GraphResult};
 use http::header::CONTENT_TYPE;
 use http::{HeaderMap, HeaderName, HeaderValue};
 use serde::de::DeserializeOwned;
 use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 use url::Url;
 
 #[derive(Default)]
@@ -22,7 +28,10 @@ impl BlockingRequestHandler {
         err: Option<GraphFailure>,
         body: Option<BodyRead>,
     ) -> BlockingRequestHandler {
-        request_components.headers.extend(inner.headers.clone());
+        request_components.headers = merge_default_headers(
+            &inner.headers,
+            std::mem::take(&mut request_components.headers),
+        );
 
         let mut error = None;
         if let Some(err) = err {
@@ -129,13 +138,36 @@ impl BlockingRequestHandler {
         self.request_components.as_mut()
     }
 
+    /// Set the token refresh policy for this request only. The policy of the client
+    /// used for other requests is not changed.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .me()
+    ///     .get_user()
+    ///     .force_token_refresh(ForceTokenRefresh::Once)
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn force_token_refresh(mut self, force_token_refresh: ForceTokenRefresh) -> Self {
+        self.inner
+            .client_application
+            .with_force_token_refresh(force_token_refresh);
+        self
+    }
+
     pub fn paging(self) -> BlockingPaging {
-        BlockingPaging(self)
+        BlockingPaging::new(self)
     }
 
     #[inline]
     fn default_request_builder(&mut self) -> GraphResult<reqwest::blocking::RequestBuilder> {
         let access_token = self.inner.client_application.get_token_silent()?;
+        if self.inner.validate_permissions {
+            self.request_components
+                .warn_missing_permissions(access_token.as_str());
+        }
 
         let request_builder = self
             .inner
@@ -168,10 +200,51 @@ impl BlockingRequestHandler {
         self.default_request_builder()
     }
 
+    /// Builds the request and returns it as an [`http::Request<Bytes>`]. Fails when
+    /// the body of the request is a stream.
+    #[allow(clippy::result_large_err)]
+    pub fn into_http_request(self) -> GraphResult<http::Request<Bytes>> {
+        self.build()?.build()?.try_into_bytes_request()
+    }
+
+    /// Render the method, url, headers, and body that [send](BlockingRequestHandler::send)
+    /// would send, including the `client-request-id` header, without sending the request.
+    /// Fails when the body of the request is a stream.
+    #[allow(clippy::result_large_err)]
+    pub fn build_request(mut self) -> GraphResult<RenderedRequest> {
+        ensure_client_request_id(&mut self.request_components.headers);
+        RenderedRequest::from_http_request(self.into_http_request()?)
+    }
+
+    /// Send the request. A `client-request-id` header is added unless one was set,
+    /// and the request is sent in a `graph_request` tracing span.
     #[inline]
-    pub fn send(self) -> GraphResult<reqwest::blocking::Response> {
+    pub fn send(mut self) -> GraphResult<reqwest::blocking::Response> {
+        let client_request_id = ensure_client_request_id(&mut self.request_components.headers);
+        let span = request_span(
+            &self.request_components.method,
+            &self.request_components.url,
+            &client_request_id,
+        );
+        let _enter = span.enter();
+        inject_trace_context(&mut self.request_components.headers);
+        let method = self.request_components.method.clone();
+        let resource_identity = self.request_components.resource_identity;
         let request_builder = self.build()?;
-        request_builder.send().map_err(GraphFailure::from)
+        let start = Instant::now();
+        let response = request_builder.send();
+        record_request(
+            &method,
+            resource_identity.as_ref(),
+            response
+                .as_ref()
+                .ok()
+                .map(reqwest::blocking::Response::status),
+            start.elapsed(),
+        );
+        let response = response.map_err(GraphFailure::from)?;
+        record_response(&span, response.status(), response.headers());
+        Ok(response)
     }
 }
 
@@ -193,9 +266,107 @@ impl AsMut<Url> for BlockingRequestHandler {
     }
 }
 
-pub struct BlockingPaging(BlockingRequestHandler);
+pub struct BlockingPaging {
+    request_handler: BlockingRequestHandler,
+    throttle_policy: ThrottlePolicy,
+}
 
 impl BlockingPaging {
+    pub(crate) fn new(request_handler: BlockingRequestHandler) -> BlockingPaging {
+        BlockingPaging {
+            throttle_policy: ThrottlePolicy {
+                resource_identity: request_handler.request_components.resource_identity,
+                ..Default::default()
+            },
+            request_handler,
+        }
+    }
+
+    /// Set the number of times a page request is retried when the response is
+    /// throttled (429 or 503). The request is retried after the duration given in
+    /// the Retry-After header and paging resumes from the same next link.
+    ///
+    /// Defaults to 5. Use 0 to return throttled responses without retrying.
+    pub fn max_throttle_retries(mut self, max_throttle_retries: usize) -> Self {
+        self.throttle_policy.max_retries = max_throttle_retries;
+        self
+    }
+
+    /// Wait for the rate limit window to reset before requesting the next page
+    /// when a response reports that no requests remain in the current window.
+    /// See [`ThrottleInfo`].
+    ///
+    /// Default is `false`.
+    pub fn pace_requests(mut self, pace_requests: bool) -> Self {
+        self.throttle_policy.pace_requests = pace_requests;
+        self
+    }
+
+    /// Send the request once in a `graph_request` tracing span and record the
+    /// request metrics.
+    #[allow(clippy::result_large_err)]
+    fn execute(
+        request: reqwest::blocking::RequestBuilder,
+        resource_identity: ResourceIdentity,
+    ) -> GraphResult<reqwest::blocking::Response> {
+        let (client, request) = request.build_split();
+        let mut request = request?;
+        let client_request_id = ensure_client_request_id(request.headers_mut());
+        let span = request_span(request.method(), request.url(), &client_request_id);
+        let _enter = span.enter();
+
+        inject_trace_context(request.headers_mut());
+        let method = request.method().clone();
+        let start = Instant::now();
+        let response = client.execute(request);
+        record_request(
+            &method,
+            resource_identity.as_ref(),
+            response
+                .as_ref()
+                .ok()
+                .map(reqwest::blocking::Response::status),
+            start.elapsed(),
+        );
+        let response = response?;
+        record_response(&span, response.status(), response.headers());
+        Ok(response)
+    }
+
+    /// Send the request, retrying the same request while the response is throttled.
+    /// The request is sent after waiting for `pacing` when given.
+    #[allow(clippy::result_large_err)]
+    fn send_page(
+        request: reqwest::blocking::RequestBuilder,
+        throttle_policy: ThrottlePolicy,
+        pacing: Option<Duration>,
+    ) -> GraphResult<reqwest::blocking::Response> {
+        if let Some(pacing) = pacing {
+            std::thread::sleep(pacing);
+        }
+
+        let mut request = request;
+        let mut retries = 0;
+        loop {
+            let retry_request = request.try_clone();
+            let response = BlockingPaging::execute(request, throttle_policy.resource_identity)?;
+            match (
+                throttle_retry_after(response.status(), response.headers()),
+                retry_request,
+            ) {
+                (Some(retry_after), Some(retry_request))
+                    if retries < throttle_policy.max_retries =>
+                {
+                    retries += 1;
+                    record_retry(throttle_policy.resource_identity.as_ref());
+                    std::thread::sleep(retry_after);
+                    request = retry_request;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+
     fn http_response<T: DeserializeOwned>(
         response: reqwest::blocking::Response,
     ) -> GraphResult<(Option<String>, PagingResponse<T>)> {
@@ -246,30 +417,34 @@ impl BlockingPaging {
     /// println!("{:#?}", response.body());
     /// ```
     pub fn json<T: DeserializeOwned>(mut self) -> GraphResult<VecDeque<PagingResponse<T>>> {
-        if let Some(err) = self.0.error {
+        if let Some(err) = self.request_handler.error {
             return Err(err);
         }
 
-        let request = self.0.default_request_builder()?;
-        let response = request.send()?;
+        let request = self.request_handler.default_request_builder()?;
+        let throttle_policy = self.throttle_policy;
+        let response = BlockingPaging::send_page(request, throttle_policy, None)?;
 
         let (next, http_response) = BlockingPaging::http_response(response)?;
         let mut next_link = next;
+        let mut pacing = throttle_policy.pacing_delay(http_response.headers());
         let mut vec = VecDeque::new();
         vec.push_back(http_response);
 
-        let client = self.0.inner.inner.clone();
-        let access_token = self.0.inner.client_application.get_token_silent()?;
+        let client = self.request_handler.inner.inner.clone();
+        let access_token = self
+            .request_handler
+            .inner
+            .client_application
+            .get_token_silent()?;
         while let Some(next) = next_link {
-            let response = client
-                .get(next)
-                .bearer_auth(access_token.as_str())
-                .send()
-                .map_err(GraphFailure::from)?;
+            let request = client.get(next).bearer_auth(access_token.as_str());
+            let response = BlockingPaging::send_page(request, throttle_policy, pacing)?;
 
             let (next, http_response) = BlockingPaging::http_response(response)?;
 
             next_link = next;
+            pacing = throttle_policy.pacing_delay(http_response.headers());
             vec.push_back(http_response);
         }
 
@@ -280,12 +455,11 @@ impl BlockingPaging {
         client: &reqwest::blocking::Client,
         next: &str,
         access_token: &str,
+        throttle_policy: ThrottlePolicy,
+        pacing: Option<Duration>,
     ) -> GraphResult<(Option<String>, PagingResponse<T>)> {
-        let response = client
-            .get(next)
-            .bearer_auth(access_token)
-            .send()
-            .map_err(GraphFailure::from)?;
+        let request = client.get(next).bearer_auth(access_token);
+        let response = BlockingPaging::send_page(request, throttle_policy, pacing)?;
 
         BlockingPaging::http_response(response)
     }
@@ -294,15 +468,21 @@ impl BlockingPaging {
         mut self,
     ) -> GraphResult<std::sync::mpsc::Receiver<Option<PagingResult<T>>>> {
         let (sender, receiver) = std::sync::mpsc::channel();
-        let request = self.0.default_request_builder()?;
-        let response = request.send()?;
+        let request = self.request_handler.default_request_builder()?;
+        let throttle_policy = self.throttle_policy;
+        let response = BlockingPaging::send_page(request, throttle_policy, None)?;
 
         let (next, http_response) = BlockingPaging::http_response(response)?;
         let mut next_link = next;
+        let mut pacing = throttle_policy.pacing_delay(http_response.headers());
         sender.send(Some(Ok(http_response))).unwrap();
 
-        let client = self.0.inner.inner.clone();
-        let access_token = self.0.inner.client_application.get_token_silent()?;
+        let client = self.request_handler.inner.inner.clone();
+        let access_token = self
+            .request_handler
+            .inner
+            .client_application
+            .get_token_silent()?;
 
         std::thread::spawn(move || {
             while let Some(next) = next_link.as_ref() {
@@ -310,9 +490,12 @@ impl BlockingPaging {
                     &client,
                     next.as_str(),
                     access_token.as_str(),
+                    throttle_policy,
+                    pacing.take(),
                 );
                 if let Ok((next_option, http_response)) = result {
                     next_link = next_option;
+                    pacing = throttle_policy.pacing_delay(http_response.headers());
                     sender.send(Some(Ok(http_response))).unwrap();
                 } else if let Err(err) = result {
                     sender.send(Some(Err(err))).unwrap();
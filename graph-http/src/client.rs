@@ -1,22 +1,74 @@
 use crate::blocking::BlockingClient;
-use graph_core::identity::{ClientApplication, ForceTokenRefresh};
-use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT};
+use crate::internal::{RequestLayer, SingleFlight};
+use graph_core::identity::{ClientApplication, ForceTokenRefresh, TokenValidation};
+use graph_error::{GraphFailure, GraphResult};
+use reqwest::header::{HeaderMap, HeaderValue, IntoHeaderName, ACCEPT, USER_AGENT};
 use reqwest::redirect::Policy;
 use reqwest::tls::Version;
 use std::env::VarError;
 use std::ffi::OsStr;
 use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 use std::time::Duration;
 
+const PREFER: &str = "prefer";
+
 fn user_agent_header_from_env() -> Option<HeaderValue> {
     let header = std::option_env!("GRAPH_CLIENT_USER_AGENT")?;
     HeaderValue::from_str(header).ok()
 }
 
+/// Merge the default headers of the client with the headers of a request. The
+/// headers of the request replace default headers with the same name except for
+/// `Prefer`, where the preferences of both are sent.
+pub(crate) fn merge_default_headers(default_headers: &HeaderMap, headers: HeaderMap) -> HeaderMap {
+    let mut merged = default_headers.clone();
+    let default_prefer: Vec<HeaderValue> =
+        default_headers.get_all(PREFER).iter().cloned().collect();
+    merged.extend(headers);
+
+    for value in default_prefer {
+        if !merged.get_all(PREFER).iter().any(|prefer| prefer == value) {
+            merged.append(PREFER, value);
+        }
+    }
+    merged
+}
+
+// Apply the timeouts, connection pool and TLS settings of the configuration to an
+// async or blocking client builder.
+macro_rules! with_connection_settings {
+    ($builder:expr, $config:expr) => {{
+        let mut builder = $builder
+            .connection_verbose($config.connection_verbose)
+            .https_only($config.https_only)
+            .min_tls_version($config.min_tls_version);
+
+        if let Some(timeout) = $config.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(connect_timeout) = $config.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+
+        if let Some(max) = $config.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+
+        if let Some(pool_idle_timeout) = $config.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        builder
+    }};
+}
+
 #[derive(Clone)]
 struct ClientConfiguration {
     client_application: Option<Box<dyn ClientApplication>>,
+    token_validation: Option<TokenValidation>,
     headers: HeaderMap,
+    application: Option<(String, String)>,
     referer: bool,
     timeout: Option<Duration>,
     connect_timeout: Option<Duration>,
@@ -25,6 +77,17 @@ struct ClientConfiguration {
     /// TLS 1.2 required to support all features in Microsoft Graph
     /// See [Reliability and Support](https://learn.microsoft.com/en-us/graph/best-practices-concept#reliability-and-support)
     min_tls_version: Version,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    http2_prior_knowledge: bool,
+    http2_adaptive_window: bool,
+    single_flight: bool,
+    validate_permissions: bool,
+    layers: Vec<Arc<dyn RequestLayer>>,
+    #[cfg(feature = "test-util")]
+    fault_injection: Option<crate::internal::FaultInjection>,
+    #[cfg(feature = "test-util")]
+    cassette: Option<crate::internal::Cassette>,
 }
 
 impl ClientConfiguration {
@@ -38,13 +101,26 @@ impl ClientConfiguration {
 
         ClientConfiguration {
             client_application: None,
+            token_validation: None,
             headers,
+            application: None,
             referer: true,
             timeout: None,
             connect_timeout: None,
             connection_verbose: false,
             https_only: true,
             min_tls_version: Version::TLS_1_2,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            http2_prior_knowledge: false,
+            http2_adaptive_window: false,
+            single_flight: false,
+            validate_permissions: false,
+            layers: Vec::new(),
+            #[cfg(feature = "test-util")]
+            fault_injection: None,
+            #[cfg(feature = "test-util")]
+            cassette: None,
         }
     }
 }
@@ -53,15 +129,44 @@ impl Debug for ClientConfiguration {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ClientConfiguration")
             .field("headers", &self.headers)
+            .field("application", &self.application)
             .field("referer", &self.referer)
             .field("timeout", &self.timeout)
             .field("connect_timeout", &self.connect_timeout)
             .field("https_only", &self.https_only)
             .field("min_tls_version", &self.min_tls_version)
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout)
+            .field("http2_prior_knowledge", &self.http2_prior_knowledge)
+            .field("http2_adaptive_window", &self.http2_adaptive_window)
+            .field("single_flight", &self.single_flight)
+            .field("validate_permissions", &self.validate_permissions)
+            .field("layers", &self.layers.len())
+            .field("token_validation", &self.token_validation)
             .finish()
     }
 }
 
+impl ClientConfiguration {
+    /// The default headers with the application added to the `User-Agent`.
+    fn default_headers(&self) -> HeaderMap {
+        let mut headers = self.headers.clone();
+        if let Some((name, version)) = self.application.as_ref() {
+            let user_agent = match headers
+                .get(USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+            {
+                Some(user_agent) => format!("{name}/{version} {user_agent}"),
+                None => format!("{name}/{version}"),
+            };
+            if let Ok(user_agent) = HeaderValue::from_str(&user_agent) {
+                headers.insert(USER_AGENT, user_agent);
+            }
+        }
+        headers
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct GraphClientConfiguration {
     config: ClientConfiguration,
@@ -84,6 +189,8 @@ impl GraphClientConfiguration {
         self
     }
 
+    /// Headers sent with every request. Headers set on a request replace these
+    /// except for `Prefer`, where the preferences of both are sent.
     pub fn default_headers(mut self, headers: HeaderMap) -> GraphClientConfiguration {
         for (key, value) in headers.iter() {
             self.config.headers.insert(key, value.clone());
@@ -91,6 +198,40 @@ impl GraphClientConfiguration {
         self
     }
 
+    /// Add a header sent with every request, such as `Prefer: IdType="ImmutableId"`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use graph_http::api_impl::GraphClientConfiguration;
+    /// use reqwest::header::HeaderValue;
+    ///
+    /// let client = GraphClientConfiguration::new()
+    ///     .access_token("ACCESS_TOKEN")
+    ///     .default_header("Prefer", HeaderValue::from_static("IdType=\"ImmutableId\""))
+    ///     .application("contoso-sync", "2.1.0")
+    ///     .build();
+    /// ```
+    pub fn default_header<K: IntoHeaderName>(
+        mut self,
+        key: K,
+        value: HeaderValue,
+    ) -> GraphClientConfiguration {
+        self.config.headers.insert(key, value);
+        self
+    }
+
+    /// The name and version of the application added to the start of the `User-Agent`
+    /// header, such as `contoso-sync/2.1.0 graph-rs-sdk/1.1.1`. Both must only contain
+    /// characters allowed in header values.
+    pub fn application(
+        mut self,
+        name: impl ToString,
+        version: impl ToString,
+    ) -> GraphClientConfiguration {
+        self.config.application = Some((name.to_string(), version.to_string()));
+        self
+    }
+
     /// Enable or disable automatic setting of the `Referer` header.
     ///
     /// Default is `true`.
@@ -146,78 +287,200 @@ impl GraphClientConfiguration {
         self
     }
 
+    /// Sets the maximum idle connection per host allowed in the pool.
+    ///
+    /// Default is no limit.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> GraphClientConfiguration {
+        self.config.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Set an optional timeout for idle sockets being kept-alive.
+    ///
+    /// Default is 90 seconds.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> GraphClientConfiguration {
+        self.config.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Only use HTTP/2 and skip negotiating the protocol version.
+    ///
+    /// Default is `false`.
+    pub fn http2_prior_knowledge(mut self, enable: bool) -> GraphClientConfiguration {
+        self.config.http2_prior_knowledge = enable;
+        self
+    }
+
+    /// Sets whether to use an adaptive flow control for HTTP/2 connections.
+    ///
+    /// Default is `false`.
+    pub fn http2_adaptive_window(mut self, enable: bool) -> GraphClientConfiguration {
+        self.config.http2_adaptive_window = enable;
+        self
+    }
+
+    /// Share a single in-flight request between concurrent GET requests that have the
+    /// same url and headers. Each caller receives a copy of the response, which reduces
+    /// quota usage when many tasks request the same resource, such as `/me`, at once.
+    ///
+    /// Response bodies of shared requests are buffered in memory. This only applies
+    /// to async requests.
+    ///
+    /// Default is `false`.
+    pub fn single_flight(mut self, enable: bool) -> GraphClientConfiguration {
+        self.config.single_flight = enable;
+        self
+    }
+
+    /// Validate the audience and tenant of each new access token before it is used,
+    /// returning an error when the token is for a different cloud, resource or tenant.
+    /// See [`TokenValidation`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let client = GraphClientConfiguration::new()
+    ///     .client_application(confidential_client)
+    ///     .validate_token(TokenValidation::new("https://graph.microsoft.us").tenant_id(TENANT_ID))
+    ///     .build();
+    /// ```
+    pub fn validate_token(mut self, token_validation: TokenValidation) -> GraphClientConfiguration {
+        self.config.token_validation = Some(token_validation);
+        self
+    }
+
+    /// Log a warning before a request is sent when the access token has none of the
+    /// delegated (`scp`) or application (`roles`) permissions that allow the request,
+    /// such as a `POST` to messages with a token that only has `Mail.Read`. Use during
+    /// development to find missing permissions before Microsoft Graph returns
+    /// 403 Forbidden. Requests are still sent.
+    ///
+    /// Permissions are only known for some resources and actions.
+    /// See [ResourceIdentity::required_permissions](graph_core::resource::ResourceIdentity::required_permissions).
+    ///
+    /// Default is `false`.
+    pub fn validate_permissions(mut self, enable: bool) -> GraphClientConfiguration {
+        self.config.validate_permissions = enable;
+        self
+    }
+
+    /// Add a layer to the pipeline that requests pass through. Layers run in the
+    /// order they are added, so a retry layer added before a cache layer retries
+    /// requests that are not found in the cache, and a telemetry layer added after
+    /// a retry layer records each retry. See [RequestLayer](crate::api_impl::RequestLayer).
+    pub fn with_layer<L: RequestLayer + 'static>(mut self, layer: L) -> GraphClientConfiguration {
+        self.config.layers.push(Arc::new(layer));
+        self
+    }
+
+    /// Inject latency and failures into requests sent with
+    /// [RequestHandler::send](crate::api_impl::RequestHandler::send).
+    /// See [FaultInjection](crate::api_impl::FaultInjection).
+    #[cfg(feature = "test-util")]
+    pub fn fault_injection(
+        mut self,
+        fault_injection: crate::internal::FaultInjection,
+    ) -> GraphClientConfiguration {
+        self.config.fault_injection = Some(fault_injection);
+        self
+    }
+
+    /// Record requests sent with [RequestHandler::send](crate::api_impl::RequestHandler::send)
+    /// to the cassette or replay the recorded responses.
+    /// See [Cassette](crate::api_impl::Cassette).
+    #[cfg(feature = "test-util")]
+    pub fn cassette(mut self, cassette: crate::internal::Cassette) -> GraphClientConfiguration {
+        self.config.cassette = Some(cassette);
+        self
+    }
+
     #[cfg(feature = "test-util")]
     pub fn https_only(mut self, https_only: bool) -> GraphClientConfiguration {
         self.config.https_only = https_only;
         self
     }
 
+    // The client used by the client application for token requests when the client
+    // application does not already have a client. Token requests share the timeouts,
+    // connection pool and TLS settings of the Graph client but not the Graph default
+    // headers, http2 prior knowledge or redirect policy.
+    fn token_client(&self) -> reqwest::Client {
+        with_connection_settings!(reqwest::ClientBuilder::new(), self.config)
+            .build()
+            .unwrap()
+    }
+
+    fn blocking_token_client(&self) -> reqwest::blocking::Client {
+        with_connection_settings!(reqwest::blocking::ClientBuilder::new(), self.config)
+            .build()
+            .unwrap()
+    }
+
     pub fn build(self) -> Client {
         let config = self.clone();
-        let headers = self.config.headers.clone();
-        let mut builder = reqwest::ClientBuilder::new()
+        let headers = self.config.default_headers();
+        let mut builder = with_connection_settings!(reqwest::ClientBuilder::new(), self.config)
             .referer(self.config.referer)
-            .connection_verbose(self.config.connection_verbose)
-            .https_only(self.config.https_only)
-            .min_tls_version(self.config.min_tls_version)
             .redirect(Policy::limited(2))
-            .default_headers(self.config.headers);
+            .default_headers(headers.clone());
 
-        if let Some(timeout) = self.config.timeout {
-            builder = builder.timeout(timeout);
+        if self.config.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
         }
 
-        if let Some(connect_timeout) = self.config.connect_timeout {
-            builder = builder.connect_timeout(connect_timeout);
+        builder = builder.http2_adaptive_window(self.config.http2_adaptive_window);
+        let inner = builder.build().unwrap();
+
+        let mut client_application = self
+            .config
+            .client_application
+            .unwrap_or_else(|| Box::<String>::default());
+        if let Some(token_validation) = self.config.token_validation {
+            client_application = token_validation.client_application(client_application);
+        }
+        if !client_application.has_http_client() {
+            client_application.with_http_client(config.token_client());
         }
 
-        if let Some(client_application) = self.config.client_application {
-            Client {
-                client_application,
-                inner: builder.build().unwrap(),
-                headers,
-                builder: config,
-            }
-        } else {
-            Client {
-                client_application: Box::<String>::default(),
-                inner: builder.build().unwrap(),
-                headers,
-                builder: config,
-            }
+        Client {
+            client_application,
+            inner,
+            headers,
+            builder: config,
+            single_flight: self.config.single_flight.then(SingleFlight::default),
         }
     }
 
-    pub(crate) fn build_blocking(self) -> BlockingClient {
-        let headers = self.config.headers.clone();
-        let mut builder = reqwest::blocking::ClientBuilder::new()
-            .referer(self.config.referer)
-            .connection_verbose(self.config.connection_verbose)
-            .https_only(self.config.https_only)
-            .min_tls_version(self.config.min_tls_version)
-            .redirect(Policy::limited(2))
-            .default_headers(self.config.headers);
+    pub(crate) fn build_blocking(mut self) -> BlockingClient {
+        let headers = self.config.default_headers();
+        let mut builder =
+            with_connection_settings!(reqwest::blocking::ClientBuilder::new(), self.config)
+                .referer(self.config.referer)
+                .redirect(Policy::limited(2))
+                .default_headers(headers.clone());
 
-        if let Some(timeout) = self.config.timeout {
-            builder = builder.timeout(timeout);
+        if self.config.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
         }
 
-        if let Some(connect_timeout) = self.config.connect_timeout {
-            builder = builder.connect_timeout(connect_timeout);
+        builder = builder.http2_adaptive_window(self.config.http2_adaptive_window);
+
+        let mut client_application = self
+            .config
+            .client_application
+            .take()
+            .unwrap_or_else(|| Box::<String>::default());
+        if let Some(token_validation) = self.config.token_validation.take() {
+            client_application = token_validation.client_application(client_application);
+        }
+        if !client_application.has_blocking_http_client() {
+            client_application.with_blocking_http_client(self.blocking_token_client());
         }
 
-        if let Some(client_application) = self.config.client_application {
-            BlockingClient {
-                client_application,
-                inner: builder.build().unwrap(),
-                headers,
-            }
-        } else {
-            BlockingClient {
-                client_application: Box::<String>::default(),
-                inner: builder.build().unwrap(),
-                headers,
-            }
+        BlockingClient {
+            client_application,
+            inner: builder.build().unwrap(),
+            headers,
+            validate_permissions: self.config.validate_permissions,
         }
     }
 }
@@ -234,6 +497,7 @@ pub struct Client {
     pub(crate) inner: reqwest::Client,
     pub(crate) headers: HeaderMap,
     pub(crate) builder: GraphClientConfiguration,
+    pub(crate) single_flight: Option<SingleFlight>,
 }
 
 impl Client {
@@ -265,10 +529,65 @@ impl Client {
         &self.headers
     }
 
+    pub(crate) fn layers(&self) -> &[Arc<dyn RequestLayer>] {
+        self.builder.config.layers.as_slice()
+    }
+
+    pub(crate) fn validate_permissions(&self) -> bool {
+        self.builder.config.validate_permissions
+    }
+
+    /// Send the request after it has passed through the layers of the client.
+    pub(crate) async fn send_request(
+        &self,
+        request: reqwest::Request,
+    ) -> GraphResult<reqwest::Response> {
+        let request_builder = reqwest::RequestBuilder::from_parts(self.inner.clone(), request);
+        #[cfg(feature = "test-util")]
+        if self.fault_injection().is_some() || self.cassette().is_some() {
+            return self
+                .fault_injection()
+                .cloned()
+                .unwrap_or_default()
+                .send(request_builder, self.cassette())
+                .await;
+        }
+        match self.single_flight.as_ref() {
+            Some(single_flight) => single_flight.send(&self.inner, request_builder).await,
+            None => request_builder.send().await.map_err(GraphFailure::from),
+        }
+    }
+
+    #[cfg(feature = "test-util")]
+    pub(crate) fn fault_injection(&self) -> Option<&crate::internal::FaultInjection> {
+        self.builder.config.fault_injection.as_ref()
+    }
+
+    #[cfg(feature = "test-util")]
+    pub(crate) fn cassette(&self) -> Option<&crate::internal::Cassette> {
+        self.builder.config.cassette.as_ref()
+    }
+
     pub fn with_force_token_refresh(&mut self, force_token_refresh: ForceTokenRefresh) {
         self.client_application
             .with_force_token_refresh(force_token_refresh);
     }
+
+    /// Returns a copy of the client that requests tokens from the given tenant.
+    /// The http client and configuration are shared with this client.
+    ///
+    /// Returns None if the client application does not support changing the
+    /// tenant, such as when the client was created using an access token.
+    pub fn for_tenant(&self, tenant_id: impl AsRef<str>) -> Option<Client> {
+        let client_application = self.client_application.for_tenant(tenant_id.as_ref())?;
+        Some(Client {
+            client_application,
+            inner: self.inner.clone(),
+            headers: self.headers.clone(),
+            builder: self.builder.clone(),
+            single_flight: self.single_flight.clone(),
+        })
+    }
 }
 
 impl Default for Client {
@@ -317,4 +636,114 @@ mod test {
         let user_agent_header = client.builder.config.headers.get(USER_AGENT).unwrap();
         assert_eq!("user_agent", user_agent_header.to_str().unwrap());
     }
+
+    #[test]
+    fn application_user_agent_and_default_headers() {
+        let client = GraphClientConfiguration::new()
+            .access_token("access_token")
+            .user_agent(HeaderValue::from_static("graph-rs-sdk/1.1.1"))
+            .application("contoso-sync", "2.1.0")
+            .default_header(PREFER, HeaderValue::from_static("IdType=\"ImmutableId\""))
+            .build();
+        assert_eq!(
+            "contoso-sync/2.1.0 graph-rs-sdk/1.1.1",
+            client.headers().get(USER_AGENT).unwrap()
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert(PREFER, HeaderValue::from_static("odata.maxpagesize=10"));
+        let headers = merge_default_headers(client.headers(), headers);
+        assert_eq!(
+            vec!["odata.maxpagesize=10", "IdType=\"ImmutableId\""],
+            headers.get_all(PREFER).iter().collect::<Vec<_>>()
+        );
+        assert!(headers.contains_key(USER_AGENT));
+    }
+
+    #[test]
+    fn connection_pool_configuration() {
+        let client = GraphClientConfiguration::new()
+            .access_token("access_token")
+            .pool_max_idle_per_host(4)
+            .pool_idle_timeout(Duration::from_secs(30))
+            .http2_adaptive_window(true)
+            .build();
+
+        assert_eq!(Some(4), client.builder.config.pool_max_idle_per_host);
+        assert_eq!(
+            Some(Duration::from_secs(30)),
+            client.builder.config.pool_idle_timeout
+        );
+        assert!(client.builder.config.http2_adaptive_window);
+        assert!(!client.builder.config.http2_prior_knowledge);
+    }
+
+    #[test]
+    fn single_flight_configuration() {
+        let client = GraphClientConfiguration::new()
+            .access_token("access_token")
+            .build();
+        assert!(client.single_flight.is_none());
+
+        let client = GraphClientConfiguration::new()
+            .access_token("access_token")
+            .single_flight(true)
+            .build();
+        assert!(client.single_flight.is_some());
+    }
+
+    #[test]
+    fn access_token_client_for_tenant() {
+        let client = GraphClientConfiguration::new()
+            .access_token("access_token")
+            .build();
+
+        assert!(client.for_tenant("contoso.onmicrosoft.com").is_none());
+    }
+
+    #[derive(Clone)]
+    struct HttpClientApplication {
+        has_http_client: bool,
+        replaced: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    #[async_trait::async_trait]
+    impl ClientApplication for HttpClientApplication {
+        fn get_token_silent(&mut self) -> graph_error::AuthExecutionResult<String> {
+            Ok("access_token".into())
+        }
+
+        async fn get_token_silent_async(&mut self) -> graph_error::AuthExecutionResult<String> {
+            Ok("access_token".into())
+        }
+
+        fn with_force_token_refresh(&mut self, _force_token_refresh: ForceTokenRefresh) {}
+
+        fn with_http_client(&mut self, _http_client: reqwest::Client) {
+            self.replaced
+                .store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+
+        fn has_http_client(&self) -> bool {
+            self.has_http_client
+        }
+    }
+
+    #[test]
+    fn keep_http_client_of_client_application() {
+        for has_http_client in [true, false] {
+            let client_application = HttpClientApplication {
+                has_http_client,
+                replaced: Default::default(),
+            };
+            let replaced = client_application.replaced.clone();
+            GraphClientConfiguration::new()
+                .client_application(client_application)
+                .build();
+            assert_eq!(
+                !has_http_client,
+                replaced.load(std::sync::atomic::Ordering::SeqCst)
+            );
+        }
+    }
 }
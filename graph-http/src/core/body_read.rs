@@ -6,6 +6,9 @@ use bytes::{Buf, BytesMut};
 use graph_error::{GraphFailure, GraphResult};
 use reqwest::Body;
 use std::io::{BufReader, Read};
+use tokio::io::AsyncReadExt;
+
+const READ_STREAM_CHUNK_SIZE: usize = 64 * 1024;
 
 pub struct BodyRead {
     buf: String,
@@ -40,6 +43,48 @@ impl BodyRead {
         reader.read_to_string(&mut buf).await?;
         Ok(BodyRead::new(buf))
     }
+
+    /// Stream the bytes of the reader as the body of an async request without reading
+    /// them into memory first. Unlike [BodyRead::from_async_read], the content does not
+    /// need to be UTF-8, such as for images.
+    pub fn from_async_read_stream<T: tokio::io::AsyncRead + Send + Sync + Unpin + 'static>(
+        reader: T,
+    ) -> BodyRead {
+        BodyRead::from(Body::wrap_stream(read_stream(reader)))
+    }
+
+    /// A copy of the body, or `None` when the body is a stream that can only be read once.
+    pub fn try_clone(&self) -> Option<BodyRead> {
+        let blocking_body = match self.blocking_body.as_ref() {
+            Some(body) => Some(reqwest::blocking::Body::from(body.as_bytes()?.to_vec())),
+            None => None,
+        };
+        let async_body = match self.async_body.as_ref() {
+            Some(body) => Some(Body::from(body.as_bytes()?.to_vec())),
+            None => None,
+        };
+
+        Some(BodyRead {
+            buf: self.buf.clone(),
+            blocking_body,
+            async_body,
+        })
+    }
+}
+
+/// The chunks of the reader, ending after the first error.
+fn read_stream<T: tokio::io::AsyncRead + Send + Sync + Unpin + 'static>(
+    mut reader: T,
+) -> impl futures::Stream<Item = std::io::Result<bytes::Bytes>> + Send + Sync + 'static {
+    async_stream::try_stream! {
+        loop {
+            let mut buf = BytesMut::with_capacity(READ_STREAM_CHUNK_SIZE);
+            if reader.read_buf(&mut buf).await? == 0 {
+                break;
+            }
+            yield buf.freeze();
+        }
+    }
 }
 
 impl From<BodyRead> for Body {
@@ -153,3 +198,23 @@ impl BodyExt for BodyRead {
         Ok(self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn read_stream_binary() {
+        let content: Vec<u8> = (0..READ_STREAM_CHUNK_SIZE * 2 + 10)
+            .map(|i| (i % 256) as u8)
+            .collect();
+        let chunks: Vec<bytes::Bytes> = read_stream(std::io::Cursor::new(content.clone()))
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+
+        assert!(chunks.len() >= 3);
+        assert_eq!(content, chunks.concat());
+    }
+}
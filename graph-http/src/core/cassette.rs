@@ -0,0 +1,466 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use graph_error::{GraphFailure, GraphResult};
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use url::Url;
+
+use crate::internal::CLIENT_REQUEST_ID;
+
+/// The environment variable that makes [Cassette::from_env] record requests.
+pub const GRAPH_CASSETTE_RECORD: &str = "GRAPH_CASSETTE_RECORD";
+
+/// Headers removed from recorded requests and responses.
+const SENSITIVE_HEADERS: [&str; 5] = [
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "www-authenticate",
+];
+
+/// Query parameters of urls, such as the `tempauth` of a pre-authenticated
+/// `@microsoft.graph.downloadUrl`, and fields of JSON bodies whose values are
+/// replaced with `REDACTED` when recorded.
+const SENSITIVE_VALUES: [&str; 7] = [
+    "tempauth",
+    "sig",
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "client_secret",
+    "password",
+];
+
+const REDACTED: &str = "REDACTED";
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CassetteMode {
+    /// Send requests and write each request and response to the cassette.
+    Record,
+    /// Return the recorded responses without sending requests.
+    Replay,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    #[serde(default, deserialize_with = "deserialize_headers")]
+    pub headers: BTreeMap<String, Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default, deserialize_with = "deserialize_headers")]
+    pub headers: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub body: String,
+}
+
+/// A request and the response to it stored in a [Cassette].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Default)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    replayed: Vec<bool>,
+}
+
+/// Record requests and responses to a JSON file and replay them without sending
+/// requests, so that tests can run without credentials for a tenant. Requires
+/// the `test-util` feature.
+///
+/// The `Authorization`, cookie, and `client-request-id` headers are not recorded.
+/// Bodies are stored as text. Tokens in urls and JSON bodies, such as the `tempauth`
+/// of download urls and `access_token` fields, are replaced with `REDACTED`.
+///
+/// A recorded response is replayed for a request with the same method and url,
+/// compared after redacting the url.
+/// Recorded responses are replayed in the order they were recorded and each
+/// response is only replayed once. Requests that have no recorded response
+/// fail with a [GraphFailure].
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::http::Cassette;
+///
+/// // Records when the GRAPH_CASSETTE_RECORD environment variable is set
+/// // and replays otherwise.
+/// let cassette = Cassette::from_env("tests/cassettes/list_users.json")?;
+/// let access_token = std::env::var("ACCESS_TOKEN").unwrap_or_default();
+///
+/// let client = GraphClient::from(
+///     GraphClientConfiguration::new()
+///         .access_token(access_token)
+///         .cassette(cassette),
+/// );
+///
+/// let response = client.users().list_user().send().await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Arc<Mutex<CassetteState>>,
+}
+
+impl Cassette {
+    /// Record requests to the file, replacing any recorded requests.
+    pub fn record(path: impl AsRef<Path>) -> Cassette {
+        Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::Record,
+            state: Default::default(),
+        }
+    }
+
+    /// Replay the requests recorded in the file.
+    #[allow(clippy::result_large_err)]
+    pub fn replay(path: impl AsRef<Path>) -> GraphResult<Cassette> {
+        let interactions: Vec<Interaction> =
+            serde_json::from_slice(&std::fs::read(path.as_ref())?)?;
+        Ok(Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::Replay,
+            state: Arc::new(Mutex::new(CassetteState {
+                replayed: vec![false; interactions.len()],
+                interactions,
+            })),
+        })
+    }
+
+    /// Record when the [GRAPH_CASSETTE_RECORD] environment variable is set
+    /// and replay otherwise.
+    #[allow(clippy::result_large_err)]
+    pub fn from_env(path: impl AsRef<Path>) -> GraphResult<Cassette> {
+        if std::env::var_os(GRAPH_CASSETTE_RECORD).is_some() {
+            Ok(Cassette::record(path))
+        } else {
+            Cassette::replay(path)
+        }
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    /// The recorded requests and responses.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state
+            .lock()
+            .map(|state| state.interactions.clone())
+            .unwrap_or_default()
+    }
+
+    pub(crate) async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> GraphResult<reqwest::Response> {
+        let (client, request) = request.build_split();
+        let request = request?;
+        match self.mode {
+            CassetteMode::Record => self.record_response(client, request).await,
+            CassetteMode::Replay => self.replay_response(&request),
+        }
+    }
+
+    async fn record_response(
+        &self,
+        client: reqwest::Client,
+        request: reqwest::Request,
+    ) -> GraphResult<reqwest::Response> {
+        let recorded_request = RecordedRequest {
+            method: request.method().to_string(),
+            url: redact_url(request.url().as_str()),
+            headers: recorded_headers(request.headers()),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(redact_body),
+        };
+
+        let response = client.execute(request).await?;
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        let interaction = Interaction {
+            request: recorded_request,
+            response: RecordedResponse {
+                status: status.as_u16(),
+                headers: recorded_headers(&headers),
+                body: redact_body(&body),
+            },
+        };
+        self.write(interaction)?;
+
+        let mut builder = http::Response::builder().status(status).version(version);
+        if let Some(response_headers) = builder.headers_mut() {
+            response_headers.extend(headers);
+        }
+        Ok(reqwest::Response::from(builder.body(body)?))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn write(&self, interaction: Interaction) -> GraphResult<()> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| GraphFailure::invalid("cassette"))?;
+        state.interactions.push(interaction);
+        state.replayed.push(true);
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_vec_pretty(&state.interactions)?)?;
+        Ok(())
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn replay_response(&self, request: &reqwest::Request) -> GraphResult<reqwest::Response> {
+        let method = request.method().as_str();
+        let url = redact_url(request.url().as_str());
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| GraphFailure::invalid("cassette"))?;
+        let CassetteState {
+            interactions,
+            replayed,
+        } = &mut *state;
+
+        let (interaction, replayed) = interactions
+            .iter()
+            .zip(replayed.iter_mut())
+            .find(|(interaction, replayed)| {
+                !**replayed
+                    && interaction.request.method == method
+                    && interaction.request.url == url
+            })
+            .ok_or_else(|| {
+                GraphFailure::not_found(&format!(
+                    "no recorded response for {method} {url} in {}",
+                    self.path.display()
+                ))
+            })?;
+        *replayed = true;
+
+        let mut builder = http::Response::builder().status(interaction.response.status);
+        for (name, values) in interaction.response.headers.iter() {
+            for value in values {
+                builder = builder.header(name.as_str(), value.as_str());
+            }
+        }
+        Ok(reqwest::Response::from(
+            builder.body(interaction.response.body.clone())?,
+        ))
+    }
+}
+
+// Every value of each header except sensitive headers, with tokens in urls such as
+// the Location header redacted.
+fn recorded_headers(headers: &HeaderMap) -> BTreeMap<String, Vec<String>> {
+    let mut recorded: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (name, value) in headers.iter() {
+        if SENSITIVE_HEADERS.contains(&name.as_str()) || name.as_str() == CLIENT_REQUEST_ID {
+            continue;
+        }
+        if let Ok(value) = value.to_str() {
+            recorded
+                .entry(name.to_string())
+                .or_default()
+                .push(redact_url(value));
+        }
+    }
+    recorded
+}
+
+// Headers recorded before multiple values were kept have a single string value.
+fn deserialize_headers<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<String, Vec<String>>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum HeaderValues {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    let headers: BTreeMap<String, HeaderValues> = BTreeMap::deserialize(deserializer)?;
+    Ok(headers
+        .into_iter()
+        .map(|(name, values)| match values {
+            HeaderValues::One(value) => (name, vec![value]),
+            HeaderValues::Many(values) => (name, values),
+        })
+        .collect())
+}
+
+// The url with the values of sensitive query parameters redacted. Values that are
+// not urls are returned unchanged.
+fn redact_url(value: &str) -> String {
+    let Ok(mut url) = Url::parse(value) else {
+        return value.to_owned();
+    };
+    if !url
+        .query_pairs()
+        .any(|(key, _)| SENSITIVE_VALUES.contains(&key.as_ref()))
+    {
+        return value.to_owned();
+    }
+
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = if SENSITIVE_VALUES.contains(&key.as_ref()) {
+                REDACTED.to_owned()
+            } else {
+                value.into_owned()
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    url.to_string()
+}
+
+// The body as text with sensitive fields and tokens in urls of JSON bodies redacted.
+fn redact_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut value) if value.is_object() || value.is_array() => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        _ => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+fn redact_value(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SENSITIVE_VALUES.contains(&key.as_str()) && value.is_string() {
+                    *value = serde_json::Value::String(REDACTED.to_owned());
+                } else {
+                    redact_value(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_value),
+        serde_json::Value::String(text) => *text = redact_url(text),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::AUTHORIZATION;
+
+    #[tokio::test]
+    async fn replay_recorded_responses() {
+        let path = std::env::temp_dir().join(format!("cassette-{}.json", uuid::Uuid::new_v4()));
+        let interaction = Interaction {
+            request: RecordedRequest {
+                method: "GET".into(),
+                url: "https://graph.microsoft.com/v1.0/me".into(),
+                headers: Default::default(),
+                body: None,
+            },
+            response: RecordedResponse {
+                status: 200,
+                headers: BTreeMap::from([("content-type".into(), vec!["application/json".into()])]),
+                body: r#"{"id":"1"}"#.into(),
+            },
+        };
+        std::fs::write(&path, serde_json::to_vec(&vec![interaction]).unwrap()).unwrap();
+
+        let cassette = Cassette::replay(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let client = reqwest::Client::new();
+        let request = || {
+            client
+                .get("https://graph.microsoft.com/v1.0/me")
+                .bearer_auth("token")
+        };
+
+        let response = cassette.send(request()).await.unwrap();
+        assert_eq!(200, response.status().as_u16());
+        assert_eq!(
+            serde_json::json!({"id": "1"}),
+            response.json::<serde_json::Value>().await.unwrap()
+        );
+        assert!(cassette.send(request()).await.is_err());
+    }
+
+    #[test]
+    fn sensitive_headers_are_not_recorded() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, "Bearer token".parse().unwrap());
+        headers.insert(CLIENT_REQUEST_ID, "id".parse().unwrap());
+        headers.insert("prefer", "odata.maxpagesize=10".parse().unwrap());
+        headers.append("prefer", "IdType=\"ImmutableId\"".parse().unwrap());
+        assert_eq!(
+            BTreeMap::from([(
+                "prefer".to_owned(),
+                vec![
+                    "odata.maxpagesize=10".to_owned(),
+                    "IdType=\"ImmutableId\"".to_owned()
+                ]
+            )]),
+            recorded_headers(&headers)
+        );
+
+        let response: RecordedResponse = serde_json::from_value(serde_json::json!({
+            "status": 200,
+            "headers": { "content-type": "application/json", "vary": ["a", "b"] },
+            "body": ""
+        }))
+        .unwrap();
+        assert_eq!(vec!["application/json"], response.headers["content-type"]);
+        assert_eq!(vec!["a", "b"], response.headers["vary"]);
+    }
+
+    #[test]
+    fn tokens_are_not_recorded() {
+        let body = serde_json::json!({
+            "access_token": "eyJ0",
+            "value": [{
+                "name": "report.pdf",
+                "@microsoft.graph.downloadUrl": "https://contoso.sharepoint.com/download.aspx?UniqueId=1&tempauth=eyJ0"
+            }]
+        });
+        assert_eq!(
+            serde_json::json!({
+                "access_token": "REDACTED",
+                "value": [{
+                    "name": "report.pdf",
+                    "@microsoft.graph.downloadUrl": "https://contoso.sharepoint.com/download.aspx?UniqueId=1&tempauth=REDACTED"
+                }]
+            }),
+            serde_json::from_str::<serde_json::Value>(&redact_body(body.to_string().as_bytes()))
+                .unwrap()
+        );
+        assert_eq!("not json", redact_body(b"not json"));
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/me?$select=id",
+            redact_url("https://graph.microsoft.com/v1.0/me?$select=id")
+        );
+    }
+}
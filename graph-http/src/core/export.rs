@@ -0,0 +1,124 @@
+/// Number of pages that are fetched and encoded ahead of the page being written.
+pub(crate) const EXPORT_CONCURRENCY: usize = 4;
+
+/// Output format for [`Paging::export`](crate::api_impl::Paging::export).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExportFormat {
+    /// One JSON object per line.
+    JsonLines,
+    /// Comma separated values with a header row of the field names.
+    ///
+    /// Fields use dot notation for nested properties, such as `manager.displayName`.
+    /// Strings are written as is, objects and arrays are written as JSON and
+    /// missing or null fields are empty.
+    Csv { fields: Vec<String> },
+}
+
+impl ExportFormat {
+    pub fn json_lines() -> ExportFormat {
+        ExportFormat::JsonLines
+    }
+
+    pub fn csv<T: ToString, I: IntoIterator<Item = T>>(fields: I) -> ExportFormat {
+        ExportFormat::Csv {
+            fields: fields.into_iter().map(|field| field.to_string()).collect(),
+        }
+    }
+
+    /// Bytes written before the first row.
+    pub(crate) fn header(&self) -> Vec<u8> {
+        match self {
+            ExportFormat::JsonLines => Vec::new(),
+            ExportFormat::Csv { fields } => {
+                let row: Vec<String> = fields.iter().map(|field| csv_escape(field)).collect();
+                format!("{}\r\n", row.join(",")).into_bytes()
+            }
+        }
+    }
+
+    /// Encode the items of the `value` array of a page. Returns the bytes
+    /// and the number of rows.
+    pub(crate) fn encode_page(
+        &self,
+        page: &serde_json::Value,
+    ) -> serde_json::Result<(Vec<u8>, u64)> {
+        let items = match page["value"].as_array() {
+            Some(items) => items,
+            None => return Ok((Vec::new(), 0)),
+        };
+
+        let mut buf = Vec::new();
+        for item in items {
+            match self {
+                ExportFormat::JsonLines => {
+                    serde_json::to_writer(&mut buf, item)?;
+                    buf.push(b'\n');
+                }
+                ExportFormat::Csv { fields } => {
+                    let row: Vec<String> = fields
+                        .iter()
+                        .map(|field| csv_escape(&csv_value(item, field)))
+                        .collect();
+                    buf.extend_from_slice(row.join(",").as_bytes());
+                    buf.extend_from_slice(b"\r\n");
+                }
+            }
+        }
+
+        Ok((buf, items.len() as u64))
+    }
+}
+
+fn csv_value(item: &serde_json::Value, field: &str) -> String {
+    let value = field.split('.').try_fold(item, |value, key| value.get(key));
+
+    match value {
+        None | Some(serde_json::Value::Null) => String::new(),
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_page_csv_and_json_lines() {
+        let page = serde_json::json!({
+            "value": [
+                { "id": "1", "displayName": "Smith, \"Jo\"", "manager": { "id": "2" } },
+                { "id": "2", "displayName": null, "businessPhones": ["1", "2"] }
+            ]
+        });
+
+        let format = ExportFormat::csv(["id", "displayName", "manager.id", "businessPhones"]);
+        assert_eq!(
+            b"id,displayName,manager.id,businessPhones\r\n".to_vec(),
+            format.header()
+        );
+        let (bytes, rows) = format.encode_page(&page).unwrap();
+        assert_eq!(2, rows);
+        assert_eq!(
+            "1,\"Smith, \"\"Jo\"\"\",2,\r\n2,,,\"[\"\"1\"\",\"\"2\"\"]\"\r\n",
+            String::from_utf8(bytes).unwrap()
+        );
+
+        let (bytes, rows) = ExportFormat::json_lines().encode_page(&page).unwrap();
+        assert_eq!(2, rows);
+        let lines: Vec<serde_json::Value> = String::from_utf8(bytes)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(page["value"], serde_json::json!(lines));
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::StreamExt;
+use graph_error::{GraphFailure, GraphResult};
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE, RETRY_AFTER};
+
+use crate::internal::Cassette;
+
+/// A failure injected into a request by [FaultInjection].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Fault {
+    /// Wait before sending the request.
+    Latency(Duration),
+    /// Return a `429 Too Many Requests` response with the `Retry-After` header
+    /// instead of sending the request.
+    Throttle { retry_after: Duration },
+    /// Return a `503 Service Unavailable` response with the `Retry-After` header
+    /// instead of sending the request.
+    ServiceUnavailable { retry_after: Duration },
+    /// Return an [io::ErrorKind::ConnectionReset] error, as [GraphFailure::Io],
+    /// instead of sending the request.
+    ConnectionReset,
+    /// Send the request and end the response body with an [io::ErrorKind::UnexpectedEof]
+    /// error after the given number of bytes.
+    TruncateBody(usize),
+}
+
+/// Inject latency and failures into requests to test how retries and backoff
+/// handle the failures returned by Graph. Requires the `test-util` feature.
+///
+/// Faults are applied in order, one for each request sent with
+/// [RequestHandler::send](crate::api_impl::RequestHandler::send). Requests are sent
+/// as usual once all faults are applied. Clones share the same faults.
+///
+/// # Example
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use graph_rs_sdk::http::{Fault, FaultInjection};
+///
+/// let fault_injection = FaultInjection::new()
+///     .latency(Duration::from_millis(200))
+///     .repeat(Fault::Throttle { retry_after: Duration::from_secs(1) }, 2)
+///     .fault(Fault::ConnectionReset)
+///     .fault(Fault::TruncateBody(100));
+///
+/// let client = GraphClient::from(
+///     GraphClientConfiguration::new()
+///         .access_token("ACCESS_TOKEN")
+///         .fault_injection(fault_injection.clone()),
+/// );
+///
+/// let response = client.users().list_user().send().await;
+/// assert_eq!(3, fault_injection.remaining());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FaultInjection {
+    latency: Option<Duration>,
+    faults: Arc<Mutex<VecDeque<Fault>>>,
+}
+
+impl FaultInjection {
+    pub fn new() -> FaultInjection {
+        FaultInjection::default()
+    }
+
+    /// Wait before sending every request.
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    /// Apply the fault to a request after the faults already added.
+    pub fn fault(self, fault: Fault) -> Self {
+        self.repeat(fault, 1)
+    }
+
+    /// Apply the fault to the given number of requests after the faults already added.
+    pub fn repeat(self, fault: Fault, count: usize) -> Self {
+        if let Ok(mut faults) = self.faults.lock() {
+            faults.extend(std::iter::repeat_n(fault, count));
+        }
+        self
+    }
+
+    /// The number of faults that have not been applied.
+    pub fn remaining(&self) -> usize {
+        self.faults
+            .lock()
+            .map(|faults| faults.len())
+            .unwrap_or_default()
+    }
+
+    fn next_fault(&self) -> Option<Fault> {
+        self.faults.lock().ok()?.pop_front()
+    }
+
+    /// Apply the next fault to the request. Requests that are sent are sent using
+    /// the cassette when given.
+    pub(crate) async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+        cassette: Option<&Cassette>,
+    ) -> GraphResult<reqwest::Response> {
+        if let Some(latency) = self.latency {
+            tokio::time::sleep(latency).await;
+        }
+
+        match self.next_fault() {
+            Some(Fault::Latency(latency)) => {
+                tokio::time::sleep(latency).await;
+                send_request(request, cassette).await
+            }
+            Some(Fault::Throttle { retry_after }) => {
+                retry_after_response(429, "TooManyRequests", retry_after)
+            }
+            Some(Fault::ServiceUnavailable { retry_after }) => {
+                retry_after_response(503, "ServiceUnavailable", retry_after)
+            }
+            Some(Fault::ConnectionReset) => Err(GraphFailure::Io(io::Error::new(
+                io::ErrorKind::ConnectionReset,
+                "connection reset by fault injection",
+            ))),
+            Some(Fault::TruncateBody(len)) => {
+                let response = send_request(request, cassette).await?;
+                truncate_body(response, len)
+            }
+            None => send_request(request, cassette).await,
+        }
+    }
+}
+
+async fn send_request(
+    request: reqwest::RequestBuilder,
+    cassette: Option<&Cassette>,
+) -> GraphResult<reqwest::Response> {
+    match cassette {
+        Some(cassette) => cassette.send(request).await,
+        None => request.send().await.map_err(GraphFailure::from),
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn retry_after_response(
+    status: u16,
+    code: &str,
+    retry_after: Duration,
+) -> GraphResult<reqwest::Response> {
+    let body = serde_json::json!({
+        "error": {
+            "code": code,
+            "message": "Injected fault"
+        }
+    });
+    let response = http::Response::builder()
+        .status(status)
+        .header(RETRY_AFTER, retry_after.as_secs())
+        .header(CONTENT_TYPE, "application/json")
+        .body(body.to_string())?;
+    Ok(reqwest::Response::from(response))
+}
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[allow(clippy::result_large_err)]
+fn truncate_body(response: reqwest::Response, len: usize) -> GraphResult<reqwest::Response> {
+    let mut builder = http::Response::builder()
+        .status(response.status())
+        .version(response.version());
+    if let Some(headers) = builder.headers_mut() {
+        headers.extend(response.headers().clone());
+        headers.remove(CONTENT_LENGTH);
+    }
+
+    let mut stream = response.bytes_stream();
+    let body = async_stream::stream! {
+        let mut remaining = len;
+        while let Some(chunk) = stream.next().await {
+            let mut chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(err) => {
+                    yield Err(BoxError::from(err));
+                    return;
+                }
+            };
+            if chunk.len() > remaining {
+                chunk.truncate(remaining);
+                if !chunk.is_empty() {
+                    yield Ok(chunk);
+                }
+                yield Err(BoxError::from(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "body truncated by fault injection",
+                )));
+                return;
+            }
+            remaining -= chunk.len();
+            yield Ok(chunk);
+        }
+    };
+
+    Ok(reqwest::Response::from(
+        builder.body(reqwest::Body::wrap_stream(body))?,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn inject_faults_in_order() {
+        let fault_injection = FaultInjection::new()
+            .repeat(
+                Fault::Throttle {
+                    retry_after: Duration::from_secs(2),
+                },
+                2,
+            )
+            .fault(Fault::ConnectionReset);
+        let client = reqwest::Client::new();
+        let request = || client.get("https://graph.microsoft.com/v1.0/me");
+
+        let response = fault_injection.send(request(), None).await.unwrap();
+        assert_eq!(429, response.status().as_u16());
+        assert_eq!("2", response.headers()[RETRY_AFTER]);
+        assert_eq!(2, fault_injection.clone().remaining());
+
+        fault_injection.send(request(), None).await.unwrap();
+        assert!(matches!(
+            fault_injection.send(request(), None).await,
+            Err(GraphFailure::Io(err)) if err.kind() == io::ErrorKind::ConnectionReset
+        ));
+        assert_eq!(0, fault_injection.remaining());
+    }
+
+    #[tokio::test]
+    async fn truncate_response_body() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .header(CONTENT_LENGTH, 11)
+                .body("hello world")
+                .unwrap(),
+        );
+
+        let mut body = truncate_body(response, 5).unwrap().bytes_stream();
+        assert_eq!("hello", body.next().await.unwrap().unwrap());
+        assert!(body.next().await.unwrap().is_err());
+    }
+}
@@ -4,6 +4,18 @@ use std::ffi::{OsStr, OsString};
 use std::io::Read;
 use std::path::{Path, PathBuf};
 
+/// What to do when downloading a file that already exists.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum OverwritePolicy {
+    /// Return an error.
+    #[default]
+    Fail,
+    /// Replace the existing file.
+    Overwrite,
+    /// Save the file with a number added to the file name, such as `file (1).txt`.
+    Rename,
+}
+
 /// Config for downloading files using Microsoft Graph File and OneDrive APIs.
 /// FileConfig can also be used for uploads but all fields except for the provided
 /// path are ignored.
@@ -26,14 +38,14 @@ use std::path::{Path, PathBuf};
 /// ```rust
 /// use std::ffi::{OsStr, OsString};
 /// use std::path::Path;
-/// use graph_http::api_impl::FileConfig;
+/// use graph_http::api_impl::{FileConfig, OverwritePolicy};
 ///
 /// let config = FileConfig::new("./examples")
 ///     .overwrite_existing_file(true)
 ///     .extension(OsStr::new("pdf"));
 ///
 /// # assert_eq!(Path::new("./examples"), config.path.as_path());
-/// # assert!(config.overwrite_existing_file);
+/// # assert_eq!(OverwritePolicy::Overwrite, config.overwrite_policy);
 /// # assert_eq!(Some(&OsString::from("pdf")),  config.extension.as_ref());
 /// ```
 #[derive(Clone, Debug, Default)]
@@ -41,8 +53,13 @@ pub struct FileConfig {
     pub path: PathBuf,
     /// Used only when downloading files. Default is true.
     pub create_directory_all: bool,
-    /// Used only when downloading files
+    /// Used only when downloading files. Default is false.
+    #[deprecated(note = "use overwrite_policy, true is the same as OverwritePolicy::Overwrite")]
     pub overwrite_existing_file: bool,
+    /// Used only when downloading files. Default is [`OverwritePolicy::Fail`].
+    pub overwrite_policy: OverwritePolicy,
+    /// Used only when downloading files. Default is false.
+    pub temp_file: bool,
     /// Used only when downloading files
     pub file_name: Option<OsString>,
     /// Used only when downloading files
@@ -71,21 +88,24 @@ impl FileConfig {
     /// ```rust
     /// use std::ffi::{OsStr, OsString};
     /// use std::path::Path;
-    /// use graph_http::api_impl::FileConfig;
+    /// use graph_http::api_impl::{FileConfig, OverwritePolicy};
     ///
     /// let config = FileConfig::new("./examples")
     ///     .overwrite_existing_file(true)
     ///     .extension(OsStr::new("pdf"));
     ///
     /// # assert_eq!(Path::new("./examples"), config.path.as_path());
-    /// # assert!(config.overwrite_existing_file);
+    /// # assert_eq!(OverwritePolicy::Overwrite, config.overwrite_policy);
     /// # assert_eq!(Some(&OsString::from("pdf")),  config.extension.as_ref());
     /// ```
+    #[allow(deprecated)]
     pub fn new<P: AsRef<Path>>(path: P) -> FileConfig {
         FileConfig {
             path: path.as_ref().to_path_buf(),
             create_directory_all: true,
             overwrite_existing_file: false,
+            overwrite_policy: OverwritePolicy::Fail,
+            temp_file: false,
             file_name: None,
             extension: None,
         }
@@ -108,19 +128,56 @@ impl FileConfig {
     }
 
     /// Overwrite an existing file of the same file name and path.
-    /// This is true by default.
+    /// This is the same as setting [`OverwritePolicy::Overwrite`] when true
+    /// and [`OverwritePolicy::Fail`] when false. Default is false.
     ///
     /// # Example
     /// ```rust
-    /// use graph_http::api_impl::FileConfig;
+    /// use graph_http::api_impl::{FileConfig, OverwritePolicy};
     ///
     /// let config = FileConfig::new("./examples")
     ///     .overwrite_existing_file(true);
     ///
-    /// # assert!(config.overwrite_existing_file);
+    /// # assert_eq!(OverwritePolicy::Overwrite, config.overwrite_policy);
     /// ```
     pub fn overwrite_existing_file(mut self, overwrite_file: bool) -> FileConfig {
-        self.overwrite_existing_file = overwrite_file;
+        self.set_overwrite_existing_file(overwrite_file);
+        self
+    }
+
+    /// Set what happens when a file of the same file name and path already exists.
+    /// Default is [`OverwritePolicy::Fail`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use graph_http::api_impl::{FileConfig, OverwritePolicy};
+    ///
+    /// let config = FileConfig::new("./examples")
+    ///     .overwrite_policy(OverwritePolicy::Rename);
+    ///
+    /// # assert_eq!(OverwritePolicy::Rename, config.overwrite_policy);
+    /// ```
+    pub fn overwrite_policy(mut self, overwrite_policy: OverwritePolicy) -> FileConfig {
+        self.set_overwrite_policy(overwrite_policy);
+        self
+    }
+
+    /// Download to a temporary file in the same directory and rename it to the
+    /// file name once the download completes, so that an incomplete download
+    /// never replaces or appears as the file. The temporary file is removed if
+    /// the download fails. Default is false.
+    ///
+    /// # Example
+    /// ```rust
+    /// use graph_http::api_impl::FileConfig;
+    ///
+    /// let config = FileConfig::new("./examples")
+    ///     .temp_file(true);
+    ///
+    /// # assert!(config.temp_file);
+    /// ```
+    pub fn temp_file(mut self, temp_file: bool) -> FileConfig {
+        self.temp_file = temp_file;
         self
     }
 
@@ -202,10 +259,36 @@ impl FileConfig {
     /// let mut config = FileConfig::new("./examples");
     /// config.set_overwrite_existing_file(true);
     ///
-    /// # assert!(config.overwrite_existing_file);
+    /// # assert!(config.overwrite_policy == graph_http::api_impl::OverwritePolicy::Overwrite);
     /// ```
     pub fn set_overwrite_existing_file(&mut self, overwrite_file: bool) {
-        self.overwrite_existing_file = overwrite_file;
+        self.set_overwrite_policy(if overwrite_file {
+            OverwritePolicy::Overwrite
+        } else {
+            OverwritePolicy::Fail
+        });
+    }
+
+    /// Set what happens when a file of the same file name and path already exists.
+    #[allow(deprecated)]
+    pub fn set_overwrite_policy(&mut self, overwrite_policy: OverwritePolicy) {
+        self.overwrite_policy = overwrite_policy;
+        self.overwrite_existing_file = overwrite_policy == OverwritePolicy::Overwrite;
+    }
+
+    // The overwrite policy with the deprecated overwrite_existing_file field set
+    // directly by the user mapped to OverwritePolicy::Overwrite.
+    #[allow(deprecated)]
+    fn effective_overwrite_policy(&self) -> OverwritePolicy {
+        match self.overwrite_policy {
+            OverwritePolicy::Fail if self.overwrite_existing_file => OverwritePolicy::Overwrite,
+            overwrite_policy => overwrite_policy,
+        }
+    }
+
+    /// Download to a temporary file and rename it to the file name once the download completes.
+    pub fn set_temp_file(&mut self, temp_file: bool) {
+        self.temp_file = temp_file;
     }
 
     /// Set the file name and extension. If you use the format ODataQuery to change
@@ -243,6 +326,42 @@ impl FileConfig {
     pub fn set_extension(&mut self, ext: &OsStr) {
         self.extension = Some(ext.to_os_string());
     }
+
+    /// The path to download the file to based on the overwrite policy or None
+    /// if the file exists and the policy is [`OverwritePolicy::Fail`].
+    pub(crate) fn download_path(&self, path: PathBuf) -> Option<PathBuf> {
+        if !path.exists() {
+            return Some(path);
+        }
+
+        match self.effective_overwrite_policy() {
+            OverwritePolicy::Fail => None,
+            OverwritePolicy::Overwrite => Some(path),
+            OverwritePolicy::Rename => {
+                let stem = path.file_stem().unwrap_or_default().to_os_string();
+                let extension = path.extension().map(|ext| ext.to_os_string());
+                (1..)
+                    .map(|n| {
+                        let mut file_name = stem.clone();
+                        file_name.push(format!(" ({n})"));
+                        if let Some(extension) = extension.as_ref() {
+                            file_name.push(".");
+                            file_name.push(extension);
+                        }
+                        path.with_file_name(file_name)
+                    })
+                    .find(|path| !path.exists())
+            }
+        }
+    }
+}
+
+/// The temporary file a download is written to before being renamed to `path`.
+pub(crate) fn temp_download_path(path: &Path) -> PathBuf {
+    let mut file_name = OsString::from(".");
+    file_name.push(path.file_name().unwrap_or_default());
+    file_name.push(".download");
+    path.with_file_name(file_name)
 }
 
 impl From<PathBuf> for FileConfig {
@@ -267,3 +386,50 @@ impl TryFrom<FileConfig> for BytesMut {
         Ok(BytesMut::from_iter(buf))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn download_path_rename_on_collision() {
+        let dir = std::env::temp_dir().join("graph_http_download_path_rename");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("file.txt");
+        std::fs::write(&path, b"1").unwrap();
+        std::fs::write(dir.join("file (1).txt"), b"2").unwrap();
+
+        let config = FileConfig::new(&dir);
+        assert_eq!(None, config.download_path(path.clone()));
+        let config = config.overwrite_policy(OverwritePolicy::Overwrite);
+        assert_eq!(Some(path.clone()), config.download_path(path.clone()));
+        let config = config.overwrite_policy(OverwritePolicy::Rename);
+        assert_eq!(
+            Some(dir.join("file (2).txt")),
+            config.download_path(path.clone())
+        );
+        assert_eq!(dir.join(".file.txt.download"), temp_download_path(&path));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_overwrite_existing_file() {
+        let dir = std::env::temp_dir().join("graph_http_download_path_overwrite");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("file.txt");
+        std::fs::write(&path, b"1").unwrap();
+
+        let mut config = FileConfig::new(&dir);
+        config.overwrite_existing_file = true;
+        assert_eq!(Some(path.clone()), config.download_path(path.clone()));
+
+        let config = FileConfig::new(&dir).overwrite_existing_file(true);
+        assert!(config.overwrite_existing_file);
+        let config = config.overwrite_policy(OverwritePolicy::Rename);
+        assert!(!config.overwrite_existing_file);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
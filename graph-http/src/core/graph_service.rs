@@ -0,0 +1,139 @@
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use graph_core::http::AsyncBytesResponseExt;
+use graph_core::resource::ResourceIdentity;
+use graph_error::{GraphFailure, GraphResult};
+use tower_service::Service;
+use url::Url;
+
+use crate::internal::{BodyRead, Client, RequestComponents, RequestHandler};
+
+/// A [tower_service::Service] that sends requests using a [Client], so that tower
+/// middleware such as rate limiting, auth injection, and metrics can be layered
+/// around requests to Graph. Requires the `tower` feature.
+///
+/// Requests are sent the same way as requests made by the api methods of the
+/// client: an access token is requested from the client application, the default
+/// headers of the client and a `client-request-id` header are added, and the
+/// request is sent in a `graph_request` tracing span. An `Authorization` header
+/// set on the request, such as by an auth middleware, is used instead of the
+/// access token of the client application.
+///
+/// Error responses are returned as responses. Only failures to get an access token
+/// or to send the request are returned as a [GraphFailure].
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::http::GraphService;
+/// use tower::{Service, ServiceBuilder, ServiceExt};
+///
+/// let mut service = ServiceBuilder::new()
+///     .rate_limit(100, Duration::from_secs(1))
+///     .service(client.service());
+///
+/// let request = http::Request::get("https://graph.microsoft.com/v1.0/me")
+///     .body(bytes::Bytes::new())?;
+/// let response: http::Response<bytes::Bytes> = service.ready().await?.call(request).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct GraphService {
+    client: Client,
+}
+
+impl GraphService {
+    pub fn new(client: Client) -> GraphService {
+        GraphService { client }
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn request_handler<B: Into<reqwest::Body>>(
+        &self,
+        request: http::Request<B>,
+    ) -> GraphResult<RequestHandler> {
+        let (parts, body) = request.into_parts();
+        let url = Url::parse(&parts.uri.to_string())?;
+        let mut request_components =
+            RequestComponents::new(ResourceIdentity::Custom, url, parts.method);
+        request_components.headers = parts.headers;
+
+        let body: reqwest::Body = body.into();
+        let body = match body.as_bytes() {
+            Some([]) => None,
+            _ => Some(BodyRead::from(body)),
+        };
+
+        Ok(RequestHandler::new(
+            self.client.clone(),
+            request_components,
+            None,
+            body,
+        ))
+    }
+}
+
+impl From<Client> for GraphService {
+    fn from(client: Client) -> Self {
+        GraphService::new(client)
+    }
+}
+
+impl<B: Into<reqwest::Body>> Service<http::Request<B>> for GraphService {
+    type Response = http::Response<Bytes>;
+    type Error = GraphFailure;
+    type Future = BoxFuture<'static, GraphResult<http::Response<Bytes>>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let request_handler = self.request_handler(request);
+        Box::pin(async move {
+            let response = request_handler?.send().await?;
+            Ok(response.into_bytes_response().await?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use http::header::{AUTHORIZATION, CONTENT_TYPE};
+
+    #[tokio::test]
+    async fn request_handler_from_http_request() {
+        let service = GraphService::from(Client::from_access_token("token"));
+        let request = http::Request::post("https://graph.microsoft.com/v1.0/users")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Bytes::from_static(br#"{"displayName":"name"}"#))
+            .unwrap();
+
+        let request = service
+            .request_handler(request)
+            .unwrap()
+            .into_http_request()
+            .await
+            .unwrap();
+        assert_eq!(http::Method::POST, request.method());
+        assert_eq!("Bearer token", request.headers()[AUTHORIZATION]);
+        assert_eq!(
+            Bytes::from_static(br#"{"displayName":"name"}"#),
+            request.body()
+        );
+
+        let request = http::Request::get("https://graph.microsoft.com/v1.0/me")
+            .header(AUTHORIZATION, "Bearer middleware")
+            .body(Bytes::new())
+            .unwrap();
+        let request = service
+            .request_handler(request)
+            .unwrap()
+            .into_http_request()
+            .await
+            .unwrap();
+        assert_eq!("Bearer middleware", request.headers()[AUTHORIZATION]);
+        assert!(request.body().is_empty());
+    }
+}
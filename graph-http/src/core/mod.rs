@@ -1,5 +1,35 @@
 mod body_read;
+#[cfg(feature = "test-util")]
+mod cassette;
+mod export;
+#[cfg(feature = "test-util")]
+mod fault_injection;
 mod file_config;
+#[cfg(feature = "tower")]
+mod graph_service;
+mod paging_cursor;
+mod rendered_request;
+mod request_id;
+mod request_layer;
+mod single_flight;
+mod throttle_info;
+mod typed_response;
+mod value_array_decoder;
 
 pub use body_read::*;
+#[cfg(feature = "test-util")]
+pub use cassette::*;
+pub use export::*;
+#[cfg(feature = "test-util")]
+pub use fault_injection::*;
 pub use file_config::*;
+#[cfg(feature = "tower")]
+pub use graph_service::*;
+pub use paging_cursor::*;
+pub use rendered_request::*;
+pub use request_id::*;
+pub use request_layer::*;
+pub(crate) use single_flight::*;
+pub use throttle_info::*;
+pub use typed_response::*;
+pub(crate) use value_array_decoder::*;
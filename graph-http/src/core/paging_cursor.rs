@@ -0,0 +1,175 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use base64::Engine;
+use graph_core::http::HttpResponseExt;
+use graph_error::{GraphFailure, GraphResult};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::traits::{ODataDeltaLink, ODataNextLink};
+
+/// Query parameters removed from the request url stored in a cursor.
+const PAGING_TOKENS: [&str; 2] = ["$skiptoken", "$deltatoken"];
+
+/// The position of a paged or delta enumeration that can be stored as a compact
+/// string and used to resume the enumeration later, such as after a process restart.
+///
+/// Create a cursor from each page with [PagingCursor::from_response] and resume
+/// using [Paging::resume_from](crate::api_impl::Paging::resume_from) on the same request.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut stream = client
+///     .users()
+///     .delta()
+///     .paging()
+///     .resume_from(&saved_cursor.parse::<PagingCursor>()?)
+///     .stream::<serde_json::Value>()?;
+///
+/// while let Some(result) = stream.next().await {
+///     let response = result?;
+///     // ... process the page
+///     if let Some(cursor) = PagingCursor::from_response(&response) {
+///         save_cursor(cursor.to_string());
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PagingCursor {
+    /// The url of the page request without the `$skiptoken` and `$deltatoken`,
+    /// which has the query of the enumeration such as `$filter` and `$select`.
+    #[serde(rename = "r", default, skip_serializing_if = "Option::is_none")]
+    pub request_url: Option<String>,
+    #[serde(rename = "n", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+    #[serde(rename = "d", default, skip_serializing_if = "Option::is_none")]
+    pub delta_link: Option<String>,
+}
+
+impl PagingCursor {
+    /// The cursor after the page. Returns None when the response does not have
+    /// the url or body of the page, such as responses not returned by paging.
+    pub fn from_response<T>(response: &http::Response<T>) -> Option<PagingCursor> {
+        let body = response.json()?;
+        let request_url = response.url().map(|mut url| {
+            let query: Vec<(String, String)> = url
+                .query_pairs()
+                .filter(|(key, _)| {
+                    !PAGING_TOKENS
+                        .iter()
+                        .any(|token| key.eq_ignore_ascii_case(token))
+                })
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect();
+            if query.is_empty() {
+                url.set_query(None);
+            } else {
+                url.query_pairs_mut().clear().extend_pairs(query);
+            }
+            url.to_string()
+        });
+
+        Some(PagingCursor {
+            request_url,
+            next_link: body.odata_next_link(),
+            delta_link: body.odata_delta_link(),
+        })
+    }
+
+    /// Whether all pages have been returned. The delta link, if any, is used
+    /// to get changes made after the enumeration.
+    pub fn is_complete(&self) -> bool {
+        self.next_link.is_none()
+    }
+
+    /// The url of the next request, which is the next link or, once all pages
+    /// have been returned, the delta link.
+    pub fn resume_url(&self) -> Option<&str> {
+        self.next_link.as_deref().or(self.delta_link.as_deref())
+    }
+
+    /// The cursor as a url safe string.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn decode(cursor: &str) -> GraphResult<PagingCursor> {
+        let json = base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(cursor.trim())?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    /// The url to resume the request from. Fails when the cursor has no link or the
+    /// host of the link is not the host of the request, so that a changed cursor
+    /// cannot send the access token to another host.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn resume_request_url(&self, request_url: &Url) -> GraphResult<Url> {
+        let url = Url::parse(
+            self.resume_url()
+                .ok_or_else(|| GraphFailure::invalid("paging cursor next or delta link"))?,
+        )?;
+        if url.host_str() != request_url.host_str() || url.scheme() != request_url.scheme() {
+            return Err(GraphFailure::invalid(
+                "paging cursor link - the host does not match the host of the request",
+            ));
+        }
+        Ok(url)
+    }
+}
+
+impl Display for PagingCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.encode())
+    }
+}
+
+impl FromStr for PagingCursor {
+    type Err = GraphFailure;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PagingCursor::decode(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use graph_core::http::HttpResponseBuilderExt;
+
+    #[test]
+    fn cursor_from_response() {
+        let body = serde_json::json!({
+            "value": [],
+            "@odata.nextLink": "https://graph.microsoft.com/v1.0/users?$filter=accountEnabled+eq+true&$skiptoken=2"
+        });
+        let response = http::Response::builder()
+            .url(
+                Url::parse(
+                    "https://graph.microsoft.com/v1.0/users?$filter=accountEnabled+eq+true&$skiptoken=1",
+                )
+                .unwrap(),
+            )
+            .json(&body)
+            .body(())
+            .unwrap();
+
+        let cursor = PagingCursor::from_response(&response).unwrap();
+        assert_eq!(
+            Some("https://graph.microsoft.com/v1.0/users?%24filter=accountEnabled+eq+true"),
+            cursor.request_url.as_deref()
+        );
+        assert!(!cursor.is_complete());
+        assert_eq!(cursor, cursor.to_string().parse::<PagingCursor>().unwrap());
+
+        let request_url = Url::parse("https://graph.microsoft.com/v1.0/users").unwrap();
+        assert!(cursor.resume_request_url(&request_url).is_ok());
+        let cursor = PagingCursor {
+            next_link: Some("https://example.com/v1.0/users?$skiptoken=2".into()),
+            ..Default::default()
+        };
+        assert!(cursor.resume_request_url(&request_url).is_err());
+        assert!("not a cursor".parse::<PagingCursor>().is_err());
+    }
+}
@@ -0,0 +1,136 @@
+use bytes::Bytes;
+use graph_error::GraphResult;
+use reqwest::header::{HeaderMap, AUTHORIZATION};
+use reqwest::Method;
+use std::fmt::{Debug, Formatter};
+use url::Url;
+
+/// The method, url, headers, and body of a request as it would be sent, returned by
+/// [RequestHandler::build_request](crate::api_impl::RequestHandler::build_request)
+/// without sending the request.
+///
+/// The headers include the `Authorization` header with the access token. The access
+/// token is not included in the [Debug] output or in [RenderedRequest::to_curl].
+///
+/// # Example
+/// ```rust,ignore
+/// let request = client
+///     .me()
+///     .send_mail(&serde_json::json!({ "message": { "subject": "Hello" } }))
+///     .build_request()
+///     .await?;
+///
+/// println!("{}", request.to_curl());
+/// ```
+#[derive(Clone)]
+pub struct RenderedRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Bytes>,
+}
+
+impl RenderedRequest {
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn from_http_request(request: http::Request<Bytes>) -> GraphResult<RenderedRequest> {
+        let url = Url::parse(&request.uri().to_string())?;
+        let (parts, body) = request.into_parts();
+        Ok(RenderedRequest {
+            method: parts.method,
+            url,
+            headers: parts.headers,
+            body: Some(body).filter(|body| !body.is_empty()),
+        })
+    }
+
+    /// The body as text, or `None` when there is no body or the body is not UTF-8.
+    pub fn body_text(&self) -> Option<&str> {
+        self.body
+            .as_ref()
+            .and_then(|body| std::str::from_utf8(body).ok())
+    }
+
+    /// An equivalent curl command. The access token is replaced with the
+    /// `$ACCESS_TOKEN` shell variable.
+    pub fn to_curl(&self) -> String {
+        let mut command = format!("curl -X {} {}", self.method, quote(self.url.as_str()));
+        for (name, value) in self.headers.iter() {
+            if name == AUTHORIZATION {
+                command.push_str(" -H \"Authorization: Bearer $ACCESS_TOKEN\"");
+            } else {
+                let value = String::from_utf8_lossy(value.as_bytes());
+                command.push_str(&format!(" -H {}", quote(&format!("{name}: {value}"))));
+            }
+        }
+
+        if let Some(body) = self.body.as_ref() {
+            command.push_str(&format!(
+                " --data-raw {}",
+                quote(&String::from_utf8_lossy(body))
+            ));
+        }
+        command
+    }
+
+    /// Convert into an [`http::Request<Bytes>`] to send with another transport.
+    #[allow(clippy::result_large_err)]
+    pub fn into_http_request(self) -> GraphResult<http::Request<Bytes>> {
+        let mut request = http::Request::builder()
+            .method(self.method)
+            .uri(self.url.as_str())
+            .body(self.body.unwrap_or_default())?;
+        *request.headers_mut() = self.headers;
+        Ok(request)
+    }
+}
+
+impl Debug for RenderedRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut headers = self.headers.clone();
+        if headers.contains_key(AUTHORIZATION) {
+            headers.insert(AUTHORIZATION, "Bearer [REDACTED]".parse().unwrap());
+        }
+
+        f.debug_struct("RenderedRequest")
+            .field("method", &self.method)
+            .field("url", &self.url.as_str())
+            .field("headers", &headers)
+            .field("body", &self.body_text().unwrap_or("<binary>"))
+            .finish()
+    }
+}
+
+// Quote for a POSIX shell.
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn curl_command() {
+        let request = reqwest::Client::new()
+            .post("https://graph.microsoft.com/v1.0/me/sendMail")
+            .bearer_auth("token")
+            .header("content-type", "application/json")
+            .body(r#"{"subject":"It's here"}"#)
+            .build()
+            .unwrap();
+        let request = RenderedRequest::from_http_request(
+            graph_core::http::BytesRequestExt::try_into_bytes_request(request).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(Some(r#"{"subject":"It's here"}"#), request.body_text());
+        assert_eq!(
+            "curl -X POST 'https://graph.microsoft.com/v1.0/me/sendMail' \
+             -H \"Authorization: Bearer $ACCESS_TOKEN\" \
+             -H 'content-type: application/json' \
+             --data-raw '{\"subject\":\"It'\\''s here\"}'",
+            request.to_curl()
+        );
+        assert!(!format!("{request:?}").contains("token"));
+    }
+}
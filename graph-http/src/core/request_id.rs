@@ -0,0 +1,107 @@
+use graph_error::ErrorMessage;
+use reqwest::header::{HeaderMap, HeaderValue};
+
+/// The header with the id Graph assigns to each request.
+pub const REQUEST_ID: &str = "request-id";
+
+/// The header with the id sent by the client for each request. Graph returns
+/// the same value in the response.
+pub const CLIENT_REQUEST_ID: &str = "client-request-id";
+
+/// The ids of a request returned in the headers of a response. Microsoft support
+/// uses the ids and the date of the request to find the request.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::http::ResponseExt;
+///
+/// let response = client.users().list_user().send().await?;
+/// let request_ids = response.request_ids();
+/// println!("{:?} {:?}", request_ids.request_id, request_ids.client_request_id);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RequestIds {
+    pub request_id: Option<String>,
+    pub client_request_id: Option<String>,
+}
+
+impl RequestIds {
+    pub fn from_headers(headers: &HeaderMap) -> RequestIds {
+        RequestIds {
+            request_id: header_string(headers, REQUEST_ID),
+            client_request_id: header_string(headers, CLIENT_REQUEST_ID),
+        }
+    }
+
+    /// Set the ids of the error message when they are not in the response body.
+    pub fn set_error_message_ids(&self, message: &mut ErrorMessage) {
+        message.set_request_ids(self.request_id.clone(), self.client_request_id.clone());
+    }
+}
+
+/// Add a new `client-request-id` to the headers unless one was already set and
+/// return the id.
+pub(crate) fn ensure_client_request_id(headers: &mut HeaderMap) -> String {
+    headers
+        .entry(CLIENT_REQUEST_ID)
+        .or_insert_with(|| {
+            HeaderValue::from_str(&uuid::Uuid::new_v4().to_string())
+                .expect("uuid is a valid header value")
+        })
+        .to_str()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// The span of a single request. The `request_id` and `status` fields are
+/// recorded once the response is received.
+pub(crate) fn request_span(
+    method: &reqwest::Method,
+    url: &url::Url,
+    client_request_id: &str,
+) -> tracing::Span {
+    tracing::debug_span!(
+        "graph_request",
+        method = %method,
+        url = %url,
+        client_request_id = %client_request_id,
+        request_id = tracing::field::Empty,
+        status = tracing::field::Empty,
+    )
+}
+
+pub(crate) fn record_response(
+    span: &tracing::Span,
+    status: reqwest::StatusCode,
+    headers: &HeaderMap,
+) {
+    span.record("status", status.as_u16());
+    if let Some(request_id) = header_string(headers, REQUEST_ID) {
+        span.record("request_id", request_id.as_str());
+    }
+}
+
+fn header_string(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn client_request_id_is_kept() {
+        let mut headers = HeaderMap::new();
+        let client_request_id = ensure_client_request_id(&mut headers);
+        assert_eq!(36, client_request_id.len());
+        assert_eq!(client_request_id, ensure_client_request_id(&mut headers));
+
+        headers.insert(REQUEST_ID, HeaderValue::from_static("1"));
+        let request_ids = RequestIds::from_headers(&headers);
+        assert_eq!(Some("1".to_string()), request_ids.request_id);
+        assert_eq!(Some(client_request_id), request_ids.client_request_id);
+    }
+}
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use graph_error::GraphResult;
+
+use crate::internal::Client;
+
+/// A step in the pipeline that requests sent by a client pass through, such as a
+/// retry, cache, or telemetry layer. Add layers to a client using
+/// [GraphClientConfiguration::with_layer](crate::api_impl::GraphClientConfiguration::with_layer).
+///
+/// Layers run in the order they are added: the first layer added receives the
+/// request first and the response last. A layer calls [Next::run] to pass the
+/// request to the next layer, can call it more than once using a copy of the
+/// request to retry, or can return a response without calling it. The access
+/// token and default headers are already set on the request.
+///
+/// Layers only apply to async requests sent with
+/// [RequestHandler::send](crate::api_impl::RequestHandler::send).
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::http::{Next, RequestLayer};
+///
+/// struct Telemetry;
+///
+/// #[async_trait::async_trait]
+/// impl RequestLayer for Telemetry {
+///     async fn handle(
+///         &self,
+///         request: reqwest::Request,
+///         next: Next<'_>,
+///     ) -> GraphResult<reqwest::Response> {
+///         let start = std::time::Instant::now();
+///         let response = next.run(request).await;
+///         record_duration(start.elapsed());
+///         response
+///     }
+/// }
+///
+/// // Each retry is recorded by the telemetry layer because the
+/// // telemetry layer is added after the retry layer.
+/// let client = GraphClient::from(
+///     GraphClientConfiguration::new()
+///         .access_token(ACCESS_TOKEN)
+///         .with_layer(Retry::new(3))
+///         .with_layer(Telemetry),
+/// );
+/// ```
+#[async_trait]
+pub trait RequestLayer: Send + Sync {
+    async fn handle(
+        &self,
+        request: reqwest::Request,
+        next: Next<'_>,
+    ) -> GraphResult<reqwest::Response>;
+}
+
+/// The layers of the pipeline after the current layer.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    client: &'a Client,
+    layers: &'a [Arc<dyn RequestLayer>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(client: &'a Client) -> Next<'a> {
+        Next {
+            client,
+            layers: client.layers(),
+        }
+    }
+
+    /// Pass the request to the next layer, or send the request when this is
+    /// the last layer.
+    pub async fn run(self, request: reqwest::Request) -> GraphResult<reqwest::Response> {
+        match self.layers.split_first() {
+            Some((layer, layers)) => {
+                layer
+                    .handle(
+                        request,
+                        Next {
+                            client: self.client,
+                            layers,
+                        },
+                    )
+                    .await
+            }
+            None => self.client.send_request(request).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::internal::GraphClientConfiguration;
+    use std::sync::Mutex;
+
+    struct Record(&'static str, Arc<Mutex<Vec<&'static str>>>);
+
+    #[async_trait]
+    impl RequestLayer for Record {
+        async fn handle(
+            &self,
+            request: reqwest::Request,
+            next: Next<'_>,
+        ) -> GraphResult<reqwest::Response> {
+            self.1.lock().unwrap().push(self.0);
+            next.run(request).await
+        }
+    }
+
+    struct Respond;
+
+    #[async_trait]
+    impl RequestLayer for Respond {
+        async fn handle(
+            &self,
+            _request: reqwest::Request,
+            _next: Next<'_>,
+        ) -> GraphResult<reqwest::Response> {
+            Ok(reqwest::Response::from(
+                http::Response::builder().status(204).body("").unwrap(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn layers_run_in_order() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let client = GraphClientConfiguration::new()
+            .access_token("token")
+            .with_layer(Record("outer", calls.clone()))
+            .with_layer(Record("inner", calls.clone()))
+            .with_layer(Respond)
+            .build();
+
+        let request = reqwest::Client::new()
+            .get("https://graph.microsoft.com/v1.0/me")
+            .build()
+            .unwrap();
+        let response = Next::new(&client).run(request).await.unwrap();
+        assert_eq!(204, response.status().as_u16());
+        assert_eq!(vec!["outer", "inner"], *calls.lock().unwrap());
+    }
+}
@@ -0,0 +1,278 @@
+use crate::internal::CLIENT_REQUEST_ID;
+use bytes::Bytes;
+use futures::future::{BoxFuture, FutureExt, Shared, WeakShared};
+use graph_core::http::{TRACEPARENT, TRACESTATE};
+use graph_error::{GraphFailure, GraphResult};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, ResponseBuilderExt, StatusCode, Version};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+type SharedResult = Result<Arc<BufferedResponse>, Arc<GraphFailure>>;
+type SharedResponse = Shared<BoxFuture<'static, SharedResult>>;
+type InFlight = Arc<Mutex<HashMap<Key, Flight>>>;
+
+// SHA-256 of the method, url, and headers of a request, so that the Authorization
+// header is not kept in memory.
+type Key = [u8; 32];
+
+struct Flight {
+    id: u64,
+    shared: WeakShared<BoxFuture<'static, SharedResult>>,
+}
+
+// Headers with a new value for each request, which are not compared when checking if
+// requests are identical.
+const PER_REQUEST_HEADERS: [&str; 3] = [CLIENT_REQUEST_ID, TRACEPARENT, TRACESTATE];
+
+/// Shares a single in-flight request between concurrent identical GET requests.
+///
+/// Requests are identical when the method, url, and headers, including the
+/// Authorization header, are the same. Each caller receives its own copy of the
+/// response. The response body is buffered in memory before it is shared.
+///
+/// The request is removed when it completes or when every caller waiting on it
+/// is cancelled.
+#[derive(Clone, Default)]
+pub(crate) struct SingleFlight {
+    in_flight: InFlight,
+    next_id: Arc<AtomicU64>,
+}
+
+// Removes a request from the in-flight requests when the request future is dropped,
+// which happens when the request completes or every caller is cancelled.
+struct InFlightGuard {
+    in_flight: InFlight,
+    key: Key,
+    id: u64,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if let Ok(mut in_flight) = self.in_flight.lock() {
+            // A new request may have replaced this one after the last caller was dropped.
+            if in_flight.get(&self.key).map(|flight| flight.id) == Some(self.id) {
+                in_flight.remove(&self.key);
+            }
+        }
+    }
+}
+
+impl SingleFlight {
+    pub(crate) async fn send(
+        &self,
+        client: &reqwest::Client,
+        request_builder: reqwest::RequestBuilder,
+    ) -> GraphResult<reqwest::Response> {
+        let request = request_builder.build()?;
+        if request.method() != Method::GET || request.body().is_some() {
+            return client.execute(request).await.map_err(GraphFailure::from);
+        }
+
+        let key = SingleFlight::key(&request);
+        let shared = self.shared(key, BufferedResponse::send(client.clone(), request));
+
+        match shared.await {
+            Ok(response) => response.to_response(),
+            // The last caller receives the original error. Other callers receive an
+            // error with the shared original error as its source.
+            Err(error) => Err(Arc::try_unwrap(error)
+                .unwrap_or_else(|error| GraphFailure::from(std::io::Error::other(error)))),
+        }
+    }
+
+    // The in-flight request with the key, or a new request when there is none.
+    fn shared(
+        &self,
+        key: Key,
+        send: impl std::future::Future<Output = SharedResult> + Send + 'static,
+    ) -> SharedResponse {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if let Some(shared) = in_flight
+            .get(&key)
+            .and_then(|flight| flight.shared.upgrade())
+        {
+            return shared;
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let guard = InFlightGuard {
+            in_flight: self.in_flight.clone(),
+            key,
+            id,
+        };
+        let shared = async move {
+            let _guard = guard;
+            send.await
+        }
+        .boxed()
+        .shared();
+        if let Some(weak) = shared.downgrade() {
+            in_flight.insert(key, Flight { id, shared: weak });
+        }
+        shared
+    }
+
+    fn key(request: &reqwest::Request) -> Key {
+        let mut headers: Vec<String> = request
+            .headers()
+            .iter()
+            .filter(|(name, _)| !PER_REQUEST_HEADERS.contains(&name.as_str()))
+            .map(|(name, value)| format!("{}:{}", name, String::from_utf8_lossy(value.as_bytes())))
+            .collect();
+        headers.sort();
+        let request = format!(
+            "{} {}\n{}",
+            request.method(),
+            request.url(),
+            headers.join("\n")
+        );
+        let digest = ring::digest::digest(&ring::digest::SHA256, request.as_bytes());
+        digest
+            .as_ref()
+            .try_into()
+            .expect("SHA-256 digest is 32 bytes")
+    }
+}
+
+#[derive(Debug)]
+struct BufferedResponse {
+    status: StatusCode,
+    version: Version,
+    headers: HeaderMap,
+    url: Url,
+    body: Bytes,
+}
+
+impl BufferedResponse {
+    async fn send(client: reqwest::Client, request: reqwest::Request) -> SharedResult {
+        let response = client
+            .execute(request)
+            .await
+            .map_err(|e| Arc::new(GraphFailure::from(e)))?;
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let url = response.url().clone();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| Arc::new(GraphFailure::from(e)))?;
+
+        Ok(Arc::new(BufferedResponse {
+            status,
+            version,
+            headers,
+            url,
+            body,
+        }))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn to_response(&self) -> GraphResult<reqwest::Response> {
+        let mut builder = http::Response::builder()
+            .status(self.status)
+            .version(self.version)
+            .url(self.url.clone());
+
+        if let Some(headers) = builder.headers_mut() {
+            headers.extend(self.headers.clone());
+        }
+
+        Ok(reqwest::Response::from(builder.body(self.body.clone())?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::{HeaderValue, AUTHORIZATION};
+
+    #[test]
+    fn key_includes_authorization() {
+        let client = reqwest::Client::new();
+        let first = client
+            .get("https://graph.microsoft.com/v1.0/me")
+            .header(AUTHORIZATION, HeaderValue::from_static("Bearer first"))
+            .build()
+            .unwrap();
+        let second = client
+            .get("https://graph.microsoft.com/v1.0/me")
+            .header(AUTHORIZATION, HeaderValue::from_static("Bearer second"))
+            .build()
+            .unwrap();
+
+        assert_ne!(SingleFlight::key(&first), SingleFlight::key(&second));
+        assert_eq!(
+            SingleFlight::key(&first),
+            SingleFlight::key(&first.try_clone().unwrap())
+        );
+    }
+
+    #[test]
+    fn key_excludes_per_request_headers() {
+        let client = reqwest::Client::new();
+        let request = |id: &'static str| {
+            client
+                .get("https://graph.microsoft.com/v1.0/me")
+                .header(CLIENT_REQUEST_ID, id)
+                .header(TRACEPARENT, id)
+                .header(TRACESTATE, id)
+                .build()
+                .unwrap()
+        };
+        assert_eq!(
+            SingleFlight::key(&request("first")),
+            SingleFlight::key(&request("second"))
+        );
+    }
+
+    #[test]
+    fn cancelled_request_removed() {
+        let single_flight = SingleFlight::default();
+        let first = single_flight.shared([0; 32], futures::future::pending());
+        let second = single_flight.shared([0; 32], futures::future::pending());
+        assert!(first.ptr_eq(&second));
+        assert_eq!(1, single_flight.in_flight.lock().unwrap().len());
+
+        drop(first);
+        drop(second);
+        assert!(single_flight.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn completed_request_removed() {
+        let single_flight = SingleFlight::default();
+        let buffered = Arc::new(BufferedResponse {
+            status: StatusCode::OK,
+            version: Version::HTTP_11,
+            headers: HeaderMap::new(),
+            url: Url::parse("https://graph.microsoft.com/v1.0/me").unwrap(),
+            body: Bytes::from_static(b"{}"),
+        });
+        let first = single_flight.shared([0; 32], async move { Ok(buffered) });
+        let second = single_flight.shared([0; 32], futures::future::pending());
+        assert!(first.ptr_eq(&second));
+
+        assert!(first.await.is_ok());
+        assert!(single_flight.in_flight.lock().unwrap().is_empty());
+        assert!(second.await.is_ok());
+    }
+
+    #[test]
+    fn buffered_response_copy() {
+        let buffered = BufferedResponse {
+            status: StatusCode::OK,
+            version: Version::HTTP_11,
+            headers: HeaderMap::new(),
+            url: Url::parse("https://graph.microsoft.com/v1.0/me").unwrap(),
+            body: Bytes::from_static(b"{}"),
+        };
+
+        let response = buffered.to_response().unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(buffered.url, *response.url());
+    }
+}
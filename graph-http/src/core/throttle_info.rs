@@ -0,0 +1,126 @@
+use crate::internal::retry_after;
+use reqwest::header::HeaderMap;
+use std::time::Duration;
+
+/// Rate limit and throttling information returned in the headers of a response.
+///
+/// Not every api returns these headers. The `RateLimit-*` headers are returned by
+/// some services, such as Outlook and Teams, when the app is close to its limit. The
+/// `x-ms-throttle-*` headers are returned by services such as Identity and Access when
+/// a request was throttled or is close to being throttled.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::http::ResponseExt;
+///
+/// let response = client.users().list_user().send().await?;
+/// let throttle_info = response.throttle_info();
+/// println!("{:#?}", throttle_info.remaining);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThrottleInfo {
+    /// The `RateLimit-Limit` header: the request quota for the current window.
+    pub limit: Option<u64>,
+    /// The `RateLimit-Remaining` header: the number of requests left in the current window.
+    pub remaining: Option<u64>,
+    /// The `RateLimit-Reset` header: the time until the current window resets.
+    pub reset: Option<Duration>,
+    /// The `Retry-After` header.
+    pub retry_after: Option<Duration>,
+    /// The `x-ms-throttle-limit-percentage` header: the portion of the limit that
+    /// has been used, returned once more than 0.8 of the limit has been used.
+    pub limit_percentage: Option<f64>,
+    /// The `x-ms-throttle-scope` header.
+    pub scope: Option<String>,
+    /// The `x-ms-throttle-information` header: the reason the request was throttled.
+    pub information: Option<String>,
+}
+
+impl ThrottleInfo {
+    pub fn from_headers(headers: &HeaderMap) -> ThrottleInfo {
+        ThrottleInfo {
+            limit: header_value(headers, "RateLimit-Limit"),
+            remaining: header_value(headers, "RateLimit-Remaining"),
+            reset: header_value(headers, "RateLimit-Reset").map(Duration::from_secs),
+            retry_after: retry_after(headers),
+            limit_percentage: header_value(headers, "x-ms-throttle-limit-percentage"),
+            scope: header_value(headers, "x-ms-throttle-scope"),
+            information: header_value(headers, "x-ms-throttle-information"),
+        }
+    }
+
+    /// Returns true if no rate limit or throttle headers were returned.
+    pub fn is_empty(&self) -> bool {
+        self.eq(&ThrottleInfo::default())
+    }
+
+    /// The duration to wait before sending the next request to avoid being throttled.
+    ///
+    /// Returns the time until the rate limit window resets when no requests are
+    /// remaining in the current window.
+    pub fn pacing_delay(&self) -> Option<Duration> {
+        if self.remaining == Some(0) {
+            return self.reset;
+        }
+
+        None
+    }
+}
+
+impl From<&HeaderMap> for ThrottleInfo {
+    fn from(headers: &HeaderMap) -> Self {
+        ThrottleInfo::from_headers(headers)
+    }
+}
+
+fn header_value<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn parse_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("RateLimit-Limit", HeaderValue::from_static("1000"));
+        headers.insert("RateLimit-Remaining", HeaderValue::from_static("0"));
+        headers.insert("RateLimit-Reset", HeaderValue::from_static("8"));
+        headers.insert(
+            "x-ms-throttle-limit-percentage",
+            HeaderValue::from_static("1.2"),
+        );
+        headers.insert(
+            "x-ms-throttle-scope",
+            HeaderValue::from_static("Tenant_Application/ReadWrite/tenant/app"),
+        );
+
+        let throttle_info = ThrottleInfo::from_headers(&headers);
+        assert_eq!(Some(1000), throttle_info.limit);
+        assert_eq!(Some(0), throttle_info.remaining);
+        assert_eq!(Some(Duration::from_secs(8)), throttle_info.reset);
+        assert_eq!(Some(1.2), throttle_info.limit_percentage);
+        assert_eq!(
+            Some("Tenant_Application/ReadWrite/tenant/app".to_string()),
+            throttle_info.scope
+        );
+        assert_eq!(Some(Duration::from_secs(8)), throttle_info.pacing_delay());
+    }
+
+    #[test]
+    fn no_pacing_with_remaining_budget() {
+        let mut headers = HeaderMap::new();
+        headers.insert("RateLimit-Remaining", HeaderValue::from_static("10"));
+        headers.insert("RateLimit-Reset", HeaderValue::from_static("8"));
+
+        let throttle_info = ThrottleInfo::from_headers(&headers);
+        assert!(!throttle_info.is_empty());
+        assert_eq!(None, throttle_info.pacing_delay());
+        assert!(ThrottleInfo::from_headers(&HeaderMap::new()).is_empty());
+    }
+}
@@ -0,0 +1,468 @@
+use crate::internal::{retry_after, throttle_retry_after, Client, Next, ResponseExt};
+use graph_error::{GraphFailure, GraphResult};
+use reqwest::header::{HeaderMap, LOCATION};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use std::io::ErrorKind;
+use std::time::{Duration, Instant};
+use url::Url;
+
+/// Successful response from the Graph Api grouped by the kind of
+/// response returned for the status code.
+///
+/// # Example
+/// ```rust,ignore
+/// let response = client
+///     .me()
+///     .drive()
+///     .item(ITEM_ID)
+///     .copy_item(&body)
+///     .send_typed()
+///     .await?;
+///
+/// if let TypedResponse::Accepted(accepted) = response {
+///     let status = accepted.wait_for_completion(Duration::from_secs(2), Duration::from_secs(300)).await?;
+///     println!("{status:#?}");
+/// }
+/// ```
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum TypedResponse {
+    /// 201 Created
+    Created(CreatedResponse),
+    /// 202 Accepted
+    Accepted(AcceptedResponse),
+    /// 204 No Content
+    NoContent(NoContentResponse),
+    /// Any other successful response.
+    Ok(reqwest::Response),
+}
+
+impl TypedResponse {
+    /// Create a [TypedResponse] from a response. Responses with a client or server
+    /// error status code are returned as an error. The client is used to poll the
+    /// monitor of a 202 Accepted response.
+    pub async fn from_response(
+        response: reqwest::Response,
+        client: Client,
+    ) -> GraphResult<TypedResponse> {
+        let status = response.status();
+        if !status.is_success() {
+            return Err(GraphFailure::from(
+                response.into_graph_error_message().await?,
+            ));
+        }
+
+        match status {
+            StatusCode::CREATED => {
+                let location = location(response.url(), response.headers());
+                let headers = response.headers().clone();
+                let body = response.json().await.ok();
+                Ok(TypedResponse::Created(CreatedResponse {
+                    headers,
+                    location,
+                    body,
+                }))
+            }
+            StatusCode::ACCEPTED => Ok(TypedResponse::Accepted(AcceptedResponse {
+                location: location(response.url(), response.headers()),
+                retry_after: retry_after(response.headers()),
+                headers: response.headers().clone(),
+                request_url: response.url().clone(),
+                client,
+            })),
+            StatusCode::NO_CONTENT => Ok(TypedResponse::NoContent(NoContentResponse {
+                headers: response.headers().clone(),
+            })),
+            _ => Ok(TypedResponse::Ok(response)),
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            TypedResponse::Created(_) => StatusCode::CREATED,
+            TypedResponse::Accepted(_) => StatusCode::ACCEPTED,
+            TypedResponse::NoContent(_) => StatusCode::NO_CONTENT,
+            TypedResponse::Ok(response) => response.status(),
+        }
+    }
+}
+
+fn location(url: &Url, headers: &HeaderMap) -> Option<Url> {
+    let location = headers.get(LOCATION)?.to_str().ok()?;
+    resolve_location(url, location)
+}
+
+// Locations relative to the host, such as /teams('id')/operations('id'), do not
+// include the version of the api, so they are resolved against the version of the
+// request url.
+fn resolve_location(url: &Url, location: &str) -> Option<Url> {
+    if location.starts_with('/') && !location.starts_with("//") {
+        let version = url
+            .path_segments()
+            .and_then(|mut segments| segments.next())
+            .filter(|version| !version.is_empty());
+        if let Some(version) = version {
+            if !location.starts_with(&format!("/{version}/")) {
+                return url.join(&format!("/{version}{location}")).ok();
+            }
+        }
+    }
+    url.join(location).ok()
+}
+
+/// 204 No Content response.
+#[derive(Clone, Debug)]
+pub struct NoContentResponse {
+    headers: HeaderMap,
+}
+
+impl NoContentResponse {
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+}
+
+/// 201 Created response.
+#[derive(Clone, Debug)]
+pub struct CreatedResponse {
+    headers: HeaderMap,
+    location: Option<Url>,
+    body: Option<serde_json::Value>,
+}
+
+impl CreatedResponse {
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Url of the created resource from the Location header.
+    pub fn location(&self) -> Option<&Url> {
+        self.location.as_ref()
+    }
+
+    pub fn body(&self) -> Option<&serde_json::Value> {
+        self.body.as_ref()
+    }
+
+    /// Id of the created resource. The id is taken from the response body or
+    /// when the body does not include an id, the last segment of the Location header.
+    pub fn id(&self) -> Option<String> {
+        self.body
+            .as_ref()
+            .and_then(|body| body["id"].as_str())
+            .map(|id| id.to_string())
+            .or_else(|| {
+                let segment = self.location.as_ref()?.path_segments()?.next_back()?;
+                Some(id_from_path_segment(segment).to_string())
+            })
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn json<T: DeserializeOwned>(&self) -> GraphResult<T> {
+        let body = self
+            .body
+            .clone()
+            .ok_or_else(|| GraphFailure::not_found("No body found in response"))?;
+        Ok(serde_json::from_value(body)?)
+    }
+}
+
+// Locations may use key syntax such as /users('id').
+fn id_from_path_segment(segment: &str) -> &str {
+    match (segment.find("('"), segment.strip_suffix("')")) {
+        (Some(start), Some(segment)) => &segment[start + 2..],
+        _ => segment,
+    }
+}
+
+/// 202 Accepted response for a long running operation.
+#[derive(Clone, Debug)]
+pub struct AcceptedResponse {
+    headers: HeaderMap,
+    location: Option<Url>,
+    retry_after: Option<Duration>,
+    request_url: Url,
+    client: Client,
+}
+
+impl AcceptedResponse {
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Url of the monitor used to check the status of the operation.
+    pub fn location(&self) -> Option<&Url> {
+        self.location.as_ref()
+    }
+
+    /// An [AcceptedResponse] for the monitor url of an operation that was started
+    /// earlier, such as the url of an operation using an operation id saved from
+    /// the Location header of a previous response. Status requests are sent with the
+    /// access token of the client only when the monitor is on the host of the client.
+    pub fn from_location(client: Client, location: Url) -> AcceptedResponse {
+        AcceptedResponse {
+            headers: HeaderMap::new(),
+            location: Some(location.clone()),
+            retry_after: None,
+            request_url: location,
+            client,
+        }
+    }
+
+    /// Duration given in the Retry-After header to wait before checking the status.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after
+    }
+
+    /// Poll the monitor url in the Location header until the operation has completed
+    /// and return the final status body. Status requests are sent with the access
+    /// token of the client only when the monitor is on the host of the request that
+    /// started the operation. Monitors on other hosts, such as the monitor of a drive
+    /// item copy, are pre-authenticated and are sent without an Authorization header.
+    ///
+    /// The status is checked every `poll_interval` unless a longer duration is given
+    /// in a Retry-After header. Returns an error if the operation failed or has not
+    /// completed before `timeout`.
+    pub async fn wait_for_completion(
+        &self,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<serde_json::Value> {
+        let location = self
+            .location
+            .clone()
+            .ok_or_else(|| GraphFailure::not_found("No Location header found in response"))?;
+
+        let start = Instant::now();
+        let mut wait = self.retry_after.unwrap_or(poll_interval);
+        loop {
+            if start.elapsed() + wait > timeout {
+                return Err(GraphFailure::error_kind(
+                    ErrorKind::TimedOut,
+                    "Operation did not complete before the timeout",
+                ));
+            }
+            tokio::time::sleep(wait).await;
+
+            let request = self.status_request(&location).await?;
+            let response = Next::new(&self.client).run(request).await?;
+            if let Some(retry_after) = throttle_retry_after(response.status(), response.headers()) {
+                wait = retry_after;
+                continue;
+            }
+
+            if !response.status().is_success() {
+                return Err(GraphFailure::from(
+                    response.into_graph_error_message().await?,
+                ));
+            }
+
+            let headers = response.headers().clone();
+            let body: serde_json::Value = response.json().await?;
+            match OperationStatus::from_body(&body) {
+                OperationStatus::Completed => return Ok(body),
+                OperationStatus::Failed => {
+                    return Err(GraphFailure::Default {
+                        url: Some(location),
+                        headers: Some(headers),
+                        message: format!("Operation failed: {body}"),
+                    })
+                }
+                OperationStatus::InProgress => {
+                    wait = retry_after(&headers).unwrap_or(poll_interval);
+                }
+            }
+        }
+    }
+}
+
+impl AcceptedResponse {
+    // The GET request for the status of the operation.
+    async fn status_request(&self, location: &Url) -> GraphResult<reqwest::Request> {
+        let mut request_builder = self
+            .client
+            .inner
+            .get(location.clone())
+            .headers(self.client.headers.clone());
+        if location.host_str() == self.request_url.host_str() {
+            let access_token = self
+                .client
+                .client_application
+                .clone()
+                .get_token_silent_async()
+                .await?;
+            request_builder = request_builder.bearer_auth(access_token);
+        }
+        Ok(request_builder.build()?)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum OperationStatus {
+    InProgress,
+    Completed,
+    Failed,
+}
+
+impl OperationStatus {
+    // The monitor returns a status such as notStarted, running, completed or failed.
+    // Monitors that redirect to the resource when complete return the resource itself.
+    fn from_body(body: &serde_json::Value) -> OperationStatus {
+        match body["status"].as_str().map(|s| s.to_lowercase()).as_deref() {
+            Some("completed") | Some("succeeded") | Some("partiallysucceeded") => {
+                OperationStatus::Completed
+            }
+            Some("failed")
+            | Some("submissionfailed")
+            | Some("invalid")
+            | Some("cancelled")
+            | Some("canceled") => OperationStatus::Failed,
+            Some(_) => OperationStatus::InProgress,
+            None => OperationStatus::Completed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::internal::{GraphClientConfiguration, RequestLayer};
+    use async_trait::async_trait;
+    use reqwest::header::AUTHORIZATION;
+    use std::sync::{Arc, Mutex};
+
+    // Records the Authorization header of each request and responds that the
+    // operation has completed.
+    struct Monitor(Arc<Mutex<Vec<Option<String>>>>);
+
+    #[async_trait]
+    impl RequestLayer for Monitor {
+        async fn handle(
+            &self,
+            request: reqwest::Request,
+            _next: Next<'_>,
+        ) -> GraphResult<reqwest::Response> {
+            let authorization = request
+                .headers()
+                .get(AUTHORIZATION)
+                .map(|value| value.to_str().unwrap().to_string());
+            self.0.lock().unwrap().push(authorization);
+            Ok(reqwest::Response::from(
+                http::Response::builder()
+                    .status(200)
+                    .body(r#"{"status":"completed"}"#)
+                    .unwrap(),
+            ))
+        }
+    }
+
+    fn accepted(client: Client, location: &str) -> AcceptedResponse {
+        AcceptedResponse {
+            headers: HeaderMap::new(),
+            location: Some(Url::parse(location).unwrap()),
+            retry_after: None,
+            request_url: Url::parse("https://graph.microsoft.com/v1.0/me/drive/items/1/copy")
+                .unwrap(),
+            client,
+        }
+    }
+
+    #[tokio::test]
+    async fn access_token_only_sent_to_request_host() {
+        let authorizations = Arc::new(Mutex::new(Vec::new()));
+        let client = GraphClientConfiguration::new()
+            .access_token("token")
+            .with_layer(Monitor(authorizations.clone()))
+            .build();
+
+        accepted(client.clone(), "https://monitor.example.com/status/1")
+            .wait_for_completion(Duration::ZERO, Duration::from_secs(5))
+            .await
+            .unwrap();
+        accepted(client, "https://graph.microsoft.com/v1.0/operations/1")
+            .wait_for_completion(Duration::ZERO, Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![None, Some("Bearer token".to_string())],
+            *authorizations.lock().unwrap()
+        );
+    }
+
+    #[test]
+    fn created_id_from_location() {
+        let created = CreatedResponse {
+            headers: HeaderMap::new(),
+            location: Some(
+                Url::parse("https://graph.microsoft.com/v1.0/teams('team-id')").unwrap(),
+            ),
+            body: None,
+        };
+        assert_eq!(Some("team-id".to_string()), created.id());
+    }
+
+    #[test]
+    fn created_id_from_body() {
+        let created = CreatedResponse {
+            headers: HeaderMap::new(),
+            location: None,
+            body: Some(serde_json::json!({ "id": "id" })),
+        };
+        assert_eq!(Some("id".to_string()), created.id());
+    }
+
+    #[test]
+    fn relative_location_keeps_version() {
+        let url = Url::parse("https://graph.microsoft.com/v1.0/teams").unwrap();
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/teams('team-id')/operations('operation-id')",
+            resolve_location(&url, "/teams('team-id')/operations('operation-id')")
+                .unwrap()
+                .as_str()
+        );
+        assert_eq!(
+            "https://graph.microsoft.com/beta/teams('team-id')",
+            resolve_location(
+                &Url::parse("https://graph.microsoft.com/beta/teams").unwrap(),
+                "/beta/teams('team-id')"
+            )
+            .unwrap()
+            .as_str()
+        );
+        assert_eq!(
+            "https://monitor.example.com/status/1",
+            resolve_location(&url, "https://monitor.example.com/status/1")
+                .unwrap()
+                .as_str()
+        );
+    }
+
+    #[test]
+    fn operation_status() {
+        assert_eq!(
+            OperationStatus::InProgress,
+            OperationStatus::from_body(&serde_json::json!({ "status": "inProgress" }))
+        );
+        assert_eq!(
+            OperationStatus::Completed,
+            OperationStatus::from_body(&serde_json::json!({ "status": "completed" }))
+        );
+        assert_eq!(
+            OperationStatus::Failed,
+            OperationStatus::from_body(&serde_json::json!({ "status": "failed" }))
+        );
+        assert_eq!(
+            OperationStatus::Completed,
+            OperationStatus::from_body(&serde_json::json!({ "status": "partiallySucceeded" }))
+        );
+        assert_eq!(
+            OperationStatus::Failed,
+            OperationStatus::from_body(&serde_json::json!({ "status": "submissionFailed" }))
+        );
+        assert_eq!(
+            OperationStatus::Failed,
+            OperationStatus::from_body(&serde_json::json!({ "status": "invalid" }))
+        );
+    }
+}
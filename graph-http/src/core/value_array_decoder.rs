@@ -0,0 +1,217 @@
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+enum DecoderState {
+    #[default]
+    Envelope,
+    ValueArray,
+}
+
+/// Incrementally splits a page of a collection response into the elements of
+/// its top level `value` array as the bytes of the body are received, so that
+/// only one element is buffered at a time instead of the whole body.
+///
+/// The rest of the body, such as `@odata.nextLink`, is kept with the `value`
+/// array left empty and is returned by [`ValueArrayDecoder::finish`].
+#[derive(Debug, Default)]
+pub(crate) struct ValueArrayDecoder {
+    state: DecoderState,
+    envelope: Vec<u8>,
+    depth: usize,
+    in_string: bool,
+    escape: bool,
+    string: Vec<u8>,
+    key: Option<Vec<u8>>,
+    after_colon: bool,
+    element: Vec<u8>,
+    element_depth: usize,
+    element_in_string: bool,
+    element_escape: bool,
+}
+
+impl ValueArrayDecoder {
+    /// Feed the next chunk of the body. Returns the bytes of each element of the
+    /// `value` array that was completed by the chunk.
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Vec<Vec<u8>> {
+        let mut elements = Vec::new();
+        for &b in chunk {
+            match self.state {
+                DecoderState::Envelope => self.envelope_byte(b),
+                DecoderState::ValueArray => {
+                    if let Some(element) = self.element_byte(b) {
+                        elements.push(element);
+                    }
+                }
+            }
+        }
+        elements
+    }
+
+    /// The body without the elements of the `value` array.
+    pub(crate) fn finish(self) -> serde_json::Result<serde_json::Value> {
+        serde_json::from_slice(&self.envelope)
+    }
+
+    fn envelope_byte(&mut self, b: u8) {
+        self.envelope.push(b);
+        if self.in_string {
+            if self.escape {
+                self.escape = false;
+            } else if b == b'\\' {
+                self.escape = true;
+            } else if b == b'"' {
+                self.in_string = false;
+                if self.depth == 1 {
+                    self.key = Some(std::mem::take(&mut self.string));
+                    self.after_colon = false;
+                }
+                return;
+            }
+
+            if self.depth == 1 {
+                self.string.push(b);
+            }
+            return;
+        }
+
+        match b {
+            b'"' => {
+                self.in_string = true;
+                self.string.clear();
+            }
+            b'[' if self.depth == 1
+                && self.after_colon
+                && self.key.as_deref() == Some(b"value".as_slice()) =>
+            {
+                self.state = DecoderState::ValueArray;
+                self.key = None;
+                self.after_colon = false;
+            }
+            b'{' | b'[' => {
+                self.depth += 1;
+                self.after_colon = false;
+            }
+            b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+            b':' if self.depth == 1 && self.key.is_some() => self.after_colon = true,
+            b',' if self.depth == 1 => {
+                self.key = None;
+                self.after_colon = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn element_byte(&mut self, b: u8) -> Option<Vec<u8>> {
+        if self.element.is_empty() {
+            match b {
+                b',' | b' ' | b'\t' | b'\r' | b'\n' => {}
+                b']' => self.end_value_array(),
+                _ => {
+                    self.element.push(b);
+                    self.element_depth = usize::from(b == b'{' || b == b'[');
+                    self.element_in_string = b == b'"';
+                }
+            }
+            return None;
+        }
+
+        if self.element_in_string {
+            self.element.push(b);
+            if self.element_escape {
+                self.element_escape = false;
+            } else if b == b'\\' {
+                self.element_escape = true;
+            } else if b == b'"' {
+                self.element_in_string = false;
+                if self.element_depth == 0 {
+                    return Some(std::mem::take(&mut self.element));
+                }
+            }
+            return None;
+        }
+
+        // Numbers and literals end at the next separator.
+        if self.element_depth == 0 {
+            return match b {
+                b',' | b' ' | b'\t' | b'\r' | b'\n' => Some(std::mem::take(&mut self.element)),
+                b']' => {
+                    self.end_value_array();
+                    Some(std::mem::take(&mut self.element))
+                }
+                _ => {
+                    self.element.push(b);
+                    None
+                }
+            };
+        }
+
+        self.element.push(b);
+        match b {
+            b'"' => self.element_in_string = true,
+            b'{' | b'[' => self.element_depth += 1,
+            b'}' | b']' => {
+                self.element_depth -= 1;
+                if self.element_depth == 0 {
+                    return Some(std::mem::take(&mut self.element));
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn end_value_array(&mut self) {
+        self.envelope.push(b']');
+        self.state = DecoderState::Envelope;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::internal::ODataNextLink;
+
+    #[test]
+    fn value_array_elements_split_across_chunks() {
+        let body = br#"{"@odata.context":"users","value":[{"id":"1","displayName":"a \"[}\" b","tags":[1,{"x":"]"}]}, {"id":"2"} ],"@odata.nextLink":"https://graph.microsoft.com/v1.0/users?$skiptoken=1"}"#;
+
+        for chunk_size in [1, 3, 7, body.len()] {
+            let mut decoder = ValueArrayDecoder::default();
+            let mut elements = Vec::new();
+            for chunk in body.chunks(chunk_size) {
+                elements.extend(decoder.feed(chunk));
+            }
+
+            assert_eq!(2, elements.len());
+            let first: serde_json::Value = serde_json::from_slice(&elements[0]).unwrap();
+            assert_eq!("a \"[}\" b", first["displayName"]);
+            assert_eq!("]", first["tags"][1]["x"]);
+            let second: serde_json::Value = serde_json::from_slice(&elements[1]).unwrap();
+            assert_eq!("2", second["id"]);
+
+            let envelope = decoder.finish().unwrap();
+            assert_eq!(serde_json::json!([]), envelope["value"]);
+            assert_eq!(
+                Some("https://graph.microsoft.com/v1.0/users?$skiptoken=1".to_string()),
+                envelope.odata_next_link()
+            );
+        }
+    }
+
+    #[test]
+    fn value_array_of_primitives() {
+        let mut decoder = ValueArrayDecoder::default();
+        let elements = decoder.feed(br#"{"value":["a,b", 2,true]}"#);
+        let elements: Vec<serde_json::Value> = elements
+            .iter()
+            .map(|element| serde_json::from_slice(element).unwrap())
+            .collect();
+        assert_eq!(
+            vec![
+                serde_json::json!("a,b"),
+                serde_json::json!(2),
+                serde_json::json!(true)
+            ],
+            elements
+        );
+        assert!(decoder.finish().is_ok());
+    }
+}
@@ -31,6 +31,7 @@ pub fn copy(
         let mut file_writer = fs::OpenOptions::new()
             .create(true)
             .write(true)
+            .truncate(true)
             .read(true)
             .open(&path)?;
         std::io::copy(&mut response, &mut file_writer)?;
@@ -42,6 +43,25 @@ pub fn copy(
     receiver.recv()?.ok_or(ThreadedIoError::NoPath)
 }
 
+/// Copy the response to a temporary file and rename it to `path` when the copy completes.
+/// The temporary file is removed if the copy fails.
+pub fn copy_temp(
+    path: PathBuf,
+    temp_path: PathBuf,
+    response: reqwest::blocking::Response,
+) -> Result<PathBuf, ThreadedIoError> {
+    match copy(temp_path.clone(), response) {
+        Ok(_) => {
+            fs::rename(&temp_path, &path)?;
+            Ok(path)
+        }
+        Err(err) => {
+            let _ = fs::remove_file(&temp_path);
+            Err(err)
+        }
+    }
+}
+
 pub async fn copy_async(
     path: PathBuf,
     response: reqwest::Response,
@@ -49,6 +69,7 @@ pub async fn copy_async(
     let mut file = tokio::fs::OpenOptions::new()
         .create(true)
         .write(true)
+        .truncate(true)
         .read(true)
         .open(&path)
         .await?;
@@ -58,3 +79,22 @@ pub async fn copy_async(
     }
     Ok(path)
 }
+
+/// Copy the response to a temporary file and rename it to `path` when the copy completes.
+/// The temporary file is removed if the copy fails.
+pub async fn copy_async_temp(
+    path: PathBuf,
+    temp_path: PathBuf,
+    response: reqwest::Response,
+) -> Result<PathBuf, AsyncIoError> {
+    match copy_async(temp_path.clone(), response).await {
+        Ok(_) => {
+            tokio::fs::rename(&temp_path, &path).await?;
+            Ok(path)
+        }
+        Err(err) => {
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            Err(err)
+        }
+    }
+}
@@ -37,10 +37,12 @@ pub mod api_impl {
     pub use crate::client::*;
     pub use crate::core::*;
     pub use crate::request_components::RequestComponents;
-    pub use crate::request_handler::{PagingResponse, PagingResult, RequestHandler};
+    pub use crate::request_handler::{
+        throttle_retry_after, Paging, PagingEvent, PagingResponse, PagingResult, RequestHandler,
+    };
     pub use crate::resource_identifier::{ResourceConfig, ResourceIdentifier};
     pub use crate::traits::{ApiClientImpl, BodyExt, ODataQuery};
-    pub use crate::upload_session::UploadSession;
+    pub use crate::upload_session::{UploadProgress, UploadSession};
     pub use graph_core::identity::ClientApplication;
     pub use graph_error::{GraphFailure, GraphResult};
 }
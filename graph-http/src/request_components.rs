@@ -1,5 +1,6 @@
 use std::io::ErrorKind;
 
+use graph_core::identity::TokenPermissions;
 use graph_core::resource::ResourceIdentity;
 use graph_error::{GraphFailure, GraphResult};
 use http::{HeaderMap, Method};
@@ -55,6 +56,22 @@ impl RequestComponents {
 
         Ok(())
     }
+
+    /// Log a warning when the access token has none of the permissions required by
+    /// the request.
+    pub(crate) fn warn_missing_permissions(&self, access_token: &str) {
+        let missing = TokenPermissions::from_access_token(access_token).and_then(|permissions| {
+            permissions.missing_permissions(self.resource_identity, &self.method, self.url.path())
+        });
+        if let Some(required) = missing {
+            tracing::warn!(
+                method = %self.method,
+                url = %self.url,
+                required = ?required,
+                "access token does not have any of the permissions required by the request"
+            );
+        }
+    }
 }
 
 impl TryFrom<(ResourceIdentity, reqwest::Method, GraphResult<Url>)> for RequestComponents {
@@ -1,18 +1,36 @@
 use crate::blocking::BlockingRequestHandler;
 use crate::internal::{
-    BodyRead, Client, GraphClientConfiguration, HttpResponseBuilderExt, ODataNextLink, ODataQuery,
-    RequestComponents,
+    ensure_client_request_id, merge_default_headers, record_response, request_span, BodyRead,
+    BytesRequestExt, Client, ExportFormat, FileConfig, GraphClientConfiguration,
+    HttpResponseBuilderExt, Next, ODataDownloadLink, ODataNextLink, ODataQuery, PagingCursor,
+    RenderedRequest, RequestComponents, RequestIds, ResponseExt, ThrottleInfo, TypedResponse,
+    ValueArrayDecoder, EXPORT_CONCURRENCY,
 };
 use async_stream::try_stream;
-use futures::Stream;
-use graph_error::{AuthExecutionResult, ErrorMessage, GraphFailure, GraphResult};
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use graph_core::http::inject_trace_context;
+use graph_core::identity::ForceTokenRefresh;
+use graph_core::metrics::{record_request, record_retry};
+use graph_core::resource::ResourceIdentity;
+use graph_error::download::AsyncDownloadError;
+use graph_error::io_error::AsyncIoError;
+use graph_error::{AuthExecutionResult, ErrorMessage, ErrorType, GraphFailure, GraphResult};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
 use std::collections::VecDeque;
 use std::fmt::Debug;
-use std::time::Duration;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tracing::Instrument;
 use url::Url;
 
+/// Number of times a new `@microsoft.graph.downloadUrl` is requested when the
+/// previous one has expired before the download completed.
+const DOWNLOAD_URL_MAX_REFRESH: usize = 3;
+
 #[derive(Default)]
 pub struct RequestHandler {
     pub(crate) inner: Client,
@@ -20,6 +38,8 @@ pub struct RequestHandler {
     pub(crate) error: Option<GraphFailure>,
     pub(crate) body: Option<BodyRead>,
     pub(crate) client_builder: GraphClientConfiguration,
+    pub(crate) max_retries: usize,
+    pub(crate) idempotent: bool,
 }
 
 impl RequestHandler {
@@ -30,9 +50,10 @@ impl RequestHandler {
         body: Option<BodyRead>,
     ) -> RequestHandler {
         let client_builder = inner.builder.clone();
-        let mut original_headers = inner.headers.clone();
-        original_headers.extend(request_components.headers.clone());
-        request_components.headers = original_headers;
+        request_components.headers = merge_default_headers(
+            &inner.headers,
+            std::mem::take(&mut request_components.headers),
+        );
 
         let mut error = None;
         if let Some(err) = err {
@@ -51,6 +72,8 @@ impl RequestHandler {
             error,
             body,
             client_builder,
+            max_retries: 0,
+            idempotent: false,
         }
     }
 
@@ -149,8 +172,58 @@ impl RequestHandler {
         self.request_components.as_mut()
     }
 
+    /// Set the token refresh policy for this request only. The policy of the client
+    /// used for other requests is not changed.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .me()
+    ///     .get_user()
+    ///     .force_token_refresh(ForceTokenRefresh::Once)
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn force_token_refresh(mut self, force_token_refresh: ForceTokenRefresh) -> Self {
+        self.inner
+            .client_application
+            .with_force_token_refresh(force_token_refresh);
+        self
+    }
+
+    /// Send the request again after the Retry-After duration when the response is
+    /// throttled (429 or 503), up to `max_retries` times. The `client-request-id` of
+    /// the request is the same for each attempt.
+    ///
+    /// A POST is only sent again when the request is marked as [idempotent](RequestHandler::idempotent),
+    /// and a request with a streamed body is never sent again.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .users()
+    ///     .list_user()
+    ///     .retry_throttled(3)
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn retry_throttled(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Mark the request as safe to send more than once, allowing a POST to be
+    /// retried by [retry_throttled](RequestHandler::retry_throttled). Only use for
+    /// actions where the body has an idempotency identifier, such as the externalId
+    /// of onlineMeetings createOrGet, so that a retried request does not create
+    /// a second resource.
+    pub fn idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
     pub fn paging(self) -> Paging {
-        Paging(self)
+        Paging::new(self)
     }
 
     pub(crate) async fn default_request_builder_with_token(
@@ -161,6 +234,10 @@ impl RequestHandler {
             .client_application
             .get_token_silent_async()
             .await?;
+        if self.inner.validate_permissions() {
+            self.request_components
+                .warn_missing_permissions(access_token.as_str());
+        }
 
         let request_builder = self
             .inner
@@ -193,6 +270,10 @@ impl RequestHandler {
             .client_application
             .get_token_silent_async()
             .await?;
+        if self.inner.validate_permissions() {
+            self.request_components
+                .warn_missing_permissions(access_token.as_str());
+        }
 
         let request_builder = self
             .inner
@@ -225,13 +306,241 @@ impl RequestHandler {
         self.default_request_builder().await
     }
 
+    /// Builds the request and returns it as an [`http::Request<Bytes>`] to use with
+    /// libraries built on the `http` crate, such as tower middleware. Fails when the
+    /// body of the request is a stream.
+    pub async fn into_http_request(self) -> GraphResult<http::Request<Bytes>> {
+        self.build().await?.build()?.try_into_bytes_request()
+    }
+
+    /// Render the method, url, headers, and body that [send](RequestHandler::send)
+    /// would send, including the `client-request-id` header, without sending the
+    /// request. Use to log what would be sent, print an equivalent curl command, or
+    /// send the request with another transport. Fails when the body of the request
+    /// is a stream.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let request = client.users().list_user().top("5").build_request().await?;
+    /// println!("{}", request.to_curl());
+    /// ```
+    pub async fn build_request(mut self) -> GraphResult<RenderedRequest> {
+        ensure_client_request_id(&mut self.request_components.headers);
+        RenderedRequest::from_http_request(self.into_http_request().await?)
+    }
+
+    /// A copy of the request that can be sent separately, or `None` when the request
+    /// has an error or the body is a stream that can only be read once.
+    pub fn try_clone(&self) -> Option<RequestHandler> {
+        if self.error.is_some() {
+            return None;
+        }
+        let body = match self.body.as_ref() {
+            Some(body) => Some(body.try_clone()?),
+            None => None,
+        };
+
+        Some(RequestHandler {
+            inner: self.inner.clone(),
+            request_components: self.request_components.clone(),
+            error: None,
+            body,
+            client_builder: self.client_builder.clone(),
+            max_retries: self.max_retries,
+            idempotent: self.idempotent,
+        })
+    }
+
+    /// Send the request. A `client-request-id` header is added unless one was set,
+    /// and the request is sent in a `graph_request` tracing span with the
+    /// `client_request_id` and, once the response is received, the `request_id`
+    /// and `status` fields.
     #[inline]
-    pub async fn send(self) -> GraphResult<reqwest::Response> {
-        let request_builder = self.build().await?;
-        request_builder.send().await.map_err(GraphFailure::from)
+    pub async fn send(mut self) -> GraphResult<reqwest::Response> {
+        let client_request_id = ensure_client_request_id(&mut self.request_components.headers);
+        let span = request_span(
+            &self.request_components.method,
+            &self.request_components.url,
+            &client_request_id,
+        );
+        async move {
+            if let Some(err) = self.error.take() {
+                return Err(err);
+            }
+            inject_trace_context(&mut self.request_components.headers);
+            let mut request = self.default_request_builder().await?.build()?;
+            let max_retries = if is_retry_safe(&self.request_components.method, self.idempotent) {
+                self.max_retries
+            } else {
+                0
+            };
+            let mut retries = 0;
+            let response = loop {
+                let retry_request = request.try_clone();
+                let start = Instant::now();
+                let response = Next::new(&self.inner).run(request).await;
+                record_request(
+                    &self.request_components.method,
+                    self.request_components.resource_identity.as_ref(),
+                    response.as_ref().ok().map(reqwest::Response::status),
+                    start.elapsed(),
+                );
+                let response = response?;
+                match (
+                    throttle_retry_after(response.status(), response.headers()),
+                    retry_request,
+                ) {
+                    (Some(retry_after), Some(retry_request)) if retries < max_retries => {
+                        retries += 1;
+                        record_retry(self.request_components.resource_identity.as_ref());
+                        tokio::time::sleep(retry_after).await;
+                        request = retry_request;
+                    }
+                    _ => break response,
+                }
+            };
+            record_response(
+                &tracing::Span::current(),
+                response.status(),
+                response.headers(),
+            );
+            Ok(response)
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Send the request and return a [`TypedResponse`] distinguishing 201 Created,
+    /// 202 Accepted, and 204 No Content responses. Error responses are returned as
+    /// a [`GraphFailure`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .user(USER_ID)
+    ///     .update_user(&body)
+    ///     .send_typed()
+    ///     .await?;
+    ///
+    /// assert!(matches!(response, TypedResponse::NoContent(_)));
+    /// ```
+    pub async fn send_typed(self) -> GraphResult<TypedResponse> {
+        let client = self.inner.clone();
+        let response = self.send().await?;
+        TypedResponse::from_response(response, client).await
+    }
+
+    async fn download_url(&mut self) -> GraphResult<String> {
+        let response = self.default_request_builder().await?.send().await?;
+
+        if !response.status().is_success() {
+            return Err(GraphFailure::from(
+                response.into_graph_error_message().await?,
+            ));
+        }
+
+        let body: serde_json::Value = response.json().await?;
+        body.odata_download_link().ok_or_else(|| {
+            GraphFailure::not_found("No @microsoft.graph.downloadUrl found in response body")
+        })
+    }
+
+    /// Download the content of a drive item using its pre-authenticated
+    /// `@microsoft.graph.downloadUrl`.
+    ///
+    /// The request must be a GET for a drive item such as `get_items`. The item is requested
+    /// with `$select=id,content.downloadUrl` and the content is then downloaded directly from
+    /// the returned url without an Authorization header, which offloads large downloads from
+    /// the Microsoft Graph endpoints and reduces the chance of being throttled.
+    ///
+    /// The download url is only valid for a short period of time. If the url has expired
+    /// before or during the download a new url is requested and the download is restarted.
+    /// The content is always downloaded to a temporary file, see [`FileConfig::temp_file`],
+    /// so that a restarted download does not leave a partially written file.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .me()
+    ///     .drive()
+    ///     .item(ITEM_ID)
+    ///     .get_items()
+    ///     .download_with_download_url(&FileConfig::new(DOWNLOAD_DIRECTORY))
+    ///     .await?;
+    ///
+    /// println!("{:#?}", response.body());
+    /// ```
+    pub async fn download_with_download_url(
+        mut self,
+        file_config: &FileConfig,
+    ) -> GraphResult<http::Response<PathBuf>> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        select_download_url(&mut self.request_components.url);
+
+        // The download url is pre-authenticated so the request is sent
+        // without the bearer token.
+        let client = self.inner.inner.clone();
+        let file_config = file_config.clone().temp_file(true);
+        let mut refresh_count = 0;
+
+        loop {
+            let download_url = self.download_url().await?;
+            let response = client.get(download_url).send().await?;
+            let status = response.status();
+
+            if matches!(
+                status,
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN | StatusCode::GONE
+            ) && refresh_count < DOWNLOAD_URL_MAX_REFRESH
+            {
+                refresh_count += 1;
+                continue;
+            }
+
+            if !status.is_success() {
+                return Err(GraphFailure::from(
+                    response.into_graph_error_message().await?,
+                ));
+            }
+
+            match response.download(&file_config).await {
+                Err(AsyncDownloadError::Io(AsyncIoError::ResponseStream(_)))
+                    if refresh_count < DOWNLOAD_URL_MAX_REFRESH =>
+                {
+                    refresh_count += 1;
+                }
+                result => return result.map_err(GraphFailure::from),
+            }
+        }
     }
 }
 
+// Adds the fields needed to download a drive item to the $select query of the
+// url, merging them into an existing $select so that it is only sent once.
+fn select_download_url(url: &mut Url) {
+    const FIELDS: [&str; 2] = ["id", "content.downloadUrl"];
+
+    let mut query: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    match query.iter_mut().find(|(key, _)| key == "$select") {
+        Some((_, select)) => {
+            for field in FIELDS {
+                if !select.split(',').any(|selected| selected.trim() == field) {
+                    if !select.is_empty() {
+                        select.push(',');
+                    }
+                    select.push_str(field);
+                }
+            }
+        }
+        None => query.push(("$select".to_owned(), FIELDS.join(","))),
+    }
+
+    url.query_pairs_mut().clear().extend_pairs(query);
+}
+
 impl ODataQuery for RequestHandler {
     fn append_query_pair<KV: AsRef<str>>(self, key: KV, value: KV) -> Self {
         self.append_query_pair(key.as_ref(), value.as_ref())
@@ -253,9 +562,282 @@ impl AsMut<Url> for RequestHandler {
 pub type PagingResponse<T> = http::Response<Result<T, ErrorMessage>>;
 pub type PagingResult<T> = GraphResult<PagingResponse<T>>;
 
-pub struct Paging(RequestHandler);
+/// Number of times a page request is retried after being throttled.
+pub(crate) const PAGING_MAX_THROTTLE_RETRIES: usize = 5;
+
+/// Wait used when a throttled response does not include a Retry-After header.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(5);
+
+/// Returns the duration to wait before retrying a request when the response is
+/// 429 Too Many Requests or 503 Service Unavailable.
+pub fn throttle_retry_after(status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+    if !ErrorType::is_throttled(status.as_u16()) {
+        return None;
+    }
+
+    Some(retry_after(headers).unwrap_or(DEFAULT_RETRY_AFTER))
+}
+
+/// Whether a request can be sent again without changing the result: methods that
+/// are idempotent in HTTP, or any request marked as idempotent.
+pub(crate) fn is_retry_safe(method: &Method, idempotent: bool) -> bool {
+    idempotent
+        || matches!(
+            *method,
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+        )
+}
+
+/// Returns the duration in seconds given in the Retry-After header.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum PagingEvent<T> {
+    /// A page of the response.
+    Response(PagingResponse<T>),
+    /// A page request was throttled. The same page is requested again once the
+    /// duration has elapsed.
+    Throttled(Duration),
+}
+
+/// How paging handles throttled responses.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct ThrottlePolicy {
+    pub(crate) max_retries: usize,
+    pub(crate) pace_requests: bool,
+    /// The resource of the paged requests, used to label request metrics.
+    pub(crate) resource_identity: ResourceIdentity,
+}
+
+impl ThrottlePolicy {
+    /// The duration to wait before requesting the next page.
+    pub(crate) fn pacing_delay(&self, headers: &HeaderMap) -> Option<Duration> {
+        if self.pace_requests {
+            ThrottleInfo::from_headers(headers).pacing_delay()
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for ThrottlePolicy {
+    fn default() -> Self {
+        ThrottlePolicy {
+            max_retries: PAGING_MAX_THROTTLE_RETRIES,
+            pace_requests: false,
+            resource_identity: Default::default(),
+        }
+    }
+}
+
+pub struct Paging {
+    request_handler: RequestHandler,
+    throttle_policy: ThrottlePolicy,
+    read_timeout: Option<Duration>,
+    skip_pages: usize,
+    take_pages: Option<usize>,
+}
 
 impl Paging {
+    pub(crate) fn new(request_handler: RequestHandler) -> Paging {
+        Paging {
+            throttle_policy: ThrottlePolicy {
+                resource_identity: request_handler.request_components.resource_identity,
+                ..Default::default()
+            },
+            request_handler,
+            read_timeout: None,
+            skip_pages: 0,
+            take_pages: None,
+        }
+    }
+
+    /// Set the number of times a page request is retried when the response is
+    /// throttled (429 or 503). The request is retried after the duration given in
+    /// the Retry-After header and paging resumes from the same next link.
+    ///
+    /// Defaults to 5. Use 0 to return throttled responses without retrying.
+    pub fn max_throttle_retries(mut self, max_throttle_retries: usize) -> Self {
+        self.throttle_policy.max_retries = max_throttle_retries;
+        self
+    }
+
+    /// Wait for the rate limit window to reset before requesting the next page
+    /// when a response reports that no requests remain in the current window.
+    /// See [`ThrottleInfo`].
+    ///
+    /// Default is `false`.
+    pub fn pace_requests(mut self, pace_requests: bool) -> Self {
+        self.throttle_policy.pace_requests = pace_requests;
+        self
+    }
+
+    /// Return an error from [`Paging::stream_items`] when no bytes of the response
+    /// body are received within the duration, instead of waiting on a stalled
+    /// connection.
+    ///
+    /// There is no read timeout by default.
+    pub fn read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.read_timeout = Some(read_timeout);
+        self
+    }
+
+    /// Skip the first `skip_pages` pages. The skipped pages are requested to get the
+    /// next link of the page that follows them but are not returned.
+    ///
+    /// Together with [`Paging::take_pages`] a window of pages can be requested, such as
+    /// for a UI that shows one page at a time. Applies to [`Paging::json`],
+    /// [`Paging::stream`], [`Paging::stream_events`] and [`Paging::export`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // The third and fourth page of 25 users.
+    /// let pages = client
+    ///     .users()
+    ///     .list_user()
+    ///     .top("25")
+    ///     .paging()
+    ///     .skip_pages(2)
+    ///     .take_pages(2)
+    ///     .json::<serde_json::Value>()
+    ///     .await?;
+    ///
+    /// // Resume after the window using the skip token of the last page.
+    /// let skip_token = pages.back().and_then(|page| page.body().as_ref().ok()?.odata_skip_token());
+    /// ```
+    pub fn skip_pages(mut self, skip_pages: usize) -> Self {
+        self.skip_pages = skip_pages;
+        self
+    }
+
+    /// Return at most `take_pages` pages after any skipped pages. No more pages are
+    /// requested once the pages are returned. See [`Paging::skip_pages`].
+    pub fn take_pages(mut self, take_pages: usize) -> Self {
+        self.take_pages = Some(take_pages);
+        self
+    }
+
+    /// Start from the next link, or the delta link when all pages were returned, of
+    /// a [`PagingCursor`] saved from an earlier enumeration of the same request.
+    ///
+    /// Requests fail when the cursor has no link or the host of the link is not
+    /// the host of the request.
+    pub fn resume_from(mut self, cursor: &PagingCursor) -> Self {
+        match cursor.resume_request_url(&self.request_handler.request_components.url) {
+            Ok(url) => self.request_handler.request_components.url = url,
+            Err(err) => {
+                self.request_handler.error.get_or_insert(err);
+            }
+        }
+        self
+    }
+
+    /// Whether the page at the index, counting skipped pages, is after the window
+    /// given by [`Paging::skip_pages`] and [`Paging::take_pages`].
+    fn is_after_window(&self, page: usize) -> bool {
+        self.take_pages
+            .is_some_and(|take_pages| page >= self.skip_pages.saturating_add(take_pages))
+    }
+
+    /// The next link of a page that is skipped.
+    async fn skipped_page_next_link(response: reqwest::Response) -> GraphResult<Option<String>> {
+        let response = Paging::success_response(response).await?;
+        let body: serde_json::Value = response.json().await?;
+        Ok(body.odata_next_link())
+    }
+
+    /// Send the request once in a `graph_request` tracing span and record the
+    /// request metrics. The request is sent through the same layers as
+    /// [`RequestHandler::send`].
+    async fn execute(
+        client: &Client,
+        request: reqwest::RequestBuilder,
+        resource_identity: ResourceIdentity,
+    ) -> GraphResult<reqwest::Response> {
+        let mut request = request.build()?;
+        let client_request_id = ensure_client_request_id(request.headers_mut());
+        let span = request_span(request.method(), request.url(), &client_request_id);
+
+        async move {
+            inject_trace_context(request.headers_mut());
+            let method = request.method().clone();
+            let start = Instant::now();
+            let response = Next::new(client).run(request).await;
+            record_request(
+                &method,
+                resource_identity.as_ref(),
+                response.as_ref().ok().map(reqwest::Response::status),
+                start.elapsed(),
+            );
+            let response = response?;
+            record_response(
+                &tracing::Span::current(),
+                response.status(),
+                response.headers(),
+            );
+            Ok(response)
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Send the request, retrying the same request while the response is throttled.
+    /// The request is sent after waiting for `pacing` when given.
+    async fn send_page(
+        client: &Client,
+        request: reqwest::RequestBuilder,
+        throttle_policy: ThrottlePolicy,
+        pacing: Option<Duration>,
+    ) -> GraphResult<reqwest::Response> {
+        if let Some(pacing) = pacing {
+            tokio::time::sleep(pacing).await;
+        }
+
+        let mut request = request;
+        let mut retries = 0;
+        loop {
+            let retry_request = request.try_clone();
+            let response =
+                Paging::execute(client, request, throttle_policy.resource_identity).await?;
+            match (
+                throttle_retry_after(response.status(), response.headers()),
+                retry_request,
+            ) {
+                (Some(retry_after), Some(retry_request))
+                    if retries < throttle_policy.max_retries =>
+                {
+                    retries += 1;
+                    record_retry(throttle_policy.resource_identity.as_ref());
+                    tokio::time::sleep(retry_after).await;
+                    request = retry_request;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+
+    /// Returns the response when the status is successful. Otherwise returns
+    /// the error message in the body of the response as a [GraphFailure].
+    pub async fn success_response(response: reqwest::Response) -> GraphResult<reqwest::Response> {
+        if response.status().is_success() {
+            return Ok(response);
+        }
+        // Responses without a Graph error body, such as an empty 503 Service Unavailable,
+        // keep the status code so the error can still be classified.
+        let status_error = response.error_for_status_ref().err();
+        match response.into_graph_error_message().await {
+            Ok(message) => Err(GraphFailure::from(message)),
+            Err(err) => Err(GraphFailure::from(status_error.unwrap_or(err))),
+        }
+    }
+
     async fn http_response<T: DeserializeOwned>(
         response: reqwest::Response,
     ) -> GraphResult<(Option<String>, PagingResponse<T>)> {
@@ -327,30 +909,44 @@ impl Paging {
     ///
     /// ```
     pub async fn json<T: DeserializeOwned>(mut self) -> GraphResult<VecDeque<PagingResponse<T>>> {
-        if let Some(err) = self.0.error {
+        if let Some(err) = self.request_handler.error {
             return Err(err);
         }
 
-        let (access_token, request) = self.0.default_request_builder_with_token().await?;
-        let response = request.send().await?;
-
-        let (next, http_response) = Paging::http_response(response).await?;
-        let mut next_link = next;
+        let (access_token, request) = self
+            .request_handler
+            .default_request_builder_with_token()
+            .await?;
+        let throttle_policy = self.throttle_policy;
+        let client = self.request_handler.inner.inner.clone();
+        let mut next_request = Some(request);
+        let mut pacing = None;
+        let mut page = 0;
         let mut vec = VecDeque::new();
-        vec.push_back(http_response);
-
-        let client = self.0.inner.inner.clone();
-        while let Some(next) = next_link {
-            let response = client
-                .get(next)
-                .bearer_auth(access_token.as_str())
-                .send()
-                .await?;
 
-            let (next, http_response) = Paging::http_response(response).await?;
+        while let Some(request) = next_request.take() {
+            if self.is_after_window(page) {
+                break;
+            }
+
+            let response = Paging::send_page(
+                &self.request_handler.inner,
+                request,
+                throttle_policy,
+                pacing,
+            )
+            .await?;
+            pacing = throttle_policy.pacing_delay(response.headers());
+            let next_link = if page < self.skip_pages {
+                Paging::skipped_page_next_link(response).await?
+            } else {
+                let (next, http_response) = Paging::http_response(response).await?;
+                vec.push_back(http_response);
+                next
+            };
 
-            next_link = next;
-            vec.push_back(http_response);
+            page += 1;
+            next_request = next_link.map(|url| client.get(url).bearer_auth(access_token.as_str()));
         }
 
         Ok(vec)
@@ -358,25 +954,66 @@ impl Paging {
 
     fn try_stream<'a, T: DeserializeOwned + 'a>(
         mut self,
-    ) -> impl Stream<Item = PagingResult<T>> + 'a {
+    ) -> impl Stream<Item = GraphResult<PagingEvent<T>>> + 'a {
         try_stream! {
-            let (access_token, request) = self.0.default_request_builder_with_token().await?;
-            let response = request.send().await?;
-            let (next, http_response) = Paging::http_response(response).await?;
-            let mut next_link = next;
-            yield http_response;
-
-            while let Some(url) = next_link {
-                let response = self.0
-                    .inner
-                    .inner
-                    .get(url)
-                    .bearer_auth(access_token.as_str())
-                    .send()
-                    .await?;
+            let (access_token, request) = self.request_handler.default_request_builder_with_token().await?;
+            let mut next_request = Some(request);
+            let mut pacing = None;
+            let mut retries = 0;
+            let mut page = 0;
+
+            while let Some(request) = next_request.take() {
+                if self.is_after_window(page) {
+                    break;
+                }
+
+                if let Some(pacing) = pacing.take() {
+                    tokio::time::sleep(pacing).await;
+                }
+
+                let retry_request = request.try_clone();
+                let response = Paging::execute(
+                    &self.request_handler.inner,
+                    request,
+                    self.throttle_policy.resource_identity,
+                )
+                .await?;
+
+                if let Some(retry_after) = throttle_retry_after(response.status(), response.headers()) {
+                    if retries < self.throttle_policy.max_retries && retry_request.is_some() {
+                        retries += 1;
+                        record_retry(self.throttle_policy.resource_identity.as_ref());
+                        yield PagingEvent::Throttled(retry_after);
+                        tokio::time::sleep(retry_after).await;
+                        next_request = retry_request;
+                        continue;
+                    }
+                }
+
+                retries = 0;
+                pacing = self.throttle_policy.pacing_delay(response.headers());
+                if page < self.skip_pages {
+                    page += 1;
+                    next_request = Paging::skipped_page_next_link(response).await?.map(|url| {
+                        self.request_handler
+                            .inner
+                            .inner
+                            .get(url)
+                            .bearer_auth(access_token.as_str())
+                    });
+                    continue;
+                }
+
+                page += 1;
                 let (next, http_response) = Paging::http_response(response).await?;
-                next_link = next;
-                yield http_response;
+                next_request = next.map(|url| {
+                    self.request_handler
+                        .inner
+                        .inner
+                        .get(url)
+                        .bearer_auth(access_token.as_str())
+                });
+                yield PagingEvent::Response(http_response);
             }
         }
     }
@@ -384,6 +1021,10 @@ impl Paging {
     /// Stream the current request along with any next link requests from the response body.
     /// Each stream.next() returns a [`GraphResult<http::Response<T>>`].
     ///
+    /// Throttled requests are retried after the duration given in the Retry-After header
+    /// and the stream resumes from the same next link. Use [`Paging::stream_events`]
+    /// to be notified when a request is throttled.
+    ///
     /// # Example
     /// ```rust,ignore
     /// let mut stream = client
@@ -397,16 +1038,203 @@ impl Paging {
     ///     println!("{result:#?}");
     ///  }
     /// ```
+    #[allow(clippy::result_large_err)]
     pub fn stream<'a, T: DeserializeOwned + 'a>(
-        mut self,
+        self,
     ) -> GraphResult<impl Stream<Item = PagingResult<T>> + 'a> {
-        if let Some(err) = self.0.error.take() {
+        let stream = self.stream_events()?.filter_map(|event| async move {
+            match event {
+                Ok(PagingEvent::Response(response)) => Some(Ok(response)),
+                Ok(PagingEvent::Throttled(_)) => None,
+                Err(err) => Some(Err(err)),
+            }
+        });
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Stream the current request along with any next link requests from the response body
+    /// as [`PagingEvent`]s. A [`PagingEvent::Throttled`] is returned before waiting on a
+    /// throttled request to be retried.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut stream = client
+    ///     .users()
+    ///     .delta()
+    ///     .paging()
+    ///     .stream_events::<serde_json::Value>()
+    ///     .unwrap();
+    ///
+    ///  while let Some(result) = stream.next().await {
+    ///     match result? {
+    ///         PagingEvent::Response(response) => println!("{response:#?}"),
+    ///         PagingEvent::Throttled(retry_after) => println!("throttled for {retry_after:?}"),
+    ///     }
+    ///  }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn stream_events<'a, T: DeserializeOwned + 'a>(
+        mut self,
+    ) -> GraphResult<impl Stream<Item = GraphResult<PagingEvent<T>>> + 'a> {
+        if let Some(err) = self.request_handler.error.take() {
             return Err(err);
         }
 
         Ok(Box::pin(self.try_stream()))
     }
 
+    /// Stream the items of the `value` array of the current request along with any
+    /// next link requests.
+    ///
+    /// Each item is deserialized as soon as its bytes are received rather than after
+    /// the whole page has been read, so only one item at a time is held in memory.
+    /// This keeps memory use low for large pages such as directory exports using
+    /// `top(999)` with many selected fields.
+    ///
+    /// Throttled requests are retried in the same way as [`Paging::stream`].
+    /// Use [`Paging::read_timeout`] to fail the stream when the connection stalls.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut stream = client
+    ///     .users()
+    ///     .list_user()
+    ///     .top("999")
+    ///     .paging()
+    ///     .read_timeout(Duration::from_secs(30))
+    ///     .stream_items::<serde_json::Value>()
+    ///     .unwrap();
+    ///
+    ///  while let Some(result) = stream.next().await {
+    ///     println!("{:#?}", result?);
+    ///  }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn stream_items<'a, T: DeserializeOwned + 'a>(
+        mut self,
+    ) -> GraphResult<impl Stream<Item = GraphResult<T>> + 'a> {
+        if let Some(err) = self.request_handler.error.take() {
+            return Err(err);
+        }
+
+        let stream = try_stream! {
+            let (access_token, request) = self.request_handler.default_request_builder_with_token().await?;
+            let client = self.request_handler.inner.inner.clone();
+            let throttle_policy = self.throttle_policy;
+            let mut next_request = Some(request);
+            let mut pacing = None;
+
+            while let Some(request) = next_request.take() {
+                let response = Paging::send_page(
+                    &self.request_handler.inner,
+                    request,
+                    throttle_policy,
+                    pacing,
+                )
+                .await?;
+                pacing = throttle_policy.pacing_delay(response.headers());
+                let response = Paging::success_response(response).await?;
+
+                let mut decoder = ValueArrayDecoder::default();
+                let mut body = response.bytes_stream();
+                loop {
+                    let chunk = match self.read_timeout {
+                        Some(read_timeout) => tokio::time::timeout(read_timeout, body.next())
+                            .await
+                            .map_err(|_| {
+                                GraphFailure::error_kind(
+                                    std::io::ErrorKind::TimedOut,
+                                    "timed out reading response body",
+                                )
+                            })?,
+                        None => body.next().await,
+                    };
+
+                    match chunk {
+                        Some(chunk) => {
+                            for item in decoder.feed(&chunk?) {
+                                yield serde_json::from_slice::<T>(&item)?;
+                            }
+                        }
+                        None => break,
+                    }
+                }
+
+                next_request = decoder
+                    .finish()?
+                    .odata_next_link()
+                    .map(|url| client.get(url).bearer_auth(access_token.as_str()));
+            }
+        };
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Write the items of the current request along with any next link requests to
+    /// `writer` as JSON Lines or CSV. Returns the number of items written.
+    ///
+    /// Pages are requested and encoded concurrently with writing earlier pages,
+    /// while the items are written in the order they are returned. Only a few pages
+    /// are held in memory at a time so collections with millions of items, such as
+    /// a tenant wide export of users, can be written to a file.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut file = tokio::fs::File::create("./users.csv").await?;
+    ///
+    /// let count = client
+    ///     .users()
+    ///     .list_user()
+    ///     .select(&["id", "displayName", "userPrincipalName"])
+    ///     .top("999")
+    ///     .paging()
+    ///     .export(
+    ///         &mut file,
+    ///         ExportFormat::csv(["id", "displayName", "userPrincipalName"]),
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn export<W: AsyncWrite + Unpin>(
+        self,
+        writer: &mut W,
+        format: ExportFormat,
+    ) -> GraphResult<u64> {
+        let pages = self.stream::<serde_json::Value>()?.map(|result| {
+            let format = format.clone();
+            async move {
+                let response = result?;
+                let status = response.status();
+                let request_ids = RequestIds::from_headers(response.headers());
+                let body = response.into_body().map_err(GraphFailure::from)?;
+                if !status.is_success() {
+                    let mut message = serde_json::from_value::<ErrorMessage>(body)?;
+                    request_ids.set_error_message_ids(&mut message);
+                    return Err(GraphFailure::from(message));
+                }
+
+                tokio::task::spawn_blocking(move || format.encode_page(&body))
+                    .await
+                    .map_err(|err| {
+                        GraphFailure::error_kind(std::io::ErrorKind::Other, &err.to_string())
+                    })?
+                    .map_err(GraphFailure::from)
+            }
+        });
+        let mut pages = Box::pin(pages.buffered(EXPORT_CONCURRENCY));
+
+        writer.write_all(&format.header()).await?;
+        let mut count = 0;
+        while let Some(page) = pages.next().await {
+            let (bytes, rows) = page?;
+            writer.write_all(&bytes).await?;
+            count += rows;
+        }
+        writer.flush().await?;
+
+        Ok(count)
+    }
+
     /// Get next link responses using a channel Receiver [`tokio::sync::mpsc::Receiver<Option<GraphResult<http::Response<T>>>>`].
     ///
     /// By default channels use [`tokio::sync::mpsc::Sender::send_timeout`] with a buffer of 100
@@ -472,11 +1300,14 @@ impl Paging {
     }
 
     async fn send_channel_request<T: DeserializeOwned>(
-        client: &reqwest::Client,
+        client: &Client,
         url: &str,
         access_token: &str,
+        throttle_policy: ThrottlePolicy,
+        pacing: Option<Duration>,
     ) -> GraphResult<(Option<String>, PagingResponse<T>)> {
-        let response = client.get(url).bearer_auth(access_token).send().await?;
+        let request = client.inner.get(url).bearer_auth(access_token);
+        let response = Paging::send_page(client, request, throttle_policy, pacing).await?;
 
         Paging::http_response(response).await
     }
@@ -515,25 +1346,37 @@ impl Paging {
     ) -> GraphResult<tokio::sync::mpsc::Receiver<PagingResult<T>>> {
         let (sender, receiver) = tokio::sync::mpsc::channel(buffer);
 
-        let (access_token, request) = self.0.default_request_builder_with_token().await?;
-        let response = request.send().await?;
+        let (access_token, request) = self
+            .request_handler
+            .default_request_builder_with_token()
+            .await?;
+        let throttle_policy = self.throttle_policy;
+        let response =
+            Paging::send_page(&self.request_handler.inner, request, throttle_policy, None).await?;
         let (next, http_response) = Paging::http_response(response).await?;
         let mut next_link = next;
+        let mut pacing = throttle_policy.pacing_delay(http_response.headers());
         sender
             .send_timeout(Ok(http_response), timeout)
             .await
             .unwrap();
 
-        let client = self.0.inner.inner.clone();
+        let client = self.request_handler.inner.clone();
         tokio::spawn(async move {
             while let Some(next) = next_link {
-                let result =
-                    Paging::send_channel_request(&client, next.as_str(), access_token.as_str())
-                        .await;
+                let result = Paging::send_channel_request(
+                    &client,
+                    next.as_str(),
+                    access_token.as_str(),
+                    throttle_policy,
+                    pacing.take(),
+                )
+                .await;
 
                 match result {
                     Ok((next, response)) => {
                         next_link = next;
+                        pacing = throttle_policy.pacing_delay(response.headers());
                         sender.send_timeout(Ok(response), timeout).await.unwrap();
                     }
                     Err(err) => {
@@ -548,3 +1391,163 @@ impl Paging {
         Ok(receiver)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn response(status: u16, body: &str) -> reqwest::Response {
+        reqwest::Response::from(
+            http::Response::builder()
+                .status(status)
+                .body(body.to_owned())
+                .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn success_response_errors() {
+        let error = Paging::success_response(response(
+            429,
+            r#"{"error":{"code":"TooManyRequests","message":"Too many requests"}}"#,
+        ))
+        .await
+        .unwrap_err();
+        assert!(matches!(error, GraphFailure::ErrorMessage(_)));
+        assert!(error.is_throttled());
+
+        let error = Paging::success_response(response(503, ""))
+            .await
+            .unwrap_err();
+        assert_eq!(Some(StatusCode::SERVICE_UNAVAILABLE), error.status());
+        assert!(error.is_throttled());
+    }
+
+    #[test]
+    fn select_download_url_merges_select() {
+        let mut url = Url::parse("https://graph.microsoft.com/v1.0/me/drive/items/1").unwrap();
+        select_download_url(&mut url);
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/me/drive/items/1?%24select=id%2Ccontent.downloadUrl",
+            url.as_str()
+        );
+
+        let mut url = Url::parse(
+            "https://graph.microsoft.com/v1.0/me/drive/items/1?$select=name,id&$expand=thumbnails",
+        )
+        .unwrap();
+        select_download_url(&mut url);
+        assert_eq!(
+            vec![
+                (
+                    "$select".to_owned(),
+                    "name,id,content.downloadUrl".to_owned()
+                ),
+                ("$expand".to_owned(), "thumbnails".to_owned()),
+            ],
+            url.query_pairs().into_owned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn throttle_retry_after_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("12"));
+
+        assert_eq!(
+            Some(Duration::from_secs(12)),
+            throttle_retry_after(StatusCode::TOO_MANY_REQUESTS, &headers)
+        );
+        assert_eq!(
+            Some(Duration::from_secs(12)),
+            throttle_retry_after(StatusCode::SERVICE_UNAVAILABLE, &headers)
+        );
+        assert_eq!(None, throttle_retry_after(StatusCode::OK, &headers));
+    }
+
+    #[test]
+    fn retry_safe_methods() {
+        assert!(is_retry_safe(&Method::GET, false));
+        assert!(is_retry_safe(&Method::DELETE, false));
+        assert!(!is_retry_safe(&Method::POST, false));
+        assert!(!is_retry_safe(&Method::PATCH, false));
+        assert!(is_retry_safe(&Method::POST, true));
+    }
+
+    #[test]
+    fn throttle_retry_after_default() {
+        assert_eq!(
+            Some(DEFAULT_RETRY_AFTER),
+            throttle_retry_after(StatusCode::TOO_MANY_REQUESTS, &HeaderMap::new())
+        );
+    }
+
+    #[test]
+    fn paging_window() {
+        let paging = Paging::new(RequestHandler::default())
+            .skip_pages(2)
+            .take_pages(3);
+        assert!(!paging.is_after_window(0));
+        assert!(!paging.is_after_window(4));
+        assert!(paging.is_after_window(5));
+        assert!(!Paging::new(RequestHandler::default()).is_after_window(usize::MAX));
+    }
+
+    struct Pages(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+    #[async_trait::async_trait]
+    impl crate::internal::RequestLayer for Pages {
+        async fn handle(
+            &self,
+            request: reqwest::Request,
+            _next: Next<'_>,
+        ) -> GraphResult<reqwest::Response> {
+            let url = request.url().to_string();
+            let body = if url.ends_with("page=2") {
+                serde_json::json!({ "value": [2] })
+            } else {
+                serde_json::json!({
+                    "value": [1],
+                    "@odata.nextLink": format!("{url}?page=2")
+                })
+            };
+            self.0.lock().unwrap().push(url);
+            Ok(reqwest::Response::from(
+                http::Response::builder().body(body.to_string()).unwrap(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn pages_sent_through_layers() {
+        let urls = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let client = GraphClientConfiguration::new()
+            .access_token("token")
+            .with_layer(Pages(urls.clone()))
+            .build();
+        let request_handler = RequestHandler::new(
+            client,
+            RequestComponents::new(
+                ResourceIdentity::default(),
+                Url::parse("https://graph.microsoft.com/v1.0/me/messages").unwrap(),
+                Method::GET,
+            ),
+            None,
+            None,
+        );
+
+        let pages = request_handler
+            .paging()
+            .json::<serde_json::Value>()
+            .await
+            .unwrap();
+        assert_eq!(2, pages.len());
+        assert_eq!(
+            vec![
+                "https://graph.microsoft.com/v1.0/me/messages".to_owned(),
+                "https://graph.microsoft.com/v1.0/me/messages?page=2".to_owned()
+            ],
+            *urls.lock().unwrap()
+        );
+    }
+}
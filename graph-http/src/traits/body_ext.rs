@@ -20,6 +20,18 @@ impl BodyExt for &FileConfig {
     }
 }
 
+impl BodyExt for String {
+    fn into_body(self) -> GraphResult<BodyRead> {
+        Ok(BodyRead::from(self))
+    }
+}
+
+impl BodyExt for bytes::Bytes {
+    fn into_body(self) -> GraphResult<BodyRead> {
+        BodyRead::try_from(self)
+    }
+}
+
 impl BodyExt for reqwest::Body {
     fn into_body(self) -> GraphResult<BodyRead> {
         Ok(BodyRead::from(self))
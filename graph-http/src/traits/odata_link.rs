@@ -1,6 +1,28 @@
 pub trait ODataNextLink<RHS = Self> {
     /// Get the OData next link URL.
     fn odata_next_link(&self) -> Option<String>;
+
+    /// Get the `$skiptoken` of the OData next link URL. Use the skip token with
+    /// `skip_token` of a request to request the next page later.
+    fn odata_skip_token(&self) -> Option<String> {
+        skip_token(self.odata_next_link()?.as_str())
+    }
+}
+
+/// Get the `$skiptoken` query parameter of a next link.
+///
+/// # Example
+/// ```rust
+/// use graph_http::traits::skip_token;
+///
+/// let next_link = "https://graph.microsoft.com/v1.0/users?$top=25&$skiptoken=RFNwdAIAAQ";
+/// assert_eq!(Some("RFNwdAIAAQ".to_string()), skip_token(next_link));
+/// ```
+pub fn skip_token(next_link: &str) -> Option<String> {
+    let url = url::Url::parse(next_link).ok()?;
+    url.query_pairs()
+        .find(|(key, _)| key.eq_ignore_ascii_case("$skiptoken"))
+        .map(|(_, value)| value.into_owned())
 }
 
 impl ODataNextLink for serde_json::Value {
@@ -53,3 +75,21 @@ impl UploadSessionLink for serde_json::Value {
         self["uploadUrl"].as_str().map(|s| s.to_string())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn odata_skip_token() {
+        let body = serde_json::json!({
+            "@odata.nextLink": "https://graph.microsoft.com/v1.0/users?$top=2&$skipToken=X%27445370%27"
+        });
+        assert_eq!(Some("X'445370'".to_string()), body.odata_skip_token());
+        assert_eq!(None, serde_json::json!({}).odata_skip_token());
+        assert_eq!(
+            None,
+            skip_token("https://graph.microsoft.com/v1.0/users?$top=2")
+        );
+    }
+}
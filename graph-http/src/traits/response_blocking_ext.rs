@@ -1,7 +1,8 @@
 use crate::blocking::UploadSessionBlocking;
 use crate::internal::{
-    copy, create_dir, parse_content_disposition, FileConfig, HttpResponseBuilderExt, RangeIter,
-    UploadSessionLink, MAX_FILE_NAME_LEN,
+    copy, copy_temp, create_dir, parse_content_disposition, temp_download_path, FileConfig,
+    HttpResponseBuilderExt, RangeIter, RequestIds, ThrottleInfo, UploadSessionLink,
+    MAX_FILE_NAME_LEN,
 };
 use graph_error::download::BlockingDownloadError;
 use graph_error::{ErrorMessage, ErrorType, GraphFailure, GraphResult};
@@ -103,7 +104,7 @@ pub trait ResponseBlockingExt {
     /// If the [`FileConfig::extension`] parameter is set to a non-empty string,
     /// this method will set the file extension of the downloaded file to the specified value. <br><br>
     ///
-    /// If the target file already exists and [`overwrite_existing_file`] is set to false,
+    /// If the target file already exists and [`FileConfig::overwrite_policy`] is [`OverwritePolicy::Fail`](crate::api_impl::OverwritePolicy::Fail),
     /// this method will return an [BlockingDownloadError::FileExists] with an error message
     /// indicating that the file already exists and cannot be overwritten. <br><br>
     ///
@@ -225,6 +226,12 @@ pub trait ResponseBlockingExt {
     /// println!("{:#?}", error_type.as_str());
     /// ```
     fn graph_error_type(&self) -> Option<ErrorType>;
+
+    /// Rate limit and throttling information from the response headers.
+    fn throttle_info(&self) -> ThrottleInfo;
+
+    /// The `request-id` and `client-request-id` from the response headers.
+    fn request_ids(&self) -> RequestIds;
 }
 
 impl ResponseBlockingExt for reqwest::blocking::Response {
@@ -342,7 +349,7 @@ impl ResponseBlockingExt for reqwest::blocking::Response {
     /// If the [`FileConfig::extension`] parameter is set to a non-empty string,
     /// this method will set the file extension of the downloaded file to the specified value. <br><br>
     ///
-    /// If the target file already exists and [`overwrite_existing_file`] is set to false,
+    /// If the target file already exists and [`FileConfig::overwrite_policy`] is [`OverwritePolicy::Fail`](crate::api_impl::OverwritePolicy::Fail),
     /// this method will return an [BlockingDownloadError::FileExists] with an error message
     /// indicating that the file already exists and cannot be overwritten. <br><br>
     ///
@@ -438,7 +445,6 @@ impl ResponseBlockingExt for reqwest::blocking::Response {
         let path = file_config.path.clone();
         let file_name = file_config.file_name.clone();
         let create_dir_all = file_config.create_directory_all;
-        let overwrite_existing_file = file_config.overwrite_existing_file;
         let extension = file_config.extension.clone();
 
         if create_dir_all {
@@ -460,16 +466,22 @@ impl ResponseBlockingExt for reqwest::blocking::Response {
             }
         };
 
-        if let Some(ext) = extension.as_ref() {
-            path.with_extension(ext.as_os_str());
-        }
+        let path = match extension.as_ref() {
+            Some(ext) => path.with_extension(ext.as_os_str()),
+            None => path,
+        };
 
-        if path.exists() && !overwrite_existing_file {
-            return Err(BlockingDownloadError::FileExists(
-                path.to_string_lossy().to_string(),
-            ));
+        // The file name may include directories.
+        if let Some(parent) = path.parent() {
+            if create_dir_all {
+                create_dir(parent)?;
+            }
         }
 
+        let path = file_config
+            .download_path(path.clone())
+            .ok_or_else(|| BlockingDownloadError::FileExists(path.to_string_lossy().to_string()))?;
+
         let status = self.status();
         let url = self.url().clone();
         let _headers = self.headers().clone();
@@ -479,7 +491,12 @@ impl ResponseBlockingExt for reqwest::blocking::Response {
             .url(url)
             .status(http::StatusCode::from(&status))
             .version(version)
-            .body(copy(path, self)?)?)
+            .body(if file_config.temp_file {
+                let temp_path = temp_download_path(&path);
+                copy_temp(path, temp_path, self)?
+            } else {
+                copy(path, self)?
+            })?)
     }
 
     /// If the response is a server error then Microsoft Graph will return
@@ -500,7 +517,10 @@ impl ResponseBlockingExt for reqwest::blocking::Response {
     /// }
     /// ```
     fn into_graph_error_message(self) -> Result<ErrorMessage, reqwest::Error> {
-        self.json()
+        let request_ids = self.request_ids();
+        let mut message: ErrorMessage = self.json()?;
+        request_ids.set_error_message_ids(&mut message);
+        Ok(message)
     }
 
     /// Microsoft Graph specific status code errors mapped from the response [StatusCode].
@@ -515,4 +535,12 @@ impl ResponseBlockingExt for reqwest::blocking::Response {
         let status = self.status();
         ErrorType::from_u16(status.as_u16())
     }
+
+    fn throttle_info(&self) -> ThrottleInfo {
+        ThrottleInfo::from_headers(self.headers())
+    }
+
+    fn request_ids(&self) -> RequestIds {
+        RequestIds::from_headers(self.headers())
+    }
 }
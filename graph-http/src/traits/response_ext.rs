@@ -1,18 +1,43 @@
 use crate::internal::{
-    copy_async, create_dir_async, FileConfig, HttpResponseBuilderExt, RangeIter, UploadSession,
+    copy_async, copy_async_temp, create_dir_async, temp_download_path, FileConfig,
+    HttpResponseBuilderExt, RangeIter, RequestIds, ThrottleInfo, UploadSession,
 };
 use crate::traits::UploadSessionLink;
 use async_trait::async_trait;
 use graph_error::download::AsyncDownloadError;
 use graph_error::{ErrorMessage, ErrorType, GraphFailure, GraphResult};
-use reqwest::header::HeaderMap;
+use reqwest::header::{HeaderMap, CONTENT_TYPE};
 use reqwest::Response;
 use std::ffi::OsString;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::io::AsyncReadExt;
 
 pub(crate) const MAX_FILE_NAME_LEN: usize = 255;
 
+/// The media type of the Content-Type header without parameters, in lowercase.
+pub(crate) fn parse_media_type(headers: &HeaderMap) -> Option<String> {
+    let value = headers.get(CONTENT_TYPE)?.to_str().ok()?;
+    let media_type = value.split(';').next()?.trim().to_lowercase();
+    if media_type.is_empty() {
+        None
+    } else {
+        Some(media_type)
+    }
+}
+
+/// The charset parameter of the Content-Type header.
+pub(crate) fn parse_charset(headers: &HeaderMap) -> Option<String> {
+    let value = headers.get(CONTENT_TYPE)?.to_str().ok()?;
+    value.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        if name.trim().eq_ignore_ascii_case("charset") {
+            Some(value.trim().trim_matches('"').to_string())
+        } else {
+            None
+        }
+    })
+}
+
 #[allow(clippy::single_char_pattern)]
 pub(crate) fn parse_content_disposition(headers: &HeaderMap) -> Option<OsString> {
     if let Some(value) = headers.get("content-disposition") {
@@ -207,7 +232,7 @@ pub trait ResponseExt {
     /// If the [`FileConfig::extension`] parameter is set to a non-empty string,
     /// this method will set the file extension of the downloaded file to the specified value. <br><br>
     ///
-    /// If the target file already exists and [`overwrite_existing_file`] is set to false,
+    /// If the target file already exists and [`FileConfig::overwrite_policy`] is [`OverwritePolicy::Fail`](crate::api_impl::OverwritePolicy::Fail),
     /// this method will return an [AsyncDownloadError::FileExists] with an error message
     /// indicating that the file already exists and cannot be overwritten. <br><br>
     ///
@@ -333,6 +358,66 @@ pub trait ResponseExt {
     /// println!("{:#?}", error_type.as_str());
     /// ```
     fn graph_error_type(&self) -> Option<ErrorType>;
+
+    /// Rate limit and throttling information from the response headers.
+    fn throttle_info(&self) -> ThrottleInfo;
+
+    /// The `request-id` and `client-request-id` from the response headers.
+    fn request_ids(&self) -> RequestIds;
+
+    /// The media type of the response from the Content-Type header without
+    /// parameters such as charset, for example `text/html` or `application/json`.
+    fn content_type(&self) -> Option<String>;
+
+    /// Returns true if the Content-Type of the response is JSON.
+    /// Use [`ResponseExt::text_with_detected_charset`] or [`ResponseExt::save_to`] for other
+    /// responses, such as OneNote page content (`text/html`), CSV reports (`text/csv`)
+    /// or file content, instead of [`reqwest::Response::json`].
+    fn is_json(&self) -> bool;
+
+    /// Returns true if the Content-Type of the response is XML, such as `application/xml`
+    /// or `text/xml`.
+    fn is_xml(&self) -> bool;
+
+    /// Get the body as text decoded using the charset of the Content-Type header,
+    /// or `default_encoding` when the header does not have a charset.
+    ///
+    /// The encoding is a label such as `utf-8` or `windows-1252`. Invalid byte
+    /// sequences are replaced with the replacement character.
+    ///
+    /// Unlike `reqwest::Response::text_with_charset`, which is called instead of a trait
+    /// method of the same name, the result is a [GraphResult].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .me()
+    ///     .onenote()
+    ///     .page(PAGE_ID)
+    ///     .get_pages_content()
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let html = response.text_with_detected_charset("utf-8").await?;
+    /// ```
+    async fn text_with_detected_charset(self, default_encoding: &str) -> GraphResult<String>;
+
+    /// Stream the body to the file at `path`, creating the parent directories if
+    /// they do not exist and replacing the file if it exists.
+    ///
+    /// Unlike [`ResponseExt::download`] the file name is not taken from the response.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .reports()
+    ///     .get_office_365_active_user_detail_by_period("D7")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let path = response.save_to("./reports/active_users.csv").await?;
+    /// ```
+    async fn save_to<P: AsRef<Path> + Send>(self, path: P) -> GraphResult<PathBuf>;
 }
 
 #[async_trait]
@@ -533,7 +618,7 @@ impl ResponseExt for reqwest::Response {
     /// If the [`FileConfig::extension`] parameter is set to a non-empty string,
     /// this method will set the file extension of the downloaded file to the specified value. <br><br>
     ///
-    /// If the target file already exists and [`overwrite_existing_file`] is set to false,
+    /// If the target file already exists and [`FileConfig::overwrite_policy`] is [`OverwritePolicy::Fail`](crate::api_impl::OverwritePolicy::Fail),
     /// this method will return an [AsyncDownloadError::FileExists] with an error message
     /// indicating that the file already exists and cannot be overwritten. <br><br>
     ///
@@ -633,7 +718,6 @@ impl ResponseExt for reqwest::Response {
         let path = file_config.path.clone();
         let file_name = file_config.file_name.clone();
         let create_dir_all = file_config.create_directory_all;
-        let overwrite_existing_file = file_config.overwrite_existing_file;
         let extension = file_config.extension.clone();
 
         if create_dir_all {
@@ -655,16 +739,22 @@ impl ResponseExt for reqwest::Response {
             }
         };
 
-        if let Some(ext) = extension.as_ref() {
-            path.with_extension(ext.as_os_str());
-        }
+        let path = match extension.as_ref() {
+            Some(ext) => path.with_extension(ext.as_os_str()),
+            None => path,
+        };
 
-        if path.exists() && !overwrite_existing_file {
-            return Err(AsyncDownloadError::FileExists(
-                path.to_string_lossy().to_string(),
-            ));
+        // The file name may include directories.
+        if let Some(parent) = path.parent() {
+            if create_dir_all {
+                create_dir_async(parent).await?;
+            }
         }
 
+        let path = file_config
+            .download_path(path.clone())
+            .ok_or_else(|| AsyncDownloadError::FileExists(path.to_string_lossy().to_string()))?;
+
         let status = self.status();
         let url = self.url().clone();
         let _headers = self.headers().clone();
@@ -674,7 +764,12 @@ impl ResponseExt for reqwest::Response {
             .url(url)
             .status(http::StatusCode::from(&status))
             .version(version)
-            .body(copy_async(path, self).await?)?)
+            .body(if file_config.temp_file {
+                let temp_path = temp_download_path(&path);
+                copy_async_temp(path, temp_path, self).await?
+            } else {
+                copy_async(path, self).await?
+            })?)
     }
 
     /// If the response is a server error then Microsoft Graph will return
@@ -695,7 +790,10 @@ impl ResponseExt for reqwest::Response {
     /// }
     /// ```
     async fn into_graph_error_message(self) -> Result<ErrorMessage, reqwest::Error> {
-        self.json().await
+        let request_ids = self.request_ids();
+        let mut message: ErrorMessage = self.json().await?;
+        request_ids.set_error_message_ids(&mut message);
+        Ok(message)
     }
 
     /// Microsoft Graph specific status code errors mapped from the response [StatusCode].
@@ -710,4 +808,73 @@ impl ResponseExt for reqwest::Response {
         let status = self.status();
         ErrorType::from_u16(status.as_u16())
     }
+
+    fn throttle_info(&self) -> ThrottleInfo {
+        ThrottleInfo::from_headers(self.headers())
+    }
+
+    fn request_ids(&self) -> RequestIds {
+        RequestIds::from_headers(self.headers())
+    }
+
+    fn content_type(&self) -> Option<String> {
+        parse_media_type(self.headers())
+    }
+
+    fn is_json(&self) -> bool {
+        self.content_type()
+            .map(|media_type| media_type == "application/json" || media_type.ends_with("+json"))
+            .unwrap_or_default()
+    }
+
+    fn is_xml(&self) -> bool {
+        self.content_type()
+            .map(|media_type| {
+                media_type == "application/xml"
+                    || media_type == "text/xml"
+                    || media_type.ends_with("+xml")
+            })
+            .unwrap_or_default()
+    }
+
+    async fn text_with_detected_charset(self, default_encoding: &str) -> GraphResult<String> {
+        let label = parse_charset(self.headers()).unwrap_or_else(|| default_encoding.to_string());
+        let encoding =
+            encoding_rs::Encoding::for_label(label.as_bytes()).unwrap_or(encoding_rs::UTF_8);
+        let bytes = self.bytes().await?;
+        let (text, _, _) = encoding.decode(&bytes);
+        Ok(text.into_owned())
+    }
+
+    async fn save_to<P: AsRef<Path> + Send>(self, path: P) -> GraphResult<PathBuf> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent() {
+            create_dir_async(parent).await?;
+        }
+
+        copy_async(path, self)
+            .await
+            .map_err(|err| GraphFailure::from(AsyncDownloadError::from(err)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn content_type_media_type_and_charset() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("Text/HTML; charset=\"ISO-8859-1\""),
+        );
+        assert_eq!(Some("text/html".to_string()), parse_media_type(&headers));
+        assert_eq!(Some("ISO-8859-1".to_string()), parse_charset(&headers));
+
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/csv"));
+        assert_eq!(Some("text/csv".to_string()), parse_media_type(&headers));
+        assert_eq!(None, parse_charset(&headers));
+    }
 }
@@ -1,5 +1,7 @@
 mod range;
+mod upload_progress;
 mod upload_session_task;
 
 pub(crate) use range::*;
+pub use upload_progress::*;
 pub use upload_session_task::*;
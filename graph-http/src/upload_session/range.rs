@@ -53,6 +53,10 @@ impl RangeIter {
         self.dequeue.len()
     }
 
+    pub(crate) fn size(&self) -> u64 {
+        self.size
+    }
+
     pub fn from_reader<T: Read>(mut reader: T) -> GraphResult<RangeIter> {
         let mut buf = BytesMut::new().writer();
         std::io::copy(&mut reader, &mut buf)?;
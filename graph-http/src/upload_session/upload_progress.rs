@@ -0,0 +1,155 @@
+use reqwest::header::{HeaderMap, CONTENT_LENGTH};
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Progress of an upload session reported after each chunk is uploaded.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct UploadProgress {
+    /// Size of the chunk that was uploaded.
+    pub chunk_bytes: u64,
+    /// Total bytes uploaded so far including the chunk.
+    pub bytes_uploaded: u64,
+    /// Size of the file being uploaded.
+    pub total_bytes: u64,
+}
+
+impl UploadProgress {
+    /// Percent of the file uploaded from 0.0 to 100.0.
+    pub fn percent(&self) -> f64 {
+        if self.total_bytes == 0 {
+            return 100.0;
+        }
+        (self.bytes_uploaded as f64 / self.total_bytes as f64) * 100.0
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.bytes_uploaded >= self.total_bytes
+    }
+}
+
+pub(crate) type UploadProgressFn = Arc<dyn Fn(UploadProgress) + Send + Sync>;
+
+/// Limits the average upload rate by delaying chunks that would exceed
+/// the max bandwidth given the bytes sent since the first chunk.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct BandwidthLimiter {
+    bytes_per_second: u64,
+    start: Option<Instant>,
+    bytes_sent: u64,
+}
+
+impl BandwidthLimiter {
+    pub(crate) fn new(bytes_per_second: u64) -> BandwidthLimiter {
+        BandwidthLimiter {
+            bytes_per_second: bytes_per_second.max(1),
+            start: None,
+            bytes_sent: 0,
+        }
+    }
+
+    /// Time to wait before sending a chunk of `chunk_bytes`.
+    pub(crate) fn delay(&mut self, chunk_bytes: u64, now: Instant) -> Duration {
+        let start = *self.start.get_or_insert(now);
+        let expected =
+            Duration::from_secs_f64(self.bytes_sent as f64 / self.bytes_per_second as f64);
+        self.bytes_sent += chunk_bytes;
+        expected.saturating_sub(now.duration_since(start))
+    }
+}
+
+/// Reports progress and applies the bandwidth limit for each chunk of an upload session.
+#[derive(Clone, Default)]
+pub(crate) struct UploadProgressTracker {
+    pub(crate) on_chunk_uploaded: Option<UploadProgressFn>,
+    pub(crate) limiter: Option<BandwidthLimiter>,
+    pub(crate) total_bytes: u64,
+    bytes_uploaded: u64,
+}
+
+impl UploadProgressTracker {
+    pub(crate) fn new(total_bytes: u64) -> UploadProgressTracker {
+        UploadProgressTracker {
+            total_bytes,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) async fn before_chunk(&mut self, header_map: &HeaderMap) {
+        if let Some(limiter) = self.limiter.as_mut() {
+            let delay = limiter.delay(chunk_bytes(header_map), Instant::now());
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+
+    pub(crate) fn after_chunk(&mut self, header_map: &HeaderMap, response: &reqwest::Response) {
+        if !response.status().is_success() {
+            return;
+        }
+
+        let chunk_bytes = chunk_bytes(header_map);
+        self.bytes_uploaded += chunk_bytes;
+        if let Some(on_chunk_uploaded) = self.on_chunk_uploaded.as_ref() {
+            on_chunk_uploaded(UploadProgress {
+                chunk_bytes,
+                bytes_uploaded: self.bytes_uploaded,
+                total_bytes: self.total_bytes,
+            });
+        }
+    }
+}
+
+impl Debug for UploadProgressTracker {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadProgressTracker")
+            .field("limiter", &self.limiter)
+            .field("total_bytes", &self.total_bytes)
+            .field("bytes_uploaded", &self.bytes_uploaded)
+            .finish()
+    }
+}
+
+fn chunk_bytes(header_map: &HeaderMap) -> u64 {
+    header_map
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bandwidth_limiter_delays_chunks() {
+        let mut limiter = BandwidthLimiter::new(1000);
+        let start = Instant::now();
+        assert_eq!(Duration::ZERO, limiter.delay(500, start));
+        assert_eq!(
+            Duration::from_millis(500),
+            limiter.delay(500, start + Duration::ZERO)
+        );
+        assert_eq!(
+            Duration::from_millis(200),
+            limiter.delay(500, start + Duration::from_millis(800))
+        );
+        assert_eq!(
+            Duration::ZERO,
+            limiter.delay(500, start + Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn upload_progress_percent() {
+        let progress = UploadProgress {
+            chunk_bytes: 10,
+            bytes_uploaded: 25,
+            total_bytes: 100,
+        };
+        assert_eq!(25.0, progress.percent());
+        assert!(!progress.is_complete());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::traits::AsyncIterator;
-use crate::upload_session::RangeIter;
+use crate::upload_session::{BandwidthLimiter, RangeIter, UploadProgress, UploadProgressTracker};
 use async_stream::try_stream;
 use async_trait::async_trait;
 use futures::Stream;
@@ -7,12 +7,14 @@ use graph_error::{GraphFailure, GraphResult};
 use reqwest::header::HeaderMap;
 use reqwest::RequestBuilder;
 use std::io::Read;
+use std::sync::Arc;
 use std::time::Duration;
 
 pub struct UploadSession {
     url: reqwest::Url,
     range_iter: RangeIter,
     client: reqwest::Client,
+    progress: UploadProgressTracker,
 }
 
 impl UploadSession {
@@ -21,12 +23,14 @@ impl UploadSession {
             url,
             range_iter: Default::default(),
             client: Default::default(),
+            progress: Default::default(),
         }
     }
 
     pub(crate) fn new(url: reqwest::Url, range_iter: RangeIter) -> UploadSession {
         UploadSession {
             url,
+            progress: UploadProgressTracker::new(range_iter.size()),
             range_iter,
             client: Default::default(),
         }
@@ -36,19 +40,32 @@ impl UploadSession {
         &self.url
     }
 
-    fn map_request_builder(
-        &self,
-        components: Vec<(HeaderMap, reqwest::Body)>,
-    ) -> Vec<RequestBuilder> {
-        components
-            .into_iter()
-            .map(|(header_map, body)| {
-                self.client
-                    .put(self.url.clone())
-                    .headers(header_map)
-                    .body(body)
-            })
-            .collect()
+    /// Call `on_chunk_uploaded` with the [`UploadProgress`] of the upload after each
+    /// chunk is uploaded successfully, such as to update a progress bar.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut upload_session = response
+    ///     .into_upload_session(file)
+    ///     .await?
+    ///     .on_chunk_uploaded(|progress| println!("{:.1}%", progress.percent()));
+    /// ```
+    pub fn on_chunk_uploaded<F>(mut self, on_chunk_uploaded: F) -> Self
+    where
+        F: Fn(UploadProgress) + Send + Sync + 'static,
+    {
+        self.progress.on_chunk_uploaded = Some(Arc::new(on_chunk_uploaded));
+        self
+    }
+
+    /// Limit the average upload rate to `bytes_per_second` by waiting before sending
+    /// a chunk that would exceed it. Chunks are still sent at the speed of the connection
+    /// so the limit applies over the whole upload rather than within a chunk.
+    ///
+    /// There is no limit by default.
+    pub fn max_bandwidth(mut self, bytes_per_second: u64) -> Self {
+        self.progress.limiter = Some(BandwidthLimiter::new(bytes_per_second));
+        self
     }
 
     async fn send(
@@ -77,11 +94,10 @@ impl UploadSession {
         upload_url: U,
         reader: R,
     ) -> GraphResult<UploadSession> {
-        Ok(UploadSession {
-            url: reqwest::Url::parse(upload_url.as_ref())?,
-            range_iter: RangeIter::from_reader(reader)?,
-            client: Default::default(),
-        })
+        Ok(UploadSession::new(
+            reqwest::Url::parse(upload_url.as_ref())?,
+            RangeIter::from_reader(reader)?,
+        ))
     }
 
     fn try_stream(&mut self) -> impl Stream<Item = GraphResult<reqwest::Response>> + '_ {
@@ -89,12 +105,12 @@ impl UploadSession {
             let components = self.range_iter.map_all().ok_or(GraphFailure::invalid(
                 "Invalid Headers (internal error, please report)",
             ))?;
-            let request_builders = self.map_request_builder(components);
 
-            for request_builder in request_builders {
-                yield request_builder.send()
-                    .await
-                    .map_err(GraphFailure::from)?
+            for (header_map, body) in components {
+                self.progress.before_chunk(&header_map).await;
+                let response = self.send(header_map.clone(), body).await?;
+                self.progress.after_chunk(&header_map, &response);
+                yield response;
             }
         }
     }
@@ -179,11 +195,22 @@ impl UploadSession {
         let components = self.range_iter.map_all().ok_or(GraphFailure::invalid(
             "Invalid Headers (internal error, please report)",
         ))?;
-        let request_builders = self.map_request_builder(components);
+        let client = self.client.clone();
+        let url = self.url.clone();
+        let mut progress = self.progress.clone();
 
         tokio::spawn(async move {
-            for request_builder in request_builders {
-                let result = request_builder.send().await;
+            for (header_map, body) in components {
+                progress.before_chunk(&header_map).await;
+                let result = client
+                    .put(url.clone())
+                    .headers(header_map.clone())
+                    .body(body)
+                    .send()
+                    .await;
+                if let Ok(response) = result.as_ref() {
+                    progress.after_chunk(&header_map, response);
+                }
                 sender.send_timeout(result, timeout).await.unwrap();
             }
         });
@@ -198,6 +225,11 @@ impl AsyncIterator for UploadSession {
 
     async fn next(&mut self) -> Option<Self::Item> {
         let (header_map, body) = self.range_iter.pop_front()?;
-        Some(self.send(header_map, body).await)
+        self.progress.before_chunk(&header_map).await;
+        let result = self.send(header_map.clone(), body).await;
+        if let Ok(response) = result.as_ref() {
+            self.progress.after_chunk(&header_map, response);
+        }
+        Some(result)
     }
 }
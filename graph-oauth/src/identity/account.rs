@@ -0,0 +1,286 @@
+use base64::Engine;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+use crate::identity::Token;
+
+/// A signed in user account.
+///
+/// The home account id is made from the object id of the user (oid) and the
+/// tenant id of the user's home tenant (tid) in the format `{oid}.{tid}`. The
+/// same account is identified by the same home account id across applications
+/// and credentials.
+#[derive(Clone, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Account {
+    pub home_account_id: String,
+    /// The object id of the user in the tenant the token was issued for.
+    pub local_account_id: Option<String>,
+    pub tenant_id: Option<String>,
+    /// The preferred_username claim of the id token.
+    pub username: Option<String>,
+}
+
+impl Account {
+    pub fn new(object_id: impl AsRef<str>, tenant_id: impl AsRef<str>) -> Account {
+        Account {
+            home_account_id: format!("{}.{}", object_id.as_ref(), tenant_id.as_ref()),
+            local_account_id: Some(object_id.as_ref().to_owned()),
+            tenant_id: Some(tenant_id.as_ref().to_owned()),
+            username: None,
+        }
+    }
+
+    /// Get the account for the user the token was issued to.
+    ///
+    /// The account is read from the client_info field of the token when present,
+    /// otherwise from the oid and tid claims of the id token. Returns None for
+    /// tokens issued to an application, such as tokens from the client credentials flow.
+    pub fn from_token(token: &Token) -> Option<Account> {
+        let claims = token
+            .id_token
+            .as_ref()
+            .and_then(|id_token| decode_segment(id_token.as_ref().split('.').nth(1)?))
+            .unwrap_or_default();
+        let client_info = token
+            .client_info
+            .as_ref()
+            .and_then(|client_info| decode_segment(client_info))
+            .unwrap_or_default();
+
+        let claim = |value: &serde_json::Value, name: &str| -> Option<String> {
+            value[name].as_str().map(|s| s.to_owned())
+        };
+
+        let object_id = claim(&client_info, "uid").or_else(|| claim(&claims, "oid"))?;
+        let home_tenant_id = claim(&client_info, "utid").or_else(|| claim(&claims, "tid"))?;
+
+        Some(Account {
+            home_account_id: format!("{}.{}", object_id, home_tenant_id),
+            local_account_id: claim(&claims, "oid").or(Some(object_id)),
+            tenant_id: claim(&claims, "tid").or(Some(home_tenant_id)),
+            username: claim(&claims, "preferred_username").or_else(|| claim(&claims, "upn")),
+        })
+    }
+}
+
+impl Debug for Account {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Account")
+            .field("home_account_id", &self.home_account_id)
+            .field("tenant_id", &self.tenant_id)
+            .finish()
+    }
+}
+
+// Decodes a base64url encoded json value such as a jwt payload or client_info.
+pub(crate) fn decode_segment(segment: &str) -> Option<serde_json::Value> {
+    let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(segment.trim_end_matches('='))
+        .ok()?;
+    serde_json::from_slice(&decoded).ok()
+}
+
+#[derive(Clone)]
+struct AccountEntry {
+    account: Account,
+    tokens: Vec<(Uuid, Token)>,
+}
+
+/// Token cache that stores tokens by account and scope.
+///
+/// The cache can be shared between credentials and applications by cloning it and
+/// passing it to each credential builder using `with_account_cache`. Tokens are
+/// only returned to credentials with the same client id as the credential that
+/// requested the token.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_oauth::{AccountTokenCache, ConfidentialClientApplication};
+///
+/// let account_cache = AccountTokenCache::new();
+/// let mut confidential_client = ConfidentialClientApplication::builder("client-id")
+///     .with_auth_code("code")
+///     .with_client_secret("secret")
+///     .with_scope(vec!["User.Read", "offline_access"])
+///     .with_account_cache(account_cache.clone())
+///     .build();
+///
+/// let token = confidential_client.get_token_silent_async().await?;
+///
+/// // Later, possibly from another credential using the same cache.
+/// let account = account_cache.accounts().first().cloned().unwrap();
+/// let token = confidential_client
+///     .acquire_token_silent_async(&account, vec!["Mail.Read"])
+///     .await?;
+/// ```
+#[derive(Clone, Default)]
+pub struct AccountTokenCache {
+    entries: Arc<RwLock<HashMap<String, AccountEntry>>>,
+}
+
+impl AccountTokenCache {
+    pub fn new() -> AccountTokenCache {
+        AccountTokenCache::default()
+    }
+
+    /// Store a token for the account the token was issued to. Replaces any token
+    /// for the same client id and set of scopes. Returns the account or None if
+    /// the token was not issued to a user.
+    pub fn store(&self, client_id: &Uuid, token: &Token) -> Option<Account> {
+        let account = Account::from_token(token)?;
+        let scope = scope_set(token.scope.iter());
+
+        let mut write_lock = self.entries.write().unwrap();
+        let entry = write_lock
+            .entry(account.home_account_id.clone())
+            .or_insert_with(|| AccountEntry {
+                account: account.clone(),
+                tokens: Vec::new(),
+            });
+
+        if account.username.is_some() {
+            entry.account = account.clone();
+        }
+
+        entry
+            .tokens
+            .retain(|(id, cached)| id != client_id || scope_set(cached.scope.iter()) != scope);
+        entry.tokens.push((*client_id, token.clone()));
+        Some(entry.account.clone())
+    }
+
+    /// All accounts that have tokens in the cache.
+    pub fn accounts(&self) -> Vec<Account> {
+        let read_lock = self.entries.read().unwrap();
+        read_lock
+            .values()
+            .map(|entry| entry.account.clone())
+            .collect()
+    }
+
+    pub fn get_account(&self, home_account_id: &str) -> Option<Account> {
+        let read_lock = self.entries.read().unwrap();
+        read_lock
+            .get(home_account_id)
+            .map(|entry| entry.account.clone())
+    }
+
+    /// Get an unexpired token for the account and client id that was granted
+    /// all of the given scopes.
+    pub fn get_token<T: AsRef<str>>(
+        &self,
+        account: &Account,
+        client_id: &Uuid,
+        scope: &[T],
+    ) -> Option<Token> {
+        let scope = scope_set(scope.iter());
+        let read_lock = self.entries.read().unwrap();
+        read_lock
+            .get(&account.home_account_id)?
+            .tokens
+            .iter()
+            .rev()
+            .filter(|(id, _)| id == client_id)
+            .map(|(_, token)| token)
+            .find(|token| {
+                !token.is_expired_sub(time::Duration::minutes(5))
+                    && scope.is_subset(&scope_set(token.scope.iter()))
+            })
+            .cloned()
+    }
+
+    /// Get the most recent token for the account and client id that has a refresh token.
+    pub fn get_refresh_token(&self, account: &Account, client_id: &Uuid) -> Option<Token> {
+        let read_lock = self.entries.read().unwrap();
+        read_lock
+            .get(&account.home_account_id)?
+            .tokens
+            .iter()
+            .rev()
+            .find(|(id, token)| id == client_id && token.refresh_token.is_some())
+            .map(|(_, token)| token.clone())
+    }
+
+    /// Remove the account and all of its tokens from the cache.
+    pub fn remove_account(&self, account: &Account) -> Option<Account> {
+        let mut write_lock = self.entries.write().unwrap();
+        write_lock
+            .remove(&account.home_account_id)
+            .map(|entry| entry.account)
+    }
+}
+
+impl PartialEq for AccountTokenCache {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.entries, &other.entries)
+    }
+}
+
+impl Debug for AccountTokenCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountTokenCache")
+            .field("accounts", &self.accounts())
+            .finish()
+    }
+}
+
+fn scope_set<T: AsRef<str>>(scope: impl Iterator<Item = T>) -> BTreeSet<String> {
+    scope.map(|s| s.as_ref().to_lowercase()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::identity::IdToken;
+
+    fn token(scope: Vec<&str>) -> Token {
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(
+            serde_json::json!({
+                "oid": "object-id",
+                "tid": "tenant-id",
+                "preferred_username": "adele@contoso.com"
+            })
+            .to_string(),
+        );
+        let mut token = Token::new("Bearer", 3600, "access_token", scope);
+        token.with_id_token(IdToken::new(
+            &format!("header.{payload}.signature"),
+            None,
+            None,
+            None,
+        ));
+        token.refresh_token = Some("refresh_token".into());
+        token
+    }
+
+    #[test]
+    fn account_from_id_token() {
+        let account = Account::from_token(&token(vec!["User.Read"])).unwrap();
+        assert_eq!("object-id.tenant-id", account.home_account_id.as_str());
+        assert_eq!(Some("adele@contoso.com".into()), account.username);
+        assert!(Account::from_token(&Token::default()).is_none());
+    }
+
+    #[test]
+    fn get_token_by_scope() {
+        let cache = AccountTokenCache::new();
+        let client_id = Uuid::new_v4();
+        let account = cache
+            .store(&client_id, &token(vec!["User.Read", "Mail.Read"]))
+            .unwrap();
+
+        assert_eq!(vec![account.clone()], cache.accounts());
+        assert!(cache
+            .get_token(&account, &client_id, &["mail.read"])
+            .is_some());
+        assert!(cache
+            .get_token(&account, &client_id, &["Files.Read"])
+            .is_none());
+        assert!(cache
+            .get_token(&account, &Uuid::new_v4(), &["User.Read"])
+            .is_none());
+        assert!(cache.get_refresh_token(&account, &client_id).is_some());
+    }
+}
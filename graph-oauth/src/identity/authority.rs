@@ -1,3 +1,5 @@
+use graph_core::identity::TokenValidation;
+use graph_error::{IdentityResult, AF};
 use std::fmt::Display;
 use url::{ParseError, Url};
 
@@ -41,6 +43,81 @@ impl AzureCloudInstance {
     pub fn get_open_id_configuration_url(&self, authority: Authority) -> String {
         format!("{}/v2.0/{}", self.as_ref(), authority.as_ref())
     }
+
+    /// The Microsoft Graph service root for the cloud, such as https://graph.microsoft.us
+    /// for the US Government cloud.
+    pub fn graph_host(&self) -> &'static str {
+        match self {
+            AzureCloudInstance::AzurePublic => "https://graph.microsoft.com",
+            AzureCloudInstance::AzureChina => "https://microsoftgraph.chinacloudapi.cn",
+            AzureCloudInstance::AzureGermany => "https://graph.microsoft.de",
+            AzureCloudInstance::AzureUsGovernment => "https://graph.microsoft.us",
+        }
+    }
+
+    /// The /.default scope for Microsoft Graph in the cloud, such as
+    /// https://graph.microsoft.us/.default for the US Government cloud.
+    pub fn graph_default_scope(&self) -> String {
+        format!("{}/.default", self.graph_host())
+    }
+
+    /// Validation of access tokens for the Microsoft Graph resource of the cloud.
+    pub fn token_validation(&self) -> TokenValidation {
+        TokenValidation::new(self.graph_host())
+    }
+
+    /// Rewrite a Microsoft Graph scope for any cloud to the Microsoft Graph resource of
+    /// this cloud. Scopes without a resource, such as User.Read, and scopes for
+    /// other resources are returned unchanged.
+    ///
+    /// # Example
+    /// ```rust
+    /// use graph_oauth::AzureCloudInstance;
+    ///
+    /// let scope = AzureCloudInstance::AzureUsGovernment
+    ///     .scope_for_cloud("https://graph.microsoft.com/.default");
+    /// assert_eq!("https://graph.microsoft.us/.default", scope.as_str());
+    /// ```
+    pub fn scope_for_cloud(&self, scope: &str) -> String {
+        match AzureCloudInstance::graph_scope_cloud(scope) {
+            Some((cloud, permission)) if cloud != *self => {
+                format!("{}{}", self.graph_host(), permission)
+            }
+            _ => scope.to_owned(),
+        }
+    }
+
+    /// Returns an error if the scope is for the Microsoft Graph resource of a different cloud.
+    pub fn validate_scope(&self, scope: &str) -> IdentityResult<()> {
+        match AzureCloudInstance::graph_scope_cloud(scope) {
+            Some((cloud, _)) if cloud != *self => Err(AF::msg_err(
+                "scope",
+                &format!(
+                    "scope {scope} is not for the Microsoft Graph resource of the configured cloud {}",
+                    self.graph_host()
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    // The cloud of the Microsoft Graph resource in the scope and the remaining
+    // permission including the leading slash.
+    fn graph_scope_cloud(scope: &str) -> Option<(AzureCloudInstance, &str)> {
+        [
+            AzureCloudInstance::AzurePublic,
+            AzureCloudInstance::AzureChina,
+            AzureCloudInstance::AzureGermany,
+            AzureCloudInstance::AzureUsGovernment,
+        ]
+        .into_iter()
+        .find_map(|cloud| {
+            scope
+                .strip_prefix(cloud.graph_host())
+                .filter(|permission| permission.starts_with('/'))
+                .map(|permission| (cloud, permission))
+        })
+    }
 }
 
 impl AsRef<str> for AzureCloudInstance {
@@ -101,6 +178,14 @@ impl AzureCloudInstance {
         ))
     }
 
+    pub fn admin_consent_v2_uri(&self, authority: &Authority) -> Result<Url, ParseError> {
+        Url::parse(&format!(
+            "{}/{}/v2.0/adminconsent",
+            self.as_ref(),
+            authority.as_ref()
+        ))
+    }
+
     pub fn device_code_uri(&self, authority: &Authority) -> Result<Url, ParseError> {
         Url::parse(&format!(
             "{}/{}/oauth2/v2.0/devicecode",
@@ -4,6 +4,10 @@ use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
 use url::Url;
 
+use crate::identity::decode_segment;
+
+pub use graph_error::AadstsError;
+
 /// The specification defines errors here:
 /// https://datatracker.ietf.org/doc/html/draft-ietf-oauth-v2-31#section-4.2.2.1
 ///
@@ -76,6 +80,20 @@ pub enum AuthorizationResponseError {
     /// Another authentication step or consent is required. Retry the request without prompt=none.
     #[serde(alias = "interaction_required", alias = "InteractionRequired")]
     InteractionRequired,
+
+    /// The user has to consent to the application.
+    /// The client requested silent authentication (prompt=none) but consent is required.
+    /// Retry the request without prompt=none.
+    #[serde(alias = "consent_required", alias = "ConsentRequired")]
+    ConsentRequired,
+
+    /// The user has to select one of the accounts signed in to the session.
+    /// Retry the request without prompt=none.
+    #[serde(
+        alias = "account_selection_required",
+        alias = "AccountSelectionRequired"
+    )]
+    AccountSelectionRequired,
 }
 
 impl Display for AuthorizationResponseError {
@@ -119,13 +137,74 @@ pub(crate) struct PhantomAuthorizationResponse {
     log_pii: bool,
 }
 
-#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct AuthorizationError {
     pub error: Option<AuthorizationResponseError>,
     pub error_description: Option<String>,
     pub error_uri: Option<Url>,
 }
 
+impl AuthorizationError {
+    /// The AADSTS error code given in the error description.
+    pub fn aadsts_error(&self) -> Option<AadstsError> {
+        self.error_description
+            .as_deref()
+            .and_then(AadstsError::from_error_description)
+    }
+
+    /// Returns true if the user has to sign in, consent, or select an account
+    /// interactively, such as when a silent authorization request with prompt=none
+    /// fails. Send the user to an authorization url without prompt=none.
+    pub fn requires_interaction(&self) -> bool {
+        matches!(
+            self.error,
+            Some(
+                AuthorizationResponseError::InteractionRequired
+                    | AuthorizationResponseError::LoginRequired
+                    | AuthorizationResponseError::ConsentRequired
+                    | AuthorizationResponseError::AccountSelectionRequired
+            )
+        ) || self
+            .aadsts_error()
+            .map(|aadsts_error| aadsts_error.requires_interaction())
+            .unwrap_or_default()
+    }
+}
+
+/// The result of a silent authorization request sent with prompt=none, such as
+/// from a hidden iframe or a background request that cannot show a sign-in page.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SilentAuthorizationResult {
+    /// The user is signed in and the response contains the authorization code or tokens.
+    Authorized(AuthorizationResponse),
+    /// The user has to sign in, consent, or select an account. Fall back to interactive
+    /// authorization using an authorization url without prompt=none.
+    InteractionRequired(AuthorizationError),
+}
+
+impl SilentAuthorizationResult {
+    /// Map the errors of a validated silent authorization response that require user
+    /// interaction to [SilentAuthorizationResult::InteractionRequired].
+    pub(crate) fn from_validated(
+        result: Result<AuthorizationResponse, AuthorizationValidationError>,
+    ) -> Result<SilentAuthorizationResult, AuthorizationValidationError> {
+        match result {
+            Ok(response) => Ok(SilentAuthorizationResult::Authorized(response)),
+            Err(AuthorizationValidationError::Authorization(error))
+                if error.requires_interaction() =>
+            {
+                Ok(SilentAuthorizationResult::InteractionRequired(error))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn is_interaction_required(&self) -> bool {
+        matches!(self, SilentAuthorizationResult::InteractionRequired(_))
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct AuthorizationResponse {
     pub code: Option<String>,
@@ -154,8 +233,170 @@ impl AuthorizationResponse {
     pub fn is_err(&self) -> bool {
         self.error.is_some()
     }
+
+    /// Parse the redirect uri that the authorization server sent the user to.
+    ///
+    /// The response is read from the query, or the fragment when the query is empty
+    /// such as when the response mode is fragment. Error responses are returned as
+    /// [AuthorizationValidationError::Authorization] and the AADSTS error code, if any,
+    /// can be read using [AuthorizationError::aadsts_error].
+    ///
+    /// # Example
+    /// ```
+    /// use graph_oauth::{AadstsError, AuthorizationResponse, AuthorizationValidationError};
+    ///
+    /// let response = AuthorizationResponse::parse(
+    ///     "http://localhost:8000/redirect?code=code&state=12345&session_state=session",
+    /// ).unwrap();
+    /// assert_eq!(Some("code"), response.code.as_deref());
+    /// assert_eq!(Some("session"), response.session_state.as_deref());
+    ///
+    /// let err = AuthorizationResponse::parse(
+    ///     "http://localhost:8000/redirect?error=access_denied&error_description=AADSTS65004%3A+User+declined+to+consent",
+    /// ).unwrap_err();
+    /// if let AuthorizationValidationError::Authorization(error) = err {
+    ///     assert_eq!(Some(AadstsError::ConsentDeclined), error.aadsts_error());
+    /// }
+    /// ```
+    pub fn parse<U: AsRef<str>>(
+        url: U,
+    ) -> Result<AuthorizationResponse, AuthorizationValidationError> {
+        let url = Url::parse(url.as_ref())
+            .map_err(|err| AuthorizationValidationError::InvalidResponse(err.to_string()))?;
+        let query = url
+            .query()
+            .filter(|query| !query.is_empty())
+            .or(url.fragment())
+            .unwrap_or_default();
+
+        AuthorizationResponse::from_query_str(query)?.into_result()
+    }
+
+    /// Parse the query or fragment of the redirect uri. A full redirect uri is also accepted.
+    pub fn from_query_or_fragment(
+        query_or_fragment: &str,
+    ) -> Result<AuthorizationResponse, AuthorizationValidationError> {
+        match Url::parse(query_or_fragment) {
+            Ok(url) => AuthorizationResponse::from_query_str(
+                url.query().or(url.fragment()).unwrap_or_default(),
+            ),
+            Err(_) => AuthorizationResponse::from_query_str(
+                query_or_fragment.trim_start_matches(['?', '#']),
+            ),
+        }
+    }
+
+    fn from_query_str(query: &str) -> Result<AuthorizationResponse, AuthorizationValidationError> {
+        serde_urlencoded::from_str(query)
+            .map_err(|err| AuthorizationValidationError::InvalidResponse(err.to_string()))
+    }
+
+    /// Return the error response as an [AuthorizationValidationError].
+    fn into_result(self) -> Result<AuthorizationResponse, AuthorizationValidationError> {
+        if self.error.is_some() {
+            return Err(AuthorizationValidationError::Authorization(
+                AuthorizationError {
+                    error: self.error,
+                    error_description: self.error_description,
+                    error_uri: self.error_uri,
+                },
+            ));
+        }
+
+        Ok(self)
+    }
+
+    /// Validate that the state and the nonce claim of the id token, when an id token is
+    /// returned, are the same as the state and nonce sent in the authorization url.
+    pub(crate) fn validate(
+        self,
+        state: Option<&str>,
+        nonce: Option<&str>,
+    ) -> Result<AuthorizationResponse, AuthorizationValidationError> {
+        if let Some(state) = state {
+            if self.state.as_deref() != Some(state) {
+                return Err(AuthorizationValidationError::StateMismatch {
+                    expected: state.to_owned(),
+                    actual: self.state,
+                });
+            }
+        }
+
+        let response = self.into_result()?;
+        if let (Some(nonce), Some(id_token)) = (nonce, response.id_token.as_ref()) {
+            let actual = id_token
+                .split('.')
+                .nth(1)
+                .and_then(decode_segment)
+                .and_then(|claims| claims["nonce"].as_str().map(|s| s.to_owned()));
+            if actual.as_deref() != Some(nonce) {
+                return Err(AuthorizationValidationError::NonceMismatch {
+                    expected: nonce.to_owned(),
+                    actual,
+                });
+            }
+        }
+
+        Ok(response)
+    }
 }
 
+/// Errors from validating the response returned to the redirect uri in an authorization flow.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuthorizationValidationError {
+    /// The state in the response is not the same as the state sent in the authorization
+    /// url. The response may be from a cross-site request forgery (CSRF) attack and
+    /// should not be used.
+    StateMismatch {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// The nonce claim in the id token is not the same as the nonce sent in the
+    /// authorization url. The id token may have been replayed and should not be used.
+    NonceMismatch {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// The authorization server returned an error.
+    Authorization(AuthorizationError),
+    /// The query or fragment could not be parsed.
+    InvalidResponse(String),
+}
+
+impl Display for AuthorizationValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthorizationValidationError::StateMismatch { expected, actual } => write!(
+                f,
+                "state mismatch, possible CSRF attack: expected {expected}, found {actual:?}"
+            ),
+            AuthorizationValidationError::NonceMismatch { expected, actual } => {
+                write!(f, "nonce mismatch: expected {expected}, found {actual:?}")
+            }
+            AuthorizationValidationError::Authorization(error) => match error.aadsts_error() {
+                Some(aadsts_error) => write!(
+                    f,
+                    "authorization error: {:?} {:?} {}",
+                    error.error,
+                    aadsts_error,
+                    error.error_description.as_deref().unwrap_or_default()
+                ),
+                None => write!(
+                    f,
+                    "authorization error: {:?} {}",
+                    error.error,
+                    error.error_description.as_deref().unwrap_or_default()
+                ),
+            },
+            AuthorizationValidationError::InvalidResponse(message) => {
+                write!(f, "invalid authorization response: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AuthorizationValidationError {}
+
 impl Debug for AuthorizationResponse {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if self.log_pii {
@@ -172,7 +413,7 @@ impl Debug for AuthorizationResponse {
                 .finish()
         } else {
             f.debug_struct("AuthQueryResponse")
-                .field("code", &self.code)
+                .field("code", &"[REDACTED]")
                 .field("id_token", &"[REDACTED]")
                 .field("access_token", &"[REDACTED]")
                 .field("state", &self.state)
@@ -189,6 +430,7 @@ impl Debug for AuthorizationResponse {
 #[cfg(test)]
 mod test {
     use super::*;
+    use base64::Engine;
 
     pub const AUTHORIZATION_RESPONSE: &str = r#"{
         "access_token": "token",
@@ -227,4 +469,67 @@ mod test {
         let response: AuthorizationResponse = serde_urlencoded::from_str(query).unwrap();
         assert_eq!(Some(String::from("token")), response.access_token);
     }
+
+    #[test]
+    pub fn validate_state_mismatch() {
+        let response = AuthorizationResponse::from_query_or_fragment(
+            "http://localhost:8000/redirect?code=code&state=54321",
+        )
+        .unwrap();
+        assert!(matches!(
+            response.validate(Some("12345"), None),
+            Err(AuthorizationValidationError::StateMismatch { .. })
+        ));
+    }
+
+    #[test]
+    pub fn validate_nonce() {
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .encode(serde_json::json!({ "nonce": "nonce" }).to_string());
+        let fragment = format!("#id_token=header.{payload}.signature&state=12345");
+        let response = AuthorizationResponse::from_query_or_fragment(&fragment).unwrap();
+
+        assert!(response
+            .clone()
+            .validate(Some("12345"), Some("nonce"))
+            .is_ok());
+        assert!(matches!(
+            response.validate(Some("12345"), Some("other")),
+            Err(AuthorizationValidationError::NonceMismatch { .. })
+        ));
+    }
+
+    #[test]
+    pub fn parse_fragment_response() {
+        let response = AuthorizationResponse::parse(
+            "http://localhost:8000/redirect#code=code&state=12345&session_state=session",
+        )
+        .unwrap();
+        assert_eq!(Some("code".to_string()), response.code);
+        assert_eq!(Some("12345".to_string()), response.state);
+        assert_eq!(Some("session".to_string()), response.session_state);
+    }
+
+    #[test]
+    pub fn parse_aadsts_error() {
+        let err = AuthorizationResponse::parse(
+            "http://localhost:8000/redirect?error=invalid_request&error_description=AADSTS50011%3A+The+redirect+URI+does+not+match",
+        )
+        .unwrap_err();
+
+        match err {
+            AuthorizationValidationError::Authorization(error) => {
+                assert_eq!(
+                    Some(AuthorizationResponseError::InvalidRequest),
+                    error.error
+                );
+                assert_eq!(Some(AadstsError::RedirectUriMismatch), error.aadsts_error());
+            }
+            _ => panic!("expected authorization error, found {err:?}"),
+        }
+        assert_eq!(
+            Some(AadstsError::Other(1234)),
+            AadstsError::from_error_description("AADSTS1234: Error")
+        );
+    }
 }
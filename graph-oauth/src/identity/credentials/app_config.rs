@@ -3,13 +3,17 @@ use http::{HeaderName, HeaderValue};
 use std::collections::{BTreeSet, HashMap};
 use std::fmt::{Debug, Formatter};
 
+use graph_core::cache::{CacheStore, InMemoryCacheStore};
 use graph_core::identity::ForceTokenRefresh;
-use graph_error::AF;
+use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
 use reqwest::header::HeaderMap;
 use url::Url;
 use uuid::Uuid;
 
-use crate::identity::{Authority, AzureCloudInstance, IdToken};
+use crate::identity::{
+    tracing_targets::CREDENTIAL_EXECUTOR, AccountTokenCache, Authority, AzureCloudInstance,
+    IdToken, Token,
+};
 use crate::ApplicationOptions;
 
 #[derive(Clone, Default, PartialEq)]
@@ -66,6 +70,25 @@ pub struct AppConfig {
     pub(crate) force_token_refresh: ForceTokenRefresh,
     pub(crate) id_token: Option<IdToken>,
     pub(crate) log_pii: bool,
+    /// Http client for async token requests, such as the token client of the Graph Api
+    /// client. When not set a new client is created for each token request.
+    pub(crate) http_client: SharedHttpClient,
+    /// Http client for blocking token requests. When not set a new client is created
+    /// for each token request.
+    pub(crate) blocking_http_client: SharedHttpClient<reqwest::blocking::Client>,
+    /// Token cache shared with other credentials to look up tokens by account.
+    pub(crate) account_cache: Option<AccountTokenCache>,
+}
+
+/// Wrapper for the http client used in token requests. The client is not
+/// considered when comparing app configs.
+#[derive(Clone, Default)]
+pub(crate) struct SharedHttpClient<C = reqwest::Client>(Option<C>);
+
+impl<C> PartialEq for SharedHttpClient<C> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
 }
 
 impl TryFrom<ApplicationOptions> for AppConfig {
@@ -73,7 +96,8 @@ impl TryFrom<ApplicationOptions> for AppConfig {
 
     fn try_from(value: ApplicationOptions) -> Result<Self, Self::Error> {
         let client_id = Uuid::try_parse(&value.client_id.to_string()).unwrap_or_default();
-        let cache_id = AppConfig::generate_cache_id(client_id, value.tenant_id.as_ref());
+        let cache_id =
+            AppConfig::generate_cache_id(client_id, value.tenant_id.as_ref(), &Default::default());
         Ok(AppConfig {
             tenant_id: value.tenant_id,
             client_id: Uuid::try_parse(&value.client_id.to_string())?,
@@ -94,6 +118,9 @@ impl TryFrom<ApplicationOptions> for AppConfig {
             force_token_refresh: Default::default(),
             id_token: Default::default(),
             log_pii: false,
+            http_client: Default::default(),
+            blocking_http_client: Default::default(),
+            account_cache: None,
         })
     }
 }
@@ -136,13 +163,31 @@ impl Debug for AppConfig {
 }
 
 impl AppConfig {
-    fn generate_cache_id(client_id: Uuid, tenant_id: Option<&String>) -> String {
-        if let Some(tenant_id) = tenant_id.as_ref() {
-            base64::engine::general_purpose::URL_SAFE_NO_PAD
-                .encode(format!("{},{}", tenant_id, client_id))
-        } else {
-            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(client_id.to_string())
+    /// Tokens are cached by tenant, client id, and scope so that a credential
+    /// used for more than one tenant or set of scopes does not reuse a token
+    /// issued for a different tenant or scope.
+    fn generate_cache_id(
+        client_id: Uuid,
+        tenant_id: Option<&String>,
+        scope: &BTreeSet<String>,
+    ) -> String {
+        let mut cache_id = match tenant_id {
+            Some(tenant_id) => format!("{},{}", tenant_id, client_id),
+            None => client_id.to_string(),
+        };
+
+        if !scope.is_empty() {
+            let scope: Vec<&str> = scope.iter().map(|s| s.as_str()).collect();
+            cache_id.push(',');
+            cache_id.push_str(scope.join(" ").as_str());
         }
+
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(cache_id)
+    }
+
+    fn update_cache_id(&mut self) {
+        self.cache_id =
+            AppConfig::generate_cache_id(self.client_id, self.tenant_id.as_ref(), &self.scope);
     }
 
     pub(crate) fn builder(client_id: impl TryInto<Uuid>) -> AppConfigBuilder {
@@ -151,7 +196,7 @@ impl AppConfig {
 
     pub(crate) fn new(client_id: impl TryInto<Uuid>) -> AppConfig {
         let client_id = client_id.try_into().unwrap_or_default();
-        let cache_id = AppConfig::generate_cache_id(client_id, None);
+        let cache_id = AppConfig::generate_cache_id(client_id, None, &Default::default());
 
         AppConfig {
             tenant_id: None,
@@ -170,6 +215,9 @@ impl AppConfig {
             force_token_refresh: Default::default(),
             id_token: Default::default(),
             log_pii: Default::default(),
+            http_client: Default::default(),
+            blocking_http_client: Default::default(),
+            account_cache: None,
         }
     }
 
@@ -179,6 +227,7 @@ impl AppConfig {
 
     pub(crate) fn with_client_id(&mut self, client_id: impl TryInto<Uuid>) {
         self.client_id = client_id.try_into().unwrap_or_default();
+        self.update_cache_id();
     }
 
     pub(crate) fn with_authority(&mut self, authority: Authority) {
@@ -186,16 +235,45 @@ impl AppConfig {
             self.tenant_id = Some(tenant_id.clone());
         }
         self.authority = authority;
+        self.update_cache_id();
     }
 
     pub(crate) fn with_azure_cloud_instance(&mut self, azure_cloud_instance: AzureCloudInstance) {
         self.azure_cloud_instance = azure_cloud_instance;
+        self.rewrite_default_scope();
+    }
+
+    /// Rewrite /.default scopes for Microsoft Graph, such as the default
+    /// https://graph.microsoft.com/.default scope, to the configured cloud.
+    fn rewrite_default_scope(&mut self) {
+        if self.scope.iter().any(|scope| scope.ends_with("/.default")) {
+            self.scope = self
+                .scope
+                .iter()
+                .map(|scope| {
+                    if scope.ends_with("/.default") {
+                        self.azure_cloud_instance.scope_for_cloud(scope)
+                    } else {
+                        scope.clone()
+                    }
+                })
+                .collect();
+            self.update_cache_id();
+        }
+    }
+
+    /// Returns an error if a Microsoft Graph scope is for a cloud other than the configured cloud.
+    pub(crate) fn validate_scope(&self) -> IdentityResult<()> {
+        self.scope
+            .iter()
+            .try_for_each(|scope| self.azure_cloud_instance.validate_scope(scope))
     }
 
     pub(crate) fn with_tenant(&mut self, tenant_id: impl AsRef<str>) {
         let tenant = tenant_id.as_ref().to_string();
         self.tenant_id = Some(tenant.clone());
         self.authority = Authority::TenantId(tenant);
+        self.update_cache_id();
     }
 
     /// Extends the query parameters of both the default query params and user defined params.
@@ -233,11 +311,81 @@ impl AppConfig {
 
     pub(crate) fn with_scope<T: ToString, I: IntoIterator<Item = T>>(&mut self, scope: I) {
         self.scope = scope.into_iter().map(|s| s.to_string()).collect();
+        self.update_cache_id();
+        self.rewrite_default_scope();
     }
 
     pub(crate) fn with_id_token(&mut self, id_token: IdToken) {
         self.id_token = Some(id_token);
     }
+
+    pub(crate) fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.http_client = SharedHttpClient(Some(http_client));
+    }
+
+    pub(crate) fn http_client(&self) -> Option<&reqwest::Client> {
+        self.http_client.0.as_ref()
+    }
+
+    pub(crate) fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.blocking_http_client = SharedHttpClient(Some(http_client));
+    }
+
+    pub(crate) fn blocking_http_client(&self) -> Option<&reqwest::blocking::Client> {
+        self.blocking_http_client.0.as_ref()
+    }
+
+    /// The duration before a cached token expires at which a new token is requested.
+    pub(crate) fn token_refresh_margin(&self) -> time::Duration {
+        time::Duration::try_from(self.force_token_refresh.refresh_margin())
+            .unwrap_or(time::Duration::MAX)
+    }
+
+    /// Get the unexpired token from the cache for [ForceTokenRefresh::CacheOnly].
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn cache_only_token(
+        &self,
+        token_cache: &InMemoryCacheStore<Token>,
+    ) -> Result<Token, AuthExecutionError> {
+        token_cache
+            .get(self.cache_id.as_str())
+            .filter(|token| !token.is_expired())
+            .ok_or_else(|| {
+                AF::msg_err(
+                    "force_token_refresh",
+                    "ForceTokenRefresh::CacheOnly is set and there is no unexpired token in the cache",
+                )
+                .into()
+            })
+    }
+
+    /// Use the cached token when refreshing it failed because the token service is
+    /// unavailable and the token is within the extended lifetime from `ext_expires_in`.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn ext_expires_fallback(
+        &self,
+        cached_token: Token,
+        token_result: AuthExecutionResult<Token>,
+    ) -> AuthExecutionResult<Token> {
+        match token_result {
+            Err(err) if err.is_service_unavailable() && !cached_token.is_ext_expired() => {
+                tracing::debug!(target: CREDENTIAL_EXECUTOR, "token service unavailable; using token from cache until ext_expires_in");
+                Ok(cached_token)
+            }
+            token_result => token_result,
+        }
+    }
+
+    pub(crate) fn with_account_cache(&mut self, account_cache: AccountTokenCache) {
+        self.account_cache = Some(account_cache);
+    }
+
+    /// Store the token in the shared account cache when one is set.
+    pub(crate) fn cache_account_token(&self, token: &Token) {
+        if let Some(account_cache) = self.account_cache.as_ref() {
+            account_cache.store(&self.client_id, token);
+        }
+    }
 }
 
 #[derive(Clone, Default, PartialEq)]
@@ -286,6 +434,7 @@ impl AppConfigBuilder {
                     .unwrap(),
             );
         }
+        self.app_config.update_cache_id();
         self.app_config
     }
 }
@@ -7,33 +7,31 @@ use reqwest::IntoUrl;
 use url::Url;
 use uuid::Uuid;
 
-use graph_core::crypto::{secure_random_32, ProofKeyCodeExchange};
+use graph_core::crypto::{secure_random_32, GenPkce, ProofKeyCodeExchange};
 use graph_error::{IdentityResult, AF};
 
 use crate::identity::{
-    AppConfig, AsQuery, AuthorizationCodeAssertionCredentialBuilder,
-    AuthorizationCodeCredentialBuilder, AuthorizationUrl, AzureCloudInstance, Prompt, ResponseMode,
-    ResponseType,
+    validate_prompt, AppConfig, AsQuery, AuthorizationCodeAssertionCredentialBuilder,
+    AuthorizationCodeCredentialBuilder, AuthorizationResponse, AuthorizationUrl,
+    AuthorizationValidationError, AzureCloudInstance, Prompt, ResponseMode, ResponseType,
+    SilentAuthorizationResult,
 };
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 
 #[cfg(feature = "openssl")]
-use crate::identity::X509Certificate;
+use crate::identity::{AuthorizationCodeCertificateCredentialBuilder, X509Certificate};
 
 #[cfg(feature = "interactive-auth")]
 use {
-    crate::identity::{
-        tracing_targets::INTERACTIVE_AUTH, AuthorizationCodeCertificateCredentialBuilder,
-        AuthorizationResponse, Token,
-    },
+    crate::identity::{tracing_targets::INTERACTIVE_AUTH, Token},
     crate::interactive::{
-        HostOptions, InteractiveAuthEvent, UserEvents, WebViewAuth, WebViewAuthorizationEvent,
-        WebViewHostValidator, WebViewOptions, WithInteractiveAuth,
+        broker_token_silent, HostOptions, InteractiveAuthEvent, UserEvents, WebViewAuth,
+        WebViewAuthorizationEvent, WebViewHostValidator, WebViewOptions, WithInteractiveAuth,
     },
     crate::{Assertion, Secret},
     graph_error::{AuthExecutionError, WebViewError, WebViewResult},
     tao::{event_loop::EventLoopProxy, window::Window},
-    wry::{WebView, WebViewBuilder},
+    wry::{WebContext, WebView, WebViewBuilder},
 };
 
 credential_builder_base!(AuthCodeAuthorizationUrlParameterBuilder);
@@ -100,6 +98,9 @@ pub struct AuthCodeAuthorizationUrlParameters {
     /// to identify the origin of the request.
     /// The nonce is automatically generated unless set by the caller.
     pub(crate) nonce: Option<String>,
+    /// A value included in the request that is also returned in the token response.
+    /// Used to prevent cross-site request forgery attacks. The state is automatically
+    /// generated unless set by the caller.
     pub(crate) state: Option<String>,
     /// Optional
     /// Indicates the type of user interaction that is required. The only valid values at
@@ -136,6 +137,9 @@ pub struct AuthCodeAuthorizationUrlParameters {
     pub(crate) login_hint: Option<String>,
     pub(crate) code_challenge: Option<String>,
     pub(crate) code_challenge_method: Option<String>,
+    /// The code verifier is not sent in the authorization url. It is kept
+    /// so that it can be set on the credential used to redeem the authorization code.
+    pub(crate) pkce: Option<ProofKeyCodeExchange>,
 }
 
 impl Debug for AuthCodeAuthorizationUrlParameters {
@@ -164,13 +168,14 @@ impl AuthCodeAuthorizationUrlParameters {
                 .build(),
             response_type,
             response_mode: None,
-            nonce: None,
-            state: None,
+            nonce: Some(secure_random_32()),
+            state: Some(secure_random_32()),
             prompt: Default::default(),
             domain_hint: None,
             login_hint: None,
             code_challenge: None,
             code_challenge_method: None,
+            pkce: None,
         })
     }
 
@@ -186,21 +191,35 @@ impl AuthCodeAuthorizationUrlParameters {
         self.authorization_url_with_host(azure_cloud_instance)
     }
 
+    /// Create the credential used to redeem the authorization code. The code verifier
+    /// is set on the credential when PKCE was used for the authorization url.
     pub fn into_credential(
         self,
         authorization_code: impl AsRef<str>,
     ) -> AuthorizationCodeCredentialBuilder {
-        AuthorizationCodeCredentialBuilder::new_with_auth_code(authorization_code, self.app_config)
+        let mut credential_builder = AuthorizationCodeCredentialBuilder::new_with_auth_code(
+            authorization_code,
+            self.app_config,
+        );
+        if let Some(pkce) = self.pkce.as_ref() {
+            credential_builder.with_pkce(pkce);
+        }
+        credential_builder
     }
 
     pub fn into_assertion_credential(
         self,
         authorization_code: impl AsRef<str>,
     ) -> AuthorizationCodeAssertionCredentialBuilder {
-        AuthorizationCodeAssertionCredentialBuilder::new_with_auth_code(
-            self.app_config,
-            authorization_code,
-        )
+        let mut credential_builder =
+            AuthorizationCodeAssertionCredentialBuilder::new_with_auth_code(
+                self.app_config,
+                authorization_code,
+            );
+        if let Some(pkce) = self.pkce.as_ref() {
+            credential_builder.with_code_verifier(pkce.code_verifier.as_str());
+        }
+        credential_builder
     }
 
     #[cfg(feature = "openssl")]
@@ -209,11 +228,21 @@ impl AuthCodeAuthorizationUrlParameters {
         authorization_code: impl AsRef<str>,
         x509: &X509Certificate,
     ) -> IdentityResult<AuthorizationCodeCertificateCredentialBuilder> {
-        AuthorizationCodeCertificateCredentialBuilder::new_with_auth_code_and_x509(
-            authorization_code,
-            x509,
-            self.app_config,
-        )
+        let mut credential_builder =
+            AuthorizationCodeCertificateCredentialBuilder::new_with_auth_code_and_x509(
+                authorization_code,
+                x509,
+                self.app_config,
+            )?;
+        if let Some(pkce) = self.pkce.as_ref() {
+            credential_builder.with_code_verifier(pkce.code_verifier.as_str());
+        }
+        Ok(credential_builder)
+    }
+
+    /// Get the [ProofKeyCodeExchange] used for the authorization url.
+    pub fn pkce(&self) -> Option<&ProofKeyCodeExchange> {
+        self.pkce.as_ref()
     }
 
     /// Get the nonce.
@@ -226,20 +255,114 @@ impl AuthCodeAuthorizationUrlParameters {
         self.nonce.as_ref()
     }
 
+    /// Get the state sent in the authorization url.
+    pub fn state(&self) -> Option<&String> {
+        self.state.as_ref()
+    }
+
+    /// Parse and validate the query or fragment of the redirect uri returned from the
+    /// authorization request.
+    ///
+    /// Returns an error if the state is not the same as the state sent in the authorization
+    /// url, if the nonce claim of a returned id token is not the same as the nonce sent,
+    /// or if the authorization server returned an error.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let auth_url_parameters = AuthCodeAuthorizationUrlParameters::builder(client_id)
+    ///     .with_redirect_uri(redirect_uri)
+    ///     .with_scope(vec!["User.Read"])
+    ///     .build();
+    ///
+    /// // Redirect the user to auth_url_parameters.url()? and later in the redirect handler:
+    /// let response = auth_url_parameters.validate_response(request_query)?;
+    /// let code = response.code.unwrap();
+    /// ```
+    pub fn validate_response(
+        &self,
+        query_or_fragment: &str,
+    ) -> Result<AuthorizationResponse, AuthorizationValidationError> {
+        AuthorizationResponse::from_query_or_fragment(query_or_fragment)?
+            .validate(self.state.as_deref(), self.nonce.as_deref())
+    }
+
+    /// The authorization url with prompt=none for silent authorization, such as from a
+    /// hidden iframe, using the existing session of the user. Set a login hint to select
+    /// the account when more than one user is signed in.
+    ///
+    /// Parse the response using [AuthCodeAuthorizationUrlParameters::try_silent_authorization].
+    pub fn silent_authorization_url(&self) -> IdentityResult<Url> {
+        let mut parameters = self.clone();
+        parameters.prompt = BTreeSet::from([Prompt::None]);
+        parameters.url()
+    }
+
+    /// Parse and validate the query or fragment of the redirect uri returned from the
+    /// [silent authorization url](AuthCodeAuthorizationUrlParameters::silent_authorization_url).
+    ///
+    /// Returns [SilentAuthorizationResult::InteractionRequired] when the user has to sign in,
+    /// consent, or select an account, in which case the app falls back to interactive
+    /// authorization using [AuthCodeAuthorizationUrlParameters::url].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // Load auth_url_parameters.silent_authorization_url()? in a hidden iframe and
+    /// // later in the redirect handler:
+    /// match auth_url_parameters.try_silent_authorization(request_query)? {
+    ///     SilentAuthorizationResult::Authorized(response) => {
+    ///         let code = response.code.unwrap();
+    ///     }
+    ///     SilentAuthorizationResult::InteractionRequired(_) => {
+    ///         // Redirect the user to auth_url_parameters.url()?
+    ///     }
+    /// }
+    /// ```
+    pub fn try_silent_authorization(
+        &self,
+        query_or_fragment: &str,
+    ) -> Result<SilentAuthorizationResult, AuthorizationValidationError> {
+        SilentAuthorizationResult::from_validated(self.validate_response(query_or_fragment))
+    }
+
+    /// A copy of the parameters using the redirect uri selected for sign in, such as
+    /// a loopback redirect uri with a free port.
+    #[cfg(feature = "interactive-auth")]
+    pub(crate) fn with_selected_redirect_uri(
+        &self,
+        options: &WebViewOptions,
+    ) -> WebViewResult<AuthCodeAuthorizationUrlParameters> {
+        let redirect_uri = self
+            .redirect_uri()
+            .ok_or_else(|| WebViewError::InvalidUri("redirect uri is required".into()))?;
+        let mut parameters = self.clone();
+        parameters.app_config.redirect_uri = Some(options.select_redirect_uri(redirect_uri)?);
+        Ok(parameters)
+    }
+
     #[cfg(feature = "interactive-auth")]
     pub(crate) fn interactive_webview_authentication(
         &self,
         options: WebViewOptions,
     ) -> WebViewResult<AuthorizationResponse> {
+        if options.use_broker {
+            if let Some(authorization_response) = broker_token_silent(&self.app_config) {
+                tracing::debug!(target: INTERACTIVE_AUTH, "signed in silently using the token broker");
+                return Ok(authorization_response);
+            }
+        }
+
         let uri = self
             .url()
             .map_err(|err| Box::new(AuthExecutionError::from(err)))?;
-        let redirect_uri = self.redirect_uri().cloned().unwrap();
+        let redirect_uri = self
+            .redirect_uri()
+            .cloned()
+            .ok_or_else(|| WebViewError::InvalidUri("redirect uri is required".into()))?;
+        let redirect_uris = options.allowed_redirect_uris(redirect_uri);
         let (sender, receiver) = std::sync::mpsc::channel();
 
         std::thread::spawn(move || {
-            AuthCodeAuthorizationUrlParameters::run(uri, vec![redirect_uri], options, sender)
-                .unwrap();
+            AuthCodeAuthorizationUrlParameters::run(uri, redirect_uris, options, sender).unwrap();
         });
         let mut iter = receiver.try_iter();
         let mut next = iter.next();
@@ -299,12 +422,15 @@ impl AuthCodeAuthorizationUrlParameters {
         let uri = self
             .url()
             .map_err(|err| Box::new(AuthExecutionError::from(err)))?;
-        let redirect_uri = self.redirect_uri().cloned().unwrap();
+        let redirect_uri = self
+            .redirect_uri()
+            .cloned()
+            .ok_or_else(|| WebViewError::InvalidUri("redirect uri is required".into()))?;
+        let redirect_uris = options.allowed_redirect_uris(redirect_uri);
         let (sender, receiver) = std::sync::mpsc::channel();
 
         std::thread::spawn(move || {
-            AuthCodeAuthorizationUrlParameters::run(uri, vec![redirect_uri], options, sender)
-                .unwrap();
+            AuthCodeAuthorizationUrlParameters::run(uri, redirect_uris, options, sender).unwrap();
         });
         let mut iter = receiver.try_iter();
         let mut next = iter.next();
@@ -351,10 +477,12 @@ mod internal {
             host_options: HostOptions,
             window: &Window,
             proxy: EventLoopProxy<UserEvents>,
+            web_context: &mut WebContext,
         ) -> anyhow::Result<WebView> {
             let start_uri = host_options.start_uri.clone();
             let validator = WebViewHostValidator::try_from(host_options)?;
             Ok(WebViewBuilder::new(window)
+                .with_web_context(web_context)
                 .with_url(start_uri.as_ref())
                 // Disables file drop
                 .with_file_drop_handler(|_| true)
@@ -454,6 +582,7 @@ impl AuthorizationUrl for AuthCodeAuthorizationUrlParameters {
             serializer.state(state.as_str());
         }
 
+        validate_prompt(&self.prompt, self.login_hint.as_ref())?;
         if !self.prompt.is_empty() {
             serializer.prompt(&self.prompt.as_query());
         }
@@ -517,13 +646,14 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
                 app_config: AppConfig::new(client_id),
                 response_mode: None,
                 response_type,
-                nonce: None,
-                state: None,
+                nonce: Some(secure_random_32()),
+                state: Some(secure_random_32()),
                 prompt: Default::default(),
                 domain_hint: None,
                 login_hint: None,
                 code_challenge: None,
                 code_challenge_method: None,
+                pkce: None,
             },
         }
     }
@@ -538,13 +668,14 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
                 app_config,
                 response_mode: None,
                 response_type,
-                nonce: None,
-                state: None,
+                nonce: Some(secure_random_32()),
+                state: Some(secure_random_32()),
                 prompt: Default::default(),
                 domain_hint: None,
                 login_hint: None,
                 code_challenge: None,
                 code_challenge_method: None,
+                pkce: None,
             },
         }
     }
@@ -604,6 +735,13 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
         self
     }
 
+    /// Generates a secure random state. A state is generated when the builder
+    /// is created, use this to replace it.
+    pub fn with_generated_state(&mut self) -> &mut Self {
+        self.credential.state = Some(secure_random_32());
+        self
+    }
+
     /// Indicates the type of user interaction that is required. Valid values are login, none,
     /// consent, and select_account.
     ///
@@ -650,14 +788,23 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
     }
 
     /// Sets the code_challenge and code_challenge_method using the [ProofKeyCodeExchange]
-    /// Callers should keep the [ProofKeyCodeExchange] and provide it to the credential
-    /// builder in order to set the client verifier and request an access token.
+    /// The code verifier is set on the credential builder returned from
+    /// [with_auth_code](AuthCodeAuthorizationUrlParameterBuilder::with_auth_code) or
+    /// [into_credential](AuthCodeAuthorizationUrlParameters::into_credential).
     pub fn with_pkce(&mut self, proof_key_for_code_exchange: &ProofKeyCodeExchange) -> &mut Self {
         self.with_code_challenge(proof_key_for_code_exchange.code_challenge.as_str());
         self.with_code_challenge_method(proof_key_for_code_exchange.code_challenge_method.as_str());
+        self.credential.pkce = Some(proof_key_for_code_exchange.clone());
         self
     }
 
+    /// Generate a secure random code verifier and S256 code challenge and set the
+    /// code_challenge and code_challenge_method. See [with_pkce](AuthCodeAuthorizationUrlParameterBuilder::with_pkce)
+    pub fn with_pkce_oneshot(&mut self) -> IdentityResult<&mut Self> {
+        let pkce = ProofKeyCodeExchange::oneshot()?;
+        Ok(self.with_pkce(&pkce))
+    }
+
     pub fn build(&self) -> AuthCodeAuthorizationUrlParameters {
         self.credential.clone()
     }
@@ -674,20 +821,15 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
         self,
         authorization_code: impl AsRef<str>,
     ) -> AuthorizationCodeCredentialBuilder {
-        AuthorizationCodeCredentialBuilder::new_with_auth_code(
-            authorization_code,
-            self.credential.app_config,
-        )
+        self.credential.into_credential(authorization_code)
     }
 
     pub fn with_auth_code_assertion(
         self,
         authorization_code: impl AsRef<str>,
     ) -> AuthorizationCodeAssertionCredentialBuilder {
-        AuthorizationCodeAssertionCredentialBuilder::new_with_auth_code(
-            self.credential.app_config,
-            authorization_code,
-        )
+        self.credential
+            .into_assertion_credential(authorization_code)
     }
 
     #[cfg(feature = "openssl")]
@@ -696,11 +838,8 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
         authorization_code: impl AsRef<str>,
         x509: &X509Certificate,
     ) -> IdentityResult<AuthorizationCodeCertificateCredentialBuilder> {
-        AuthorizationCodeCertificateCredentialBuilder::new_with_auth_code_and_x509(
-            authorization_code,
-            x509,
-            self.credential.app_config,
-        )
+        self.credential
+            .into_certificate_credential(authorization_code, x509)
     }
 }
 
@@ -713,9 +852,8 @@ impl WithInteractiveAuth<Secret> for AuthCodeAuthorizationUrlParameterBuilder {
         auth_type: Secret,
         options: WebViewOptions,
     ) -> WebViewResult<WebViewAuthorizationEvent<Self::CredentialBuilder>> {
-        let authorization_response = self
-            .credential
-            .interactive_webview_authentication(options)?;
+        let credential = self.credential.with_selected_redirect_uri(&options)?;
+        let authorization_response = credential.interactive_webview_authentication(options)?;
 
         if authorization_response.is_err() {
             tracing::debug!(target: INTERACTIVE_AUTH, "error in authorization query or fragment from redirect uri");
@@ -728,13 +866,10 @@ impl WithInteractiveAuth<Secret> for AuthCodeAuthorizationUrlParameterBuilder {
 
         let mut credential_builder = {
             if let Some(authorization_code) = authorization_response.code.as_ref() {
-                AuthorizationCodeCredentialBuilder::new_with_auth_code(
-                    authorization_code,
-                    self.credential.app_config.clone(),
-                )
+                credential.clone().into_credential(authorization_code)
             } else {
                 AuthorizationCodeCredentialBuilder::new_with_token(
-                    self.credential.app_config.clone(),
+                    credential.app_config.clone(),
                     Token::try_from(authorization_response.clone())?,
                 )
             }
@@ -757,9 +892,8 @@ impl WithInteractiveAuth<Assertion> for AuthCodeAuthorizationUrlParameterBuilder
         auth_type: Assertion,
         options: WebViewOptions,
     ) -> WebViewResult<WebViewAuthorizationEvent<Self::CredentialBuilder>> {
-        let authorization_response = self
-            .credential
-            .interactive_webview_authentication(options)?;
+        let credential = self.credential.with_selected_redirect_uri(&options)?;
+        let authorization_response = credential.interactive_webview_authentication(options)?;
 
         if authorization_response.is_err() {
             tracing::debug!(target: INTERACTIVE_AUTH, "error in authorization query or fragment from redirect uri");
@@ -771,13 +905,12 @@ impl WithInteractiveAuth<Assertion> for AuthCodeAuthorizationUrlParameterBuilder
         tracing::debug!(target: INTERACTIVE_AUTH, "parsed authorization query or fragment from redirect uri");
         let mut credential_builder = {
             if let Some(authorization_code) = authorization_response.code.as_ref() {
-                AuthorizationCodeAssertionCredentialBuilder::new_with_auth_code(
-                    self.credential.app_config.clone(),
-                    authorization_code,
-                )
+                credential
+                    .clone()
+                    .into_assertion_credential(authorization_code)
             } else {
                 AuthorizationCodeAssertionCredentialBuilder::new_with_token(
-                    self.credential.app_config.clone(),
+                    credential.app_config.clone(),
                     Token::try_from(authorization_response.clone())?,
                 )
             }
@@ -801,9 +934,8 @@ impl WithInteractiveAuth<&X509Certificate> for AuthCodeAuthorizationUrlParameter
         auth_type: &X509Certificate,
         options: WebViewOptions,
     ) -> WebViewResult<WebViewAuthorizationEvent<Self::CredentialBuilder>> {
-        let authorization_response = self
-            .credential
-            .interactive_webview_authentication(options)?;
+        let credential = self.credential.with_selected_redirect_uri(&options)?;
+        let authorization_response = credential.interactive_webview_authentication(options)?;
 
         if authorization_response.is_err() {
             tracing::debug!(target: INTERACTIVE_AUTH, "error in authorization query or fragment from redirect uri");
@@ -815,16 +947,14 @@ impl WithInteractiveAuth<&X509Certificate> for AuthCodeAuthorizationUrlParameter
         tracing::debug!(target: INTERACTIVE_AUTH, "parsed authorization query or fragment from redirect uri");
         let mut credential_builder = {
             if let Some(authorization_code) = authorization_response.code.as_ref() {
-                AuthorizationCodeCertificateCredentialBuilder::new_with_auth_code_and_x509(
-                    authorization_code,
-                    auth_type,
-                    self.credential.app_config.clone(),
-                )?
+                credential
+                    .clone()
+                    .into_certificate_credential(authorization_code, auth_type)?
             } else {
                 AuthorizationCodeCertificateCredentialBuilder::new_with_token(
                     Token::try_from(authorization_response.clone())?,
                     auth_type,
-                    self.credential.app_config.clone(),
+                    credential.app_config.clone(),
                 )?
             }
         };
@@ -840,6 +970,7 @@ impl WithInteractiveAuth<&X509Certificate> for AuthCodeAuthorizationUrlParameter
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::identity::TokenCredentialExecutor;
 
     #[test]
     fn serialize_uri() {
@@ -916,4 +1047,97 @@ mod test {
         let query = url.query().unwrap();
         assert!(query.contains("nonce"));
     }
+
+    #[test]
+    fn pkce_oneshot_sets_code_verifier_on_credential() {
+        let mut builder = AuthCodeAuthorizationUrlParameters::builder(Uuid::new_v4());
+        builder
+            .with_redirect_uri(Url::parse("https://localhost:8080").unwrap())
+            .with_scope(["read", "write"])
+            .with_pkce_oneshot()
+            .unwrap();
+
+        let pkce = builder.build().pkce().cloned().unwrap();
+        let url = builder.url().unwrap();
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!(pkce.code_challenge, query["code_challenge"]);
+        assert_eq!("S256", query["code_challenge_method"]);
+        assert!(!query.contains_key("code_verifier"));
+
+        let mut confidential_client = builder
+            .with_auth_code("code")
+            .with_client_secret("secret")
+            .build();
+        let form = confidential_client.form_urlencode().unwrap();
+        assert_eq!(Some(&pkce.code_verifier), form.get("code_verifier"));
+    }
+
+    #[test]
+    fn silent_authorization() {
+        let auth_url_parameters = AuthCodeAuthorizationUrlParameters::builder(Uuid::new_v4())
+            .with_redirect_uri(Url::parse("https://localhost:8080").unwrap())
+            .with_scope(["User.Read"])
+            .with_prompt(Prompt::Login)
+            .with_login_hint("user@contoso.com")
+            .build();
+
+        let query: HashMap<String, String> = auth_url_parameters
+            .silent_authorization_url()
+            .unwrap()
+            .query_pairs()
+            .into_owned()
+            .collect();
+        assert_eq!("none", query["prompt"]);
+        assert_eq!("user@contoso.com", query["login_hint"]);
+
+        let state = auth_url_parameters.state().cloned().unwrap();
+        let result = auth_url_parameters
+            .try_silent_authorization(&format!("?code=code&state={state}"))
+            .unwrap();
+        assert!(matches!(result, SilentAuthorizationResult::Authorized(_)));
+
+        let result = auth_url_parameters
+            .try_silent_authorization(&format!(
+                "?error=login_required&error_description=AADSTS50058%3A+No+user+signed+in&state={state}"
+            ))
+            .unwrap();
+        assert!(result.is_interaction_required());
+
+        assert!(matches!(
+            auth_url_parameters.try_silent_authorization(&format!(
+                "?error=invalid_request&error_description=AADSTS90014%3A+Missing+field&state={state}"
+            )),
+            Err(AuthorizationValidationError::Authorization(_))
+        ));
+    }
+
+    #[test]
+    fn validate_response_state() {
+        let auth_url_parameters = AuthCodeAuthorizationUrlParameters::builder(Uuid::new_v4())
+            .with_redirect_uri(Url::parse("https://localhost:8080").unwrap())
+            .with_scope(["read", "write"])
+            .build();
+
+        let state = auth_url_parameters.state().cloned().unwrap();
+        let query: HashMap<String, String> = auth_url_parameters
+            .url()
+            .unwrap()
+            .query_pairs()
+            .into_owned()
+            .collect();
+        assert_eq!(state, query["state"]);
+
+        let response = auth_url_parameters
+            .validate_response(&format!("?code=code&state={state}"))
+            .unwrap();
+        assert_eq!(Some("code".to_string()), response.code);
+
+        let err = auth_url_parameters
+            .validate_response("?code=code&state=forged")
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AuthorizationValidationError::StateMismatch { .. }
+        ));
+    }
 }
@@ -14,11 +14,12 @@ use graph_core::identity::ForceTokenRefresh;
 use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
 
 use crate::identity::credentials::app_config::AppConfig;
+use crate::identity::credentials::{Grant, TokenRequestForm};
 use crate::identity::{
     AuthCodeAuthorizationUrlParameterBuilder, Authority, AzureCloudInstance,
-    ConfidentialClientApplication, Token, TokenCredentialExecutor, CLIENT_ASSERTION_TYPE,
+    ConfidentialClientApplication, SecretString, Token, TokenCredentialExecutor,
+    CLIENT_ASSERTION_TYPE,
 };
-use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 
 credential_builder!(
     AuthorizationCodeAssertionCredentialBuilder,
@@ -40,7 +41,7 @@ pub struct AuthorizationCodeAssertionCredential {
     pub(crate) authorization_code: Option<String>,
     /// The refresh token needed to make an access token request using a refresh token.
     /// Do not include an authorization code when using a refresh token.
-    pub(crate) refresh_token: Option<String>,
+    pub(crate) refresh_token: Option<SecretString>,
     /// The same code_verifier that was used to obtain the authorization_code.
     /// Required if PKCE was used in the authorization code grant request. For more information,
     /// see the PKCE RFC https://datatracker.ietf.org/doc/html/rfc7636.
@@ -50,7 +51,7 @@ pub struct AuthorizationCodeAssertionCredential {
     /// An assertion (a JSON web token) that you need to create and sign with the certificate
     /// you registered as credentials for your application. Read about certificate credentials
     /// to learn how to register your certificate and the format of the assertion.
-    pub(crate) client_assertion: String,
+    pub(crate) client_assertion: SecretString,
     token_cache: InMemoryCacheStore<Token>,
 }
 
@@ -85,7 +86,7 @@ impl AuthorizationCodeAssertionCredential {
             refresh_token: None,
             code_verifier: None,
             client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
-            client_assertion: client_assertion.as_ref().to_owned(),
+            client_assertion: SecretString::new(client_assertion.as_ref()),
             token_cache: Default::default(),
         })
     }
@@ -117,9 +118,10 @@ impl AuthorizationCodeAssertionCredential {
 
         let new_token: Token = response.json()?;
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
 
         if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
+            self.refresh_token = new_token.refresh_token.clone().map(SecretString::new);
         }
 
         Ok(new_token)
@@ -140,10 +142,11 @@ impl AuthorizationCodeAssertionCredential {
         let new_token: Token = response.json().await?;
 
         if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
+            self.refresh_token = new_token.refresh_token.clone().map(SecretString::new);
         }
 
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
         Ok(new_token)
     }
 }
@@ -156,7 +159,7 @@ impl TokenCache for AuthorizationCodeAssertionCredential {
         let cache_id = self.app_config.cache_id.to_string();
 
         match self.app_config.force_token_refresh {
-            ForceTokenRefresh::Never => {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
                 // Attempt to bypass a read on the token store by using previous
                 // refresh token stored outside of RwLock
                 if self.refresh_token.is_some() {
@@ -166,12 +169,13 @@ impl TokenCache for AuthorizationCodeAssertionCredential {
                 }
 
                 if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-                    if token.is_expired_sub(time::Duration::minutes(5)) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
                         if let Some(refresh_token) = token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(SecretString::new(refresh_token));
                         }
 
-                        self.execute_cached_token_refresh(cache_id)
+                        let token_result = self.execute_cached_token_refresh(cache_id);
+                        self.app_config.ext_expires_fallback(token, token_result)
                     } else {
                         Ok(token)
                     }
@@ -179,6 +183,7 @@ impl TokenCache for AuthorizationCodeAssertionCredential {
                     self.execute_cached_token_refresh(cache_id)
                 }
             }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 let token_result = self.execute_cached_token_refresh(cache_id);
                 if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
@@ -193,7 +198,7 @@ impl TokenCache for AuthorizationCodeAssertionCredential {
         let cache_id = self.app_config.cache_id.to_string();
 
         match self.app_config.force_token_refresh {
-            ForceTokenRefresh::Never => {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
                 // Attempt to bypass a read on the token store by using previous
                 // refresh token stored outside of RwLock
                 if self.refresh_token.is_some() {
@@ -206,12 +211,14 @@ impl TokenCache for AuthorizationCodeAssertionCredential {
                 }
 
                 if let Some(old_token) = self.token_cache.get(cache_id.as_str()) {
-                    if old_token.is_expired_sub(time::Duration::minutes(5)) {
+                    if old_token.is_expired_sub(self.app_config.token_refresh_margin()) {
                         if let Some(refresh_token) = old_token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(SecretString::new(refresh_token));
                         }
 
-                        self.execute_cached_token_refresh_async(cache_id).await
+                        let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                        self.app_config
+                            .ext_expires_fallback(old_token, token_result)
                     } else {
                         Ok(old_token.clone())
                     }
@@ -219,6 +226,7 @@ impl TokenCache for AuthorizationCodeAssertionCredential {
                     self.execute_cached_token_refresh_async(cache_id).await
                 }
             }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 let token_result = self.execute_cached_token_refresh_async(cache_id).await;
                 if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
@@ -237,89 +245,42 @@ impl TokenCache for AuthorizationCodeAssertionCredential {
 #[async_trait]
 impl TokenCredentialExecutor for AuthorizationCodeAssertionCredential {
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
-        let mut serializer = AuthSerializer::new();
         let client_id = self.app_config.client_id.to_string();
         if client_id.is_empty() || self.app_config.client_id.is_nil() {
-            return AF::result(AuthParameter::ClientId);
-        }
-
-        if self.client_assertion.trim().is_empty() {
-            return AF::result(AuthParameter::ClientAssertion);
+            return AF::result("client_id");
         }
 
         if self.client_assertion_type.trim().is_empty() {
             self.client_assertion_type = CLIENT_ASSERTION_TYPE.to_owned();
         }
 
-        serializer
-            .client_id(client_id.as_str())
-            .client_assertion(self.client_assertion.as_str())
-            .client_assertion_type(self.client_assertion_type.as_str())
-            .set_scope(self.app_config.scope.clone());
-
-        if let Some(redirect_uri) = self.app_config.redirect_uri.as_ref() {
-            serializer.redirect_uri(redirect_uri.as_str());
-        }
-
-        if let Some(code_verifier) = self.code_verifier.as_ref() {
-            serializer.code_verifier(code_verifier.as_ref());
-        }
-
-        if let Some(refresh_token) = self.refresh_token.as_ref() {
-            if refresh_token.trim().is_empty() {
-                return AF::msg_result(
-                    AuthParameter::RefreshToken.alias(),
-                    "refresh_token is empty - cannot be an empty string",
-                );
-            }
-
-            serializer
-                .refresh_token(refresh_token.as_ref())
-                .grant_type("refresh_token");
-
-            return serializer.as_credential_map(
-                vec![AuthParameter::Scope],
-                vec![
-                    AuthParameter::RefreshToken,
-                    AuthParameter::ClientId,
-                    AuthParameter::GrantType,
-                    AuthParameter::ClientAssertion,
-                    AuthParameter::ClientAssertionType,
-                ],
-            );
+        let grant = if let Some(refresh_token) = self.refresh_token.as_ref() {
+            Grant::refresh_token(refresh_token.expose_secret())?
         } else if let Some(authorization_code) = self.authorization_code.as_ref() {
-            if authorization_code.trim().is_empty() {
-                return AF::msg_result(
-                    AuthParameter::AuthorizationCode.alias(),
-                    "authorization_code is empty - cannot be an empty string",
-                );
-            }
-
-            serializer
-                .authorization_code(authorization_code.as_str())
-                .grant_type("authorization_code");
-
-            return serializer.as_credential_map(
-                vec![AuthParameter::Scope, AuthParameter::CodeVerifier],
-                vec![
-                    AuthParameter::AuthorizationCode,
-                    AuthParameter::ClientId,
-                    AuthParameter::GrantType,
-                    AuthParameter::RedirectUri,
-                    AuthParameter::ClientAssertion,
-                    AuthParameter::ClientAssertionType,
-                ],
+            let redirect_uri = self
+                .app_config
+                .redirect_uri
+                .as_ref()
+                .ok_or_else(|| AF::required("redirect_uri"))?;
+            Grant::authorization_code(
+                authorization_code,
+                Some(redirect_uri.to_string()),
+                self.code_verifier.clone(),
+            )?
+        } else {
+            return AF::msg_result(
+                "code or refresh_token",
+                "Either authorization code or refresh token is required",
             );
-        }
+        };
 
-        AF::msg_result(
-            format!(
-                "{} or {}",
-                AuthParameter::AuthorizationCode.alias(),
-                AuthParameter::RefreshToken.alias()
-            ),
-            "Either authorization code or refresh token is required",
-        )
+        TokenRequestForm::new(client_id, grant)
+            .client_assertion(
+                self.client_assertion_type.as_str(),
+                self.client_assertion.expose_secret(),
+            )?
+            .scope(&self.app_config.scope)
+            .into_form()
     }
 
     fn client_id(&self) -> &Uuid {
@@ -337,6 +298,18 @@ impl TokenCredentialExecutor for AuthorizationCodeAssertionCredential {
     fn app_config(&self) -> &AppConfig {
         &self.app_config
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.app_config.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.app_config.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.app_config.with_tenant(tenant_id);
+    }
 }
 
 #[derive(Clone)]
@@ -366,7 +339,7 @@ impl AuthorizationCodeAssertionCredentialBuilder {
                 refresh_token: None,
                 code_verifier: None,
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
-                client_assertion: String::new(),
+                client_assertion: SecretString::default(),
                 token_cache: Default::default(),
             },
         }
@@ -388,7 +361,7 @@ impl AuthorizationCodeAssertionCredentialBuilder {
                 refresh_token: None,
                 code_verifier: None,
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
-                client_assertion: String::new(),
+                client_assertion: SecretString::default(),
                 token_cache,
             },
         }
@@ -406,7 +379,7 @@ impl AuthorizationCodeAssertionCredentialBuilder {
                 refresh_token: None,
                 code_verifier: None,
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
-                client_assertion: assertion.as_ref().to_owned(),
+                client_assertion: SecretString::new(assertion.as_ref()),
                 token_cache: Default::default(),
             },
         }
@@ -419,7 +392,7 @@ impl AuthorizationCodeAssertionCredentialBuilder {
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
         self.credential.authorization_code = None;
-        self.credential.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.credential.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
         self
     }
 
@@ -434,7 +407,7 @@ impl AuthorizationCodeAssertionCredentialBuilder {
     }
 
     pub fn with_client_assertion<T: AsRef<str>>(&mut self, client_assertion: T) -> &mut Self {
-        self.credential.client_assertion = client_assertion.as_ref().to_owned();
+        self.credential.client_assertion = SecretString::new(client_assertion.as_ref());
         self
     }
 
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use http::{HeaderMap, HeaderName, HeaderValue};
@@ -13,14 +14,20 @@ use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
 use graph_core::identity::ForceTokenRefresh;
 use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
 
+#[cfg(all(feature = "openssl", feature = "interactive-auth"))]
+use crate::identity::AuthorizationResponse;
 #[cfg(feature = "openssl")]
-use crate::identity::{AuthorizationResponse, X509Certificate};
+use crate::identity::{
+    credentials::certificate_provider::{sign_client_assertion, sign_client_assertion_async},
+    CertificateProvider, X509Certificate,
+};
 
+use crate::identity::credentials::{Grant, RemoteAssertionSigner, TokenRequestForm};
 use crate::identity::{
     AppConfig, AuthCodeAuthorizationUrlParameterBuilder, Authority, AzureCloudInstance,
-    ConfidentialClientApplication, Token, TokenCredentialExecutor, CLIENT_ASSERTION_TYPE,
+    ConfidentialClientApplication, RemoteSigner, SecretString, Token, TokenCredentialExecutor,
+    CLIENT_ASSERTION_TYPE,
 };
-use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 
 credential_builder!(
     AuthorizationCodeCertificateCredentialBuilder,
@@ -86,7 +93,7 @@ pub struct AuthorizationCodeCertificateCredential {
     pub(crate) authorization_code: Option<String>,
     /// The refresh token needed to make an access token request using a refresh token.
     /// Do not include an authorization code when using a refresh token.
-    pub(crate) refresh_token: Option<String>,
+    pub(crate) refresh_token: Option<SecretString>,
     /// The same code_verifier that was used to obtain the authorization_code.
     /// Required if PKCE was used in the authorization code grant request. For more information,
     /// see the PKCE RFC https://datatracker.ietf.org/doc/html/rfc7636.
@@ -96,7 +103,12 @@ pub struct AuthorizationCodeCertificateCredential {
     /// An assertion (a JSON web token) that you need to create and sign with the certificate
     /// you registered as credentials for your application. Read about certificate credentials
     /// to learn how to register your certificate and the format of the assertion.
-    pub(crate) client_assertion: String,
+    pub(crate) client_assertion: SecretString,
+    /// Provides the certificate used to sign a new client assertion before each token request.
+    #[cfg(feature = "openssl")]
+    certificate_provider: Option<Arc<dyn CertificateProvider>>,
+    /// Signs a new client assertion when the client assertion is about to expire.
+    remote_signer: Option<RemoteAssertionSigner>,
     token_cache: InMemoryCacheStore<Token>,
 }
 
@@ -130,7 +142,10 @@ impl AuthorizationCodeCertificateCredential {
             refresh_token: None,
             code_verifier: None,
             client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
-            client_assertion: client_assertion.as_ref().to_owned(),
+            client_assertion: SecretString::new(client_assertion.as_ref()),
+            #[cfg(feature = "openssl")]
+            certificate_provider: None,
+            remote_signer: None,
             token_cache: Default::default(),
         })
     }
@@ -154,7 +169,43 @@ impl AuthorizationCodeCertificateCredential {
         AuthCodeAuthorizationUrlParameterBuilder::new(client_id)
     }
 
+    /// Sign a new client assertion when a remote signer or certificate provider is used.
+    #[allow(clippy::result_large_err)]
+    fn refresh_client_assertion(&mut self) -> AuthExecutionResult<()> {
+        let tenant_id = self.app_config.authority.tenant_id().cloned();
+        if let Some(remote_signer) = self.remote_signer.as_ref() {
+            self.client_assertion =
+                remote_signer.sign(&self.app_config.client_id.to_string(), tenant_id)?;
+            return Ok(());
+        }
+
+        #[cfg(feature = "openssl")]
+        if let Some(certificate_provider) = self.certificate_provider.as_ref() {
+            self.client_assertion =
+                sign_client_assertion(certificate_provider.as_ref(), tenant_id)?;
+        }
+        Ok(())
+    }
+
+    async fn refresh_client_assertion_async(&mut self) -> AuthExecutionResult<()> {
+        let tenant_id = self.app_config.authority.tenant_id().cloned();
+        if let Some(remote_signer) = self.remote_signer.clone() {
+            self.client_assertion = remote_signer
+                .sign_async(&self.app_config.client_id.to_string(), tenant_id)
+                .await?;
+            return Ok(());
+        }
+
+        #[cfg(feature = "openssl")]
+        if let Some(certificate_provider) = self.certificate_provider.clone() {
+            self.client_assertion =
+                sign_client_assertion_async(certificate_provider.as_ref(), tenant_id).await?;
+        }
+        Ok(())
+    }
+
     fn execute_cached_token_refresh(&mut self, cache_id: String) -> AuthExecutionResult<Token> {
+        self.refresh_client_assertion()?;
         let response = self.execute()?;
 
         if !response.status().is_success() {
@@ -165,9 +216,10 @@ impl AuthorizationCodeCertificateCredential {
 
         let new_token: Token = response.json()?;
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
 
         if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
+            self.refresh_token = new_token.refresh_token.clone().map(SecretString::new);
         }
 
         Ok(new_token)
@@ -177,6 +229,7 @@ impl AuthorizationCodeCertificateCredential {
         &mut self,
         cache_id: String,
     ) -> AuthExecutionResult<Token> {
+        self.refresh_client_assertion_async().await?;
         let response = self.execute_async().await?;
 
         if !response.status().is_success() {
@@ -188,10 +241,11 @@ impl AuthorizationCodeCertificateCredential {
         let new_token: Token = response.json().await?;
 
         if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
+            self.refresh_token = new_token.refresh_token.clone().map(SecretString::new);
         }
 
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
         Ok(new_token)
     }
 }
@@ -204,7 +258,7 @@ impl TokenCache for AuthorizationCodeCertificateCredential {
         let cache_id = self.app_config.cache_id.to_string();
 
         match self.app_config.force_token_refresh {
-            ForceTokenRefresh::Never => {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
                 // Attempt to bypass a read on the token store by using previous
                 // refresh token stored outside of RwLock
                 if self.refresh_token.is_some() {
@@ -214,12 +268,13 @@ impl TokenCache for AuthorizationCodeCertificateCredential {
                 }
 
                 if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-                    if token.is_expired_sub(time::Duration::minutes(5)) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
                         if let Some(refresh_token) = token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(SecretString::new(refresh_token));
                         }
 
-                        self.execute_cached_token_refresh(cache_id)
+                        let token_result = self.execute_cached_token_refresh(cache_id);
+                        self.app_config.ext_expires_fallback(token, token_result)
                     } else {
                         Ok(token)
                     }
@@ -227,6 +282,7 @@ impl TokenCache for AuthorizationCodeCertificateCredential {
                     self.execute_cached_token_refresh(cache_id)
                 }
             }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 let token_result = self.execute_cached_token_refresh(cache_id);
                 if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
@@ -241,7 +297,7 @@ impl TokenCache for AuthorizationCodeCertificateCredential {
         let cache_id = self.app_config.cache_id.to_string();
 
         match self.app_config.force_token_refresh {
-            ForceTokenRefresh::Never => {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
                 // Attempt to bypass a read on the token store by using previous
                 // refresh token stored outside of RwLock
                 if self.refresh_token.is_some() {
@@ -254,12 +310,14 @@ impl TokenCache for AuthorizationCodeCertificateCredential {
                 }
 
                 if let Some(old_token) = self.token_cache.get(cache_id.as_str()) {
-                    if old_token.is_expired_sub(time::Duration::minutes(5)) {
+                    if old_token.is_expired_sub(self.app_config.token_refresh_margin()) {
                         if let Some(refresh_token) = old_token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(SecretString::new(refresh_token));
                         }
 
-                        self.execute_cached_token_refresh_async(cache_id).await
+                        let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                        self.app_config
+                            .ext_expires_fallback(old_token, token_result)
                     } else {
                         Ok(old_token.clone())
                     }
@@ -267,6 +325,7 @@ impl TokenCache for AuthorizationCodeCertificateCredential {
                     self.execute_cached_token_refresh_async(cache_id).await
                 }
             }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 let token_result = self.execute_cached_token_refresh_async(cache_id).await;
                 if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
@@ -285,89 +344,42 @@ impl TokenCache for AuthorizationCodeCertificateCredential {
 #[async_trait]
 impl TokenCredentialExecutor for AuthorizationCodeCertificateCredential {
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
-        let mut serializer = AuthSerializer::new();
         let client_id = self.app_config.client_id.to_string();
         if client_id.is_empty() || self.app_config.client_id.is_nil() {
-            return AF::result(AuthParameter::ClientId);
-        }
-
-        if self.client_assertion.trim().is_empty() {
-            return AF::result(AuthParameter::ClientAssertion);
+            return AF::result("client_id");
         }
 
         if self.client_assertion_type.trim().is_empty() {
             self.client_assertion_type = CLIENT_ASSERTION_TYPE.to_owned();
         }
 
-        serializer
-            .client_id(client_id.as_str())
-            .client_assertion(self.client_assertion.as_str())
-            .client_assertion_type(self.client_assertion_type.as_str())
-            .set_scope(self.app_config.scope.clone());
-
-        if let Some(redirect_uri) = self.app_config.redirect_uri.as_ref() {
-            serializer.redirect_uri(redirect_uri.as_str());
-        }
-
-        if let Some(code_verifier) = self.code_verifier.as_ref() {
-            serializer.code_verifier(code_verifier.as_ref());
-        }
-
-        if let Some(refresh_token) = self.refresh_token.as_ref() {
-            if refresh_token.trim().is_empty() {
-                return AF::msg_result(
-                    AuthParameter::RefreshToken.alias(),
-                    "refresh_token is empty - cannot be an empty string",
-                );
-            }
-
-            serializer
-                .refresh_token(refresh_token.as_ref())
-                .grant_type("refresh_token");
-
-            return serializer.as_credential_map(
-                vec![AuthParameter::Scope],
-                vec![
-                    AuthParameter::RefreshToken,
-                    AuthParameter::ClientId,
-                    AuthParameter::GrantType,
-                    AuthParameter::ClientAssertion,
-                    AuthParameter::ClientAssertionType,
-                ],
-            );
+        let grant = if let Some(refresh_token) = self.refresh_token.as_ref() {
+            Grant::refresh_token(refresh_token.expose_secret())?
         } else if let Some(authorization_code) = self.authorization_code.as_ref() {
-            if authorization_code.trim().is_empty() {
-                return AF::msg_result(
-                    AuthParameter::AuthorizationCode.alias(),
-                    "authorization_code is empty - cannot be an empty string",
-                );
-            }
-
-            serializer
-                .authorization_code(authorization_code.as_str())
-                .grant_type("authorization_code");
-
-            return serializer.as_credential_map(
-                vec![AuthParameter::Scope, AuthParameter::CodeVerifier],
-                vec![
-                    AuthParameter::AuthorizationCode,
-                    AuthParameter::ClientId,
-                    AuthParameter::GrantType,
-                    AuthParameter::RedirectUri,
-                    AuthParameter::ClientAssertion,
-                    AuthParameter::ClientAssertionType,
-                ],
+            let redirect_uri = self
+                .app_config
+                .redirect_uri
+                .as_ref()
+                .ok_or_else(|| AF::required("redirect_uri"))?;
+            Grant::authorization_code(
+                authorization_code,
+                Some(redirect_uri.to_string()),
+                self.code_verifier.clone(),
+            )?
+        } else {
+            return AF::msg_result(
+                "code or refresh_token",
+                "Either authorization code or refresh token is required",
             );
-        }
+        };
 
-        AF::msg_result(
-            format!(
-                "{} or {}",
-                AuthParameter::AuthorizationCode.alias(),
-                AuthParameter::RefreshToken.alias()
-            ),
-            "Either authorization code or refresh token is required",
-        )
+        TokenRequestForm::new(client_id, grant)
+            .client_assertion(
+                self.client_assertion_type.as_str(),
+                self.client_assertion.expose_secret(),
+            )?
+            .scope(&self.app_config.scope)
+            .into_form()
     }
 
     fn client_id(&self) -> &Uuid {
@@ -385,6 +397,18 @@ impl TokenCredentialExecutor for AuthorizationCodeCertificateCredential {
     fn app_config(&self) -> &AppConfig {
         &self.app_config
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.app_config.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.app_config.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.app_config.with_tenant(tenant_id);
+    }
 }
 
 #[derive(Clone)]
@@ -406,7 +430,10 @@ impl AuthorizationCodeCertificateCredentialBuilder {
                 refresh_token: None,
                 code_verifier: None,
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
-                client_assertion: String::new(),
+                client_assertion: SecretString::default(),
+                #[cfg(feature = "openssl")]
+                certificate_provider: None,
+                remote_signer: None,
                 token_cache: Default::default(),
             },
         };
@@ -433,7 +460,10 @@ impl AuthorizationCodeCertificateCredentialBuilder {
                 refresh_token: None,
                 code_verifier: None,
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
-                client_assertion: String::new(),
+                client_assertion: SecretString::default(),
+                #[cfg(feature = "openssl")]
+                certificate_provider: None,
+                remote_signer: None,
                 token_cache,
             },
         };
@@ -443,6 +473,7 @@ impl AuthorizationCodeCertificateCredentialBuilder {
     }
 
     #[allow(unused)]
+    #[cfg(feature = "interactive-auth")]
     #[cfg(feature = "openssl")]
     pub(crate) fn new_authorization_response(
         value: (AppConfig, AuthorizationResponse, &X509Certificate),
@@ -470,7 +501,7 @@ impl AuthorizationCodeCertificateCredentialBuilder {
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
         self.credential.authorization_code = None;
-        self.credential.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.credential.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
         self
     }
 
@@ -499,8 +530,28 @@ impl AuthorizationCodeCertificateCredentialBuilder {
         Ok(self)
     }
 
+    /// Sign client assertions using a service that holds the private key, such as
+    /// Azure Key Vault, instead of a certificate in the memory of the application.
+    /// A client assertion is signed before a token request when the previous one is
+    /// about to expire.
+    pub fn with_remote_signer<S: RemoteSigner + 'static>(&mut self, remote_signer: S) -> &mut Self {
+        self.credential.remote_signer = Some(RemoteAssertionSigner::new(Arc::new(remote_signer)));
+        self
+    }
+
+    /// Sign a new client assertion before each token request using the certificate of the
+    /// provider, so that a rotated certificate is used without creating a new credential.
+    #[cfg(feature = "openssl")]
+    pub fn with_certificate_provider<P: CertificateProvider + 'static>(
+        &mut self,
+        certificate_provider: P,
+    ) -> &mut Self {
+        self.credential.certificate_provider = Some(Arc::new(certificate_provider));
+        self
+    }
+
     pub fn with_client_assertion<T: AsRef<str>>(&mut self, client_assertion: T) -> &mut Self {
-        self.credential.client_assertion = client_assertion.as_ref().to_owned();
+        self.credential.client_assertion = SecretString::new(client_assertion.as_ref());
         self
     }
 
@@ -14,11 +14,12 @@ use graph_core::identity::ForceTokenRefresh;
 use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
 
 use crate::identity::credentials::app_config::{AppConfig, AppConfigBuilder};
+use crate::identity::credentials::{Grant, TokenRequestForm};
 use crate::identity::{
-    tracing_targets::CREDENTIAL_EXECUTOR, Authority, AuthorizationResponse, AzureCloudInstance,
-    ConfidentialClientApplication, Token, TokenCredentialExecutor,
+    tracing_targets::CREDENTIAL_EXECUTOR, Account, Authority, AuthorizationResponse,
+    AzureCloudInstance, ConfidentialClientApplication, SecretString, Token,
+    TokenCredentialExecutor,
 };
-use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 use crate::AuthCodeAuthorizationUrlParameterBuilder;
 
 credential_builder!(
@@ -42,7 +43,7 @@ pub struct AuthorizationCodeCredential {
     /// Required when requesting a new access token using a refresh token
     /// The refresh token needed to make an access token request using a refresh token.
     /// Do not include an authorization code when using a refresh token.
-    pub(crate) refresh_token: Option<String>,
+    pub(crate) refresh_token: Option<SecretString>,
     /// Required
     /// The application secret that you created in the app registration portal for your app.
     /// Don't use the application secret in a native app or single page app because a
@@ -52,7 +53,7 @@ pub struct AuthorizationCodeCredential {
     /// is done by the SDK. For more information on URI encoding, see the URI Generic Syntax
     /// specification. The Basic auth pattern of instead providing credentials in the Authorization
     /// header, per RFC 6749 is also supported.
-    pub(crate) client_secret: String,
+    pub(crate) client_secret: SecretString,
     /// The same code_verifier that was used to obtain the authorization_code.
     /// Required if PKCE was used in the authorization code grant request. For more information,
     /// see the PKCE RFC https://datatracker.ietf.org/doc/html/rfc7636.
@@ -81,7 +82,7 @@ impl AuthorizationCodeCredential {
                 .build(),
             authorization_code: Some(authorization_code.as_ref().to_owned()),
             refresh_token: None,
-            client_secret: client_secret.as_ref().to_owned(),
+            client_secret: SecretString::new(client_secret.as_ref()),
             code_verifier: None,
             token_cache: Default::default(),
         })
@@ -101,14 +102,14 @@ impl AuthorizationCodeCredential {
                 .build(),
             authorization_code: Some(authorization_code.as_ref().to_owned()),
             refresh_token: None,
-            client_secret: client_secret.as_ref().to_owned(),
+            client_secret: SecretString::new(client_secret.as_ref()),
             code_verifier: None,
             token_cache: Default::default(),
         })
     }
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) {
-        self.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
     }
 
     pub fn builder(
@@ -125,6 +126,195 @@ impl AuthorizationCodeCredential {
         AuthCodeAuthorizationUrlParameterBuilder::new(client_id)
     }
 
+    /// Acquire a token for an account from the shared [AccountTokenCache](crate::identity::AccountTokenCache)
+    /// set using `with_account_cache`.
+    ///
+    /// Returns a cached token for the account when one was granted the scopes and
+    /// has not expired. Otherwise the refresh token stored for the account is used
+    /// to request a new token for the scopes. The scope, refresh token and token
+    /// cache of the credential are not changed.
+    #[allow(clippy::result_large_err)]
+    pub fn acquire_token_silent<T: ToString, I: IntoIterator<Item = T>>(
+        &self,
+        account: &Account,
+        scope: I,
+    ) -> AuthExecutionResult<Token> {
+        let mut credential = self.account_credential(scope);
+        if let Some(token) = credential.cached_account_token(account)? {
+            return Ok(token);
+        }
+        let cache_id = credential.app_config.cache_id.to_string();
+        credential.execute_cached_token_refresh(cache_id)
+    }
+
+    /// Acquire a token for an account from the shared [AccountTokenCache](crate::identity::AccountTokenCache)
+    /// set using `with_account_cache`. See [acquire_token_silent](AuthorizationCodeCredential::acquire_token_silent).
+    pub async fn acquire_token_silent_async<T: ToString, I: IntoIterator<Item = T>>(
+        &self,
+        account: &Account,
+        scope: I,
+    ) -> AuthExecutionResult<Token> {
+        let mut credential = self.account_credential(scope);
+        if let Some(token) = credential.cached_account_token(account)? {
+            return Ok(token);
+        }
+        let cache_id = credential.app_config.cache_id.to_string();
+        credential
+            .execute_cached_token_refresh_async(cache_id)
+            .await
+    }
+
+    // Copy of the credential used to get tokens for an account in the account cache
+    // so that looking up another account does not change this credential.
+    fn account_credential<T: ToString, I: IntoIterator<Item = T>>(
+        &self,
+        scope: I,
+    ) -> AuthorizationCodeCredential {
+        let mut credential = self.clone();
+        credential.app_config.with_scope(scope);
+        credential.authorization_code = None;
+        credential.refresh_token = None;
+        credential.token_cache = InMemoryCacheStore::new();
+        credential
+    }
+
+    // Returns the cached token for the account or sets the refresh token for the
+    // account so that the next request refreshes the token.
+    #[allow(clippy::result_large_err)]
+    fn cached_account_token(&mut self, account: &Account) -> AuthExecutionResult<Option<Token>> {
+        let account_cache = self.app_config.account_cache.clone().ok_or_else(|| {
+            AF::msg_err(
+                "account_cache",
+                "Set an account cache using with_account_cache",
+            )
+        })?;
+
+        let scope: Vec<String> = self.app_config.scope.iter().cloned().collect();
+        let client_id = self.app_config.client_id;
+        if let Some(token) = account_cache.get_token(account, &client_id, &scope) {
+            return Ok(Some(token));
+        }
+
+        let token = account_cache
+            .get_refresh_token(account, &client_id)
+            .ok_or_else(|| AF::msg_err("account", "No refresh token found for account"))?;
+        self.refresh_token = token.refresh_token.clone().map(SecretString::new);
+        self.token_cache
+            .store(self.app_config.cache_id.to_string(), token);
+        Ok(None)
+    }
+
+    /// Request a token that includes additional scopes for the signed in user.
+    ///
+    /// The scopes are added to the scope of the credential and the refresh token is
+    /// used to request a token for all of the scopes. When the user has not consented
+    /// to the scopes, or there is no refresh token, an authorization url builder for the
+    /// scopes is returned so that the user can consent. Tokens are stored in the token
+    /// cache of the credential and in the account cache when one is set.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// match credential.with_additional_scopes(["Mail.Read"])? {
+    ///     IncrementalConsent::Granted(token) => println!("{token:#?}"),
+    ///     IncrementalConsent::ConsentRequired(url_builder) => {
+    ///         let url = url_builder.url()?;
+    ///         // Send the user to the url and exchange the returned code.
+    ///         let token = credential.complete_consent(authorization_code)?;
+    ///     }
+    /// }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn with_additional_scopes<T: ToString, I: IntoIterator<Item = T>>(
+        &mut self,
+        scope: I,
+    ) -> AuthExecutionResult<IncrementalConsent> {
+        if !self.add_scopes(scope) {
+            return Ok(self.consent_required());
+        }
+
+        let cache_id = self.app_config.cache_id.to_string();
+        match self.execute_cached_token_refresh(cache_id) {
+            Ok(token) => Ok(IncrementalConsent::Granted(token)),
+            Err(err) if err.is_interaction_required() => Ok(self.consent_required()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Request a token that includes additional scopes for the signed in user.
+    /// See [with_additional_scopes](AuthorizationCodeCredential::with_additional_scopes).
+    pub async fn with_additional_scopes_async<T: ToString, I: IntoIterator<Item = T>>(
+        &mut self,
+        scope: I,
+    ) -> AuthExecutionResult<IncrementalConsent> {
+        if !self.add_scopes(scope) {
+            return Ok(self.consent_required());
+        }
+
+        let cache_id = self.app_config.cache_id.to_string();
+        match self.execute_cached_token_refresh_async(cache_id).await {
+            Ok(token) => Ok(IncrementalConsent::Granted(token)),
+            Err(err) if err.is_interaction_required() => Ok(self.consent_required()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Exchange the authorization code returned after the user consented to
+    /// additional scopes for a token and store the token in the cache.
+    #[allow(clippy::result_large_err)]
+    pub fn complete_consent(
+        &mut self,
+        authorization_code: impl AsRef<str>,
+    ) -> AuthExecutionResult<Token> {
+        let cache_id = self.use_authorization_code(authorization_code);
+        let token = self.execute_cached_token_refresh(cache_id);
+        self.authorization_code = None;
+        token
+    }
+
+    /// Exchange the authorization code returned after the user consented to
+    /// additional scopes for a token and store the token in the cache.
+    pub async fn complete_consent_async(
+        &mut self,
+        authorization_code: impl AsRef<str>,
+    ) -> AuthExecutionResult<Token> {
+        let cache_id = self.use_authorization_code(authorization_code);
+        let token = self.execute_cached_token_refresh_async(cache_id).await;
+        self.authorization_code = None;
+        token
+    }
+
+    // Adds the scopes to the credential keeping the refresh token of the current
+    // token. Returns false when there is no refresh token.
+    fn add_scopes<T: ToString, I: IntoIterator<Item = T>>(&mut self, scope: I) -> bool {
+        if self.refresh_token.is_none() {
+            self.refresh_token = self
+                .token_cache
+                .get(self.app_config.cache_id.as_str())
+                .and_then(|token| token.refresh_token.map(SecretString::new));
+        }
+
+        let mut scopes = self.app_config.scope.clone();
+        scopes.extend(scope.into_iter().map(|s| s.to_string()));
+        self.app_config.with_scope(scopes);
+        self.refresh_token.is_some()
+    }
+
+    fn consent_required(&self) -> IncrementalConsent {
+        IncrementalConsent::ConsentRequired(
+            AuthCodeAuthorizationUrlParameterBuilder::new_with_app_config(self.app_config.clone()),
+        )
+    }
+
+    // Sets the authorization code so that the next token request exchanges the code
+    // instead of using a cached refresh token. Returns the cache id.
+    fn use_authorization_code(&mut self, authorization_code: impl AsRef<str>) -> String {
+        let cache_id = self.app_config.cache_id.to_string();
+        self.token_cache.evict(cache_id.as_str());
+        self.refresh_token = None;
+        self.authorization_code = Some(authorization_code.as_ref().to_owned());
+        cache_id
+    }
+
     fn execute_cached_token_refresh(&mut self, cache_id: String) -> AuthExecutionResult<Token> {
         let response = self.execute()?;
 
@@ -136,9 +326,10 @@ impl AuthorizationCodeCredential {
 
         let new_token: Token = response.json()?;
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
 
         if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
+            self.refresh_token = new_token.refresh_token.clone().map(SecretString::new);
         }
 
         Ok(new_token)
@@ -158,14 +349,38 @@ impl AuthorizationCodeCredential {
 
         let new_token: Token = response.json().await?;
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
 
         if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
+            self.refresh_token = new_token.refresh_token.clone().map(SecretString::new);
         }
         Ok(new_token)
     }
 }
 
+/// The result of requesting additional scopes using
+/// [AuthorizationCodeCredential::with_additional_scopes].
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+pub enum IncrementalConsent {
+    /// The user has consented to the scopes and a token for the scopes was
+    /// issued without user interaction.
+    Granted(Token),
+    /// The user has to consent to the scopes. Send the user to the authorization url,
+    /// or use `with_interactive_auth`, and pass the returned authorization code to
+    /// [AuthorizationCodeCredential::complete_consent].
+    ConsentRequired(AuthCodeAuthorizationUrlParameterBuilder),
+}
+
+impl Debug for IncrementalConsent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IncrementalConsent::Granted(token) => f.debug_tuple("Granted").field(token).finish(),
+            IncrementalConsent::ConsentRequired(_) => f.write_str("ConsentRequired"),
+        }
+    }
+}
+
 #[async_trait]
 impl TokenCache for AuthorizationCodeCredential {
     type Token = Token;
@@ -175,7 +390,7 @@ impl TokenCache for AuthorizationCodeCredential {
         let cache_id = self.app_config.cache_id.to_string();
 
         match self.app_config.force_token_refresh {
-            ForceTokenRefresh::Never => {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
                 // Attempt to bypass a read on the token store by using previous
                 // refresh token stored outside of RwLock
                 if self.refresh_token.is_some() {
@@ -186,13 +401,14 @@ impl TokenCache for AuthorizationCodeCredential {
                 }
 
                 if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-                    if token.is_expired_sub(time::Duration::minutes(5)) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
                         tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=Some");
                         if let Some(refresh_token) = token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(SecretString::new(refresh_token));
                         }
 
-                        self.execute_cached_token_refresh(cache_id)
+                        let token_result = self.execute_cached_token_refresh(cache_id);
+                        self.app_config.ext_expires_fallback(token, token_result)
                     } else {
                         tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
                         Ok(token)
@@ -202,6 +418,7 @@ impl TokenCache for AuthorizationCodeCredential {
                     self.execute_cached_token_refresh(cache_id)
                 }
             }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
                 let token_result = self.execute_cached_token_refresh(cache_id);
@@ -218,7 +435,7 @@ impl TokenCache for AuthorizationCodeCredential {
         let cache_id = self.app_config.cache_id.to_string();
 
         match self.app_config.force_token_refresh {
-            ForceTokenRefresh::Never => {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
                 // Attempt to bypass a read on the token store by using previous
                 // refresh token stored outside of RwLock
                 if self.refresh_token.is_some() {
@@ -232,12 +449,14 @@ impl TokenCache for AuthorizationCodeCredential {
                 }
 
                 if let Some(old_token) = self.token_cache.get(cache_id.as_str()) {
-                    if old_token.is_expired_sub(time::Duration::minutes(5)) {
+                    if old_token.is_expired_sub(self.app_config.token_refresh_margin()) {
                         if let Some(refresh_token) = old_token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(SecretString::new(refresh_token));
                         }
                         tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=Some");
-                        self.execute_cached_token_refresh_async(cache_id).await
+                        let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                        self.app_config
+                            .ext_expires_fallback(old_token, token_result)
                     } else {
                         tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
                         Ok(old_token.clone())
@@ -247,6 +466,7 @@ impl TokenCache for AuthorizationCodeCredential {
                     self.execute_cached_token_refresh_async(cache_id).await
                 }
             }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 let token_result = self.execute_cached_token_refresh_async(cache_id).await;
                 if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
@@ -278,7 +498,7 @@ impl AuthorizationCodeCredentialBuilder {
                 app_config: AppConfig::new(client_id.as_ref()),
                 authorization_code: Some(authorization_code.as_ref().to_owned()),
                 refresh_token: None,
-                client_secret: client_secret.as_ref().to_owned(),
+                client_secret: SecretString::new(client_secret.as_ref()),
                 code_verifier: None,
                 token_cache: Default::default(),
             },
@@ -298,7 +518,7 @@ impl AuthorizationCodeCredentialBuilder {
                 app_config,
                 authorization_code: None,
                 refresh_token: None,
-                client_secret: String::new(),
+                client_secret: SecretString::default(),
                 code_verifier: None,
                 token_cache,
             },
@@ -314,7 +534,7 @@ impl AuthorizationCodeCredentialBuilder {
                 app_config,
                 authorization_code: Some(authorization_code.as_ref().to_owned()),
                 refresh_token: None,
-                client_secret: String::new(),
+                client_secret: SecretString::default(),
                 code_verifier: None,
                 token_cache: Default::default(),
             },
@@ -332,7 +552,7 @@ impl AuthorizationCodeCredentialBuilder {
                 app_config,
                 authorization_code: Some(authorization_code),
                 refresh_token: None,
-                client_secret: secret,
+                client_secret: SecretString::new(secret),
                 code_verifier: None,
                 token_cache: Default::default(),
             },
@@ -346,7 +566,7 @@ impl AuthorizationCodeCredentialBuilder {
     }
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
-        self.credential.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.credential.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
         self
     }
 
@@ -357,7 +577,7 @@ impl AuthorizationCodeCredentialBuilder {
     }
 
     pub fn with_client_secret<T: AsRef<str>>(&mut self, client_secret: T) -> &mut Self {
-        self.credential.client_secret = client_secret.as_ref().to_owned();
+        self.credential.client_secret = SecretString::new(client_secret.as_ref());
         self
     }
 
@@ -381,103 +601,52 @@ impl From<AuthorizationCodeCredential> for AuthorizationCodeCredentialBuilder {
 #[async_trait]
 impl TokenCredentialExecutor for AuthorizationCodeCredential {
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
-        let mut serializer = AuthSerializer::new();
         let client_id = self.app_config.client_id.to_string();
         if client_id.is_empty() || self.app_config.client_id.is_nil() {
-            return AF::result(AuthParameter::ClientId.alias());
-        }
-
-        if self.client_secret.trim().is_empty() {
-            return AF::result(AuthParameter::ClientSecret.alias());
+            return AF::result("client_id");
         }
 
-        serializer
-            .client_id(client_id.as_str())
-            .client_secret(self.client_secret.as_str())
-            .set_scope(self.app_config.scope.clone());
-
         let cache_id = self.app_config.cache_id.to_string();
-        if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-            if let Some(refresh_token) = token.refresh_token.as_ref() {
-                serializer
-                    .grant_type("refresh_token")
-                    .refresh_token(refresh_token.as_ref());
-
-                return serializer.as_credential_map(
-                    vec![AuthParameter::Scope],
-                    vec![
-                        AuthParameter::ClientId,
-                        AuthParameter::ClientSecret,
-                        AuthParameter::RefreshToken,
-                        AuthParameter::GrantType,
-                    ],
-                );
-            }
-        }
+        let cached_refresh_token = self
+            .token_cache
+            .get(cache_id.as_str())
+            .and_then(|token| token.refresh_token);
 
         let should_attempt_refresh = self.refresh_token.is_some()
             && self.app_config.force_token_refresh != ForceTokenRefresh::Once
             && self.app_config.force_token_refresh != ForceTokenRefresh::Always;
 
-        if should_attempt_refresh {
-            let refresh_token = self.refresh_token.clone().unwrap_or_default();
-            if refresh_token.trim().is_empty() {
-                return AF::msg_result(AuthParameter::RefreshToken, "Refresh token is empty");
-            }
-
-            serializer
-                .grant_type("refresh_token")
-                .refresh_token(refresh_token.as_ref());
-
-            return serializer.as_credential_map(
-                vec![AuthParameter::Scope],
-                vec![
-                    AuthParameter::ClientId,
-                    AuthParameter::ClientSecret,
-                    AuthParameter::RefreshToken,
-                    AuthParameter::GrantType,
-                ],
-            );
+        let grant = if let Some(refresh_token) = cached_refresh_token {
+            Grant::refresh_token(&refresh_token)?
+        } else if should_attempt_refresh {
+            Grant::refresh_token(
+                self.refresh_token
+                    .as_ref()
+                    .map(SecretString::expose_secret)
+                    .unwrap_or_default(),
+            )?
         } else if let Some(authorization_code) = self.authorization_code.as_ref() {
-            if authorization_code.trim().is_empty() {
-                return AF::msg_result(
-                    AuthParameter::AuthorizationCode.alias(),
-                    "Authorization code is empty",
-                );
-            }
-
-            if let Some(redirect_uri) = self.app_config.redirect_uri.as_ref() {
-                serializer.redirect_uri(redirect_uri.as_str());
-            }
-
-            serializer
-                .authorization_code(authorization_code.as_ref())
-                .grant_type("authorization_code");
-
-            if let Some(code_verifier) = self.code_verifier.as_ref() {
-                serializer.code_verifier(code_verifier.as_str());
-            }
-
-            return serializer.as_credential_map(
-                vec![AuthParameter::Scope, AuthParameter::CodeVerifier],
-                vec![
-                    AuthParameter::ClientId,
-                    AuthParameter::ClientSecret,
-                    AuthParameter::RedirectUri,
-                    AuthParameter::AuthorizationCode,
-                    AuthParameter::GrantType,
-                ],
+            let redirect_uri = self
+                .app_config
+                .redirect_uri
+                .as_ref()
+                .ok_or_else(|| AF::required("redirect_uri"))?;
+            Grant::authorization_code(
+                authorization_code,
+                Some(redirect_uri.to_string()),
+                self.code_verifier.clone(),
+            )?
+        } else {
+            return AF::msg_result(
+                "code or refresh_token",
+                "Either authorization code or refresh token is required",
             );
-        }
+        };
 
-        AF::msg_result(
-            format!(
-                "{} or {}",
-                AuthParameter::AuthorizationCode.alias(),
-                AuthParameter::RefreshToken.alias()
-            ),
-            "Either authorization code or refresh token is required",
-        )
+        TokenRequestForm::new(client_id, grant)
+            .client_secret(self.client_secret.expose_secret())?
+            .scope(&self.app_config.scope)
+            .into_form()
     }
 
     fn client_id(&self) -> &Uuid {
@@ -495,13 +664,25 @@ impl TokenCredentialExecutor for AuthorizationCodeCredential {
     fn basic_auth(&self) -> Option<(String, String)> {
         Some((
             self.app_config.client_id.to_string(),
-            self.client_secret.clone(),
+            self.client_secret.expose_secret().to_owned(),
         ))
     }
 
     fn app_config(&self) -> &AppConfig {
         &self.app_config
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.app_config.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.app_config.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.app_config.with_tenant(tenant_id);
+    }
 }
 
 impl Debug for AuthorizationCodeCredentialBuilder {
@@ -597,4 +778,67 @@ mod test {
             .with_tenant("tenant_id")
             .build();
     }
+
+    #[test]
+    fn additional_scopes_require_consent_without_refresh_token() {
+        let mut credential_builder =
+            AuthorizationCodeCredential::builder("auth_code", Uuid::new_v4().to_string(), "secret");
+        credential_builder.with_scope(vec!["User.Read"]);
+        let mut credential = credential_builder.credential.clone();
+
+        let consent = credential.with_additional_scopes(["Mail.Read"]).unwrap();
+        assert!(matches!(consent, IncrementalConsent::ConsentRequired(_)));
+        assert_eq!(
+            std::collections::BTreeSet::from(["Mail.Read".to_owned(), "User.Read".to_owned()]),
+            credential.app_config.scope
+        );
+    }
+
+    #[test]
+    fn acquire_token_silent_does_not_change_credential() {
+        use base64::Engine;
+
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .encode(serde_json::json!({ "oid": "object-id", "tid": "tenant-id" }).to_string());
+        let mut token = Token::new("Bearer", 3600, "access_token", vec!["Mail.Read"]);
+        token.with_id_token(crate::identity::IdToken::new(
+            &format!("header.{payload}.signature"),
+            None,
+            None,
+            None,
+        ));
+        token.refresh_token = Some("account_refresh_token".into());
+
+        let account_cache = crate::identity::AccountTokenCache::new();
+        let mut credential_builder =
+            AuthorizationCodeCredential::builder("auth_code", Uuid::new_v4().to_string(), "secret");
+        credential_builder
+            .with_scope(vec!["User.Read"])
+            .with_refresh_token("refresh_token")
+            .with_account_cache(account_cache.clone());
+        let credential = credential_builder.credential.clone();
+        let account = account_cache
+            .store(&credential.app_config.client_id, &token)
+            .unwrap();
+
+        let cached = credential
+            .acquire_token_silent(&account, ["Mail.Read"])
+            .unwrap();
+        assert_eq!("access_token", cached.access_token);
+        assert_eq!(
+            std::collections::BTreeSet::from(["User.Read".to_owned()]),
+            credential.app_config.scope
+        );
+        assert_eq!(
+            Some("refresh_token"),
+            credential
+                .refresh_token
+                .as_ref()
+                .map(SecretString::expose_secret)
+        );
+        assert!(credential
+            .token_cache
+            .get(credential.app_config.cache_id.as_str())
+            .is_none());
+    }
 }
@@ -2,59 +2,69 @@ use async_trait::async_trait;
 use graph_core::cache::AsBearer;
 use graph_core::identity::{ClientApplication, ForceTokenRefresh};
 use graph_error::AuthExecutionResult;
-use std::fmt::Display;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::identity::SecretString;
 
 #[derive(Clone)]
-pub struct BearerTokenCredential(String);
+pub struct BearerTokenCredential(SecretString);
 
 impl BearerTokenCredential {
     pub fn new(access_token: impl ToString) -> BearerTokenCredential {
-        BearerTokenCredential(access_token.to_string())
+        BearerTokenCredential(SecretString::new(access_token.to_string()))
     }
 
     pub fn as_str(&self) -> &str {
-        self.0.as_str()
+        self.0.expose_secret()
+    }
+}
+
+impl Debug for BearerTokenCredential {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BearerTokenCredential")
+            .field(&self.0)
+            .finish()
     }
 }
 
 impl Display for BearerTokenCredential {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
     }
 }
 
 impl AsBearer for BearerTokenCredential {
     fn as_bearer(&self) -> String {
-        self.0.clone()
+        self.as_str().to_owned()
     }
 }
 
 impl AsRef<str> for BearerTokenCredential {
     fn as_ref(&self) -> &str {
-        self.0.as_str()
+        self.as_str()
     }
 }
 
 impl From<&str> for BearerTokenCredential {
     fn from(value: &str) -> Self {
-        BearerTokenCredential(value.to_string())
+        BearerTokenCredential(SecretString::from(value))
     }
 }
 
 impl From<String> for BearerTokenCredential {
     fn from(value: String) -> Self {
-        BearerTokenCredential(value)
+        BearerTokenCredential(SecretString::from(value))
     }
 }
 
 #[async_trait]
 impl ClientApplication for BearerTokenCredential {
     fn get_token_silent(&mut self) -> AuthExecutionResult<String> {
-        Ok(self.0.clone())
+        Ok(self.as_str().to_owned())
     }
 
     async fn get_token_silent_async(&mut self) -> AuthExecutionResult<String> {
-        Ok(self.0.clone())
+        Ok(self.as_str().to_owned())
     }
 
     fn with_force_token_refresh(&mut self, _force_token_refresh: ForceTokenRefresh) {}
@@ -0,0 +1,438 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime};
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
+use graph_core::identity::ClientApplication;
+use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
+use reqwest::tls::Version;
+use url::Url;
+
+use crate::identity::{tracing_targets::CREDENTIAL_EXECUTOR, SecretString, X509Certificate};
+
+/// The api version of the Azure Key Vault secrets api.
+const KEY_VAULT_API_VERSION: &str = "7.4";
+
+/// How long a certificate fetched from Azure Key Vault is used before it is fetched again.
+const KEY_VAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// File extensions of the certificates loaded by [DirectoryCertificateProvider].
+const CERTIFICATE_EXTENSIONS: [&str; 3] = ["pem", "pfx", "p12"];
+
+/// Provides the certificate used to sign the client assertion of the client certificate
+/// credentials. Requires the `openssl` feature.
+///
+/// The provider is consulted before each token request so that a long-running application
+/// uses a rotated certificate without restarting. Implementations should keep the certificate
+/// and only load it again when it may have changed.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::{ClientCertificateCredential, DirectoryCertificateProvider};
+///
+/// let provider = DirectoryCertificateProvider::new("client_id", "/etc/certificates")
+///     .with_password("password");
+///
+/// let confidential_client = ClientCertificateCredential::builder("client_id")
+///     .with_tenant("tenant_id")
+///     .with_certificate_provider(provider)
+///     .build();
+/// ```
+#[async_trait]
+pub trait CertificateProvider: Send + Sync {
+    #[allow(clippy::result_large_err)]
+    fn certificate(&self) -> AuthExecutionResult<Arc<X509Certificate>>;
+
+    async fn certificate_async(&self) -> AuthExecutionResult<Arc<X509Certificate>>;
+}
+
+/// Sign a client assertion using the current certificate of the provider.
+#[allow(clippy::result_large_err)]
+pub(crate) fn sign_client_assertion(
+    provider: &dyn CertificateProvider,
+    tenant_id: Option<String>,
+) -> AuthExecutionResult<SecretString> {
+    let certificate = provider.certificate()?;
+    Ok(SecretString::new(certificate.sign_with_tenant(tenant_id)?))
+}
+
+pub(crate) async fn sign_client_assertion_async(
+    provider: &dyn CertificateProvider,
+    tenant_id: Option<String>,
+) -> AuthExecutionResult<SecretString> {
+    let certificate = provider.certificate_async().await?;
+    Ok(SecretString::new(certificate.sign_with_tenant(tenant_id)?))
+}
+
+struct LoadedCertificate {
+    path: PathBuf,
+    modified: SystemTime,
+    certificate: Arc<X509Certificate>,
+}
+
+/// Loads the most recently modified certificate in a directory and loads it again
+/// when a newer certificate is written to the directory.
+///
+/// Certificates are PKCS#12 files with the `.pfx` or `.p12` extension or PEM files
+/// with the `.pem` extension that have both the certificate and the private key.
+/// The password, when given, is used for all certificates in the directory.
+pub struct DirectoryCertificateProvider {
+    client_id: String,
+    directory: PathBuf,
+    password: Option<SecretString>,
+    send_x5c: bool,
+    loaded: Mutex<Option<LoadedCertificate>>,
+}
+
+impl DirectoryCertificateProvider {
+    pub fn new(
+        client_id: impl AsRef<str>,
+        directory: impl AsRef<Path>,
+    ) -> DirectoryCertificateProvider {
+        DirectoryCertificateProvider {
+            client_id: client_id.as_ref().to_owned(),
+            directory: directory.as_ref().to_path_buf(),
+            password: None,
+            send_x5c: false,
+            loaded: Mutex::new(None),
+        }
+    }
+
+    pub fn with_password(mut self, password: impl AsRef<str>) -> Self {
+        self.password = Some(SecretString::new(password.as_ref()));
+        self
+    }
+
+    /// Send the x5c header for subject name and issuer authentication.
+    /// See [X509Certificate::set_send_x5c].
+    pub fn with_send_x5c(mut self, send_x5c: bool) -> Self {
+        self.send_x5c = send_x5c;
+        self
+    }
+
+    /// The most recently modified certificate file in the directory.
+    fn newest_certificate_file(&self) -> IdentityResult<(PathBuf, SystemTime)> {
+        let entries = std::fs::read_dir(&self.directory).map_err(|err| {
+            AF::x509(format!(
+                "Unable to read certificate directory {} - error {err}",
+                self.directory.display()
+            ))
+        })?;
+
+        entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .and_then(|extension| extension.to_str())
+                    .map(|extension| {
+                        CERTIFICATE_EXTENSIONS.iter().any(|certificate_extension| {
+                            extension.eq_ignore_ascii_case(certificate_extension)
+                        })
+                    })
+                    .unwrap_or_default()
+            })
+            .filter_map(|path| {
+                let modified = path.metadata().ok()?.modified().ok()?;
+                Some((path, modified))
+            })
+            .max_by_key(|(_, modified)| *modified)
+            .ok_or_else(|| {
+                AF::x509(format!(
+                    "No certificate found in directory {}",
+                    self.directory.display()
+                ))
+            })
+    }
+
+    fn load(&self, path: &Path) -> IdentityResult<X509Certificate> {
+        let bytes = std::fs::read(path).map_err(|err| {
+            AF::x509(format!(
+                "Unable to read certificate file {} - error {err}",
+                path.display()
+            ))
+        })?;
+        let password = self
+            .password
+            .as_ref()
+            .map(|password| password.expose_secret());
+
+        let is_pem = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.eq_ignore_ascii_case("pem"))
+            .unwrap_or_default();
+        let mut certificate = if is_pem {
+            X509Certificate::from_pem(&self.client_id, &bytes, password)?
+        } else {
+            X509Certificate::from_pkcs12(&self.client_id, &bytes, password.unwrap_or_default())?
+        };
+        certificate.set_send_x5c(self.send_x5c);
+        Ok(certificate)
+    }
+}
+
+#[async_trait]
+impl CertificateProvider for DirectoryCertificateProvider {
+    #[allow(clippy::result_large_err)]
+    fn certificate(&self) -> AuthExecutionResult<Arc<X509Certificate>> {
+        let (path, modified) = self.newest_certificate_file()?;
+        let mut loaded = self
+            .loaded
+            .lock()
+            .map_err(|_| AF::msg_internal_err("certificate_provider"))?;
+
+        if let Some(loaded) = loaded.as_ref() {
+            if loaded.path == path && loaded.modified == modified {
+                return Ok(loaded.certificate.clone());
+            }
+        }
+
+        tracing::debug!(target: CREDENTIAL_EXECUTOR, "loading certificate {}", path.display());
+        let certificate = Arc::new(self.load(&path)?);
+        *loaded = Some(LoadedCertificate {
+            path,
+            modified,
+            certificate: certificate.clone(),
+        });
+        Ok(certificate)
+    }
+
+    async fn certificate_async(&self) -> AuthExecutionResult<Arc<X509Certificate>> {
+        self.certificate()
+    }
+}
+
+#[derive(Deserialize)]
+struct KeyVaultSecret {
+    value: SecretString,
+    #[serde(rename = "contentType", default)]
+    content_type: Option<String>,
+}
+
+/// Fetches a certificate and its private key from Azure Key Vault and fetches it again
+/// once the refresh interval has passed, so that a certificate rotated in Key Vault is used.
+///
+/// The certificate is read from the secret that Key Vault creates for each certificate,
+/// which requires the `Get` secret permission. The access token for Key Vault is requested
+/// using the given client application, which must request the `https://vault.azure.net/.default`
+/// scope.
+///
+/// The provider creates one http client for fetching the certificate, or uses the client
+/// given with [KeyVaultCertificateProvider::with_http_client].
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::{ConfidentialClientApplication, KeyVaultCertificateProvider};
+///
+/// let key_vault_client = ConfidentialClientApplication::builder("client_id")
+///     .with_client_secret("secret")
+///     .with_tenant("tenant_id")
+///     .with_scope(vec!["https://vault.azure.net/.default"])
+///     .build();
+///
+/// let provider = KeyVaultCertificateProvider::new(
+///     "client_id",
+///     "https://my-vault.vault.azure.net",
+///     "my-certificate",
+///     key_vault_client,
+/// )?;
+/// ```
+pub struct KeyVaultCertificateProvider {
+    client_id: String,
+    secret_url: Url,
+    client_application: Box<dyn ClientApplication>,
+    refresh_interval: Duration,
+    send_x5c: bool,
+    loaded: Mutex<Option<(Instant, Arc<X509Certificate>)>>,
+    http_client: OnceLock<reqwest::Client>,
+    blocking_http_client: OnceLock<reqwest::blocking::Client>,
+}
+
+impl KeyVaultCertificateProvider {
+    pub fn new(
+        client_id: impl AsRef<str>,
+        vault_url: impl AsRef<str>,
+        certificate_name: impl AsRef<str>,
+        client_application: impl ClientApplication + 'static,
+    ) -> IdentityResult<KeyVaultCertificateProvider> {
+        let secret_url = Url::parse(vault_url.as_ref())?
+            .join(format!("secrets/{}", certificate_name.as_ref()).as_str())?;
+
+        Ok(KeyVaultCertificateProvider {
+            client_id: client_id.as_ref().to_owned(),
+            secret_url,
+            client_application: Box::new(client_application),
+            refresh_interval: KEY_VAULT_REFRESH_INTERVAL,
+            send_x5c: false,
+            loaded: Mutex::new(None),
+            http_client: OnceLock::new(),
+            blocking_http_client: OnceLock::new(),
+        })
+    }
+
+    /// Use the given http client, such as the client of the application, to fetch
+    /// the certificate asynchronously.
+    pub fn with_http_client(self, http_client: reqwest::Client) -> Self {
+        let _ = self.http_client.set(http_client);
+        self
+    }
+
+    /// How long the certificate is used before it is fetched again. The default is one hour.
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Send the x5c header for subject name and issuer authentication.
+    /// See [X509Certificate::set_send_x5c].
+    pub fn with_send_x5c(mut self, send_x5c: bool) -> Self {
+        self.send_x5c = send_x5c;
+        self
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn http_client(&self) -> AuthExecutionResult<&reqwest::Client> {
+        if let Some(http_client) = self.http_client.get() {
+            return Ok(http_client);
+        }
+        let http_client = reqwest::ClientBuilder::new()
+            .min_tls_version(Version::TLS_1_2)
+            .https_only(true)
+            .build()?;
+        Ok(self.http_client.get_or_init(|| http_client))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn blocking_http_client(&self) -> AuthExecutionResult<&reqwest::blocking::Client> {
+        if let Some(http_client) = self.blocking_http_client.get() {
+            return Ok(http_client);
+        }
+        let http_client = reqwest::blocking::ClientBuilder::new()
+            .min_tls_version(Version::TLS_1_2)
+            .https_only(true)
+            .build()?;
+        Ok(self.blocking_http_client.get_or_init(|| http_client))
+    }
+
+    /// The certificate if it was fetched within the refresh interval.
+    fn current(&self) -> IdentityResult<Option<Arc<X509Certificate>>> {
+        let loaded = self
+            .loaded
+            .lock()
+            .map_err(|_| AF::msg_internal_err("certificate_provider"))?;
+        Ok(loaded
+            .as_ref()
+            .filter(|(fetched, _)| fetched.elapsed() < self.refresh_interval)
+            .map(|(_, certificate)| certificate.clone()))
+    }
+
+    fn store(&self, secret: KeyVaultSecret) -> IdentityResult<Arc<X509Certificate>> {
+        let mut certificate = match secret.content_type.as_deref() {
+            Some("application/x-pem-file") => X509Certificate::from_pem(
+                &self.client_id,
+                secret.value.expose_secret().as_bytes(),
+                None,
+            )?,
+            _ => {
+                let der = STANDARD
+                    .decode(secret.value.expose_secret())
+                    .map_err(|err| AF::x509(err.to_string()))?;
+                X509Certificate::from_pkcs12(&self.client_id, &der, "")?
+            }
+        };
+        certificate.set_send_x5c(self.send_x5c);
+
+        let certificate = Arc::new(certificate);
+        let mut loaded = self
+            .loaded
+            .lock()
+            .map_err(|_| AF::msg_internal_err("certificate_provider"))?;
+        *loaded = Some((Instant::now(), certificate.clone()));
+        Ok(certificate)
+    }
+}
+
+#[async_trait]
+impl CertificateProvider for KeyVaultCertificateProvider {
+    #[allow(clippy::result_large_err)]
+    fn certificate(&self) -> AuthExecutionResult<Arc<X509Certificate>> {
+        if let Some(certificate) = self.current()? {
+            return Ok(certificate);
+        }
+
+        tracing::debug!(target: CREDENTIAL_EXECUTOR, "fetching certificate from key vault");
+        let access_token = self.client_application.clone().get_token_silent()?;
+        let response = self
+            .blocking_http_client()?
+            .get(self.secret_url.clone())
+            .query(&[("api-version", KEY_VAULT_API_VERSION)])
+            .bearer_auth(access_token)
+            .send()?;
+
+        if !response.status().is_success() {
+            return Err(AuthExecutionError::error_response(
+                "key vault certificate request failed",
+                response.into_http_response()?,
+            ));
+        }
+        let secret: KeyVaultSecret = response.json()?;
+        Ok(self.store(secret)?)
+    }
+
+    async fn certificate_async(&self) -> AuthExecutionResult<Arc<X509Certificate>> {
+        if let Some(certificate) = self.current()? {
+            return Ok(certificate);
+        }
+
+        tracing::debug!(target: CREDENTIAL_EXECUTOR, "fetching certificate from key vault");
+        let access_token = self
+            .client_application
+            .clone()
+            .get_token_silent_async()
+            .await?;
+        let response = self
+            .http_client()?
+            .get(self.secret_url.clone())
+            .query(&[("api-version", KEY_VAULT_API_VERSION)])
+            .bearer_auth(access_token)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(AuthExecutionError::error_response(
+                "key vault certificate request failed",
+                response.into_http_response_async().await?,
+            ));
+        }
+        let secret: KeyVaultSecret = response.json().await?;
+        Ok(self.store(secret)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn load_newest_certificate_in_directory() {
+        let directory = std::env::temp_dir().join(format!("certificates-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("README.txt"), "not a certificate").unwrap();
+
+        let provider = DirectoryCertificateProvider::new("client_id", &directory)
+            .with_password("password")
+            .with_send_x5c(true);
+        assert!(provider.certificate().is_err());
+
+        std::fs::write(directory.join("cert.pfx"), include_bytes!("test/cert.pfx")).unwrap();
+        let certificate = provider.certificate().unwrap();
+        assert!(Arc::ptr_eq(&certificate, &provider.certificate().unwrap()));
+        assert!(sign_client_assertion(&provider, None).is_ok());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use graph_error::IdentityResult;
+use serde_json::{Map, Value};
+use time::OffsetDateTime;
+
+/// How long a signed client assertion is valid by default.
+pub(crate) const ASSERTION_LIFETIME: Duration = Duration::from_secs(60 * 10);
+
+/// How long before a client assertion expires a new one is signed by default.
+pub(crate) const CLOCK_SKEW: Duration = Duration::from_secs(60);
+
+/// The JWT header of a client assertion signed using RS256 with the base64url
+/// encoded SHA-1 thumbprint of the certificate in the x5t header.
+pub(crate) fn assertion_header(x5t: String) -> Map<String, Value> {
+    let mut header = Map::new();
+    header.insert("x5t".to_owned(), Value::String(x5t));
+    header.insert("alg".to_owned(), Value::String("RS256".to_owned()));
+    header.insert("typ".to_owned(), Value::String("JWT".to_owned()));
+    header
+}
+
+/// The claims of a client assertion for the token endpoint of the tenant that
+/// expire after the assertion lifetime.
+pub(crate) fn assertion_claims(
+    client_id: &str,
+    tenant_id: Option<&String>,
+    jti: String,
+    assertion_lifetime: Duration,
+) -> HashMap<String, String> {
+    let aud = match tenant_id {
+        Some(tenant_id) => {
+            format!("https://login.microsoftonline.com/{tenant_id}/oauth2/v2.0/token")
+        }
+        None => "https://login.microsoftonline.com/common/oauth2/v2.0/token".to_owned(),
+    };
+    let nbf = OffsetDateTime::now_utc().unix_timestamp();
+    let exp = nbf + assertion_lifetime.as_secs() as i64;
+
+    let mut claims = HashMap::new();
+    claims.insert("aud".to_owned(), aud);
+    claims.insert("exp".to_owned(), exp.to_string());
+    claims.insert("nbf".to_owned(), nbf.to_string());
+    claims.insert("jti".to_owned(), jti);
+    claims.insert("sub".to_owned(), client_id.to_owned());
+    claims.insert("iss".to_owned(), client_id.to_owned());
+    claims
+}
+
+/// JWT header and payload in the format header.payload
+pub(crate) fn unsigned_token(
+    header: &Map<String, Value>,
+    claims: &HashMap<String, String>,
+) -> IdentityResult<String> {
+    let header = URL_SAFE_NO_PAD.encode(serde_json::to_vec(header)?);
+    let claims = URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims)?);
+    Ok(format!("{header}.{claims}"))
+}
+
+/// Whether a client assertion that expires at the unix timestamp can still be used
+/// without expiring within the clock skew.
+pub(crate) fn is_valid(expires_on: i64, clock_skew: Duration) -> bool {
+    OffsetDateTime::now_utc().unix_timestamp() + (clock_skew.as_secs() as i64) < expires_on
+}
@@ -6,7 +6,7 @@ use http::{HeaderMap, HeaderName, HeaderValue};
 
 use uuid::Uuid;
 
-use crate::oauth_serializer::{AuthParameter, AuthSerializer};
+use crate::identity::credentials::{Grant, TokenRequestForm};
 use graph_core::cache::{CacheStore, InMemoryCacheStore, TokenCache};
 use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
 use graph_core::identity::ForceTokenRefresh;
@@ -15,7 +15,8 @@ use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
 use crate::identity::credentials::app_config::AppConfig;
 use crate::identity::{
     tracing_targets::CREDENTIAL_EXECUTOR, Authority, AzureCloudInstance,
-    ConfidentialClientApplication, Token, TokenCredentialExecutor, CLIENT_ASSERTION_TYPE,
+    ConfidentialClientApplication, SecretString, Token, TokenCredentialExecutor,
+    CLIENT_ASSERTION_TYPE,
 };
 
 credential_builder!(
@@ -49,7 +50,7 @@ pub struct ClientAssertionCredential {
     /// JWT must be registered on your application as a federated identity credential. Read about
     /// workload identity federation to learn how to setup and use assertions generated from
     /// other identity providers.
-    pub(crate) client_assertion: String,
+    pub(crate) client_assertion: SecretString,
     token_cache: InMemoryCacheStore<Token>,
 }
 
@@ -73,7 +74,7 @@ impl ClientAssertionCredential {
                 .scope(vec!["https://graph.microsoft.com/.default"])
                 .build(),
             client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
-            client_assertion: assertion.as_ref().to_string(),
+            client_assertion: SecretString::new(assertion.as_ref()),
             token_cache: Default::default(),
         }
     }
@@ -117,34 +118,62 @@ impl TokenCache for ClientAssertionCredential {
     #[tracing::instrument]
     fn get_token_silent(&mut self) -> Result<Self::Token, AuthExecutionError> {
         let cache_id = self.app_config.cache_id.to_string();
-        if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-            if token.is_expired_sub(time::Duration::minutes(5)) {
+        match self.app_config.force_token_refresh {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
+                if let Some(token) = self.token_cache.get(cache_id.as_str()) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                        let token_result = self.execute_cached_token_refresh(cache_id);
+                        self.app_config.ext_expires_fallback(token, token_result)
+                    } else {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                        Ok(token)
+                    }
+                } else {
+                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                    self.execute_cached_token_refresh(cache_id)
+                }
+            }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
+            ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-                self.execute_cached_token_refresh(cache_id)
-            } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
-                Ok(token)
+                let token_result = self.execute_cached_token_refresh(cache_id);
+                if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
+                    self.app_config.force_token_refresh = ForceTokenRefresh::Never;
+                }
+                token_result
             }
-        } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-            self.execute_cached_token_refresh(cache_id)
         }
     }
 
     #[tracing::instrument]
     async fn get_token_silent_async(&mut self) -> Result<Self::Token, AuthExecutionError> {
         let cache_id = self.app_config.cache_id.to_string();
-        if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-            if token.is_expired_sub(time::Duration::minutes(5)) {
+        match self.app_config.force_token_refresh {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
+                if let Some(token) = self.token_cache.get(cache_id.as_str()) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                        let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                        self.app_config.ext_expires_fallback(token, token_result)
+                    } else {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                        Ok(token.clone())
+                    }
+                } else {
+                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                    self.execute_cached_token_refresh_async(cache_id).await
+                }
+            }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
+            ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-                self.execute_cached_token_refresh_async(cache_id).await
-            } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
-                Ok(token.clone())
+                let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
+                    self.app_config.force_token_refresh = ForceTokenRefresh::Never;
+                }
+                token_result
             }
-        } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-            self.execute_cached_token_refresh_async(cache_id).await
         }
     }
 
@@ -156,36 +185,22 @@ impl TokenCache for ClientAssertionCredential {
 #[async_trait]
 impl TokenCredentialExecutor for ClientAssertionCredential {
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
-        let mut serializer = AuthSerializer::new();
         let client_id = self.client_id().to_string();
         if client_id.trim().is_empty() {
-            return AF::result(AuthParameter::ClientId.alias());
-        }
-
-        if self.client_assertion.trim().is_empty() {
-            return AF::result(AuthParameter::ClientAssertion.alias());
+            return AF::result("client_id");
         }
 
         if self.client_assertion_type.trim().is_empty() {
             self.client_assertion_type = CLIENT_ASSERTION_TYPE.to_owned();
         }
 
-        serializer
-            .client_id(client_id.as_str())
-            .client_assertion(self.client_assertion.as_str())
-            .client_assertion_type(self.client_assertion_type.as_str())
-            .set_scope(self.app_config.scope.clone())
-            .grant_type("client_credentials");
-
-        serializer.as_credential_map(
-            vec![AuthParameter::Scope],
-            vec![
-                AuthParameter::ClientId,
-                AuthParameter::GrantType,
-                AuthParameter::ClientAssertion,
-                AuthParameter::ClientAssertionType,
-            ],
-        )
+        TokenRequestForm::new(client_id, Grant::ClientCredentials)
+            .client_assertion(
+                self.client_assertion_type.as_str(),
+                self.client_assertion.expose_secret(),
+            )?
+            .scope(&self.app_config.scope)
+            .into_form()
     }
 
     fn client_id(&self) -> &Uuid {
@@ -203,6 +218,18 @@ impl TokenCredentialExecutor for ClientAssertionCredential {
     fn app_config(&self) -> &AppConfig {
         &self.app_config
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.app_config.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.app_config.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.app_config.with_tenant(tenant_id);
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -221,7 +248,7 @@ impl ClientAssertionCredentialBuilder {
                     .scope(vec!["https://graph.microsoft.com/.default"])
                     .build(),
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_string(),
-                client_assertion: signed_assertion.as_ref().to_owned(),
+                client_assertion: SecretString::new(signed_assertion.as_ref()),
                 token_cache: Default::default(),
             },
         }
@@ -233,19 +260,19 @@ impl ClientAssertionCredentialBuilder {
     ) -> ClientAssertionCredentialBuilder {
         app_config
             .scope
-            .insert("https://graph.microsoft.com/.default".to_string());
+            .insert(app_config.azure_cloud_instance.graph_default_scope());
         ClientAssertionCredentialBuilder {
             credential: ClientAssertionCredential {
                 app_config,
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_string(),
-                client_assertion: signed_assertion.as_ref().to_owned(),
+                client_assertion: SecretString::new(signed_assertion.as_ref()),
                 token_cache: Default::default(),
             },
         }
     }
 
     pub fn with_client_assertion<T: AsRef<str>>(&mut self, client_assertion: T) -> &mut Self {
-        self.credential.client_assertion = client_assertion.as_ref().to_owned();
+        self.credential.client_assertion = SecretString::new(client_assertion.as_ref());
         self
     }
 }
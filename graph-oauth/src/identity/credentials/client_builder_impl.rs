@@ -83,6 +83,16 @@ macro_rules! credential_builder_base {
                 self.credential.app_config.with_scope(scope);
                 self
             }
+
+            /// Share tokens with other credentials using the same [AccountTokenCache](crate::identity::AccountTokenCache).
+            /// Tokens issued to a user are stored in the cache by account and scope.
+            pub fn with_account_cache(
+                &mut self,
+                account_cache: crate::identity::AccountTokenCache,
+            ) -> &mut Self {
+                self.credential.app_config.with_account_cache(account_cache);
+                self
+            }
         }
     };
 }
@@ -95,6 +105,17 @@ macro_rules! credential_builder {
             pub fn build(&self) -> $client {
                 <$client>::new(self.credential.clone())
             }
+
+            /// Use the token cache, which can be shared with other credentials. Tokens are
+            /// stored by tenant, client id and scope. Use a cache with a maximum number of
+            /// entries or time to live when acquiring tokens for many tenants.
+            pub fn with_token_cache(
+                &mut self,
+                token_cache: graph_core::cache::InMemoryCacheStore<crate::identity::Token>,
+            ) -> &mut Self {
+                self.credential.token_cache = token_cache;
+                self
+            }
         }
     };
 }
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use http::{HeaderMap, HeaderName, HeaderValue};
@@ -12,14 +13,17 @@ use graph_core::identity::ForceTokenRefresh;
 use graph_error::{AuthExecutionError, AuthExecutionResult, AuthorizationFailure, IdentityResult};
 
 use crate::identity::credentials::app_config::AppConfig;
+use crate::identity::credentials::{Grant, RemoteAssertionSigner, TokenRequestForm};
 #[cfg(feature = "openssl")]
-use crate::identity::X509Certificate;
+use crate::identity::{
+    credentials::certificate_provider::{sign_client_assertion, sign_client_assertion_async},
+    CertificateProvider, X509Certificate,
+};
 use crate::identity::{
     tracing_targets::CREDENTIAL_EXECUTOR, Authority, AzureCloudInstance,
-    ClientCredentialsAuthorizationUrlParameterBuilder, ConfidentialClientApplication, Token,
-    TokenCredentialExecutor,
+    ClientCredentialsAuthorizationUrlParameterBuilder, ConfidentialClientApplication, RemoteSigner,
+    SecretString, Token, TokenCredentialExecutor,
 };
-use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 
 pub(crate) static CLIENT_ASSERTION_TYPE: &str =
     "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
@@ -58,7 +62,15 @@ pub struct ClientCertificateCredential {
     /// The SDK handles certificates and creating the assertion automatically using the
     /// openssl crate. This is significantly easier than having to format the assertion from
     /// the certificate yourself.
-    pub(crate) client_assertion: String,
+    pub(crate) client_assertion: SecretString,
+    /// Signs a new client assertion when the client assertion is about to expire.
+    #[cfg(feature = "openssl")]
+    certificate: Option<Arc<X509Certificate>>,
+    /// Provides the certificate used to sign a new client assertion before each token request.
+    #[cfg(feature = "openssl")]
+    certificate_provider: Option<Arc<dyn CertificateProvider>>,
+    /// Signs a new client assertion when the client assertion is about to expire.
+    remote_signer: Option<RemoteAssertionSigner>,
     token_cache: InMemoryCacheStore<Token>,
 }
 
@@ -83,7 +95,57 @@ impl ClientCertificateCredential {
         ClientCredentialsAuthorizationUrlParameterBuilder::new(client_id)
     }
 
+    /// Sign a new client assertion when a certificate, remote signer or certificate
+    /// provider is used. A certificate reuses its client assertion until it is within
+    /// the clock skew of expiring, see [X509Certificate::set_clock_skew].
+    #[allow(clippy::result_large_err)]
+    fn refresh_client_assertion(&mut self) -> AuthExecutionResult<()> {
+        let tenant_id = self.app_config.authority.tenant_id().cloned();
+        #[cfg(feature = "openssl")]
+        if let Some(certificate) = self.certificate.as_ref() {
+            self.client_assertion = SecretString::new(certificate.sign_with_tenant(tenant_id)?);
+            return Ok(());
+        }
+
+        if let Some(remote_signer) = self.remote_signer.as_ref() {
+            self.client_assertion =
+                remote_signer.sign(&self.app_config.client_id.to_string(), tenant_id)?;
+            return Ok(());
+        }
+
+        #[cfg(feature = "openssl")]
+        if let Some(certificate_provider) = self.certificate_provider.as_ref() {
+            self.client_assertion =
+                sign_client_assertion(certificate_provider.as_ref(), tenant_id)?;
+        }
+        Ok(())
+    }
+
+    async fn refresh_client_assertion_async(&mut self) -> AuthExecutionResult<()> {
+        let tenant_id = self.app_config.authority.tenant_id().cloned();
+        #[cfg(feature = "openssl")]
+        if let Some(certificate) = self.certificate.as_ref() {
+            self.client_assertion = SecretString::new(certificate.sign_with_tenant(tenant_id)?);
+            return Ok(());
+        }
+
+        if let Some(remote_signer) = self.remote_signer.clone() {
+            self.client_assertion = remote_signer
+                .sign_async(&self.app_config.client_id.to_string(), tenant_id)
+                .await?;
+            return Ok(());
+        }
+
+        #[cfg(feature = "openssl")]
+        if let Some(certificate_provider) = self.certificate_provider.clone() {
+            self.client_assertion =
+                sign_client_assertion_async(certificate_provider.as_ref(), tenant_id).await?;
+        }
+        Ok(())
+    }
+
     fn execute_cached_token_refresh(&mut self, cache_id: String) -> AuthExecutionResult<Token> {
+        self.refresh_client_assertion()?;
         let response = self.execute()?;
 
         if !response.status().is_success() {
@@ -101,6 +163,7 @@ impl ClientCertificateCredential {
         &mut self,
         cache_id: String,
     ) -> AuthExecutionResult<Token> {
+        self.refresh_client_assertion_async().await?;
         let response = self.execute_async().await?;
 
         if !response.status().is_success() {
@@ -130,34 +193,62 @@ impl TokenCache for ClientCertificateCredential {
     #[tracing::instrument]
     fn get_token_silent(&mut self) -> Result<Self::Token, AuthExecutionError> {
         let cache_id = self.app_config.cache_id.to_string();
-        if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-            if token.is_expired_sub(time::Duration::minutes(5)) {
+        match self.app_config.force_token_refresh {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
+                if let Some(token) = self.token_cache.get(cache_id.as_str()) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                        let token_result = self.execute_cached_token_refresh(cache_id);
+                        self.app_config.ext_expires_fallback(token, token_result)
+                    } else {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                        Ok(token)
+                    }
+                } else {
+                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                    self.execute_cached_token_refresh(cache_id)
+                }
+            }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
+            ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-                self.execute_cached_token_refresh(cache_id)
-            } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
-                Ok(token)
+                let token_result = self.execute_cached_token_refresh(cache_id);
+                if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
+                    self.app_config.force_token_refresh = ForceTokenRefresh::Never;
+                }
+                token_result
             }
-        } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-            self.execute_cached_token_refresh(cache_id)
         }
     }
 
     #[tracing::instrument]
     async fn get_token_silent_async(&mut self) -> Result<Self::Token, AuthExecutionError> {
         let cache_id = self.app_config.cache_id.to_string();
-        if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-            if token.is_expired_sub(time::Duration::minutes(5)) {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token refresh");
-                self.execute_cached_token_refresh_async(cache_id).await
-            } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
-                Ok(token.clone())
+        match self.app_config.force_token_refresh {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
+                if let Some(token) = self.token_cache.get(cache_id.as_str()) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token refresh");
+                        let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                        self.app_config.ext_expires_fallback(token, token_result)
+                    } else {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                        Ok(token.clone())
+                    }
+                } else {
+                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request");
+                    self.execute_cached_token_refresh_async(cache_id).await
+                }
+            }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
+            ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
+                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
+                    self.app_config.force_token_refresh = ForceTokenRefresh::Never;
+                }
+                token_result
             }
-        } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request");
-            self.execute_cached_token_refresh_async(cache_id).await
         }
     }
 
@@ -169,36 +260,22 @@ impl TokenCache for ClientCertificateCredential {
 #[async_trait]
 impl TokenCredentialExecutor for ClientCertificateCredential {
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
-        let mut serializer = AuthSerializer::new();
         let client_id = self.app_config.client_id.to_string();
         if client_id.is_empty() || self.app_config.client_id.is_nil() {
-            return AuthorizationFailure::result(AuthParameter::ClientId.alias());
-        }
-
-        if self.client_assertion.trim().is_empty() {
-            return AuthorizationFailure::result(AuthParameter::ClientAssertion.alias());
+            return AuthorizationFailure::result("client_id");
         }
 
         if self.client_assertion_type.trim().is_empty() {
             self.client_assertion_type = CLIENT_ASSERTION_TYPE.to_owned();
         }
 
-        serializer
-            .client_id(client_id.as_str())
-            .client_assertion(self.client_assertion.as_str())
-            .client_assertion_type(self.client_assertion_type.as_str())
-            .grant_type("client_credentials")
-            .set_scope(self.app_config.scope.clone());
-
-        serializer.as_credential_map(
-            vec![AuthParameter::Scope],
-            vec![
-                AuthParameter::ClientId,
-                AuthParameter::GrantType,
-                AuthParameter::ClientAssertion,
-                AuthParameter::ClientAssertionType,
-            ],
-        )
+        TokenRequestForm::new(client_id, Grant::ClientCredentials)
+            .client_assertion(
+                self.client_assertion_type.as_str(),
+                self.client_assertion.expose_secret(),
+            )?
+            .scope(&self.app_config.scope)
+            .into_form()
     }
 
     fn client_id(&self) -> &Uuid {
@@ -216,6 +293,18 @@ impl TokenCredentialExecutor for ClientCertificateCredential {
     fn app_config(&self) -> &AppConfig {
         &self.app_config
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.app_config.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.app_config.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.app_config.with_tenant(tenant_id);
+    }
 }
 
 #[derive(Clone)]
@@ -232,6 +321,11 @@ impl ClientCertificateCredentialBuilder {
                     .build(),
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
                 client_assertion: Default::default(),
+                #[cfg(feature = "openssl")]
+                certificate: None,
+                #[cfg(feature = "openssl")]
+                certificate_provider: None,
+                remote_signer: None,
                 token_cache: Default::default(),
             },
         }
@@ -244,12 +338,17 @@ impl ClientCertificateCredentialBuilder {
     ) -> IdentityResult<ClientCertificateCredentialBuilder> {
         app_config
             .scope
-            .insert("https://graph.microsoft.com/.default".into());
+            .insert(app_config.azure_cloud_instance.graph_default_scope());
         let mut credential_builder = ClientCertificateCredentialBuilder {
             credential: ClientCertificateCredential {
                 app_config,
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
                 client_assertion: Default::default(),
+                #[cfg(feature = "openssl")]
+                certificate: None,
+                #[cfg(feature = "openssl")]
+                certificate_provider: None,
+                remote_signer: None,
                 token_cache: Default::default(),
             },
         };
@@ -257,19 +356,40 @@ impl ClientCertificateCredentialBuilder {
         Ok(credential_builder)
     }
 
+    /// Sign client assertions using the certificate. A new client assertion is signed
+    /// before a token request when the previous one is about to expire, see
+    /// [X509Certificate::set_assertion_lifetime] and [X509Certificate::set_clock_skew].
     #[cfg(feature = "openssl")]
     pub fn with_certificate(&mut self, certificate: &X509Certificate) -> IdentityResult<&mut Self> {
-        if let Some(tenant_id) = self.credential.app_config.authority.tenant_id() {
-            self.with_client_assertion(certificate.sign_with_tenant(Some(tenant_id.clone()))?);
-        } else {
-            self.with_client_assertion(certificate.sign_with_tenant(None)?);
-        }
+        let tenant_id = self.credential.app_config.authority.tenant_id().cloned();
+        self.with_client_assertion(certificate.sign_with_tenant(tenant_id)?);
+        self.credential.certificate = Some(Arc::new(certificate.clone()));
         Ok(self)
     }
 
+    /// Sign client assertions using a service that holds the private key, such as
+    /// Azure Key Vault, instead of a certificate in the memory of the application.
+    /// A client assertion is signed before a token request when the previous one is
+    /// about to expire.
+    pub fn with_remote_signer<S: RemoteSigner + 'static>(&mut self, remote_signer: S) -> &mut Self {
+        self.credential.remote_signer = Some(RemoteAssertionSigner::new(Arc::new(remote_signer)));
+        self
+    }
+
+    /// Sign a new client assertion before each token request using the certificate of the
+    /// provider, so that a rotated certificate is used without creating a new credential.
+    #[cfg(feature = "openssl")]
+    pub fn with_certificate_provider<P: CertificateProvider + 'static>(
+        &mut self,
+        certificate_provider: P,
+    ) -> &mut Self {
+        self.credential.certificate_provider = Some(Arc::new(certificate_provider));
+        self
+    }
+
     #[allow(dead_code)]
     fn with_client_assertion<T: AsRef<str>>(&mut self, client_assertion: T) -> &mut Self {
-        self.credential.client_assertion = client_assertion.as_ref().to_owned();
+        self.credential.client_assertion = SecretString::new(client_assertion.as_ref());
         self
     }
 
@@ -320,4 +440,37 @@ mod test {
             "4ef900be-dfd9-4da6-b224-0011e46c54dd"
         );
     }
+
+    #[test]
+    #[cfg(feature = "openssl")]
+    fn sign_new_client_assertion_after_assertion_lifetime() {
+        use openssl::pkey::PKey;
+        use openssl::x509::X509;
+        use std::time::Duration;
+
+        let client_id = Uuid::new_v4().to_string();
+        let cert = X509::from_pem(include_bytes!("test/cert.pem")).unwrap();
+        let private_key = PKey::private_key_from_pem(include_bytes!("test/key.pem")).unwrap();
+        let mut certificate = X509Certificate::new(&client_id, cert, private_key);
+        certificate.set_assertion_lifetime(Duration::from_secs(2));
+        certificate.set_clock_skew(Duration::ZERO);
+
+        let mut builder = ClientCertificateCredentialBuilder::new(&client_id);
+        builder.with_certificate(&certificate).unwrap();
+        let mut credential = builder.credential();
+        let client_assertion = credential.client_assertion.expose_secret().to_owned();
+
+        credential.refresh_client_assertion().unwrap();
+        assert_eq!(
+            client_assertion,
+            credential.client_assertion.expose_secret()
+        );
+
+        std::thread::sleep(Duration::from_secs(3));
+        credential.refresh_client_assertion().unwrap();
+        assert_ne!(
+            client_assertion,
+            credential.client_assertion.expose_secret()
+        );
+    }
 }
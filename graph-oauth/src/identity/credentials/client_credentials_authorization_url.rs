@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
 use reqwest::IntoUrl;
 
 use url::Url;
@@ -5,24 +8,178 @@ use uuid::Uuid;
 
 use graph_error::{AuthorizationFailure, IdentityResult};
 
-use crate::identity::{credentials::app_config::AppConfig, Authority, AzureCloudInstance};
+use crate::identity::{
+    credentials::app_config::AppConfig, validate_prompt, Authority, AzureCloudInstance, Prompt,
+};
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 use crate::{ClientAssertionCredentialBuilder, ClientSecretCredentialBuilder};
 
 #[cfg(feature = "openssl")]
 use crate::identity::{ClientCertificateCredentialBuilder, X509Certificate};
 
+/// The query of the redirect uri after an administrator has granted consent.
+///
+/// # Example
+/// ```rust
+/// # use graph_oauth::ClientCredentialAdminConsentResponse;
+/// # use url::Url;
+/// let redirect_uri = Url::parse(
+///     "http://localhost:8000/redirect?admin_consent=True&tenant=tenant-id&state=12345"
+/// ).unwrap();
+///
+/// let response =
+///     ClientCredentialAdminConsentResponse::from_redirect_uri(&redirect_uri, Some("12345")).unwrap();
+/// assert!(response.admin_consent);
+/// assert_eq!("tenant-id", response.tenant);
+/// ```
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct ClientCredentialAdminConsentResponse {
     pub admin_consent: bool,
     pub tenant: String,
+    #[serde(default)]
+    pub state: Option<String>,
+    /// The scopes granted when using the v2.0 admin consent endpoint.
+    #[serde(default)]
+    pub scope: Option<String>,
+}
+
+impl ClientCredentialAdminConsentResponse {
+    /// Parse the redirect uri the administrator was sent to after the consent request.
+    ///
+    /// When `expected_state` is given the state returned in the redirect uri must
+    /// be the same as the state sent in the admin consent url. Returns an error if
+    /// the administrator declined to grant consent or the consent request failed.
+    pub fn from_redirect_uri(
+        redirect_uri: &Url,
+        expected_state: Option<&str>,
+    ) -> Result<ClientCredentialAdminConsentResponse, AdminConsentError> {
+        let query: HashMap<String, String> = redirect_uri.query_pairs().into_owned().collect();
+        ClientCredentialAdminConsentResponse::from_query(query, expected_state)
+    }
+
+    /// Parse the query string of the redirect uri. See [ClientCredentialAdminConsentResponse::from_redirect_uri]
+    pub fn from_query_str(
+        query: &str,
+        expected_state: Option<&str>,
+    ) -> Result<ClientCredentialAdminConsentResponse, AdminConsentError> {
+        let query: HashMap<String, String> =
+            url::form_urlencoded::parse(query.trim_start_matches('?').as_bytes())
+                .into_owned()
+                .collect();
+        ClientCredentialAdminConsentResponse::from_query(query, expected_state)
+    }
+
+    fn from_query(
+        mut query: HashMap<String, String>,
+        expected_state: Option<&str>,
+    ) -> Result<ClientCredentialAdminConsentResponse, AdminConsentError> {
+        let state = query.remove("state");
+        if let Some(expected_state) = expected_state {
+            if state.as_deref() != Some(expected_state) {
+                return Err(AdminConsentError::StateMismatch {
+                    expected: expected_state.to_owned(),
+                    actual: state,
+                });
+            }
+        }
+
+        if let Some(error) = query.remove("error") {
+            return Err(AdminConsentError::ConsentDenied {
+                error,
+                error_description: query.remove("error_description"),
+                error_subcode: query.remove("error_subcode"),
+                state,
+            });
+        }
+
+        let admin_consent = query
+            .remove("admin_consent")
+            .map(|value| value.eq_ignore_ascii_case("true"))
+            .ok_or_else(|| AdminConsentError::InvalidResponse("admin_consent".into()))?;
+        let tenant = query
+            .remove("tenant")
+            .ok_or_else(|| AdminConsentError::InvalidResponse("tenant".into()))?;
+
+        Ok(ClientCredentialAdminConsentResponse {
+            admin_consent,
+            tenant,
+            state,
+            scope: query.remove("scope"),
+        })
+    }
 }
 
+/// Errors returned in the redirect uri of an admin consent request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminConsentError {
+    /// The administrator declined to grant consent or the consent request failed.
+    ConsentDenied {
+        /// The error code such as access_denied.
+        error: String,
+        error_description: Option<String>,
+        /// The error subcode. The subcode is cancel when the administrator declined consent.
+        error_subcode: Option<String>,
+        state: Option<String>,
+    },
+    /// The state in the redirect uri is not the same as the state sent in the admin consent url.
+    StateMismatch {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// The redirect uri is missing the given parameter.
+    InvalidResponse(String),
+}
+
+impl AdminConsentError {
+    /// Returns true if the administrator declined to grant consent.
+    pub fn is_cancelled(&self) -> bool {
+        match self {
+            AdminConsentError::ConsentDenied {
+                error,
+                error_subcode,
+                ..
+            } => error == "access_denied" || error_subcode.as_deref() == Some("cancel"),
+            _ => false,
+        }
+    }
+}
+
+impl Display for AdminConsentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdminConsentError::ConsentDenied {
+                error,
+                error_description,
+                ..
+            } => write!(
+                f,
+                "admin consent denied: {error} {}",
+                error_description.as_deref().unwrap_or_default()
+            ),
+            AdminConsentError::StateMismatch { expected, actual } => write!(
+                f,
+                "admin consent state mismatch: expected {expected}, found {actual:?}"
+            ),
+            AdminConsentError::InvalidResponse(name) => {
+                write!(f, "admin consent response is missing {name}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AdminConsentError {}
+
 #[derive(Clone)]
 pub struct ClientCredentialsAuthorizationUrlParameters {
     /// The client (application) ID of the service principal
     pub(crate) app_config: AppConfig,
     pub(crate) state: Option<String>,
+    pub(crate) prompt: Option<Prompt>,
+    /// Pre-fills the username of the administrator on the sign-in page.
+    pub(crate) login_hint: Option<String>,
+    /// The realm of the administrator in a federated directory, which skips the
+    /// email-based discovery on the sign-in page.
+    pub(crate) domain_hint: Option<String>,
 }
 
 impl ClientCredentialsAuthorizationUrlParameters {
@@ -38,6 +195,9 @@ impl ClientCredentialsAuthorizationUrlParameters {
                 .redirect_uri(redirect_uri)
                 .build(),
             state: None,
+            prompt: None,
+            login_hint: None,
+            domain_hint: None,
         })
     }
 
@@ -98,9 +258,35 @@ impl ClientCredentialsAuthorizationUrlParameters {
             serializer.state(state.as_ref());
         }
 
-        let mut uri = azure_cloud_instance.admin_consent_uri(&self.app_config.authority)?;
+        validate_prompt(&self.prompt, self.login_hint.as_ref())?;
+        if let Some(prompt) = self.prompt.as_ref() {
+            serializer.prompt(prompt.as_ref());
+        }
+
+        if let Some(login_hint) = self.login_hint.as_ref() {
+            serializer.login_hint(login_hint.as_str());
+        }
+
+        if let Some(domain_hint) = self.domain_hint.as_ref() {
+            serializer.domain_hint(domain_hint.as_str());
+        }
+
+        // Scopes are only supported by the v2.0 admin consent endpoint.
+        let mut uri = if self.app_config.scope.is_empty() {
+            azure_cloud_instance.admin_consent_uri(&self.app_config.authority)?
+        } else {
+            serializer.set_scope(self.app_config.scope.clone());
+            azure_cloud_instance.admin_consent_v2_uri(&self.app_config.authority)?
+        };
+
         let query = serializer.encode_query(
-            vec![AuthParameter::State],
+            vec![
+                AuthParameter::Scope,
+                AuthParameter::State,
+                AuthParameter::Prompt,
+                AuthParameter::LoginHint,
+                AuthParameter::DomainHint,
+            ],
             vec![AuthParameter::ClientId, AuthParameter::RedirectUri],
         )?;
         uri.set_query(Some(query.as_str()));
@@ -119,6 +305,9 @@ impl ClientCredentialsAuthorizationUrlParameterBuilder {
             credential: ClientCredentialsAuthorizationUrlParameters {
                 app_config: AppConfig::new(client_id.as_ref()),
                 state: None,
+                prompt: None,
+                login_hint: None,
+                domain_hint: None,
             },
         }
     }
@@ -128,6 +317,9 @@ impl ClientCredentialsAuthorizationUrlParameterBuilder {
             credential: ClientCredentialsAuthorizationUrlParameters {
                 app_config,
                 state: None,
+                prompt: None,
+                login_hint: None,
+                domain_hint: None,
             },
         }
     }
@@ -158,6 +350,35 @@ impl ClientCredentialsAuthorizationUrlParameterBuilder {
         self
     }
 
+    /// Request consent for the given scopes using the v2.0 admin consent endpoint.
+    /// Use the /.default scope, such as https://graph.microsoft.com/.default, to request
+    /// consent for all permissions configured in the app registration.
+    pub fn with_scope<T: ToString, I: IntoIterator<Item = T>>(&mut self, scope: I) -> &mut Self {
+        self.credential.app_config.with_scope(scope);
+        self
+    }
+
+    /// Use [Prompt::AdminConsent] to require the administrator to grant consent
+    /// even if consent was granted before.
+    pub fn with_prompt(&mut self, prompt: Prompt) -> &mut Self {
+        self.credential.prompt = Some(prompt);
+        self
+    }
+
+    /// Pre-fill the username of the administrator on the sign-in page.
+    /// Cannot be used with [Prompt::SelectAccount].
+    pub fn with_login_hint<T: AsRef<str>>(&mut self, login_hint: T) -> &mut Self {
+        self.credential.login_hint = Some(login_hint.as_ref().to_owned());
+        self
+    }
+
+    /// The realm of the administrator in a federated directory, which skips the
+    /// email-based discovery on the sign-in page.
+    pub fn with_domain_hint<T: AsRef<str>>(&mut self, domain_hint: T) -> &mut Self {
+        self.credential.domain_hint = Some(domain_hint.as_ref().to_owned());
+        self
+    }
+
     pub fn build(&self) -> ClientCredentialsAuthorizationUrlParameters {
         self.credential.clone()
     }
@@ -195,3 +416,70 @@ impl ClientCredentialsAuthorizationUrlParameterBuilder {
         ClientCertificateCredentialBuilder::new_with_certificate(x509, self.credential.app_config)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn admin_consent_url_with_scope() {
+        let url = ClientCredentialsAuthorizationUrlParameters::builder(Uuid::new_v4().to_string())
+            .with_redirect_uri(Url::parse("http://localhost:8000/redirect").unwrap())
+            .with_tenant("tenant-id")
+            .with_scope(vec!["https://graph.microsoft.com/.default"])
+            .with_state("12345")
+            .with_prompt(Prompt::AdminConsent)
+            .url()
+            .unwrap();
+
+        assert_eq!("/tenant-id/v2.0/adminconsent", url.path());
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!("https://graph.microsoft.com/.default", query["scope"]);
+        assert_eq!("admin_consent", query["prompt"]);
+        assert_eq!("12345", query["state"]);
+    }
+
+    #[test]
+    fn admin_consent_url_with_hints() {
+        let mut builder =
+            ClientCredentialsAuthorizationUrlParameters::builder(Uuid::new_v4().to_string());
+        builder
+            .with_redirect_uri(Url::parse("http://localhost:8000/redirect").unwrap())
+            .with_tenant("tenant-id")
+            .with_login_hint("admin@contoso.com")
+            .with_domain_hint("contoso.com");
+
+        let url = builder.url().unwrap();
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!("admin@contoso.com", query["login_hint"]);
+        assert_eq!("contoso.com", query["domain_hint"]);
+
+        builder.with_prompt(Prompt::SelectAccount);
+        assert!(matches!(
+            builder.url(),
+            Err(AuthorizationFailure::MutuallyExclusive { .. })
+        ));
+    }
+
+    #[test]
+    fn admin_consent_response_state_mismatch() {
+        let result = ClientCredentialAdminConsentResponse::from_query_str(
+            "admin_consent=True&tenant=tenant-id&state=54321",
+            Some("12345"),
+        );
+        assert!(matches!(
+            result,
+            Err(AdminConsentError::StateMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn admin_consent_response_denied() {
+        let error = ClientCredentialAdminConsentResponse::from_query_str(
+            "?error=access_denied&error_subcode=cancel&error_description=declined&state=12345",
+            Some("12345"),
+        )
+        .unwrap_err();
+        assert!(error.is_cancelled());
+    }
+}
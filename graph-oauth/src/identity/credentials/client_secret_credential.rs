@@ -11,12 +11,12 @@ use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
 use graph_core::identity::ForceTokenRefresh;
 use graph_error::{AuthExecutionError, AuthExecutionResult, AuthorizationFailure, IdentityResult};
 
+use crate::identity::credentials::{Grant, TokenRequestForm};
 use crate::identity::{
     credentials::app_config::AppConfig, tracing_targets::CREDENTIAL_EXECUTOR, Authority,
     AzureCloudInstance, ClientCredentialsAuthorizationUrlParameterBuilder,
-    ConfidentialClientApplication, Token, TokenCredentialExecutor,
+    ConfidentialClientApplication, SecretString, Token, TokenCredentialExecutor,
 };
-use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 
 credential_builder!(
     ClientSecretCredentialBuilder,
@@ -45,7 +45,7 @@ pub struct ClientSecretCredential {
     /// is done by the SDK. For more information on URI encoding, see the URI Generic Syntax
     /// specification. The Basic auth pattern of instead providing credentials in the Authorization
     /// header, per RFC 6749 is also supported.
-    pub(crate) client_secret: String,
+    pub(crate) client_secret: SecretString,
     token_cache: InMemoryCacheStore<Token>,
 }
 
@@ -66,7 +66,7 @@ impl ClientSecretCredential {
             app_config: AppConfig::builder(client_id.as_ref())
                 .scope(vec!["https://graph.microsoft.com/.default"])
                 .build(),
-            client_secret: client_secret.as_ref().to_owned(),
+            client_secret: SecretString::new(client_secret.as_ref()),
             token_cache: InMemoryCacheStore::new(),
         }
     }
@@ -81,7 +81,7 @@ impl ClientSecretCredential {
                 .tenant(tenant_id.as_ref())
                 .scope(vec!["https://graph.microsoft.com/.default"])
                 .build(),
-            client_secret: client_secret.as_ref().to_owned(),
+            client_secret: SecretString::new(client_secret.as_ref()),
             token_cache: InMemoryCacheStore::new(),
         }
     }
@@ -130,33 +130,61 @@ impl TokenCache for ClientSecretCredential {
 
     fn get_token_silent(&mut self) -> Result<Self::Token, AuthExecutionError> {
         let cache_id = self.app_config.cache_id.to_string();
-        if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-            if token.is_expired_sub(time::Duration::minutes(5)) {
+        match self.app_config.force_token_refresh {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
+                if let Some(token) = self.token_cache.get(cache_id.as_str()) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                        let token_result = self.execute_cached_token_refresh(cache_id);
+                        self.app_config.ext_expires_fallback(token, token_result)
+                    } else {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                        Ok(token)
+                    }
+                } else {
+                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                    self.execute_cached_token_refresh(cache_id)
+                }
+            }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
+            ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-                self.execute_cached_token_refresh(cache_id)
-            } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
-                Ok(token)
+                let token_result = self.execute_cached_token_refresh(cache_id);
+                if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
+                    self.app_config.force_token_refresh = ForceTokenRefresh::Never;
+                }
+                token_result
             }
-        } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-            self.execute_cached_token_refresh(cache_id)
         }
     }
 
     async fn get_token_silent_async(&mut self) -> Result<Self::Token, AuthExecutionError> {
         let cache_id = self.app_config.cache_id.to_string();
-        if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-            if token.is_expired_sub(time::Duration::minutes(5)) {
+        match self.app_config.force_token_refresh {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
+                if let Some(token) = self.token_cache.get(cache_id.as_str()) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                        let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                        self.app_config.ext_expires_fallback(token, token_result)
+                    } else {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                        Ok(token.clone())
+                    }
+                } else {
+                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                    self.execute_cached_token_refresh_async(cache_id).await
+                }
+            }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
+            ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-                self.execute_cached_token_refresh_async(cache_id).await
-            } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
-                Ok(token.clone())
+                let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
+                    self.app_config.force_token_refresh = ForceTokenRefresh::Never;
+                }
+                token_result
             }
-        } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-            self.execute_cached_token_refresh_async(cache_id).await
         }
     }
 
@@ -168,25 +196,20 @@ impl TokenCache for ClientSecretCredential {
 #[async_trait]
 impl TokenCredentialExecutor for ClientSecretCredential {
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
-        let mut serializer = AuthSerializer::new();
         let client_id = self.app_config.client_id.to_string();
         if client_id.is_empty() || self.app_config.client_id.is_nil() {
-            return AuthorizationFailure::result(AuthParameter::ClientId);
+            return AuthorizationFailure::result("client_id");
         }
 
-        if self.client_secret.trim().is_empty() {
-            return AuthorizationFailure::result(AuthParameter::ClientSecret);
+        if self.client_secret.expose_secret().trim().is_empty() {
+            return AuthorizationFailure::result("client_secret");
         }
 
-        serializer
-            .client_id(client_id.as_str())
-            .client_secret(self.client_secret.as_str())
-            .grant_type("client_credentials")
-            .set_scope(self.app_config.scope.clone());
-
-        // Don't include ClientId and Client Secret in the fields for form url encode because
-        // Client Id and Client Secret are already included as basic auth.
-        serializer.as_credential_map(vec![AuthParameter::Scope], vec![AuthParameter::GrantType])
+        // The client id and client secret are not included in the form because
+        // they are sent using basic auth.
+        TokenRequestForm::basic_auth(Grant::ClientCredentials)
+            .scope(&self.app_config.scope)
+            .into_form()
     }
 
     fn client_id(&self) -> &Uuid {
@@ -204,13 +227,25 @@ impl TokenCredentialExecutor for ClientSecretCredential {
     fn basic_auth(&self) -> Option<(String, String)> {
         Some((
             self.app_config.client_id.to_string(),
-            self.client_secret.clone(),
+            self.client_secret.expose_secret().to_owned(),
         ))
     }
 
     fn app_config(&self) -> &AppConfig {
         &self.app_config
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.app_config.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.app_config.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.app_config.with_tenant(tenant_id);
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -231,18 +266,18 @@ impl ClientSecretCredentialBuilder {
     ) -> ClientSecretCredentialBuilder {
         app_config
             .scope
-            .insert("https://graph.microsoft.com/.default".into());
+            .insert(app_config.azure_cloud_instance.graph_default_scope());
         Self {
             credential: ClientSecretCredential {
                 app_config,
-                client_secret: client_secret.as_ref().to_string(),
+                client_secret: SecretString::new(client_secret.as_ref()),
                 token_cache: InMemoryCacheStore::new(),
             },
         }
     }
 
     pub fn with_client_secret<T: AsRef<str>>(&mut self, client_secret: T) -> &mut Self {
-        self.credential.client_secret = client_secret.as_ref().to_owned();
+        self.credential.client_secret = SecretString::new(client_secret.as_ref());
         self
     }
 
@@ -12,10 +12,11 @@ use graph_core::identity::{ClientApplication, ForceTokenRefresh};
 use graph_error::{AuthExecutionResult, IdentityResult};
 
 use crate::identity::{
-    AppConfig, Authority, AuthorizationCodeAssertionCredential,
+    Account, AppConfig, Authority, AuthorizationCodeAssertionCredential,
     AuthorizationCodeCertificateCredential, AuthorizationCodeCredential, AzureCloudInstance,
     ClientAssertionCredential, ClientCertificateCredential, ClientSecretCredential,
-    ConfidentialClientApplicationBuilder, OpenIdCredential, TokenCredentialExecutor,
+    ConfidentialClientApplicationBuilder, IncrementalConsent, OpenIdCredential, Token,
+    TokenCredentialExecutor, TokenRefresher,
 };
 
 /// Clients capable of maintaining the confidentiality of their credentials
@@ -57,8 +58,35 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCredentialExecutor>
     }
 }
 
+impl<Credential: Clone + TokenCache<Token = Token> + Send + 'static>
+    ConfidentialClientApplication<Credential>
+{
+    /// Spawn a tokio task that refreshes the token when it is within `margin` of
+    /// expiring, so that requests using this client, or any clone of it, do not wait
+    /// on a token request.
+    ///
+    /// The refresher stops when the returned [TokenRefresher] is dropped.
+    ///
+    /// # Panics
+    /// Panics if called outside of a tokio runtime.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let confidential_client = ConfidentialClientApplication::builder(client_id)
+    ///     .with_client_secret(client_secret)
+    ///     .with_tenant(tenant_id)
+    ///     .build();
+    ///
+    /// let token_refresher = confidential_client.spawn_token_refresher(Duration::from_secs(300));
+    /// let graph_client = GraphClient::new(confidential_client);
+    /// ```
+    pub fn spawn_token_refresher(&self, margin: std::time::Duration) -> TokenRefresher {
+        TokenRefresher::spawn(self.credential.clone(), margin)
+    }
+}
+
 #[async_trait]
-impl<Credential: Clone + Debug + Send + Sync + TokenCache + TokenCredentialExecutor>
+impl<Credential: Clone + Debug + Send + Sync + TokenCache + TokenCredentialExecutor + 'static>
     ClientApplication for ConfidentialClientApplication<Credential>
 {
     fn get_token_silent(&mut self) -> AuthExecutionResult<String> {
@@ -75,6 +103,31 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCache + TokenCredentialExecu
         self.credential
             .with_force_token_refresh(force_token_refresh);
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        TokenCredentialExecutor::with_http_client(&mut self.credential, http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        TokenCredentialExecutor::with_blocking_http_client(&mut self.credential, http_client);
+    }
+
+    fn has_http_client(&self) -> bool {
+        self.credential.app_config().http_client().is_some()
+    }
+
+    fn has_blocking_http_client(&self) -> bool {
+        self.credential
+            .app_config()
+            .blocking_http_client()
+            .is_some()
+    }
+
+    fn for_tenant(&self, tenant_id: &str) -> Option<Box<dyn ClientApplication>> {
+        let mut client_application = self.clone();
+        TokenCredentialExecutor::with_tenant(&mut client_application.credential, tenant_id);
+        Some(Box::new(client_application))
+    }
 }
 
 #[async_trait]
@@ -109,6 +162,18 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCredentialExecutor> TokenCre
         self.credential.app_config()
     }
 
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.credential.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.credential.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.credential.with_tenant(tenant_id);
+    }
+
     fn execute(&mut self) -> AuthExecutionResult<reqwest::blocking::Response> {
         self.credential.execute()
     }
@@ -118,6 +183,66 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCredentialExecutor> TokenCre
     }
 }
 
+impl ConfidentialClientApplication<AuthorizationCodeCredential> {
+    /// Acquire a token for an account from the shared [AccountTokenCache](crate::identity::AccountTokenCache).
+    /// See [AuthorizationCodeCredential::acquire_token_silent].
+    #[allow(clippy::result_large_err)]
+    pub fn acquire_token_silent<T: ToString, I: IntoIterator<Item = T>>(
+        &self,
+        account: &Account,
+        scope: I,
+    ) -> AuthExecutionResult<Token> {
+        self.credential.acquire_token_silent(account, scope)
+    }
+
+    pub async fn acquire_token_silent_async<T: ToString, I: IntoIterator<Item = T>>(
+        &self,
+        account: &Account,
+        scope: I,
+    ) -> AuthExecutionResult<Token> {
+        self.credential
+            .acquire_token_silent_async(account, scope)
+            .await
+    }
+
+    /// Request a token that includes additional scopes for the signed in user.
+    /// See [AuthorizationCodeCredential::with_additional_scopes].
+    #[allow(clippy::result_large_err)]
+    pub fn with_additional_scopes<T: ToString, I: IntoIterator<Item = T>>(
+        &mut self,
+        scope: I,
+    ) -> AuthExecutionResult<IncrementalConsent> {
+        self.credential.with_additional_scopes(scope)
+    }
+
+    pub async fn with_additional_scopes_async<T: ToString, I: IntoIterator<Item = T>>(
+        &mut self,
+        scope: I,
+    ) -> AuthExecutionResult<IncrementalConsent> {
+        self.credential.with_additional_scopes_async(scope).await
+    }
+
+    /// Exchange the authorization code returned after the user consented to
+    /// additional scopes for a token.
+    /// See [AuthorizationCodeCredential::complete_consent].
+    #[allow(clippy::result_large_err)]
+    pub fn complete_consent(
+        &mut self,
+        authorization_code: impl AsRef<str>,
+    ) -> AuthExecutionResult<Token> {
+        self.credential.complete_consent(authorization_code)
+    }
+
+    pub async fn complete_consent_async(
+        &mut self,
+        authorization_code: impl AsRef<str>,
+    ) -> AuthExecutionResult<Token> {
+        self.credential
+            .complete_consent_async(authorization_code)
+            .await
+    }
+}
+
 impl From<AuthorizationCodeCredential>
     for ConfidentialClientApplication<AuthorizationCodeCredential>
 {
@@ -235,4 +360,74 @@ mod test {
             credential_uri.as_str()
         );
     }
+
+    #[test]
+    fn confidential_client_for_tenant() {
+        let mut confidential_client = ConfidentialClientApplication::builder(Uuid::new_v4())
+            .with_client_secret("ALDSKFJLKERLKJALSDKJF2209LAKJGFL")
+            .with_tenant("tenant")
+            .build();
+
+        let mut tenant_client = confidential_client.clone();
+        TokenCredentialExecutor::with_tenant(&mut tenant_client, "contoso.onmicrosoft.com");
+
+        assert_eq!(
+            "https://login.microsoftonline.com/contoso.onmicrosoft.com/oauth2/v2.0/token",
+            tenant_client.uri().unwrap().as_str()
+        );
+        assert_ne!(
+            confidential_client.app_config().cache_id,
+            tenant_client.app_config().cache_id
+        );
+        assert_eq!(
+            "https://login.microsoftonline.com/tenant/oauth2/v2.0/token",
+            confidential_client.uri().unwrap().as_str()
+        );
+        assert!(
+            ClientApplication::for_tenant(&confidential_client, "contoso.onmicrosoft.com")
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn force_token_refresh_cache_only() {
+        let mut confidential_client = ConfidentialClientApplication::builder(Uuid::new_v4())
+            .with_client_secret("ALDSKFJLKERLKJALSDKJF2209LAKJGFL")
+            .with_tenant("tenant")
+            .build();
+
+        ClientApplication::with_force_token_refresh(
+            &mut confidential_client,
+            ForceTokenRefresh::WhenExpiredWithin(std::time::Duration::from_secs(900)),
+        );
+        assert_eq!(
+            time::Duration::minutes(15),
+            confidential_client.app_config().token_refresh_margin()
+        );
+
+        ClientApplication::with_force_token_refresh(
+            &mut confidential_client,
+            ForceTokenRefresh::CacheOnly,
+        );
+        assert!(ClientApplication::get_token_silent(&mut confidential_client).is_err());
+    }
+
+    #[test]
+    fn national_cloud_default_scope() {
+        let mut confidential_client = ConfidentialClientApplication::builder(Uuid::new_v4())
+            .with_client_secret("ALDSKFJLKERLKJALSDKJF2209LAKJGFL")
+            .with_tenant("tenant")
+            .with_azure_cloud_instance(AzureCloudInstance::AzureUsGovernment)
+            .build();
+
+        let form = confidential_client.form_urlencode().unwrap();
+        assert_eq!("https://graph.microsoft.us/.default", form["scope"]);
+
+        let mut confidential_client = ConfidentialClientApplication::builder(Uuid::new_v4())
+            .with_client_secret("ALDSKFJLKERLKJALSDKJF2209LAKJGFL")
+            .with_azure_cloud_instance(AzureCloudInstance::AzureUsGovernment)
+            .with_scope(vec!["https://graph.microsoft.com/User.Read.All"])
+            .build();
+        assert!(confidential_client.request_parts().is_err());
+    }
 }
@@ -0,0 +1,311 @@
+use std::fmt::{Debug, Formatter};
+use std::path::{Path, PathBuf};
+
+use graph_core::identity::ClientApplication;
+use graph_error::{IdentityResult, AF};
+
+use crate::identity::{
+    AzureCloudInstance, ClientCertificateCredential, ClientSecretCredentialBuilder,
+    ConfidentialClientApplication, DeviceCodeCredential, DeviceCodePollingExecutor,
+    PublicClientApplication,
+};
+
+/// Credential configuration that can be loaded from a configuration file such as
+/// YAML, TOML, or JSON. The kind of credential is given by `type`.
+///
+/// When no scope is given the `.default` scope of Microsoft Graph in the
+/// cloud of the credential is used.
+///
+/// # Example
+/// ```rust,ignore
+/// // config.toml
+/// // [credential]
+/// // type = "client_certificate"
+/// // client_id = "<CLIENT_ID>"
+/// // tenant_id = "<TENANT_ID>"
+/// // certificate_path = "certificate.pem"
+/// // certificate_password = "<PASSWORD>"
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     credential: CredentialConfig,
+/// }
+///
+/// let config: Config = toml::from_str(&std::fs::read_to_string("config.toml")?)?;
+/// let client = GraphClient::from_client_app(config.credential.client_application()?);
+/// ```
+#[derive(Clone, Eq, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CredentialConfig {
+    ClientSecret {
+        client_id: String,
+        #[serde(default)]
+        tenant_id: Option<String>,
+        client_secret: String,
+        #[serde(default)]
+        scope: Vec<String>,
+        #[serde(default)]
+        azure_cloud_instance: AzureCloudInstance,
+    },
+    /// A PEM file that has both the certificate and the private key. The private
+    /// key is decrypted using the password when one is given. Requires the
+    /// `openssl` feature.
+    ClientCertificate {
+        client_id: String,
+        #[serde(default)]
+        tenant_id: Option<String>,
+        certificate_path: PathBuf,
+        #[serde(default)]
+        certificate_password: Option<String>,
+        #[serde(default)]
+        scope: Vec<String>,
+        #[serde(default)]
+        azure_cloud_instance: AzureCloudInstance,
+    },
+    /// The device code flow. A client application can only be created when a
+    /// refresh token from a previous sign in is given, otherwise use
+    /// [CredentialConfig::device_code_executor] to sign in.
+    DeviceCode {
+        client_id: String,
+        #[serde(default)]
+        tenant_id: Option<String>,
+        #[serde(default)]
+        refresh_token: Option<String>,
+        #[serde(default)]
+        scope: Vec<String>,
+        #[serde(default)]
+        azure_cloud_instance: AzureCloudInstance,
+    },
+}
+
+impl Debug for CredentialConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CredentialConfig::ClientSecret {
+                client_id,
+                tenant_id,
+                scope,
+                azure_cloud_instance,
+                ..
+            } => f
+                .debug_struct("ClientSecret")
+                .field("client_id", client_id)
+                .field("tenant_id", tenant_id)
+                .field("scope", scope)
+                .field("azure_cloud_instance", azure_cloud_instance)
+                .finish(),
+            CredentialConfig::ClientCertificate {
+                client_id,
+                tenant_id,
+                certificate_path,
+                scope,
+                azure_cloud_instance,
+                ..
+            } => f
+                .debug_struct("ClientCertificate")
+                .field("client_id", client_id)
+                .field("tenant_id", tenant_id)
+                .field("certificate_path", certificate_path)
+                .field("scope", scope)
+                .field("azure_cloud_instance", azure_cloud_instance)
+                .finish(),
+            CredentialConfig::DeviceCode {
+                client_id,
+                tenant_id,
+                scope,
+                azure_cloud_instance,
+                ..
+            } => f
+                .debug_struct("DeviceCode")
+                .field("client_id", client_id)
+                .field("tenant_id", tenant_id)
+                .field("scope", scope)
+                .field("azure_cloud_instance", azure_cloud_instance)
+                .finish(),
+        }
+    }
+}
+
+impl CredentialConfig {
+    /// Create the client application for the credential.
+    pub fn client_application(&self) -> IdentityResult<Box<dyn ClientApplication>> {
+        match self {
+            CredentialConfig::ClientSecret {
+                client_id,
+                tenant_id,
+                client_secret,
+                scope,
+                azure_cloud_instance,
+            } => {
+                let mut builder = ClientSecretCredentialBuilder::new(client_id, client_secret);
+                if let Some(tenant_id) = tenant_id {
+                    builder.with_tenant(tenant_id);
+                }
+                builder
+                    .with_azure_cloud_instance(*azure_cloud_instance)
+                    .with_scope(default_scope(scope, azure_cloud_instance));
+                Ok(Box::new(ConfidentialClientApplication::credential(
+                    builder.credential(),
+                )))
+            }
+            CredentialConfig::ClientCertificate {
+                client_id,
+                tenant_id,
+                certificate_path,
+                certificate_password,
+                scope,
+                azure_cloud_instance,
+            } => Ok(Box::new(client_certificate_from_pem_file(
+                "certificate_path",
+                client_id,
+                tenant_id.as_deref(),
+                certificate_path,
+                certificate_password.as_deref(),
+                *azure_cloud_instance,
+                default_scope(scope, azure_cloud_instance),
+            )?)),
+            CredentialConfig::DeviceCode {
+                client_id,
+                tenant_id,
+                refresh_token,
+                scope,
+                azure_cloud_instance,
+            } => {
+                let refresh_token = refresh_token.as_ref().ok_or_else(|| {
+                    AF::msg_err(
+                        "refresh_token",
+                        "device code credentials require a refresh token, use device_code_executor to sign in",
+                    )
+                })?;
+                let mut builder = DeviceCodeCredential::builder(client_id);
+                if let Some(tenant_id) = tenant_id {
+                    builder.with_tenant(tenant_id);
+                }
+                builder
+                    .with_azure_cloud_instance(*azure_cloud_instance)
+                    .with_scope(default_scope(scope, azure_cloud_instance))
+                    .with_refresh_token(refresh_token);
+                let client_application: PublicClientApplication<DeviceCodeCredential> =
+                    builder.build();
+                Ok(Box::new(client_application))
+            }
+        }
+    }
+
+    /// Create the executor that signs in using the device code flow.
+    /// Returns an error when the credential is not a device code credential.
+    pub fn device_code_executor(&self) -> IdentityResult<DeviceCodePollingExecutor> {
+        match self {
+            CredentialConfig::DeviceCode {
+                client_id,
+                tenant_id,
+                scope,
+                azure_cloud_instance,
+                ..
+            } => {
+                let mut builder = PublicClientApplication::builder(client_id);
+                if let Some(tenant_id) = tenant_id {
+                    builder.with_tenant(tenant_id);
+                }
+                builder
+                    .with_azure_cloud_instance(*azure_cloud_instance)
+                    .with_scope(default_scope(scope, azure_cloud_instance));
+                Ok(builder.with_device_code_executor())
+            }
+            _ => Err(AF::msg_err("type", "expected a device_code credential")),
+        }
+    }
+}
+
+fn default_scope(scope: &[String], azure_cloud_instance: &AzureCloudInstance) -> Vec<String> {
+    if scope.is_empty() {
+        vec![format!("{}/.default", azure_cloud_instance.graph_host())]
+    } else {
+        scope.to_vec()
+    }
+}
+
+/// Create a client certificate credential from a PEM file that has both the
+/// certificate and the private key. The `field` is the name of the setting the
+/// path was read from and is used in errors.
+#[cfg(feature = "openssl")]
+pub(crate) fn client_certificate_from_pem_file(
+    field: &str,
+    client_id: &str,
+    tenant_id: Option<&str>,
+    certificate_path: &Path,
+    certificate_password: Option<&str>,
+    azure_cloud_instance: AzureCloudInstance,
+    scope: Vec<String>,
+) -> IdentityResult<ConfidentialClientApplication<ClientCertificateCredential>> {
+    use crate::identity::X509Certificate;
+
+    let pem = std::fs::read(certificate_path)
+        .map_err(|err| AF::msg_err(field, err.to_string().as_str()))?;
+    let x509 = X509Certificate::from_pem(client_id, &pem, certificate_password)?;
+
+    let mut builder = ClientCertificateCredential::builder(client_id);
+    if let Some(tenant_id) = tenant_id {
+        builder.with_tenant(tenant_id);
+    }
+    builder
+        .with_azure_cloud_instance(azure_cloud_instance)
+        .with_scope(scope)
+        .with_certificate(&x509)?;
+    Ok(ConfidentialClientApplication::credential(
+        builder.credential(),
+    ))
+}
+
+#[cfg(not(feature = "openssl"))]
+pub(crate) fn client_certificate_from_pem_file(
+    field: &str,
+    _client_id: &str,
+    _tenant_id: Option<&str>,
+    _certificate_path: &Path,
+    _certificate_password: Option<&str>,
+    _azure_cloud_instance: AzureCloudInstance,
+    _scope: Vec<String>,
+) -> IdentityResult<ConfidentialClientApplication<ClientCertificateCredential>> {
+    Err(AF::msg_err(
+        field,
+        "certificate credentials require the openssl feature",
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_credential_config() {
+        let config: CredentialConfig = serde_json::from_value(serde_json::json!({
+            "type": "client_secret",
+            "client_id": "6731de76-14a6-49ae-97bc-6eba6914391e",
+            "tenant_id": "tenant",
+            "client_secret": "secret",
+            "azure_cloud_instance": "AzureUsGovernment"
+        }))
+        .unwrap();
+        assert!(!format!("{config:?}").contains("secret\""));
+        assert!(config.client_application().is_ok());
+        assert!(config.device_code_executor().is_err());
+
+        let config: CredentialConfig = serde_json::from_value(serde_json::json!({
+            "type": "device_code",
+            "client_id": "6731de76-14a6-49ae-97bc-6eba6914391e",
+            "scope": ["User.Read"]
+        }))
+        .unwrap();
+        assert!(config.client_application().is_err());
+        assert!(config.device_code_executor().is_ok());
+
+        assert!(
+            serde_json::from_value::<CredentialConfig>(serde_json::json!({
+                "type": "managed_identity",
+                "client_id": "6731de76-14a6-49ae-97bc-6eba6914391e"
+            }))
+            .is_err()
+        );
+    }
+}
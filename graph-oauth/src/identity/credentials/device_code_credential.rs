@@ -12,11 +12,11 @@ use tracing::error;
 use url::Url;
 use uuid::Uuid;
 
+use crate::identity::credentials::{DeviceAuthorizationForm, Grant, TokenRequestForm};
 use crate::identity::{
     AppConfig, Authority, AzureCloudInstance, DeviceAuthorizationResponse, PollDeviceCodeEvent,
-    PublicClientApplication, Token, TokenCredentialExecutor,
+    PublicClientApplication, SecretString, Token, TokenCredentialExecutor,
 };
-use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 use graph_core::http::{
     AsyncResponseConverterExt, HttpResponseExt, JsonHttpResponse, ResponseConverterExt,
 };
@@ -31,11 +31,9 @@ use {
     crate::tracing_targets::INTERACTIVE_AUTH,
     graph_error::WebViewDeviceCodeError,
     tao::{event_loop::EventLoopProxy, window::Window},
-    wry::{WebView, WebViewBuilder},
+    wry::{WebContext, WebView, WebViewBuilder},
 };
 
-const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
-
 credential_builder!(
     DeviceCodeCredentialBuilder,
     PublicClientApplication<DeviceCodeCredential>
@@ -54,7 +52,7 @@ pub struct DeviceCodeCredential {
     /// Required when requesting a new access token using a refresh token
     /// The refresh token needed to make an access token request using a refresh token.
     /// Do not include an authorization code when using a refresh token.
-    pub(crate) refresh_token: Option<String>,
+    pub(crate) refresh_token: Option<SecretString>,
     /// Required.
     /// The device_code returned in the device authorization request.
     /// A device_code is a long string used to verify the session between the client and the authorization server.
@@ -78,7 +76,7 @@ impl DeviceCodeCredential {
     }
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
-        self.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
         self
     }
 
@@ -102,9 +100,10 @@ impl DeviceCodeCredential {
 
         let new_token: Token = response.json()?;
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
 
         if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
+            self.refresh_token = new_token.refresh_token.clone().map(SecretString::new);
         }
 
         Ok(new_token)
@@ -125,10 +124,11 @@ impl DeviceCodeCredential {
         let new_token: Token = response.json().await?;
 
         if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
+            self.refresh_token = new_token.refresh_token.clone().map(SecretString::new);
         }
 
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
         Ok(new_token)
     }
 }
@@ -149,7 +149,7 @@ impl TokenCache for DeviceCodeCredential {
         let cache_id = self.app_config.cache_id.to_string();
 
         match self.app_config.force_token_refresh {
-            ForceTokenRefresh::Never => {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
                 // Attempt to bypass a read on the token store by using previous
                 // refresh token stored outside of RwLock
                 if self.refresh_token.is_some() {
@@ -159,12 +159,13 @@ impl TokenCache for DeviceCodeCredential {
                 }
 
                 if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-                    if token.is_expired_sub(time::Duration::minutes(5)) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
                         if let Some(refresh_token) = token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(SecretString::new(refresh_token));
                         }
 
-                        self.execute_cached_token_refresh(cache_id)
+                        let token_result = self.execute_cached_token_refresh(cache_id);
+                        self.app_config.ext_expires_fallback(token, token_result)
                     } else {
                         Ok(token)
                     }
@@ -172,6 +173,7 @@ impl TokenCache for DeviceCodeCredential {
                     self.execute_cached_token_refresh(cache_id)
                 }
             }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 let token_result = self.execute_cached_token_refresh(cache_id);
                 if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
@@ -186,7 +188,7 @@ impl TokenCache for DeviceCodeCredential {
         let cache_id = self.app_config.cache_id.to_string();
 
         match self.app_config.force_token_refresh {
-            ForceTokenRefresh::Never => {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
                 // Attempt to bypass a read on the token store by using previous
                 // refresh token stored outside of RwLock
                 if self.refresh_token.is_some() {
@@ -199,12 +201,14 @@ impl TokenCache for DeviceCodeCredential {
                 }
 
                 if let Some(old_token) = self.token_cache.get(cache_id.as_str()) {
-                    if old_token.is_expired_sub(time::Duration::minutes(5)) {
+                    if old_token.is_expired_sub(self.app_config.token_refresh_margin()) {
                         if let Some(refresh_token) = old_token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(SecretString::new(refresh_token));
                         }
 
-                        self.execute_cached_token_refresh_async(cache_id).await
+                        let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                        self.app_config
+                            .ext_expires_fallback(old_token, token_result)
                     } else {
                         Ok(old_token.clone())
                     }
@@ -212,6 +216,7 @@ impl TokenCache for DeviceCodeCredential {
                     self.execute_cached_token_refresh_async(cache_id).await
                 }
             }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 let token_result = self.execute_cached_token_refresh_async(cache_id).await;
                 if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
@@ -239,61 +244,34 @@ impl TokenCredentialExecutor for DeviceCodeCredential {
     }
 
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
-        let mut serializer = AuthSerializer::new();
         let client_id = self.app_config.client_id.to_string();
         if client_id.is_empty() || self.app_config.client_id.is_nil() {
-            return AuthorizationFailure::result(AuthParameter::ClientId.alias());
+            return AuthorizationFailure::result("client_id");
         }
 
-        serializer
-            .client_id(client_id.as_str())
-            .set_scope(self.app_config.scope.clone());
-
-        if let Some(refresh_token) = self.refresh_token.as_ref() {
-            if refresh_token.trim().is_empty() {
-                return AuthorizationFailure::msg_result(
-                    AuthParameter::RefreshToken.alias(),
-                    "Found empty string for refresh token",
-                );
-            }
-
-            serializer
-                .grant_type("refresh_token")
-                .device_code(refresh_token.as_ref());
-
-            return serializer.as_credential_map(
-                vec![],
-                vec![
-                    AuthParameter::ClientId,
-                    AuthParameter::RefreshToken,
-                    AuthParameter::Scope,
-                    AuthParameter::GrantType,
-                ],
-            );
+        let grant = if let Some(refresh_token) = self.refresh_token.as_ref() {
+            Grant::refresh_token(refresh_token.expose_secret())?
         } else if let Some(device_code) = self.device_code.as_ref() {
             if device_code.trim().is_empty() {
                 return AuthorizationFailure::msg_result(
-                    AuthParameter::DeviceCode.alias(),
+                    "device_code",
                     "Found empty string for device code",
                 );
             }
+            Grant::DeviceCode {
+                device_code: SecretString::new(device_code),
+            }
+        } else {
+            return DeviceAuthorizationForm::new(client_id, &self.app_config.scope)?.into_form();
+        };
 
-            serializer
-                .grant_type(DEVICE_CODE_GRANT_TYPE)
-                .device_code(device_code.as_ref());
-
-            return serializer.as_credential_map(
-                vec![],
-                vec![
-                    AuthParameter::ClientId,
-                    AuthParameter::DeviceCode,
-                    AuthParameter::Scope,
-                    AuthParameter::GrantType,
-                ],
-            );
+        if self.app_config.scope.is_empty() {
+            return AuthorizationFailure::result("scope");
         }
 
-        serializer.as_credential_map(vec![], vec![AuthParameter::ClientId, AuthParameter::Scope])
+        TokenRequestForm::new(client_id, grant)
+            .scope(&self.app_config.scope)
+            .into_form()
     }
 
     fn client_id(&self) -> &Uuid {
@@ -311,6 +289,18 @@ impl TokenCredentialExecutor for DeviceCodeCredential {
     fn app_config(&self) -> &AppConfig {
         &self.app_config
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.app_config.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.app_config.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.app_config.with_tenant(tenant_id);
+    }
 }
 
 #[derive(Clone)]
@@ -352,7 +342,7 @@ impl DeviceCodeCredentialBuilder {
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
         self.credential.device_code = None;
-        self.credential.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.credential.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
         self
     }
 }
@@ -562,8 +552,10 @@ pub(crate) mod internal {
             host_options: HostOptions,
             window: &Window,
             _proxy: EventLoopProxy<UserEvents>,
+            web_context: &mut WebContext,
         ) -> anyhow::Result<WebView> {
             Ok(WebViewBuilder::new(window)
+                .with_web_context(web_context)
                 .with_url(host_options.start_uri.as_ref())
                 // Disables file drop
                 .with_file_drop_handler(|_| true)
@@ -643,6 +635,7 @@ impl DeviceCodeInteractiveAuth {
                     let token: Token = serde_json::from_value(json)
                         .map_err(|err| Box::new(AuthExecutionError::from(err)))?;
                     let cache_id = credential.app_config.cache_id.clone();
+                    credential.app_config.cache_account_token(&token);
                     credential.token_cache.store(cache_id, token);
                     Ok(PublicClientApplication::from(credential))
                 } else {
@@ -1,16 +1,26 @@
 use std::env::VarError;
 use std::fmt::{Debug, Formatter};
+use std::path::Path;
 
+use async_trait::async_trait;
+
+use graph_core::identity::{ClientApplication, ForceTokenRefresh};
+use graph_error::{AuthExecutionResult, IdentityResult, AF};
+
+use crate::identity::credentials::credential_config::client_certificate_from_pem_file;
 use crate::identity::{
-    ClientSecretCredential, ConfidentialClientApplication, PublicClientApplication,
-    ResourceOwnerPasswordCredential,
+    AzureCloudInstance, ClientCertificateCredential, ClientSecretCredential,
+    ClientSecretCredentialBuilder, ConfidentialClientApplication, PublicClientApplication,
+    ResourceOwnerPasswordCredential, TokenCredentialExecutor,
 };
 
 const AZURE_TENANT_ID: &str = "AZURE_TENANT_ID";
 const AZURE_CLIENT_ID: &str = "AZURE_CLIENT_ID";
 const AZURE_CLIENT_SECRET: &str = "AZURE_CLIENT_SECRET";
+const AZURE_CLIENT_CERTIFICATE_PATH: &str = "AZURE_CLIENT_CERTIFICATE_PATH";
 const AZURE_USERNAME: &str = "AZURE_USERNAME";
 const AZURE_PASSWORD: &str = "AZURE_PASSWORD";
+const GRAPH_CLOUD: &str = "GRAPH_CLOUD";
 
 #[derive(Clone)]
 pub struct EnvironmentCredential;
@@ -22,6 +32,87 @@ impl Debug for EnvironmentCredential {
 }
 
 impl EnvironmentCredential {
+    /// Create a confidential client from environment variables.
+    ///
+    /// Reads the following variables:
+    /// - `AZURE_CLIENT_ID`: Required.
+    /// - `AZURE_TENANT_ID`: Optional. The tenant used when not set is common.
+    /// - `AZURE_CLIENT_CERTIFICATE_PATH`: Path to a PEM file that has both the certificate and
+    ///   the private key. Requires the `openssl` feature. Used instead of the client secret when set.
+    /// - `AZURE_CLIENT_SECRET`: Required when no certificate path is set.
+    /// - `GRAPH_CLOUD`: Optional. One of `public` (default), `usgov`, `china`, or `germany`.
+    ///
+    /// The scope is set to the `.default` scope of Microsoft Graph in the given cloud.
+    pub fn confidential_client() -> IdentityResult<EnvironmentConfidentialClient> {
+        let azure_cloud_instance = EnvironmentCredential::azure_cloud_instance()?;
+        let tenant_id = std::env::var(AZURE_TENANT_ID).ok();
+        let client_id = EnvironmentCredential::required_var(AZURE_CLIENT_ID)?;
+        let scope = vec![format!("{}/.default", azure_cloud_instance.graph_host())];
+
+        if let Ok(certificate_path) = std::env::var(AZURE_CLIENT_CERTIFICATE_PATH) {
+            return EnvironmentCredential::client_certificate_env(
+                tenant_id,
+                client_id,
+                certificate_path,
+                azure_cloud_instance,
+                scope,
+            )
+            .map(EnvironmentConfidentialClient::ClientCertificate);
+        }
+
+        let client_secret = EnvironmentCredential::required_var(AZURE_CLIENT_SECRET)?;
+        let mut builder = ClientSecretCredentialBuilder::new(client_id, client_secret);
+        if let Some(tenant_id) = tenant_id {
+            builder.with_tenant(tenant_id);
+        }
+        builder
+            .with_azure_cloud_instance(azure_cloud_instance)
+            .with_scope(scope);
+        Ok(EnvironmentConfidentialClient::ClientSecret(
+            ConfidentialClientApplication::credential(builder.credential()),
+        ))
+    }
+
+    /// The cloud set in the `GRAPH_CLOUD` environment variable. Defaults to
+    /// the Azure public cloud when the variable is not set.
+    pub fn azure_cloud_instance() -> IdentityResult<AzureCloudInstance> {
+        match std::env::var(GRAPH_CLOUD) {
+            Ok(cloud) => match cloud.trim().to_lowercase().as_str() {
+                "" | "public" | "global" => Ok(AzureCloudInstance::AzurePublic),
+                "usgov" | "us_gov" | "usgovernment" => Ok(AzureCloudInstance::AzureUsGovernment),
+                "china" => Ok(AzureCloudInstance::AzureChina),
+                "germany" => Ok(AzureCloudInstance::AzureGermany),
+                _ => Err(AF::msg_err(
+                    GRAPH_CLOUD,
+                    "expected one of public, usgov, china, or germany",
+                )),
+            },
+            Err(_) => Ok(AzureCloudInstance::AzurePublic),
+        }
+    }
+
+    fn required_var(name: &str) -> IdentityResult<String> {
+        std::env::var(name).map_err(|err| AF::msg_err(name, err.to_string().as_str()))
+    }
+
+    fn client_certificate_env(
+        tenant_id: Option<String>,
+        client_id: String,
+        certificate_path: String,
+        azure_cloud_instance: AzureCloudInstance,
+        scope: Vec<String>,
+    ) -> IdentityResult<ConfidentialClientApplication<ClientCertificateCredential>> {
+        client_certificate_from_pem_file(
+            AZURE_CLIENT_CERTIFICATE_PATH,
+            &client_id,
+            tenant_id.as_deref(),
+            Path::new(&certificate_path),
+            None,
+            azure_cloud_instance,
+            scope,
+        )
+    }
+
     pub fn resource_owner_password_credential(
     ) -> Result<PublicClientApplication<ResourceOwnerPasswordCredential>, VarError> {
         match EnvironmentCredential::try_username_password_compile_time_env() {
@@ -128,3 +219,106 @@ impl EnvironmentCredential {
         }
     }
 }
+
+/// Confidential client created from environment variables using
+/// [EnvironmentCredential::confidential_client].
+#[derive(Clone, Debug)]
+pub enum EnvironmentConfidentialClient {
+    ClientSecret(ConfidentialClientApplication<ClientSecretCredential>),
+    ClientCertificate(ConfidentialClientApplication<ClientCertificateCredential>),
+}
+
+impl EnvironmentConfidentialClient {
+    pub fn azure_cloud_instance(&self) -> AzureCloudInstance {
+        match self {
+            EnvironmentConfidentialClient::ClientSecret(client) => client.azure_cloud_instance(),
+            EnvironmentConfidentialClient::ClientCertificate(client) => {
+                client.azure_cloud_instance()
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ClientApplication for EnvironmentConfidentialClient {
+    fn get_token_silent(&mut self) -> AuthExecutionResult<String> {
+        match self {
+            EnvironmentConfidentialClient::ClientSecret(client) => client.get_token_silent(),
+            EnvironmentConfidentialClient::ClientCertificate(client) => client.get_token_silent(),
+        }
+    }
+
+    async fn get_token_silent_async(&mut self) -> AuthExecutionResult<String> {
+        match self {
+            EnvironmentConfidentialClient::ClientSecret(client) => {
+                client.get_token_silent_async().await
+            }
+            EnvironmentConfidentialClient::ClientCertificate(client) => {
+                client.get_token_silent_async().await
+            }
+        }
+    }
+
+    fn with_force_token_refresh(&mut self, force_token_refresh: ForceTokenRefresh) {
+        match self {
+            EnvironmentConfidentialClient::ClientSecret(client) => {
+                ClientApplication::with_force_token_refresh(client, force_token_refresh)
+            }
+            EnvironmentConfidentialClient::ClientCertificate(client) => {
+                ClientApplication::with_force_token_refresh(client, force_token_refresh)
+            }
+        }
+    }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        match self {
+            EnvironmentConfidentialClient::ClientSecret(client) => {
+                ClientApplication::with_http_client(client, http_client)
+            }
+            EnvironmentConfidentialClient::ClientCertificate(client) => {
+                ClientApplication::with_http_client(client, http_client)
+            }
+        }
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        match self {
+            EnvironmentConfidentialClient::ClientSecret(client) => {
+                ClientApplication::with_blocking_http_client(client, http_client)
+            }
+            EnvironmentConfidentialClient::ClientCertificate(client) => {
+                ClientApplication::with_blocking_http_client(client, http_client)
+            }
+        }
+    }
+
+    fn for_tenant(&self, tenant_id: &str) -> Option<Box<dyn ClientApplication>> {
+        match self {
+            EnvironmentConfidentialClient::ClientSecret(client) => client.for_tenant(tenant_id),
+            EnvironmentConfidentialClient::ClientCertificate(client) => {
+                client.for_tenant(tenant_id)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn graph_cloud_env() {
+        std::env::set_var(GRAPH_CLOUD, "USGov");
+        assert_eq!(
+            AzureCloudInstance::AzureUsGovernment,
+            EnvironmentCredential::azure_cloud_instance().unwrap()
+        );
+        std::env::set_var(GRAPH_CLOUD, "mars");
+        assert!(EnvironmentCredential::azure_cloud_instance().is_err());
+        std::env::remove_var(GRAPH_CLOUD);
+        assert_eq!(
+            AzureCloudInstance::AzurePublic,
+            EnvironmentCredential::azure_cloud_instance().unwrap()
+        );
+    }
+}
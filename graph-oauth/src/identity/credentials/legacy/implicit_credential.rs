@@ -1,5 +1,5 @@
 use crate::identity::credentials::app_config::AppConfig;
-use crate::identity::{AzureCloudInstance, Prompt, ResponseMode, ResponseType};
+use crate::identity::{validate_prompt, AzureCloudInstance, Prompt, ResponseMode, ResponseType};
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 use graph_core::crypto::secure_random_32;
 use graph_error::{AuthorizationFailure, IdentityResult, AF};
@@ -164,6 +164,7 @@ impl ImplicitCredential {
             serializer.state(state.as_str());
         }
 
+        validate_prompt(&self.prompt, self.login_hint.as_ref())?;
         if let Some(prompt) = self.prompt.as_ref() {
             serializer.prompt(prompt.as_ref());
         }
@@ -6,22 +6,30 @@ pub use authorization_code_assertion_credential::*;
 pub use authorization_code_certificate_credential::*;
 pub use authorization_code_credential::*;
 pub use bearer_token_credential::*;
+#[cfg(feature = "openssl")]
+pub use certificate_provider::*;
 pub use client_assertion_credential::*;
 
 pub use client_certificate_credential::*;
 pub use client_credentials_authorization_url::*;
 pub use client_secret_credential::*;
 pub use confidential_client_application::*;
+pub use credential_config::*;
 pub use device_code_credential::*;
 pub use environment_credential::*;
 pub use open_id_authorization_url::*;
 pub use open_id_credential::*;
 pub use prompt::*;
 pub use public_client_application::*;
+pub use remote_signer::*;
 pub use resource_owner_password_credential::*;
 pub use response_mode::*;
 pub use response_type::*;
 pub use token_credential_executor::*;
+pub use token_refresher::*;
+pub(crate) use token_request_form::*;
+#[cfg(feature = "openssl")]
+use x509_certificate::client_assertion_claims;
 #[cfg(feature = "openssl")]
 pub use x509_certificate::*;
 
@@ -38,21 +46,30 @@ mod authorization_code_assertion_credential;
 mod authorization_code_certificate_credential;
 mod authorization_code_credential;
 mod bearer_token_credential;
+#[cfg(feature = "openssl")]
+mod certificate_provider;
+// Included by x509_certificate when the openssl feature is enabled.
+#[cfg(not(feature = "openssl"))]
+mod client_assertion_claims;
 mod client_assertion_credential;
 mod client_certificate_credential;
 mod client_credentials_authorization_url;
 mod client_secret_credential;
 mod confidential_client_application;
+mod credential_config;
 mod device_code_credential;
 mod environment_credential;
 mod open_id_authorization_url;
 mod open_id_credential;
 mod prompt;
 mod public_client_application;
+mod remote_signer;
 mod resource_owner_password_credential;
 mod response_mode;
 mod response_type;
 mod token_credential_executor;
+mod token_refresher;
+mod token_request_form;
 
 #[cfg(feature = "openssl")]
 mod x509_certificate;
@@ -11,8 +11,9 @@ use graph_error::{AuthorizationFailure, IdentityResult, AF};
 
 use crate::identity::credentials::app_config::AppConfig;
 use crate::identity::{
-    AsQuery, Authority, AuthorizationUrl, AzureCloudInstance, OpenIdCredentialBuilder, Prompt,
-    ResponseMode, ResponseType,
+    validate_prompt, AsQuery, Authority, AuthorizationResponse, AuthorizationUrl,
+    AuthorizationValidationError, AzureCloudInstance, OpenIdCredentialBuilder, Prompt,
+    ResponseMode, ResponseType, SilentAuthorizationResult,
 };
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 
@@ -20,7 +21,6 @@ use crate::identity::tracing_targets::CREDENTIAL_EXECUTOR;
 
 #[cfg(feature = "interactive-auth")]
 use {
-    crate::identity::AuthorizationResponse,
     crate::interactive::{
         HostOptions, InteractiveAuthEvent, UserEvents, WebViewAuth, WebViewAuthorizationEvent,
         WebViewHostValidator, WebViewOptions,
@@ -28,7 +28,7 @@ use {
     crate::Secret,
     graph_error::{WebViewError, WebViewResult},
     tao::{event_loop::EventLoopProxy, window::Window},
-    wry::{WebView, WebViewBuilder},
+    wry::{WebContext, WebView, WebViewBuilder},
 };
 
 const RESPONSE_TYPES_SUPPORTED: &[&str] = &["code", "id_token", "code id_token", "id_token token"];
@@ -150,7 +150,7 @@ impl OpenIdAuthorizationUrlParameters {
             response_type: BTreeSet::from([ResponseType::IdToken]),
             response_mode: None,
             nonce: secure_random_32(),
-            state: None,
+            state: Some(secure_random_32()),
             prompt: Default::default(),
             domain_hint: None,
             login_hint: None,
@@ -163,7 +163,7 @@ impl OpenIdAuthorizationUrlParameters {
             response_type: BTreeSet::from([ResponseType::IdToken]),
             response_mode: None,
             nonce: secure_random_32(),
-            state: None,
+            state: Some(secure_random_32()),
             prompt: Default::default(),
             domain_hint: None,
             login_hint: None,
@@ -196,6 +196,47 @@ impl OpenIdAuthorizationUrlParameters {
         &self.nonce
     }
 
+    /// Get the state sent in the authorization url.
+    pub fn state(&self) -> Option<&String> {
+        self.state.as_ref()
+    }
+
+    /// Parse and validate the query or fragment of the redirect uri returned from the
+    /// authorization request.
+    ///
+    /// Returns an error if the state is not the same as the state sent in the authorization
+    /// url, if the nonce claim of the id token is not the same as the nonce sent,
+    /// or if the authorization server returned an error.
+    pub fn validate_response(
+        &self,
+        query_or_fragment: &str,
+    ) -> Result<AuthorizationResponse, AuthorizationValidationError> {
+        AuthorizationResponse::from_query_or_fragment(query_or_fragment)?
+            .validate(self.state.as_deref(), Some(self.nonce.as_str()))
+    }
+
+    /// The authorization url with prompt=none for silent authorization, such as from a
+    /// hidden iframe, using the existing session of the user. Set a login hint to select
+    /// the account when more than one user is signed in.
+    pub fn silent_authorization_url(&self) -> IdentityResult<Url> {
+        let mut parameters = self.clone();
+        parameters.prompt = BTreeSet::from([Prompt::None]);
+        parameters.url()
+    }
+
+    /// Parse and validate the query or fragment of the redirect uri returned from the
+    /// [silent authorization url](OpenIdAuthorizationUrlParameters::silent_authorization_url).
+    ///
+    /// Returns [SilentAuthorizationResult::InteractionRequired] when the user has to sign in,
+    /// consent, or select an account, in which case the app falls back to interactive
+    /// authorization using [OpenIdAuthorizationUrlParameters::url].
+    pub fn try_silent_authorization(
+        &self,
+        query_or_fragment: &str,
+    ) -> Result<SilentAuthorizationResult, AuthorizationValidationError> {
+        SilentAuthorizationResult::from_validated(self.validate_response(query_or_fragment))
+    }
+
     #[cfg(feature = "interactive-auth")]
     pub fn interactive_webview_authentication(
         &self,
@@ -208,18 +249,20 @@ impl OpenIdAuthorizationUrlParameters {
                 "interactive auth does not support ResponseMode::FormPost at this time",
             ))?;
         }
-        let uri = self.url()?;
-        let redirect_uri = self.redirect_uri().cloned().unwrap();
+        let redirect_uri = self
+            .redirect_uri()
+            .ok_or_else(|| WebViewError::InvalidUri("redirect uri is required".into()))?;
+        let redirect_uri = web_view_options.select_redirect_uri(redirect_uri)?;
+        let mut parameters = self.clone();
+        parameters.app_config.redirect_uri = Some(redirect_uri.clone());
+
+        let uri = parameters.url()?;
+        let redirect_uris = web_view_options.allowed_redirect_uris(redirect_uri);
         let (sender, receiver) = std::sync::mpsc::channel();
 
         std::thread::spawn(move || {
-            OpenIdAuthorizationUrlParameters::run(
-                uri,
-                vec![redirect_uri],
-                web_view_options,
-                sender,
-            )
-            .unwrap();
+            OpenIdAuthorizationUrlParameters::run(uri, redirect_uris, web_view_options, sender)
+                .unwrap();
         });
         let mut iter = receiver.try_iter();
         let mut next = iter.next();
@@ -261,7 +304,7 @@ impl OpenIdAuthorizationUrlParameters {
                     tracing::debug!(target: "graph_rs_sdk::interactive_auth", "parsed authorization query or fragment from redirect uri");
 
                     let mut credential_builder = OpenIdCredentialBuilder::from((
-                        self.app_config.clone(),
+                        parameters.app_config.clone(),
                         authorization_response.clone(),
                     ));
 
@@ -354,6 +397,7 @@ impl AuthorizationUrl for OpenIdAuthorizationUrlParameters {
             serializer.state(state.as_str());
         }
 
+        validate_prompt(&self.prompt, self.login_hint.as_ref())?;
         if !self.prompt.is_empty() {
             serializer.prompt(&self.prompt.as_query());
         }
@@ -395,10 +439,12 @@ impl WebViewAuth for OpenIdAuthorizationUrlParameters {
         host_options: HostOptions,
         window: &Window,
         proxy: EventLoopProxy<UserEvents>,
+        web_context: &mut WebContext,
     ) -> anyhow::Result<WebView> {
         let start_uri = host_options.start_uri.clone();
         let validator = WebViewHostValidator::try_from(host_options)?;
         Ok(WebViewBuilder::new(window)
+            .with_web_context(web_context)
             .with_url(start_uri.as_ref())
             // Disables file drop
             .with_file_drop_handler(|_| true)
@@ -518,6 +564,13 @@ impl OpenIdAuthorizationUrlParameterBuilder {
         self
     }
 
+    /// Generates a secure random state. A state is generated when the builder
+    /// is created, use this to replace it.
+    pub fn with_generated_state(&mut self) -> &mut Self {
+        self.credential.state = Some(secure_random_32());
+        self
+    }
+
     /// Takes an iterator of scopes to use in the request.
     /// Replaces current scopes if any were added previously.
     pub fn with_scope<T: ToString, I: IntoIterator<Item = T>>(&mut self, scope: I) -> &mut Self {
@@ -18,12 +18,12 @@ use graph_core::{
 use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
 
 use crate::identity::credentials::app_config::{AppConfig, AppConfigBuilder};
+use crate::identity::credentials::{Grant, TokenRequestForm};
 use crate::identity::{
     Authority, AuthorizationResponse, AzureCloudInstance, ConfidentialClientApplication, IdToken,
-    OpenIdAuthorizationUrlParameterBuilder, OpenIdAuthorizationUrlParameters, Token,
+    OpenIdAuthorizationUrlParameterBuilder, OpenIdAuthorizationUrlParameters, SecretString, Token,
     TokenCredentialExecutor,
 };
-use crate::internal::{AuthParameter, AuthSerializer};
 
 credential_builder!(
     OpenIdCredentialBuilder,
@@ -45,7 +45,7 @@ pub struct OpenIdCredential {
     /// Required when requesting a new access token using a refresh token
     /// The refresh token needed to make an access token request using a refresh token.
     /// Do not include an authorization code when using a refresh token.
-    pub(crate) refresh_token: Option<String>,
+    pub(crate) refresh_token: Option<SecretString>,
     /// Required
     /// The application secret that you created in the app registration portal for your app.
     /// Don't use the application secret in a native app or single page app because a
@@ -55,7 +55,7 @@ pub struct OpenIdCredential {
     /// is done by the SDK. For more information on URI encoding, see the URI Generic Syntax
     /// specification. The Basic auth pattern of instead providing credentials in the Authorization
     /// header, per RFC 6749 is also supported.
-    pub(crate) client_secret: String,
+    pub(crate) client_secret: SecretString,
     /// The same code_verifier that was used to obtain the authorization_code.
     /// Required if PKCE was used in the authorization code grant request. For more information,
     /// see the PKCE RFC https://datatracker.ietf.org/doc/html/rfc7636.
@@ -63,7 +63,6 @@ pub struct OpenIdCredential {
     /// Used only when the client generates the pkce itself when the generate method
     /// is called.
     pub(crate) pkce: Option<ProofKeyCodeExchange>,
-    serializer: AuthSerializer,
     token_cache: InMemoryCacheStore<Token>,
 }
 
@@ -90,17 +89,16 @@ impl OpenIdCredential {
                 .build(),
             authorization_code: Some(authorization_code.as_ref().to_owned()),
             refresh_token: None,
-            client_secret: client_secret.as_ref().to_owned(),
+            client_secret: SecretString::new(client_secret.as_ref()),
             code_verifier: None,
             pkce: None,
-            serializer: Default::default(),
             token_cache: Default::default(),
         })
     }
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) {
         self.authorization_code = None;
-        self.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
     }
 
     pub fn builder(client_id: impl TryInto<Uuid>) -> OpenIdCredentialBuilder {
@@ -130,9 +128,10 @@ impl OpenIdCredential {
 
         let new_token: Token = response.json()?;
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
 
         if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
+            self.refresh_token = new_token.refresh_token.clone().map(SecretString::new);
         }
 
         Ok(new_token)
@@ -153,10 +152,11 @@ impl OpenIdCredential {
         let new_token: Token = response.json().await?;
 
         if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
+            self.refresh_token = new_token.refresh_token.clone().map(SecretString::new);
         }
 
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
         Ok(new_token)
     }
 }
@@ -169,7 +169,7 @@ impl TokenCache for OpenIdCredential {
         let cache_id = self.app_config.cache_id.to_string();
 
         match self.app_config.force_token_refresh {
-            ForceTokenRefresh::Never => {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
                 // Attempt to bypass a read on the token store by using previous
                 // refresh token stored outside of RwLock
                 if self.refresh_token.is_some() {
@@ -179,12 +179,13 @@ impl TokenCache for OpenIdCredential {
                 }
 
                 if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-                    if token.is_expired_sub(time::Duration::minutes(5)) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
                         if let Some(refresh_token) = token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(SecretString::new(refresh_token));
                         }
 
-                        self.execute_cached_token_refresh(cache_id)
+                        let token_result = self.execute_cached_token_refresh(cache_id);
+                        self.app_config.ext_expires_fallback(token, token_result)
                     } else {
                         Ok(token)
                     }
@@ -192,6 +193,7 @@ impl TokenCache for OpenIdCredential {
                     self.execute_cached_token_refresh(cache_id)
                 }
             }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 let token_result = self.execute_cached_token_refresh(cache_id);
                 if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
@@ -206,7 +208,7 @@ impl TokenCache for OpenIdCredential {
         let cache_id = self.app_config.cache_id.to_string();
 
         match self.app_config.force_token_refresh {
-            ForceTokenRefresh::Never => {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
                 // Attempt to bypass a read on the token store by using previous
                 // refresh token stored outside of RwLock
                 if self.refresh_token.is_some() {
@@ -219,12 +221,14 @@ impl TokenCache for OpenIdCredential {
                 }
 
                 if let Some(old_token) = self.token_cache.get(cache_id.as_str()) {
-                    if old_token.is_expired_sub(time::Duration::minutes(5)) {
+                    if old_token.is_expired_sub(self.app_config.token_refresh_margin()) {
                         if let Some(refresh_token) = old_token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(SecretString::new(refresh_token));
                         }
 
-                        self.execute_cached_token_refresh_async(cache_id).await
+                        let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                        self.app_config
+                            .ext_expires_fallback(old_token, token_result)
                     } else {
                         Ok(old_token.clone())
                     }
@@ -232,6 +236,7 @@ impl TokenCache for OpenIdCredential {
                     self.execute_cached_token_refresh_async(cache_id).await
                 }
             }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 let token_result = self.execute_cached_token_refresh_async(cache_id).await;
                 if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
@@ -252,79 +257,37 @@ impl TokenCredentialExecutor for OpenIdCredential {
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
         let client_id = self.app_config.client_id.to_string();
         if client_id.is_empty() || self.app_config.client_id.is_nil() {
-            return AF::result(AuthParameter::ClientId.alias());
+            return AF::result("client_id");
         }
 
-        if self.client_secret.trim().is_empty() {
-            return AF::result(AuthParameter::ClientSecret.alias());
-        }
-
-        self.serializer
-            .client_id(client_id.as_str())
-            .client_secret(self.client_secret.as_str())
-            .set_scope(self.app_config.scope.clone());
-
-        if let Some(refresh_token) = self.refresh_token.as_ref() {
-            if refresh_token.trim().is_empty() {
-                return AF::msg_result(AuthParameter::RefreshToken, "Refresh token is empty");
-            }
-
-            self.serializer
-                .grant_type("refresh_token")
-                .refresh_token(refresh_token.as_ref());
-
-            return self.serializer.as_credential_map(
-                vec![AuthParameter::Scope],
-                vec![
-                    AuthParameter::ClientId,
-                    AuthParameter::ClientSecret,
-                    AuthParameter::RefreshToken,
-                    AuthParameter::GrantType,
-                ],
-            );
+        let grant = if let Some(refresh_token) = self.refresh_token.as_ref() {
+            Grant::refresh_token(refresh_token.expose_secret())?
         } else if let Some(authorization_code) = self.authorization_code.as_ref() {
-            if authorization_code.trim().is_empty() {
-                return AF::msg_result(
-                    AuthParameter::AuthorizationCode.alias(),
-                    "Authorization code is empty",
-                );
-            }
-
-            if let Some(redirect_uri) = self.app_config.redirect_uri.as_ref() {
-                self.serializer.redirect_uri(redirect_uri.as_str());
-            }
-
-            self.serializer
-                .authorization_code(authorization_code.as_ref())
-                .grant_type("authorization_code");
+            let redirect_uri = self
+                .app_config
+                .redirect_uri
+                .as_ref()
+                .ok_or_else(|| AF::required("redirect_uri"))?;
+            let grant = Grant::authorization_code(
+                authorization_code,
+                Some(redirect_uri.to_string()),
+                self.code_verifier.clone(),
+            )?;
 
             // Authorization codes can only be used once. Remove it from the configuration.
             self.authorization_code = None;
-
-            if let Some(code_verifier) = self.code_verifier.as_ref() {
-                self.serializer.code_verifier(code_verifier.as_str());
-            }
-
-            return self.serializer.as_credential_map(
-                vec![AuthParameter::Scope, AuthParameter::CodeVerifier],
-                vec![
-                    AuthParameter::ClientId,
-                    AuthParameter::ClientSecret,
-                    AuthParameter::RedirectUri,
-                    AuthParameter::AuthorizationCode,
-                    AuthParameter::GrantType,
-                ],
+            grant
+        } else {
+            return AF::msg_result(
+                "code or refresh_token",
+                "Either authorization code or refresh token is required",
             );
-        }
+        };
 
-        AF::msg_result(
-            format!(
-                "{} or {}",
-                AuthParameter::AuthorizationCode.alias(),
-                AuthParameter::RefreshToken.alias()
-            ),
-            "Either authorization code or refresh token is required",
-        )
+        TokenRequestForm::new(client_id, grant)
+            .client_secret(self.client_secret.expose_secret())?
+            .scope(&self.app_config.scope)
+            .into_form()
     }
 
     fn client_id(&self) -> &Uuid {
@@ -342,13 +305,25 @@ impl TokenCredentialExecutor for OpenIdCredential {
     fn basic_auth(&self) -> Option<(String, String)> {
         Some((
             self.app_config.client_id.to_string(),
-            self.client_secret.clone(),
+            self.client_secret.expose_secret().to_owned(),
         ))
     }
 
     fn app_config(&self) -> &AppConfig {
         &self.app_config
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.app_config.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.app_config.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.app_config.with_tenant(tenant_id);
+    }
 }
 
 #[derive(Clone)]
@@ -374,10 +349,9 @@ impl OpenIdCredentialBuilder {
                     .build(),
                 authorization_code: None,
                 refresh_token: None,
-                client_secret: String::new(),
+                client_secret: SecretString::default(),
                 code_verifier: None,
                 pkce: None,
-                serializer: Default::default(),
                 token_cache: Default::default(),
             },
         }
@@ -390,10 +364,9 @@ impl OpenIdCredentialBuilder {
                 app_config,
                 authorization_code: None,
                 refresh_token: None,
-                client_secret: String::new(),
+                client_secret: SecretString::default(),
                 code_verifier: None,
                 pkce: None,
-                serializer: Default::default(),
                 token_cache: Default::default(),
             },
         }
@@ -412,7 +385,6 @@ impl OpenIdCredentialBuilder {
                 client_secret: Default::default(),
                 code_verifier: None,
                 pkce: None,
-                serializer: Default::default(),
                 token_cache: Default::default(),
             },
         }
@@ -429,10 +401,9 @@ impl OpenIdCredentialBuilder {
                 app_config,
                 authorization_code: Some(authorization_code.as_ref().to_owned()),
                 refresh_token: None,
-                client_secret: client_secret.as_ref().to_owned(),
+                client_secret: SecretString::new(client_secret.as_ref()),
                 code_verifier: None,
                 pkce: None,
-                serializer: Default::default(),
                 token_cache: Default::default(),
             },
         }
@@ -451,7 +422,6 @@ impl OpenIdCredentialBuilder {
                 client_secret: Default::default(),
                 code_verifier: None,
                 pkce: None,
-                serializer: Default::default(),
                 token_cache,
             },
         }
@@ -465,7 +435,7 @@ impl OpenIdCredentialBuilder {
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
         self.credential.authorization_code = None;
-        self.credential.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.credential.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
         self
     }
 
@@ -475,7 +445,7 @@ impl OpenIdCredentialBuilder {
     }
 
     pub fn with_client_secret<T: AsRef<str>>(&mut self, client_secret: T) -> &mut Self {
-        self.credential.client_secret = client_secret.as_ref().to_owned();
+        self.credential.client_secret = SecretString::new(client_secret.as_ref());
         self
     }
 
@@ -1,9 +1,11 @@
 use std::collections::BTreeSet;
 
+use graph_error::{IdentityResult, AF};
+
 use crate::identity::credentials::as_query::AsQuery;
 
 /// Indicates the type of user interaction that is required. Valid values are login, none,
-/// consent, and select_account.
+/// consent, select_account, and admin_consent.
 ///
 /// - **prompt=login** forces the user to enter their credentials on that request, negating single-sign on.
 /// - **prompt=none** is the opposite. It ensures that the user isn't presented with any interactive prompt.
@@ -26,6 +28,9 @@ pub enum Prompt {
     /// Use only for federated users. Provides same functionality as prompt=none
     /// for managed users.
     AttemptNone,
+    /// An administrator will be prompted to grant consent on behalf of all users
+    /// in the organization.
+    AdminConsent,
 }
 
 impl AsRef<str> for Prompt {
@@ -36,6 +41,7 @@ impl AsRef<str> for Prompt {
             Prompt::Consent => "consent",
             Prompt::SelectAccount => "select_account",
             Prompt::AttemptNone => "attempt_none",
+            Prompt::AdminConsent => "admin_consent",
         }
     }
 }
@@ -66,3 +72,53 @@ impl AsQuery for BTreeSet<Prompt> {
             .join(" ")
     }
 }
+
+/// Check the prompt and login hint of an authorization url for combinations that
+/// cannot be used together. [Prompt::None] cannot be combined with a prompt that
+/// shows an interactive page, and [Prompt::SelectAccount] cannot be used with a login hint.
+pub(crate) fn validate_prompt<'a>(
+    prompt: impl IntoIterator<Item = &'a Prompt>,
+    login_hint: Option<&String>,
+) -> IdentityResult<()> {
+    let prompt: BTreeSet<&Prompt> = prompt.into_iter().collect();
+    if prompt.contains(&Prompt::None) {
+        if let Some(other) = prompt.iter().find(|prompt| !matches!(prompt, Prompt::None)) {
+            return Err(AF::mutually_exclusive(
+                "prompt=none",
+                format!("prompt={}", other.as_ref()),
+            ));
+        }
+    }
+
+    if prompt.contains(&Prompt::SelectAccount) && login_hint.is_some() {
+        return Err(AF::mutually_exclusive(
+            "prompt=select_account",
+            "login_hint",
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use graph_error::AuthorizationFailure;
+
+    #[test]
+    fn mutually_exclusive_prompt() {
+        let login_hint = "user@contoso.com".to_owned();
+        assert!(validate_prompt(&[Prompt::Login, Prompt::Consent], Some(&login_hint)).is_ok());
+        assert!(validate_prompt(&[Prompt::None], Some(&login_hint)).is_ok());
+
+        let error = validate_prompt(&[Prompt::None, Prompt::Login], None).unwrap_err();
+        assert!(matches!(
+            error,
+            AuthorizationFailure::MutuallyExclusive { ref other, .. } if other == "prompt=login"
+        ));
+        assert!(matches!(
+            validate_prompt(&[Prompt::SelectAccount], Some(&login_hint)),
+            Err(AuthorizationFailure::MutuallyExclusive { .. })
+        ));
+    }
+}
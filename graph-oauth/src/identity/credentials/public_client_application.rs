@@ -44,8 +44,8 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCredentialExecutor>
 }
 
 #[async_trait]
-impl<Credential: Clone + Debug + Send + Sync + TokenCache> ClientApplication
-    for PublicClientApplication<Credential>
+impl<Credential: Clone + Debug + Send + Sync + TokenCache + TokenCredentialExecutor + 'static>
+    ClientApplication for PublicClientApplication<Credential>
 {
     fn get_token_silent(&mut self) -> AuthExecutionResult<String> {
         let token = self.credential.get_token_silent()?;
@@ -61,6 +61,31 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCache> ClientApplication
         self.credential
             .with_force_token_refresh(force_token_refresh);
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        TokenCredentialExecutor::with_http_client(&mut self.credential, http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        TokenCredentialExecutor::with_blocking_http_client(&mut self.credential, http_client);
+    }
+
+    fn has_http_client(&self) -> bool {
+        self.credential.app_config().http_client().is_some()
+    }
+
+    fn has_blocking_http_client(&self) -> bool {
+        self.credential
+            .app_config()
+            .blocking_http_client()
+            .is_some()
+    }
+
+    fn for_tenant(&self, tenant_id: &str) -> Option<Box<dyn ClientApplication>> {
+        let mut client_application = self.clone();
+        TokenCredentialExecutor::with_tenant(&mut client_application.credential, tenant_id);
+        Some(Box::new(client_application))
+    }
 }
 
 #[async_trait]
@@ -91,6 +116,18 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCredentialExecutor> TokenCre
         self.credential.app_config()
     }
 
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.credential.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.credential.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.credential.with_tenant(tenant_id);
+    }
+
     fn execute(&mut self) -> AuthExecutionResult<reqwest::blocking::Response> {
         self.credential.execute()
     }
@@ -0,0 +1,442 @@
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
+use graph_core::identity::ClientApplication;
+use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
+use reqwest::tls::Version;
+use url::Url;
+use uuid::Uuid;
+
+use crate::identity::credentials::client_assertion_claims::{
+    assertion_claims, assertion_header, is_valid, unsigned_token, ASSERTION_LIFETIME, CLOCK_SKEW,
+};
+use crate::identity::{tracing_targets::CREDENTIAL_EXECUTOR, SecretString};
+
+/// The api version of the Azure Key Vault keys api.
+const KEY_VAULT_API_VERSION: &str = "7.4";
+
+/// Signs the client assertion of the client certificate credentials using a service
+/// that holds the private key, such as Azure Key Vault or a hardware security module,
+/// so that the private key is never in the memory of the application.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::{ClientCertificateCredential, KeyVaultSigner};
+///
+/// let signer = KeyVaultSigner::new(
+///     "https://my-vault.vault.azure.net",
+///     "my-certificate",
+///     "6A1D3F5C2B4E7F8091A2B3C4D5E6F708192A3B4C",
+///     key_vault_client,
+/// )?;
+///
+/// let confidential_client = ClientCertificateCredential::builder("client_id")
+///     .with_tenant("tenant_id")
+///     .with_remote_signer(signer)
+///     .build();
+/// ```
+#[async_trait]
+pub trait RemoteSigner: Send + Sync {
+    /// Hex encoded SHA-1 thumbprint of the certificate registered for the application,
+    /// which is sent in the x5t header of the client assertion.
+    fn thumbprint(&self) -> String;
+
+    /// Sign the message using RS256 (RSASSA-PKCS1-v1_5 using SHA-256) and
+    /// return the signature.
+    #[allow(clippy::result_large_err)]
+    fn sign(&self, message: &[u8]) -> AuthExecutionResult<Vec<u8>>;
+
+    async fn sign_async(&self, message: &[u8]) -> AuthExecutionResult<Vec<u8>>;
+
+    /// How long a signed client assertion is valid. The default is 10 minutes.
+    fn assertion_lifetime(&self) -> Duration {
+        ASSERTION_LIFETIME
+    }
+
+    /// How long before a client assertion expires a new one is signed. The default
+    /// is 1 minute.
+    fn clock_skew(&self) -> Duration {
+        CLOCK_SKEW
+    }
+}
+
+/// A client assertion signed by a [RemoteSigner] and the unix timestamp of its exp claim.
+struct SignedAssertion {
+    tenant_id: Option<String>,
+    assertion: SecretString,
+    expires_on: i64,
+}
+
+/// Creates client assertions signed by a [RemoteSigner] and reuses each client assertion
+/// until it is within the clock skew of expiring, so that the remote service is not called
+/// for every token request. Clones share the signed client assertion.
+#[derive(Clone)]
+pub(crate) struct RemoteAssertionSigner {
+    signer: Arc<dyn RemoteSigner>,
+    signed_assertion: Arc<Mutex<Option<SignedAssertion>>>,
+}
+
+impl RemoteAssertionSigner {
+    pub(crate) fn new(signer: Arc<dyn RemoteSigner>) -> RemoteAssertionSigner {
+        RemoteAssertionSigner {
+            signer,
+            signed_assertion: Default::default(),
+        }
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn sign(
+        &self,
+        client_id: &str,
+        tenant_id: Option<String>,
+    ) -> AuthExecutionResult<SecretString> {
+        if let Some(assertion) = self.signed_assertion(&tenant_id) {
+            return Ok(assertion);
+        }
+
+        let (token, expires_on) = self.unsigned_token(client_id, &tenant_id)?;
+        let signature = self.signer.sign(token.as_bytes())?;
+        Ok(self.store(token, signature, tenant_id, expires_on))
+    }
+
+    pub(crate) async fn sign_async(
+        &self,
+        client_id: &str,
+        tenant_id: Option<String>,
+    ) -> AuthExecutionResult<SecretString> {
+        if let Some(assertion) = self.signed_assertion(&tenant_id) {
+            return Ok(assertion);
+        }
+
+        let (token, expires_on) = self.unsigned_token(client_id, &tenant_id)?;
+        let signature = self.signer.sign_async(token.as_bytes()).await?;
+        Ok(self.store(token, signature, tenant_id, expires_on))
+    }
+
+    fn signed_assertion(&self, tenant_id: &Option<String>) -> Option<SecretString> {
+        let signed_assertion = self.signed_assertion.lock().ok()?;
+        let signed_assertion = signed_assertion.as_ref()?;
+        if signed_assertion.tenant_id.eq(tenant_id)
+            && is_valid(signed_assertion.expires_on, self.signer.clock_skew())
+        {
+            Some(signed_assertion.assertion.clone())
+        } else {
+            None
+        }
+    }
+
+    /// The JWT header and payload in the format header.payload and the exp claim.
+    fn unsigned_token(
+        &self,
+        client_id: &str,
+        tenant_id: &Option<String>,
+    ) -> IdentityResult<(String, i64)> {
+        let thumbprint = hex::decode(self.signer.thumbprint().trim()).map_err(|err| {
+            AF::msg_err(
+                "thumbprint",
+                format!("Invalid hex encoded thumbprint: {err}").as_str(),
+            )
+        })?;
+
+        let header = assertion_header(URL_SAFE_NO_PAD.encode(thumbprint));
+        let claims = assertion_claims(
+            client_id,
+            tenant_id.as_ref(),
+            Uuid::new_v4().to_string(),
+            self.signer.assertion_lifetime(),
+        );
+        let exp = claims
+            .get("exp")
+            .and_then(|exp| exp.parse::<i64>().ok())
+            .unwrap_or_default();
+        Ok((unsigned_token(&header, &claims)?, exp))
+    }
+
+    fn store(
+        &self,
+        token: String,
+        signature: Vec<u8>,
+        tenant_id: Option<String>,
+        expires_on: i64,
+    ) -> SecretString {
+        let assertion = SecretString::new(format!("{token}.{}", URL_SAFE_NO_PAD.encode(signature)));
+        if let Ok(mut signed_assertion) = self.signed_assertion.lock() {
+            *signed_assertion = Some(SignedAssertion {
+                tenant_id,
+                assertion: assertion.clone(),
+                expires_on,
+            });
+        }
+        assertion
+    }
+}
+
+#[derive(Serialize)]
+struct KeyVaultSignRequest {
+    alg: &'static str,
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct KeyVaultSignResponse {
+    value: String,
+}
+
+/// Signs client assertions using the sign operation of an Azure Key Vault key, such as
+/// the key of a certificate stored in Key Vault. The private key does not leave Key Vault.
+///
+/// The access token for Key Vault is requested using the given client application, which
+/// must request the `https://vault.azure.net/.default` scope and have the `Sign` key
+/// permission. The thumbprint is the hex encoded thumbprint of the certificate, which is
+/// shown for the certificate in Key Vault and for the application in Azure Active Directory.
+///
+/// The signer creates one http client for signing, or uses the client given with
+/// [KeyVaultSigner::with_http_client].
+pub struct KeyVaultSigner {
+    sign_url: Url,
+    thumbprint: String,
+    client_application: Box<dyn ClientApplication>,
+    assertion_lifetime: Duration,
+    clock_skew: Duration,
+    http_client: OnceLock<reqwest::Client>,
+    blocking_http_client: OnceLock<reqwest::blocking::Client>,
+}
+
+impl KeyVaultSigner {
+    /// Sign using the latest version of the key. The key of a Key Vault certificate
+    /// has the same name as the certificate.
+    pub fn new(
+        vault_url: impl AsRef<str>,
+        key_name: impl AsRef<str>,
+        thumbprint: impl AsRef<str>,
+        client_application: impl ClientApplication + 'static,
+    ) -> IdentityResult<KeyVaultSigner> {
+        KeyVaultSigner::new_with_version(vault_url, key_name, "", thumbprint, client_application)
+    }
+
+    /// Sign using the given version of the key.
+    pub fn new_with_version(
+        vault_url: impl AsRef<str>,
+        key_name: impl AsRef<str>,
+        key_version: impl AsRef<str>,
+        thumbprint: impl AsRef<str>,
+        client_application: impl ClientApplication + 'static,
+    ) -> IdentityResult<KeyVaultSigner> {
+        let sign_url = Url::parse(vault_url.as_ref())?
+            .join(format!("keys/{}/{}/sign", key_name.as_ref(), key_version.as_ref()).as_str())?;
+
+        Ok(KeyVaultSigner {
+            sign_url,
+            thumbprint: thumbprint.as_ref().to_owned(),
+            client_application: Box::new(client_application),
+            assertion_lifetime: ASSERTION_LIFETIME,
+            clock_skew: CLOCK_SKEW,
+            http_client: OnceLock::new(),
+            blocking_http_client: OnceLock::new(),
+        })
+    }
+
+    /// Use the given http client, such as the client of the application, to sign
+    /// client assertions asynchronously.
+    pub fn with_http_client(self, http_client: reqwest::Client) -> Self {
+        let _ = self.http_client.set(http_client);
+        self
+    }
+
+    /// How long a signed client assertion is valid. The default is 10 minutes.
+    pub fn with_assertion_lifetime(mut self, assertion_lifetime: Duration) -> Self {
+        self.assertion_lifetime = assertion_lifetime;
+        self
+    }
+
+    /// How long before a client assertion expires a new one is signed. The default
+    /// is 1 minute.
+    pub fn with_clock_skew(mut self, clock_skew: Duration) -> Self {
+        self.clock_skew = clock_skew;
+        self
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn http_client(&self) -> AuthExecutionResult<&reqwest::Client> {
+        if let Some(http_client) = self.http_client.get() {
+            return Ok(http_client);
+        }
+        let http_client = reqwest::ClientBuilder::new()
+            .min_tls_version(Version::TLS_1_2)
+            .https_only(true)
+            .build()?;
+        Ok(self.http_client.get_or_init(|| http_client))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn blocking_http_client(&self) -> AuthExecutionResult<&reqwest::blocking::Client> {
+        if let Some(http_client) = self.blocking_http_client.get() {
+            return Ok(http_client);
+        }
+        let http_client = reqwest::blocking::ClientBuilder::new()
+            .min_tls_version(Version::TLS_1_2)
+            .https_only(true)
+            .build()?;
+        Ok(self.blocking_http_client.get_or_init(|| http_client))
+    }
+
+    fn sign_request(message: &[u8]) -> KeyVaultSignRequest {
+        let digest = ring::digest::digest(&ring::digest::SHA256, message);
+        KeyVaultSignRequest {
+            alg: "RS256",
+            value: URL_SAFE_NO_PAD.encode(digest.as_ref()),
+        }
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn signature(response: KeyVaultSignResponse) -> AuthExecutionResult<Vec<u8>> {
+        Ok(URL_SAFE_NO_PAD
+            .decode(response.value.as_str())
+            .map_err(|err| AF::msg_err("signature", err.to_string().as_str()))?)
+    }
+}
+
+#[async_trait]
+impl RemoteSigner for KeyVaultSigner {
+    fn thumbprint(&self) -> String {
+        self.thumbprint.clone()
+    }
+
+    fn sign(&self, message: &[u8]) -> AuthExecutionResult<Vec<u8>> {
+        tracing::debug!(target: CREDENTIAL_EXECUTOR, "signing client assertion with key vault");
+        let access_token = self.client_application.clone().get_token_silent()?;
+        let response = self
+            .blocking_http_client()?
+            .post(self.sign_url.clone())
+            .query(&[("api-version", KEY_VAULT_API_VERSION)])
+            .bearer_auth(access_token)
+            .json(&KeyVaultSigner::sign_request(message))
+            .send()?;
+
+        if !response.status().is_success() {
+            return Err(AuthExecutionError::error_response(
+                "key vault sign request failed",
+                response.into_http_response()?,
+            ));
+        }
+        KeyVaultSigner::signature(response.json()?)
+    }
+
+    async fn sign_async(&self, message: &[u8]) -> AuthExecutionResult<Vec<u8>> {
+        tracing::debug!(target: CREDENTIAL_EXECUTOR, "signing client assertion with key vault");
+        let access_token = self
+            .client_application
+            .clone()
+            .get_token_silent_async()
+            .await?;
+        let response = self
+            .http_client()?
+            .post(self.sign_url.clone())
+            .query(&[("api-version", KEY_VAULT_API_VERSION)])
+            .bearer_auth(access_token)
+            .json(&KeyVaultSigner::sign_request(message))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(AuthExecutionError::error_response(
+                "key vault sign request failed",
+                response.into_http_response_async().await?,
+            ));
+        }
+        KeyVaultSigner::signature(response.json().await?)
+    }
+
+    fn assertion_lifetime(&self) -> Duration {
+        self.assertion_lifetime
+    }
+
+    fn clock_skew(&self) -> Duration {
+        self.clock_skew
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct TestSigner {
+        signed: AtomicUsize,
+        assertion_lifetime: Option<Duration>,
+    }
+
+    #[async_trait]
+    impl RemoteSigner for TestSigner {
+        fn thumbprint(&self) -> String {
+            "0A0B".to_owned()
+        }
+
+        fn sign(&self, _message: &[u8]) -> AuthExecutionResult<Vec<u8>> {
+            self.signed.fetch_add(1, Ordering::Relaxed);
+            Ok(vec![1, 2, 3])
+        }
+
+        async fn sign_async(&self, message: &[u8]) -> AuthExecutionResult<Vec<u8>> {
+            self.sign(message)
+        }
+
+        fn assertion_lifetime(&self) -> Duration {
+            self.assertion_lifetime.unwrap_or(ASSERTION_LIFETIME)
+        }
+    }
+
+    #[test]
+    fn reuse_remote_signed_assertion() {
+        let signer = Arc::new(TestSigner::default());
+        let assertion_signer = RemoteAssertionSigner::new(signer.clone());
+
+        let assertion = assertion_signer.sign("client_id", None).unwrap();
+        let parts: Vec<&str> = assertion.expose_secret().split('.').collect();
+        assert_eq!(3, parts.len());
+        assert_eq!(URL_SAFE_NO_PAD.encode([1, 2, 3]), parts[2]);
+        let header: serde_json::Value =
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(parts[0]).unwrap()).unwrap();
+        assert_eq!(URL_SAFE_NO_PAD.encode([0x0A, 0x0B]), header["x5t"]);
+
+        assert_eq!(
+            assertion,
+            assertion_signer.clone().sign("client_id", None).unwrap()
+        );
+        assert_eq!(1, signer.signed.load(Ordering::Relaxed));
+
+        assertion_signer
+            .sign("client_id", Some("tenant_id".to_owned()))
+            .unwrap();
+        assert_eq!(2, signer.signed.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn sign_again_within_clock_skew() {
+        let signer = Arc::new(TestSigner {
+            assertion_lifetime: Some(Duration::from_secs(30)),
+            ..Default::default()
+        });
+        let assertion_signer = RemoteAssertionSigner::new(signer.clone());
+
+        let assertion = assertion_signer.sign("client_id", None).unwrap();
+        let claims: serde_json::Value = serde_json::from_slice(
+            &URL_SAFE_NO_PAD
+                .decode(assertion.expose_secret().split('.').nth(1).unwrap())
+                .unwrap(),
+        )
+        .unwrap();
+        let nbf: i64 = claims["nbf"].as_str().unwrap().parse().unwrap();
+        let exp: i64 = claims["exp"].as_str().unwrap().parse().unwrap();
+        assert_eq!(30, exp - nbf);
+
+        // The assertion lifetime is shorter than the default clock skew.
+        assertion_signer.sign("client_id", None).unwrap();
+        assert_eq!(2, signer.signed.load(Ordering::Relaxed));
+    }
+}
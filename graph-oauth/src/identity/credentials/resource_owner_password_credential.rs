@@ -1,9 +1,9 @@
 use crate::identity::credentials::app_config::AppConfig;
+use crate::identity::credentials::{Grant, TokenRequestForm};
 use crate::identity::{
-    tracing_targets::CREDENTIAL_EXECUTOR, Authority, AzureCloudInstance, Token,
+    tracing_targets::CREDENTIAL_EXECUTOR, Authority, AzureCloudInstance, SecretString, Token,
     TokenCredentialExecutor,
 };
-use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 use async_trait::async_trait;
 use graph_core::cache::{CacheStore, InMemoryCacheStore, TokenCache};
 use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
@@ -30,7 +30,7 @@ pub struct ResourceOwnerPasswordCredential {
     pub(crate) username: String,
     /// Required
     /// The user's password.
-    pub(crate) password: String,
+    pub(crate) password: SecretString,
     token_cache: InMemoryCacheStore<Token>,
 }
 
@@ -53,7 +53,7 @@ impl ResourceOwnerPasswordCredential {
                 .authority(Authority::Organizations)
                 .build(),
             username: username.as_ref().to_owned(),
-            password: password.as_ref().to_owned(),
+            password: SecretString::new(password.as_ref()),
             token_cache: Default::default(),
         }
     }
@@ -69,7 +69,7 @@ impl ResourceOwnerPasswordCredential {
                 .tenant(tenant_id.as_ref())
                 .build(),
             username: username.as_ref().to_owned(),
-            password: password.as_ref().to_owned(),
+            password: SecretString::new(password.as_ref()),
             token_cache: Default::default(),
         }
     }
@@ -89,6 +89,7 @@ impl ResourceOwnerPasswordCredential {
 
         let new_token: Token = response.json()?;
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
         Ok(new_token)
     }
 
@@ -106,6 +107,7 @@ impl ResourceOwnerPasswordCredential {
 
         let new_token: Token = response.json().await?;
         self.token_cache.store(cache_id, new_token.clone());
+        self.app_config.cache_account_token(&new_token);
         Ok(new_token)
     }
 }
@@ -116,33 +118,61 @@ impl TokenCache for ResourceOwnerPasswordCredential {
 
     fn get_token_silent(&mut self) -> Result<Self::Token, AuthExecutionError> {
         let cache_id = self.app_config.cache_id.to_string();
-        if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-            if token.is_expired_sub(time::Duration::minutes(5)) {
+        match self.app_config.force_token_refresh {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
+                if let Some(token) = self.token_cache.get(cache_id.as_str()) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                        let token_result = self.execute_cached_token_refresh(cache_id);
+                        self.app_config.ext_expires_fallback(token, token_result)
+                    } else {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                        Ok(token)
+                    }
+                } else {
+                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                    self.execute_cached_token_refresh(cache_id)
+                }
+            }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
+            ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-                self.execute_cached_token_refresh(cache_id)
-            } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
-                Ok(token)
+                let token_result = self.execute_cached_token_refresh(cache_id);
+                if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
+                    self.app_config.force_token_refresh = ForceTokenRefresh::Never;
+                }
+                token_result
             }
-        } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-            self.execute_cached_token_refresh(cache_id)
         }
     }
 
     async fn get_token_silent_async(&mut self) -> Result<Self::Token, AuthExecutionError> {
         let cache_id = self.app_config.cache_id.to_string();
-        if let Some(token) = self.token_cache.get(cache_id.as_str()) {
-            if token.is_expired_sub(time::Duration::minutes(5)) {
+        match self.app_config.force_token_refresh {
+            ForceTokenRefresh::Never | ForceTokenRefresh::WhenExpiredWithin(_) => {
+                if let Some(token) = self.token_cache.get(cache_id.as_str()) {
+                    if token.is_expired_sub(self.app_config.token_refresh_margin()) {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                        let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                        self.app_config.ext_expires_fallback(token, token_result)
+                    } else {
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                        Ok(token.clone())
+                    }
+                } else {
+                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                    self.execute_cached_token_refresh_async(cache_id).await
+                }
+            }
+            ForceTokenRefresh::CacheOnly => self.app_config.cache_only_token(&self.token_cache),
+            ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
                 tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-                self.execute_cached_token_refresh_async(cache_id).await
-            } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
-                Ok(token.clone())
+                let token_result = self.execute_cached_token_refresh_async(cache_id).await;
+                if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
+                    self.app_config.force_token_refresh = ForceTokenRefresh::Never;
+                }
+                token_result
             }
-        } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
-            self.execute_cached_token_refresh_async(cache_id).await
         }
     }
 
@@ -154,29 +184,26 @@ impl TokenCache for ResourceOwnerPasswordCredential {
 #[async_trait]
 impl TokenCredentialExecutor for ResourceOwnerPasswordCredential {
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
-        let mut serializer = AuthSerializer::new();
         let client_id = self.app_config.client_id.to_string();
         if client_id.is_empty() || self.app_config.client_id.is_nil() {
-            return AF::result(AuthParameter::ClientId.alias());
+            return AF::result("client_id");
         }
 
         if self.username.trim().is_empty() {
-            return AF::result(AuthParameter::Username.alias());
+            return AF::result("username");
         }
 
-        if self.password.trim().is_empty() {
-            return AF::result(AuthParameter::Password.alias());
+        if self.password.expose_secret().trim().is_empty() {
+            return AF::result("password");
         }
 
-        serializer
-            .client_id(client_id.as_str())
-            .grant_type("password")
-            .set_scope(self.app_config.scope.clone());
-
-        serializer.as_credential_map(
-            vec![AuthParameter::Scope],
-            vec![AuthParameter::ClientId, AuthParameter::GrantType],
-        )
+        let grant = Grant::Password {
+            username: self.username.clone(),
+            password: self.password.clone(),
+        };
+        TokenRequestForm::new(client_id, grant)
+            .scope(&self.app_config.scope)
+            .into_form()
     }
 
     fn client_id(&self) -> &Uuid {
@@ -192,12 +219,27 @@ impl TokenCredentialExecutor for ResourceOwnerPasswordCredential {
     }
 
     fn basic_auth(&self) -> Option<(String, String)> {
-        Some((self.username.clone(), self.password.clone()))
+        Some((
+            self.username.clone(),
+            self.password.expose_secret().to_owned(),
+        ))
     }
 
     fn app_config(&self) -> &AppConfig {
         &self.app_config
     }
+
+    fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.app_config.with_http_client(http_client);
+    }
+
+    fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.app_config.with_blocking_http_client(http_client);
+    }
+
+    fn with_tenant(&mut self, tenant_id: &str) {
+        self.app_config.with_tenant(tenant_id);
+    }
 }
 
 #[derive(Clone)]
@@ -226,7 +268,7 @@ impl ResourceOwnerPasswordCredentialBuilder {
             credential: ResourceOwnerPasswordCredential {
                 app_config,
                 username: username.as_ref().to_owned(),
-                password: password.as_ref().to_owned(),
+                password: SecretString::new(password.as_ref()),
                 token_cache: Default::default(),
             },
         }
@@ -244,7 +286,7 @@ impl ResourceOwnerPasswordCredentialBuilder {
     }
 
     pub fn with_password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
-        self.credential.password = password.as_ref().to_owned();
+        self.credential.password = SecretString::new(password.as_ref());
         self
     }
 
@@ -9,6 +9,8 @@ use reqwest::tls::Version;
 use url::{ParseError, Url};
 use uuid::Uuid;
 
+use graph_core::http::inject_trace_context;
+use graph_core::metrics::record_token_request;
 use graph_error::{AuthExecutionResult, IdentityResult};
 
 use crate::identity::credentials::app_config::AppConfig;
@@ -27,6 +29,7 @@ pub trait TokenCredentialExecutor: DynClone + Debug {
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>>;
 
     fn request_parts(&mut self) -> IdentityResult<AuthorizationRequestParts> {
+        self.app_config().validate_scope()?;
         let uri = self.uri()?;
         let form = self.form_urlencode()?;
         let basic_auth = self.basic_auth();
@@ -41,10 +44,13 @@ pub trait TokenCredentialExecutor: DynClone + Debug {
     }
 
     fn build_request(&mut self) -> AuthExecutionResult<reqwest::blocking::RequestBuilder> {
-        let http_client = reqwest::blocking::ClientBuilder::new()
-            .min_tls_version(Version::TLS_1_2)
-            .https_only(true)
-            .build()?;
+        let http_client = match self.app_config().blocking_http_client() {
+            Some(http_client) => http_client.clone(),
+            None => reqwest::blocking::ClientBuilder::new()
+                .min_tls_version(Version::TLS_1_2)
+                .https_only(true)
+                .build()?,
+        };
 
         let auth_request = self.request_parts()?;
         let basic_auth = auth_request.basic_auth;
@@ -76,10 +82,13 @@ pub trait TokenCredentialExecutor: DynClone + Debug {
     }
 
     fn build_request_async(&mut self) -> AuthExecutionResult<reqwest::RequestBuilder> {
-        let http_client = reqwest::ClientBuilder::new()
-            .min_tls_version(Version::TLS_1_2)
-            .https_only(true)
-            .build()?;
+        let http_client = match self.app_config().http_client() {
+            Some(http_client) => http_client.clone(),
+            None => reqwest::ClientBuilder::new()
+                .min_tls_version(Version::TLS_1_2)
+                .https_only(true)
+                .build()?,
+        };
 
         let auth_request = self.request_parts()?;
         let basic_auth = auth_request.basic_auth;
@@ -128,6 +137,17 @@ pub trait TokenCredentialExecutor: DynClone + Debug {
 
     fn app_config(&self) -> &AppConfig;
 
+    /// Use the given http client for async token requests instead of creating
+    /// a new client for each request.
+    fn with_http_client(&mut self, _http_client: reqwest::Client) {}
+
+    /// Use the given blocking http client for token requests instead of creating
+    /// a new client for each request.
+    fn with_blocking_http_client(&mut self, _http_client: reqwest::blocking::Client) {}
+
+    /// Request tokens from the given tenant. Tokens are cached separately for each tenant.
+    fn with_tenant(&mut self, _tenant_id: &str) {}
+
     fn extra_header_parameters(&self) -> &HeaderMap {
         &self.app_config().extra_header_parameters
     }
@@ -141,16 +161,24 @@ pub trait TokenCredentialExecutor: DynClone + Debug {
     }
 
     fn execute(&mut self) -> AuthExecutionResult<reqwest::blocking::Response> {
-        let request_builder = self.build_request()?;
-        let response = request_builder.send()?;
+        let (client, request) = self.build_request()?.build_split();
+        let mut request = request?;
+        inject_trace_context(request.headers_mut());
+        let response = client.execute(request);
+        record_token_request(response.as_ref().ok().map(|response| response.status()));
+        let response = response?;
         let status = response.status();
         tracing::debug!(target: CREDENTIAL_EXECUTOR, "authorization response received; status={status:#?}");
         Ok(response)
     }
 
     async fn execute_async(&mut self) -> AuthExecutionResult<reqwest::Response> {
-        let request_builder = self.build_request_async()?;
-        let response = request_builder.send().await?;
+        let (client, request) = self.build_request_async()?.build_split();
+        let mut request = request?;
+        inject_trace_context(request.headers_mut());
+        let response = client.execute(request).await;
+        record_token_request(response.as_ref().ok().map(|response| response.status()));
+        let response = response?;
         let status = response.status();
         tracing::debug!(target: CREDENTIAL_EXECUTOR, "authorization response received; status={status:#?}");
         Ok(response)
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use time::OffsetDateTime;
+use tokio::task::JoinHandle;
+
+use graph_core::cache::TokenCache;
+use graph_core::identity::ForceTokenRefresh;
+
+use crate::identity::tracing_targets::CREDENTIAL_EXECUTOR;
+use crate::identity::Token;
+
+/// Shortest time to wait between token requests so that a margin larger than the
+/// lifetime of the token does not cause the refresher to request tokens continuously.
+const MIN_REFRESH_DELAY: Duration = Duration::from_secs(10);
+
+/// Longest time to wait before retrying after a failed token request.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// Handle to a background task that refreshes tokens before they expire.
+///
+/// Tokens are stored in the token cache of the client application the refresher
+/// was spawned from, which is shared by all clones of the client, so requests
+/// using the client get an unexpired token from the cache.
+///
+/// The task is stopped when the handle is dropped or [TokenRefresher::abort] is called.
+#[derive(Debug)]
+pub struct TokenRefresher {
+    handle: JoinHandle<()>,
+}
+
+impl TokenRefresher {
+    /// Spawn the refresher on the current tokio runtime.
+    ///
+    /// # Panics
+    /// Panics if called outside of a tokio runtime.
+    pub(crate) fn spawn<Credential>(mut credential: Credential, margin: Duration) -> TokenRefresher
+    where
+        Credential: TokenCache<Token = Token> + Send + 'static,
+    {
+        let handle = tokio::spawn(async move {
+            let mut retry_delay = MIN_REFRESH_DELAY;
+            loop {
+                match credential.get_token_silent_async().await {
+                    Ok(token) => {
+                        retry_delay = MIN_REFRESH_DELAY;
+                        let delay = refresh_delay(&token, margin);
+                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "next token refresh in {delay:?}");
+                        tokio::time::sleep(delay).await;
+                    }
+                    Err(err) => {
+                        tracing::warn!(target: CREDENTIAL_EXECUTOR, "background token refresh failed, retrying in {retry_delay:?}: {err}");
+                        tokio::time::sleep(retry_delay).await;
+                        retry_delay = (retry_delay * 2).min(MAX_RETRY_DELAY);
+                    }
+                }
+                credential.with_force_token_refresh(ForceTokenRefresh::Once);
+            }
+        });
+
+        TokenRefresher { handle }
+    }
+
+    /// Stop refreshing tokens.
+    pub fn abort(&self) {
+        self.handle.abort();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+}
+
+impl Drop for TokenRefresher {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Time to wait until the token expires minus the margin.
+fn refresh_delay(token: &Token, margin: Duration) -> Duration {
+    let expires_in = match token.expires_on {
+        Some(expires_on) => (expires_on - OffsetDateTime::now_utc()).whole_seconds(),
+        None => token.expires_in,
+    };
+
+    Duration::from_secs(expires_in.max(0) as u64)
+        .saturating_sub(margin)
+        .max(MIN_REFRESH_DELAY)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn refresh_delay_subtracts_margin() {
+        let mut token = Token::new("Bearer", 3600, "access_token", vec!["User.Read"]);
+        token.expires_on = None;
+        assert_eq!(
+            Duration::from_secs(3300),
+            refresh_delay(&token, Duration::from_secs(300))
+        );
+        assert_eq!(
+            MIN_REFRESH_DELAY,
+            refresh_delay(&token, Duration::from_secs(7200))
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::{BTreeSet, HashMap};
+
+use graph_error::{IdentityResult, AF};
+
+use crate::identity::SecretString;
+
+/// The grant type of a token request with the parameters that belong to it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "grant_type")]
+pub(crate) enum Grant {
+    #[serde(rename = "authorization_code")]
+    AuthorizationCode {
+        code: SecretString,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        redirect_uri: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        code_verifier: Option<SecretString>,
+    },
+    #[serde(rename = "refresh_token")]
+    RefreshToken { refresh_token: SecretString },
+    #[serde(rename = "client_credentials")]
+    ClientCredentials,
+    #[serde(rename = "password")]
+    Password {
+        username: String,
+        password: SecretString,
+    },
+    #[serde(rename = "urn:ietf:params:oauth:grant-type:device_code")]
+    DeviceCode { device_code: SecretString },
+}
+
+impl Grant {
+    /// The authorization code grant. Returns an error when the code is empty.
+    pub(crate) fn authorization_code(
+        code: &str,
+        redirect_uri: Option<String>,
+        code_verifier: Option<String>,
+    ) -> IdentityResult<Grant> {
+        if code.trim().is_empty() {
+            return AF::msg_result("code", "Authorization code is empty");
+        }
+        Ok(Grant::AuthorizationCode {
+            code: SecretString::from(code),
+            redirect_uri,
+            code_verifier: code_verifier.map(SecretString::from),
+        })
+    }
+
+    /// The refresh token grant. Returns an error when the refresh token is empty.
+    pub(crate) fn refresh_token(refresh_token: &str) -> IdentityResult<Grant> {
+        if refresh_token.trim().is_empty() {
+            return AF::msg_result("refresh_token", "Refresh token is empty");
+        }
+        Ok(Grant::RefreshToken {
+            refresh_token: SecretString::from(refresh_token),
+        })
+    }
+}
+
+/// How a confidential client authenticates in the body of a token request.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub(crate) enum ClientAuthentication {
+    Secret {
+        client_secret: SecretString,
+    },
+    Assertion {
+        client_assertion_type: String,
+        client_assertion: SecretString,
+    },
+}
+
+/// The x-www-form-urlencoded body of a token request.
+///
+/// Only the parameters of the [Grant] and [ClientAuthentication] are sent so a
+/// parameter of one grant type cannot be sent with another.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub(crate) struct TokenRequestForm {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_id: Option<String>,
+    #[serde(flatten)]
+    client_authentication: Option<ClientAuthentication>,
+    #[serde(flatten)]
+    grant: Grant,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+}
+
+impl TokenRequestForm {
+    pub(crate) fn new(client_id: impl ToString, grant: Grant) -> TokenRequestForm {
+        TokenRequestForm {
+            client_id: Some(client_id.to_string()),
+            client_authentication: None,
+            grant,
+            scope: None,
+        }
+    }
+
+    /// A request where the client id and secret are sent using basic auth.
+    pub(crate) fn basic_auth(grant: Grant) -> TokenRequestForm {
+        TokenRequestForm {
+            client_id: None,
+            client_authentication: None,
+            grant,
+            scope: None,
+        }
+    }
+
+    pub(crate) fn client_secret(mut self, client_secret: &str) -> IdentityResult<Self> {
+        if client_secret.trim().is_empty() {
+            return AF::result("client_secret");
+        }
+        self.client_authentication = Some(ClientAuthentication::Secret {
+            client_secret: SecretString::from(client_secret),
+        });
+        Ok(self)
+    }
+
+    pub(crate) fn client_assertion(
+        mut self,
+        client_assertion_type: &str,
+        client_assertion: &str,
+    ) -> IdentityResult<Self> {
+        if client_assertion.trim().is_empty() {
+            return AF::result("client_assertion");
+        }
+        self.client_authentication = Some(ClientAuthentication::Assertion {
+            client_assertion_type: client_assertion_type.to_owned(),
+            client_assertion: SecretString::from(client_assertion),
+        });
+        Ok(self)
+    }
+
+    /// The space separated scopes. No scope is sent when there are no scopes.
+    pub(crate) fn scope(mut self, scope: &BTreeSet<String>) -> Self {
+        self.scope = scope_value(scope);
+        self
+    }
+
+    pub(crate) fn into_form(self) -> IdentityResult<HashMap<String, String>> {
+        into_form(&self)
+    }
+}
+
+/// The body of the request to the device authorization endpoint that starts
+/// the device code flow.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub(crate) struct DeviceAuthorizationForm {
+    client_id: String,
+    scope: String,
+}
+
+impl DeviceAuthorizationForm {
+    pub(crate) fn new(
+        client_id: impl ToString,
+        scope: &BTreeSet<String>,
+    ) -> IdentityResult<DeviceAuthorizationForm> {
+        Ok(DeviceAuthorizationForm {
+            client_id: client_id.to_string(),
+            scope: scope_value(scope).ok_or_else(|| AF::required("scope"))?,
+        })
+    }
+
+    pub(crate) fn into_form(self) -> IdentityResult<HashMap<String, String>> {
+        into_form(&self)
+    }
+}
+
+fn scope_value(scope: &BTreeSet<String>) -> Option<String> {
+    if scope.is_empty() {
+        None
+    } else {
+        Some(
+            scope
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<&str>>()
+                .join(" "),
+        )
+    }
+}
+
+fn into_form<T: serde::Serialize>(value: &T) -> IdentityResult<HashMap<String, String>> {
+    let value = serde_json::to_value(value)?;
+    let form = value
+        .as_object()
+        .map(|object| {
+            object
+                .iter()
+                .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_owned())))
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(form)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn form_includes_only_grant_parameters() {
+        let scope = BTreeSet::from(["User.Read".to_owned(), "offline_access".to_owned()]);
+        let form = TokenRequestForm::new(
+            "client-id",
+            Grant::authorization_code("code", Some("http://localhost".into()), None).unwrap(),
+        )
+        .client_secret("secret")
+        .unwrap()
+        .scope(&scope)
+        .into_form()
+        .unwrap();
+
+        assert_eq!(
+            HashMap::from([
+                ("client_id".to_owned(), "client-id".to_owned()),
+                ("client_secret".to_owned(), "secret".to_owned()),
+                ("grant_type".to_owned(), "authorization_code".to_owned()),
+                ("code".to_owned(), "code".to_owned()),
+                ("redirect_uri".to_owned(), "http://localhost".to_owned()),
+                ("scope".to_owned(), "User.Read offline_access".to_owned()),
+            ]),
+            form
+        );
+
+        let form = TokenRequestForm::basic_auth(Grant::ClientCredentials)
+            .into_form()
+            .unwrap();
+        assert_eq!(
+            HashMap::from([("grant_type".to_owned(), "client_credentials".to_owned())]),
+            form
+        );
+
+        assert!(Grant::refresh_token(" ").is_err());
+        assert!(DeviceAuthorizationForm::new("client-id", &BTreeSet::new()).is_err());
+    }
+
+    #[test]
+    fn secrets_are_not_in_debug_output() {
+        let form = TokenRequestForm::new(
+            "client-id",
+            Grant::Password {
+                username: "user".into(),
+                password: SecretString::from("password-value"),
+            },
+        )
+        .client_secret("secret-value")
+        .unwrap();
+
+        let debug = format!("{form:?}");
+        assert!(!debug.contains("password-value"));
+        assert!(!debug.contains("secret-value"));
+        assert!(debug.contains("[REDACTED]"));
+    }
+}
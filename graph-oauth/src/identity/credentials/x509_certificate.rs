@@ -1,6 +1,9 @@
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
 use base64::Engine;
 use graph_error::{IdentityResult, AF};
 use openssl::error::ErrorStack;
@@ -10,9 +13,18 @@ use openssl::pkey::{PKey, Private};
 use openssl::rsa::Padding;
 use openssl::sign::Signer;
 use openssl::x509::{X509Ref, X509};
-use time::OffsetDateTime;
+use serde_json::{Map, Value};
 use uuid::Uuid;
 
+// This file is also the root of the x509_certificate_tests target so the claims
+// shared with the remote signer are included by path.
+#[path = "client_assertion_claims.rs"]
+pub(crate) mod client_assertion_claims;
+
+use client_assertion_claims::{
+    assertion_claims, assertion_header, is_valid, unsigned_token, ASSERTION_LIFETIME, CLOCK_SKEW,
+};
+
 fn encode_cert(cert: &X509) -> IdentityResult<String> {
     Ok(format!(
         "\"{}\"",
@@ -20,11 +32,9 @@ fn encode_cert(cert: &X509) -> IdentityResult<String> {
     ))
 }
 
-fn encode_cert_ref(cert: &X509Ref) -> IdentityResult<String> {
-    Ok(format!(
-        "\"{}\"",
-        URL_SAFE_NO_PAD.encode(cert.to_pem().map_err(|err| AF::x509(err.to_string()))?)
-    ))
+/// Standard base64 encoding of the DER encoding of the certificate as used in the x5c header.
+fn encode_der(cert: &X509Ref) -> IdentityResult<String> {
+    Ok(STANDARD.encode(cert.to_der().map_err(|err| AF::x509(err.to_string()))?))
 }
 
 #[allow(unused)]
@@ -41,6 +51,10 @@ fn thumbprint(cert: &X509) -> IdentityResult<String> {
 /// Client assertions are generated using the openssl library for security reasons.
 /// You can see an example of how this is done by Microsoft located at
 /// https://learn.microsoft.com/en-us/azure/active-directory/develop/msal-net-client-assertions
+///
+/// The private key is held by openssl, which clears the memory of the key when it is freed.
+/// With the `zeroize` feature the signed client assertion stored by the credential is
+/// overwritten with zeros when the credential is dropped.
 pub struct X509Certificate {
     client_id: String,
     tenant_id: Option<String>,
@@ -49,8 +63,44 @@ pub struct X509Certificate {
     certificate: X509,
     pkey: PKey<Private>,
     certificate_chain: bool,
-    parsed_pkcs12: Option<ParsedPkcs12_2>,
+    parsed_pkcs12: Option<Arc<ParsedPkcs12_2>>,
     uuid: Uuid,
+    assertion_lifetime: Duration,
+    clock_skew: Duration,
+    cached_assertion: Mutex<Option<CachedAssertion>>,
+}
+
+/// A signed client assertion and the unix timestamp of its exp claim.
+#[derive(Clone)]
+struct CachedAssertion {
+    tenant_id: Option<String>,
+    assertion: String,
+    expires_on: i64,
+}
+
+impl Clone for X509Certificate {
+    fn clone(&self) -> Self {
+        let cached_assertion = self
+            .cached_assertion
+            .lock()
+            .ok()
+            .and_then(|cached_assertion| cached_assertion.clone());
+
+        X509Certificate {
+            client_id: self.client_id.clone(),
+            tenant_id: self.tenant_id.clone(),
+            claims: self.claims.clone(),
+            extend_claims: self.extend_claims,
+            certificate: self.certificate.clone(),
+            pkey: self.pkey.clone(),
+            certificate_chain: self.certificate_chain,
+            parsed_pkcs12: self.parsed_pkcs12.clone(),
+            uuid: self.uuid,
+            assertion_lifetime: self.assertion_lifetime,
+            clock_skew: self.clock_skew,
+            cached_assertion: Mutex::new(cached_assertion),
+        }
+    }
 }
 
 impl X509Certificate {
@@ -65,6 +115,9 @@ impl X509Certificate {
             pkey: private_key,
             parsed_pkcs12: None,
             uuid: Uuid::new_v4(),
+            assertion_lifetime: ASSERTION_LIFETIME,
+            clock_skew: CLOCK_SKEW,
+            cached_assertion: Mutex::new(None),
         }
     }
 
@@ -84,6 +137,9 @@ impl X509Certificate {
             pkey: private_key,
             parsed_pkcs12: None,
             uuid: Uuid::new_v4(),
+            assertion_lifetime: ASSERTION_LIFETIME,
+            clock_skew: CLOCK_SKEW,
+            cached_assertion: Mutex::new(None),
         }
     }
 
@@ -118,8 +174,11 @@ impl X509Certificate {
             certificate,
             certificate_chain: true,
             pkey: private_key.clone(),
-            parsed_pkcs12: Some(parsed_pkcs12),
+            parsed_pkcs12: Some(Arc::new(parsed_pkcs12)),
             uuid: Uuid::new_v4(),
+            assertion_lifetime: ASSERTION_LIFETIME,
+            clock_skew: CLOCK_SKEW,
+            cached_assertion: Mutex::new(None),
         })
     }
 
@@ -155,17 +214,111 @@ impl X509Certificate {
             certificate,
             certificate_chain: true,
             pkey: private_key.clone(),
-            parsed_pkcs12: Some(parsed_pkcs12),
+            parsed_pkcs12: Some(Arc::new(parsed_pkcs12)),
             uuid: Uuid::new_v4(),
+            assertion_lifetime: ASSERTION_LIFETIME,
+            clock_skew: CLOCK_SKEW,
+            cached_assertion: Mutex::new(None),
         })
     }
 
+    /// Load the certificate and private key from a PEM encoding that has both.
+    /// The private key is decrypted using the password when one is given.
+    pub fn from_pem(
+        client_id: impl AsRef<str>,
+        pem: &[u8],
+        password: Option<&str>,
+    ) -> IdentityResult<Self> {
+        let certificate = X509::from_pem(pem).map_err(|err| AF::x509(err.to_string()))?;
+        let private_key = match password {
+            Some(password) => PKey::private_key_from_pem_passphrase(pem, password.as_bytes()),
+            None => PKey::private_key_from_pem(pem),
+        }
+        .map_err(|err| AF::x509(err.to_string()))?;
+        Ok(X509Certificate::new(client_id, certificate, private_key))
+    }
+
+    /// Load the certificate and private key from the DER encoding of a PKCS#12 archive,
+    /// such as the contents of a `.pfx` file, protected by the password.
+    ///
+    /// Any other certificates in the archive are sent after the certificate in the x5c
+    /// header when [X509Certificate::set_send_x5c] is set.
+    pub fn from_pkcs12(
+        client_id: impl AsRef<str>,
+        der: &[u8],
+        password: impl AsRef<str>,
+    ) -> IdentityResult<Self> {
+        let parsed_pkcs12 = Pkcs12::from_der(der)
+            .map_err(|err| AF::x509(err.to_string()))?
+            .parse2(password.as_ref())
+            .map_err(|err| AF::x509(err.to_string()))?;
+
+        let certificate = parsed_pkcs12.cert.clone().ok_or(AF::x509(
+            "No certificate found after parsing Pkcs12 using pass",
+        ))?;
+
+        let private_key = parsed_pkcs12.pkey.clone().ok_or(AF::x509(
+            "No private key found after parsing Pkcs12 using pass",
+        ))?;
+
+        Ok(Self {
+            client_id: client_id.as_ref().to_owned(),
+            tenant_id: None,
+            claims: None,
+            extend_claims: true,
+            certificate,
+            certificate_chain: false,
+            pkey: private_key,
+            parsed_pkcs12: Some(Arc::new(parsed_pkcs12)),
+            uuid: Uuid::new_v4(),
+            assertion_lifetime: ASSERTION_LIFETIME,
+            clock_skew: CLOCK_SKEW,
+            cached_assertion: Mutex::new(None),
+        })
+    }
+
+    /// Load the certificate and private key from a PKCS#12 (`.pfx` or `.p12`) file
+    /// protected by the password.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use graph_rs_sdk::identity::X509Certificate;
+    ///
+    /// let mut x509_certificate =
+    ///     X509Certificate::from_pkcs12_file("client_id", "certificate.pfx", "password")?;
+    /// x509_certificate.set_send_x5c(true);
+    /// ```
+    pub fn from_pkcs12_file(
+        client_id: impl AsRef<str>,
+        path: impl AsRef<Path>,
+        password: impl AsRef<str>,
+    ) -> IdentityResult<Self> {
+        let der = std::fs::read(path.as_ref()).map_err(|err| {
+            AF::x509(format!(
+                "Unable to read Pkcs12 file {} - error {err}",
+                path.as_ref().display()
+            ))
+        })?;
+        X509Certificate::from_pkcs12(client_id, &der, password)
+    }
+
+    /// Send the certificate, followed by the other certificates of the PKCS#12 archive
+    /// if any, in the x5c header of the client assertion.
+    ///
+    /// The x5c header is required for subject name and issuer (SNI) authentication,
+    /// which allows rolling over the certificate without updating the app registration.
+    pub fn set_send_x5c(&mut self, value: bool) {
+        self.clear_cached_assertion();
+        self.certificate_chain = value;
+    }
+
     /// Provide your own set of claims in the payload of the JWT.
     ///
     /// Replace the claims that would be generated for the client assertion.
     /// This replaces the following payload fields: aud, exp, nbf, jti, sub, and iss.
     /// Only the claims given are passed for the payload of the JWT used in the client assertion.
     pub fn replace_claims(&mut self, claims: HashMap<String, String>) {
+        self.clear_cached_assertion();
         self.claims = Some(claims);
         self.extend_claims = false;
     }
@@ -181,6 +334,7 @@ impl X509Certificate {
     /// to those claims mentioned above and do not replace them, however, any claim provided
     /// with the same fields above will replace those that are generated.
     pub fn extend_claims(&mut self, claims: HashMap<String, String>) {
+        self.clear_cached_assertion();
         match self.claims.as_mut() {
             Some(c) => c.extend(claims),
             None => self.claims = Some(claims),
@@ -224,47 +378,78 @@ impl X509Certificate {
 
     /// Set the UUID for the jti field of the claims/payload of the jwt.
     pub fn set_uuid(&mut self, value: Uuid) {
+        self.clear_cached_assertion();
         self.uuid = value;
     }
 
-    fn x5c(&self) -> IdentityResult<String> {
-        let parsed_pkcs12 = self.parsed_pkcs12.as_ref().ok_or(AF::x509(
-            "No certificate found after parsing Pkcs12 using pass",
-        ))?;
+    /// How long the signed client assertion is valid, which is the time between the nbf
+    /// and exp claims. The default is 10 minutes.
+    pub fn set_assertion_lifetime(&mut self, assertion_lifetime: Duration) {
+        self.clear_cached_assertion();
+        self.assertion_lifetime = assertion_lifetime;
+    }
 
-        let certificate = parsed_pkcs12.cert.as_ref().ok_or(AF::x509(
-            "No certificate found after parsing Pkcs12 using pass",
-        ))?;
+    pub fn assertion_lifetime(&self) -> Duration {
+        self.assertion_lifetime
+    }
 
-        let sig = encode_cert(certificate)?;
+    /// The signed client assertion is reused until this long before it expires, which
+    /// allows for the difference between the local clock and the clock of the identity
+    /// platform. The default is 1 minute.
+    pub fn set_clock_skew(&mut self, clock_skew: Duration) {
+        self.clock_skew = clock_skew;
+    }
 
-        if let Some(stack) = parsed_pkcs12.ca.as_ref() {
-            let chain = stack
-                .into_iter()
-                .map(encode_cert_ref)
-                .collect::<IdentityResult<Vec<String>>>()
-                .map_err(|err| {
+    pub fn clock_skew(&self) -> Duration {
+        self.clock_skew
+    }
+
+    fn clear_cached_assertion(&mut self) {
+        if let Ok(cached_assertion) = self.cached_assertion.get_mut() {
+            *cached_assertion = None;
+        }
+    }
+
+    /// The cached client assertion for the tenant if it does not expire within the clock skew.
+    fn cached_assertion(&self, tenant_id: &Option<String>) -> Option<String> {
+        let cached_assertion = self.cached_assertion.lock().ok()?;
+        let cached_assertion = cached_assertion.as_ref()?;
+        if cached_assertion.tenant_id.eq(tenant_id)
+            && is_valid(cached_assertion.expires_on, self.clock_skew)
+        {
+            Some(cached_assertion.assertion.clone())
+        } else {
+            None
+        }
+    }
+
+    /// The certificate followed by the other certificates of the chain.
+    fn x5c(&self) -> IdentityResult<Vec<String>> {
+        let mut x5c = vec![encode_der(&self.certificate)?];
+
+        if let Some(stack) = self
+            .parsed_pkcs12
+            .as_ref()
+            .and_then(|parsed_pkcs12| parsed_pkcs12.ca.as_ref())
+        {
+            for certificate in stack {
+                x5c.push(encode_der(certificate).map_err(|err| {
                     AF::x509(format!(
                         "Unable to encode certificates in certificate chain - error {err}"
                     ))
-                })?
-                .join(",");
-
-            Ok(format! {"{},{}", sig, chain})
-        } else {
-            Ok(sig)
+                })?);
+            }
         }
+
+        Ok(x5c)
     }
 
-    fn get_header(&self) -> IdentityResult<HashMap<String, String>> {
-        let mut header = HashMap::new();
-        header.insert("x5t".to_owned(), self.get_thumbprint()?);
-        header.insert("alg".to_owned(), "RS256".to_owned());
-        header.insert("typ".to_owned(), "JWT".to_owned());
+    fn get_header(&self) -> IdentityResult<Map<String, Value>> {
+        let mut header = assertion_header(self.get_thumbprint()?);
 
-        if self.certificate_chain && self.parsed_pkcs12.is_some() {
-            let x5c = self.x5c()?;
-            header.insert("x5c".to_owned(), x5c);
+        if self.certificate_chain {
+            let x5c = self.x5c()?.into_iter().map(Value::String).collect();
+            header.insert("x5c".to_owned(), Value::Array(x5c));
         }
 
         Ok(header)
@@ -277,29 +462,12 @@ impl X509Certificate {
             }
         }
 
-        let aud = {
-            if let Some(tenant_id) = tenant_id.as_ref() {
-                format!(
-                    "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
-                    tenant_id
-                )
-            } else {
-                "https://login.microsoftonline.com/common/oauth2/v2.0/token".to_owned()
-            }
-        };
-
-        // 10 minutes until expiration.
-        let exp = 60 * 10;
-        let nbf = OffsetDateTime::now_utc().unix_timestamp();
-        let exp = nbf + exp;
-
-        let mut claims = HashMap::new();
-        claims.insert("aud".to_owned(), aud);
-        claims.insert("exp".to_owned(), exp.to_string());
-        claims.insert("nbf".to_owned(), nbf.to_string());
-        claims.insert("jti".to_owned(), self.uuid.to_string());
-        claims.insert("sub".to_owned(), self.client_id.to_owned());
-        claims.insert("iss".to_owned(), self.client_id.to_owned());
+        let mut claims = assertion_claims(
+            &self.client_id,
+            tenant_id.as_ref(),
+            self.uuid.to_string(),
+            self.assertion_lifetime,
+        );
 
         if let Some(internal_claims) = self.claims.as_ref() {
             claims.extend(internal_claims.clone());
@@ -309,16 +477,8 @@ impl X509Certificate {
     }
 
     /// JWT Header and Payload in the format header.payload
-    fn base64_token(&self, tenant_id: Option<String>) -> IdentityResult<String> {
-        let header = self.get_header()?;
-        let header = serde_json::to_string(&header)?;
-        let header_base64 = URL_SAFE_NO_PAD.encode(header.as_bytes());
-
-        let claims = self.get_claims(tenant_id)?;
-        let claims = serde_json::to_string(&claims)?;
-        let claims_base64 = URL_SAFE_NO_PAD.encode(claims.as_bytes());
-
-        Ok(format!("{}.{}", header_base64, claims_base64))
+    fn base64_token(&self, claims: &HashMap<String, String>) -> IdentityResult<String> {
+        unsigned_token(&self.get_header()?, claims)
     }
 
     /*
@@ -340,32 +500,27 @@ impl X509Certificate {
            Ok(signed_client_assertion)
     */
 
+    /// Get the signed client assertion for the tenant of the certificate.
+    ///
+    /// The client assertion is reused until it is within the clock skew of expiring.
     pub fn sign(&self) -> IdentityResult<String> {
-        let token = self.base64_token(self.tenant_id.clone())?;
-
-        let mut signer = Signer::new(MessageDigest::sha256(), &self.pkey)
-            .map_err(|err| AF::x509(err.to_string()))?;
-        signer
-            .set_rsa_padding(Padding::PKCS1)
-            .map_err(|err| AF::x509(err.to_string()))?;
-        signer
-            .update(token.as_bytes())
-            .map_err(|err| AF::x509(err.to_string()))?;
-        let signature = URL_SAFE_NO_PAD.encode(
-            signer
-                .sign_to_vec()
-                .map_err(|err| AF::x509(err.to_string()))?,
-        );
-
-        Ok(format!("{token}.{signature}"))
+        self.sign_with_tenant(self.tenant_id.clone())
     }
 
     /// Get the signed client assertion.
     ///
     /// The signature is a Base64 Url encoded (No Pad) JWT Header and Payload signed with the private key using SHA_256
     /// and RSA padding PKCS1
+    ///
+    /// The client assertion is reused until it is within the clock skew of expiring.
+    /// See [X509Certificate::set_clock_skew].
     pub fn sign_with_tenant(&self, tenant_id: Option<String>) -> IdentityResult<String> {
-        let token = self.base64_token(tenant_id)?;
+        if let Some(assertion) = self.cached_assertion(&tenant_id) {
+            return Ok(assertion);
+        }
+
+        let claims = self.get_claims(tenant_id.clone())?;
+        let token = self.base64_token(&claims)?;
 
         let mut signer = Signer::new(MessageDigest::sha256(), &self.pkey)
             .map_err(|err| AF::x509(err.to_string()))?;
@@ -380,8 +535,20 @@ impl X509Certificate {
                 .sign_to_vec()
                 .map_err(|err| AF::x509(err.to_string()))?,
         );
+        let assertion = format!("{token}.{signature}");
+
+        // Claims without an exp claim, such as replaced claims, are not cached.
+        if let Some(expires_on) = claims.get("exp").and_then(|exp| exp.parse::<i64>().ok()) {
+            if let Ok(mut cached_assertion) = self.cached_assertion.lock() {
+                *cached_assertion = Some(CachedAssertion {
+                    tenant_id,
+                    assertion: assertion.clone(),
+                    expires_on,
+                });
+            }
+        }
 
-        Ok(format!("{token}.{signature}"))
+        Ok(assertion)
     }
 }
 
@@ -425,4 +592,50 @@ mod test {
         let certificate = X509Certificate::new("client_id", cert, private_key);
         assert!(certificate.sign_with_tenant(None).is_ok());
     }
+
+    #[test]
+    pub fn from_pkcs12_with_x5c() {
+        let pkcs12_bytes = include_bytes!("test/cert.pfx");
+        let cert = X509::from_pem(include_bytes!("test/cert.pem")).unwrap();
+
+        let mut certificate =
+            X509Certificate::from_pkcs12("client_id", pkcs12_bytes, "password").unwrap();
+        assert!(!certificate.get_header().unwrap().contains_key("x5c"));
+
+        certificate.set_send_x5c(true);
+        let header = certificate.get_header().unwrap();
+        assert_eq!(
+            Value::Array(vec![Value::String(STANDARD.encode(cert.to_der().unwrap()))]),
+            header["x5c"]
+        );
+        assert_eq!(
+            Value::String(certificate.get_thumbprint().unwrap()),
+            header["x5t"]
+        );
+        assert!(certificate.sign().is_ok());
+
+        assert!(X509Certificate::from_pkcs12("client_id", pkcs12_bytes, "wrong").is_err());
+    }
+
+    #[test]
+    pub fn reuse_assertion_until_clock_skew() {
+        let cert = X509::from_pem(include_bytes!("test/cert.pem")).unwrap();
+        let private_key = PKey::private_key_from_pem(include_bytes!("test/key.pem")).unwrap();
+        let mut certificate = X509Certificate::new("client_id", cert, private_key);
+        assert_eq!(Duration::from_secs(600), certificate.assertion_lifetime());
+
+        let assertion = certificate.sign_with_tenant(None).unwrap();
+        assert_eq!(Some(assertion), certificate.cached_assertion(&None));
+        assert!(certificate
+            .cached_assertion(&Some("tenant_id".to_owned()))
+            .is_none());
+
+        certificate.set_assertion_lifetime(Duration::from_secs(30));
+        assert!(certificate.cached_assertion(&None).is_none());
+        certificate.sign_with_tenant(None).unwrap();
+        assert!(certificate.cached_assertion(&None).is_none());
+
+        certificate.set_clock_skew(Duration::ZERO);
+        assert!(certificate.cached_assertion(&None).is_some());
+    }
 }
@@ -1,5 +1,5 @@
 use std::collections::{BTreeSet, HashMap};
-use std::fmt::{Display, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::str::FromStr;
 
 use serde_json::Value;
@@ -33,7 +33,7 @@ use crate::identity::{DeviceCodeCredential, PublicClientApplication};
 ///     "verification_uri": String("https://microsoft.com/devicelogin"),
 /// }
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct DeviceAuthorizationResponse {
     ///  A long string used to verify the session between the client and the authorization server.
     /// The client uses this parameter to request the access token from the authorization server.
@@ -68,6 +68,22 @@ fn default_interval() -> u64 {
     5
 }
 
+impl Debug for DeviceAuthorizationResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeviceAuthorizationResponse")
+            .field("device_code", &"[REDACTED]")
+            .field("expires_in", &self.expires_in)
+            .field("interval", &self.interval)
+            .field("message", &self.message)
+            .field("user_code", &self.user_code)
+            .field("verification_uri", &self.verification_uri)
+            .field("verification_uri_complete", &self.verification_uri_complete)
+            .field("scopes", &self.scopes)
+            .field("additional_fields", &self.additional_fields)
+            .finish()
+    }
+}
+
 impl Display for DeviceAuthorizationResponse {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -181,6 +181,15 @@ impl Debug for IdToken {
 
 struct IdTokenVisitor;
 
+/// Overwrite the id token and authorization code with zeros.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for IdToken {
+    fn zeroize(&mut self) {
+        self.id_token.zeroize();
+        self.code.zeroize();
+    }
+}
+
 impl<'de> Deserialize<'de> for IdToken {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
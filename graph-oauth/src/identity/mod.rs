@@ -1,3 +1,4 @@
+mod account;
 mod allowed_host_validator;
 mod application_options;
 mod authority;
@@ -8,6 +9,9 @@ mod credentials;
 mod device_authorization_response;
 mod id_token;
 mod into_credential_builder;
+mod open_id_configuration;
+mod scope;
+mod secret_string;
 mod token;
 
 #[cfg(feature = "openssl")]
@@ -16,6 +20,7 @@ pub use openssl::{
     x509::X509,
 };
 
+pub use account::*;
 pub use allowed_host_validator::*;
 pub use application_options::*;
 pub use authority::*;
@@ -26,4 +31,7 @@ pub use credentials::*;
 pub use device_authorization_response::*;
 pub use id_token::*;
 pub use into_credential_builder::*;
+pub use open_id_configuration::*;
+pub use scope::*;
+pub use secret_string::*;
 pub use token::*;
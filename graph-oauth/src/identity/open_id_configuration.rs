@@ -0,0 +1,519 @@
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
+use graph_core::identity::{Claims, JwksKey, JwksKeySet};
+use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
+use jsonwebtoken::{Algorithm, DecodingKey, TokenData, Validation};
+use reqwest::tls::Version;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use url::Url;
+
+use crate::identity::{tracing_targets::CREDENTIAL_EXECUTOR, Authority, AzureCloudInstance};
+
+/// How long the OpenID configuration and signing keys are cached by default.
+const DEFAULT_CACHE_DURATION: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// The minimum time between requests for the signing keys when a token is signed
+/// with a key that is not in the cached key set.
+const MIN_KEY_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 5);
+
+/// The OpenID Connect discovery document of the Microsoft identity platform, returned
+/// from the `.well-known/openid-configuration` endpoint of an authority.
+///
+/// [OpenID Connect Discovery](https://openid.net/specs/openid-connect-discovery-1_0.html#ProviderMetadata)
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct OpenIdConfiguration {
+    /// The issuer of tokens. For the common and organizations authorities the issuer
+    /// contains a `{tenantid}` placeholder for the tenant of the token.
+    pub issuer: String,
+    pub authorization_endpoint: Url,
+    pub token_endpoint: Url,
+    /// The url of the JSON Web Key Set with the keys used to sign tokens.
+    pub jwks_uri: Url,
+    pub end_session_endpoint: Option<Url>,
+    pub userinfo_endpoint: Option<Url>,
+    pub device_authorization_endpoint: Option<Url>,
+    #[serde(default)]
+    pub response_types_supported: Vec<String>,
+    #[serde(default)]
+    pub response_modes_supported: Vec<String>,
+    #[serde(default)]
+    pub subject_types_supported: Vec<String>,
+    #[serde(default)]
+    pub id_token_signing_alg_values_supported: Vec<String>,
+    #[serde(default)]
+    pub scopes_supported: Vec<String>,
+    #[serde(default)]
+    pub claims_supported: Vec<String>,
+    pub tenant_region_scope: Option<String>,
+    pub cloud_instance_name: Option<String>,
+    pub msgraph_host: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl OpenIdConfiguration {
+    /// The issuer for a token of the given tenant, replacing the `{tenantid}`
+    /// placeholder of multi-tenant authorities.
+    pub fn issuer_for_tenant(&self, tenant_id: &str) -> String {
+        self.issuer.replace("{tenantid}", tenant_id)
+    }
+
+    /// Whether the issuer contains the `{tenantid}` placeholder of the common and
+    /// organizations authorities.
+    pub fn is_multi_tenant(&self) -> bool {
+        self.issuer.contains("{tenantid}")
+    }
+}
+
+// The issuer of v1.0 access tokens for the tenant.
+fn v1_issuer(tenant_id: &str) -> String {
+    format!("https://sts.windows.net/{tenant_id}/")
+}
+
+struct Cached<T> {
+    value: Arc<T>,
+    fetched: Instant,
+}
+
+#[derive(Default)]
+struct DiscoveryCache {
+    configuration: Option<Cached<OpenIdConfiguration>>,
+    key_set: Option<Cached<JwksKeySet>>,
+}
+
+/// Fetches and caches the [OpenIdConfiguration] and the signing keys of an authority
+/// for validating tokens issued by the Microsoft identity platform, such as the access
+/// tokens sent to an API protected by Azure Active Directory.
+///
+/// When a token is signed with a key that is not in the cached key set, such as after
+/// the signing keys are rotated, the key set is requested again. Clones share the cache.
+///
+/// The issuer of a token must be the tenant of the authority. Both v1.0 issuers,
+/// `https://sts.windows.net/{tenant}/`, and v2.0 issuers are accepted. For the common and
+/// organizations authorities set the tenants that are allowed to issue tokens using
+/// [OpenIdDiscovery::with_allowed_tenants].
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::{AzureCloudInstance, OpenIdDiscovery};
+///
+/// let discovery = OpenIdDiscovery::new(AzureCloudInstance::AzurePublic, "tenant_id")?;
+/// let token_data = discovery.validate_token_async(access_token, "api://my-api").await?;
+/// println!("{:#?}", token_data.claims.oid);
+/// ```
+#[derive(Clone)]
+pub struct OpenIdDiscovery {
+    configuration_uri: Url,
+    cache_duration: Duration,
+    allowed_tenants: Vec<String>,
+    cache: Arc<RwLock<DiscoveryCache>>,
+    http_client: OnceLock<reqwest::Client>,
+    blocking_http_client: OnceLock<reqwest::blocking::Client>,
+}
+
+impl OpenIdDiscovery {
+    pub fn new(
+        azure_cloud_instance: AzureCloudInstance,
+        authority: impl Into<Authority>,
+    ) -> IdentityResult<OpenIdDiscovery> {
+        let configuration_uri = azure_cloud_instance.openid_configuration_uri(&authority.into())?;
+        Ok(OpenIdDiscovery::from_configuration_uri(configuration_uri))
+    }
+
+    /// Use the discovery document at the given url, such as the
+    /// `.well-known/openid-configuration` url of an Azure AD B2C policy.
+    pub fn from_configuration_uri(configuration_uri: Url) -> OpenIdDiscovery {
+        OpenIdDiscovery {
+            configuration_uri,
+            cache_duration: DEFAULT_CACHE_DURATION,
+            allowed_tenants: Vec::new(),
+            cache: Default::default(),
+            http_client: OnceLock::new(),
+            blocking_http_client: OnceLock::new(),
+        }
+    }
+
+    /// The tenants that are allowed to issue tokens when the authority is the common
+    /// or organizations authority, such as the tenants that have signed up for a
+    /// multi-tenant API. Tokens from other tenants are rejected.
+    pub fn with_allowed_tenants<T: ToString, I: IntoIterator<Item = T>>(
+        mut self,
+        tenant_ids: I,
+    ) -> Self {
+        self.allowed_tenants = tenant_ids.into_iter().map(|t| t.to_string()).collect();
+        self
+    }
+
+    /// Use the given http client, such as the client of the application, to request
+    /// the configuration and signing keys asynchronously.
+    pub fn with_http_client(self, http_client: reqwest::Client) -> Self {
+        let _ = self.http_client.set(http_client);
+        self
+    }
+
+    /// How long the configuration and signing keys are cached. The default is 24 hours.
+    pub fn with_cache_duration(mut self, cache_duration: Duration) -> Self {
+        self.cache_duration = cache_duration;
+        self
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn configuration(&self) -> AuthExecutionResult<Arc<OpenIdConfiguration>> {
+        if let Some(configuration) = self.cached_configuration() {
+            return Ok(configuration);
+        }
+
+        tracing::debug!(target: CREDENTIAL_EXECUTOR, "requesting openid configuration");
+        let configuration = self.get(&self.configuration_uri)?;
+        Ok(self.store_configuration(configuration))
+    }
+
+    pub async fn configuration_async(&self) -> AuthExecutionResult<Arc<OpenIdConfiguration>> {
+        if let Some(configuration) = self.cached_configuration() {
+            return Ok(configuration);
+        }
+
+        tracing::debug!(target: CREDENTIAL_EXECUTOR, "requesting openid configuration");
+        let configuration = self.get_async(&self.configuration_uri).await?;
+        Ok(self.store_configuration(configuration))
+    }
+
+    /// The signing keys from the `jwks_uri` of the configuration.
+    #[allow(clippy::result_large_err)]
+    pub fn key_set(&self) -> AuthExecutionResult<Arc<JwksKeySet>> {
+        match self.cached_key_set(self.cache_duration) {
+            Some(key_set) => Ok(key_set),
+            None => self.fetch_key_set(),
+        }
+    }
+
+    pub async fn key_set_async(&self) -> AuthExecutionResult<Arc<JwksKeySet>> {
+        match self.cached_key_set(self.cache_duration) {
+            Some(key_set) => Ok(key_set),
+            None => self.fetch_key_set_async().await,
+        }
+    }
+
+    /// The signing key with the given key id. The key set is requested again when the
+    /// key is not in the cached key set, at most once every five minutes.
+    #[allow(clippy::result_large_err)]
+    pub fn key(&self, kid: &str) -> AuthExecutionResult<JwksKey> {
+        if let Some(key) = OpenIdDiscovery::find_key(self.key_set()?, kid) {
+            return Ok(key);
+        }
+        if self.cached_key_set(MIN_KEY_REFRESH_INTERVAL).is_none() {
+            if let Some(key) = OpenIdDiscovery::find_key(self.fetch_key_set()?, kid) {
+                return Ok(key);
+            }
+        }
+        Err(OpenIdDiscovery::key_not_found(kid))
+    }
+
+    pub async fn key_async(&self, kid: &str) -> AuthExecutionResult<JwksKey> {
+        if let Some(key) = OpenIdDiscovery::find_key(self.key_set_async().await?, kid) {
+            return Ok(key);
+        }
+        if self.cached_key_set(MIN_KEY_REFRESH_INTERVAL).is_none() {
+            if let Some(key) = OpenIdDiscovery::find_key(self.fetch_key_set_async().await?, kid) {
+                return Ok(key);
+            }
+        }
+        Err(OpenIdDiscovery::key_not_found(kid))
+    }
+
+    /// Validate the signature, issuer, audience and lifetime of a token signed with RS256
+    /// by the Microsoft identity platform and return the decoded token.
+    #[allow(clippy::result_large_err)]
+    pub fn validate_token(
+        &self,
+        token: &str,
+        audience: &str,
+    ) -> AuthExecutionResult<TokenData<Claims>> {
+        let kid = OpenIdDiscovery::kid(token)?;
+        let key = self.key(kid.as_str())?;
+        let configuration = self.configuration()?;
+        self.decode(token, audience, &key, &configuration)
+    }
+
+    pub async fn validate_token_async(
+        &self,
+        token: &str,
+        audience: &str,
+    ) -> AuthExecutionResult<TokenData<Claims>> {
+        let kid = OpenIdDiscovery::kid(token)?;
+        let key = self.key_async(kid.as_str()).await?;
+        let configuration = self.configuration_async().await?;
+        self.decode(token, audience, &key, &configuration)
+    }
+
+    fn cached_configuration(&self) -> Option<Arc<OpenIdConfiguration>> {
+        let cache = self.cache.read().ok()?;
+        let cached = cache.configuration.as_ref()?;
+        (cached.fetched.elapsed() < self.cache_duration).then(|| cached.value.clone())
+    }
+
+    fn store_configuration(&self, configuration: OpenIdConfiguration) -> Arc<OpenIdConfiguration> {
+        let configuration = Arc::new(configuration);
+        if let Ok(mut cache) = self.cache.write() {
+            cache.configuration = Some(Cached {
+                value: configuration.clone(),
+                fetched: Instant::now(),
+            });
+        }
+        configuration
+    }
+
+    /// The cached key set if it was requested within the given duration.
+    fn cached_key_set(&self, max_age: Duration) -> Option<Arc<JwksKeySet>> {
+        let cache = self.cache.read().ok()?;
+        let cached = cache.key_set.as_ref()?;
+        (cached.fetched.elapsed() < max_age).then(|| cached.value.clone())
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn fetch_key_set(&self) -> AuthExecutionResult<Arc<JwksKeySet>> {
+        let configuration = self.configuration()?;
+        tracing::debug!(target: CREDENTIAL_EXECUTOR, "requesting json web key set");
+        let key_set = self.get(&configuration.jwks_uri)?;
+        Ok(self.store_key_set(key_set))
+    }
+
+    async fn fetch_key_set_async(&self) -> AuthExecutionResult<Arc<JwksKeySet>> {
+        let configuration = self.configuration_async().await?;
+        tracing::debug!(target: CREDENTIAL_EXECUTOR, "requesting json web key set");
+        let key_set = self.get_async(&configuration.jwks_uri).await?;
+        Ok(self.store_key_set(key_set))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn http_client(&self) -> AuthExecutionResult<&reqwest::Client> {
+        if let Some(http_client) = self.http_client.get() {
+            return Ok(http_client);
+        }
+        let http_client = reqwest::ClientBuilder::new()
+            .min_tls_version(Version::TLS_1_2)
+            .https_only(true)
+            .build()?;
+        Ok(self.http_client.get_or_init(|| http_client))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn blocking_http_client(&self) -> AuthExecutionResult<&reqwest::blocking::Client> {
+        if let Some(http_client) = self.blocking_http_client.get() {
+            return Ok(http_client);
+        }
+        let http_client = reqwest::blocking::ClientBuilder::new()
+            .min_tls_version(Version::TLS_1_2)
+            .https_only(true)
+            .build()?;
+        Ok(self.blocking_http_client.get_or_init(|| http_client))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn get<T: DeserializeOwned>(&self, url: &Url) -> AuthExecutionResult<T> {
+        let response = self.blocking_http_client()?.get(url.clone()).send()?;
+        if !response.status().is_success() {
+            return Err(AuthExecutionError::error_response(
+                format!("request to {url} failed"),
+                response.into_http_response()?,
+            ));
+        }
+        Ok(response.json()?)
+    }
+
+    async fn get_async<T: DeserializeOwned>(&self, url: &Url) -> AuthExecutionResult<T> {
+        let response = self.http_client()?.get(url.clone()).send().await?;
+        if !response.status().is_success() {
+            return Err(AuthExecutionError::error_response(
+                format!("request to {url} failed"),
+                response.into_http_response_async().await?,
+            ));
+        }
+        Ok(response.json().await?)
+    }
+
+    fn store_key_set(&self, key_set: JwksKeySet) -> Arc<JwksKeySet> {
+        let key_set = Arc::new(key_set);
+        if let Ok(mut cache) = self.cache.write() {
+            cache.key_set = Some(Cached {
+                value: key_set.clone(),
+                fetched: Instant::now(),
+            });
+        }
+        key_set
+    }
+
+    fn find_key(key_set: Arc<JwksKeySet>, kid: &str) -> Option<JwksKey> {
+        key_set.keys.iter().find(|key| key.kid.eq(kid)).cloned()
+    }
+
+    fn key_not_found(kid: &str) -> AuthExecutionError {
+        AF::msg_err(
+            "kid",
+            format!("no signing key found with key id {kid}").as_str(),
+        )
+        .into()
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn kid(token: &str) -> AuthExecutionResult<String> {
+        jsonwebtoken::decode_header(token)?
+            .kid
+            .ok_or_else(|| AF::msg_err("kid", "the token header does not have a key id").into())
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn decode(
+        &self,
+        token: &str,
+        audience: &str,
+        key: &JwksKey,
+        configuration: &OpenIdConfiguration,
+    ) -> AuthExecutionResult<TokenData<Claims>> {
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.set_audience(&[audience]);
+        validation.set_issuer(&self.issuers(configuration)?);
+
+        let decoding_key = DecodingKey::from_rsa_components(&key.modulus, &key.exponent)?;
+        Ok(jsonwebtoken::decode::<Claims>(
+            token,
+            &decoding_key,
+            &validation,
+        )?)
+    }
+
+    // The v1.0 and v2.0 issuers of the tenant of the configuration, or of the allowed
+    // tenants for multi-tenant authorities.
+    #[allow(clippy::result_large_err)]
+    fn issuers(&self, configuration: &OpenIdConfiguration) -> AuthExecutionResult<Vec<String>> {
+        if !configuration.is_multi_tenant() {
+            let mut issuers = vec![configuration.issuer.clone()];
+            let tenant_id = Url::parse(&configuration.issuer)
+                .ok()
+                .and_then(|issuer| Some(issuer.path_segments()?.next()?.to_owned()));
+            if let Some(tenant_id) = tenant_id.filter(|tenant_id| !tenant_id.is_empty()) {
+                issuers.push(v1_issuer(&tenant_id));
+            }
+            return Ok(issuers);
+        }
+
+        if self.allowed_tenants.is_empty() {
+            return Err(AF::msg_err(
+                "issuer",
+                "set the tenants allowed to issue tokens using with_allowed_tenants",
+            )
+            .into());
+        }
+        Ok(self
+            .allowed_tenants
+            .iter()
+            .flat_map(|tenant_id| {
+                [
+                    configuration.issuer_for_tenant(tenant_id),
+                    v1_issuer(tenant_id),
+                ]
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_configuration() {
+        let configuration: OpenIdConfiguration = serde_json::from_value(serde_json::json!({
+            "token_endpoint": "https://login.microsoftonline.com/common/oauth2/v2.0/token",
+            "jwks_uri": "https://login.microsoftonline.com/common/discovery/v2.0/keys",
+            "response_modes_supported": ["query", "fragment", "form_post"],
+            "issuer": "https://login.microsoftonline.com/{tenantid}/v2.0",
+            "authorization_endpoint": "https://login.microsoftonline.com/common/oauth2/v2.0/authorize",
+            "tenant_region_scope": null,
+            "cloud_instance_name": "microsoftonline.com",
+            "msgraph_host": "graph.microsoft.com",
+            "rbac_url": "https://pas.windows.net"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            "https://login.microsoftonline.com/common/discovery/v2.0/keys",
+            configuration.jwks_uri.as_str()
+        );
+        assert_eq!(
+            Some("graph.microsoft.com"),
+            configuration.msgraph_host.as_deref()
+        );
+        assert_eq!(
+            "https://login.microsoftonline.com/tenant-id/v2.0",
+            configuration.issuer_for_tenant("tenant-id")
+        );
+        assert!(configuration.additional_fields.contains_key("rbac_url"));
+    }
+
+    fn configuration(issuer: &str) -> OpenIdConfiguration {
+        serde_json::from_value(serde_json::json!({
+            "issuer": issuer,
+            "authorization_endpoint": "https://login.microsoftonline.com/common/oauth2/v2.0/authorize",
+            "token_endpoint": "https://login.microsoftonline.com/common/oauth2/v2.0/token",
+            "jwks_uri": "https://login.microsoftonline.com/common/discovery/v2.0/keys"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn issuers_of_configured_tenant() {
+        let discovery = OpenIdDiscovery::new(AzureCloudInstance::AzurePublic, "tenant-id").unwrap();
+        assert_eq!(
+            vec![
+                "https://login.microsoftonline.com/tenant-id/v2.0".to_owned(),
+                "https://sts.windows.net/tenant-id/".to_owned()
+            ],
+            discovery
+                .issuers(&configuration(
+                    "https://login.microsoftonline.com/tenant-id/v2.0"
+                ))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn issuers_of_allowed_tenants() {
+        let multi_tenant = configuration("https://login.microsoftonline.com/{tenantid}/v2.0");
+        let discovery = OpenIdDiscovery::new(AzureCloudInstance::AzurePublic, "common").unwrap();
+        assert!(discovery.issuers(&multi_tenant).is_err());
+
+        let discovery = discovery.with_allowed_tenants(["tenant-a"]);
+        assert_eq!(
+            vec![
+                "https://login.microsoftonline.com/tenant-a/v2.0".to_owned(),
+                "https://sts.windows.net/tenant-a/".to_owned()
+            ],
+            discovery.issuers(&multi_tenant).unwrap()
+        );
+    }
+
+    #[test]
+    fn cached_key_lookup() {
+        let discovery = OpenIdDiscovery::new(AzureCloudInstance::AzurePublic, "tenant-id").unwrap();
+        assert_eq!(
+            "https://login.microsoftonline.com/tenant-id/v2.0/.well-known/openid-configuration",
+            discovery.configuration_uri.as_str()
+        );
+
+        let key_set: JwksKeySet = serde_json::from_value(serde_json::json!({
+            "keys": [{ "kty": "RSA", "use": "sig", "kid": "key-1", "n": "modulus", "e": "AQAB" }]
+        }))
+        .unwrap();
+        discovery.store_key_set(key_set);
+
+        assert_eq!("modulus", discovery.key("key-1").unwrap().modulus);
+        assert!(discovery.cached_key_set(MIN_KEY_REFRESH_INTERVAL).is_some());
+        // Keys are not requested again within the minimum refresh interval.
+        assert!(discovery.key("key-2").is_err());
+    }
+}
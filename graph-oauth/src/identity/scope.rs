@@ -0,0 +1,229 @@
+use std::fmt::{Display, Formatter};
+
+macro_rules! scopes {
+    ($($resource:ident {$($name:ident => $value:literal,)+})+) => {
+        /// The resource a Microsoft Graph permission grants access to.
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub enum ScopeResource {
+            $($resource,)+
+        }
+
+        impl ScopeResource {
+            /// All permissions for the resource.
+            pub fn scopes(&self) -> &'static [Scope] {
+                match self {
+                    $(ScopeResource::$resource => &[$(Scope::$name,)+],)+
+                }
+            }
+        }
+
+        /// Microsoft Graph permissions that can be used as scopes.
+        ///
+        /// Scopes can be passed to any method that accepts scopes, such as `with_scope`,
+        /// in place of strings.
+        ///
+        /// See [Microsoft Graph permissions reference](https://learn.microsoft.com/en-us/graph/permissions-reference)
+        ///
+        /// # Example
+        /// ```rust
+        /// use graph_oauth::{Scope, ScopeResource};
+        ///
+        /// assert_eq!("Files.Read.All", Scope::FilesReadAll.as_ref());
+        /// assert_eq!(ScopeResource::Mail, Scope::MailSend.resource());
+        /// ```
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub enum Scope {
+            $($($name,)+)+
+        }
+
+        impl Scope {
+            /// All permissions.
+            pub const ALL: &'static [Scope] = &[$($(Scope::$name,)+)+];
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($(Scope::$name => $value,)+)+
+                }
+            }
+
+            pub fn resource(&self) -> ScopeResource {
+                match self {
+                    $($(Scope::$name => ScopeResource::$resource,)+)+
+                }
+            }
+        }
+    };
+}
+
+scopes! {
+    OpenId {
+        OpenId => "openid",
+        Profile => "profile",
+        Email => "email",
+        OfflineAccess => "offline_access",
+    }
+    Application {
+        ApplicationReadAll => "Application.Read.All",
+        ApplicationReadWriteAll => "Application.ReadWrite.All",
+        ApplicationReadWriteOwnedBy => "Application.ReadWrite.OwnedBy",
+    }
+    AuditLog {
+        AuditLogReadAll => "AuditLog.Read.All",
+    }
+    Calendars {
+        CalendarsRead => "Calendars.Read",
+        CalendarsReadShared => "Calendars.Read.Shared",
+        CalendarsReadWrite => "Calendars.ReadWrite",
+        CalendarsReadWriteShared => "Calendars.ReadWrite.Shared",
+    }
+    Channel {
+        ChannelCreate => "Channel.Create",
+        ChannelDeleteAll => "Channel.Delete.All",
+        ChannelReadBasicAll => "Channel.ReadBasic.All",
+        ChannelMessageSend => "ChannelMessage.Send",
+        ChannelMessageReadAll => "ChannelMessage.Read.All",
+    }
+    Chat {
+        ChatCreate => "Chat.Create",
+        ChatRead => "Chat.Read",
+        ChatReadAll => "Chat.Read.All",
+        ChatReadBasic => "Chat.ReadBasic",
+        ChatReadWrite => "Chat.ReadWrite",
+        ChatReadWriteAll => "Chat.ReadWrite.All",
+        ChatMessageSend => "ChatMessage.Send",
+    }
+    Contacts {
+        ContactsRead => "Contacts.Read",
+        ContactsReadShared => "Contacts.Read.Shared",
+        ContactsReadWrite => "Contacts.ReadWrite",
+        ContactsReadWriteShared => "Contacts.ReadWrite.Shared",
+    }
+    Device {
+        DeviceRead => "Device.Read",
+        DeviceReadAll => "Device.Read.All",
+        DeviceReadWriteAll => "Device.ReadWrite.All",
+    }
+    Directory {
+        DirectoryReadAll => "Directory.Read.All",
+        DirectoryReadWriteAll => "Directory.ReadWrite.All",
+        DirectoryAccessAsUserAll => "Directory.AccessAsUser.All",
+    }
+    Files {
+        FilesRead => "Files.Read",
+        FilesReadAll => "Files.Read.All",
+        FilesReadSelected => "Files.Read.Selected",
+        FilesReadWrite => "Files.ReadWrite",
+        FilesReadWriteAll => "Files.ReadWrite.All",
+        FilesReadWriteAppFolder => "Files.ReadWrite.AppFolder",
+        FilesReadWriteSelected => "Files.ReadWrite.Selected",
+    }
+    Group {
+        GroupCreate => "Group.Create",
+        GroupReadAll => "Group.Read.All",
+        GroupReadWriteAll => "Group.ReadWrite.All",
+        GroupMemberReadAll => "GroupMember.Read.All",
+        GroupMemberReadWriteAll => "GroupMember.ReadWrite.All",
+    }
+    Mail {
+        MailRead => "Mail.Read",
+        MailReadBasic => "Mail.ReadBasic",
+        MailReadBasicAll => "Mail.ReadBasic.All",
+        MailReadShared => "Mail.Read.Shared",
+        MailReadWrite => "Mail.ReadWrite",
+        MailReadWriteShared => "Mail.ReadWrite.Shared",
+        MailSend => "Mail.Send",
+        MailSendShared => "Mail.Send.Shared",
+        MailboxSettingsRead => "MailboxSettings.Read",
+        MailboxSettingsReadWrite => "MailboxSettings.ReadWrite",
+    }
+    Notes {
+        NotesCreate => "Notes.Create",
+        NotesRead => "Notes.Read",
+        NotesReadAll => "Notes.Read.All",
+        NotesReadWrite => "Notes.ReadWrite",
+        NotesReadWriteAll => "Notes.ReadWrite.All",
+    }
+    People {
+        PeopleRead => "People.Read",
+        PeopleReadAll => "People.Read.All",
+    }
+    Policy {
+        PolicyReadAll => "Policy.Read.All",
+        PolicyReadWriteConditionalAccess => "Policy.ReadWrite.ConditionalAccess",
+    }
+    Presence {
+        PresenceRead => "Presence.Read",
+        PresenceReadAll => "Presence.Read.All",
+        PresenceReadWrite => "Presence.ReadWrite",
+    }
+    Reports {
+        ReportsReadAll => "Reports.Read.All",
+    }
+    RoleManagement {
+        RoleManagementReadDirectory => "RoleManagement.Read.Directory",
+        RoleManagementReadWriteDirectory => "RoleManagement.ReadWrite.Directory",
+    }
+    Sites {
+        SitesReadAll => "Sites.Read.All",
+        SitesReadWriteAll => "Sites.ReadWrite.All",
+        SitesManageAll => "Sites.Manage.All",
+        SitesFullControlAll => "Sites.FullControl.All",
+        SitesSelected => "Sites.Selected",
+    }
+    Tasks {
+        TasksRead => "Tasks.Read",
+        TasksReadShared => "Tasks.Read.Shared",
+        TasksReadWrite => "Tasks.ReadWrite",
+        TasksReadWriteShared => "Tasks.ReadWrite.Shared",
+    }
+    Team {
+        TeamCreate => "Team.Create",
+        TeamReadBasicAll => "Team.ReadBasic.All",
+        TeamMemberReadAll => "TeamMember.Read.All",
+        TeamMemberReadWriteAll => "TeamMember.ReadWrite.All",
+        TeamSettingsReadAll => "TeamSettings.Read.All",
+        TeamSettingsReadWriteAll => "TeamSettings.ReadWrite.All",
+    }
+    User {
+        UserRead => "User.Read",
+        UserReadBasicAll => "User.ReadBasic.All",
+        UserReadAll => "User.Read.All",
+        UserReadWrite => "User.ReadWrite",
+        UserReadWriteAll => "User.ReadWrite.All",
+        UserInviteAll => "User.Invite.All",
+    }
+}
+
+impl AsRef<str> for Scope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<Scope> for String {
+    fn from(value: Scope) -> Self {
+        value.as_str().to_owned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scopes_grouped_by_resource() {
+        assert!(ScopeResource::Files
+            .scopes()
+            .iter()
+            .all(|scope| scope.as_str().starts_with("Files.")));
+        assert!(Scope::ALL
+            .iter()
+            .all(|scope| scope.resource().scopes().contains(scope)));
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::{Debug, Formatter};
+
+/// A string holding a secret, such as a client secret, client assertion, password,
+/// refresh token, or access token. The secret is not included in the [Debug]
+/// output so that it is not written to logs, and is only returned by
+/// [SecretString::expose_secret].
+///
+/// With the `zeroize` feature the memory of the secret is overwritten with zeros
+/// when the [SecretString] is dropped.
+///
+/// # Example
+/// ```rust
+/// # use graph_oauth::SecretString;
+/// let client_secret = SecretString::new("secret");
+/// assert_eq!("[REDACTED]", format!("{client_secret:?}"));
+/// assert_eq!("secret", client_secret.expose_secret());
+/// ```
+#[derive(Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SecretString(String);
+
+impl SecretString {
+    pub fn new(secret: impl Into<String>) -> SecretString {
+        SecretString(secret.into())
+    }
+
+    /// The secret. Take care not to log or otherwise output the returned value.
+    pub fn expose_secret(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Debug for SecretString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        SecretString(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        SecretString(value.to_owned())
+    }
+}
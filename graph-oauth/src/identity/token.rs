@@ -16,14 +16,38 @@ fn deserialize_scope<'de, D>(scope: D) -> Result<Vec<String>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let scope_string: Result<String, D::Error> = serde::Deserialize::deserialize(scope);
-    if let Ok(scope) = scope_string {
-        Ok(scope.split(' ').map(|scope| scope.to_owned()).collect())
-    } else {
-        Ok(vec![])
+    // The token endpoint returns a space separated string and a stored token an array.
+    let scope: Option<Value> = serde::Deserialize::deserialize(scope)?;
+    match scope {
+        Some(Value::String(scope)) => Ok(scope.split(' ').map(|scope| scope.to_owned()).collect()),
+        Some(Value::Array(scope)) => Ok(scope
+            .iter()
+            .filter_map(|scope| scope.as_str().map(|scope| scope.to_owned()))
+            .collect()),
+        _ => Ok(vec![]),
     }
 }
 
+/// Deserialize a point in time that is either a unix timestamp, as a number or string,
+/// such as the `expires_on` and `not_before` fields of the v1.0 token endpoint, or a
+/// timestamp serialized by [Token] when the token is stored.
+fn deserialize_timestamp<'de, D>(timestamp: D) -> Result<Option<OffsetDateTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<Value> = Deserialize::deserialize(timestamp)?;
+    Ok(value.and_then(|value| match value {
+        Value::Number(number) => number
+            .as_i64()
+            .and_then(|unix| OffsetDateTime::from_unix_timestamp(unix).ok()),
+        Value::String(string) => match string.parse::<i64>() {
+            Ok(unix) => OffsetDateTime::from_unix_timestamp(unix).ok(),
+            Err(_) => serde_json::from_value(Value::String(string)).ok(),
+        },
+        value => serde_json::from_value(value).ok(),
+    }))
+}
+
 // Used to set timestamp based on expires in
 // which can only be done after deserialization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +57,8 @@ struct PhantomToken {
     #[serde(deserialize_with = "deserialize_number_from_string")]
     expires_in: i64,
     /// Legacy version of expires_in
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_option_number_from_string")]
     ext_expires_in: Option<i64>,
     #[serde(default)]
     #[serde(deserialize_with = "deserialize_scope")]
@@ -45,6 +71,18 @@ struct PhantomToken {
     nonce: Option<String>,
     correlation_id: Option<String>,
     client_info: Option<String>,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    timestamp: Option<OffsetDateTime>,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    expires_on: Option<OffsetDateTime>,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    ext_expires_on: Option<OffsetDateTime>,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    not_before: Option<OffsetDateTime>,
     #[serde(flatten)]
     additional_fields: HashMap<String, Value>,
 }
@@ -115,7 +153,16 @@ pub struct Token {
     pub correlation_id: Option<String>,
     pub client_info: Option<String>,
     pub timestamp: Option<time::OffsetDateTime>,
+    /// The point in time the access token expires, computed from `expires_in` when
+    /// the token response is received.
     pub expires_on: Option<time::OffsetDateTime>,
+    /// The point in time the access token expires when the token service is unavailable,
+    /// computed from `ext_expires_in`. A cached token is used until this time when
+    /// refreshing the token fails because of an outage of the token service.
+    pub ext_expires_on: Option<time::OffsetDateTime>,
+    /// The point in time before which the access token is not valid, returned by the
+    /// v1.0 token endpoint.
+    pub not_before: Option<time::OffsetDateTime>,
     /// Any extra returned fields for AccessToken.
     #[serde(flatten)]
     pub additional_fields: HashMap<String, Value>,
@@ -149,6 +196,8 @@ impl Token {
             client_info: None,
             timestamp: Some(timestamp),
             expires_on: Some(expires_on),
+            ext_expires_on: None,
+            not_before: None,
             additional_fields: Default::default(),
             log_pii: false,
         }
@@ -179,9 +228,7 @@ impl Token {
     /// ```
     pub fn with_expires_in(&mut self, expires_in: i64) -> &mut Self {
         self.expires_in = expires_in;
-        let timestamp = time::OffsetDateTime::now_utc();
-        self.expires_on = Some(timestamp.add(time::Duration::seconds(self.expires_in)));
-        self.timestamp = Some(timestamp);
+        self.gen_timestamp();
         self
     }
 
@@ -324,6 +371,31 @@ impl Token {
         let expires_on = timestamp.add(time::Duration::seconds(self.expires_in));
         self.timestamp = Some(timestamp);
         self.expires_on = Some(expires_on);
+        self.ext_expires_on = self
+            .ext_expires_in
+            .map(|ext_expires_in| timestamp.add(time::Duration::seconds(ext_expires_in)));
+    }
+
+    /// The point in time the access token expires.
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::Token;
+    ///
+    /// let access_token = Token::new("Bearer", 3600, "ASODFIUJ34KJ;LADSK", vec!["User.Read"]);
+    /// assert!(access_token.expires_at().is_some());
+    /// ```
+    pub fn expires_at(&self) -> Option<OffsetDateTime> {
+        self.expires_on
+    }
+
+    /// Check whether the extended lifetime of the access token, `ext_expires_in`, has
+    /// passed. Tokens without an extended lifetime use [Token::is_expired].
+    pub fn is_ext_expired(&self) -> bool {
+        match self.ext_expires_on.as_ref() {
+            Some(ext_expires_on) => ext_expires_on.lt(&OffsetDateTime::now_utc()),
+            None => self.is_expired(),
+        }
     }
 
     /// Check whether the access token is expired. Checks if expires_on timestamp
@@ -435,6 +507,8 @@ impl Default for Token {
             expires_on: Some(
                 OffsetDateTime::from_unix_timestamp(0).unwrap_or(time::OffsetDateTime::UNIX_EPOCH),
             ),
+            ext_expires_on: None,
+            not_before: None,
             additional_fields: Default::default(),
             log_pii: false,
         }
@@ -465,6 +539,8 @@ impl TryFrom<AuthorizationResponse> for Token {
             client_info: None,
             timestamp: None,
             expires_on: None,
+            ext_expires_on: None,
+            not_before: None,
             additional_fields: Default::default(),
             log_pii: false,
         })
@@ -533,6 +609,7 @@ impl fmt::Debug for Token {
                 .field("state", &self.state)
                 .field("timestamp", &self.timestamp)
                 .field("expires_on", &self.expires_on)
+                .field("ext_expires_on", &self.ext_expires_on)
                 .field("additional_fields", &self.additional_fields)
                 .finish()
         } else {
@@ -556,12 +633,32 @@ impl fmt::Debug for Token {
                 .field("state", &self.state)
                 .field("timestamp", &self.timestamp)
                 .field("expires_on", &self.expires_on)
+                .field("ext_expires_on", &self.ext_expires_on)
                 .field("additional_fields", &self.additional_fields)
                 .finish()
         }
     }
 }
 
+/// Overwrite the access, refresh, and id tokens with zeros. Use
+/// [`zeroize::Zeroizing<Token>`](zeroize::Zeroizing) to overwrite them when the
+/// token is dropped.
+///
+/// # Example
+/// ```rust,ignore
+/// let token = zeroize::Zeroizing::new(credential.get_token()?);
+/// ```
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Token {
+    fn zeroize(&mut self) {
+        self.access_token.zeroize();
+        self.refresh_token.zeroize();
+        if let Some(id_token) = self.id_token.as_mut() {
+            id_token.zeroize();
+        }
+    }
+}
+
 impl AsRef<str> for Token {
     fn as_ref(&self) -> &str {
         self.access_token.as_str()
@@ -574,8 +671,26 @@ impl<'de> Deserialize<'de> for Token {
         D: Deserializer<'de>,
     {
         let phantom_access_token: PhantomToken = Deserialize::deserialize(deserializer)?;
-        let timestamp = OffsetDateTime::now_utc();
-        let expires_on = timestamp.add(time::Duration::seconds(phantom_access_token.expires_in));
+        if phantom_access_token.expires_in < 0
+            || phantom_access_token.ext_expires_in.unwrap_or_default() < 0
+        {
+            return Err(serde::de::Error::custom(
+                "expires_in and ext_expires_in must not be negative",
+            ));
+        }
+
+        // Stored tokens keep the expiry computed when the token response was received.
+        let timestamp = phantom_access_token
+            .timestamp
+            .unwrap_or_else(OffsetDateTime::now_utc);
+        let expires_on = phantom_access_token.expires_on.unwrap_or_else(|| {
+            timestamp.add(time::Duration::seconds(phantom_access_token.expires_in))
+        });
+        let ext_expires_on = phantom_access_token.ext_expires_on.or_else(|| {
+            phantom_access_token
+                .ext_expires_in
+                .map(|ext_expires_in| timestamp.add(time::Duration::seconds(ext_expires_in)))
+        });
         let id_token = phantom_access_token
             .id_token
             .map(|id_token_string| IdToken::new(id_token_string.as_ref(), None, None, None));
@@ -596,6 +711,8 @@ impl<'de> Deserialize<'de> for Token {
             client_info: phantom_access_token.client_info,
             timestamp: Some(timestamp),
             expires_on: Some(expires_on),
+            ext_expires_on,
+            not_before: phantom_access_token.not_before,
             additional_fields: phantom_access_token.additional_fields,
             log_pii: false,
         };
@@ -653,6 +770,47 @@ mod test {
         let _token: Token = serde_json::from_str(ACCESS_TOKEN_STRING).unwrap();
     }
 
+    #[test]
+    pub fn deserialize_expiry_timestamps() {
+        let token: Token = serde_json::from_str(
+            r#"{
+            "access_token": "token",
+            "token_type": "Bearer",
+            "expires_in": "3599",
+            "ext_expires_in": "7199",
+            "not_before": "1700000000"
+        }"#,
+        )
+        .unwrap();
+        let timestamp = token.timestamp.unwrap();
+        assert_eq!(
+            Some(timestamp + time::Duration::seconds(3599)),
+            token.expires_at()
+        );
+        assert_eq!(
+            Some(timestamp + time::Duration::seconds(7199)),
+            token.ext_expires_on
+        );
+        assert_eq!(
+            OffsetDateTime::from_unix_timestamp(1700000000).ok(),
+            token.not_before
+        );
+        assert!(!token.is_ext_expired());
+
+        // A stored token keeps its expiry instead of starting over from now.
+        let mut expired = Token::new("Bearer", 3600, "token", vec!["User.Read"]);
+        expired.expires_on = Some(OffsetDateTime::now_utc() - time::Duration::seconds(60));
+        let stored: Token =
+            serde_json::from_str(&serde_json::to_string(&expired).unwrap()).unwrap();
+        assert!(stored.is_expired());
+        assert_eq!(expired.expires_at(), stored.expires_at());
+
+        assert!(serde_json::from_str::<Token>(
+            r#"{"access_token": "token", "token_type": "Bearer", "expires_in": -1}"#
+        )
+        .is_err());
+    }
+
     #[test]
     pub fn try_from_url_authorization_response() {
         let authorization_response = AuthorizationResponse {
@@ -685,4 +843,19 @@ mod test {
         assert_eq!(token.session_state, Some("session_state".to_string()));
         assert_eq!(token.expires_in, 3600);
     }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize_token() {
+        let mut token = Token::new("Bearer", 3600, "token", vec!["User.Read"]);
+        token.refresh_token = Some("refresh_token".into());
+
+        // Fields can still be moved out of a token with the zeroize feature.
+        let refresh_token = token.clone().refresh_token;
+        assert_eq!(Some("refresh_token".to_string()), refresh_token);
+
+        zeroize::Zeroize::zeroize(&mut token);
+        assert!(token.access_token.is_empty());
+        assert_eq!(None, token.refresh_token);
+    }
 }
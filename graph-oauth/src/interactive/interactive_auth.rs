@@ -8,7 +8,7 @@ use tao::event_loop::{ControlFlow, EventLoop, EventLoopBuilder, EventLoopProxy};
 use tao::platform::run_return::EventLoopExtRunReturn;
 use tao::window::{Window, WindowBuilder};
 use url::Url;
-use wry::WebView;
+use wry::{WebContext, WebView};
 
 #[cfg(target_family = "unix")]
 use tao::platform::unix::EventLoopBuilderExtUnix;
@@ -58,6 +58,7 @@ where
         host_options: HostOptions,
         window: &Window,
         proxy: EventLoopProxy<UserEvents>,
+        web_context: &mut WebContext,
     ) -> anyhow::Result<WebView>;
 
     fn run(
@@ -70,7 +71,10 @@ where
         let proxy = event_loop.create_proxy();
         let window = Self::window_builder(&options).build(&event_loop).unwrap();
         let host_options = HostOptions::new(start_url, redirect_uris, options.ports.clone());
-        let webview = Self::webview(host_options, &window, proxy)?;
+        // Browsing data such as cookies is stored in the session data directory
+        // when set so that returning users are signed in using SSO.
+        let mut web_context = WebContext::new(options.webview_data_directory());
+        let webview = Self::webview(host_options, &window, proxy, &mut web_context)?;
 
         event_loop.run_return(move |event, _, control_flow| {
             if let Some(timeout) = options.timeout.as_ref() {
@@ -1,4 +1,5 @@
 mod interactive_auth;
+mod token_broker;
 mod webview_authorization_event;
 mod webview_host_validator;
 mod webview_options;
@@ -7,6 +8,8 @@ mod with_interactive_auth;
 #[allow(unused_imports)]
 pub use webview_host_validator::*;
 
+pub(crate) use token_broker::*;
+
 pub use interactive_auth::*;
 pub use webview_authorization_event::*;
 pub use webview_options::*;
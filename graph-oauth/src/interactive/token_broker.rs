@@ -0,0 +1,112 @@
+use crate::identity::{AppConfig, AuthorizationResponse};
+
+/// Request a token silently from the Windows Web Account Manager (WAM) broker
+/// for the account the user signed in to Windows with.
+///
+/// Returns `None` when the broker is not available, such as on other platforms
+/// or when the `broker` feature is not enabled, or when the broker cannot return
+/// a token without prompting the user. The webview is used in that case.
+#[cfg(all(windows, feature = "broker"))]
+pub(crate) fn broker_token_silent(app_config: &AppConfig) -> Option<AuthorizationResponse> {
+    use crate::identity::tracing_targets::INTERACTIVE_AUTH;
+
+    match wam::get_token_silently(app_config) {
+        Ok(authorization_response) => authorization_response,
+        Err(err) => {
+            tracing::debug!(target: INTERACTIVE_AUTH, "token broker unavailable: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(not(all(windows, feature = "broker")))]
+pub(crate) fn broker_token_silent(_app_config: &AppConfig) -> Option<AuthorizationResponse> {
+    None
+}
+
+#[cfg(all(windows, feature = "broker"))]
+mod wam {
+    use std::collections::HashMap;
+
+    use windows::core::HSTRING;
+    use windows::Security::Authentication::Web::Core::{
+        WebAuthenticationCoreManager, WebTokenRequest, WebTokenRequestPromptType,
+        WebTokenRequestStatus,
+    };
+
+    use crate::identity::tracing_targets::INTERACTIVE_AUTH;
+    use crate::identity::{AppConfig, Authority, AuthorizationResponse};
+
+    /// The account provider of Microsoft work, school and personal accounts.
+    const MICROSOFT_ACCOUNT_PROVIDER: &str = "https://login.microsoft.com";
+
+    pub(super) fn get_token_silently(
+        app_config: &AppConfig,
+    ) -> windows::core::Result<Option<AuthorizationResponse>> {
+        let authority = match app_config.tenant_id.as_ref() {
+            Some(tenant_id) => tenant_id.clone(),
+            None => match &app_config.authority {
+                Authority::Consumers => "consumers".to_owned(),
+                Authority::TenantId(tenant_id) => tenant_id.clone(),
+                _ => "organizations".to_owned(),
+            },
+        };
+
+        let provider = WebAuthenticationCoreManager::FindAccountProviderWithAuthorityAsync(
+            &HSTRING::from(MICROSOFT_ACCOUNT_PROVIDER),
+            &HSTRING::from(authority),
+        )?
+        .get()?;
+
+        let scope = app_config
+            .scope
+            .iter()
+            .map(|scope| scope.as_str())
+            .collect::<Vec<&str>>()
+            .join(" ");
+        let request = WebTokenRequest::CreateWithPromptType(
+            &provider,
+            &HSTRING::from(scope),
+            &HSTRING::from(app_config.client_id.to_string()),
+            WebTokenRequestPromptType::Default,
+        )?;
+        // Request v2 tokens using scopes instead of resources.
+        request
+            .Properties()?
+            .Insert(&HSTRING::from("wam_compat"), &HSTRING::from("2.0"))?;
+
+        let result = WebAuthenticationCoreManager::GetTokenSilentlyAsync(&request)?.get()?;
+        let status = result.ResponseStatus()?;
+        if status != WebTokenRequestStatus::Success {
+            tracing::debug!(target: INTERACTIVE_AUTH, "token broker requires user interaction: {status:?}");
+            return Ok(None);
+        }
+
+        let response = result.ResponseData()?.GetAt(0)?;
+        let properties = response.Properties()?;
+        let property = |name: &str| {
+            properties
+                .Lookup(&HSTRING::from(name))
+                .ok()
+                .map(|value| value.to_string())
+        };
+        let expires_in = property("TokenExpiresOn")
+            .and_then(|expires_on| expires_on.parse::<i64>().ok())
+            .map(|expires_on| expires_on - time::OffsetDateTime::now_utc().unix_timestamp());
+
+        Ok(Some(AuthorizationResponse {
+            code: None,
+            id_token: property("wamcompat_id_token"),
+            expires_in,
+            access_token: Some(response.Token()?.to_string()),
+            state: None,
+            session_state: None,
+            nonce: None,
+            error: None,
+            error_description: None,
+            error_uri: None,
+            additional_fields: HashMap::new(),
+            log_pii: false,
+        }))
+    }
+}
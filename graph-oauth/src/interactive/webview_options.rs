@@ -1,8 +1,15 @@
+use graph_error::{WebViewError, WebViewResult};
 use std::collections::HashSet;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 use tao::window::Theme;
 use url::Url;
 
+// Subdirectory of the session data directory that the webview stores browsing
+// data in so that clearing the session never removes files of the caller.
+const WEBVIEW_DATA_DIRECTORY: &str = "graph-rs-webview";
+
 #[derive(Clone, Debug)]
 pub struct HostOptions {
     pub(crate) start_uri: Url,
@@ -57,6 +64,30 @@ pub struct WebViewOptions {
     ///
     /// Default is false
     pub clear_browsing_data: bool,
+    /// Sign in silently with the account the user signed in to Windows with using the
+    /// Web Account Manager (WAM) token broker before opening the webview. The webview
+    /// is used when the broker is not available or cannot sign in without prompting the user.
+    ///
+    /// Requires the `broker` feature and is only available on Windows.
+    ///
+    /// Default is false
+    pub use_broker: bool,
+    /// Redirect uris that the webview accepts in addition to the redirect uri of the
+    /// authorization url. Each must be registered in your application registration.
+    pub redirect_uris: Vec<Url>,
+    /// When the redirect uri is a loopback address such as http://localhost, pick a
+    /// free port and use it in the redirect uri of the authorization url and token request.
+    /// The Microsoft identity platform accepts any port for loopback redirect uris.
+    ///
+    /// Default is false
+    pub auto_select_port: bool,
+    /// Directory where the webview stores browsing data, such as cookies, between runs
+    /// so that returning users are signed in using SSO instead of entering their credentials.
+    /// The browsing data is stored in a `graph-rs-webview` subdirectory of this directory.
+    /// Use [WebViewOptions::clear_session] to sign the user out of the webview.
+    ///
+    /// Default is none and the browsing data is stored in the default location of the webview.
+    pub session_data_directory: Option<PathBuf>,
 }
 
 impl WebViewOptions {
@@ -99,6 +130,90 @@ impl WebViewOptions {
         self.clear_browsing_data = clear_browsing_data;
         self
     }
+
+    /// Sign in silently with the account the user signed in to Windows with using the
+    /// Web Account Manager (WAM) token broker before opening the webview. The webview
+    /// is used when the broker is not available or cannot sign in without prompting the user.
+    ///
+    /// Requires the `broker` feature and is only available on Windows.
+    pub fn use_broker(mut self, use_broker: bool) -> Self {
+        self.use_broker = use_broker;
+        self
+    }
+
+    /// Redirect uris that the webview accepts in addition to the redirect uri of the
+    /// authorization url. Each must be registered in your application registration.
+    pub fn redirect_uris<I: IntoIterator<Item = Url>>(mut self, redirect_uris: I) -> Self {
+        self.redirect_uris = redirect_uris.into_iter().collect();
+        self
+    }
+
+    /// When the redirect uri is a loopback address such as http://localhost, pick a
+    /// free port and use it in the redirect uri of the authorization url and token request.
+    pub fn auto_select_port(mut self, auto_select_port: bool) -> Self {
+        self.auto_select_port = auto_select_port;
+        self
+    }
+
+    /// Persist browsing data, such as cookies, to the directory so that returning users
+    /// are signed in using SSO instead of entering their credentials on every run.
+    ///
+    /// Browsing data is not cleared when the window closes when a session directory is set.
+    pub fn persist_session(mut self, session_data_directory: impl AsRef<Path>) -> Self {
+        self.session_data_directory = Some(session_data_directory.as_ref().to_path_buf());
+        self.clear_browsing_data = false;
+        self
+    }
+
+    /// Remove the browsing data persisted to the session data directory. The user
+    /// has to sign in again the next time the webview is opened.
+    ///
+    /// Only the `graph-rs-webview` subdirectory created by the webview is removed,
+    /// other files in the session data directory are left untouched.
+    pub fn clear_session(&self) -> std::io::Result<()> {
+        match self.webview_data_directory() {
+            Some(directory) if directory.exists() => std::fs::remove_dir_all(directory),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn webview_data_directory(&self) -> Option<PathBuf> {
+        self.session_data_directory
+            .as_ref()
+            .map(|directory| directory.join(WEBVIEW_DATA_DIRECTORY))
+    }
+
+    /// The redirect uri to use for sign in. A free port is set when
+    /// [auto_select_port](Self::auto_select_port) is enabled and the redirect uri is
+    /// a loopback address.
+    pub(crate) fn select_redirect_uri(&self, redirect_uri: &Url) -> WebViewResult<Url> {
+        if !self.auto_select_port || !is_loopback(redirect_uri) {
+            return Ok(redirect_uri.clone());
+        }
+
+        let port = TcpListener::bind(("127.0.0.1", 0))
+            .and_then(|listener| listener.local_addr())
+            .map_err(|err| {
+                WebViewError::InvalidUri(format!("unable to select a free port: {err}"))
+            })?
+            .port();
+        let mut redirect_uri = redirect_uri.clone();
+        redirect_uri.set_port(Some(port)).map_err(|_| {
+            WebViewError::InvalidUri(format!("unable to set port of {redirect_uri}"))
+        })?;
+        Ok(redirect_uri)
+    }
+
+    /// The redirect uri used for sign in followed by the allowed redirect uris.
+    pub(crate) fn allowed_redirect_uris(&self, redirect_uri: Url) -> Vec<Url> {
+        let mut redirect_uris = vec![redirect_uri];
+        for uri in self.redirect_uris.iter() {
+            if !redirect_uris.contains(uri) {
+                redirect_uris.push(uri.clone());
+            }
+        }
+        redirect_uris
+    }
 }
 
 #[cfg(windows)]
@@ -110,6 +225,10 @@ impl Default for WebViewOptions {
             ports: Default::default(),
             timeout: None,
             clear_browsing_data: Default::default(),
+            use_broker: Default::default(),
+            redirect_uris: Default::default(),
+            auto_select_port: Default::default(),
+            session_data_directory: None,
         }
     }
 }
@@ -122,6 +241,72 @@ impl Default for WebViewOptions {
             ports: Default::default(),
             timeout: None,
             clear_browsing_data: Default::default(),
+            use_broker: Default::default(),
+            redirect_uris: Default::default(),
+            auto_select_port: Default::default(),
+            session_data_directory: None,
         }
     }
 }
+
+fn is_loopback(uri: &Url) -> bool {
+    uri.scheme() == "http"
+        && match uri.host() {
+            Some(url::Host::Domain(domain)) => domain.eq_ignore_ascii_case("localhost"),
+            Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+            Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+            None => false,
+        }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn select_loopback_redirect_uri_port() {
+        let options = WebViewOptions::builder().auto_select_port(true);
+        let redirect_uri = options
+            .select_redirect_uri(&Url::parse("http://localhost/redirect").unwrap())
+            .unwrap();
+        assert_eq!(Some("localhost"), redirect_uri.host_str());
+        assert_eq!("/redirect", redirect_uri.path());
+        assert!(redirect_uri.port().is_some());
+
+        let https = Url::parse("https://example.com/redirect").unwrap();
+        assert_eq!(https, options.select_redirect_uri(&https).unwrap());
+
+        let options = WebViewOptions::builder().redirect_uris([https.clone()]);
+        let localhost = Url::parse("http://localhost:3000").unwrap();
+        assert_eq!(localhost, options.select_redirect_uri(&localhost).unwrap());
+        assert_eq!(
+            vec![localhost.clone(), https],
+            options.allowed_redirect_uris(localhost)
+        );
+    }
+
+    #[test]
+    fn clear_persisted_session() {
+        let directory =
+            std::env::temp_dir().join(format!("webview-session-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("settings.json"), "{}").unwrap();
+
+        let options = WebViewOptions::builder()
+            .clear_browsing_data_on_close(true)
+            .persist_session(&directory);
+        assert!(!options.clear_browsing_data);
+        let webview_data_directory = options.webview_data_directory().unwrap();
+        assert_eq!(
+            directory.join(WEBVIEW_DATA_DIRECTORY),
+            webview_data_directory
+        );
+        std::fs::create_dir_all(webview_data_directory.join("Cookies")).unwrap();
+
+        options.clear_session().unwrap();
+        assert!(!webview_data_directory.exists());
+        assert!(directory.join("settings.json").exists());
+        options.clear_session().unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -53,9 +53,8 @@ pub(crate) mod identity;
 #[cfg(feature = "interactive-auth")]
 pub mod interactive;
 
-pub(crate) mod internal {
-    pub use crate::oauth_serializer::*;
-}
+#[cfg(feature = "web-integrations")]
+pub mod web;
 
 pub mod extensions {
     pub use crate::oauth_serializer::*;
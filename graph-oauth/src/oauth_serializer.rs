@@ -681,6 +681,9 @@ impl AuthSerializer {
         Ok(serializer.finish())
     }
 
+    /// The parameters as a map of form fields. Returns an error when a required
+    /// parameter has not been set.
+    #[deprecated(note = "credentials build typed token request forms and no longer use this map")]
     pub fn as_credential_map(
         &mut self,
         optional_fields: Vec<AuthParameter>,
@@ -0,0 +1,83 @@
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::web::Data;
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError};
+
+use graph_error::AuthorizationFailure;
+
+use crate::identity::AuthorizationResponse;
+use crate::web::{AuthCodeRedirect, AuthCodeRedirectError, AuthCodeRedirectHandler};
+
+/// Extracts the [AuthorizationResponse] from the query of the redirect request.
+///
+/// The response is not validated. Use [AuthCodeRedirect] as the extractor to validate
+/// the state and redeem the authorization code.
+#[derive(Clone, Debug)]
+pub struct ActixAuthorizationRedirect(pub AuthorizationResponse);
+
+impl FromRequest for ActixAuthorizationRedirect {
+    type Error = AuthCodeRedirectError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(
+            AuthorizationResponse::from_query_or_fragment(req.query_string())
+                .map(ActixAuthorizationRedirect)
+                .map_err(AuthCodeRedirectError::from),
+        )
+    }
+}
+
+/// Validates the redirect request and redeems the authorization code using the
+/// [AuthCodeRedirectHandler] added to the app data with `App::app_data(Data::new(handler))`.
+///
+/// # Example
+/// ```rust,ignore
+/// use actix_web::{get, web::Data, App, HttpServer};
+/// use graph_rs_sdk::oauth::web::{AuthCodeRedirect, AuthCodeRedirectHandler};
+///
+/// #[get("/redirect")]
+/// async fn redirect(redirect: AuthCodeRedirect) -> String {
+///     format!("{:#?}", redirect.token.expires_in)
+/// }
+///
+/// HttpServer::new(move || App::new().app_data(Data::new(handler.clone())).service(redirect))
+///     .bind(("127.0.0.1", 8000))?
+///     .run()
+///     .await
+/// ```
+impl FromRequest for AuthCodeRedirect {
+    type Error = AuthCodeRedirectError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let handler = req
+            .app_data::<Data<AuthCodeRedirectHandler>>()
+            .map(|handler| handler.get_ref().clone());
+        let response = AuthorizationResponse::from_query_or_fragment(req.query_string());
+
+        Box::pin(async move {
+            let handler = handler.ok_or_else(|| {
+                AuthorizationFailure::msg_err(
+                    "AuthCodeRedirectHandler",
+                    "the handler was not added to the app data",
+                )
+            })?;
+            handler.handle_redirect(response?).await
+        })
+    }
+}
+
+impl ResponseError for AuthCodeRedirectError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(AuthCodeRedirectError::status_code(self))
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(ResponseError::status_code(self)).body(self.to_string())
+    }
+}
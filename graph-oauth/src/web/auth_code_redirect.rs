@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use url::Url;
+
+use graph_core::cache::TokenCache;
+use graph_error::{AuthExecutionError, AuthorizationFailure, IdentityResult};
+
+use crate::identity::{
+    AuthCodeAuthorizationUrlParameterBuilder, AuthCodeAuthorizationUrlParameters,
+    AuthorizationCodeCredential, AuthorizationResponse, AuthorizationValidationError,
+    ConfidentialClientApplication, SecretString, Token,
+};
+
+/// Authorization urls that have not been redirected back after this duration are removed.
+const PENDING_AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Handles the authorization code redirect for web servers.
+///
+/// The handler creates an authorization url with a new state, nonce, and PKCE code
+/// challenge (when PKCE is used in the builder) for each sign in. The state is kept
+/// until the user is redirected back so that the redirect can be validated and the code
+/// redeemed using the same parameters that were sent in the authorization url.
+///
+/// Cloning the handler shares the pending sign ins, so the same handler can be given
+/// to each worker of a web server.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_oauth::AuthCodeAuthorizationUrlParameters;
+/// use graph_oauth::web::AuthCodeRedirectHandler;
+///
+/// let mut builder = AuthCodeAuthorizationUrlParameters::builder(client_id);
+/// builder
+///     .with_redirect_uri(Url::parse("http://localhost:8000/redirect")?)
+///     .with_scope(vec!["User.Read", "offline_access"])
+///     .with_pkce_oneshot()?;
+///
+/// let handler = AuthCodeRedirectHandler::new(&builder, client_secret);
+///
+/// // Send the user to the authorization url.
+/// let url = handler.authorization_url()?;
+///
+/// // In the redirect handler.
+/// let redirect = handler.handle_redirect_query(request_query).await?;
+/// let graph_client = GraphClient::from(&redirect);
+/// ```
+#[derive(Clone)]
+pub struct AuthCodeRedirectHandler {
+    builder: AuthCodeAuthorizationUrlParameterBuilder,
+    client_secret: SecretString,
+    pending: Arc<Mutex<HashMap<String, (Instant, AuthCodeAuthorizationUrlParameters)>>>,
+}
+
+impl AuthCodeRedirectHandler {
+    pub fn new<T: AsRef<str>>(
+        builder: &AuthCodeAuthorizationUrlParameterBuilder,
+        client_secret: T,
+    ) -> AuthCodeRedirectHandler {
+        AuthCodeRedirectHandler {
+            builder: builder.clone(),
+            client_secret: SecretString::new(client_secret.as_ref()),
+            pending: Default::default(),
+        }
+    }
+
+    /// Create the url to send the user to for sign in.
+    pub fn authorization_url(&self) -> IdentityResult<Url> {
+        let mut builder = self.builder.clone();
+        builder.with_generated_state().with_generated_nonce();
+        if builder.build().pkce().is_some() {
+            builder.with_pkce_oneshot()?;
+        }
+
+        let parameters = builder.build();
+        let url = parameters.url()?;
+        let state = parameters
+            .state()
+            .cloned()
+            .ok_or_else(|| AuthorizationFailure::required("state"))?;
+
+        let mut pending = self.pending.lock().unwrap();
+        pending.retain(|_, (created, _)| created.elapsed() < PENDING_AUTHORIZATION_TIMEOUT);
+        pending.insert(state, (Instant::now(), parameters));
+        Ok(url)
+    }
+
+    /// Validate the query of the redirect request and redeem the authorization code.
+    pub async fn handle_redirect_query(
+        &self,
+        query: &str,
+    ) -> Result<AuthCodeRedirect, AuthCodeRedirectError> {
+        let response = AuthorizationResponse::from_query_or_fragment(query)?;
+        self.handle_redirect(response).await
+    }
+
+    /// Validate the authorization response and redeem the authorization code.
+    ///
+    /// Returns [AuthorizationValidationError::StateMismatch] when the state does not belong
+    /// to an authorization url created by this handler or was already used.
+    pub async fn handle_redirect(
+        &self,
+        response: AuthorizationResponse,
+    ) -> Result<AuthCodeRedirect, AuthCodeRedirectError> {
+        let parameters = response.state.as_ref().and_then(|state| {
+            let mut pending = self.pending.lock().unwrap();
+            pending
+                .remove(state)
+                .filter(|(created, _)| created.elapsed() < PENDING_AUTHORIZATION_TIMEOUT)
+                .map(|(_, parameters)| parameters)
+        });
+
+        let parameters = parameters.ok_or_else(|| AuthorizationValidationError::StateMismatch {
+            expected: String::new(),
+            actual: response.state.clone(),
+        })?;
+
+        let response =
+            response.validate(parameters.state.as_deref(), parameters.nonce.as_deref())?;
+        let code = response
+            .code
+            .ok_or_else(|| AuthorizationFailure::required("code"))?;
+
+        let mut credential_builder = parameters.into_credential(code);
+        credential_builder.with_client_secret(self.client_secret.expose_secret());
+        let mut credential = credential_builder.build().into_inner();
+        let token = credential.get_token_silent_async().await?;
+
+        Ok(AuthCodeRedirect {
+            token,
+            confidential_client: ConfidentialClientApplication::credential(credential),
+        })
+    }
+}
+
+impl Debug for AuthCodeRedirectHandler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthCodeRedirectHandler")
+            .field("builder", &self.builder.build().url().ok())
+            .field("client_secret", &self.client_secret)
+            .finish()
+    }
+}
+
+/// The result of a successful authorization code redirect.
+///
+/// The confidential client has the token in its cache and can be used to create a
+/// GraphClient that refreshes the token when it expires.
+#[derive(Clone, Debug)]
+pub struct AuthCodeRedirect {
+    pub token: Token,
+    pub confidential_client: ConfidentialClientApplication<AuthorizationCodeCredential>,
+}
+
+/// Errors from handling the authorization code redirect.
+#[derive(Debug)]
+pub enum AuthCodeRedirectError {
+    /// The redirect could not be validated or the authorization server returned an error.
+    Validation(AuthorizationValidationError),
+    /// The authorization code could not be redeemed for a token or the handler is
+    /// not configured correctly.
+    Execution(AuthExecutionError),
+}
+
+impl AuthCodeRedirectError {
+    /// The http status code to respond to the redirect request with.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            AuthCodeRedirectError::Validation(AuthorizationValidationError::Authorization(_)) => {
+                401
+            }
+            AuthCodeRedirectError::Validation(_) => 400,
+            AuthCodeRedirectError::Execution(AuthExecutionError::Authorization(_)) => 500,
+            AuthCodeRedirectError::Execution(_) => 502,
+        }
+    }
+}
+
+impl Display for AuthCodeRedirectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthCodeRedirectError::Validation(err) => Display::fmt(err, f),
+            AuthCodeRedirectError::Execution(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl std::error::Error for AuthCodeRedirectError {}
+
+impl From<AuthorizationValidationError> for AuthCodeRedirectError {
+    fn from(value: AuthorizationValidationError) -> Self {
+        AuthCodeRedirectError::Validation(value)
+    }
+}
+
+impl From<AuthExecutionError> for AuthCodeRedirectError {
+    fn from(value: AuthExecutionError) -> Self {
+        AuthCodeRedirectError::Execution(value)
+    }
+}
+
+impl From<AuthorizationFailure> for AuthCodeRedirectError {
+    fn from(value: AuthorizationFailure) -> Self {
+        AuthCodeRedirectError::Execution(AuthExecutionError::from(value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use uuid::Uuid;
+
+    fn handler() -> AuthCodeRedirectHandler {
+        let mut builder = AuthCodeAuthorizationUrlParameters::builder(Uuid::new_v4());
+        builder
+            .with_redirect_uri(Url::parse("http://localhost:8000/redirect").unwrap())
+            .with_scope(["User.Read"])
+            .with_pkce_oneshot()
+            .unwrap();
+        AuthCodeRedirectHandler::new(&builder, "secret")
+    }
+
+    #[test]
+    fn authorization_url_generates_state_per_sign_in() {
+        let handler = handler();
+        let state = |url: Url| {
+            url.query_pairs()
+                .find(|(key, _)| key == "state")
+                .map(|(_, value)| value.to_string())
+                .unwrap()
+        };
+
+        let first = state(handler.authorization_url().unwrap());
+        let second = state(handler.authorization_url().unwrap());
+        assert_ne!(first, second);
+        assert_eq!(2, handler.pending.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn unknown_state_is_rejected() {
+        let handler = handler();
+        handler.authorization_url().unwrap();
+
+        let err = handler
+            .handle_redirect_query("code=code&state=forged")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AuthCodeRedirectError::Validation(AuthorizationValidationError::StateMismatch { .. })
+        ));
+        assert_eq!(400, err.status_code());
+    }
+}
@@ -0,0 +1,70 @@
+use axum::async_trait;
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+use crate::identity::AuthorizationResponse;
+use crate::web::{AuthCodeRedirect, AuthCodeRedirectError, AuthCodeRedirectHandler};
+
+/// Extracts the [AuthorizationResponse] from the query of the redirect request.
+///
+/// The response is not validated. Use [AuthCodeRedirect] as the extractor to validate
+/// the state and redeem the authorization code.
+#[derive(Clone, Debug)]
+pub struct AuthorizationRedirect(pub AuthorizationResponse);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AuthorizationRedirect {
+    type Rejection = AuthCodeRedirectError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let query = parts.uri.query().unwrap_or_default();
+        Ok(AuthorizationRedirect(
+            AuthorizationResponse::from_query_or_fragment(query)?,
+        ))
+    }
+}
+
+/// Validates the redirect request and redeems the authorization code using the
+/// [AuthCodeRedirectHandler] in the router state.
+///
+/// # Example
+/// ```rust,ignore
+/// use axum::{routing::get, Router};
+/// use graph_rs_sdk::GraphClient;
+/// use graph_rs_sdk::oauth::web::{AuthCodeRedirect, AuthCodeRedirectHandler};
+///
+/// async fn redirect(redirect: AuthCodeRedirect) -> String {
+///     let client = GraphClient::from(&redirect);
+///     let response = client.me().get_user().send().await.unwrap();
+///     format!("{:#?}", response.status())
+/// }
+///
+/// let app = Router::new()
+///     .route("/redirect", get(redirect))
+///     .with_state(handler);
+/// ```
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AuthCodeRedirect
+where
+    AuthCodeRedirectHandler: FromRef<S>,
+{
+    type Rejection = AuthCodeRedirectError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let AuthorizationRedirect(response) =
+            AuthorizationRedirect::from_request_parts(parts, state).await?;
+        AuthCodeRedirectHandler::from_ref(state)
+            .handle_redirect(response)
+            .await
+    }
+}
+
+impl IntoResponse for AuthCodeRedirectError {
+    fn into_response(self) -> Response {
+        let status =
+            StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (status, self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,7 @@
+mod actix_integration;
+mod auth_code_redirect;
+mod axum_integration;
+
+pub use actix_integration::*;
+pub use auth_code_redirect::*;
+pub use axum_integration::*;
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+
+use crate::api_default_imports::*;
+use crate::batch::BatchApiClient;
+
+/// Maximum number of requests in a single $batch request.
+pub(crate) const BATCH_MAX_REQUESTS: usize = 20;
+
+/// Number of times a request in a $batch request is sent again after being throttled.
+pub(crate) const MAX_THROTTLE_RETRIES: usize = 3;
+
+/// The response to one request of a $batch request. A request without a response
+/// has a status of 0.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct BatchResponse {
+    pub(crate) status: u16,
+    pub(crate) headers: HeaderMap,
+    pub(crate) body: serde_json::Value,
+}
+
+impl BatchResponse {
+    pub(crate) fn from_value(response: &serde_json::Value) -> BatchResponse {
+        let headers = response["headers"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter_map(|(name, value)| {
+                Some((
+                    HeaderName::from_bytes(name.as_bytes()).ok()?,
+                    HeaderValue::from_str(value.as_str()?).ok()?,
+                ))
+            })
+            .collect();
+
+        BatchResponse {
+            status: response["status"].as_u64().unwrap_or_default() as u16,
+            headers,
+            body: response["body"].clone(),
+        }
+    }
+
+    pub(crate) fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// The duration to wait before sending the request again when the request
+    /// was throttled.
+    pub(crate) fn throttle_retry_after(&self) -> Option<Duration> {
+        throttle_retry_after(StatusCode::from_u16(self.status).ok()?, &self.headers)
+    }
+
+    /// The error of a failed request.
+    pub(crate) fn error(&self) -> Option<ErrorMessage> {
+        serde_json::from_value(self.body.clone()).ok()
+    }
+}
+
+impl BatchApiClient {
+    /// Send the requests in one $batch request and return the response to each
+    /// request by the id of the request.
+    pub(crate) async fn send_requests(
+        &self,
+        requests: &[serde_json::Value],
+    ) -> GraphResult<HashMap<String, BatchResponse>> {
+        let response = self
+            .batch(&serde_json::json!({ "requests": requests }))
+            .send()
+            .await?;
+        let body: serde_json::Value = Paging::success_response(response).await?.json().await?;
+
+        Ok(body["responses"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|response| {
+                let id = response["id"].as_str()?.to_string();
+                Some((id, BatchResponse::from_value(response)))
+            })
+            .collect())
+    }
+
+    /// Send the requests in $batch requests of 20 and return the response to each
+    /// request by the id of the request. Throttled requests are sent again after the
+    /// Retry-After duration, up to 3 times, after which the throttled response is
+    /// returned. The ids of the requests must be unique.
+    pub(crate) async fn send_all_requests(
+        &self,
+        requests: Vec<serde_json::Value>,
+    ) -> GraphResult<HashMap<String, BatchResponse>> {
+        let mut responses = HashMap::new();
+        for chunk in requests.chunks(BATCH_MAX_REQUESTS) {
+            let mut pending = chunk.to_vec();
+            let mut retries = 0;
+
+            while !pending.is_empty() {
+                let mut batch = self.send_requests(&pending).await?;
+                let mut throttled = Vec::new();
+                let mut wait = Duration::ZERO;
+
+                for request in pending {
+                    let id = request["id"].as_str().unwrap_or_default().to_string();
+                    let response = batch.remove(&id).unwrap_or_default();
+                    match response.throttle_retry_after() {
+                        Some(retry_after) if retries < MAX_THROTTLE_RETRIES => {
+                            wait = wait.max(retry_after);
+                            throttled.push(request);
+                        }
+                        _ => {
+                            responses.insert(id, response);
+                        }
+                    }
+                }
+
+                if !throttled.is_empty() {
+                    retries += 1;
+                    tokio::time::sleep(wait).await;
+                }
+                pending = throttled;
+            }
+        }
+
+        Ok(responses)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn batch_response() {
+        let response = BatchResponse::from_value(&serde_json::json!({
+            "id": "1",
+            "status": 429,
+            "headers": { "Retry-After": "7" },
+            "body": { "error": { "code": "TooManyRequests", "message": "Slow down" } }
+        }));
+        assert!(!response.is_success());
+        assert_eq!(
+            Some(Duration::from_secs(7)),
+            response.throttle_retry_after()
+        );
+        assert_eq!(
+            Some("TooManyRequests".to_string()),
+            response.error().unwrap().code_property()
+        );
+
+        let response = BatchResponse::from_value(&serde_json::json!({ "id": "2", "status": 204 }));
+        assert!(response.is_success());
+        assert_eq!(None, response.throttle_retry_after());
+        assert_eq!(0, BatchResponse::default().status);
+    }
+}
@@ -1,3 +1,7 @@
+mod batch_requests;
+
+pub(crate) use batch_requests::*;
+
 use crate::api_default_imports::*;
 use crate::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 
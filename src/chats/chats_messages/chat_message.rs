@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+/// The body of `set_reaction` and `unset_reaction`. The reaction is one of the
+/// named reactions or a unicode emoji.
+///
+/// # Example
+/// ```rust,ignore
+/// client
+///     .chat(CHAT_ID)
+///     .message(MESSAGE_ID)
+///     .add_reaction(&ChatMessageReaction::emoji("🚀"))
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChatMessageReaction {
+    #[serde(rename = "reactionType")]
+    pub reaction_type: String,
+}
+
+impl ChatMessageReaction {
+    pub fn like() -> ChatMessageReaction {
+        ChatMessageReaction::emoji("like")
+    }
+
+    pub fn heart() -> ChatMessageReaction {
+        ChatMessageReaction::emoji("heart")
+    }
+
+    pub fn laugh() -> ChatMessageReaction {
+        ChatMessageReaction::emoji("laugh")
+    }
+
+    pub fn surprised() -> ChatMessageReaction {
+        ChatMessageReaction::emoji("surprised")
+    }
+
+    pub fn sad() -> ChatMessageReaction {
+        ChatMessageReaction::emoji("sad")
+    }
+
+    pub fn angry() -> ChatMessageReaction {
+        ChatMessageReaction::emoji("angry")
+    }
+
+    pub fn emoji(reaction_type: impl ToString) -> ChatMessageReaction {
+        ChatMessageReaction {
+            reaction_type: reaction_type.to_string(),
+        }
+    }
+}
+
+/// A page of chat messages from `messages_delta` and the link used to request
+/// the changes after this page.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChatMessagesDelta {
+    #[serde(default)]
+    pub value: Vec<serde_json::Value>,
+    #[serde(rename = "@odata.nextLink", skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+    /// Use the delta link with [ChatMessagesDelta::delta_token] to get only the
+    /// messages created or changed since this sync.
+    #[serde(rename = "@odata.deltaLink", skip_serializing_if = "Option::is_none")]
+    pub delta_link: Option<String>,
+}
+
+impl ChatMessagesDelta {
+    /// The `$deltatoken` of the delta link.
+    pub fn delta_token(&self) -> Option<String> {
+        let url = url::Url::parse(self.delta_link.as_deref()?).ok()?;
+        url.query_pairs()
+            .find(|(key, _)| key == "$deltatoken")
+            .map(|(_, value)| value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chat_messages_delta_token() {
+        let delta: ChatMessagesDelta = serde_json::from_value(serde_json::json!({
+            "value": [{ "id": "1616990032035" }],
+            "@odata.deltaLink": "https://graph.microsoft.com/v1.0/chats/19:id/messages/delta?$deltatoken=abc%3D"
+        }))
+        .unwrap();
+        assert_eq!(Some("abc=".to_string()), delta.delta_token());
+        assert_eq!(
+            serde_json::json!({ "reactionType": "like" }),
+            serde_json::to_value(ChatMessageReaction::like()).unwrap()
+        );
+    }
+}
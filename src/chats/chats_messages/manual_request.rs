@@ -0,0 +1,31 @@
+use crate::api_default_imports::*;
+use crate::chats::{ChatMessageReaction, ChatsMessagesIdApiClient};
+
+impl ChatsMessagesIdApiClient {
+    post!(
+        doc: "Invoke action setReaction",
+        name: set_reaction,
+        path: "/messages/{{RID}}/setReaction",
+        body: true
+    );
+    post!(
+        doc: "Invoke action unsetReaction",
+        name: unset_reaction,
+        path: "/messages/{{RID}}/unsetReaction",
+        body: true
+    );
+
+    /// React to the message as the signed in user.
+    pub async fn add_reaction(&self, reaction: &ChatMessageReaction) -> GraphResult<()> {
+        let response = self.set_reaction(reaction).send().await?;
+        Paging::success_response(response).await?;
+        Ok(())
+    }
+
+    /// Remove a reaction of the signed in user from the message.
+    pub async fn remove_reaction(&self, reaction: &ChatMessageReaction) -> GraphResult<()> {
+        let response = self.unset_reaction(reaction).send().await?;
+        Paging::success_response(response).await?;
+        Ok(())
+    }
+}
@@ -1,3 +1,8 @@
+mod chat_message;
+mod manual_request;
 mod request;
 
+pub use chat_message::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -0,0 +1,45 @@
+use crate::api_default_imports::*;
+use crate::chats::{ChatMessagesDelta, ChatsIdApiClient};
+
+impl ChatsIdApiClient {
+    get!(
+        doc: "Invoke function delta",
+        name: messages_delta,
+        path: "/chats/{{RID}}/messages/delta()"
+    );
+
+    /// Get the messages created or changed since the delta token of a previous sync,
+    /// or all messages when there is no delta token. Store
+    /// [ChatMessagesDelta::delta_token] of the result for the next sync.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let delta = client.chat(CHAT_ID).sync_messages(None).await?;
+    /// for message in delta.value.iter() {
+    ///     println!("{:?}", message["body"]["content"]);
+    /// }
+    ///
+    /// // Later, get only the new and changed messages.
+    /// let delta = client
+    ///     .chat(CHAT_ID)
+    ///     .sync_messages(delta.delta_token().as_deref())
+    ///     .await?;
+    /// ```
+    pub async fn sync_messages(&self, delta_token: Option<&str>) -> GraphResult<ChatMessagesDelta> {
+        let mut request = self.messages_delta();
+        if let Some(delta_token) = delta_token {
+            request = request.append_query_pair("$deltatoken", delta_token);
+        }
+
+        let pages = request.paging().json::<ChatMessagesDelta>().await?;
+        let mut delta = ChatMessagesDelta::default();
+        for page in pages {
+            let page = page.into_body()?;
+            delta.value.extend(page.value);
+            if page.delta_link.is_some() {
+                delta.delta_link = page.delta_link;
+            }
+        }
+        Ok(delta)
+    }
+}
@@ -1,7 +1,10 @@
 mod chats_messages;
 mod chats_messages_replies;
+mod manual_request;
 mod request;
 
 pub use chats_messages::*;
 pub use chats_messages_replies::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -3,6 +3,9 @@ mod resource_provisioner;
 use graph_error::GraphResult;
 use graph_http::api_impl::{BodyRead, RequestComponents};
 pub(crate) use resource_provisioner::*;
+use url::Url;
+
+use crate::GRAPH_URL;
 
 pub(crate) fn map_parameters(params: &[&str]) -> serde_json::Value {
     let mut map = serde_json::Map::new();
@@ -19,6 +22,20 @@ pub(crate) fn map_parameters(params: &[&str]) -> serde_json::Value {
     serde_json::Value::Object(map)
 }
 
+/// The url of the resource at the path, such as `users('{id}')`, for `@odata.id` and
+/// `@odata.bind` values. Uses the host and api version of the base url of the client,
+/// or the global Microsoft Graph service when there is no base url.
+pub(crate) fn odata_bind_url(base_url: Option<&Url>, path: &str) -> String {
+    match base_url {
+        Some(base_url) => {
+            let mut base_url = base_url.clone();
+            base_url.set_query(None);
+            format!("{}/{path}", base_url.as_str().trim_end_matches('/'))
+        }
+        None => format!("{GRAPH_URL}/{path}"),
+    }
+}
+
 pub(crate) fn map_errors(
     rc_result: GraphResult<RequestComponents>,
     body_result: GraphResult<BodyRead>,
@@ -33,16 +33,21 @@ use crate::domain_dns_records::{DomainDnsRecordsApiClient, DomainDnsRecordsIdApi
 use crate::domains::{DomainsApiClient, DomainsIdApiClient};
 use crate::drives::{DrivesApiClient, DrivesIdApiClient};
 use crate::education::EducationApiClient;
+use crate::external::ExternalApiClient;
 use crate::group_lifecycle_policies::{
     GroupLifecyclePoliciesApiClient, GroupLifecyclePoliciesIdApiClient,
 };
+use crate::group_setting_templates::{
+    GroupSettingTemplatesApiClient, GroupSettingTemplatesIdApiClient,
+};
+use crate::group_settings::{GroupSettingsApiClient, GroupSettingsIdApiClient};
 use crate::groups::{GroupsApiClient, GroupsIdApiClient};
 use crate::identity::{
     AllowedHostValidator, AuthorizationCodeAssertionCredential,
     AuthorizationCodeCertificateCredential, AuthorizationCodeCredential, BearerTokenCredential,
     ClientAssertionCredential, ClientCertificateCredential, ClientSecretCredential,
-    ConfidentialClientApplication, DeviceCodeCredential, HostIs, OpenIdCredential,
-    PublicClientApplication, ResourceOwnerPasswordCredential, Token,
+    ConfidentialClientApplication, DeviceCodeCredential, EnvironmentCredential, HostIs,
+    OpenIdCredential, PublicClientApplication, ResourceOwnerPasswordCredential, Token,
 };
 use crate::identity_access::IdentityApiClient;
 use crate::identity_governance::IdentityGovernanceApiClient;
@@ -54,11 +59,14 @@ use crate::oauth2_permission_grants::{
 };
 use crate::organization::{OrganizationApiClient, OrganizationIdApiClient};
 use crate::permission_grants::{PermissionGrantsApiClient, PermissionGrantsIdApiClient};
-use crate::places::PlacesApiClient;
+use crate::places::{PlacesApiClient, PlacesIdApiClient};
 use crate::planner::PlannerApiClient;
 use crate::policies::PoliciesApiClient;
+use crate::print::PrintApiClient;
 use crate::reports::ReportsApiClient;
+use crate::role_management::RoleManagementApiClient;
 use crate::schema_extensions::{SchemaExtensionsApiClient, SchemaExtensionsIdApiClient};
+use crate::security::SecurityApiClient;
 use crate::service_principals::{ServicePrincipalsApiClient, ServicePrincipalsIdApiClient};
 use crate::sites::{SitesApiClient, SitesIdApiClient};
 use crate::solutions::SolutionsApiClient;
@@ -85,6 +93,7 @@ pub type Graph = GraphClient;
 pub struct GraphClient {
     client: Client,
     endpoint: Url,
+    base_url: Option<Url>,
     allowed_host_validator: AllowedHostValidator,
 }
 
@@ -93,18 +102,68 @@ impl GraphClient {
         GraphClient {
             client: Client::new(BearerTokenCredential::from(access_token.to_string())),
             endpoint: PARSED_GRAPH_URL.clone(),
+            base_url: None,
             allowed_host_validator: AllowedHostValidator::default(),
         }
     }
 
+    /// Create a client using the client application to get access tokens.
+    ///
+    /// The client application can be chosen at runtime by passing a
+    /// `Box<dyn ClientApplication>`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let client_app: Box<dyn ClientApplication> = if use_certificate {
+    ///     Box::new(ConfidentialClientApplication::builder(CLIENT_ID)
+    ///         .with_client_x509_certificate(&x509)?
+    ///         .with_tenant(TENANT_ID)
+    ///         .build())
+    /// } else {
+    ///     Box::new(ConfidentialClientApplication::builder(CLIENT_ID)
+    ///         .with_client_secret(CLIENT_SECRET)
+    ///         .with_tenant(TENANT_ID)
+    ///         .build())
+    /// };
+    ///
+    /// let client = GraphClient::from_client_app(client_app);
+    /// ```
     pub fn from_client_app<CA: ClientApplication + 'static>(client_app: CA) -> GraphClient {
         GraphClient {
             client: Client::new(client_app),
             endpoint: PARSED_GRAPH_URL.clone(),
+            base_url: None,
             allowed_host_validator: AllowedHostValidator::default(),
         }
     }
 
+    /// Create a client using a confidential client application built from environment
+    /// variables. The Microsoft Graph endpoint is set for the cloud in `GRAPH_CLOUD`.
+    ///
+    /// See [EnvironmentCredential::confidential_client] for the variables that are read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use graph_rs_sdk::GraphClient;
+    ///
+    /// // AZURE_TENANT_ID, AZURE_CLIENT_ID, and AZURE_CLIENT_SECRET are set.
+    /// let client = GraphClient::from_env()?;
+    /// let response = client.users().list_user().send().await?;
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn from_env() -> GraphResult<GraphClient> {
+        let confidential_client =
+            EnvironmentCredential::confidential_client().map_err(AuthExecutionError::from)?;
+        let endpoint = Url::parse(&format!(
+            "{}/v1.0",
+            confidential_client.azure_cloud_instance().graph_host()
+        ))?;
+
+        let mut client = GraphClient::from_client_app(confidential_client);
+        client.use_endpoint(&endpoint);
+        Ok(client)
+    }
+
     /// Use the v1 endpoint for the Microsoft Graph API. This is the default
     /// endpoint used by the client.
     ///
@@ -122,7 +181,7 @@ impl GraphClient {
     ///     .await?;
     /// ```
     pub fn v1(&mut self) -> &mut GraphClient {
-        self.endpoint = PARSED_GRAPH_URL.clone();
+        self.endpoint = self.versioned_endpoint(false);
         self
     }
 
@@ -141,7 +200,7 @@ impl GraphClient {
     /// assert_eq!(client.url().to_string(), GRAPH_URL.to_string())
     /// ```
     pub fn use_v1(&mut self) {
-        self.endpoint = PARSED_GRAPH_URL.clone();
+        self.endpoint = self.versioned_endpoint(false);
     }
 
     /// Use the beta endpoint for the Microsoft Graph API
@@ -160,7 +219,7 @@ impl GraphClient {
     ///     .await?;
     /// ```
     pub fn beta(&mut self) -> &mut GraphClient {
-        self.endpoint = PARSED_GRAPH_URL_BETA.clone();
+        self.endpoint = self.versioned_endpoint(true);
         self
     }
 
@@ -179,7 +238,7 @@ impl GraphClient {
     /// assert_eq!(client.url().to_string(), GRAPH_URL_BETA.to_string())
     /// ```
     pub fn use_beta(&mut self) {
-        self.endpoint = PARSED_GRAPH_URL_BETA.clone();
+        self.endpoint = self.versioned_endpoint(true);
     }
 
     pub fn url(&self) -> &Url {
@@ -198,6 +257,33 @@ impl GraphClient {
         self.client.with_force_token_refresh(force_token_refresh);
     }
 
+    /// Returns a copy of the client that sends requests on behalf of the given tenant.
+    ///
+    /// The client application is copied and tokens are requested from the tenant
+    /// and cached separately for each tenant. Returns None if the client was created
+    /// with an access token or a client application that does not support tenants.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use graph_rs_sdk::{identity::ConfidentialClientApplication, GraphClient};
+    ///
+    /// let confidential_client = ConfidentialClientApplication::builder("client-id")
+    ///     .with_client_secret("secret")
+    ///     .build();
+    ///
+    /// let client = GraphClient::from(&confidential_client);
+    /// let contoso_client = client.for_tenant("contoso.onmicrosoft.com").unwrap();
+    /// let response = contoso_client.users().list_user().send().await?;
+    /// ```
+    pub fn for_tenant(&self, tenant_id: impl AsRef<str>) -> Option<GraphClient> {
+        Some(GraphClient {
+            client: self.client.for_tenant(tenant_id)?,
+            endpoint: self.endpoint.clone(),
+            base_url: self.base_url.clone(),
+            allowed_host_validator: self.allowed_host_validator.clone(),
+        })
+    }
+
     /// Set a custom endpoint for the Microsoft Graph API. Provide the scheme and host with an
     /// optional path. The path is not set by the sdk when using a custom endpoint.
     ///
@@ -289,16 +375,74 @@ impl GraphClient {
         match self.allowed_host_validator.validate_url(url) {
             HostIs::Valid => {
                 self.endpoint = url.clone();
+                self.base_url = None;
             }
             HostIs::Invalid => panic!("Invalid host"),
         }
     }
 
+    /// Replace `https://graph.microsoft.com` with the base url for all requests, such as
+    /// to send requests through an API gateway, a recording proxy, or a mock server.
+    /// The version path, `v1.0` or `beta`, is added to the path of the base url.
+    ///
+    /// Any host is allowed. Use [GraphClientConfiguration::https_only] to allow
+    /// a base url that does not use https.
+    ///
+    /// Providing a base url with a query will cause a panic.
+    ///
+    /// # Example
+    /// ```rust
+    /// use url::Url;
+    /// use graph_rs_sdk::GraphClient;
+    ///
+    /// let mut client = GraphClient::new("ACCESS_TOKEN");
+    /// client.with_base_url(&Url::parse("https://gateway.contoso.com/graph").unwrap());
+    /// assert_eq!("https://gateway.contoso.com/graph/v1.0", client.url().as_str());
+    ///
+    /// client.beta();
+    /// assert_eq!("https://gateway.contoso.com/graph/beta", client.url().as_str());
+    /// ```
+    pub fn with_base_url(&mut self, url: &Url) -> &mut GraphClient {
+        if url.query().is_some() {
+            panic!(
+                "Invalid query - provide only the scheme, host, and optional path of the base url such as https://gateway.contoso.com/graph"
+            );
+        }
+
+        let is_beta = self.endpoint.path().trim_end_matches('/').ends_with("beta");
+        self.base_url = Some(url.clone());
+        self.endpoint = self.versioned_endpoint(is_beta);
+        self
+    }
+
+    fn versioned_endpoint(&self, beta: bool) -> Url {
+        let version = if beta { "beta" } else { "v1.0" };
+        match self.base_url.as_ref() {
+            Some(base_url) => {
+                let mut url = base_url.clone();
+                url.set_path(&format!(
+                    "{}/{version}",
+                    base_url.path().trim_end_matches('/')
+                ));
+                url
+            }
+            None if beta => PARSED_GRAPH_URL_BETA.clone(),
+            None => PARSED_GRAPH_URL.clone(),
+        }
+    }
+
     #[cfg(feature = "test-util")]
     pub fn use_test_endpoint(&mut self, url: &Url) {
         self.endpoint = url.clone();
     }
 
+    /// A [tower_service::Service] that sends requests using the http client and
+    /// client application of this client. Requires the `tower` feature.
+    #[cfg(feature = "tower")]
+    pub fn service(&self) -> graph_http::api_impl::GraphService {
+        graph_http::api_impl::GraphService::new(self.client.clone())
+    }
+
     api_client_impl!(admin, AdminApiClient);
 
     api_client_impl!(app_catalogs, AppCatalogsApiClient);
@@ -402,6 +546,8 @@ impl GraphClient {
 
     api_client_impl_link!(education, EducationApiClient);
 
+    api_client_impl!(external, ExternalApiClient);
+
     api_client_impl!(groups, GroupsApiClient, group, GroupsIdApiClient);
 
     api_client_impl!(
@@ -411,6 +557,20 @@ impl GraphClient {
         GroupLifecyclePoliciesIdApiClient
     );
 
+    api_client_impl!(
+        group_setting_templates,
+        GroupSettingTemplatesApiClient,
+        group_setting_template,
+        GroupSettingTemplatesIdApiClient
+    );
+
+    api_client_impl!(
+        group_settings,
+        GroupSettingsApiClient,
+        group_setting,
+        GroupSettingsIdApiClient
+    );
+
     api_client_impl_link!(identity, IdentityApiClient);
 
     api_client_impl!(identity_governance, IdentityGovernanceApiClient);
@@ -440,7 +600,7 @@ impl GraphClient {
         OrganizationIdApiClient
     );
 
-    api_client_impl!(places, PlacesApiClient);
+    api_client_impl!(places, PlacesApiClient, place, PlacesIdApiClient);
 
     api_client_impl!(
         permission_grants,
@@ -453,8 +613,12 @@ impl GraphClient {
 
     api_client_impl!(policies, PoliciesApiClient);
 
+    api_client_impl!(print, PrintApiClient);
+
     api_client_impl!(reports, ReportsApiClient);
 
+    api_client_impl!(role_management, RoleManagementApiClient);
+
     api_client_impl!(
         schema_extensions,
         SchemaExtensionsApiClient,
@@ -462,6 +626,8 @@ impl GraphClient {
         SchemaExtensionsIdApiClient
     );
 
+    api_client_impl!(security, SecurityApiClient);
+
     api_client_impl!(
         service_principals,
         ServicePrincipalsApiClient,
@@ -529,6 +695,10 @@ impl GraphClient {
     }
 
     pub fn batch<B: serde::Serialize>(&self, batch: &B) -> RequestHandler {
+        self.batch_client().batch(batch)
+    }
+
+    pub(crate) fn batch_client(&self) -> BatchApiClient {
         BatchApiClient::new(
             self.client.clone(),
             ResourceProvisioner::resource_config_with_url(
@@ -537,7 +707,6 @@ impl GraphClient {
             ),
             Handlebars::new(),
         )
-        .batch(batch)
     }
 }
 
@@ -559,11 +728,18 @@ impl From<&Token> for GraphClient {
     }
 }
 
+impl From<Box<dyn ClientApplication>> for GraphClient {
+    fn from(client_app: Box<dyn ClientApplication>) -> Self {
+        GraphClient::from_client_app(client_app)
+    }
+}
+
 impl From<GraphClientConfiguration> for GraphClient {
     fn from(graph_client_builder: GraphClientConfiguration) -> Self {
         GraphClient {
             client: Client::from(graph_client_builder),
             endpoint: PARSED_GRAPH_URL.clone(),
+            base_url: None,
             allowed_host_validator: AllowedHostValidator::default(),
         }
     }
@@ -575,6 +751,13 @@ impl From<&ConfidentialClientApplication<AuthorizationCodeCredential>> for Graph
     }
 }
 
+#[cfg(feature = "web-integrations")]
+impl From<&graph_oauth::web::AuthCodeRedirect> for GraphClient {
+    fn from(value: &graph_oauth::web::AuthCodeRedirect) -> Self {
+        GraphClient::from_client_app(value.confidential_client.clone())
+    }
+}
+
 impl From<&ConfidentialClientApplication<AuthorizationCodeAssertionCredential>> for GraphClient {
     fn from(value: &ConfidentialClientApplication<AuthorizationCodeAssertionCredential>) -> Self {
         GraphClient::from_client_app(value.clone())
@@ -683,6 +866,15 @@ mod test {
         client.use_endpoint(&Url::parse("https://example.org/v1").unwrap());
     }
 
+    #[test]
+    fn base_url_with_version() {
+        let mut client = GraphClient::new("token");
+        client.beta();
+        client.with_base_url(&Url::parse("http://localhost:8080").unwrap());
+        assert_eq!("http://localhost:8080/beta", client.url().as_str());
+        assert_eq!("http://localhost:8080/v1.0", client.v1().url().as_str());
+    }
+
     #[test]
     fn try_valid_hosts() {
         let urls = [
@@ -0,0 +1,37 @@
+//! The collection returned by list and delta requests.
+
+use serde::{Deserialize, Serialize};
+
+/// A collection of entities, such as a page of a list request or the changes
+/// from a delta query. The next link is set when there are more pages and the
+/// delta link is set on the last page of a delta query.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::collection::Collection;
+///
+/// let response = client.me().insights().list_trending().send().await?;
+/// let trending: Collection<Trending> = response.json().await?;
+/// for item in trending.value.iter() {
+///     println!("{:#?} {}", item.resource_visualization, item.weight);
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Collection<T> {
+    #[serde(default = "Vec::new")]
+    pub value: Vec<T>,
+    #[serde(rename = "@odata.nextLink", skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+    #[serde(rename = "@odata.deltaLink", skip_serializing_if = "Option::is_none")]
+    pub delta_link: Option<String>,
+}
+
+impl<T> Collection<T> {
+    /// The `$deltatoken` of the delta link.
+    pub fn delta_token(&self) -> Option<String> {
+        let url = url::Url::parse(self.delta_link.as_deref()?).ok()?;
+        url.query_pairs()
+            .find(|(key, _)| key == "$deltatoken")
+            .map(|(_, value)| value.to_string())
+    }
+}
@@ -0,0 +1,373 @@
+//! Date and time types used by the request bodies and models of the client.
+//!
+//! Graph returns dates and times in ISO 8601 format with up to seven digits
+//! of fractional seconds, such as `2024-03-20T08:00:00.1234567Z`. Properties such as
+//! `createdDateTime` are represented as [OffsetDateTime] and always serialized in UTC.
+//! Properties that are a date and time in a named time zone, such as the start of an
+//! event, use [DateTimeTimeZone].
+//!
+//! Enable the `chrono` feature to convert to and from `chrono::DateTime`.
+
+use graph_error::{GraphFailure, GraphResult};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+pub use time::{OffsetDateTime, PrimitiveDateTime};
+
+/// Format the date and time in UTC as Graph does, with seven digits of fractional
+/// seconds when the fraction is not zero, such as `2024-03-20T08:00:00.1234567Z`.
+pub fn format_iso8601(date_time: &OffsetDateTime) -> String {
+    let date_time = date_time.to_offset(time::UtcOffset::UTC);
+    format!(
+        "{}Z",
+        format_primitive(PrimitiveDateTime::new(date_time.date(), date_time.time()))
+    )
+}
+
+/// Parse a date and time in ISO 8601 format. A date and time without an offset,
+/// such as `2024-03-20T08:00:00.0000000`, is parsed as UTC.
+pub fn parse_iso8601(value: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    OffsetDateTime::parse(value, &Rfc3339)
+        .or_else(|_| parse_primitive(value).map(|date_time| date_time.assume_utc()))
+}
+
+fn format_primitive(date_time: PrimitiveDateTime) -> String {
+    let seconds = date_time
+        .format(format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second]"
+        ))
+        .unwrap_or_default();
+    match date_time.nanosecond() {
+        0 => seconds,
+        nanosecond => format!("{seconds}.{:07}", nanosecond / 100),
+    }
+}
+
+fn parse_primitive(value: &str) -> Result<PrimitiveDateTime, time::error::Parse> {
+    PrimitiveDateTime::parse(
+        value,
+        format_description!(
+            version = 2,
+            "[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]"
+        ),
+    )
+}
+
+/// Serialize and deserialize an [OffsetDateTime] in the ISO 8601 format used by Graph.
+///
+/// Use with `#[serde(with = "graph_rs_sdk::datetime::iso8601")]`, or
+/// `graph_rs_sdk::datetime::iso8601::option` for optional properties.
+pub mod iso8601 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        date_time: &OffsetDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format_iso8601(date_time))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        parse_iso8601(&value).map_err(serde::de::Error::custom)
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            date_time: &Option<OffsetDateTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match date_time {
+                Some(date_time) => serializer.serialize_some(&format_iso8601(date_time)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<OffsetDateTime>, D::Error> {
+            match Option::<String>::deserialize(deserializer)? {
+                Some(value) => parse_iso8601(&value)
+                    .map(Some)
+                    .map_err(serde::de::Error::custom),
+                None => Ok(None),
+            }
+        }
+    }
+}
+
+/// A date and time in a time zone given by name, such as `Pacific Standard Time`
+/// or `UTC`.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::datetime::DateTimeTimeZone;
+/// use time::macros::datetime;
+///
+/// let start = DateTimeTimeZone::new(datetime!(2024-03-20 08:00), "Pacific Standard Time");
+/// let end = DateTimeTimeZone::utc(OffsetDateTime::now_utc());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DateTimeTimeZone {
+    #[serde(rename = "dateTime", with = "primitive")]
+    pub date_time: PrimitiveDateTime,
+    #[serde(rename = "timeZone")]
+    pub time_zone: String,
+}
+
+impl DateTimeTimeZone {
+    pub fn new(date_time: PrimitiveDateTime, time_zone: impl ToString) -> DateTimeTimeZone {
+        DateTimeTimeZone {
+            date_time,
+            time_zone: time_zone.to_string(),
+        }
+    }
+
+    /// The date and time converted to UTC.
+    pub fn utc(date_time: OffsetDateTime) -> DateTimeTimeZone {
+        let date_time = date_time.to_offset(time::UtcOffset::UTC);
+        DateTimeTimeZone::new(
+            PrimitiveDateTime::new(date_time.date(), date_time.time()),
+            "UTC",
+        )
+    }
+
+    /// The date and time with an offset when the time zone is UTC. Other time
+    /// zones are returned as `None` because the offset depends on the time zone rules.
+    pub fn to_offset_date_time(&self) -> Option<OffsetDateTime> {
+        if self.time_zone.eq_ignore_ascii_case("UTC") {
+            Some(self.date_time.assume_utc())
+        } else {
+            None
+        }
+    }
+}
+
+impl From<OffsetDateTime> for DateTimeTimeZone {
+    fn from(date_time: OffsetDateTime) -> Self {
+        DateTimeTimeZone::utc(date_time)
+    }
+}
+
+mod primitive {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        date_time: &PrimitiveDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format_primitive(*date_time))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PrimitiveDateTime, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        parse_primitive(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// When a subscription, pass or link expires, either at a date and time or
+/// after a duration from now.
+///
+/// # Example
+/// ```rust,ignore
+/// use std::time::Duration;
+///
+/// let in_one_hour = Expiration::from(Duration::from_secs(60 * 60));
+/// let at = Expiration::from(datetime!(2024-03-20 08:00 UTC));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Expiration {
+    At(OffsetDateTime),
+    After(time::Duration),
+}
+
+impl Expiration {
+    /// The date and time of the expiration relative to `now`. Fails when the expiration
+    /// is not after `now`, is later than `max_lifetime` from `now`, or is out of the
+    /// range of [OffsetDateTime].
+    #[allow(clippy::result_large_err)]
+    pub fn resolve(
+        &self,
+        now: OffsetDateTime,
+        max_lifetime: Option<time::Duration>,
+    ) -> GraphResult<OffsetDateTime> {
+        let expiration = match self {
+            Expiration::At(date_time) => *date_time,
+            Expiration::After(duration) => now.checked_add(*duration).ok_or_else(|| {
+                GraphFailure::invalid(&format!(
+                    "expiration after {} seconds is out of range",
+                    duration.whole_seconds()
+                ))
+            })?,
+        };
+        if expiration <= now {
+            return Err(GraphFailure::invalid(&format!(
+                "expiration {} is not in the future",
+                format_iso8601(&expiration)
+            )));
+        }
+        match max_lifetime {
+            Some(max_lifetime) if expiration - now > max_lifetime => {
+                Err(GraphFailure::invalid(&format!(
+                    "expiration {} exceeds the maximum lifetime of {} minutes",
+                    format_iso8601(&expiration),
+                    max_lifetime.whole_minutes()
+                )))
+            }
+            _ => Ok(expiration),
+        }
+    }
+}
+
+impl From<OffsetDateTime> for Expiration {
+    fn from(date_time: OffsetDateTime) -> Self {
+        Expiration::At(date_time)
+    }
+}
+
+impl From<time::Duration> for Expiration {
+    fn from(duration: time::Duration) -> Self {
+        Expiration::After(duration)
+    }
+}
+
+impl From<std::time::Duration> for Expiration {
+    fn from(duration: std::time::Duration) -> Self {
+        Expiration::After(time::Duration::try_from(duration).unwrap_or(time::Duration::MAX))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Expiration {
+    type Error = GraphFailure;
+
+    fn try_from(date_time: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        Ok(Expiration::At(try_from_chrono(date_time)?))
+    }
+}
+
+/// Convert a `chrono::DateTime` to an [OffsetDateTime]. Returns `None` when the
+/// date and time is out of the range of [OffsetDateTime].
+#[cfg(feature = "chrono")]
+pub fn from_chrono<Tz: chrono::TimeZone>(
+    date_time: chrono::DateTime<Tz>,
+) -> Option<OffsetDateTime> {
+    let seconds = OffsetDateTime::from_unix_timestamp(date_time.timestamp()).ok()?;
+    seconds
+        .replace_nanosecond(date_time.timestamp_subsec_nanos())
+        .ok()
+}
+
+/// Convert an [OffsetDateTime] to a `chrono::DateTime` in UTC. Returns `None` when
+/// the date and time is out of the range of `chrono::DateTime`.
+#[cfg(feature = "chrono")]
+pub fn to_chrono(date_time: OffsetDateTime) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp(date_time.unix_timestamp(), date_time.nanosecond())
+}
+
+#[cfg(feature = "chrono")]
+#[allow(clippy::result_large_err)]
+fn try_from_chrono(date_time: chrono::DateTime<chrono::Utc>) -> GraphResult<OffsetDateTime> {
+    from_chrono(date_time)
+        .ok_or_else(|| GraphFailure::invalid(&format!("date and time {date_time} is out of range")))
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for DateTimeTimeZone {
+    type Error = GraphFailure;
+
+    fn try_from(date_time: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        Ok(DateTimeTimeZone::utc(try_from_chrono(date_time)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn iso8601_fractional_seconds() {
+        let date_time = parse_iso8601("2024-03-20T08:00:00.1234567Z").unwrap();
+        assert_eq!(datetime!(2024-03-20 08:00:00.1234567 UTC), date_time);
+        assert_eq!("2024-03-20T08:00:00.1234567Z", format_iso8601(&date_time));
+
+        assert_eq!(
+            "2024-03-20T08:00:00Z",
+            format_iso8601(&parse_iso8601("2024-03-20T10:00:00+02:00").unwrap())
+        );
+        assert_eq!(
+            datetime!(2024-03-20 08:00 UTC),
+            parse_iso8601("2024-03-20T08:00:00.0000000").unwrap()
+        );
+    }
+
+    #[test]
+    fn serialize_date_time_time_zone() {
+        let value = serde_json::json!({
+            "dateTime": "2024-03-20T08:30:00.0000000",
+            "timeZone": "Pacific Standard Time"
+        });
+        let date_time: DateTimeTimeZone = serde_json::from_value(value).unwrap();
+        assert_eq!(datetime!(2024-03-20 08:30), date_time.date_time);
+        assert_eq!(None, date_time.to_offset_date_time());
+        assert_eq!(
+            serde_json::json!({
+                "dateTime": "2024-03-20T08:30:00",
+                "timeZone": "Pacific Standard Time"
+            }),
+            serde_json::to_value(&date_time).unwrap()
+        );
+        assert_eq!(
+            Some(datetime!(2024-03-20 08:00 UTC)),
+            DateTimeTimeZone::utc(datetime!(2024-03-20 10:00 +2)).to_offset_date_time()
+        );
+    }
+
+    #[test]
+    fn resolve_expiration() {
+        let now = datetime!(2024-03-20 08:00 UTC);
+        let max_lifetime = Some(time::Duration::minutes(4230));
+
+        assert_eq!(
+            datetime!(2024-03-20 09:00 UTC),
+            Expiration::from(std::time::Duration::from_secs(3600))
+                .resolve(now, max_lifetime)
+                .unwrap()
+        );
+        assert_eq!(
+            datetime!(2024-03-21 08:00 UTC),
+            Expiration::from(datetime!(2024-03-21 08:00 UTC))
+                .resolve(now, max_lifetime)
+                .unwrap()
+        );
+        assert!(Expiration::from(datetime!(2024-03-20 07:00 UTC))
+            .resolve(now, max_lifetime)
+            .is_err());
+        assert!(Expiration::from(time::Duration::days(3))
+            .resolve(now, max_lifetime)
+            .is_err());
+        assert!(Expiration::from(time::Duration::days(3))
+            .resolve(now, None)
+            .is_ok());
+        assert!(Expiration::from(std::time::Duration::MAX)
+            .resolve(now, None)
+            .is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_conversion() {
+        let date_time = datetime!(2024-03-20 08:00:00.1234567 UTC);
+        let chrono_date_time = to_chrono(date_time).unwrap();
+        assert_eq!(Some(date_time), from_chrono(chrono_date_time));
+        assert_eq!(None, from_chrono(chrono::DateTime::<chrono::Utc>::MAX_UTC));
+        assert!(Expiration::try_from(chrono::DateTime::<chrono::Utc>::MIN_UTC).is_err());
+    }
+}
@@ -7,6 +7,9 @@ mod request;
 mod role_definitions;
 mod terms_and_conditions;
 mod troubleshooting_events;
+mod virtual_endpoint;
+mod virtual_endpoint_cloud_pcs;
+mod virtual_endpoint_provisioning_policies;
 mod windows_autopilot_device_identities;
 
 pub use device_compliance_policy_setting_state_summaries::*;
@@ -18,4 +21,7 @@ pub use request::*;
 pub use role_definitions::*;
 pub use terms_and_conditions::*;
 pub use troubleshooting_events::*;
+pub use virtual_endpoint::*;
+pub use virtual_endpoint_cloud_pcs::*;
+pub use virtual_endpoint_provisioning_policies::*;
 pub use windows_autopilot_device_identities::*;
@@ -22,6 +22,7 @@ impl DeviceManagementApiClient {
         DeviceCompliancePolicySettingStateSummariesIdApiClient
     );
     api_client_link!(reports, DeviceManagementReportsApiClient);
+    api_client_link!(virtual_endpoint, VirtualEndpointApiClient);
     api_client_link_id!(
         windows_autopilot_device_identities_id,
         WindowsAutopilotDeviceIdentitiesIdApiClient
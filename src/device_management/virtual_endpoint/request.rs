@@ -0,0 +1,154 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+use crate::device_management::*;
+
+api_client!(VirtualEndpointApiClient, ResourceIdentity::VirtualEndpoint);
+
+impl VirtualEndpointApiClient {
+    api_client_link!(cloud_pcs, VirtualEndpointCloudPcsApiClient);
+    api_client_link_id!(cloud_pc, VirtualEndpointCloudPcsIdApiClient);
+    api_client_link!(
+        provisioning_policies,
+        VirtualEndpointProvisioningPoliciesApiClient
+    );
+    api_client_link_id!(
+        provisioning_policy,
+        VirtualEndpointProvisioningPoliciesIdApiClient
+    );
+
+    get!(
+        doc: "Get virtualEndpoint from deviceManagement",
+        name: get_virtual_endpoint,
+        path: "/virtualEndpoint"
+    );
+    patch!(
+        doc: "Update the navigation property virtualEndpoint in deviceManagement",
+        name: update_virtual_endpoint,
+        path: "/virtualEndpoint",
+        body: true
+    );
+    get!(
+        doc: "List auditEvents",
+        name: list_audit_events,
+        path: "/virtualEndpoint/auditEvents"
+    );
+    get!(
+        doc: "Get cloudPcAuditEvent",
+        name: get_audit_events,
+        path: "/virtualEndpoint/auditEvents/{{id}}",
+        params: cloud_pc_audit_event_id
+    );
+    post!(
+        doc: "Create cloudPcDeviceImage",
+        name: create_device_images,
+        path: "/virtualEndpoint/deviceImages",
+        body: true
+    );
+    get!(
+        doc: "List deviceImages",
+        name: list_device_images,
+        path: "/virtualEndpoint/deviceImages"
+    );
+    delete!(
+        doc: "Delete cloudPcDeviceImage",
+        name: delete_device_images,
+        path: "/virtualEndpoint/deviceImages/{{id}}",
+        params: cloud_pc_device_image_id
+    );
+    get!(
+        doc: "Get cloudPcDeviceImage",
+        name: get_device_images,
+        path: "/virtualEndpoint/deviceImages/{{id}}",
+        params: cloud_pc_device_image_id
+    );
+    get!(
+        doc: "List galleryImages",
+        name: list_gallery_images,
+        path: "/virtualEndpoint/galleryImages"
+    );
+    get!(
+        doc: "Get cloudPcGalleryImage",
+        name: get_gallery_images,
+        path: "/virtualEndpoint/galleryImages/{{id}}",
+        params: cloud_pc_gallery_image_id
+    );
+    post!(
+        doc: "Create cloudPcOnPremisesConnection",
+        name: create_on_premises_connections,
+        path: "/virtualEndpoint/onPremisesConnections",
+        body: true
+    );
+    get!(
+        doc: "List onPremisesConnections",
+        name: list_on_premises_connections,
+        path: "/virtualEndpoint/onPremisesConnections"
+    );
+    delete!(
+        doc: "Delete cloudPcOnPremisesConnection",
+        name: delete_on_premises_connections,
+        path: "/virtualEndpoint/onPremisesConnections/{{id}}",
+        params: cloud_pc_on_premises_connection_id
+    );
+    get!(
+        doc: "Get cloudPcOnPremisesConnection",
+        name: get_on_premises_connections,
+        path: "/virtualEndpoint/onPremisesConnections/{{id}}",
+        params: cloud_pc_on_premises_connection_id
+    );
+    patch!(
+        doc: "Update cloudPcOnPremisesConnection",
+        name: update_on_premises_connections,
+        path: "/virtualEndpoint/onPremisesConnections/{{id}}",
+        body: true,
+        params: cloud_pc_on_premises_connection_id
+    );
+    post!(
+        doc: "Invoke action runHealthChecks",
+        name: run_health_checks,
+        path: "/virtualEndpoint/onPremisesConnections/{{id}}/runHealthChecks",
+        params: cloud_pc_on_premises_connection_id
+    );
+    get!(
+        doc: "List servicePlans",
+        name: list_service_plans,
+        path: "/virtualEndpoint/servicePlans"
+    );
+    post!(
+        doc: "Create cloudPcUserSetting",
+        name: create_user_settings,
+        path: "/virtualEndpoint/userSettings",
+        body: true
+    );
+    get!(
+        doc: "List userSettings",
+        name: list_user_settings,
+        path: "/virtualEndpoint/userSettings"
+    );
+    delete!(
+        doc: "Delete cloudPcUserSetting",
+        name: delete_user_settings,
+        path: "/virtualEndpoint/userSettings/{{id}}",
+        params: cloud_pc_user_setting_id
+    );
+    get!(
+        doc: "Get cloudPcUserSetting",
+        name: get_user_settings,
+        path: "/virtualEndpoint/userSettings/{{id}}",
+        params: cloud_pc_user_setting_id
+    );
+    patch!(
+        doc: "Update cloudPcUserSetting",
+        name: update_user_settings,
+        path: "/virtualEndpoint/userSettings/{{id}}",
+        body: true,
+        params: cloud_pc_user_setting_id
+    );
+    post!(
+        doc: "Invoke action assign",
+        name: assign_user_settings,
+        path: "/virtualEndpoint/userSettings/{{id}}/assign",
+        body: true,
+        params: cloud_pc_user_setting_id
+    );
+}
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::OffsetDateTime;
+
+/// A cloudPC as returned by `list_cloud_pcs` and `get_cloud_pcs`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CloudPc {
+    pub id: String,
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Such as `provisioning`, `provisioned`, `inGracePeriod`, `deprovisioning` or `failed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(rename = "servicePlanId", skip_serializing_if = "Option::is_none")]
+    pub service_plan_id: Option<String>,
+    #[serde(rename = "servicePlanName", skip_serializing_if = "Option::is_none")]
+    pub service_plan_name: Option<String>,
+    #[serde(
+        rename = "provisioningPolicyId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub provisioning_policy_id: Option<String>,
+    #[serde(rename = "userPrincipalName", skip_serializing_if = "Option::is_none")]
+    pub user_principal_name: Option<String>,
+    #[serde(rename = "managedDeviceId", skip_serializing_if = "Option::is_none")]
+    pub managed_device_id: Option<String>,
+    #[serde(rename = "managedDeviceName", skip_serializing_if = "Option::is_none")]
+    pub managed_device_name: Option<String>,
+    #[serde(rename = "imageDisplayName", skip_serializing_if = "Option::is_none")]
+    pub image_display_name: Option<String>,
+    #[serde(
+        rename = "gracePeriodEndDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub grace_period_end_date_time: Option<OffsetDateTime>,
+    #[serde(
+        rename = "lastModifiedDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub last_modified_date_time: Option<OffsetDateTime>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CloudPcUserAccountType {
+    StandardUser,
+    Administrator,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CloudPcOperatingSystem {
+    Windows10,
+    Windows11,
+}
+
+/// The body of `reprovision`. The Cloud PC is reprovisioned with the current
+/// image of its provisioning policy when no properties are set.
+///
+/// # Example
+/// ```rust,ignore
+/// let body = CloudPcReprovision::default()
+///     .user_account_type(CloudPcUserAccountType::StandardUser)
+///     .os_version(CloudPcOperatingSystem::Windows11);
+///
+/// let response = client
+///     .device_management()
+///     .virtual_endpoint()
+///     .cloud_pc(CLOUD_PC_ID)
+///     .reprovision(&body)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CloudPcReprovision {
+    #[serde(rename = "userAccountType", skip_serializing_if = "Option::is_none")]
+    pub user_account_type: Option<CloudPcUserAccountType>,
+    #[serde(rename = "osVersion", skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<CloudPcOperatingSystem>,
+}
+
+impl CloudPcReprovision {
+    pub fn user_account_type(mut self, user_account_type: CloudPcUserAccountType) -> Self {
+        self.user_account_type = Some(user_account_type);
+        self
+    }
+
+    pub fn os_version(mut self, os_version: CloudPcOperatingSystem) -> Self {
+        self.os_version = Some(os_version);
+        self
+    }
+}
+
+/// The body of `resize` to upgrade or downgrade the Cloud PC to another service plan.
+///
+/// # Example
+/// ```rust,ignore
+/// let response = client
+///     .device_management()
+///     .virtual_endpoint()
+///     .cloud_pc(CLOUD_PC_ID)
+///     .resize(&CloudPcResize::new(SERVICE_PLAN_ID))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CloudPcResize {
+    #[serde(rename = "targetServicePlanId")]
+    pub target_service_plan_id: String,
+}
+
+impl CloudPcResize {
+    /// The id of the service plan from `list_service_plans` of the virtual endpoint.
+    pub fn new(target_service_plan_id: impl ToString) -> CloudPcResize {
+        CloudPcResize {
+            target_service_plan_id: target_service_plan_id.to_string(),
+        }
+    }
+}
+
+/// The body of `rename`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CloudPcRename {
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+}
+
+impl CloudPcRename {
+    pub fn new(display_name: impl ToString) -> CloudPcRename {
+        CloudPcRename {
+            display_name: display_name.to_string(),
+        }
+    }
+}
+
+/// The body of `restore` to restore the Cloud PC from a snapshot.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CloudPcRestore {
+    #[serde(rename = "cloudPcSnapshotId")]
+    pub cloud_pc_snapshot_id: String,
+}
+
+impl CloudPcRestore {
+    pub fn new(cloud_pc_snapshot_id: impl ToString) -> CloudPcRestore {
+        CloudPcRestore {
+            cloud_pc_snapshot_id: cloud_pc_snapshot_id.to_string(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod cloud_pc;
+mod request;
+
+pub use cloud_pc::*;
+pub use request::*;
@@ -0,0 +1,75 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    VirtualEndpointCloudPcsApiClient,
+    VirtualEndpointCloudPcsIdApiClient,
+    ResourceIdentity::VirtualEndpointCloudPcs
+);
+
+impl VirtualEndpointCloudPcsApiClient {
+    get!(
+        doc: "List cloudPCs",
+        name: list_cloud_pcs,
+        path: "/cloudPCs"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_cloud_pcs_count,
+        path: "/cloudPCs/$count"
+    );
+}
+
+impl VirtualEndpointCloudPcsIdApiClient {
+    get!(
+        doc: "Get cloudPC",
+        name: get_cloud_pcs,
+        path: "/cloudPCs/{{RID}}"
+    );
+    patch!(
+        doc: "Update the navigation property cloudPCs in deviceManagement",
+        name: update_cloud_pcs,
+        path: "/cloudPCs/{{RID}}",
+        body: true
+    );
+    post!(
+        doc: "Invoke action endGracePeriod",
+        name: end_grace_period,
+        path: "/cloudPCs/{{RID}}/endGracePeriod"
+    );
+    post!(
+        doc: "Invoke action reboot",
+        name: reboot,
+        path: "/cloudPCs/{{RID}}/reboot"
+    );
+    post!(
+        doc: "Invoke action rename",
+        name: rename,
+        path: "/cloudPCs/{{RID}}/rename",
+        body: true
+    );
+    post!(
+        doc: "Invoke action reprovision",
+        name: reprovision,
+        path: "/cloudPCs/{{RID}}/reprovision",
+        body: true
+    );
+    post!(
+        doc: "Invoke action resize",
+        name: resize,
+        path: "/cloudPCs/{{RID}}/resize",
+        body: true
+    );
+    post!(
+        doc: "Invoke action restore",
+        name: restore,
+        path: "/cloudPCs/{{RID}}/restore",
+        body: true
+    );
+    post!(
+        doc: "Invoke action troubleshoot",
+        name: troubleshoot,
+        path: "/cloudPCs/{{RID}}/troubleshoot"
+    );
+}
@@ -0,0 +1,5 @@
+mod provisioning_policy;
+mod request;
+
+pub use provisioning_policy::*;
+pub use request::*;
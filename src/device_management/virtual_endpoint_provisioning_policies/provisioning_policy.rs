@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CloudPcProvisioningPolicyImageType {
+    Gallery,
+    Custom,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CloudPcDomainJoinType {
+    #[serde(rename = "azureADJoin")]
+    AzureAdJoin,
+    #[serde(rename = "hybridAzureADJoin")]
+    HybridAzureAdJoin,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CloudPcDomainJoinConfiguration {
+    #[serde(rename = "domainJoinType")]
+    pub domain_join_type: CloudPcDomainJoinType,
+    /// The network connection used for a hybrid joined Cloud PC or an Azure AD
+    /// joined Cloud PC that uses a customer network.
+    #[serde(
+        rename = "onPremisesConnectionId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub on_premises_connection_id: Option<String>,
+    /// The Microsoft hosted network region such as `automatic` or `westus2`.
+    #[serde(rename = "regionName", skip_serializing_if = "Option::is_none")]
+    pub region_name: Option<String>,
+}
+
+impl CloudPcDomainJoinConfiguration {
+    /// Azure AD joined using the Microsoft hosted network in the region.
+    pub fn azure_ad_join(region_name: impl ToString) -> CloudPcDomainJoinConfiguration {
+        CloudPcDomainJoinConfiguration {
+            domain_join_type: CloudPcDomainJoinType::AzureAdJoin,
+            on_premises_connection_id: None,
+            region_name: Some(region_name.to_string()),
+        }
+    }
+
+    /// Hybrid Azure AD joined using the on premises network connection.
+    pub fn hybrid_azure_ad_join(
+        on_premises_connection_id: impl ToString,
+    ) -> CloudPcDomainJoinConfiguration {
+        CloudPcDomainJoinConfiguration {
+            domain_join_type: CloudPcDomainJoinType::HybridAzureAdJoin,
+            on_premises_connection_id: Some(on_premises_connection_id.to_string()),
+            region_name: None,
+        }
+    }
+}
+
+/// The body used to create or update a cloudPcProvisioningPolicy.
+///
+/// # Example
+/// ```rust,ignore
+/// let policy = CloudPcProvisioningPolicy::builder(
+///     "Engineering",
+///     "microsoftwindowsdesktop_windows-ent-cpc_win11-22h2-ent-cpc-m365",
+///     CloudPcProvisioningPolicyImageType::Gallery,
+/// )
+/// .description("Cloud PCs for the engineering team")
+/// .domain_join_configuration(CloudPcDomainJoinConfiguration::azure_ad_join("automatic"))
+/// .enable_single_sign_on(true)
+/// .build();
+///
+/// let response = client
+///     .device_management()
+///     .virtual_endpoint()
+///     .provisioning_policies()
+///     .create_provisioning_policies(&policy)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CloudPcProvisioningPolicy {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "imageId", skip_serializing_if = "Option::is_none")]
+    pub image_id: Option<String>,
+    #[serde(rename = "imageType", skip_serializing_if = "Option::is_none")]
+    pub image_type: Option<CloudPcProvisioningPolicyImageType>,
+    #[serde(
+        rename = "domainJoinConfigurations",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub domain_join_configurations: Vec<CloudPcDomainJoinConfiguration>,
+    #[serde(rename = "enableSingleSignOn", skip_serializing_if = "Option::is_none")]
+    pub enable_single_sign_on: Option<bool>,
+    #[serde(
+        rename = "cloudPcNamingTemplate",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cloud_pc_naming_template: Option<String>,
+}
+
+impl CloudPcProvisioningPolicy {
+    pub fn builder(
+        display_name: impl ToString,
+        image_id: impl ToString,
+        image_type: CloudPcProvisioningPolicyImageType,
+    ) -> CloudPcProvisioningPolicyBuilder {
+        CloudPcProvisioningPolicyBuilder {
+            policy: CloudPcProvisioningPolicy {
+                display_name: display_name.to_string(),
+                image_id: Some(image_id.to_string()),
+                image_type: Some(image_type),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CloudPcProvisioningPolicyBuilder {
+    policy: CloudPcProvisioningPolicy,
+}
+
+impl CloudPcProvisioningPolicyBuilder {
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.policy.description = Some(description.to_string());
+        self
+    }
+
+    pub fn domain_join_configuration(
+        mut self,
+        configuration: CloudPcDomainJoinConfiguration,
+    ) -> Self {
+        self.policy.domain_join_configurations.push(configuration);
+        self
+    }
+
+    pub fn enable_single_sign_on(mut self, enable: bool) -> Self {
+        self.policy.enable_single_sign_on = Some(enable);
+        self
+    }
+
+    /// The template used to name Cloud PCs such as `CPC-%USERNAME:4%-%RAND:5%`.
+    pub fn cloud_pc_naming_template(mut self, template: impl ToString) -> Self {
+        self.policy.cloud_pc_naming_template = Some(template.to_string());
+        self
+    }
+
+    pub fn build(self) -> CloudPcProvisioningPolicy {
+        self.policy
+    }
+}
+
+/// The body of `assign` for a provisioning policy. Assigning replaces the
+/// existing assignments of the policy.
+///
+/// # Example
+/// ```rust,ignore
+/// let response = client
+///     .device_management()
+///     .virtual_endpoint()
+///     .provisioning_policy(POLICY_ID)
+///     .assign(&CloudPcProvisioningPolicyAssignments::groups(&[GROUP_ID]))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CloudPcProvisioningPolicyAssignments {
+    pub assignments: Vec<serde_json::Value>,
+}
+
+impl CloudPcProvisioningPolicyAssignments {
+    pub fn groups<T: AsRef<str>>(group_ids: &[T]) -> CloudPcProvisioningPolicyAssignments {
+        CloudPcProvisioningPolicyAssignments {
+            assignments: group_ids
+                .iter()
+                .map(|group_id| {
+                    serde_json::json!({
+                        "target": {
+                            "@odata.type": "microsoft.graph.cloudPcManagementGroupAssignmentTarget",
+                            "groupId": group_id.as_ref()
+                        }
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_provisioning_policy() {
+        let policy = CloudPcProvisioningPolicy::builder(
+            "Engineering",
+            "image-id",
+            CloudPcProvisioningPolicyImageType::Gallery,
+        )
+        .domain_join_configuration(CloudPcDomainJoinConfiguration::hybrid_azure_ad_join(
+            "connection-id",
+        ))
+        .build();
+
+        assert_eq!(
+            serde_json::json!({
+                "displayName": "Engineering",
+                "imageId": "image-id",
+                "imageType": "gallery",
+                "domainJoinConfigurations": [{
+                    "domainJoinType": "hybridAzureADJoin",
+                    "onPremisesConnectionId": "connection-id"
+                }]
+            }),
+            serde_json::to_value(&policy).unwrap()
+        );
+        assert_eq!(
+            "group-id",
+            CloudPcProvisioningPolicyAssignments::groups(&["group-id"]).assignments[0]["target"]
+                ["groupId"]
+        );
+    }
+}
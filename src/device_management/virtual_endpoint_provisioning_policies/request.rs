@@ -0,0 +1,63 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    VirtualEndpointProvisioningPoliciesApiClient,
+    VirtualEndpointProvisioningPoliciesIdApiClient,
+    ResourceIdentity::VirtualEndpointProvisioningPolicies
+);
+
+impl VirtualEndpointProvisioningPoliciesApiClient {
+    post!(
+        doc: "Create cloudPcProvisioningPolicy",
+        name: create_provisioning_policies,
+        path: "/provisioningPolicies",
+        body: true
+    );
+    get!(
+        doc: "List provisioningPolicies",
+        name: list_provisioning_policies,
+        path: "/provisioningPolicies"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_provisioning_policies_count,
+        path: "/provisioningPolicies/$count"
+    );
+}
+
+impl VirtualEndpointProvisioningPoliciesIdApiClient {
+    delete!(
+        doc: "Delete cloudPcProvisioningPolicy",
+        name: delete_provisioning_policies,
+        path: "/provisioningPolicies/{{RID}}"
+    );
+    get!(
+        doc: "Get cloudPcProvisioningPolicy",
+        name: get_provisioning_policies,
+        path: "/provisioningPolicies/{{RID}}"
+    );
+    patch!(
+        doc: "Update cloudPcProvisioningPolicy",
+        name: update_provisioning_policies,
+        path: "/provisioningPolicies/{{RID}}",
+        body: true
+    );
+    post!(
+        doc: "Invoke action assign",
+        name: assign,
+        path: "/provisioningPolicies/{{RID}}/assign",
+        body: true
+    );
+    get!(
+        doc: "Get assignments from deviceManagement",
+        name: list_assignments,
+        path: "/provisioningPolicies/{{RID}}/assignments"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_assignments_count,
+        path: "/provisioningPolicies/{{RID}}/assignments/$count"
+    );
+}
@@ -0,0 +1,119 @@
+use crate::api_default_imports::*;
+use crate::batch::BatchApiClient;
+use crate::directory_objects::*;
+use serde::de::DeserializeOwned;
+
+/// Maximum number of ids in a single getByIds request.
+const GET_BY_IDS_MAX_IDS: usize = 1000;
+
+impl DirectoryObjectsApiClient {
+    /// Get the directory objects for a list of ids of any length.
+    ///
+    /// The ids are split into getByIds requests of up to 1000 ids each, which are
+    /// sent as $batch requests, and the objects from each response are returned in
+    /// one collection. Throttled requests are sent again after the Retry-After duration. Pass the types to restrict the objects returned, such as `user`
+    /// or `group`, or an empty slice for all types.
+    ///
+    /// Ids that are not found are not included in the result.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[derive(Debug, Deserialize)]
+    /// struct User {
+    ///     id: String,
+    ///     #[serde(rename = "displayName")]
+    ///     display_name: Option<String>,
+    /// }
+    ///
+    /// let users: Vec<User> = client
+    ///     .directory_objects()
+    ///     .get_by_ids_batch(&member_ids, &["user"])
+    ///     .await?;
+    /// ```
+    pub async fn get_by_ids_batch<T: DeserializeOwned, I: AsRef<str>, S: AsRef<str>>(
+        &self,
+        ids: &[I],
+        types: &[S],
+    ) -> GraphResult<Vec<T>> {
+        let batch_client = BatchApiClient::new(
+            self.client.clone(),
+            ResourceProvisioner::resource_config_with_url(
+                self.resource_config.url.clone(),
+                ResourceIdentity::Batch,
+            ),
+            Handlebars::new(),
+        );
+
+        let requests = get_by_ids_requests(ids, types);
+        let count = requests.len();
+        let mut responses = batch_client.send_all_requests(requests).await?;
+
+        let mut objects = Vec::new();
+        for id in 1..=count {
+            let response = match responses.remove(&id.to_string()) {
+                Some(response) => response,
+                None => continue,
+            };
+            if !response.is_success() {
+                let error: ErrorMessage = serde_json::from_value(response.body)?;
+                return Err(GraphFailure::from(error));
+            }
+
+            if let Some(value) = response.body["value"].as_array() {
+                for object in value {
+                    objects.push(serde_json::from_value(object.clone())?);
+                }
+            }
+        }
+
+        Ok(objects)
+    }
+}
+
+// The $batch requests for getByIds requests of up to 1000 ids each.
+fn get_by_ids_requests<I: AsRef<str>, S: AsRef<str>>(
+    ids: &[I],
+    types: &[S],
+) -> Vec<serde_json::Value> {
+    let types: Vec<&str> = types.iter().map(|t| t.as_ref()).collect();
+    ids.chunks(GET_BY_IDS_MAX_IDS)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let ids: Vec<&str> = chunk.iter().map(|id| id.as_ref()).collect();
+            let mut body = serde_json::json!({ "ids": ids });
+            if !types.is_empty() {
+                body["types"] = serde_json::json!(types);
+            }
+
+            serde_json::json!({
+                "id": (i + 1).to_string(),
+                "method": "POST",
+                "url": "/directoryObjects/getByIds",
+                "headers": {
+                    "Content-Type": "application/json"
+                },
+                "body": body
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_by_ids_split_into_requests() {
+        let ids: Vec<String> = (0..25_001).map(|i| i.to_string()).collect();
+        let requests = get_by_ids_requests(&ids, &["user"]);
+
+        assert_eq!(26, requests.len());
+        assert_eq!(1000, requests[0]["body"]["ids"].as_array().unwrap().len());
+        assert_eq!(serde_json::json!(["user"]), requests[0]["body"]["types"]);
+        assert_eq!("26", requests[25]["id"]);
+        assert_eq!(1, requests[25]["body"]["ids"].as_array().unwrap().len());
+
+        let requests = get_by_ids_requests(&["id"], &[] as &[&str]);
+        assert!(requests[0]["body"].get("types").is_none());
+    }
+}
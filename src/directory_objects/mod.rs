@@ -1,3 +1,6 @@
+mod manual_request;
 mod request;
 
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -0,0 +1,48 @@
+use crate::api_default_imports::*;
+use crate::client::odata_bind_url;
+use crate::directory_roles::{DirectoryRolesApiClient, DirectoryRolesIdApiClient};
+
+impl DirectoryRolesApiClient {
+    /// Activate the directory role from its role template. A role must be activated
+    /// before members can be added to it, and the role is then available with
+    /// [DirectoryRolesApiClient::get_directory_role_by_role_template_id].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // Helpdesk Administrator
+    /// let response = client
+    ///     .directory_roles()
+    ///     .activate("729827e3-9c14-49f7-bb1b-9608f156bbb8")
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn activate(&self, role_template_id: &str) -> RequestHandler {
+        self.create_directory_role(&serde_json::json!({ "roleTemplateId": role_template_id }))
+    }
+}
+
+impl DirectoryRolesIdApiClient {
+    /// Add a user, group or service principal to the members of the role.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .directory_role(DIRECTORY_ROLE_ID)
+    ///     .add_member(USER_ID)
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn add_member(&self, directory_object_id: &str) -> RequestHandler {
+        self.members().create_ref_members(&serde_json::json!({
+            "@odata.id": odata_bind_url(
+                Some(&self.resource_config.url),
+                &format!("directoryObjects/{directory_object_id}")
+            )
+        }))
+    }
+
+    /// Remove the user, group or service principal from the members of the role.
+    pub fn remove_member(&self, directory_object_id: &str) -> RequestHandler {
+        self.member(directory_object_id).delete_ref_members()
+    }
+}
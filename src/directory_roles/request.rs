@@ -26,6 +26,12 @@ impl DirectoryRolesApiClient {
         name: get_directory_roles_count,
         path: "/directoryRoles/$count"
     );
+    get!(
+        doc: "Get directoryRole by roleTemplateId",
+        name: get_directory_role_by_role_template_id,
+        path: "/directoryRoles(roleTemplateId='{{id}}')",
+        params: role_template_id
+    );
     get!(
         doc: "Invoke function delta",
         name: delta,
@@ -0,0 +1,248 @@
+use serde::{Deserialize, Serialize};
+
+/// A domain of the tenant, and the body used to add a domain.
+///
+/// # Example
+/// ```rust,ignore
+/// let response = client
+///     .domains()
+///     .create_domain(&Domain::new("contoso.com"))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Domain {
+    /// The fully qualified name of the domain, such as `contoso.com`.
+    pub id: String,
+    /// Either `Managed` or `Federated`.
+    #[serde(default, skip_serializing)]
+    pub authentication_type: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub availability_status: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub is_admin_managed: bool,
+    #[serde(default, skip_serializing)]
+    pub is_default: bool,
+    #[serde(default, skip_serializing)]
+    pub is_initial: bool,
+    #[serde(default, skip_serializing)]
+    pub is_root: bool,
+    #[serde(default, skip_serializing)]
+    pub is_verified: bool,
+    /// Such as `Email`, `OfficeCommunicationsOnline` or `Intune`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supported_services: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_validity_period_in_days: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_notification_window_in_days: Option<i32>,
+}
+
+impl Domain {
+    pub fn new(name: impl ToString) -> Domain {
+        Domain {
+            id: name.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// The record type specific data of a [DomainDnsRecord].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@odata.type")]
+pub enum DomainDnsRecordData {
+    #[serde(rename = "#microsoft.graph.domainDnsTxtRecord")]
+    Txt { text: String },
+    #[serde(
+        rename = "#microsoft.graph.domainDnsMxRecord",
+        rename_all = "camelCase"
+    )]
+    Mx {
+        mail_exchange: String,
+        preference: Option<i32>,
+    },
+    #[serde(
+        rename = "#microsoft.graph.domainDnsCnameRecord",
+        rename_all = "camelCase"
+    )]
+    CName { canonical_name: String },
+    #[serde(
+        rename = "#microsoft.graph.domainDnsSrvRecord",
+        rename_all = "camelCase"
+    )]
+    Srv {
+        name_target: String,
+        port: Option<i32>,
+        priority: Option<i32>,
+        /// Such as `_tls`.
+        protocol: String,
+        /// Such as `_sip`.
+        service: String,
+        weight: Option<i32>,
+    },
+    /// A record that can not be used, such as when the domain does not support a service.
+    #[serde(
+        rename = "#microsoft.graph.domainDnsUnavailableRecord",
+        rename_all = "camelCase"
+    )]
+    Unavailable { description: Option<String> },
+}
+
+/// A DNS record returned by verificationDnsRecords or serviceConfigurationRecords.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainDnsRecord {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub is_optional: bool,
+    /// The host name of the record, such as `contoso.com` or `autodiscover.contoso.com`.
+    pub label: String,
+    #[serde(default)]
+    pub record_type: Option<String>,
+    /// The service that the record configures, such as `Email`.
+    #[serde(default)]
+    pub supported_service: Option<String>,
+    #[serde(default = "default_ttl")]
+    pub ttl: i32,
+    #[serde(flatten)]
+    pub data: DomainDnsRecordData,
+}
+
+fn default_ttl() -> i32 {
+    3600
+}
+
+impl DomainDnsRecord {
+    /// The record as a line of a DNS zone file, or `None` for an unavailable record.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // contoso.com. 3600 IN TXT "MS=ms12345678"
+    /// let line = record.to_zone_file_line();
+    /// ```
+    pub fn to_zone_file_line(&self) -> Option<String> {
+        let (name, record_type, value) = match &self.data {
+            DomainDnsRecordData::Txt { text } => (
+                fully_qualified(&self.label),
+                "TXT",
+                format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"")),
+            ),
+            DomainDnsRecordData::Mx {
+                mail_exchange,
+                preference,
+            } => (
+                fully_qualified(&self.label),
+                "MX",
+                format!(
+                    "{} {}",
+                    preference.unwrap_or_default(),
+                    fully_qualified(mail_exchange)
+                ),
+            ),
+            DomainDnsRecordData::CName { canonical_name } => (
+                fully_qualified(&self.label),
+                "CNAME",
+                fully_qualified(canonical_name),
+            ),
+            DomainDnsRecordData::Srv {
+                name_target,
+                port,
+                priority,
+                protocol,
+                service,
+                weight,
+            } => (
+                fully_qualified(&format!("{service}.{protocol}.{}", self.label)),
+                "SRV",
+                format!(
+                    "{} {} {} {}",
+                    priority.unwrap_or_default(),
+                    weight.unwrap_or_default(),
+                    port.unwrap_or_default(),
+                    fully_qualified(name_target)
+                ),
+            ),
+            DomainDnsRecordData::Unavailable { .. } => return None,
+        };
+
+        Some(format!("{name} {} IN {record_type} {value}", self.ttl))
+    }
+}
+
+fn fully_qualified(name: &str) -> String {
+    if name.ends_with('.') {
+        name.to_string()
+    } else {
+        format!("{name}.")
+    }
+}
+
+/// The records as the lines of a DNS zone file, skipping unavailable records.
+pub fn dns_zone_file(records: &[DomainDnsRecord]) -> String {
+    records
+        .iter()
+        .filter_map(DomainDnsRecord::to_zone_file_line)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn zone_file_lines() {
+        let records: Vec<DomainDnsRecord> = serde_json::from_value(serde_json::json!([
+            {
+                "@odata.type": "#microsoft.graph.domainDnsTxtRecord",
+                "isOptional": false,
+                "label": "contoso.com",
+                "recordType": "Txt",
+                "supportedService": "None",
+                "ttl": 3600,
+                "text": "MS=ms12345678"
+            },
+            {
+                "@odata.type": "#microsoft.graph.domainDnsMxRecord",
+                "label": "contoso.com",
+                "recordType": "Mx",
+                "ttl": 3600,
+                "mailExchange": "contoso-com.mail.protection.outlook.com",
+                "preference": 0
+            },
+            {
+                "@odata.type": "#microsoft.graph.domainDnsCnameRecord",
+                "label": "autodiscover.contoso.com",
+                "ttl": 3600,
+                "canonicalName": "autodiscover.outlook.com"
+            },
+            {
+                "@odata.type": "#microsoft.graph.domainDnsSrvRecord",
+                "label": "contoso.com",
+                "ttl": 3600,
+                "nameTarget": "sipdir.online.lync.com",
+                "port": 443,
+                "priority": 100,
+                "protocol": "_tls",
+                "service": "_sip",
+                "weight": 1
+            },
+            {
+                "@odata.type": "#microsoft.graph.domainDnsUnavailableRecord",
+                "label": "contoso.com",
+                "description": "Unavailable"
+            }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            "contoso.com. 3600 IN TXT \"MS=ms12345678\"\n\
+             contoso.com. 3600 IN MX 0 contoso-com.mail.protection.outlook.com.\n\
+             autodiscover.contoso.com. 3600 IN CNAME autodiscover.outlook.com.\n\
+             _sip._tls.contoso.com. 3600 IN SRV 100 1 443 sipdir.online.lync.com.",
+            dns_zone_file(&records)
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use futures::TryStreamExt;
+
+use crate::api_default_imports::*;
+use crate::domains::{Domain, DomainDnsRecord, DomainsIdApiClient};
+
+impl DomainsIdApiClient {
+    /// The DNS records to add at the DNS host of the domain to verify ownership
+    /// of the domain.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let records = client
+    ///     .domain("contoso.com")
+    ///     .list_verification_records()
+    ///     .await?;
+    /// println!("{}", dns_zone_file(&records));
+    ///
+    /// // After the records are added at the DNS host.
+    /// let domain = client.domain("contoso.com").verify_domain().await?;
+    /// assert!(domain.is_verified);
+    /// ```
+    pub async fn list_verification_records(&self) -> GraphResult<Vec<DomainDnsRecord>> {
+        self.list_verification_dns_records()
+            .paging()
+            .stream_items::<DomainDnsRecord>()?
+            .try_collect()
+            .await
+    }
+
+    /// The DNS records to add at the DNS host of a verified domain to enable the
+    /// services of the domain, such as Email.
+    pub async fn list_service_records(&self) -> GraphResult<Vec<DomainDnsRecord>> {
+        self.list_service_configuration_records()
+            .paging()
+            .stream_items::<DomainDnsRecord>()?
+            .try_collect()
+            .await
+    }
+
+    /// Verify ownership of the domain, returning the domain. The request fails if
+    /// the verification records can not be found at the DNS host.
+    pub async fn verify_domain(&self) -> GraphResult<Domain> {
+        let response = self.verify().send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+}
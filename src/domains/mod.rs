@@ -1,3 +1,8 @@
+mod domain;
+mod manual_request;
 mod request;
 
+pub use domain::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
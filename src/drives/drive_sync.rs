@@ -0,0 +1,410 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use graph_http::traits::{AsyncIterator, ResponseExt};
+use reqwest::header::{HeaderValue, IF_MATCH};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::api_default_imports::*;
+use crate::drives::{ConflictBehavior, DrivesIdApiClient, UploadSessionOptions};
+
+/// Reference to the parent of a drive item.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SyncItemReference {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "driveId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drive_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// The drive item properties used to detect changes between syncs.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SyncDriveItem {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Changes when any property of the item, including its content, changes.
+    #[serde(rename = "eTag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e_tag: Option<String>,
+    /// Changes only when the content of the item changes. Not set for folders.
+    #[serde(rename = "cTag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub c_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(rename = "parentReference")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_reference: Option<SyncItemReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<serde_json::Value>,
+}
+
+impl SyncDriveItem {
+    pub fn is_folder(&self) -> bool {
+        self.folder.is_some()
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted.is_some()
+    }
+}
+
+/// A change to a drive item since the last sync.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DriveChange {
+    /// The item was not known from a previous sync.
+    Created(SyncDriveItem),
+    /// The content of the item changed. The cTag of the item is different.
+    ContentChanged(SyncDriveItem),
+    /// Properties of the item such as the name or parent changed but the
+    /// content did not. The eTag of the item is different.
+    MetadataChanged(SyncDriveItem),
+    Deleted(SyncDriveItem),
+}
+
+impl DriveChange {
+    /// Compare an item from a delta query with the item from the previous sync.
+    /// Returns None when the item has not changed.
+    pub fn detect(previous: Option<&SyncDriveItem>, item: SyncDriveItem) -> Option<DriveChange> {
+        if item.is_deleted() {
+            return Some(DriveChange::Deleted(item));
+        }
+
+        match previous {
+            None => Some(DriveChange::Created(item)),
+            Some(previous) if previous.c_tag != item.c_tag => {
+                Some(DriveChange::ContentChanged(item))
+            }
+            Some(previous) if previous.e_tag != item.e_tag => {
+                Some(DriveChange::MetadataChanged(item))
+            }
+            Some(_) => None,
+        }
+    }
+
+    pub fn item(&self) -> &SyncDriveItem {
+        match self {
+            DriveChange::Created(item)
+            | DriveChange::ContentChanged(item)
+            | DriveChange::MetadataChanged(item)
+            | DriveChange::Deleted(item) => item,
+        }
+    }
+}
+
+/// The result of uploading content to a drive item.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UploadOutcome {
+    Uploaded(SyncDriveItem),
+    /// The item changed remotely since the last sync or an item with the same
+    /// name already exists. Nothing was uploaded. The remote item is returned when
+    /// it could be read so the caller can decide how to resolve the conflict.
+    Conflict(Option<SyncDriveItem>),
+}
+
+/// The state of a drive after the last sync. The state can be serialized and
+/// stored so that a later sync only requests the changes since this sync.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DriveSyncState {
+    #[serde(rename = "deltaLink")]
+    pub delta_link: Option<String>,
+    pub items: HashMap<String, SyncDriveItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeltaPage {
+    #[serde(default)]
+    value: Vec<SyncDriveItem>,
+    #[serde(rename = "@odata.deltaLink")]
+    delta_link: Option<String>,
+}
+
+/// Track changes to a drive and download and upload items with conflict detection.
+///
+/// Changes are found using delta queries and compared against the items from the
+/// previous sync using the eTag and cTag of each item. Uploads to existing items
+/// are only made when the item has not changed remotely since the last sync.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::drives::{DriveChange, DriveSync, UploadOutcome};
+///
+/// let mut drive_sync = DriveSync::new(client.drive(DRIVE_ID));
+///
+/// for change in drive_sync.changes().await? {
+///     match change {
+///         DriveChange::Created(item) | DriveChange::ContentChanged(item) if !item.is_folder() => {
+///             drive_sync.download(&item.id, &FileConfig::new("./sync")).await?;
+///         }
+///         _ => {}
+///     }
+/// }
+///
+/// // Later, upload a local edit.
+/// let file = std::fs::File::open("./sync/report.docx")?;
+/// if let UploadOutcome::Conflict(remote) = drive_sync.upload(ITEM_ID, file).await? {
+///     println!("changed remotely: {remote:#?}");
+/// }
+///
+/// // Store the state to continue from the same point next time.
+/// let state = serde_json::to_string(drive_sync.state())?;
+/// ```
+#[derive(Debug)]
+pub struct DriveSync {
+    client: DrivesIdApiClient,
+    state: DriveSyncState,
+}
+
+impl DriveSync {
+    pub fn new(client: DrivesIdApiClient) -> DriveSync {
+        DriveSync {
+            client,
+            state: Default::default(),
+        }
+    }
+
+    /// Continue syncing from a previously stored state.
+    pub fn with_state(client: DrivesIdApiClient, state: DriveSyncState) -> DriveSync {
+        DriveSync { client, state }
+    }
+
+    pub fn state(&self) -> &DriveSyncState {
+        &self.state
+    }
+
+    pub fn into_state(self) -> DriveSyncState {
+        self.state
+    }
+
+    /// Get the changes to the drive since the last sync and update the sync state.
+    ///
+    /// All items in the drive are returned as [DriveChange::Created] on the first sync.
+    pub async fn changes(&mut self) -> GraphResult<Vec<DriveChange>> {
+        let request = match self.state.delta_link.as_deref().and_then(delta_token) {
+            Some(token) => DrivesIdApiClient::delta_token(&self.client, token),
+            None => self.client.delta(),
+        };
+
+        let pages = request.paging().json::<DeltaPage>().await?;
+        let mut changes = Vec::new();
+        let mut delta_link = None;
+
+        for page in pages {
+            let page = page.into_body()?;
+            if page.delta_link.is_some() {
+                delta_link = page.delta_link;
+            }
+
+            for item in page.value {
+                let previous = self.state.items.get(&item.id);
+                if let Some(change) = DriveChange::detect(previous, item) {
+                    let item = change.item();
+                    if item.is_deleted() {
+                        self.state.items.remove(&item.id);
+                    } else {
+                        self.state.items.insert(item.id.clone(), item.clone());
+                    }
+                    changes.push(change);
+                }
+            }
+        }
+
+        self.state.delta_link = delta_link;
+        Ok(changes)
+    }
+
+    /// Download the content of an item using its pre-authenticated download url.
+    pub async fn download(
+        &self,
+        item_id: &str,
+        file_config: &FileConfig,
+    ) -> GraphResult<http::Response<PathBuf>> {
+        self.client
+            .item(item_id)
+            .get_items()
+            .download_with_download_url(file_config)
+            .await
+    }
+
+    /// Replace the content of an existing item using an upload session.
+    ///
+    /// When the item is known from a previous sync the upload session is created with
+    /// the eTag of the item in the If-Match header so that changes made remotely since
+    /// the last sync are not overwritten. [UploadOutcome::Conflict] is returned in that case.
+    pub async fn upload<R: std::io::Read + Send>(
+        &mut self,
+        item_id: &str,
+        reader: R,
+    ) -> GraphResult<UploadOutcome> {
+        let mut request = self.client.item(item_id).create_upload_session(
+            &UploadSessionOptions::new().conflict_behavior(ConflictBehavior::Replace),
+        );
+
+        if let Some(e_tag) = self
+            .state
+            .items
+            .get(item_id)
+            .and_then(|item| item.e_tag.as_deref())
+        {
+            let e_tag = HeaderValue::from_str(e_tag).map_err(|_| GraphFailure::invalid("eTag"))?;
+            request = request.header(IF_MATCH, e_tag);
+        }
+
+        let response = request.send().await?;
+        if response.status() == StatusCode::PRECONDITION_FAILED {
+            let remote = self.remote_item(item_id).await.ok();
+            return Ok(UploadOutcome::Conflict(remote));
+        }
+
+        self.upload_session(Paging::success_response(response).await?, reader)
+            .await
+    }
+
+    /// Upload a new file to the path, relative to the root of the drive, such as
+    /// `Documents/report.docx`. [UploadOutcome::Conflict] is returned when an item
+    /// already exists at the path.
+    pub async fn upload_new<R: std::io::Read + Send>(
+        &mut self,
+        path: &str,
+        reader: R,
+    ) -> GraphResult<UploadOutcome> {
+        let path = path.trim_matches('/');
+        let response = self
+            .client
+            .item_by_path(format!(":/{path}:"))
+            .create_upload_session(
+                &UploadSessionOptions::new().conflict_behavior(ConflictBehavior::Fail),
+            )
+            .send()
+            .await?;
+
+        if response.status() == StatusCode::CONFLICT {
+            return Ok(UploadOutcome::Conflict(None));
+        }
+
+        self.upload_session(Paging::success_response(response).await?, reader)
+            .await
+    }
+
+    async fn upload_session<R: std::io::Read + Send>(
+        &mut self,
+        response: reqwest::Response,
+        reader: R,
+    ) -> GraphResult<UploadOutcome> {
+        let mut upload_session = response.into_upload_session(reader).await?;
+        let mut last_response = None;
+        while let Some(result) = upload_session.next().await {
+            let response = result?;
+            if response.status() == StatusCode::CONFLICT {
+                return Ok(UploadOutcome::Conflict(None));
+            }
+            last_response = Some(Paging::success_response(response).await?);
+        }
+
+        let response = last_response
+            .ok_or_else(|| GraphFailure::not_found("No response from upload session"))?;
+
+        let item: SyncDriveItem = response.json().await?;
+        self.state.items.insert(item.id.clone(), item.clone());
+        Ok(UploadOutcome::Uploaded(item))
+    }
+
+    async fn remote_item(&self, item_id: &str) -> GraphResult<SyncDriveItem> {
+        let response = self.client.item(item_id).get_items().send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+}
+
+// The token of a delta link such as /drives/{id}/root/delta?token={token}
+// or /drives/{id}/root/delta(token='{token}').
+fn delta_token(delta_link: &str) -> Option<String> {
+    let url = Url::parse(delta_link).ok()?;
+    if let Some((_, token)) = url.query_pairs().find(|(key, _)| key == "token") {
+        return Some(token.into_owned());
+    }
+
+    let path = url.path();
+    let start = path.find("token='")? + "token='".len();
+    let end = start + path[start..].find('\'')?;
+    Some(path[start..end].to_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Graph;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn item(e_tag: &str, c_tag: &str) -> SyncDriveItem {
+        SyncDriveItem {
+            id: "item-id".into(),
+            e_tag: Some(e_tag.into()),
+            c_tag: Some(c_tag.into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn detect_changes() {
+        let previous = item("e1", "c1");
+        assert_eq!(None, DriveChange::detect(Some(&previous), item("e1", "c1")));
+        assert!(matches!(
+            DriveChange::detect(Some(&previous), item("e2", "c1")),
+            Some(DriveChange::MetadataChanged(_))
+        ));
+        assert!(matches!(
+            DriveChange::detect(Some(&previous), item("e2", "c2")),
+            Some(DriveChange::ContentChanged(_))
+        ));
+        assert!(matches!(
+            DriveChange::detect(None, item("e1", "c1")),
+            Some(DriveChange::Created(_))
+        ));
+    }
+
+    #[test]
+    fn parse_delta_token() {
+        assert_eq!(
+            Some("abc".to_string()),
+            delta_token("https://graph.microsoft.com/v1.0/drives/id/root/delta?token=abc")
+        );
+        assert_eq!(
+            Some("abc".to_string()),
+            delta_token("https://graph.microsoft.com/v1.0/drives/id/root/delta(token='abc')")
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_session_conflict() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/upload"))
+            .respond_with(ResponseTemplate::new(409))
+            .mount(&mock_server)
+            .await;
+
+        let upload_url = format!("{}/upload", mock_server.uri());
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .body(serde_json::json!({ "uploadUrl": upload_url }).to_string())
+                .unwrap(),
+        );
+
+        let mut drive_sync = DriveSync::new(Graph::new("token").drive("drive-id"));
+        let outcome = drive_sync
+            .upload_session(response, "content".as_bytes())
+            .await
+            .unwrap();
+        assert!(matches!(outcome, UploadOutcome::Conflict(None)));
+    }
+}
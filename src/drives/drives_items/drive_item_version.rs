@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::OffsetDateTime;
+
+/// The body of `checkin` with the comment for the new version.
+///
+/// # Example
+/// ```rust,ignore
+/// let item = client.drive(DRIVE_ID).item(ITEM_ID);
+///
+/// item.check_out().await?;
+/// // Edit the document.
+/// item.check_in(&DriveItemCheckIn::new("Updated the budget").publish()).await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DriveItemCheckIn {
+    pub comment: String,
+    /// `published` to publish a major version. Otherwise a minor version is created.
+    #[serde(rename = "checkInAs", skip_serializing_if = "Option::is_none")]
+    pub check_in_as: Option<String>,
+}
+
+impl DriveItemCheckIn {
+    pub fn new(comment: impl ToString) -> DriveItemCheckIn {
+        DriveItemCheckIn {
+            comment: comment.to_string(),
+            check_in_as: None,
+        }
+    }
+
+    /// Publish the document as a major version when checked in.
+    pub fn publish(mut self) -> Self {
+        self.check_in_as = Some("published".into());
+        self
+    }
+}
+
+/// A driveItemVersion as returned by `list_versions`. Versions are listed
+/// newest first and the first version is the current version.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DriveItemVersion {
+    /// The version label such as `3.0`.
+    pub id: String,
+    #[serde(
+        rename = "lastModifiedDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub last_modified_date_time: Option<OffsetDateTime>,
+    #[serde(rename = "lastModifiedBy", skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
+impl DriveItemVersion {
+    /// The display name of the user that created the version.
+    pub fn last_modified_by_name(&self) -> Option<&str> {
+        self.last_modified_by.as_ref()?["user"]["displayName"].as_str()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_check_in_and_version() {
+        assert_eq!(
+            serde_json::json!({ "comment": "Updated", "checkInAs": "published" }),
+            serde_json::to_value(DriveItemCheckIn::new("Updated").publish()).unwrap()
+        );
+
+        let version: DriveItemVersion = serde_json::from_value(serde_json::json!({
+            "id": "2.0",
+            "lastModifiedBy": { "user": { "id": "1", "displayName": "Adele Vance" } },
+            "size": 1024
+        }))
+        .unwrap();
+        assert_eq!(Some("Adele Vance"), version.last_modified_by_name());
+    }
+}
@@ -0,0 +1,50 @@
+use futures::TryStreamExt;
+use serde::de::DeserializeOwned;
+
+use crate::api_default_imports::*;
+use crate::drives::{
+    CreateLink, DriveItemCheckIn, DriveItemVersion, DrivesItemsIdApiClient, SharingPermission,
+};
+
+impl DrivesItemsIdApiClient {
+    /// Check out the item so that only the signed in user can edit it until it is
+    /// checked in.
+    pub async fn check_out(&self) -> GraphResult<()> {
+        let response = self.checkout().send().await?;
+        Paging::success_response(response).await?;
+        Ok(())
+    }
+
+    /// Check in the item, creating a new version with the comment.
+    pub async fn check_in(&self, check_in: &DriveItemCheckIn) -> GraphResult<()> {
+        let response = self.checkin(check_in).send().await?;
+        Paging::success_response(response).await?;
+        Ok(())
+    }
+
+    /// Every version of the item, newest first.
+    pub async fn list_item_versions(&self) -> GraphResult<Vec<DriveItemVersion>> {
+        all_versions(self.list_versions()).await
+    }
+
+    /// Restore a previous version of the item. The restored content is saved as
+    /// a new version.
+    pub async fn restore_item_version(&self, version_id: &str) -> GraphResult<()> {
+        let response = self.restore_version(version_id).send().await?;
+        Paging::success_response(response).await?;
+        Ok(())
+    }
+
+    /// Create a sharing link for the item. An existing link of the same type and
+    /// scope is returned instead of creating a new link.
+    pub async fn create_sharing_link(&self, body: &CreateLink) -> GraphResult<SharingPermission> {
+        let response = self.create_link(body).send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+}
+
+pub(crate) async fn all_versions<T: DeserializeOwned>(
+    request: RequestHandler,
+) -> GraphResult<Vec<T>> {
+    request.paging().stream_items::<T>()?.try_collect().await
+}
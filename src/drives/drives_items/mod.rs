@@ -1,3 +1,10 @@
+mod drive_item_version;
+mod manual_request;
 mod request;
+mod sharing_link;
 
+pub use drive_item_version::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
+pub use sharing_link::*;
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api_default_imports::*;
+use crate::datetime::{Expiration, OffsetDateTime};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SharingLinkType {
+    View,
+    Edit,
+    Embed,
+    #[serde(other)]
+    UnknownFutureValue,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SharingLinkScope {
+    Anonymous,
+    Organization,
+    Users,
+    #[serde(other)]
+    UnknownFutureValue,
+}
+
+/// The body of the createLink action. Use [CreateLink::builder] to set when the
+/// link expires.
+///
+/// # Example
+/// ```rust,ignore
+/// use std::time::Duration;
+///
+/// let body = CreateLink::builder(SharingLinkType::View, SharingLinkScope::Anonymous)
+///     .expiration(Duration::from_secs(7 * 24 * 60 * 60))
+///     .build()?;
+///
+/// let permission = client
+///     .drive(DRIVE_ID)
+///     .item(ITEM_ID)
+///     .create_sharing_link(&body)
+///     .await?;
+/// println!("{:?}", permission.link.and_then(|link| link.web_url));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CreateLink {
+    #[serde(rename = "type")]
+    pub link_type: SharingLinkType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<SharingLinkScope>,
+    #[serde(
+        rename = "expirationDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub expiration_date_time: Option<OffsetDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(
+        rename = "retainInheritedPermissions",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub retain_inherited_permissions: Option<bool>,
+}
+
+impl CreateLink {
+    pub fn new(link_type: SharingLinkType, scope: SharingLinkScope) -> CreateLink {
+        CreateLink {
+            link_type,
+            scope: Some(scope),
+            expiration_date_time: None,
+            password: None,
+            retain_inherited_permissions: None,
+        }
+    }
+
+    pub fn builder(link_type: SharingLinkType, scope: SharingLinkScope) -> CreateLinkBuilder {
+        CreateLinkBuilder {
+            link: CreateLink::new(link_type, scope),
+            expiration: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateLinkBuilder {
+    link: CreateLink,
+    expiration: Option<Expiration>,
+}
+
+impl CreateLinkBuilder {
+    /// When the link expires, either a date and time or a duration from the time
+    /// the body is built. The maximum lifetime of anonymous links is set by the
+    /// sharing policy of the tenant.
+    pub fn expiration(mut self, expiration: impl Into<Expiration>) -> Self {
+        self.expiration = Some(expiration.into());
+        self
+    }
+
+    /// The password of the link. Only supported for OneDrive for consumer.
+    pub fn password(mut self, password: impl ToString) -> Self {
+        self.link.password = Some(password.to_string());
+        self
+    }
+
+    pub fn retain_inherited_permissions(mut self, retain: bool) -> Self {
+        self.link.retain_inherited_permissions = Some(retain);
+        self
+    }
+
+    /// Build the body, failing when the expiration is not in the future.
+    #[allow(clippy::result_large_err)]
+    pub fn build(self) -> GraphResult<CreateLink> {
+        self.build_at(OffsetDateTime::now_utc())
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn build_at(mut self, now: OffsetDateTime) -> GraphResult<CreateLink> {
+        if let Some(expiration) = self.expiration {
+            self.link.expiration_date_time = Some(expiration.resolve(now, None)?);
+        }
+        Ok(self.link)
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SharingLink {
+    #[serde(rename = "type")]
+    pub link_type: Option<SharingLinkType>,
+    pub scope: Option<SharingLinkScope>,
+    #[serde(rename = "webUrl")]
+    pub web_url: Option<String>,
+    #[serde(rename = "preventsDownload")]
+    pub prevents_download: Option<bool>,
+}
+
+/// The permission returned by the createLink action.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SharingPermission {
+    pub id: String,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    pub link: Option<SharingLink>,
+    #[serde(rename = "hasPassword")]
+    pub has_password: Option<bool>,
+    #[serde(
+        rename = "expirationDateTime",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub expiration_date_time: Option<OffsetDateTime>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn create_link_with_expiration() {
+        let now = datetime!(2024-03-20 08:00 UTC);
+        let body = CreateLink::builder(SharingLinkType::View, SharingLinkScope::Anonymous)
+            .expiration(std::time::Duration::from_secs(7 * 24 * 60 * 60))
+            .build_at(now)
+            .unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "type": "view",
+                "scope": "anonymous",
+                "expirationDateTime": "2024-03-27T08:00:00Z"
+            }),
+            serde_json::to_value(&body).unwrap()
+        );
+        assert!(
+            CreateLink::builder(SharingLinkType::Edit, SharingLinkScope::Organization)
+                .expiration(datetime!(2024-03-19 08:00 UTC))
+                .build_at(now)
+                .is_err()
+        );
+    }
+}
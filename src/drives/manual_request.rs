@@ -1,5 +1,7 @@
 use crate::api_default_imports::*;
 use crate::drives::*;
+use futures::{Stream, StreamExt};
+use serde::Deserialize;
 
 impl DrivesIdApiClient {
     post!(
@@ -43,6 +45,83 @@ impl WorksheetsIdApiClient {
         body: true,
         params: address
     );
+
+    /// Get the range at the address, such as `A1:C20`, with its values.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let range = client
+    ///     .drive(DRIVE_ID)
+    ///     .item(ITEM_ID)
+    ///     .workbook()
+    ///     .worksheet("Sheet1")
+    ///     .get_range("A1:B20")
+    ///     .await?;
+    ///
+    /// let rows: Vec<(String, f64)> = range.rows_as()?;
+    /// ```
+    pub async fn get_range(&self, address: impl AsRef<str>) -> GraphResult<WorkbookRange> {
+        let response = self.get_range_object_by_address(address).send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+
+    /// Update the values, formulas or number format of the range at the address.
+    pub fn update_range(&self, address: impl AsRef<str>, update: &RangeUpdate) -> RequestHandler {
+        self.update_range_object_by_address(address, update)
+    }
+}
+
+impl WorksheetsApiClient {
+    /// Add a worksheet with the name to the workbook.
+    pub fn add_worksheet(&self, name: impl AsRef<str>) -> RequestHandler {
+        self.add(&serde_json::json!({ "name": name.as_ref() }))
+    }
+}
+
+impl WorkbookTablesRowsApiClient {
+    /// Add rows to the end of the table, or at the index of [TableRowsAdd].
+    pub fn add_rows(&self, rows: &TableRowsAdd) -> RequestHandler {
+        self.add(rows)
+    }
+
+    /// Stream every row of the table, requesting the next page of rows as needed.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use futures::StreamExt;
+    ///
+    /// let mut rows = client
+    ///     .drive(DRIVE_ID)
+    ///     .item(ITEM_ID)
+    ///     .workbook()
+    ///     .table("Table1")
+    ///     .rows()
+    ///     .list_rows_all()?;
+    ///
+    /// while let Some(row) = rows.next().await {
+    ///     let (item, price): (String, f64) = row?.value_as()?;
+    /// }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn list_rows_all(&self) -> GraphResult<impl Stream<Item = GraphResult<WorkbookTableRow>>> {
+        let pages = self.list_rows().paging().stream::<TableRowsPage>()?;
+
+        Ok(pages.flat_map(|page| {
+            let rows: Vec<GraphResult<WorkbookTableRow>> =
+                match page.map(|response| response.into_body()) {
+                    Ok(Ok(page)) => page.value.into_iter().map(Ok).collect(),
+                    Ok(Err(err)) => vec![Err(GraphFailure::from(err))],
+                    Err(err) => vec![Err(err)],
+                };
+            futures::stream::iter(rows)
+        }))
+    }
+}
+
+#[derive(Deserialize)]
+struct TableRowsPage {
+    #[serde(default = "Vec::new")]
+    value: Vec<WorkbookTableRow>,
 }
 
 impl WorkbookTablesIdApiClient {
@@ -1,4 +1,5 @@
 mod created_by_user;
+mod drive_sync;
 mod drives_items;
 mod drives_items_path;
 mod drives_list;
@@ -6,8 +7,10 @@ mod drives_list_content_types;
 mod last_modified_by_user;
 mod manual_request;
 mod request;
+mod upload_session_options;
 mod workbook;
 mod workbook_functions;
+mod workbook_range;
 mod workbook_tables;
 mod workbook_tables_columns;
 mod workbook_tables_rows;
@@ -24,6 +27,7 @@ mod worksheets_charts_series;
 mod worksheets_charts_title;
 
 pub use created_by_user::*;
+pub use drive_sync::*;
 pub use drives_items::*;
 pub use drives_items_path::*;
 pub use drives_list::*;
@@ -32,8 +36,10 @@ pub use last_modified_by_user::*;
 #[allow(unused_imports)]
 pub use manual_request::*;
 pub use request::*;
+pub use upload_session_options::*;
 pub use workbook::*;
 pub use workbook_functions::*;
+pub use workbook_range::*;
 pub use workbook_tables::*;
 pub use workbook_tables_columns::*;
 pub use workbook_tables_rows::*;
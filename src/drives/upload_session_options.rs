@@ -0,0 +1,171 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use graph_error::GraphFailure;
+use serde::{Deserialize, Serialize};
+
+/// What happens when an item with the same name already exists in the parent folder.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConflictBehavior {
+    /// The upload fails with a 409 Conflict response.
+    Fail,
+    /// The existing item is replaced.
+    Replace,
+    /// The uploaded item is given a unique name, such as `file 1.txt`.
+    Rename,
+}
+
+impl AsRef<str> for ConflictBehavior {
+    fn as_ref(&self) -> &str {
+        match self {
+            ConflictBehavior::Fail => "fail",
+            ConflictBehavior::Replace => "replace",
+            ConflictBehavior::Rename => "rename",
+        }
+    }
+}
+
+impl Display for ConflictBehavior {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for ConflictBehavior {
+    type Err = GraphFailure;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fail" => Ok(ConflictBehavior::Fail),
+            "replace" => Ok(ConflictBehavior::Replace),
+            "rename" => Ok(ConflictBehavior::Rename),
+            _ => Err(GraphFailure::invalid(
+                "conflict behavior - must be one of fail, replace, or rename",
+            )),
+        }
+    }
+}
+
+impl TryFrom<&str> for ConflictBehavior {
+    type Error = GraphFailure;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        ConflictBehavior::from_str(value)
+    }
+}
+
+/// The properties of the item that is uploaded with an upload session.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DriveItemUploadableProperties {
+    #[serde(
+        rename = "@microsoft.graph.conflictBehavior",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub conflict_behavior: Option<ConflictBehavior>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "fileSize", skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<u64>,
+}
+
+/// The request body of createUploadSession for a drive item.
+///
+/// When `defer_commit` is set the file is not created in the drive after the
+/// last byte range is uploaded. The upload has to be completed by sending an
+/// empty POST request to the upload url of the session.
+///
+/// [Create an upload session](https://learn.microsoft.com/en-us/graph/api/driveitem-createuploadsession?view=graph-rest-1.0)
+///
+/// # Example
+/// ```rust,ignore
+/// let options = UploadSessionOptions::new()
+///     .name("report.docx")
+///     .conflict_behavior(ConflictBehavior::Rename)
+///     .defer_commit(true);
+///
+/// let response = client
+///     .me()
+///     .drive()
+///     .item_by_path(":/Documents/report.docx:")
+///     .create_upload_session(&options)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UploadSessionOptions {
+    pub item: DriveItemUploadableProperties,
+    #[serde(rename = "deferCommit", skip_serializing_if = "Option::is_none")]
+    pub defer_commit: Option<bool>,
+}
+
+impl UploadSessionOptions {
+    pub fn new() -> UploadSessionOptions {
+        UploadSessionOptions::default()
+    }
+
+    /// The name of the item. Must match the file name in the request path when
+    /// the item is addressed by path.
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.item.name = Some(name.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.item.description = Some(description.to_string());
+        self
+    }
+
+    /// The size of the file in bytes. The upload fails with a 507 Insufficient
+    /// Storage response up front when the drive does not have enough space.
+    pub fn file_size(mut self, file_size: u64) -> Self {
+        self.item.file_size = Some(file_size);
+        self
+    }
+
+    pub fn conflict_behavior(mut self, conflict_behavior: ConflictBehavior) -> Self {
+        self.item.conflict_behavior = Some(conflict_behavior);
+        self
+    }
+
+    pub fn defer_commit(mut self, defer_commit: bool) -> Self {
+        self.defer_commit = Some(defer_commit);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_upload_session_options() {
+        let options = UploadSessionOptions::new()
+            .name("report.docx")
+            .file_size(1024)
+            .conflict_behavior("rename".parse().unwrap())
+            .defer_commit(true);
+
+        assert_eq!(
+            serde_json::json!({
+                "item": {
+                    "@microsoft.graph.conflictBehavior": "rename",
+                    "name": "report.docx",
+                    "fileSize": 1024
+                },
+                "deferCommit": true
+            }),
+            serde_json::to_value(&options).unwrap()
+        );
+
+        assert!(ConflictBehavior::from_str("overwrite").is_err());
+        assert!(
+            serde_json::from_value::<UploadSessionOptions>(serde_json::json!({
+                "item": { "@microsoft.graph.conflictBehavior": "overwrite" }
+            }))
+            .is_err()
+        );
+    }
+}
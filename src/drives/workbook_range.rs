@@ -0,0 +1,228 @@
+use graph_error::{GraphFailure, GraphResult};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Convert rows of cell values into the two-dimensional array of values used by
+/// workbook ranges and tables. Graph requires every row to have the same number of
+/// columns so shorter rows are padded with `fill`.
+fn rectangular_values<I, R, V>(rows: I, fill: Value) -> Vec<Vec<Value>>
+where
+    I: IntoIterator<Item = R>,
+    R: IntoIterator<Item = V>,
+    V: Into<Value>,
+{
+    let mut values: Vec<Vec<Value>> = rows
+        .into_iter()
+        .map(|row| row.into_iter().map(Into::into).collect())
+        .collect();
+    let columns = values.iter().map(Vec::len).max().unwrap_or_default();
+    for row in values.iter_mut() {
+        row.resize(columns, fill.clone());
+    }
+    values
+}
+
+/// A range of cells in a worksheet, such as the response of
+/// `range(address='A1:C20')` or `usedRange()`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkbookRange {
+    #[serde(default)]
+    pub address: String,
+    #[serde(default)]
+    pub address_local: String,
+    #[serde(default)]
+    pub row_count: usize,
+    #[serde(default)]
+    pub column_count: usize,
+    #[serde(default)]
+    pub row_index: usize,
+    #[serde(default)]
+    pub column_index: usize,
+    #[serde(default)]
+    pub values: Vec<Vec<Value>>,
+    #[serde(default)]
+    pub text: Vec<Vec<Value>>,
+    #[serde(default)]
+    pub formulas: Vec<Vec<Value>>,
+    #[serde(default)]
+    pub number_format: Vec<Vec<Value>>,
+}
+
+impl WorkbookRange {
+    /// Deserialize each row of values, such as into a tuple or a struct with
+    /// fields in the order of the columns.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let range: WorkbookRange = response.json().await?;
+    /// let rows: Vec<(String, f64)> = range.rows_as()?;
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn rows_as<T: DeserializeOwned>(&self) -> GraphResult<Vec<T>> {
+        self.values
+            .iter()
+            .map(|row| {
+                serde_json::from_value(Value::Array(row.clone())).map_err(GraphFailure::from)
+            })
+            .collect()
+    }
+}
+
+/// The request body to update the values, formulas or number format of a range.
+///
+/// The values must have the same number of rows and columns as the range. A null
+/// value leaves the cell unchanged.
+///
+/// # Example
+/// ```rust,ignore
+/// let update = RangeUpdate::values([
+///     vec![json!("Item"), json!("Price")],
+///     vec![json!("Coffee"), json!(4.5)],
+/// ]);
+///
+/// let response = client
+///     .drive(DRIVE_ID)
+///     .item(ITEM_ID)
+///     .workbook()
+///     .worksheet("Sheet1")
+///     .update_range("A1:B2", &update)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RangeUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<Vec<Value>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formulas: Option<Vec<Vec<Value>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_format: Option<Vec<Vec<Value>>>,
+}
+
+impl RangeUpdate {
+    /// Update the values of the range. Rows with fewer columns than the widest row
+    /// are padded with null, which leaves those cells unchanged.
+    pub fn values<I, R, V>(rows: I) -> RangeUpdate
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        RangeUpdate {
+            values: Some(rectangular_values(rows, Value::Null)),
+            ..Default::default()
+        }
+    }
+
+    /// Formulas such as `=SUM(B2:B10)`. Rows are padded with null as with values.
+    pub fn formulas<I, R, V>(mut self, rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        self.formulas = Some(rectangular_values(rows, Value::Null));
+        self
+    }
+
+    /// Number formats such as `0.00` or `mm/dd/yyyy`. Rows are padded with null as with values.
+    pub fn number_format<I, R, V>(mut self, rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        self.number_format = Some(rectangular_values(rows, Value::Null));
+        self
+    }
+}
+
+/// The request body to add rows to the end of a table, or at an index of the table.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TableRowsAdd {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+    pub values: Vec<Vec<Value>>,
+}
+
+impl TableRowsAdd {
+    /// Rows with fewer columns than the widest row are padded with empty cells.
+    /// Every row must have the same number of columns as the table.
+    pub fn new<I, R, V>(rows: I) -> TableRowsAdd
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        TableRowsAdd {
+            index: None,
+            values: rectangular_values(rows, Value::String(String::new())),
+        }
+    }
+
+    /// Insert the rows at the zero-based index of the table instead of at the end.
+    pub fn index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+}
+
+/// A row of a workbook table.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkbookTableRow {
+    #[serde(default)]
+    pub index: usize,
+    #[serde(default)]
+    pub values: Vec<Vec<Value>>,
+}
+
+impl WorkbookTableRow {
+    /// Deserialize the values of the row, such as into a tuple or a struct with
+    /// fields in the order of the columns.
+    #[allow(clippy::result_large_err)]
+    pub fn value_as<T: DeserializeOwned>(&self) -> GraphResult<T> {
+        let row = self.values.first().cloned().unwrap_or_default();
+        Ok(serde_json::from_value(Value::Array(row))?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn jagged_rows() {
+        let update = RangeUpdate::values([vec![json!("Item"), json!("Price")], vec![json!("Tax")]])
+            .number_format([["@", "0.00"]]);
+        assert_eq!(
+            json!({
+                "values": [["Item", "Price"], ["Tax", null]],
+                "numberFormat": [["@", "0.00"]]
+            }),
+            serde_json::to_value(&update).unwrap()
+        );
+
+        let rows = TableRowsAdd::new([vec![json!(1), json!(2), json!(3)], vec![json!(4)]]).index(0);
+        assert_eq!(
+            json!({ "index": 0, "values": [[1, 2, 3], [4, "", ""]] }),
+            serde_json::to_value(&rows).unwrap()
+        );
+
+        let range: WorkbookRange = serde_json::from_value(json!({
+            "address": "Sheet1!A1:B2",
+            "rowCount": 2,
+            "columnCount": 2,
+            "values": [["Coffee", 4.5], ["Tea", 3]]
+        }))
+        .unwrap();
+        let rows: Vec<(String, f64)> = range.rows_as().unwrap();
+        assert_eq!(
+            vec![("Coffee".to_string(), 4.5), ("Tea".to_string(), 3.0)],
+            rows
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::OffsetDateTime;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EducationItemBody {
+    pub content: String,
+    /// The type of the content, `text` or `html`.
+    #[serde(rename = "contentType")]
+    pub content_type: String,
+}
+
+impl EducationItemBody {
+    pub fn text(content: impl ToString) -> EducationItemBody {
+        EducationItemBody {
+            content: content.to_string(),
+            content_type: "text".into(),
+        }
+    }
+
+    pub fn html(content: impl ToString) -> EducationItemBody {
+        EducationItemBody {
+            content: content.to_string(),
+            content_type: "html".into(),
+        }
+    }
+}
+
+/// How an assignment is graded.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@odata.type")]
+pub enum EducationAssignmentGradeType {
+    #[serde(rename = "#microsoft.graph.educationAssignmentPointsGradeType")]
+    Points {
+        #[serde(rename = "maxPoints")]
+        max_points: f32,
+    },
+}
+
+/// Who an assignment is assigned to when it is published.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@odata.type")]
+pub enum EducationAssignmentRecipient {
+    /// All students in the class including students added after the assignment is published.
+    #[serde(rename = "#microsoft.graph.educationAssignmentClassRecipient")]
+    Class,
+    #[serde(rename = "#microsoft.graph.educationAssignmentIndividualRecipient")]
+    Individual {
+        #[serde(default)]
+        recipients: Vec<String>,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// The body used to create or update an educationAssignment and the assignment
+/// returned in the response.
+///
+/// Assignments are created as drafts. Call `publish` on the assignment to assign
+/// it to the students given by [EducationAssignment::assign_to].
+///
+/// # Example
+/// ```rust,ignore
+/// let assignment = EducationAssignment::builder("Reading Test 09.14")
+///     .instructions(EducationItemBody::text("Read chapter 4"))
+///     .due_date_time(datetime!(2023-09-30 00:00 UTC))
+///     .max_points(50.0)
+///     .assign_to_class()
+///     .build();
+///
+/// let assignment: EducationAssignment = client
+///     .education()
+///     .class(CLASS_ID)
+///     .assignments()
+///     .create_assignments(&assignment)
+///     .send()
+///     .await?
+///     .json()
+///     .await?;
+///
+/// client
+///     .education()
+///     .class(CLASS_ID)
+///     .assignment(assignment.id.unwrap())
+///     .publish()
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EducationAssignment {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "classId", skip_serializing)]
+    pub class_id: Option<String>,
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<EducationItemBody>,
+    #[serde(
+        rename = "dueDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub due_date_time: Option<OffsetDateTime>,
+    #[serde(
+        rename = "closeDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub close_date_time: Option<OffsetDateTime>,
+    #[serde(
+        rename = "assignDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub assign_date_time: Option<OffsetDateTime>,
+    #[serde(
+        rename = "allowLateSubmissions",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub allow_late_submissions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grading: Option<EducationAssignmentGradeType>,
+    #[serde(rename = "assignTo", skip_serializing_if = "Option::is_none")]
+    pub assign_to: Option<EducationAssignmentRecipient>,
+    #[serde(skip_serializing)]
+    pub status: Option<String>,
+    #[serde(rename = "resourcesFolderUrl", skip_serializing)]
+    pub resources_folder_url: Option<String>,
+    #[serde(rename = "webUrl", skip_serializing)]
+    pub web_url: Option<String>,
+}
+
+impl EducationAssignment {
+    pub fn builder(display_name: impl ToString) -> EducationAssignmentBuilder {
+        EducationAssignmentBuilder {
+            assignment: EducationAssignment {
+                display_name: Some(display_name.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct EducationAssignmentBuilder {
+    assignment: EducationAssignment,
+}
+
+impl EducationAssignmentBuilder {
+    pub fn instructions(mut self, instructions: EducationItemBody) -> Self {
+        self.assignment.instructions = Some(instructions);
+        self
+    }
+
+    /// The date and time the assignment is due.
+    pub fn due_date_time(mut self, due_date_time: OffsetDateTime) -> Self {
+        self.assignment.due_date_time = Some(due_date_time);
+        self
+    }
+
+    /// The date and time after which submissions are no longer accepted.
+    pub fn close_date_time(mut self, close_date_time: OffsetDateTime) -> Self {
+        self.assignment.close_date_time = Some(close_date_time);
+        self
+    }
+
+    pub fn allow_late_submissions(mut self, allow_late_submissions: bool) -> Self {
+        self.assignment.allow_late_submissions = Some(allow_late_submissions);
+        self
+    }
+
+    /// Grade the assignment with points out of `max_points`.
+    pub fn max_points(mut self, max_points: f32) -> Self {
+        self.assignment.grading = Some(EducationAssignmentGradeType::Points { max_points });
+        self
+    }
+
+    /// Assign to every student in the class when published.
+    pub fn assign_to_class(mut self) -> Self {
+        self.assignment.assign_to = Some(EducationAssignmentRecipient::Class);
+        self
+    }
+
+    /// Assign to the students with the given user ids when published.
+    pub fn assign_to_students<T: ToString>(mut self, user_ids: &[T]) -> Self {
+        self.assignment.assign_to = Some(EducationAssignmentRecipient::Individual {
+            recipients: user_ids.iter().map(|id| id.to_string()).collect(),
+        });
+        self
+    }
+
+    pub fn build(self) -> EducationAssignment {
+        self.assignment
+    }
+}
+
+/// A resource of an assignment or submission using the `@odata.type` of the resource.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@odata.type")]
+pub enum EducationResource {
+    #[serde(rename = "#microsoft.graph.educationFileResource")]
+    File {
+        #[serde(rename = "displayName")]
+        display_name: String,
+        #[serde(rename = "fileUrl")]
+        file_url: String,
+    },
+    #[serde(rename = "#microsoft.graph.educationLinkResource")]
+    Link {
+        #[serde(rename = "displayName")]
+        display_name: String,
+        link: String,
+    },
+    #[serde(rename = "#microsoft.graph.educationWordResource")]
+    Word {
+        #[serde(rename = "displayName")]
+        display_name: String,
+        #[serde(rename = "fileUrl")]
+        file_url: String,
+    },
+    #[serde(rename = "#microsoft.graph.educationExcelResource")]
+    Excel {
+        #[serde(rename = "displayName")]
+        display_name: String,
+        #[serde(rename = "fileUrl")]
+        file_url: String,
+    },
+    #[serde(rename = "#microsoft.graph.educationPowerPointResource")]
+    PowerPoint {
+        #[serde(rename = "displayName")]
+        display_name: String,
+        #[serde(rename = "fileUrl")]
+        file_url: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl EducationResource {
+    /// A file resource for a file in the resources folder of the assignment.
+    pub fn file(display_name: impl ToString, file_url: impl ToString) -> EducationResource {
+        EducationResource::File {
+            display_name: display_name.to_string(),
+            file_url: file_url.to_string(),
+        }
+    }
+
+    pub fn link(display_name: impl ToString, link: impl ToString) -> EducationResource {
+        EducationResource::Link {
+            display_name: display_name.to_string(),
+            link: link.to_string(),
+        }
+    }
+}
+
+/// The body of `create_resources` for an assignment.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EducationAssignmentResource {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    /// Copy the resource into each submission so students can edit their own copy.
+    #[serde(rename = "distributeForStudentWork")]
+    pub distribute_for_student_work: bool,
+    pub resource: EducationResource,
+}
+
+impl EducationAssignmentResource {
+    pub fn new(resource: EducationResource) -> EducationAssignmentResource {
+        EducationAssignmentResource {
+            id: None,
+            distribute_for_student_work: false,
+            resource,
+        }
+    }
+
+    pub fn distribute_for_student_work(mut self, distribute: bool) -> Self {
+        self.distribute_for_student_work = distribute;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_assignment_and_resource() {
+        let assignment = EducationAssignment::builder("Reading Test")
+            .instructions(EducationItemBody::text("Read chapter 4"))
+            .max_points(50.0)
+            .assign_to_class()
+            .build();
+
+        assert_eq!(
+            serde_json::json!({
+                "displayName": "Reading Test",
+                "instructions": { "content": "Read chapter 4", "contentType": "text" },
+                "grading": {
+                    "@odata.type": "#microsoft.graph.educationAssignmentPointsGradeType",
+                    "maxPoints": 50.0
+                },
+                "assignTo": { "@odata.type": "#microsoft.graph.educationAssignmentClassRecipient" }
+            }),
+            serde_json::to_value(&assignment).unwrap()
+        );
+
+        let resource = EducationAssignmentResource::new(EducationResource::link(
+            "Syllabus",
+            "https://contoso.com/syllabus",
+        ));
+        assert_eq!(
+            serde_json::json!({
+                "distributeForStudentWork": false,
+                "resource": {
+                    "@odata.type": "#microsoft.graph.educationLinkResource",
+                    "displayName": "Syllabus",
+                    "link": "https://contoso.com/syllabus"
+                }
+            }),
+            serde_json::to_value(&resource).unwrap()
+        );
+    }
+}
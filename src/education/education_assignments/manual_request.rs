@@ -0,0 +1,116 @@
+use serde::Deserialize;
+
+use crate::api_default_imports::*;
+use crate::education::{
+    EducationAssignment, EducationAssignmentResource, EducationAssignmentsIdApiClient,
+    EducationResource,
+};
+
+#[derive(Deserialize)]
+struct UploadedDriveItem {
+    id: String,
+}
+
+impl EducationAssignmentsIdApiClient {
+    /// Upload a file to the resources folder of the assignment and add it to the
+    /// resources of the assignment. The resources folder is set up if it does not
+    /// already exist.
+    ///
+    /// The file is uploaded in a single request and should be less than 4 MB.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let file = std::fs::File::open("./worksheet.pdf")?;
+    /// let resource = client
+    ///     .education()
+    ///     .class(CLASS_ID)
+    ///     .assignment(ASSIGNMENT_ID)
+    ///     .upload_resource("worksheet.pdf", file, true)
+    ///     .await?;
+    /// ```
+    pub async fn upload_resource<R: std::io::Read>(
+        &self,
+        file_name: &str,
+        reader: R,
+        distribute_for_student_work: bool,
+    ) -> GraphResult<EducationAssignmentResource> {
+        let response = self.set_up_resources_folder().send().await?;
+        let assignment: EducationAssignment =
+            Paging::success_response(response).await?.json().await?;
+        let folder_url = assignment
+            .resources_folder_url
+            .ok_or_else(|| GraphFailure::not_found("resourcesFolderUrl not found in assignment"))?;
+
+        let upload_url = upload_url(&folder_url, file_name)?;
+        let response = RequestHandler::new(
+            self.client.clone(),
+            RequestComponents::new(
+                self.resource_config.resource_identity,
+                upload_url,
+                Method::PUT,
+            ),
+            None,
+            Some(BodyRead::from_read(reader)?),
+        )
+        .send()
+        .await?;
+        let item: UploadedDriveItem = Paging::success_response(response).await?.json().await?;
+
+        let resource = EducationAssignmentResource::new(EducationResource::file(
+            file_name,
+            resource_file_url(&folder_url, &item.id),
+        ))
+        .distribute_for_student_work(distribute_for_student_work);
+
+        let response = self.create_resources(&resource).send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+}
+
+// The url to upload the file to in the resources folder, {folder-url}:/{file-name}:/content,
+// with the file name percent encoded.
+fn upload_url(folder_url: &str, file_name: &str) -> Result<Url, url::ParseError> {
+    let mut url = Url::parse(&format!("{}:", folder_url.trim_end_matches('/')))?;
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.push(&format!("{file_name}:")).push("content");
+    }
+    Ok(url)
+}
+
+// The url of an item in the same drive as the resources folder such as
+// https://graph.microsoft.com/v1.0/drives/{drive-id}/items/{item-id}.
+fn resource_file_url(folder_url: &str, item_id: &str) -> String {
+    match folder_url.rfind("/items/") {
+        Some(index) => format!("{}/items/{}", &folder_url[..index], item_id),
+        None => format!("{}/items/{}", folder_url.trim_end_matches('/'), item_id),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn upload_url_encodes_file_name() {
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/drives/b!abc/items/01FOLDER:/week%201%20%2350%25%3F.pdf:/content",
+            upload_url(
+                "https://graph.microsoft.com/v1.0/drives/b!abc/items/01FOLDER/",
+                "week 1 #50%?.pdf"
+            )
+            .unwrap()
+            .as_str()
+        );
+    }
+
+    #[test]
+    fn resource_file_url_uses_folder_drive() {
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/drives/b!abc/items/01NEW",
+            resource_file_url(
+                "https://graph.microsoft.com/v1.0/drives/b!abc/items/01FOLDER",
+                "01NEW"
+            )
+        );
+    }
+}
@@ -1,3 +1,8 @@
+mod education_assignment;
+mod manual_request;
 mod request;
 
+pub use education_assignment::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use crate::education::EducationItemBody;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EducationAssignmentPointsGrade {
+    pub points: f32,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EducationFeedback {
+    pub text: EducationItemBody,
+}
+
+/// An outcome of a submission using the `@odata.type` of the outcome.
+///
+/// Outcomes are created with the submission. Update the points or feedback
+/// outcome with `update_outcomes` and call `submissions_return` to release
+/// it to the student.
+///
+/// # Example
+/// ```rust,ignore
+/// let response = client
+///     .education()
+///     .class(CLASS_ID)
+///     .assignment(ASSIGNMENT_ID)
+///     .submission(SUBMISSION_ID)
+///     .update_outcomes(OUTCOME_ID, &EducationOutcome::points(45.0))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@odata.type")]
+pub enum EducationOutcome {
+    #[serde(rename = "#microsoft.graph.educationPointsOutcome")]
+    Points {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        points: Option<EducationAssignmentPointsGrade>,
+        #[serde(rename = "publishedPoints", skip_serializing)]
+        published_points: Option<EducationAssignmentPointsGrade>,
+    },
+    #[serde(rename = "#microsoft.graph.educationFeedbackOutcome")]
+    Feedback {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        feedback: Option<EducationFeedback>,
+        #[serde(rename = "publishedFeedback", skip_serializing)]
+        published_feedback: Option<EducationFeedback>,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl EducationOutcome {
+    pub fn points(points: f32) -> EducationOutcome {
+        EducationOutcome::Points {
+            points: Some(EducationAssignmentPointsGrade { points }),
+            published_points: None,
+        }
+    }
+
+    pub fn feedback(text: impl ToString) -> EducationOutcome {
+        EducationOutcome::Feedback {
+            feedback: Some(EducationFeedback {
+                text: EducationItemBody::text(text),
+            }),
+            published_feedback: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn outcomes_by_odata_type() {
+        assert_eq!(
+            serde_json::json!({
+                "@odata.type": "#microsoft.graph.educationFeedbackOutcome",
+                "feedback": {
+                    "text": { "content": "Great work", "contentType": "text" }
+                }
+            }),
+            serde_json::to_value(EducationOutcome::feedback("Great work")).unwrap()
+        );
+
+        let outcomes: Vec<EducationOutcome> = serde_json::from_value(serde_json::json!([
+            {
+                "@odata.type": "#microsoft.graph.educationPointsOutcome",
+                "id": "1",
+                "points": { "points": 45.0 },
+                "publishedPoints": null
+            },
+            {
+                "@odata.type": "#microsoft.graph.educationRubricOutcome",
+                "id": "2"
+            }
+        ]))
+        .unwrap();
+        assert_eq!(EducationOutcome::points(45.0), outcomes[0]);
+        assert_eq!(EducationOutcome::Other, outcomes[1]);
+    }
+}
@@ -1,3 +1,5 @@
+mod education_outcome;
 mod request;
 
+pub use education_outcome::*;
 pub use request::*;
@@ -0,0 +1,434 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+/// The body used to create or update an externalConnection and the connection
+/// returned in the response.
+///
+/// # Example
+/// ```rust,ignore
+/// let connection = ExternalConnection::new("contosohr", "Contoso HR")
+///     .description("Connection to index Contoso HR system");
+///
+/// let response = client
+///     .external()
+///     .connections()
+///     .create_connections(&connection)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ExternalConnection {
+    /// The id of the connection. Must be 3 to 32 alphanumeric characters.
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// One of `draft`, `ready`, `obsolete` or `limitExceeded`.
+    #[serde(skip_serializing)]
+    pub state: Option<String>,
+}
+
+impl ExternalConnection {
+    pub fn new(id: impl ToString, name: impl ToString) -> ExternalConnection {
+        ExternalConnection {
+            id: id.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+}
+
+/// The type of a property in the schema of a connection.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyType {
+    String,
+    Int64,
+    Double,
+    DateTime,
+    Boolean,
+    StringCollection,
+    Int64Collection,
+    DoubleCollection,
+    DateTimeCollection,
+}
+
+/// Semantic labels that let Microsoft Search understand a property, such as the
+/// title or the url of an item.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyLabel {
+    Title,
+    Url,
+    CreatedBy,
+    LastModifiedBy,
+    Authors,
+    CreatedDateTime,
+    LastModifiedDateTime,
+    FileName,
+    FileExtension,
+    IconUrl,
+    ContainerName,
+    ContainerUrl,
+}
+
+/// A property in the schema of a connection.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaProperty {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub property_type: PropertyType,
+    #[serde(default)]
+    pub is_searchable: bool,
+    #[serde(default)]
+    pub is_retrievable: bool,
+    #[serde(default)]
+    pub is_queryable: bool,
+    #[serde(default)]
+    pub is_refinable: bool,
+    #[serde(default)]
+    pub is_exact_match_required: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<PropertyLabel>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+}
+
+impl SchemaProperty {
+    pub fn new(name: impl ToString, property_type: PropertyType) -> SchemaProperty {
+        SchemaProperty {
+            name: name.to_string(),
+            property_type,
+            is_searchable: false,
+            is_retrievable: false,
+            is_queryable: false,
+            is_refinable: false,
+            is_exact_match_required: false,
+            labels: vec![],
+            aliases: vec![],
+        }
+    }
+
+    /// The content of the property is added to the full text index.
+    pub fn searchable(mut self) -> Self {
+        self.is_searchable = true;
+        self
+    }
+
+    /// The property can be returned in search results.
+    pub fn retrievable(mut self) -> Self {
+        self.is_retrievable = true;
+        self
+    }
+
+    /// The property can be used in KQL queries.
+    pub fn queryable(mut self) -> Self {
+        self.is_queryable = true;
+        self
+    }
+
+    /// The property can be used as a refiner to filter search results.
+    /// Refinable properties cannot be searchable.
+    pub fn refinable(mut self) -> Self {
+        self.is_refinable = true;
+        self
+    }
+
+    pub fn exact_match_required(mut self) -> Self {
+        self.is_exact_match_required = true;
+        self
+    }
+
+    pub fn label(mut self, label: PropertyLabel) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    pub fn alias(mut self, alias: impl ToString) -> Self {
+        self.aliases.push(alias.to_string());
+        self
+    }
+}
+
+/// The schema of a connection. Registering the schema is a long running operation
+/// and the response has the operation in the Location header.
+///
+/// # Example
+/// ```rust,ignore
+/// let schema = ConnectionSchema::new(vec![
+///     SchemaProperty::new("ticketTitle", PropertyType::String)
+///         .searchable()
+///         .retrievable()
+///         .label(PropertyLabel::Title),
+///     SchemaProperty::new("priority", PropertyType::String)
+///         .queryable()
+///         .retrievable()
+///         .refinable(),
+/// ]);
+///
+/// let response = client
+///     .external()
+///     .connection("contosohr")
+///     .update_schema(&schema)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionSchema {
+    pub base_type: String,
+    pub properties: Vec<SchemaProperty>,
+}
+
+impl ConnectionSchema {
+    pub fn new(properties: Vec<SchemaProperty>) -> ConnectionSchema {
+        ConnectionSchema {
+            base_type: "microsoft.graph.externalItem".into(),
+            properties,
+        }
+    }
+}
+
+/// Who the access control entry applies to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AclType {
+    User,
+    Group,
+    Everyone,
+    EveryoneExceptGuests,
+    ExternalGroup,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AccessType {
+    Grant,
+    Deny,
+}
+
+/// An access control entry of an external item.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Acl {
+    #[serde(rename = "type")]
+    pub acl_type: AclType,
+    /// The id of the user or group, or the tenant id for everyone.
+    pub value: String,
+    pub access_type: AccessType,
+}
+
+impl Acl {
+    pub fn grant(acl_type: AclType, value: impl ToString) -> Acl {
+        Acl {
+            acl_type,
+            value: value.to_string(),
+            access_type: AccessType::Grant,
+        }
+    }
+
+    pub fn deny(acl_type: AclType, value: impl ToString) -> Acl {
+        Acl {
+            acl_type,
+            value: value.to_string(),
+            access_type: AccessType::Deny,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExternalItemContentType {
+    Text,
+    Html,
+}
+
+/// The full text content of an external item that is indexed for search.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ExternalItemContent {
+    #[serde(rename = "type")]
+    pub content_type: ExternalItemContentType,
+    pub value: String,
+}
+
+/// An item ingested into a connection. The properties must match the schema of
+/// the connection.
+///
+/// # Example
+/// ```rust,ignore
+/// let item = ExternalItem::new(vec![Acl::grant(AclType::Everyone, TENANT_ID)])
+///     .property("ticketTitle", "Printer is on fire")
+///     .property("priority", "high")
+///     .text_content("The printer on the third floor is on fire.");
+///
+/// let response = client
+///     .external()
+///     .connection("contosohr")
+///     .item("TSP228082938")
+///     .update_items(&item)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExternalItem {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    pub acl: Vec<Acl>,
+    #[serde(default)]
+    pub properties: BTreeMap<String, Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<ExternalItemContent>,
+}
+
+impl ExternalItem {
+    pub fn new(acl: Vec<Acl>) -> ExternalItem {
+        ExternalItem {
+            acl,
+            ..Default::default()
+        }
+    }
+
+    pub fn property(mut self, name: impl ToString, value: impl Into<Value>) -> Self {
+        self.properties.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// A property of type `stringCollection` or another collection type. Collection
+    /// properties must be annotated with their type such as `Collection(String)`.
+    pub fn collection_property(
+        mut self,
+        name: impl ToString,
+        odata_type: impl ToString,
+        values: Vec<Value>,
+    ) -> Self {
+        let name = name.to_string();
+        self.properties.insert(
+            format!("{name}@odata.type"),
+            Value::String(odata_type.to_string()),
+        );
+        self.properties.insert(name, Value::Array(values));
+        self
+    }
+
+    pub fn text_content(mut self, value: impl ToString) -> Self {
+        self.content = Some(ExternalItemContent {
+            content_type: ExternalItemContentType::Text,
+            value: value.to_string(),
+        });
+        self
+    }
+
+    pub fn html_content(mut self, value: impl ToString) -> Self {
+        self.content = Some(ExternalItemContent {
+            content_type: ExternalItemContentType::Html,
+            value: value.to_string(),
+        });
+        self
+    }
+}
+
+/// A long running connectionOperation such as registering the schema of a connection.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ConnectionOperation {
+    pub id: String,
+    /// One of `unspecified`, `inprogress`, `completed` or `failed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<Value>,
+}
+
+impl ConnectionOperation {
+    pub fn is_completed(&self) -> bool {
+        self.status.as_deref() == Some("completed")
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.status.as_deref() == Some("failed")
+    }
+
+    /// The id of the operation in the Location header of an accepted response such as
+    /// `/external/connections/contosohr/operations/{operation-id}`.
+    pub fn id_from_location(location: &Url) -> Option<String> {
+        location
+            .path_segments()?
+            .next_back()
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_schema_and_item() {
+        let schema =
+            ConnectionSchema::new(vec![SchemaProperty::new("title", PropertyType::String)
+                .searchable()
+                .retrievable()
+                .label(PropertyLabel::Title)]);
+        assert_eq!(
+            serde_json::json!({
+                "baseType": "microsoft.graph.externalItem",
+                "properties": [{
+                    "name": "title",
+                    "type": "string",
+                    "isSearchable": true,
+                    "isRetrievable": true,
+                    "isQueryable": false,
+                    "isRefinable": false,
+                    "isExactMatchRequired": false,
+                    "labels": ["title"]
+                }]
+            }),
+            serde_json::to_value(&schema).unwrap()
+        );
+
+        let item = ExternalItem::new(vec![
+            Acl::grant(AclType::EveryoneExceptGuests, "tenant-id"),
+            Acl::deny(AclType::User, "user-id"),
+        ])
+        .property("title", "Printer is on fire")
+        .collection_property(
+            "assignees",
+            "Collection(String)",
+            vec!["alex".into(), "megan".into()],
+        )
+        .text_content("The printer is on fire.");
+        assert_eq!(
+            serde_json::json!({
+                "acl": [
+                    { "type": "everyoneExceptGuests", "value": "tenant-id", "accessType": "grant" },
+                    { "type": "user", "value": "user-id", "accessType": "deny" }
+                ],
+                "properties": {
+                    "title": "Printer is on fire",
+                    "assignees@odata.type": "Collection(String)",
+                    "assignees": ["alex", "megan"]
+                },
+                "content": { "type": "text", "value": "The printer is on fire." }
+            }),
+            serde_json::to_value(&item).unwrap()
+        );
+
+        let location = Url::parse(
+            "https://graph.microsoft.com/v1.0/external/connections/contosohr/operations/1.neu.0903C8BB",
+        )
+        .unwrap();
+        assert_eq!(
+            Some("1.neu.0903C8BB".to_string()),
+            ConnectionOperation::id_from_location(&location)
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use crate::api_default_imports::*;
+use crate::external::{ConnectionOperation, ExternalConnectionsIdApiClient};
+
+impl ExternalConnectionsIdApiClient {
+    /// Poll the connection operation every `poll_interval` until it has completed and
+    /// return the operation. Returns an error if the operation failed or has not
+    /// completed before `timeout`.
+    ///
+    /// Registering the schema of a connection returns 202 Accepted with the operation
+    /// in the Location header. Items cannot be ingested until the schema is registered.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let connection = client.external().connection("contosohr");
+    ///
+    /// let response = connection.update_schema(&schema).send_typed().await?;
+    /// if let TypedResponse::Accepted(accepted) = response {
+    ///     let operation_id = accepted
+    ///         .location()
+    ///         .and_then(ConnectionOperation::id_from_location)
+    ///         .unwrap();
+    ///     connection
+    ///         .wait_for_operation(&operation_id, Duration::from_secs(60), Duration::from_secs(3600))
+    ///         .await?;
+    /// }
+    /// ```
+    pub async fn wait_for_operation(
+        &self,
+        operation_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<ConnectionOperation> {
+        let location = self.get_operations(operation_id).url();
+        let body = AcceptedResponse::from_location(self.client.clone(), location)
+            .wait_for_completion(poll_interval, timeout)
+            .await?;
+        Ok(serde_json::from_value(body)?)
+    }
+}
@@ -0,0 +1,8 @@
+mod external_connection;
+mod manual_request;
+mod request;
+
+pub use external_connection::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
+pub use request::*;
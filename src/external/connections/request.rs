@@ -0,0 +1,72 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+use crate::external::*;
+
+api_client!(
+    ExternalConnectionsApiClient,
+    ExternalConnectionsIdApiClient,
+    ResourceIdentity::ExternalConnections
+);
+
+impl ExternalConnectionsApiClient {
+    post!(
+        doc: "Create connection",
+        name: create_connections,
+        path: "/connections",
+        body: true
+    );
+    get!(
+        doc: "List connections",
+        name: list_connections,
+        path: "/connections"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_connections_count,
+        path: "/connections/$count"
+    );
+}
+
+impl ExternalConnectionsIdApiClient {
+    api_client_link_id!(item, ExternalConnectionsItemsIdApiClient);
+
+    delete!(
+        doc: "Delete externalConnection",
+        name: delete_connections,
+        path: "/connections/{{RID}}"
+    );
+    get!(
+        doc: "Get externalConnection",
+        name: get_connections,
+        path: "/connections/{{RID}}"
+    );
+    patch!(
+        doc: "Update externalConnection",
+        name: update_connections,
+        path: "/connections/{{RID}}",
+        body: true
+    );
+    get!(
+        doc: "List connectionOperations",
+        name: list_operations,
+        path: "/connections/{{RID}}/operations"
+    );
+    get!(
+        doc: "Get connectionOperation",
+        name: get_operations,
+        path: "/connections/{{RID}}/operations/{{id}}",
+        params: connection_operation_id
+    );
+    get!(
+        doc: "Get schema",
+        name: get_schema,
+        path: "/connections/{{RID}}/schema"
+    );
+    patch!(
+        doc: "Create or update schema",
+        name: update_schema,
+        path: "/connections/{{RID}}/schema",
+        body: true
+    );
+}
@@ -0,0 +1,28 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    ExternalConnectionsItemsApiClient,
+    ExternalConnectionsItemsIdApiClient,
+    ResourceIdentity::ExternalConnectionsItems
+);
+
+impl ExternalConnectionsItemsIdApiClient {
+    delete!(
+        doc: "Delete externalItem",
+        name: delete_items,
+        path: "/items/{{RID}}"
+    );
+    get!(
+        doc: "Get externalItem",
+        name: get_items,
+        path: "/items/{{RID}}"
+    );
+    put!(
+        doc: "Create or replace externalItem",
+        name: update_items,
+        path: "/items/{{RID}}",
+        body: true
+    );
+}
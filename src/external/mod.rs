@@ -0,0 +1,7 @@
+mod connections;
+mod connections_items;
+mod request;
+
+pub use connections::*;
+pub use connections_items::*;
+pub use request::*;
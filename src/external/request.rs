@@ -0,0 +1,11 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+use crate::external::*;
+
+api_client!(ExternalApiClient, ResourceIdentity::External);
+
+impl ExternalApiClient {
+    api_client_link!(connections, ExternalConnectionsApiClient);
+    api_client_link_id!(connection, ExternalConnectionsIdApiClient);
+}
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ManagedGroupTypes {
+    All,
+    Selected,
+    None,
+}
+
+/// The body used to create or update a groupLifecyclePolicy.
+///
+/// # Example
+/// ```rust,ignore
+/// let policy = GroupLifecyclePolicy::builder()
+///     .group_lifetime_in_days(180)
+///     .managed_group_types(ManagedGroupTypes::All)
+///     .alternate_notification_emails(&["admin@contoso.com"])
+///     .build();
+///
+/// let response = client
+///     .group_lifecycle_policies()
+///     .create_group_lifecycle_policy(&policy)
+///     .send()
+///     .await?;
+///
+/// // Renew a group before it expires.
+/// let response = client
+///     .group_lifecycle_policies()
+///     .renew_group(&GroupLifecyclePolicyGroup::new(GROUP_ID))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GroupLifecyclePolicy {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(
+        rename = "groupLifetimeInDays",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub group_lifetime_in_days: Option<i32>,
+    #[serde(rename = "managedGroupTypes", skip_serializing_if = "Option::is_none")]
+    pub managed_group_types: Option<ManagedGroupTypes>,
+    #[serde(
+        rename = "alternateNotificationEmails",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub alternate_notification_emails: Option<String>,
+}
+
+impl GroupLifecyclePolicy {
+    pub fn builder() -> GroupLifecyclePolicyBuilder {
+        GroupLifecyclePolicyBuilder::default()
+    }
+
+    /// The notification emails given in alternateNotificationEmails.
+    pub fn notification_emails(&self) -> Vec<&str> {
+        self.alternate_notification_emails
+            .as_deref()
+            .map(|emails| {
+                emails
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|email| !email.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct GroupLifecyclePolicyBuilder {
+    policy: GroupLifecyclePolicy,
+}
+
+impl GroupLifecyclePolicyBuilder {
+    /// Number of days before a group expires and needs to be renewed.
+    pub fn group_lifetime_in_days(mut self, days: i32) -> Self {
+        self.policy.group_lifetime_in_days = Some(days);
+        self
+    }
+
+    pub fn managed_group_types(mut self, managed_group_types: ManagedGroupTypes) -> Self {
+        self.policy.managed_group_types = Some(managed_group_types);
+        self
+    }
+
+    /// Email addresses notified when a group has no owners.
+    pub fn alternate_notification_emails<T: AsRef<str>>(mut self, emails: &[T]) -> Self {
+        let emails: Vec<&str> = emails.iter().map(|email| email.as_ref()).collect();
+        self.policy.alternate_notification_emails = Some(emails.join(";"));
+        self
+    }
+
+    pub fn build(self) -> GroupLifecyclePolicy {
+        self.policy
+    }
+}
+
+/// The body of the addGroup, removeGroup and renewGroup actions.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GroupLifecyclePolicyGroup {
+    #[serde(rename = "groupId")]
+    pub group_id: String,
+}
+
+impl GroupLifecyclePolicyGroup {
+    pub fn new(group_id: impl ToString) -> GroupLifecyclePolicyGroup {
+        GroupLifecyclePolicyGroup {
+            group_id: group_id.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_group_lifecycle_policy() {
+        let policy = GroupLifecyclePolicy::builder()
+            .group_lifetime_in_days(180)
+            .managed_group_types(ManagedGroupTypes::Selected)
+            .alternate_notification_emails(&["admin@contoso.com", "it@contoso.com"])
+            .build();
+
+        assert_eq!(
+            serde_json::json!({
+                "groupLifetimeInDays": 180,
+                "managedGroupTypes": "Selected",
+                "alternateNotificationEmails": "admin@contoso.com;it@contoso.com"
+            }),
+            serde_json::to_value(&policy).unwrap()
+        );
+        assert_eq!(
+            vec!["admin@contoso.com", "it@contoso.com"],
+            policy.notification_emails()
+        );
+    }
+}
@@ -0,0 +1,11 @@
+use crate::api_default_imports::*;
+use crate::group_lifecycle_policies::GroupLifecyclePoliciesApiClient;
+
+impl GroupLifecyclePoliciesApiClient {
+    post!(
+        doc: "Invoke action renewGroup",
+        name: renew_group,
+        path: "/groupLifecyclePolicies/renewGroup",
+        body: true
+    );
+}
@@ -1,3 +1,8 @@
+mod group_lifecycle_policy;
+mod manual_request;
 mod request;
 
+pub use group_lifecycle_policy::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
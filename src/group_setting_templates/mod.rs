@@ -0,0 +1,3 @@
+mod request;
+
+pub use request::*;
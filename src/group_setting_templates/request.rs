@@ -0,0 +1,30 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    GroupSettingTemplatesApiClient,
+    GroupSettingTemplatesIdApiClient,
+    ResourceIdentity::GroupSettingTemplates
+);
+
+impl GroupSettingTemplatesApiClient {
+    get!(
+        doc: "List groupSettingTemplates",
+        name: list_group_setting_template,
+        path: "/groupSettingTemplates"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_group_setting_templates_count,
+        path: "/groupSettingTemplates/$count"
+    );
+}
+
+impl GroupSettingTemplatesIdApiClient {
+    get!(
+        doc: "Get a groupSettingTemplate",
+        name: get_group_setting_template,
+        path: "/groupSettingTemplates/{{RID}}"
+    );
+}
@@ -0,0 +1,236 @@
+use serde::{Deserialize, Serialize};
+
+/// Template id of the `Group.Unified` settings that apply to all Microsoft 365 groups
+/// in the tenant, such as guest access, group creation and naming policies.
+pub const GROUP_UNIFIED_TEMPLATE_ID: &str = "62375ab9-6b52-47ed-826b-58e47e0e304b";
+
+/// Template id of the `Group.Unified.Guest` settings that apply to a single group.
+pub const GROUP_UNIFIED_GUEST_TEMPLATE_ID: &str = "08d542b9-071f-4e16-94b0-74abb372e3d9";
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SettingValue {
+    pub name: String,
+    pub value: String,
+}
+
+impl SettingValue {
+    pub fn new(name: impl ToString, value: impl ToString) -> SettingValue {
+        SettingValue {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SettingTemplateValue {
+    pub name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub value_type: Option<String>,
+    #[serde(rename = "defaultValue", skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// A groupSettingTemplate as returned by `client.group_setting_template(id).get_group_setting_template()`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GroupSettingTemplate {
+    pub id: String,
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub values: Vec<SettingTemplateValue>,
+}
+
+impl GroupSettingTemplate {
+    /// A group setting for the template with the default value of each setting.
+    pub fn setting(&self) -> GroupSettingBuilder {
+        let mut builder = GroupSetting::builder(self.id.as_str());
+        for value in self.values.iter() {
+            builder = builder.value(
+                value.name.as_str(),
+                value.default_value.clone().unwrap_or_default(),
+            );
+        }
+        builder
+    }
+}
+
+/// The body used to create or update a groupSetting.
+///
+/// # Example
+/// ```rust,ignore
+/// let setting = GroupSetting::group_unified()
+///     .allow_to_add_guests(false)
+///     .prefix_suffix_naming_requirement("GRP_[GroupName]_[Department]")
+///     .build();
+///
+/// let response = client
+///     .group_settings()
+///     .create_group_setting(&setting)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GroupSetting {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "displayName", skip_serializing)]
+    pub display_name: Option<String>,
+    #[serde(rename = "templateId", skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+    #[serde(default)]
+    pub values: Vec<SettingValue>,
+}
+
+impl GroupSetting {
+    pub fn builder(template_id: impl ToString) -> GroupSettingBuilder {
+        GroupSettingBuilder {
+            setting: GroupSetting {
+                template_id: Some(template_id.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Tenant wide settings for Microsoft 365 groups using the `Group.Unified` template.
+    pub fn group_unified() -> GroupSettingBuilder {
+        GroupSetting::builder(GROUP_UNIFIED_TEMPLATE_ID)
+    }
+
+    /// Guest settings for a single Microsoft 365 group using the `Group.Unified.Guest` template.
+    pub fn group_unified_guest() -> GroupSettingBuilder {
+        GroupSetting::builder(GROUP_UNIFIED_GUEST_TEMPLATE_ID)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|value| value.name == name)
+            .map(|value| value.value.as_str())
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct GroupSettingBuilder {
+    setting: GroupSetting,
+}
+
+impl GroupSettingBuilder {
+    /// Set the value of a setting, replacing the value if the setting was already set.
+    pub fn value(mut self, name: impl ToString, value: impl ToString) -> Self {
+        let name = name.to_string();
+        let value = value.to_string();
+        match self.setting.values.iter_mut().find(|v| v.name == name) {
+            Some(setting_value) => setting_value.value = value,
+            None => self.setting.values.push(SettingValue { name, value }),
+        }
+        self
+    }
+
+    pub fn allow_to_add_guests(self, allow: bool) -> Self {
+        self.value("AllowToAddGuests", bool_value(allow))
+    }
+
+    pub fn allow_guests_to_access_groups(self, allow: bool) -> Self {
+        self.value("AllowGuestsToAccessGroups", bool_value(allow))
+    }
+
+    pub fn allow_guests_to_be_group_owner(self, allow: bool) -> Self {
+        self.value("AllowGuestsToBeGroupOwner", bool_value(allow))
+    }
+
+    pub fn enable_group_creation(self, enable: bool) -> Self {
+        self.value("EnableGroupCreation", bool_value(enable))
+    }
+
+    /// The id of the security group whose members can create Microsoft 365 groups
+    /// when group creation is disabled.
+    pub fn group_creation_allowed_group_id(self, group_id: impl ToString) -> Self {
+        self.value("GroupCreationAllowedGroupId", group_id)
+    }
+
+    /// The naming policy format such as `GRP_[GroupName]_[Department]`.
+    pub fn prefix_suffix_naming_requirement(self, requirement: impl ToString) -> Self {
+        self.value("PrefixSuffixNamingRequirement", requirement)
+    }
+
+    pub fn custom_blocked_words_list<T: AsRef<str>>(self, words: &[T]) -> Self {
+        self.value("CustomBlockedWordsList", join_values(words))
+    }
+
+    pub fn classification_list<T: AsRef<str>>(self, classifications: &[T]) -> Self {
+        self.value("ClassificationList", join_values(classifications))
+    }
+
+    pub fn default_classification(self, classification: impl ToString) -> Self {
+        self.value("DefaultClassification", classification)
+    }
+
+    pub fn usage_guidelines_url(self, url: impl ToString) -> Self {
+        self.value("UsageGuidelinesUrl", url)
+    }
+
+    pub fn guest_usage_guidelines_url(self, url: impl ToString) -> Self {
+        self.value("GuestUsageGuidelinesUrl", url)
+    }
+
+    pub fn build(self) -> GroupSetting {
+        self.setting
+    }
+}
+
+fn bool_value(value: bool) -> &'static str {
+    if value {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+fn join_values<T: AsRef<str>>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| value.as_ref())
+        .collect::<Vec<&str>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn group_setting_from_template_defaults() {
+        let template: GroupSettingTemplate = serde_json::from_value(serde_json::json!({
+            "id": GROUP_UNIFIED_TEMPLATE_ID,
+            "displayName": "Group.Unified",
+            "values": [
+                { "name": "AllowToAddGuests", "type": "System.Boolean", "defaultValue": "true" },
+                { "name": "ClassificationList", "type": "System.String", "defaultValue": "" }
+            ]
+        }))
+        .unwrap();
+
+        let setting = template
+            .setting()
+            .allow_to_add_guests(false)
+            .classification_list(&["Low", "High"])
+            .build();
+
+        assert_eq!(
+            serde_json::json!({
+                "templateId": GROUP_UNIFIED_TEMPLATE_ID,
+                "values": [
+                    { "name": "AllowToAddGuests", "value": "false" },
+                    { "name": "ClassificationList", "value": "Low,High" }
+                ]
+            }),
+            serde_json::to_value(&setting).unwrap()
+        );
+        assert_eq!(Some("false"), setting.value("AllowToAddGuests"));
+    }
+}
@@ -0,0 +1,5 @@
+mod group_setting;
+mod request;
+
+pub use group_setting::*;
+pub use request::*;
@@ -0,0 +1,47 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    GroupSettingsApiClient,
+    GroupSettingsIdApiClient,
+    ResourceIdentity::GroupSettings
+);
+
+impl GroupSettingsApiClient {
+    post!(
+        doc: "Create settings",
+        name: create_group_setting,
+        path: "/groupSettings",
+        body: true
+    );
+    get!(
+        doc: "List settings",
+        name: list_group_setting,
+        path: "/groupSettings"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_group_settings_count,
+        path: "/groupSettings/$count"
+    );
+}
+
+impl GroupSettingsIdApiClient {
+    delete!(
+        doc: "Delete a group setting",
+        name: delete_group_setting,
+        path: "/groupSettings/{{RID}}"
+    );
+    get!(
+        doc: "Get groupSetting",
+        name: get_group_setting,
+        path: "/groupSettings/{{RID}}"
+    );
+    patch!(
+        doc: "Update groupSetting",
+        name: update_group_setting,
+        path: "/groupSettings/{{RID}}",
+        body: true
+    );
+}
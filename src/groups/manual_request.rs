@@ -0,0 +1,47 @@
+use crate::api_default_imports::*;
+use crate::groups::*;
+use futures::Stream;
+use reqwest::header::{HeaderName, HeaderValue};
+use serde::de::DeserializeOwned;
+
+impl GroupsIdApiClient {
+    /// Stream every user that is a direct or nested member of the group.
+    ///
+    /// Pages through `/transitiveMembers/microsoft.graph.user` using advanced query
+    /// capabilities (the `ConsistencyLevel: eventual` header and `$count=true`) and
+    /// returns each member as it is received instead of each page.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use futures::StreamExt;
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct User {
+    ///     id: String,
+    ///     #[serde(rename = "userPrincipalName")]
+    ///     user_principal_name: Option<String>,
+    /// }
+    ///
+    /// let mut members = client
+    ///     .group(GROUP_ID)
+    ///     .transitive_members_all::<User>()?;
+    ///
+    /// while let Some(user) = members.next().await {
+    ///     println!("{:#?}", user?);
+    /// }
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn transitive_members_all<T: DeserializeOwned + 'static>(
+        &self,
+    ) -> GraphResult<impl Stream<Item = GraphResult<T>>> {
+        self.transitive_members()
+            .get_directory_object_items_as_user_type()
+            .header(
+                HeaderName::from_static("consistencylevel"),
+                HeaderValue::from_static("eventual"),
+            )
+            .count("true")
+            .paging()
+            .stream_items::<T>()
+    }
+}
@@ -0,0 +1,160 @@
+use crate::identity_governance::{EntitlementReference, OriginSystem};
+use serde::{Deserialize, Serialize};
+
+/// The body used to create or update an accessPackage and the access package
+/// returned in the response.
+///
+/// # Example
+/// ```rust,ignore
+/// let access_package = AccessPackage::new("Sales and Marketing", CATALOG_ID)
+///     .description("Access to the Sales and Marketing groups");
+///
+/// let response = client
+///     .identity_governance()
+///     .entitlement_management()
+///     .access_packages()
+///     .create_access_packages(&access_package)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPackage {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Hidden access packages are not shown to users in the My Access portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_hidden: Option<bool>,
+    /// Only set when creating the access package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog: Option<EntitlementReference>,
+}
+
+impl AccessPackage {
+    pub fn new(display_name: impl ToString, catalog_id: impl ToString) -> AccessPackage {
+        AccessPackage {
+            display_name: display_name.to_string(),
+            catalog: Some(EntitlementReference::new(catalog_id)),
+            ..Default::default()
+        }
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn hidden(mut self, is_hidden: bool) -> Self {
+        self.is_hidden = Some(is_hidden);
+        self
+    }
+}
+
+/// The resource of a catalog that a role belongs to.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceRoleResource {
+    /// The id of the resource in the catalog.
+    pub id: String,
+    pub origin_id: String,
+    pub origin_system: OriginSystem,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPackageResourceRole {
+    /// Such as `Member_{group-id}` for the member role of a group.
+    pub origin_id: String,
+    pub origin_system: OriginSystem,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub resource: ResourceRoleResource,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPackageResourceScope {
+    pub origin_id: String,
+    pub origin_system: OriginSystem,
+    #[serde(default)]
+    pub is_root_scope: bool,
+}
+
+/// The body of resourceRoleScopes, which adds the role of a catalog resource
+/// to an access package.
+///
+/// # Example
+/// ```rust,ignore
+/// // The id of the group in the catalog is returned by listing the catalog resources.
+/// let response = client
+///     .identity_governance()
+///     .entitlement_management()
+///     .access_package(ACCESS_PACKAGE_ID)
+///     .create_resource_role_scopes(&AccessPackageResourceRoleScope::group_member(
+///         CATALOG_RESOURCE_ID,
+///         GROUP_ID,
+///     ))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AccessPackageResourceRoleScope {
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    pub role: AccessPackageResourceRole,
+    pub scope: AccessPackageResourceScope,
+}
+
+impl AccessPackageResourceRoleScope {
+    /// The role `role_origin_id` of the catalog resource `resource_id`, scoped
+    /// to the whole resource.
+    pub fn new(
+        resource_id: impl ToString,
+        resource_origin_id: impl ToString,
+        origin_system: OriginSystem,
+        role_origin_id: impl ToString,
+    ) -> AccessPackageResourceRoleScope {
+        let resource_origin_id = resource_origin_id.to_string();
+        AccessPackageResourceRoleScope {
+            id: None,
+            role: AccessPackageResourceRole {
+                origin_id: role_origin_id.to_string(),
+                origin_system,
+                display_name: None,
+                resource: ResourceRoleResource {
+                    id: resource_id.to_string(),
+                    origin_id: resource_origin_id.clone(),
+                    origin_system,
+                },
+            },
+            scope: AccessPackageResourceScope {
+                origin_id: resource_origin_id,
+                origin_system,
+                is_root_scope: true,
+            },
+        }
+    }
+
+    /// Membership of the group `group_id`, which was added to the catalog as the
+    /// resource `resource_id`.
+    pub fn group_member(resource_id: impl ToString, group_id: &str) -> Self {
+        AccessPackageResourceRoleScope::new(
+            resource_id,
+            group_id,
+            OriginSystem::AadGroup,
+            format!("Member_{group_id}"),
+        )
+    }
+
+    pub fn group_owner(resource_id: impl ToString, group_id: &str) -> Self {
+        AccessPackageResourceRoleScope::new(
+            resource_id,
+            group_id,
+            OriginSystem::AadGroup,
+            format!("Owner_{group_id}"),
+        )
+    }
+}
@@ -1,2 +1,5 @@
+mod access_package;
 mod request;
+
+pub use access_package::*;
 pub use request::*;
@@ -142,4 +142,32 @@ impl AccessPackagesIdApiClient {
         path: "/accessPackages/{{RID}}/incompatibleGroups/{{id}}/$ref",
         params: group_id
     );
+    post!(
+        doc: "Create accessPackageResourceRoleScope",
+        name: create_resource_role_scopes,
+        path: "/accessPackages/{{RID}}/resourceRoleScopes",
+        body: true
+    );
+    get!(
+        doc: "Get resourceRoleScopes from identityGovernance",
+        name: list_resource_role_scopes,
+        path: "/accessPackages/{{RID}}/resourceRoleScopes"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_resource_role_scopes_count,
+        path: "/accessPackages/{{RID}}/resourceRoleScopes/$count"
+    );
+    delete!(
+        doc: "Remove resourceRoleScopes",
+        name: delete_resource_role_scopes,
+        path: "/accessPackages/{{RID}}/resourceRoleScopes/{{id}}",
+        params: access_package_resource_role_scope_id
+    );
+    get!(
+        doc: "Get resourceRoleScopes from identityGovernance",
+        name: get_resource_role_scopes,
+        path: "/accessPackages/{{RID}}/resourceRoleScopes/{{id}}",
+        params: access_package_resource_role_scope_id
+    );
 }
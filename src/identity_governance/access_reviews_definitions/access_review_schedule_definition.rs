@@ -0,0 +1,298 @@
+use serde::{Deserialize, Serialize};
+
+/// A query of the resources or reviewers of an access review, such as
+/// `/groups/{group-id}/transitiveMembers` or `/groups/{group-id}/owners`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessReviewQueryScope {
+    #[serde(rename = "@odata.type")]
+    pub odata_type: String,
+    pub query: String,
+    pub query_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_root: Option<String>,
+}
+
+impl AccessReviewQueryScope {
+    pub fn new(query: impl ToString) -> AccessReviewQueryScope {
+        AccessReviewQueryScope {
+            odata_type: "#microsoft.graph.accessReviewQueryScope".into(),
+            query: query.to_string(),
+            query_type: "MicrosoftGraph".into(),
+            query_root: None,
+        }
+    }
+
+    /// The members of a group, including members of nested groups.
+    pub fn group_members(group_id: &str) -> AccessReviewQueryScope {
+        AccessReviewQueryScope::new(format!("/groups/{group_id}/transitiveMembers"))
+    }
+
+    /// The query is relative to the resource being reviewed, such as `./manager`
+    /// with a query root of `decisions` to have the manager of each user review them.
+    pub fn query_root(mut self, query_root: impl ToString) -> Self {
+        self.query_root = Some(query_root.to_string());
+        self
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessReviewReviewerScope {
+    pub query: String,
+    pub query_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_root: Option<String>,
+}
+
+impl AccessReviewReviewerScope {
+    pub fn new(query: impl ToString) -> AccessReviewReviewerScope {
+        AccessReviewReviewerScope {
+            query: query.to_string(),
+            query_type: "MicrosoftGraph".into(),
+            query_root: None,
+        }
+    }
+
+    pub fn user(user_id: &str) -> AccessReviewReviewerScope {
+        AccessReviewReviewerScope::new(format!("/users/{user_id}"))
+    }
+
+    pub fn group_owners(group_id: &str) -> AccessReviewReviewerScope {
+        AccessReviewReviewerScope::new(format!("/groups/{group_id}/owners"))
+    }
+
+    /// The manager of each user under review.
+    pub fn managers() -> AccessReviewReviewerScope {
+        AccessReviewReviewerScope {
+            query: "./manager".into(),
+            query_type: "MicrosoftGraph".into(),
+            query_root: Some("decisions".into()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AccessReviewRecurrencePatternType {
+    Weekly,
+    AbsoluteMonthly,
+    AbsoluteYearly,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AccessReviewRecurrencePattern {
+    #[serde(rename = "type")]
+    pub pattern_type: AccessReviewRecurrencePatternType,
+    pub interval: i32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessReviewRecurrenceRange {
+    /// One of `noEnd`, `endDate` or `numbered`.
+    #[serde(rename = "type")]
+    pub range_type: String,
+    /// The date of the first review, such as `2024-03-20`.
+    pub start_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_occurrences: Option<i32>,
+}
+
+/// How often an access review repeats.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AccessReviewRecurrence {
+    pub pattern: AccessReviewRecurrencePattern,
+    pub range: AccessReviewRecurrenceRange,
+}
+
+impl AccessReviewRecurrence {
+    /// Repeat every `interval` of the pattern, starting on the date such as `2024-03-20`.
+    pub fn new(
+        pattern_type: AccessReviewRecurrencePatternType,
+        interval: i32,
+        start_date: impl ToString,
+    ) -> AccessReviewRecurrence {
+        AccessReviewRecurrence {
+            pattern: AccessReviewRecurrencePattern {
+                pattern_type,
+                interval,
+            },
+            range: AccessReviewRecurrenceRange {
+                range_type: "noEnd".into(),
+                start_date: start_date.to_string(),
+                end_date: None,
+                number_of_occurrences: None,
+            },
+        }
+    }
+
+    pub fn end_date(mut self, end_date: impl ToString) -> Self {
+        self.range.range_type = "endDate".into();
+        self.range.end_date = Some(end_date.to_string());
+        self
+    }
+}
+
+/// The settings of an access review schedule definition.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessReviewScheduleSettings {
+    pub mail_notifications_enabled: bool,
+    pub reminder_notifications_enabled: bool,
+    pub justification_required_on_approval: bool,
+    pub default_decision_enabled: bool,
+    /// One of `None`, `Approve`, `Deny` or `Recommendation`. Applied to
+    /// users that were not reviewed when `default_decision_enabled` is set.
+    pub default_decision: String,
+    pub instance_duration_in_days: i32,
+    /// Apply the decisions to the resource when each review ends.
+    pub auto_apply_decisions_enabled: bool,
+    pub recommendations_enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<AccessReviewRecurrence>,
+}
+
+impl Default for AccessReviewScheduleSettings {
+    fn default() -> Self {
+        AccessReviewScheduleSettings {
+            mail_notifications_enabled: true,
+            reminder_notifications_enabled: true,
+            justification_required_on_approval: false,
+            default_decision_enabled: false,
+            default_decision: "None".into(),
+            instance_duration_in_days: 14,
+            auto_apply_decisions_enabled: false,
+            recommendations_enabled: true,
+            recurrence: None,
+        }
+    }
+}
+
+/// The body used to create an accessReviewScheduleDefinition and the definition
+/// returned in the response.
+///
+/// # Example
+/// ```rust,ignore
+/// let definition = AccessReviewScheduleDefinition::builder(
+///     "Review guest access to Sales",
+///     AccessReviewQueryScope::group_members(GROUP_ID),
+/// )
+/// .reviewer(AccessReviewReviewerScope::group_owners(GROUP_ID))
+/// .instance_duration_in_days(7)
+/// .auto_apply_decisions(true)
+/// .recurrence(AccessReviewRecurrence::new(
+///     AccessReviewRecurrencePatternType::AbsoluteMonthly,
+///     3,
+///     "2024-03-20",
+/// ))
+/// .build();
+///
+/// let response = client
+///     .identity_governance()
+///     .access_reviews()
+///     .definitions()
+///     .create_definitions(&definition)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessReviewScheduleDefinition {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_for_admins: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_for_reviewers: Option<String>,
+    pub scope: AccessReviewQueryScope,
+    #[serde(default)]
+    pub reviewers: Vec<AccessReviewReviewerScope>,
+    #[serde(default)]
+    pub settings: AccessReviewScheduleSettings,
+    /// One of `NotStarted`, `InProgress`, `Completed`, `Applied` and others.
+    #[serde(skip_serializing)]
+    pub status: Option<String>,
+}
+
+impl AccessReviewScheduleDefinition {
+    pub fn builder(
+        display_name: impl ToString,
+        scope: AccessReviewQueryScope,
+    ) -> AccessReviewScheduleDefinitionBuilder {
+        AccessReviewScheduleDefinitionBuilder {
+            definition: AccessReviewScheduleDefinition {
+                id: None,
+                display_name: display_name.to_string(),
+                description_for_admins: None,
+                description_for_reviewers: None,
+                scope,
+                reviewers: vec![],
+                settings: Default::default(),
+                status: None,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AccessReviewScheduleDefinitionBuilder {
+    definition: AccessReviewScheduleDefinition,
+}
+
+impl AccessReviewScheduleDefinitionBuilder {
+    pub fn description_for_admins(mut self, description: impl ToString) -> Self {
+        self.definition.description_for_admins = Some(description.to_string());
+        self
+    }
+
+    pub fn description_for_reviewers(mut self, description: impl ToString) -> Self {
+        self.definition.description_for_reviewers = Some(description.to_string());
+        self
+    }
+
+    /// Without reviewers the users under review review their own access.
+    pub fn reviewer(mut self, reviewer: AccessReviewReviewerScope) -> Self {
+        self.definition.reviewers.push(reviewer);
+        self
+    }
+
+    pub fn instance_duration_in_days(mut self, days: i32) -> Self {
+        self.definition.settings.instance_duration_in_days = days;
+        self
+    }
+
+    pub fn auto_apply_decisions(mut self, enabled: bool) -> Self {
+        self.definition.settings.auto_apply_decisions_enabled = enabled;
+        self
+    }
+
+    pub fn justification_required_on_approval(mut self, required: bool) -> Self {
+        self.definition.settings.justification_required_on_approval = required;
+        self
+    }
+
+    /// The decision applied to users that were not reviewed, such as `Deny`.
+    pub fn default_decision(mut self, default_decision: impl ToString) -> Self {
+        self.definition.settings.default_decision_enabled = true;
+        self.definition.settings.default_decision = default_decision.to_string();
+        self
+    }
+
+    pub fn recurrence(mut self, recurrence: AccessReviewRecurrence) -> Self {
+        self.definition.settings.recurrence = Some(recurrence);
+        self
+    }
+
+    pub fn settings(mut self, settings: AccessReviewScheduleSettings) -> Self {
+        self.definition.settings = settings;
+        self
+    }
+
+    pub fn build(self) -> AccessReviewScheduleDefinition {
+        self.definition
+    }
+}
@@ -1,3 +1,5 @@
+mod access_review_schedule_definition;
 mod request;
 
+pub use access_review_schedule_definition::*;
 pub use request::*;
@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+
+/// The decision of a reviewer for a user or resource under review.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AccessReviewDecision {
+    Approve,
+    Deny,
+    NotReviewed,
+    DontKnow,
+}
+
+/// The body used to record the decision of a single decision item.
+///
+/// # Example
+/// ```rust,ignore
+/// let response = client
+///     .identity_governance()
+///     .access_reviews()
+///     .definition(DEFINITION_ID)
+///     .instance(INSTANCE_ID)
+///     .update_decisions(
+///         DECISION_ID,
+///         &RecordDecision::new(AccessReviewDecision::Deny).justification("Left the project"),
+///     )
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecordDecision {
+    pub decision: AccessReviewDecision,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub justification: Option<String>,
+}
+
+impl RecordDecision {
+    pub fn new(decision: AccessReviewDecision) -> RecordDecision {
+        RecordDecision {
+            decision,
+            justification: None,
+        }
+    }
+
+    pub fn justification(mut self, justification: impl ToString) -> Self {
+        self.justification = Some(justification.to_string());
+        self
+    }
+}
+
+/// The body of batchRecordDecisions, which records the same decision for all
+/// decision items of the instance, or only those of a principal or resource.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchRecordDecisions {
+    pub decision: AccessReviewDecision,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub justification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+}
+
+impl BatchRecordDecisions {
+    pub fn new(decision: AccessReviewDecision) -> BatchRecordDecisions {
+        BatchRecordDecisions {
+            decision,
+            justification: None,
+            principal_id: None,
+            resource_id: None,
+        }
+    }
+
+    pub fn justification(mut self, justification: impl ToString) -> Self {
+        self.justification = Some(justification.to_string());
+        self
+    }
+
+    pub fn principal_id(mut self, principal_id: impl ToString) -> Self {
+        self.principal_id = Some(principal_id.to_string());
+        self
+    }
+
+    pub fn resource_id(mut self, resource_id: impl ToString) -> Self {
+        self.resource_id = Some(resource_id.to_string());
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessReviewIdentity {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub user_principal_name: Option<String>,
+}
+
+/// A decision item of an access review instance, such as the access of one user.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AccessReviewInstanceDecisionItem {
+    pub id: String,
+    /// One of `Approve`, `Deny`, `NotReviewed` or `DontKnow`.
+    pub decision: Option<String>,
+    /// One of `Approve`, `Deny` or `NoInfoAvailable`.
+    pub recommendation: Option<String>,
+    pub justification: Option<String>,
+    /// One of `New`, `AppliedSuccessfully`, `AppliedWithUnknownFailure` and others.
+    pub apply_result: Option<String>,
+    pub access_review_id: Option<String>,
+    pub principal: Option<AccessReviewIdentity>,
+    pub principal_link: Option<String>,
+    pub reviewed_by: Option<AccessReviewIdentity>,
+    pub reviewed_date_time: Option<String>,
+}
+
+impl AccessReviewInstanceDecisionItem {
+    pub fn is_reviewed(&self) -> bool {
+        !matches!(self.decision.as_deref(), None | Some("NotReviewed"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_decisions() {
+        assert_eq!(
+            serde_json::json!({ "decision": "DontKnow", "justification": "On leave" }),
+            serde_json::to_value(
+                RecordDecision::new(AccessReviewDecision::DontKnow).justification("On leave")
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            serde_json::json!({ "decision": "Approve", "principalId": "user-id" }),
+            serde_json::to_value(
+                BatchRecordDecisions::new(AccessReviewDecision::Approve).principal_id("user-id")
+            )
+            .unwrap()
+        );
+
+        let item: AccessReviewInstanceDecisionItem = serde_json::from_value(serde_json::json!({
+            "id": "decision-id",
+            "decision": "NotReviewed",
+            "recommendation": "Deny",
+            "principal": {
+                "@odata.type": "#microsoft.graph.userIdentity",
+                "id": "user-id",
+                "displayName": "Adele Vance"
+            }
+        }))
+        .unwrap();
+        assert!(!item.is_reviewed());
+        assert_eq!(
+            Some("Adele Vance"),
+            item.principal.unwrap().display_name.as_deref()
+        );
+    }
+}
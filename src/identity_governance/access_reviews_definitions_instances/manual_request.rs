@@ -0,0 +1,58 @@
+use crate::api_default_imports::*;
+use crate::identity_governance::{
+    AccessReviewInstanceDecisionItem, AccessReviewsDefinitionsInstancesIdApiClient,
+};
+use futures::{Stream, StreamExt};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct DecisionsPage {
+    #[serde(default = "Vec::new")]
+    value: Vec<AccessReviewInstanceDecisionItem>,
+}
+
+impl AccessReviewsDefinitionsInstancesIdApiClient {
+    /// Stream every decision item of the instance, requesting the next page of
+    /// decisions as needed.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use futures::StreamExt;
+    ///
+    /// let instance = client
+    ///     .identity_governance()
+    ///     .access_reviews()
+    ///     .definition(DEFINITION_ID)
+    ///     .instance(INSTANCE_ID);
+    ///
+    /// let mut decisions = instance.list_decisions_all()?;
+    /// while let Some(decision) = decisions.next().await {
+    ///     let decision = decision?;
+    ///     if !decision.is_reviewed() && decision.recommendation.as_deref() == Some("Deny") {
+    ///         instance
+    ///             .update_decisions(&decision.id, &RecordDecision::new(AccessReviewDecision::Deny))
+    ///             .send()
+    ///             .await?;
+    ///     }
+    /// }
+    ///
+    /// instance.stop().send().await?;
+    /// instance.apply_decisions().send().await?;
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn list_decisions_all(
+        &self,
+    ) -> GraphResult<impl Stream<Item = GraphResult<AccessReviewInstanceDecisionItem>>> {
+        let pages = self.list_decisions().paging().stream::<DecisionsPage>()?;
+
+        Ok(pages.flat_map(|page| {
+            let decisions: Vec<GraphResult<AccessReviewInstanceDecisionItem>> =
+                match page.map(|response| response.into_body()) {
+                    Ok(Ok(page)) => page.value.into_iter().map(Ok).collect(),
+                    Ok(Err(err)) => vec![Err(GraphFailure::from(err))],
+                    Err(err) => vec![Err(err)],
+                };
+            futures::stream::iter(decisions)
+        }))
+    }
+}
@@ -1,2 +1,8 @@
+mod access_review_decision;
+mod manual_request;
 mod request;
+
+pub use access_review_decision::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -0,0 +1,365 @@
+use crate::identity_governance::EntitlementReference;
+use serde::{Deserialize, Serialize};
+
+/// A set of users who can request an access package or approve a request.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@odata.type")]
+pub enum SubjectSet {
+    #[serde(rename = "#microsoft.graph.singleUser")]
+    SingleUser {
+        #[serde(rename = "userId")]
+        user_id: String,
+    },
+    #[serde(rename = "#microsoft.graph.groupMembers")]
+    GroupMembers {
+        #[serde(rename = "groupId")]
+        group_id: String,
+    },
+    /// The manager of the requestor, where a level of 1 is their direct manager.
+    #[serde(rename = "#microsoft.graph.requestorManager")]
+    RequestorManager {
+        #[serde(rename = "managerLevel")]
+        manager_level: i32,
+    },
+    #[serde(rename = "#microsoft.graph.internalSponsors")]
+    InternalSponsors,
+    #[serde(rename = "#microsoft.graph.externalSponsors")]
+    ExternalSponsors,
+}
+
+impl SubjectSet {
+    pub fn user(user_id: impl ToString) -> SubjectSet {
+        SubjectSet::SingleUser {
+            user_id: user_id.to_string(),
+        }
+    }
+
+    pub fn group_members(group_id: impl ToString) -> SubjectSet {
+        SubjectSet::GroupMembers {
+            group_id: group_id.to_string(),
+        }
+    }
+
+    pub fn manager() -> SubjectSet {
+        SubjectSet::RequestorManager { manager_level: 1 }
+    }
+}
+
+/// When assignments of a policy expire.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ExpirationPattern {
+    /// One of `noExpiration`, `afterDateTime` or `afterDuration`.
+    #[serde(rename = "type")]
+    pub expiration_type: String,
+    /// An ISO 8601 duration such as `P365D`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    #[serde(
+        default,
+        rename = "endDateTime",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub end_date_time: Option<String>,
+}
+
+impl ExpirationPattern {
+    pub fn no_expiration() -> ExpirationPattern {
+        ExpirationPattern {
+            expiration_type: "noExpiration".into(),
+            duration: None,
+            end_date_time: None,
+        }
+    }
+
+    pub fn after_duration(duration: impl ToString) -> ExpirationPattern {
+        ExpirationPattern {
+            expiration_type: "afterDuration".into(),
+            duration: Some(duration.to_string()),
+            end_date_time: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RequestorSettings {
+    pub enable_targets_to_self_add_access: bool,
+    pub enable_targets_to_self_update_access: bool,
+    pub enable_targets_to_self_remove_access: bool,
+    pub allow_custom_assignment_schedule: bool,
+    pub enable_on_behalf_requestors_to_add_access: bool,
+    pub enable_on_behalf_requestors_to_update_access: bool,
+    pub enable_on_behalf_requestors_to_remove_access: bool,
+    pub on_behalf_requestors: Vec<SubjectSet>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalStage {
+    /// An ISO 8601 duration such as `P14D` after which the request is denied.
+    pub duration_before_automatic_denial: String,
+    pub is_approver_justification_required: bool,
+    pub is_escalation_enabled: bool,
+    pub primary_approvers: Vec<SubjectSet>,
+    #[serde(default)]
+    pub fallback_primary_approvers: Vec<SubjectSet>,
+}
+
+impl ApprovalStage {
+    pub fn new<I: IntoIterator<Item = SubjectSet>>(approvers: I) -> ApprovalStage {
+        ApprovalStage {
+            duration_before_automatic_denial: "P14D".into(),
+            is_approver_justification_required: false,
+            is_escalation_enabled: false,
+            primary_approvers: approvers.into_iter().collect(),
+            fallback_primary_approvers: vec![],
+        }
+    }
+
+    pub fn duration_before_automatic_denial(mut self, duration: impl ToString) -> Self {
+        self.duration_before_automatic_denial = duration.to_string();
+        self
+    }
+
+    pub fn justification_required(mut self, required: bool) -> Self {
+        self.is_approver_justification_required = required;
+        self
+    }
+
+    pub fn fallback_approver(mut self, approver: SubjectSet) -> Self {
+        self.fallback_primary_approvers.push(approver);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ApprovalSettings {
+    pub is_approval_required_for_add: bool,
+    pub is_approval_required_for_update: bool,
+    pub stages: Vec<ApprovalStage>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPackageQuestionChoice {
+    /// The value returned in the answer when the choice is selected.
+    pub actual_value: String,
+    pub text: String,
+}
+
+/// A question asked of the requestor, answered when creating an assignment request.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@odata.type")]
+pub enum AccessPackageQuestion {
+    #[serde(
+        rename = "#microsoft.graph.accessPackageTextInputQuestion",
+        rename_all = "camelCase"
+    )]
+    TextInput {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        text: String,
+        is_required: bool,
+        is_single_line_question: bool,
+        #[serde(default)]
+        sequence: i32,
+    },
+    #[serde(
+        rename = "#microsoft.graph.accessPackageMultipleChoiceQuestion",
+        rename_all = "camelCase"
+    )]
+    MultipleChoice {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        text: String,
+        is_required: bool,
+        is_multiple_selection_allowed: bool,
+        choices: Vec<AccessPackageQuestionChoice>,
+        #[serde(default)]
+        sequence: i32,
+    },
+}
+
+impl AccessPackageQuestion {
+    pub fn text(text: impl ToString, is_required: bool) -> AccessPackageQuestion {
+        AccessPackageQuestion::TextInput {
+            id: None,
+            text: text.to_string(),
+            is_required,
+            is_single_line_question: true,
+            sequence: 0,
+        }
+    }
+
+    /// A question answered with one of the choices, given as `(actual value, text)`.
+    pub fn multiple_choice<I, T, U>(
+        text: impl ToString,
+        is_required: bool,
+        choices: I,
+    ) -> AccessPackageQuestion
+    where
+        I: IntoIterator<Item = (T, U)>,
+        T: ToString,
+        U: ToString,
+    {
+        AccessPackageQuestion::MultipleChoice {
+            id: None,
+            text: text.to_string(),
+            is_required,
+            is_multiple_selection_allowed: false,
+            choices: choices
+                .into_iter()
+                .map(|(actual_value, text)| AccessPackageQuestionChoice {
+                    actual_value: actual_value.to_string(),
+                    text: text.to_string(),
+                })
+                .collect(),
+            sequence: 0,
+        }
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            AccessPackageQuestion::TextInput { id, .. } => id.as_deref(),
+            AccessPackageQuestion::MultipleChoice { id, .. } => id.as_deref(),
+        }
+    }
+}
+
+/// The body used to create or update an accessPackageAssignmentPolicy and the
+/// policy returned in the response.
+///
+/// # Example
+/// ```rust,ignore
+/// let policy = AccessPackageAssignmentPolicy::builder("Sales employees", ACCESS_PACKAGE_ID)
+///     .specific_allowed_target(SubjectSet::group_members(SALES_GROUP_ID))
+///     .expiration(ExpirationPattern::after_duration("P365D"))
+///     .approval_stage(ApprovalStage::new([SubjectSet::manager()]))
+///     .question(AccessPackageQuestion::text("Why do you need access?", true))
+///     .build();
+///
+/// let response = client
+///     .identity_governance()
+///     .entitlement_management()
+///     .assignment_policies()
+///     .create_assignment_policies(&policy)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPackageAssignmentPolicy {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Such as `specificDirectoryUsers`, `allMemberUsers`, `allDirectoryUsers`
+    /// or `notSpecified` for policies only used for direct assignments.
+    pub allowed_target_scope: String,
+    #[serde(default)]
+    pub specific_allowed_targets: Vec<SubjectSet>,
+    pub expiration: ExpirationPattern,
+    #[serde(default)]
+    pub requestor_settings: RequestorSettings,
+    #[serde(default)]
+    pub request_approval_settings: ApprovalSettings,
+    #[serde(default)]
+    pub questions: Vec<AccessPackageQuestion>,
+    /// Only set when creating the policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_package: Option<EntitlementReference>,
+}
+
+impl AccessPackageAssignmentPolicy {
+    /// A policy that only allows direct assignments by an administrator until
+    /// targets or approval stages are added.
+    pub fn builder(
+        display_name: impl ToString,
+        access_package_id: impl ToString,
+    ) -> AccessPackageAssignmentPolicyBuilder {
+        AccessPackageAssignmentPolicyBuilder {
+            policy: AccessPackageAssignmentPolicy {
+                id: None,
+                display_name: display_name.to_string(),
+                description: None,
+                allowed_target_scope: "notSpecified".into(),
+                specific_allowed_targets: vec![],
+                expiration: ExpirationPattern::no_expiration(),
+                requestor_settings: Default::default(),
+                request_approval_settings: Default::default(),
+                questions: vec![],
+                access_package: Some(EntitlementReference::new(access_package_id)),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AccessPackageAssignmentPolicyBuilder {
+    policy: AccessPackageAssignmentPolicy,
+}
+
+impl AccessPackageAssignmentPolicyBuilder {
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.policy.description = Some(description.to_string());
+        self
+    }
+
+    /// Allow the users of the subject set to request the access package
+    /// for themselves.
+    pub fn specific_allowed_target(mut self, target: SubjectSet) -> Self {
+        self.policy.allowed_target_scope = "specificDirectoryUsers".into();
+        self.policy.specific_allowed_targets.push(target);
+        self.policy
+            .requestor_settings
+            .enable_targets_to_self_add_access = true;
+        self.policy
+            .requestor_settings
+            .enable_targets_to_self_remove_access = true;
+        self
+    }
+
+    /// Such as `allMemberUsers`, which allows every member user to request the
+    /// access package.
+    pub fn allowed_target_scope(mut self, allowed_target_scope: impl ToString) -> Self {
+        self.policy.allowed_target_scope = allowed_target_scope.to_string();
+        self
+    }
+
+    pub fn expiration(mut self, expiration: ExpirationPattern) -> Self {
+        self.policy.expiration = expiration;
+        self
+    }
+
+    pub fn requestor_settings(mut self, requestor_settings: RequestorSettings) -> Self {
+        self.policy.requestor_settings = requestor_settings;
+        self
+    }
+
+    /// Requests are approved in the order the stages are added.
+    pub fn approval_stage(mut self, stage: ApprovalStage) -> Self {
+        self.policy
+            .request_approval_settings
+            .is_approval_required_for_add = true;
+        self.policy.request_approval_settings.stages.push(stage);
+        self
+    }
+
+    /// Questions are asked in the order they are added.
+    pub fn question(mut self, mut question: AccessPackageQuestion) -> Self {
+        match &mut question {
+            AccessPackageQuestion::TextInput { sequence, .. }
+            | AccessPackageQuestion::MultipleChoice { sequence, .. } => {
+                *sequence = self.policy.questions.len() as i32;
+            }
+        }
+        self.policy.questions.push(question);
+        self
+    }
+
+    pub fn build(self) -> AccessPackageAssignmentPolicy {
+        self.policy
+    }
+}
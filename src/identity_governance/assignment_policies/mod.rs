@@ -1,2 +1,5 @@
+mod assignment_policy;
 mod request;
+
+pub use assignment_policy::*;
 pub use request::*;
@@ -0,0 +1,215 @@
+use serde::{Deserialize, Serialize};
+
+/// The question an answer is for.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AnsweredQuestion {
+    #[serde(rename = "@odata.type")]
+    pub odata_type: String,
+    pub id: String,
+}
+
+/// The answer of the requestor to a question of the assignment policy.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPackageAnswer {
+    #[serde(rename = "@odata.type")]
+    pub odata_type: String,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_value: Option<String>,
+    pub answered_question: AnsweredQuestion,
+}
+
+impl AccessPackageAnswer {
+    fn new(question_type: &str, question_id: impl ToString, value: impl ToString) -> Self {
+        AccessPackageAnswer {
+            odata_type: "#microsoft.graph.accessPackageAnswerString".into(),
+            value: value.to_string(),
+            display_value: None,
+            answered_question: AnsweredQuestion {
+                odata_type: question_type.into(),
+                id: question_id.to_string(),
+            },
+        }
+    }
+
+    /// The answer to a text input question.
+    pub fn text(question_id: impl ToString, value: impl ToString) -> AccessPackageAnswer {
+        AccessPackageAnswer::new(
+            "#microsoft.graph.accessPackageTextInputQuestion",
+            question_id,
+            value,
+        )
+    }
+
+    /// The answer to a multiple choice question, which is the actual value of the
+    /// selected choice.
+    pub fn choice(question_id: impl ToString, actual_value: impl ToString) -> AccessPackageAnswer {
+        AccessPackageAnswer::new(
+            "#microsoft.graph.accessPackageMultipleChoiceQuestion",
+            question_id,
+            actual_value,
+        )
+    }
+}
+
+/// The assignment that a request adds, updates or removes.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssignmentRequestAssignment {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignment_policy_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_package_id: Option<String>,
+}
+
+/// The body used to create an accessPackageAssignmentRequest and the request
+/// returned in the response.
+///
+/// # Example
+/// ```rust,ignore
+/// let request = AccessPackageAssignmentRequest::user_add(ACCESS_PACKAGE_ID, POLICY_ID)
+///     .justification("Joining the Sales team")
+///     .answer(AccessPackageAnswer::text(QUESTION_ID, "Managing the EMEA accounts"));
+///
+/// let response = client
+///     .identity_governance()
+///     .entitlement_management()
+///     .assignment_requests()
+///     .create_assignment_requests(&request)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPackageAssignmentRequest {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    /// Such as `userAdd`, `adminAdd` or `adminRemove`.
+    pub request_type: String,
+    pub assignment: AssignmentRequestAssignment,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub justification: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub answers: Vec<AccessPackageAnswer>,
+    /// Such as `submitted`, `pendingApproval`, `delivered` or `denied`.
+    #[serde(skip_serializing)]
+    pub state: Option<String>,
+    #[serde(skip_serializing)]
+    pub status: Option<String>,
+}
+
+impl AccessPackageAssignmentRequest {
+    /// A request by the signed in user for an assignment of the access package.
+    pub fn user_add(
+        access_package_id: impl ToString,
+        assignment_policy_id: impl ToString,
+    ) -> AccessPackageAssignmentRequest {
+        AccessPackageAssignmentRequest {
+            request_type: "userAdd".into(),
+            assignment: AssignmentRequestAssignment {
+                assignment_policy_id: Some(assignment_policy_id.to_string()),
+                access_package_id: Some(access_package_id.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// A direct assignment of the access package to the user `target_id` by an
+    /// administrator.
+    pub fn admin_add(
+        target_id: impl ToString,
+        access_package_id: impl ToString,
+        assignment_policy_id: impl ToString,
+    ) -> AccessPackageAssignmentRequest {
+        let mut request =
+            AccessPackageAssignmentRequest::user_add(access_package_id, assignment_policy_id);
+        request.request_type = "adminAdd".into();
+        request.assignment.target_id = Some(target_id.to_string());
+        request
+    }
+
+    /// The removal of an existing assignment by an administrator.
+    pub fn admin_remove(assignment_id: impl ToString) -> AccessPackageAssignmentRequest {
+        AccessPackageAssignmentRequest {
+            request_type: "adminRemove".into(),
+            assignment: AssignmentRequestAssignment {
+                id: Some(assignment_id.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn justification(mut self, justification: impl ToString) -> Self {
+        self.justification = Some(justification.to_string());
+        self
+    }
+
+    pub fn answer(mut self, answer: AccessPackageAnswer) -> Self {
+        self.answers.push(answer);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::identity_governance::{
+        AccessPackageAssignmentPolicy, AccessPackageQuestion, ApprovalStage, SubjectSet,
+    };
+
+    #[test]
+    fn serialize_assignment_request_with_answers() {
+        let request = AccessPackageAssignmentRequest::user_add("package-id", "policy-id")
+            .justification("Joining the Sales team")
+            .answer(AccessPackageAnswer::choice("question-id", "emea"));
+
+        assert_eq!(
+            serde_json::json!({
+                "requestType": "userAdd",
+                "assignment": {
+                    "assignmentPolicyId": "policy-id",
+                    "accessPackageId": "package-id"
+                },
+                "justification": "Joining the Sales team",
+                "answers": [{
+                    "@odata.type": "#microsoft.graph.accessPackageAnswerString",
+                    "value": "emea",
+                    "answeredQuestion": {
+                        "@odata.type": "#microsoft.graph.accessPackageMultipleChoiceQuestion",
+                        "id": "question-id"
+                    }
+                }]
+            }),
+            serde_json::to_value(&request).unwrap()
+        );
+
+        let policy = AccessPackageAssignmentPolicy::builder("Sales", "package-id")
+            .specific_allowed_target(SubjectSet::group_members("group-id"))
+            .approval_stage(ApprovalStage::new([SubjectSet::manager()]))
+            .question(AccessPackageQuestion::text("Why?", true))
+            .question(AccessPackageQuestion::multiple_choice(
+                "Region",
+                true,
+                [("emea", "EMEA"), ("apac", "APAC")],
+            ))
+            .build();
+        let value = serde_json::to_value(&policy).unwrap();
+        assert_eq!(
+            serde_json::json!([{ "@odata.type": "#microsoft.graph.groupMembers", "groupId": "group-id" }]),
+            value["specificAllowedTargets"]
+        );
+        assert_eq!(
+            serde_json::json!({ "@odata.type": "#microsoft.graph.requestorManager", "managerLevel": 1 }),
+            value["requestApprovalSettings"]["stages"][0]["primaryApprovers"][0]
+        );
+        assert_eq!(1, value["questions"][1]["sequence"]);
+        assert_eq!(policy, serde_json::from_value(value).unwrap());
+    }
+}
@@ -1,2 +1,5 @@
+mod assignment_request;
 mod request;
+
+pub use assignment_request::*;
 pub use request::*;
@@ -74,4 +74,26 @@ impl EntitlementManagementApiClient {
         path: "/entitlementManagement/settings",
         body: true
     );
+    post!(
+        doc: "Create accessPackageResourceRequest",
+        name: create_resource_requests,
+        path: "/entitlementManagement/resourceRequests",
+        body: true
+    );
+    get!(
+        doc: "List resourceRequests",
+        name: list_resource_requests,
+        path: "/entitlementManagement/resourceRequests"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_resource_requests_count,
+        path: "/entitlementManagement/resourceRequests/$count"
+    );
+    get!(
+        doc: "Get resourceRequests from identityGovernance",
+        name: get_resource_requests,
+        path: "/entitlementManagement/resourceRequests/{{id}}",
+        params: access_package_resource_request_id
+    );
 }
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+
+/// A reference to an entitlement management object by id, such as the catalog
+/// of an access package.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EntitlementReference {
+    pub id: String,
+}
+
+impl EntitlementReference {
+    pub fn new(id: impl ToString) -> EntitlementReference {
+        EntitlementReference { id: id.to_string() }
+    }
+}
+
+/// The system a catalog resource comes from.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum OriginSystem {
+    /// A security group or Microsoft 365 group.
+    AadGroup,
+    /// An enterprise application.
+    AadApplication,
+    /// A SharePoint Online site.
+    SharePointOnline,
+}
+
+/// The body used to create or update an accessPackageCatalog and the catalog
+/// returned in the response.
+///
+/// # Example
+/// ```rust,ignore
+/// let catalog = AccessPackageCatalog::new("Sales resources")
+///     .description("Groups and sites used by the Sales department")
+///     .externally_visible(false);
+///
+/// let response = client
+///     .identity_governance()
+///     .entitlement_management()
+///     .catalogs()
+///     .create_catalogs(&catalog)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPackageCatalog {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether users outside of the organization can request access packages
+    /// of the catalog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_externally_visible: Option<bool>,
+    /// Either `published` or `unpublished`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing)]
+    pub catalog_type: Option<String>,
+}
+
+impl AccessPackageCatalog {
+    pub fn new(display_name: impl ToString) -> AccessPackageCatalog {
+        AccessPackageCatalog {
+            display_name: display_name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn externally_visible(mut self, is_externally_visible: bool) -> Self {
+        self.is_externally_visible = Some(is_externally_visible);
+        self
+    }
+
+    /// Access packages of an unpublished catalog can not be requested.
+    pub fn published(mut self, published: bool) -> Self {
+        let state = if published {
+            "published"
+        } else {
+            "unpublished"
+        };
+        self.state = Some(state.into());
+        self
+    }
+}
+
+/// A resource of a catalog, such as a group, application or site.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPackageResource {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The id of the resource in the origin system, such as the object id of a
+    /// group or the url of a SharePoint Online site.
+    pub origin_id: String,
+    pub origin_system: OriginSystem,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
+
+impl AccessPackageResource {
+    pub fn new(origin_id: impl ToString, origin_system: OriginSystem) -> AccessPackageResource {
+        AccessPackageResource {
+            id: None,
+            origin_id: origin_id.to_string(),
+            origin_system,
+            display_name: None,
+        }
+    }
+
+    pub fn group(group_id: impl ToString) -> AccessPackageResource {
+        AccessPackageResource::new(group_id, OriginSystem::AadGroup)
+    }
+
+    pub fn application(service_principal_id: impl ToString) -> AccessPackageResource {
+        AccessPackageResource::new(service_principal_id, OriginSystem::AadApplication)
+    }
+
+    pub fn site(site_url: impl ToString) -> AccessPackageResource {
+        AccessPackageResource::new(site_url, OriginSystem::SharePointOnline)
+    }
+}
+
+/// The body of a resourceRequests request, which adds a resource to or removes
+/// a resource from a catalog.
+///
+/// # Example
+/// ```rust,ignore
+/// let response = client
+///     .identity_governance()
+///     .entitlement_management()
+///     .create_resource_requests(&AccessPackageResourceRequest::add(
+///         CATALOG_ID,
+///         AccessPackageResource::group(GROUP_ID),
+///     ))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessPackageResourceRequest {
+    /// Either `adminAdd` or `adminRemove`.
+    pub request_type: String,
+    pub resource: AccessPackageResource,
+    pub catalog: EntitlementReference,
+}
+
+impl AccessPackageResourceRequest {
+    pub fn add(catalog_id: impl ToString, resource: AccessPackageResource) -> Self {
+        AccessPackageResourceRequest {
+            request_type: "adminAdd".into(),
+            resource,
+            catalog: EntitlementReference::new(catalog_id),
+        }
+    }
+
+    /// The resource must be the resource of the catalog, including its `id`.
+    pub fn remove(catalog_id: impl ToString, resource: AccessPackageResource) -> Self {
+        AccessPackageResourceRequest {
+            request_type: "adminRemove".into(),
+            resource,
+            catalog: EntitlementReference::new(catalog_id),
+        }
+    }
+}
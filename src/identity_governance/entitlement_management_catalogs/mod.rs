@@ -1,2 +1,5 @@
+mod access_package_catalog;
 mod request;
+
+pub use access_package_catalog::*;
 pub use request::*;
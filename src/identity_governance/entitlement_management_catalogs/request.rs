@@ -67,4 +67,31 @@ impl EntitlementManagementCatalogsIdApiClient {
         name: list_access_packages,
         path: "/catalogs/{{RID}}/accessPackages"
     );
+    get!(
+        doc: "List resources",
+        name: list_resources,
+        path: "/catalogs/{{RID}}/resources"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_resources_count,
+        path: "/catalogs/{{RID}}/resources/$count"
+    );
+    get!(
+        doc: "Get resources from identityGovernance",
+        name: get_resources,
+        path: "/catalogs/{{RID}}/resources/{{id}}",
+        params: access_package_resource_id
+    );
+    get!(
+        doc: "List resourceRoles",
+        name: list_resource_roles,
+        path: "/catalogs/{{RID}}/resourceRoles"
+    );
+    get!(
+        doc: "Get resourceRoles from identityGovernance",
+        name: get_resource_roles,
+        path: "/catalogs/{{RID}}/resourceRoles/{{id}}",
+        params: access_package_resource_role_id
+    );
 }
@@ -0,0 +1,82 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+use crate::identity_governance::*;
+
+api_client!(
+    LifecycleWorkflowsApiClient,
+    ResourceIdentity::LifecycleWorkflows
+);
+
+impl LifecycleWorkflowsApiClient {
+    api_client_link!(workflows, LifecycleWorkflowsWorkflowsApiClient);
+    api_client_link_id!(workflow, LifecycleWorkflowsWorkflowsIdApiClient);
+
+    get!(
+        doc: "Get lifecycleWorkflows from identityGovernance",
+        name: get_lifecycle_workflows,
+        path: "/lifecycleWorkflows"
+    );
+    patch!(
+        doc: "Update the navigation property lifecycleWorkflows in identityGovernance",
+        name: update_lifecycle_workflows,
+        path: "/lifecycleWorkflows",
+        body: true
+    );
+    get!(
+        doc: "List deleted workflows",
+        name: list_deleted_workflows,
+        path: "/lifecycleWorkflows/deletedItems/workflows"
+    );
+    get!(
+        doc: "Get deleted workflow",
+        name: get_deleted_workflows,
+        path: "/lifecycleWorkflows/deletedItems/workflows/{{id}}",
+        params: workflow_id
+    );
+    delete!(
+        doc: "Permanently delete a deleted workflow",
+        name: delete_deleted_workflows,
+        path: "/lifecycleWorkflows/deletedItems/workflows/{{id}}",
+        params: workflow_id
+    );
+    post!(
+        doc: "Invoke action restore",
+        name: restore_deleted_workflow,
+        path: "/lifecycleWorkflows/deletedItems/workflows/{{id}}/microsoft.graph.identityGovernance.restore",
+        params: workflow_id
+    );
+    get!(
+        doc: "Get lifecycleManagementSettings",
+        name: get_settings,
+        path: "/lifecycleWorkflows/settings"
+    );
+    patch!(
+        doc: "Update lifecycleManagementSettings",
+        name: update_settings,
+        path: "/lifecycleWorkflows/settings",
+        body: true
+    );
+    get!(
+        doc: "List taskDefinitions",
+        name: list_task_definitions,
+        path: "/lifecycleWorkflows/taskDefinitions"
+    );
+    get!(
+        doc: "Get taskDefinition",
+        name: get_task_definitions,
+        path: "/lifecycleWorkflows/taskDefinitions/{{id}}",
+        params: task_definition_id
+    );
+    get!(
+        doc: "List workflowTemplates",
+        name: list_workflow_templates,
+        path: "/lifecycleWorkflows/workflowTemplates"
+    );
+    get!(
+        doc: "Get workflowTemplate",
+        name: get_workflow_templates,
+        path: "/lifecycleWorkflows/workflowTemplates/{{id}}",
+        params: workflow_template_id
+    );
+}
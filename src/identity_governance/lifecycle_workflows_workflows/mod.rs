@@ -0,0 +1,5 @@
+mod request;
+mod workflow;
+
+pub use request::*;
+pub use workflow::*;
@@ -0,0 +1,127 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    LifecycleWorkflowsWorkflowsApiClient,
+    LifecycleWorkflowsWorkflowsIdApiClient,
+    ResourceIdentity::LifecycleWorkflowsWorkflows
+);
+
+impl LifecycleWorkflowsWorkflowsApiClient {
+    post!(
+        doc: "Create workflow",
+        name: create_workflows,
+        path: "/workflows",
+        body: true
+    );
+    get!(
+        doc: "List workflows",
+        name: list_workflows,
+        path: "/workflows"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_workflows_count,
+        path: "/workflows/$count"
+    );
+}
+
+impl LifecycleWorkflowsWorkflowsIdApiClient {
+    delete!(
+        doc: "Delete workflow",
+        name: delete_workflows,
+        path: "/workflows/{{RID}}"
+    );
+    get!(
+        doc: "Get workflow",
+        name: get_workflows,
+        path: "/workflows/{{RID}}"
+    );
+    patch!(
+        doc: "Update workflow",
+        name: update_workflows,
+        path: "/workflows/{{RID}}",
+        body: true
+    );
+    post!(
+        doc: "Invoke action activate",
+        name: activate,
+        path: "/workflows/{{RID}}/microsoft.graph.identityGovernance.activate",
+        body: true
+    );
+    post!(
+        doc: "Invoke action createNewVersion",
+        name: create_new_version,
+        path: "/workflows/{{RID}}/microsoft.graph.identityGovernance.createNewVersion",
+        body: true
+    );
+    get!(
+        doc: "List runs",
+        name: list_runs,
+        path: "/workflows/{{RID}}/runs"
+    );
+    get!(
+        doc: "Invoke function summary",
+        name: runs_summary,
+        path: "/workflows/{{RID}}/runs/microsoft.graph.identityGovernance.summary(startDateTime={{id}},endDateTime={{id2}})",
+        params: start_date_time, end_date_time
+    );
+    get!(
+        doc: "Get run",
+        name: get_runs,
+        path: "/workflows/{{RID}}/runs/{{id}}",
+        params: run_id
+    );
+    get!(
+        doc: "List userProcessingResults of a run",
+        name: list_run_user_processing_results,
+        path: "/workflows/{{RID}}/runs/{{id}}/userProcessingResults",
+        params: run_id
+    );
+    get!(
+        doc: "List taskProcessingResults of a run",
+        name: list_run_task_processing_results,
+        path: "/workflows/{{RID}}/runs/{{id}}/taskProcessingResults",
+        params: run_id
+    );
+    get!(
+        doc: "List tasks",
+        name: list_tasks,
+        path: "/workflows/{{RID}}/tasks"
+    );
+    get!(
+        doc: "Get task",
+        name: get_tasks,
+        path: "/workflows/{{RID}}/tasks/{{id}}",
+        params: task_id
+    );
+    get!(
+        doc: "List userProcessingResults",
+        name: list_user_processing_results,
+        path: "/workflows/{{RID}}/userProcessingResults"
+    );
+    get!(
+        doc: "Invoke function summary",
+        name: user_processing_results_summary,
+        path: "/workflows/{{RID}}/userProcessingResults/microsoft.graph.identityGovernance.summary(startDateTime={{id}},endDateTime={{id2}})",
+        params: start_date_time, end_date_time
+    );
+    get!(
+        doc: "Get userProcessingResult",
+        name: get_user_processing_results,
+        path: "/workflows/{{RID}}/userProcessingResults/{{id}}",
+        params: user_processing_result_id
+    );
+    get!(
+        doc: "List versions",
+        name: list_versions,
+        path: "/workflows/{{RID}}/versions"
+    );
+    get!(
+        doc: "Get workflowVersion",
+        name: get_versions,
+        path: "/workflows/{{RID}}/versions/{{id}}",
+        params: version_number
+    );
+}
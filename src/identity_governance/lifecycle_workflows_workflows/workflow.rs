@@ -0,0 +1,366 @@
+use serde::{Deserialize, Serialize};
+
+/// Whether a workflow runs for users joining, moving within, or leaving the organization.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WorkflowCategory {
+    Joiner,
+    Mover,
+    Leaver,
+}
+
+/// The user attribute that the trigger of a workflow is based on.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimeBasedAttribute {
+    EmployeeHireDate,
+    EmployeeLeaveDateTime,
+    CreatedDateTime,
+}
+
+/// The users in scope of a workflow, such as `department eq 'Marketing'`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RuleBasedSubjectSet {
+    #[serde(rename = "@odata.type")]
+    pub odata_type: String,
+    pub rule: String,
+}
+
+/// Run a workflow a number of days before or after the date of a user attribute.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeBasedAttributeTrigger {
+    #[serde(rename = "@odata.type")]
+    pub odata_type: String,
+    pub time_based_attribute: TimeBasedAttribute,
+    /// Between -180 and 180 days.
+    pub offset_in_days: i32,
+}
+
+/// When a workflow runs and for which users.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@odata.type")]
+pub enum WorkflowExecutionConditions {
+    #[serde(rename = "#microsoft.graph.identityGovernance.triggerAndScopeBasedConditions")]
+    TriggerAndScopeBased {
+        scope: RuleBasedSubjectSet,
+        trigger: TimeBasedAttributeTrigger,
+    },
+    /// The workflow only runs when it is activated for users on demand.
+    #[serde(rename = "#microsoft.graph.identityGovernance.onDemandExecutionOnly")]
+    OnDemandExecutionOnly,
+}
+
+impl WorkflowExecutionConditions {
+    /// Run the workflow for users matching the rule, `offset_in_days` before or
+    /// after the date of the attribute.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // Three days before the hire date of new users in the Sales department.
+    /// let conditions = WorkflowExecutionConditions::scheduled(
+    ///     "(department eq 'Sales')",
+    ///     TimeBasedAttribute::EmployeeHireDate,
+    ///     -3,
+    /// );
+    /// ```
+    pub fn scheduled(
+        rule: impl ToString,
+        time_based_attribute: TimeBasedAttribute,
+        offset_in_days: i32,
+    ) -> WorkflowExecutionConditions {
+        WorkflowExecutionConditions::TriggerAndScopeBased {
+            scope: RuleBasedSubjectSet {
+                odata_type: "#microsoft.graph.identityGovernance.ruleBasedSubjectSet".into(),
+                rule: rule.to_string(),
+            },
+            trigger: TimeBasedAttributeTrigger {
+                odata_type: "#microsoft.graph.identityGovernance.timeBasedAttributeTrigger".into(),
+                time_based_attribute,
+                offset_in_days,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TaskArgument {
+    pub name: String,
+    pub value: String,
+}
+
+/// A task of a workflow based on one of the task definitions, such as
+/// `Generate Temporary Access Pass and send via email to user's manager`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowTask {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    pub task_definition_id: String,
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub is_enabled: bool,
+    pub continue_on_error: bool,
+    #[serde(default)]
+    pub arguments: Vec<TaskArgument>,
+    #[serde(skip_serializing)]
+    pub execution_sequence: Option<i32>,
+}
+
+impl WorkflowTask {
+    pub fn new(task_definition_id: impl ToString, display_name: impl ToString) -> WorkflowTask {
+        WorkflowTask {
+            task_definition_id: task_definition_id.to_string(),
+            display_name: display_name.to_string(),
+            is_enabled: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Continue with the next task of the workflow when this task fails.
+    pub fn continue_on_error(mut self, continue_on_error: bool) -> Self {
+        self.continue_on_error = continue_on_error;
+        self
+    }
+
+    /// An argument required by the task definition, such as `tapLifetimeMinutes`.
+    pub fn argument(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.arguments.push(TaskArgument {
+            name: name.to_string(),
+            value: value.to_string(),
+        });
+        self
+    }
+}
+
+/// The body used to create or update a lifecycle workflow and the workflow
+/// returned in the response.
+///
+/// # Example
+/// ```rust,ignore
+/// let workflow = Workflow::builder(
+///     WorkflowCategory::Joiner,
+///     "Onboard pre-hire employee",
+///     WorkflowExecutionConditions::scheduled(
+///         "(department eq 'Sales')",
+///         TimeBasedAttribute::EmployeeHireDate,
+///         -3,
+///     ),
+/// )
+/// .task(
+///     WorkflowTask::new("1b555e50-7f65-41d5-b514-5894a026d10d", "Generate TAP And Send Email")
+///         .argument("tapLifetimeMinutes", "480")
+///         .argument("tapIsUsableOnce", "true"),
+/// )
+/// .scheduling_enabled(true)
+/// .build();
+///
+/// let response = client
+///     .identity_governance()
+///     .lifecycle_workflows()
+///     .workflows()
+///     .create_workflows(&workflow)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Workflow {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    pub category: WorkflowCategory,
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub is_enabled: bool,
+    /// Run the workflow on the schedule of the lifecycle workflow settings
+    /// instead of only on demand.
+    pub is_scheduling_enabled: bool,
+    pub execution_conditions: WorkflowExecutionConditions,
+    #[serde(default)]
+    pub tasks: Vec<WorkflowTask>,
+    #[serde(skip_serializing)]
+    pub version: Option<i32>,
+}
+
+impl Workflow {
+    pub fn builder(
+        category: WorkflowCategory,
+        display_name: impl ToString,
+        execution_conditions: WorkflowExecutionConditions,
+    ) -> WorkflowBuilder {
+        WorkflowBuilder {
+            workflow: Workflow {
+                id: None,
+                category,
+                display_name: display_name.to_string(),
+                description: None,
+                is_enabled: true,
+                is_scheduling_enabled: false,
+                execution_conditions,
+                tasks: vec![],
+                version: None,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WorkflowBuilder {
+    workflow: Workflow,
+}
+
+impl WorkflowBuilder {
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.workflow.description = Some(description.to_string());
+        self
+    }
+
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.workflow.is_enabled = is_enabled;
+        self
+    }
+
+    pub fn scheduling_enabled(mut self, is_scheduling_enabled: bool) -> Self {
+        self.workflow.is_scheduling_enabled = is_scheduling_enabled;
+        self
+    }
+
+    /// Tasks run in the order they are added.
+    pub fn task(mut self, task: WorkflowTask) -> Self {
+        self.workflow.tasks.push(task);
+        self
+    }
+
+    pub fn build(self) -> Workflow {
+        self.workflow
+    }
+}
+
+/// The body of createNewVersion. Changes to the tasks or execution conditions of a
+/// workflow are made by creating a new version of the workflow.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NewWorkflowVersion {
+    pub workflow: Workflow,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowSubject {
+    pub id: String,
+}
+
+/// The body of activate, which runs a workflow on demand for the users.
+///
+/// # Example
+/// ```rust,ignore
+/// let response = client
+///     .identity_governance()
+///     .lifecycle_workflows()
+///     .workflow(WORKFLOW_ID)
+///     .activate(&ActivateWorkflow::users([USER_ID]))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ActivateWorkflow {
+    pub subjects: Vec<WorkflowSubject>,
+}
+
+impl ActivateWorkflow {
+    pub fn users<I: IntoIterator<Item = T>, T: ToString>(user_ids: I) -> ActivateWorkflow {
+        ActivateWorkflow {
+            subjects: user_ids
+                .into_iter()
+                .map(|id| WorkflowSubject { id: id.to_string() })
+                .collect(),
+        }
+    }
+}
+
+/// The response of the runs summary function of a workflow.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RunSummary {
+    pub failed_runs: i32,
+    pub failed_tasks: i32,
+    pub successful_runs: i32,
+    pub total_runs: i32,
+    pub total_tasks: i32,
+    pub total_users: i32,
+}
+
+/// The response of the userProcessingResults summary function of a workflow.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UserSummary {
+    pub failed_tasks: i32,
+    pub failed_users: i32,
+    pub successful_users: i32,
+    pub total_tasks: i32,
+    pub total_users: i32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_workflow() {
+        let workflow = Workflow::builder(
+            WorkflowCategory::Leaver,
+            "Offboard employee",
+            WorkflowExecutionConditions::scheduled(
+                "(department eq 'Sales')",
+                TimeBasedAttribute::EmployeeLeaveDateTime,
+                7,
+            ),
+        )
+        .task(WorkflowTask::new(
+            "task-definition-id",
+            "Remove user from all groups",
+        ))
+        .build();
+
+        let value = serde_json::to_value(&workflow).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "category": "leaver",
+                "displayName": "Offboard employee",
+                "isEnabled": true,
+                "isSchedulingEnabled": false,
+                "executionConditions": {
+                    "@odata.type": "#microsoft.graph.identityGovernance.triggerAndScopeBasedConditions",
+                    "scope": {
+                        "@odata.type": "#microsoft.graph.identityGovernance.ruleBasedSubjectSet",
+                        "rule": "(department eq 'Sales')"
+                    },
+                    "trigger": {
+                        "@odata.type": "#microsoft.graph.identityGovernance.timeBasedAttributeTrigger",
+                        "timeBasedAttribute": "employeeLeaveDateTime",
+                        "offsetInDays": 7
+                    }
+                },
+                "tasks": [{
+                    "taskDefinitionId": "task-definition-id",
+                    "displayName": "Remove user from all groups",
+                    "isEnabled": true,
+                    "continueOnError": false,
+                    "arguments": []
+                }]
+            }),
+            value
+        );
+        assert_eq!(workflow, serde_json::from_value(value).unwrap());
+
+        assert_eq!(
+            serde_json::json!({ "subjects": [{ "id": "user-id" }] }),
+            serde_json::to_value(ActivateWorkflow::users(["user-id"])).unwrap()
+        );
+    }
+}
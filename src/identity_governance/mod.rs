@@ -13,6 +13,8 @@ mod connected_organizations_internal_sponsors;
 mod entitlement_management;
 mod entitlement_management_assignments;
 mod entitlement_management_catalogs;
+mod lifecycle_workflows;
+mod lifecycle_workflows_workflows;
 mod request;
 
 pub use access_package_assignment_approvals::*;
@@ -30,4 +32,6 @@ pub use connected_organizations_internal_sponsors::*;
 pub use entitlement_management::*;
 pub use entitlement_management_assignments::*;
 pub use entitlement_management_catalogs::*;
+pub use lifecycle_workflows::*;
+pub use lifecycle_workflows_workflows::*;
 pub use request::*;
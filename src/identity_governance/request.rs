@@ -3,6 +3,7 @@
 use crate::api_default_imports::*;
 use crate::identity_governance::{
     AccessReviewsApiClient, AppConsentApiClient, EntitlementManagementApiClient,
+    LifecycleWorkflowsApiClient,
 };
 
 api_client!(
@@ -22,6 +23,11 @@ impl IdentityGovernanceApiClient {
         ResourceIdentity::EntitlementManagement,
         EntitlementManagementApiClient
     );
+    api_client_link!(
+        lifecycle_workflows,
+        ResourceIdentity::LifecycleWorkflows,
+        LifecycleWorkflowsApiClient
+    );
 
     get!(
         doc: "Get identityGovernance",
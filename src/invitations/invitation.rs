@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+use crate::users::Recipient;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InvitedUserMessageInfo {
+    #[serde(
+        rename = "ccRecipients",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub cc_recipients: Vec<Recipient>,
+    #[serde(
+        rename = "customizedMessageBody",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub customized_message_body: Option<String>,
+    #[serde(rename = "messageLanguage", skip_serializing_if = "Option::is_none")]
+    pub message_language: Option<String>,
+}
+
+impl InvitedUserMessageInfo {
+    fn is_empty(&self) -> bool {
+        self.cc_recipients.is_empty()
+            && self.customized_message_body.is_none()
+            && self.message_language.is_none()
+    }
+}
+
+/// The body of the create invitation request and the invitation returned in the response.
+///
+/// # Example
+/// ```rust,ignore
+/// let invitation = Invitation::builder("guest@fabrikam.com", "https://myapps.microsoft.com")
+///     .invited_user_display_name("Guest User")
+///     .send_invitation_message(true)
+///     .cc_recipient("manager@contoso.com")
+///     .build();
+///
+/// let response = client
+///     .invitations()
+///     .create_invitation(&invitation)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Invitation {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "invitedUserEmailAddress")]
+    pub invited_user_email_address: String,
+    #[serde(rename = "inviteRedirectUrl")]
+    pub invite_redirect_url: String,
+    #[serde(
+        rename = "invitedUserDisplayName",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub invited_user_display_name: Option<String>,
+    #[serde(rename = "invitedUserType", skip_serializing_if = "Option::is_none")]
+    pub invited_user_type: Option<String>,
+    #[serde(
+        rename = "sendInvitationMessage",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub send_invitation_message: Option<bool>,
+    #[serde(
+        rename = "invitedUserMessageInfo",
+        default,
+        skip_serializing_if = "InvitedUserMessageInfo::is_empty"
+    )]
+    pub invited_user_message_info: InvitedUserMessageInfo,
+    #[serde(rename = "inviteRedeemUrl", skip_serializing)]
+    pub invite_redeem_url: Option<String>,
+    #[serde(skip_serializing)]
+    pub status: Option<String>,
+    #[serde(rename = "invitedUser", skip_serializing)]
+    pub invited_user: Option<InvitedUser>,
+}
+
+impl Invitation {
+    pub fn builder(
+        invited_user_email_address: impl ToString,
+        invite_redirect_url: impl ToString,
+    ) -> InvitationBuilder {
+        InvitationBuilder {
+            invitation: Invitation {
+                invited_user_email_address: invited_user_email_address.to_string(),
+                invite_redirect_url: invite_redirect_url.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// The user created for the invitation.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InvitedUser {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct InvitationBuilder {
+    invitation: Invitation,
+}
+
+impl InvitationBuilder {
+    pub fn invited_user_display_name(mut self, display_name: impl ToString) -> Self {
+        self.invitation.invited_user_display_name = Some(display_name.to_string());
+        self
+    }
+
+    /// The user type of the invited user, `Guest` (default) or `Member`.
+    pub fn invited_user_type(mut self, user_type: impl ToString) -> Self {
+        self.invitation.invited_user_type = Some(user_type.to_string());
+        self
+    }
+
+    /// Send the invitation email to the invited user. Default is false, in which
+    /// case the redeem url in the response must be given to the user.
+    pub fn send_invitation_message(mut self, send_invitation_message: bool) -> Self {
+        self.invitation.send_invitation_message = Some(send_invitation_message);
+        self
+    }
+
+    /// Send a copy of the invitation email to the address. Only one cc recipient is supported.
+    pub fn cc_recipient(mut self, address: impl ToString) -> Self {
+        self.invitation
+            .invited_user_message_info
+            .cc_recipients
+            .push(Recipient::new(address));
+        self
+    }
+
+    pub fn customized_message_body(mut self, message: impl ToString) -> Self {
+        self.invitation
+            .invited_user_message_info
+            .customized_message_body = Some(message.to_string());
+        self
+    }
+
+    /// The language of the invitation email such as `en-US`.
+    pub fn message_language(mut self, language: impl ToString) -> Self {
+        self.invitation.invited_user_message_info.message_language = Some(language.to_string());
+        self
+    }
+
+    pub fn build(self) -> Invitation {
+        self.invitation
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_invitation() {
+        let invitation = Invitation::builder("guest@fabrikam.com", "https://myapps.microsoft.com")
+            .send_invitation_message(true)
+            .cc_recipient("manager@contoso.com")
+            .build();
+
+        assert_eq!(
+            serde_json::json!({
+                "invitedUserEmailAddress": "guest@fabrikam.com",
+                "inviteRedirectUrl": "https://myapps.microsoft.com",
+                "sendInvitationMessage": true,
+                "invitedUserMessageInfo": {
+                    "ccRecipients": [{
+                        "emailAddress": {
+                            "address": "manager@contoso.com"
+                        }
+                    }]
+                }
+            }),
+            serde_json::to_value(&invitation).unwrap()
+        );
+
+        let invitation =
+            Invitation::builder("guest@fabrikam.com", "https://myapps.microsoft.com").build();
+        assert!(serde_json::to_value(&invitation)
+            .unwrap()
+            .get("invitedUserMessageInfo")
+            .is_none());
+    }
+}
@@ -0,0 +1,30 @@
+use crate::api_default_imports::*;
+use crate::invitations::{Invitation, InvitationsApiClient};
+
+impl InvitationsApiClient {
+    /// Invite a guest user without sending the invitation email and return the
+    /// url the guest uses to redeem the invitation.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let redeem_url = client
+    ///     .invitations()
+    ///     .invite_guest("guest@fabrikam.com", "https://myapps.microsoft.com")
+    ///     .await?;
+    /// ```
+    pub async fn invite_guest(
+        &self,
+        email: impl ToString,
+        redirect_url: impl ToString,
+    ) -> GraphResult<String> {
+        let invitation = Invitation::builder(email, redirect_url)
+            .send_invitation_message(false)
+            .build();
+
+        let response = self.create_invitation(&invitation).send().await?;
+        let invitation: Invitation = Paging::success_response(response).await?.json().await?;
+        invitation
+            .invite_redeem_url
+            .ok_or_else(|| GraphFailure::not_found("inviteRedeemUrl not found in invitation"))
+    }
+}
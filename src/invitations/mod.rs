@@ -1,3 +1,8 @@
+mod invitation;
+mod manual_request;
 mod request;
 
+pub use invitation::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -187,9 +187,11 @@ pub mod batch;
 pub mod branding;
 pub mod certificate_based_auth_configuration;
 pub mod chats;
+pub mod collection;
 pub mod communications;
 pub mod contracts;
 pub mod data_policy_operations;
+pub mod datetime;
 pub mod default_drive;
 pub mod device_app_management;
 pub mod device_management;
@@ -202,7 +204,10 @@ pub mod domains;
 pub mod drives;
 pub mod education;
 pub mod extended_properties;
+pub mod external;
 pub mod group_lifecycle_policies;
+pub mod group_setting_templates;
+pub mod group_settings;
 pub mod groups;
 /// The main identity APIs with starting path `identity/`
 pub mod identity_access;
@@ -216,8 +221,13 @@ pub mod permission_grants;
 pub mod places;
 pub mod planner;
 pub mod policies;
+pub mod print;
+#[cfg(feature = "beta")]
+pub mod profile;
 pub mod reports;
+pub mod role_management;
 pub mod schema_extensions;
+pub mod security;
 pub mod service_principals;
 pub mod sites;
 pub mod solutions;
@@ -237,16 +247,31 @@ pub use graph_http::api_impl::{GraphClientConfiguration, ODataQuery};
 
 /// Reexport of graph-oauth crate.
 pub mod identity {
-    pub use graph_core::identity::ClientApplication;
+    pub use graph_core::identity::{ClientApplication, TokenValidation};
     pub use graph_oauth::*;
 }
 
+/// Names of the metrics recorded when the `metrics` feature is enabled.
+/// See [graph_core::metrics].
+pub mod metrics {
+    pub use graph_core::metrics::{
+        GRAPH_REQUESTS, GRAPH_REQUEST_DURATION, GRAPH_REQUEST_RETRIES, GRAPH_THROTTLED_REQUESTS,
+        GRAPH_TOKEN_REQUESTS,
+    };
+}
+
 pub mod http {
-    pub use graph_core::http::{HttpResponseBuilderExt, HttpResponseExt};
-    pub use graph_http::api_impl::{BodyRead, FileConfig, UploadSession};
+    pub use graph_core::http::{
+        AsyncBytesResponseExt, BytesRequestExt, BytesResponseExt, HttpResponseBuilderExt,
+        HttpResponseExt, TRACEPARENT, TRACESTATE,
+    };
+    pub use graph_http::api_impl::{
+        BodyRead, ExportFormat, FileConfig, Next, OverwritePolicy, PagingCursor, RequestLayer,
+        UploadProgress, UploadSession,
+    };
     pub use graph_http::traits::{
-        AsyncIterator, ODataDeltaLink, ODataDownloadLink, ODataMetadataLink, ODataNextLink,
-        ODataQuery, ResponseBlockingExt, ResponseExt, UploadSessionLink,
+        skip_token, AsyncIterator, ODataDeltaLink, ODataDownloadLink, ODataMetadataLink,
+        ODataNextLink, ODataQuery, ResponseBlockingExt, ResponseExt, UploadSessionLink,
     };
 
     pub mod blocking {
@@ -254,6 +279,13 @@ pub mod http {
         pub use reqwest::blocking::Body;
     }
 
+    #[cfg(feature = "tower")]
+    pub use graph_http::api_impl::GraphService;
+    #[cfg(feature = "test-util")]
+    pub use graph_http::api_impl::{
+        Cassette, CassetteMode, Fault, FaultInjection, Interaction, RecordedRequest,
+        RecordedResponse, GRAPH_CASSETTE_RECORD,
+    };
     pub use reqwest::tls::Version;
     pub use reqwest::{Body, Method};
     pub use url::Url;
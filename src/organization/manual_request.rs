@@ -0,0 +1,155 @@
+use crate::api_default_imports::*;
+use crate::organization::{BrandingAsset, OrganizationIdApiClient};
+use graph_http::api_impl::BodyRead;
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use std::path::Path;
+
+impl OrganizationIdApiClient {
+    /// Upload an image of the default branding from a body in memory, such as
+    /// [bytes::Bytes] or a [reqwest::Body], with the content type of the image,
+    /// such as `image/png`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .organization(ORGANIZATION_ID)
+    ///     .upload_branding_asset(
+    ///         BrandingAsset::BannerLogo,
+    ///         bytes::Bytes::from(std::fs::read("./banner.png")?),
+    ///         "image/png",
+    ///     )?
+    ///     .send()
+    ///     .await?;
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn upload_branding_asset<B: BodyExt>(
+        &self,
+        asset: BrandingAsset,
+        body: B,
+        content_type: &str,
+    ) -> GraphResult<RequestHandler> {
+        let content_type = asset_content_type(content_type)?;
+        let request = match asset {
+            BrandingAsset::BackgroundImage => self.update_branding_background_image(body),
+            BrandingAsset::BannerLogo => self.update_branding_banner_logo(body),
+            BrandingAsset::SquareLogo => self.update_branding_square_logo(body),
+        };
+        Ok(request.header(CONTENT_TYPE, content_type))
+    }
+
+    /// Upload an image of the branding localization for the locale, such as `fr-FR`.
+    #[allow(clippy::result_large_err)]
+    pub fn upload_localization_asset<B: BodyExt>(
+        &self,
+        locale: &str,
+        asset: BrandingAsset,
+        body: B,
+        content_type: &str,
+    ) -> GraphResult<RequestHandler> {
+        let content_type = asset_content_type(content_type)?;
+        let request = match asset {
+            BrandingAsset::BackgroundImage => {
+                self.update_localizations_background_image(locale, body)
+            }
+            BrandingAsset::BannerLogo => self.update_localizations_banner_logo(locale, body),
+            BrandingAsset::SquareLogo => self.update_localizations_square_logo(locale, body),
+        };
+        Ok(request.header(CONTENT_TYPE, content_type))
+    }
+
+    /// Stream an image file as an image of the default branding. The content type
+    /// is `image/png` or `image/jpeg` based on the extension of the file.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .organization(ORGANIZATION_ID)
+    ///     .upload_branding_asset_from_file(BrandingAsset::BackgroundImage, "./background.jpg")
+    ///     .await?
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub async fn upload_branding_asset_from_file<P: AsRef<Path>>(
+        &self,
+        asset: BrandingAsset,
+        path: P,
+    ) -> GraphResult<RequestHandler> {
+        let content_type = BrandingAsset::content_type(path.as_ref())?;
+        let file = tokio::fs::File::open(path.as_ref()).await?;
+        self.upload_branding_asset(asset, BodyRead::from_async_read_stream(file), content_type)
+    }
+
+    /// Stream an image file as an image of the branding localization for the locale.
+    pub async fn upload_localization_asset_from_file<P: AsRef<Path>>(
+        &self,
+        locale: &str,
+        asset: BrandingAsset,
+        path: P,
+    ) -> GraphResult<RequestHandler> {
+        let content_type = BrandingAsset::content_type(path.as_ref())?;
+        let file = tokio::fs::File::open(path.as_ref()).await?;
+        self.upload_localization_asset(
+            locale,
+            asset,
+            BodyRead::from_async_read_stream(file),
+            content_type,
+        )
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn asset_content_type(content_type: &str) -> GraphResult<HeaderValue> {
+    if !content_type.starts_with("image/") {
+        return Err(GraphFailure::invalid(
+            "branding image content type, expected image/png or image/jpeg",
+        ));
+    }
+    HeaderValue::from_str(content_type).map_err(|_| GraphFailure::invalid("content type"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::organization::OrganizationalBranding;
+    use crate::Graph;
+
+    #[test]
+    fn upload_branding_asset_content_type() {
+        let client = Graph::new("token");
+        let mut request = client
+            .organization("organization-id")
+            .upload_localization_asset(
+                "fr-FR",
+                BrandingAsset::BannerLogo,
+                bytes::Bytes::from_static(&[0x89, 0x50, 0x4e, 0x47]),
+                "image/png",
+            )
+            .unwrap();
+
+        assert_eq!(
+            Some(&HeaderValue::from_static("image/png")),
+            request.headers_mut().get(CONTENT_TYPE)
+        );
+        assert!(client
+            .organization("organization-id")
+            .upload_branding_asset(BrandingAsset::SquareLogo, String::new(), "text/plain")
+            .is_err());
+
+        assert_eq!(
+            "image/jpeg",
+            BrandingAsset::content_type("background.JPG").unwrap()
+        );
+        assert!(BrandingAsset::content_type("background.gif").is_err());
+
+        let branding: OrganizationalBranding = serde_json::from_value(serde_json::json!({
+            "id": "0",
+            "bannerLogoRelativeUrl": "c1c6b6c8-urnpi8ofq5m5/logintenantbranding/0/bannerlogo",
+            "cdnList": ["secure.aadcdn.microsoftonline-p.com"]
+        }))
+        .unwrap();
+        assert_eq!(
+            Some("https://secure.aadcdn.microsoftonline-p.com/c1c6b6c8-urnpi8ofq5m5/logintenantbranding/0/bannerlogo".to_string()),
+            branding.asset_url(BrandingAsset::BannerLogo)
+        );
+    }
+}
@@ -1,3 +1,8 @@
+mod manual_request;
+mod organizational_branding;
 mod request;
 
+#[allow(unused_imports)]
+pub use manual_request::*;
+pub use organizational_branding::*;
 pub use request::*;
@@ -0,0 +1,154 @@
+use graph_error::{GraphFailure, GraphResult};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// An image of the sign-in page branding of an organization.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BrandingAsset {
+    /// The image shown behind the sign-in box, a PNG or JPEG no larger than
+    /// 1920x1080 and 300 KB.
+    BackgroundImage,
+    /// The logo shown in the sign-in box, a transparent PNG or JPEG of 245x36.
+    BannerLogo,
+    /// The logo shown during Windows Autopilot and in mobile apps, a PNG or
+    /// JPEG of 240x240.
+    SquareLogo,
+}
+
+impl BrandingAsset {
+    /// The content type of an image file from its extension, which must be
+    /// `png`, `jpg` or `jpeg`.
+    #[allow(clippy::result_large_err)]
+    pub fn content_type<P: AsRef<Path>>(path: P) -> GraphResult<&'static str> {
+        let extension = path
+            .as_ref()
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase());
+
+        match extension.as_deref() {
+            Some("png") => Ok("image/png"),
+            Some("jpg") | Some("jpeg") => Ok("image/jpeg"),
+            _ => Err(GraphFailure::invalid(
+                "branding image file extension, expected png, jpg or jpeg",
+            )),
+        }
+    }
+}
+
+/// The body used to update the default branding of an organization or to create
+/// and update a localization of the branding, and the branding returned in the
+/// response. Images are uploaded separately as a [BrandingAsset].
+///
+/// # Example
+/// ```rust,ignore
+/// client
+///     .organization(ORGANIZATION_ID)
+///     .update_branding(
+///         &OrganizationalBranding::new()
+///             .background_color("#FFFF33")
+///             .sign_in_page_text("Welcome to Contoso"),
+///     )
+///     .send()
+///     .await?;
+///
+/// client
+///     .organization(ORGANIZATION_ID)
+///     .create_localizations(
+///         &OrganizationalBranding::localization("fr-FR").sign_in_page_text("Bienvenue chez Contoso"),
+///     )
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationalBranding {
+    /// The locale of a localization, such as `fr-FR`, or `0` for the default branding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The color shown in place of the background image on low-bandwidth
+    /// connections, such as `#FFFFFF`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header_background_color: Option<String>,
+    /// Text at the bottom of the sign-in box, up to 1024 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sign_in_page_text: Option<String>,
+    /// The hint in the username box, up to 64 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username_hint_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_privacy_and_cookies_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_terms_of_use_url: Option<String>,
+    /// The relative urls of the uploaded images, returned in the response.
+    #[serde(default, skip_serializing)]
+    pub background_image_relative_url: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub banner_logo_relative_url: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub square_logo_relative_url: Option<String>,
+    /// The base urls that the relative urls of the images are served from.
+    #[serde(default, skip_serializing)]
+    pub cdn_list: Vec<String>,
+}
+
+impl OrganizationalBranding {
+    pub fn new() -> OrganizationalBranding {
+        OrganizationalBranding::default()
+    }
+
+    /// The branding for users whose browser language is the locale, such as `fr-FR`.
+    pub fn localization(locale: impl ToString) -> OrganizationalBranding {
+        OrganizationalBranding {
+            id: Some(locale.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn background_color(mut self, background_color: impl ToString) -> Self {
+        self.background_color = Some(background_color.to_string());
+        self
+    }
+
+    pub fn header_background_color(mut self, header_background_color: impl ToString) -> Self {
+        self.header_background_color = Some(header_background_color.to_string());
+        self
+    }
+
+    pub fn sign_in_page_text(mut self, sign_in_page_text: impl ToString) -> Self {
+        self.sign_in_page_text = Some(sign_in_page_text.to_string());
+        self
+    }
+
+    pub fn username_hint_text(mut self, username_hint_text: impl ToString) -> Self {
+        self.username_hint_text = Some(username_hint_text.to_string());
+        self
+    }
+
+    pub fn custom_privacy_and_cookies_url(mut self, url: impl ToString) -> Self {
+        self.custom_privacy_and_cookies_url = Some(url.to_string());
+        self
+    }
+
+    pub fn custom_terms_of_use_url(mut self, url: impl ToString) -> Self {
+        self.custom_terms_of_use_url = Some(url.to_string());
+        self
+    }
+
+    /// The full url of an uploaded image, using the first url of the CDN list.
+    pub fn asset_url(&self, asset: BrandingAsset) -> Option<String> {
+        let relative_url = match asset {
+            BrandingAsset::BackgroundImage => self.background_image_relative_url.as_ref(),
+            BrandingAsset::BannerLogo => self.banner_logo_relative_url.as_ref(),
+            BrandingAsset::SquareLogo => self.square_logo_relative_url.as_ref(),
+        }?;
+        let cdn = self.cdn_list.first()?;
+        Some(format!(
+            "https://{}/{}",
+            cdn.trim_end_matches('/'),
+            relative_url.trim_start_matches('/')
+        ))
+    }
+}
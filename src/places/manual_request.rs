@@ -0,0 +1,57 @@
+use crate::api_default_imports::*;
+use crate::places::{PlacesApiClient, PlacesIdApiClient, Room, RoomFilter, RoomList};
+use futures::TryStreamExt;
+use serde::de::DeserializeOwned;
+
+async fn list_all<T: DeserializeOwned>(request: RequestHandler) -> GraphResult<Vec<T>> {
+    request.paging().stream_items::<T>()?.try_collect().await
+}
+
+impl PlacesApiClient {
+    /// Every room of the tenant.
+    pub async fn list_rooms_all(&self) -> GraphResult<Vec<Room>> {
+        list_all(self.list_rooms()).await
+    }
+
+    /// Every room list of the tenant.
+    pub async fn list_room_lists_all(&self) -> GraphResult<Vec<RoomList>> {
+        list_all(self.list_room_lists()).await
+    }
+
+    /// The rooms of the tenant that match the filter.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let rooms = client
+    ///     .places()
+    ///     .find_rooms(&RoomFilter::new().min_capacity(8).video())
+    ///     .await?;
+    /// ```
+    pub async fn find_rooms(&self, filter: &RoomFilter) -> GraphResult<Vec<Room>> {
+        let rooms = self.list_rooms_all().await?;
+        Ok(rooms
+            .into_iter()
+            .filter(|room| filter.matches(room))
+            .collect())
+    }
+}
+
+impl PlacesIdApiClient {
+    /// The rooms of the room list that match the filter. The id of the client is
+    /// the id or email address of the room list.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let rooms = client
+    ///     .place("bldg2@contoso.com")
+    ///     .find_rooms(&RoomFilter::new().floor_number(2).display())
+    ///     .await?;
+    /// ```
+    pub async fn find_rooms(&self, filter: &RoomFilter) -> GraphResult<Vec<Room>> {
+        let rooms: Vec<Room> = list_all(self.list_room_list_rooms()).await?;
+        Ok(rooms
+            .into_iter()
+            .filter(|room| filter.matches(room))
+            .collect())
+    }
+}
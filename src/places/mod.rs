@@ -1,3 +1,8 @@
+mod manual_request;
 mod request;
+mod room;
 
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
+pub use room::*;
@@ -12,7 +12,7 @@ impl PlacesApiClient {
     );
     get!(
         doc: "Get the items of type microsoft.graph.room in the microsoft.graph.place collection",
-        name: graph,
+        name: list_rooms,
         path: "/places/graph.room"
     );
     get!(
@@ -20,6 +20,16 @@ impl PlacesApiClient {
         name: get_room_count,
         path: "/places/graph.room/$count"
     );
+    get!(
+        doc: "Get the items of type microsoft.graph.roomList in the microsoft.graph.place collection",
+        name: list_room_lists,
+        path: "/places/graph.roomList"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_room_list_count,
+        path: "/places/graph.roomList/$count"
+    );
 }
 
 impl PlacesIdApiClient {
@@ -28,6 +38,11 @@ impl PlacesIdApiClient {
         name: delete_place,
         path: "/places/{{RID}}"
     );
+    get!(
+        doc: "Get place",
+        name: get_place,
+        path: "/places/{{RID}}"
+    );
     patch!(
         doc: "Update place",
         name: update_place,
@@ -36,7 +51,28 @@ impl PlacesIdApiClient {
     );
     get!(
         doc: "Get the item of type microsoft.graph.place as microsoft.graph.room",
-        name: graph,
+        name: get_room,
         path: "/places/{{RID}}/graph.room"
     );
+    get!(
+        doc: "Get the item of type microsoft.graph.place as microsoft.graph.roomList",
+        name: get_room_list,
+        path: "/places/{{RID}}/graph.roomList"
+    );
+    get!(
+        doc: "Get rooms from places",
+        name: list_room_list_rooms,
+        path: "/places/{{RID}}/graph.roomList/rooms"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_room_list_rooms_count,
+        path: "/places/{{RID}}/graph.roomList/rooms/$count"
+    );
+    get!(
+        doc: "Get rooms from places",
+        name: get_room_list_rooms,
+        path: "/places/{{RID}}/graph.roomList/rooms/{{id}}",
+        params: room_id
+    );
 }
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+
+use crate::users::PhysicalAddress;
+
+/// A meeting room, as returned by `list_rooms` and `get_room`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Room {
+    pub id: String,
+    pub display_name: Option<String>,
+    pub email_address: Option<String>,
+    pub nickname: Option<String>,
+    pub label: Option<String>,
+    pub phone: Option<String>,
+    pub address: Option<PhysicalAddress>,
+    pub building: Option<String>,
+    pub floor_label: Option<String>,
+    pub floor_number: Option<i32>,
+    pub capacity: Option<i32>,
+    /// One of `standard` or `reserved`.
+    pub booking_type: Option<String>,
+    pub audio_device_name: Option<String>,
+    pub video_device_name: Option<String>,
+    pub display_device_name: Option<String>,
+    pub is_wheel_chair_accessible: bool,
+    pub tags: Vec<String>,
+}
+
+impl Room {
+    pub fn has_audio(&self) -> bool {
+        has_device(&self.audio_device_name)
+    }
+
+    pub fn has_video(&self) -> bool {
+        has_device(&self.video_device_name)
+    }
+
+    pub fn has_display(&self) -> bool {
+        has_device(&self.display_device_name)
+    }
+}
+
+fn has_device(name: &Option<String>) -> bool {
+    name.as_deref().is_some_and(|name| !name.trim().is_empty())
+}
+
+/// A group of rooms, such as the rooms of a building.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RoomList {
+    pub id: String,
+    pub display_name: Option<String>,
+    pub email_address: Option<String>,
+    pub phone: Option<String>,
+    pub address: Option<PhysicalAddress>,
+}
+
+/// Conditions that a [Room] must meet to be returned by `find_rooms`. Conditions
+/// that are not set match every room.
+///
+/// # Example
+/// ```rust,ignore
+/// let rooms = client
+///     .places()
+///     .find_rooms(&RoomFilter::new().min_capacity(8).video().building("Building 1"))
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RoomFilter {
+    pub min_capacity: Option<i32>,
+    pub building: Option<String>,
+    pub floor_number: Option<i32>,
+    pub audio: bool,
+    pub video: bool,
+    pub display: bool,
+    pub wheel_chair_accessible: bool,
+    pub tags: Vec<String>,
+}
+
+impl RoomFilter {
+    pub fn new() -> RoomFilter {
+        RoomFilter::default()
+    }
+
+    pub fn min_capacity(mut self, capacity: i32) -> Self {
+        self.min_capacity = Some(capacity);
+        self
+    }
+
+    /// Match the building case insensitively.
+    pub fn building(mut self, building: impl ToString) -> Self {
+        self.building = Some(building.to_string());
+        self
+    }
+
+    pub fn floor_number(mut self, floor_number: i32) -> Self {
+        self.floor_number = Some(floor_number);
+        self
+    }
+
+    /// Require an audio device.
+    pub fn audio(mut self) -> Self {
+        self.audio = true;
+        self
+    }
+
+    /// Require a video device.
+    pub fn video(mut self) -> Self {
+        self.video = true;
+        self
+    }
+
+    /// Require a display device.
+    pub fn display(mut self) -> Self {
+        self.display = true;
+        self
+    }
+
+    pub fn wheel_chair_accessible(mut self) -> Self {
+        self.wheel_chair_accessible = true;
+        self
+    }
+
+    /// Require the tag, matched case insensitively. Can be called more than once.
+    pub fn tag(mut self, tag: impl ToString) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    pub fn matches(&self, room: &Room) -> bool {
+        let eq_ignore_case = |expected: &str, value: &Option<String>| {
+            value
+                .as_deref()
+                .is_some_and(|value| value.eq_ignore_ascii_case(expected))
+        };
+
+        self.min_capacity
+            .is_none_or(|min| room.capacity.unwrap_or_default() >= min)
+            && self
+                .building
+                .as_deref()
+                .is_none_or(|building| eq_ignore_case(building, &room.building))
+            && self
+                .floor_number
+                .is_none_or(|floor| room.floor_number == Some(floor))
+            && (!self.audio || room.has_audio())
+            && (!self.video || room.has_video())
+            && (!self.display || room.has_display())
+            && (!self.wheel_chair_accessible || room.is_wheel_chair_accessible)
+            && self.tags.iter().all(|tag| {
+                room.tags
+                    .iter()
+                    .any(|room_tag| room_tag.eq_ignore_ascii_case(tag))
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn filter_rooms() {
+        let rooms: Vec<Room> = serde_json::from_value(serde_json::json!([
+            {
+                "id": "3162F1E1-C4C0-604B-51D8-91DA78989EB1",
+                "emailAddress": "cf100@contoso.com",
+                "displayName": "Conf Room 100",
+                "building": "1",
+                "floorNumber": 1,
+                "capacity": 50,
+                "bookingType": "standard",
+                "audioDeviceName": null,
+                "videoDeviceName": "Surface Hub",
+                "displayDeviceName": "",
+                "isWheelChairAccessible": true,
+                "tags": ["bean bags"]
+            },
+            {
+                "id": "3162F1E1-C4C0-604B-51D8-91DA78970B97",
+                "emailAddress": "cf200@contoso.com",
+                "displayName": "Conf Room 200",
+                "building": "2",
+                "capacity": 4
+            }
+        ]))
+        .unwrap();
+
+        let filter = RoomFilter::new()
+            .min_capacity(8)
+            .video()
+            .wheel_chair_accessible()
+            .tag("Bean Bags");
+        assert!(filter.matches(&rooms[0]));
+        assert!(!filter.matches(&rooms[1]));
+        assert!(!RoomFilter::new().display().matches(&rooms[0]));
+        assert!(RoomFilter::new().building("2").matches(&rooms[1]));
+    }
+}
@@ -0,0 +1,9 @@
+mod print_jobs;
+mod print_printers;
+mod print_shares;
+mod request;
+
+pub use print_jobs::*;
+pub use print_printers::*;
+pub use print_shares::*;
+pub use request::*;
@@ -0,0 +1,49 @@
+use crate::api_default_imports::*;
+use crate::print::{CreatePrintUploadSession, PrintJobsIdApiClient};
+use graph_http::traits::ResponseExt;
+
+impl PrintJobsIdApiClient {
+    /// Create an upload session for the document of the job and begin uploading the
+    /// content of the reader. The size of the upload must be the size of the content.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use graph_rs_sdk::http::{AsyncIterator, ResponseExt};
+    ///
+    /// let file = tokio::fs::File::open("./report.pdf").await?;
+    /// let size = file.metadata().await?.len() as i64;
+    ///
+    /// let job = client.print().share(SHARE_ID).job(JOB_ID);
+    /// let mut upload_session = job
+    ///     .upload_document(
+    ///         DOCUMENT_ID,
+    ///         &CreatePrintUploadSession::new("report.pdf", "application/pdf", size),
+    ///         file,
+    ///     )
+    ///     .await?;
+    ///
+    /// while let Some(result) = upload_session.next().await {
+    ///     let response = result?;
+    ///     if !response.status().is_success() {
+    ///         return Err(GraphFailure::from(response.into_graph_error_message().await?));
+    ///     }
+    /// }
+    ///
+    /// job.start().send().await?;
+    /// ```
+    pub async fn upload_document<R: tokio::io::AsyncReadExt + Send + Unpin>(
+        &self,
+        document_id: &str,
+        upload: &CreatePrintUploadSession,
+        reader: R,
+    ) -> GraphResult<UploadSession> {
+        let response = self
+            .create_upload_session(document_id, upload)
+            .send()
+            .await?;
+        Paging::success_response(response)
+            .await?
+            .into_upload_session_async_read(reader)
+            .await
+    }
+}
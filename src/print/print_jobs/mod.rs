@@ -0,0 +1,8 @@
+mod manual_request;
+mod print_job;
+mod request;
+
+#[allow(unused_imports)]
+pub use manual_request::*;
+pub use print_job::*;
+pub use request::*;
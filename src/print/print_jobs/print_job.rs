@@ -0,0 +1,231 @@
+use serde::{Deserialize, Serialize};
+
+/// A range of pages, such as pages 1 to 3.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IntegerRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+/// How a job is printed. Settings that are not set use the defaults of the printer.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintJobConfiguration {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copies: Option<i32>,
+    /// Such as `color`, `grayscale` or `blackAndWhite`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color_mode: Option<String>,
+    /// One of `oneSided`, `flipOnLongEdge` or `flipOnShortEdge`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duplex_mode: Option<String>,
+    /// Such as `portrait` or `landscape`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orientation: Option<String>,
+    /// Such as `A4` or `North America Letter`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_size: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dpi: Option<i32>,
+    /// One of `low`, `medium` or `high`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub page_ranges: Vec<IntegerRange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collate: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fit_pdf_to_page: Option<bool>,
+}
+
+impl PrintJobConfiguration {
+    pub fn new() -> PrintJobConfiguration {
+        PrintJobConfiguration::default()
+    }
+
+    pub fn copies(mut self, copies: i32) -> Self {
+        self.copies = Some(copies);
+        self
+    }
+
+    pub fn color_mode(mut self, color_mode: impl ToString) -> Self {
+        self.color_mode = Some(color_mode.to_string());
+        self
+    }
+
+    pub fn duplex_mode(mut self, duplex_mode: impl ToString) -> Self {
+        self.duplex_mode = Some(duplex_mode.to_string());
+        self
+    }
+
+    pub fn orientation(mut self, orientation: impl ToString) -> Self {
+        self.orientation = Some(orientation.to_string());
+        self
+    }
+
+    pub fn media_size(mut self, media_size: impl ToString) -> Self {
+        self.media_size = Some(media_size.to_string());
+        self
+    }
+
+    pub fn dpi(mut self, dpi: i32) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    pub fn quality(mut self, quality: impl ToString) -> Self {
+        self.quality = Some(quality.to_string());
+        self
+    }
+
+    /// Print only the pages from `start` to `end`, inclusive.
+    pub fn page_range(mut self, start: i64, end: i64) -> Self {
+        self.page_ranges.push(IntegerRange { start, end });
+        self
+    }
+
+    pub fn collate(mut self, collate: bool) -> Self {
+        self.collate = Some(collate);
+        self
+    }
+
+    pub fn fit_pdf_to_page(mut self, fit_pdf_to_page: bool) -> Self {
+        self.fit_pdf_to_page = Some(fit_pdf_to_page);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PrintDocument {
+    pub id: String,
+    pub display_name: Option<String>,
+    pub content_type: Option<String>,
+    pub size: i64,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PrintJobStatus {
+    /// Such as `pending`, `processing`, `paused`, `stopped`, `completed`,
+    /// `canceled` or `aborted`.
+    pub state: String,
+    pub description: Option<String>,
+    pub details: Vec<String>,
+    pub is_acquired_by_printer: bool,
+}
+
+impl PrintJobStatus {
+    /// Whether the job has finished printing, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state.as_str(), "completed" | "canceled" | "aborted")
+    }
+}
+
+/// The body used to create a print job for a printer share, and the job returned
+/// in the response. A new job has one document that the content is uploaded to.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintJob {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub configuration: PrintJobConfiguration,
+    #[serde(default, skip_serializing)]
+    pub documents: Vec<PrintDocument>,
+    #[serde(default, skip_serializing)]
+    pub status: Option<PrintJobStatus>,
+    #[serde(default, skip_serializing)]
+    pub created_date_time: Option<String>,
+}
+
+impl PrintJob {
+    pub fn new(configuration: PrintJobConfiguration) -> PrintJob {
+        PrintJob {
+            configuration,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintDocumentUploadProperties {
+    pub document_name: String,
+    /// Such as `application/pdf` or `application/oxps`.
+    pub content_type: String,
+    /// The size of the document in bytes.
+    pub size: i64,
+}
+
+/// The body of createUploadSession for the document of a print job.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CreatePrintUploadSession {
+    pub properties: PrintDocumentUploadProperties,
+}
+
+impl CreatePrintUploadSession {
+    pub fn new(
+        document_name: impl ToString,
+        content_type: impl ToString,
+        size: i64,
+    ) -> CreatePrintUploadSession {
+        CreatePrintUploadSession {
+            properties: PrintDocumentUploadProperties {
+                document_name: document_name.to_string(),
+                content_type: content_type.to_string(),
+                size,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_print_job() {
+        let job = PrintJob::new(
+            PrintJobConfiguration::new()
+                .copies(2)
+                .duplex_mode("flipOnLongEdge")
+                .page_range(1, 3),
+        );
+        assert_eq!(
+            serde_json::json!({
+                "configuration": {
+                    "copies": 2,
+                    "duplexMode": "flipOnLongEdge",
+                    "pageRanges": [{ "start": 1, "end": 3 }]
+                }
+            }),
+            serde_json::to_value(&job).unwrap()
+        );
+
+        assert_eq!(
+            serde_json::json!({
+                "properties": {
+                    "documentName": "report.pdf",
+                    "contentType": "application/pdf",
+                    "size": 4533322
+                }
+            }),
+            serde_json::to_value(CreatePrintUploadSession::new(
+                "report.pdf",
+                "application/pdf",
+                4533322
+            ))
+            .unwrap()
+        );
+
+        let job: PrintJob = serde_json::from_value(serde_json::json!({
+            "id": "job-id",
+            "status": { "state": "completed", "description": "", "details": [] },
+            "documents": [{ "id": "document-id", "displayName": "", "size": 0 }]
+        }))
+        .unwrap();
+        assert!(job.status.unwrap().is_finished());
+        assert_eq!("document-id", job.documents[0].id);
+    }
+}
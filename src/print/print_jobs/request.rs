@@ -0,0 +1,104 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    PrintJobsApiClient,
+    PrintJobsIdApiClient,
+    ResourceIdentity::PrintJobs
+);
+
+impl PrintJobsApiClient {
+    post!(
+        doc: "Create printJob for a printerShare",
+        name: create_jobs,
+        path: "/jobs",
+        body: true
+    );
+    get!(
+        doc: "List printJobs",
+        name: list_jobs,
+        path: "/jobs"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_jobs_count,
+        path: "/jobs/$count"
+    );
+}
+
+impl PrintJobsIdApiClient {
+    delete!(
+        doc: "Delete navigation property jobs for print",
+        name: delete_jobs,
+        path: "/jobs/{{RID}}"
+    );
+    get!(
+        doc: "Get printJob",
+        name: get_jobs,
+        path: "/jobs/{{RID}}"
+    );
+    patch!(
+        doc: "Update printJob",
+        name: update_jobs,
+        path: "/jobs/{{RID}}",
+        body: true
+    );
+    post!(
+        doc: "Invoke action abort",
+        name: abort,
+        path: "/jobs/{{RID}}/abort",
+        body: true
+    );
+    post!(
+        doc: "Invoke action cancel",
+        name: cancel,
+        path: "/jobs/{{RID}}/cancel"
+    );
+    get!(
+        doc: "List documents",
+        name: list_documents,
+        path: "/jobs/{{RID}}/documents"
+    );
+    get!(
+        doc: "Get printDocument",
+        name: get_documents,
+        path: "/jobs/{{RID}}/documents/{{id}}",
+        params: print_document_id
+    );
+    get!(
+        doc: "Get media content for the navigation property documents from print",
+        name: get_documents_content,
+        path: "/jobs/{{RID}}/documents/{{id}}/$value",
+        params: print_document_id
+    );
+    post!(
+        doc: "Invoke action createUploadSession",
+        name: create_upload_session,
+        path: "/jobs/{{RID}}/documents/{{id}}/createUploadSession",
+        body: true,
+        params: print_document_id
+    );
+    post!(
+        doc: "Invoke action redirect",
+        name: redirect,
+        path: "/jobs/{{RID}}/redirect",
+        body: true
+    );
+    post!(
+        doc: "Invoke action start",
+        name: start,
+        path: "/jobs/{{RID}}/start"
+    );
+    get!(
+        doc: "List printTasks",
+        name: list_tasks,
+        path: "/jobs/{{RID}}/tasks"
+    );
+    get!(
+        doc: "Get printTask",
+        name: get_tasks,
+        path: "/jobs/{{RID}}/tasks/{{id}}",
+        params: print_task_id
+    );
+}
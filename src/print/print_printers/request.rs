@@ -0,0 +1,71 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+use crate::print::*;
+
+api_client!(
+    PrintPrintersApiClient,
+    PrintPrintersIdApiClient,
+    ResourceIdentity::PrintPrinters
+);
+
+impl PrintPrintersApiClient {
+    get!(
+        doc: "List printers",
+        name: list_printers,
+        path: "/printers"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_printers_count,
+        path: "/printers/$count"
+    );
+    post!(
+        doc: "Invoke action create",
+        name: create,
+        path: "/printers/create",
+        body: true
+    );
+}
+
+impl PrintPrintersIdApiClient {
+    api_client_link!(jobs, PrintJobsApiClient);
+    api_client_link_id!(job, PrintJobsIdApiClient);
+
+    delete!(
+        doc: "Delete printer",
+        name: delete_printers,
+        path: "/printers/{{RID}}"
+    );
+    get!(
+        doc: "Get printer",
+        name: get_printers,
+        path: "/printers/{{RID}}"
+    );
+    patch!(
+        doc: "Update printer",
+        name: update_printers,
+        path: "/printers/{{RID}}",
+        body: true
+    );
+    get!(
+        doc: "List printConnectors for printer",
+        name: list_connectors,
+        path: "/printers/{{RID}}/connectors"
+    );
+    post!(
+        doc: "Invoke action restoreFactoryDefaults",
+        name: restore_factory_defaults,
+        path: "/printers/{{RID}}/restoreFactoryDefaults"
+    );
+    get!(
+        doc: "List printerShares for printer",
+        name: list_shares,
+        path: "/printers/{{RID}}/shares"
+    );
+    get!(
+        doc: "List taskTriggers",
+        name: list_task_triggers,
+        path: "/printers/{{RID}}/taskTriggers"
+    );
+}
@@ -0,0 +1,57 @@
+use crate::api_default_imports::*;
+use crate::print::{CreatePrintUploadSession, PrintJob, PrintSharesIdApiClient};
+use graph_http::traits::AsyncIterator;
+
+impl PrintSharesIdApiClient {
+    /// Print a document on the printer share: create a print job with the
+    /// configuration, upload the content of the reader to the document of the job,
+    /// and start the job. Returns the job as it was when it was started, which can
+    /// be polled with `job(id).get_jobs()` to follow its status.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let file = tokio::fs::File::open("./report.pdf").await?;
+    /// let size = file.metadata().await?.len() as i64;
+    ///
+    /// let job = client
+    ///     .print()
+    ///     .share(SHARE_ID)
+    ///     .print_document(
+    ///         &PrintJob::new(PrintJobConfiguration::new().copies(2)),
+    ///         &CreatePrintUploadSession::new("report.pdf", "application/pdf", size),
+    ///         file,
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn print_document<R: tokio::io::AsyncReadExt + Send + Unpin>(
+        &self,
+        job: &PrintJob,
+        upload: &CreatePrintUploadSession,
+        reader: R,
+    ) -> GraphResult<PrintJob> {
+        let response = self.jobs().create_jobs(job).send().await?;
+        let job: PrintJob = Paging::success_response(response).await?.json().await?;
+        let job_id = job
+            .id
+            .clone()
+            .ok_or_else(|| GraphFailure::invalid("print job id"))?;
+        let document_id = job
+            .documents
+            .first()
+            .map(|document| document.id.clone())
+            .ok_or_else(|| GraphFailure::invalid("print job document id"))?;
+
+        let job_client = self.job(&job_id);
+        let mut upload_session = job_client
+            .upload_document(&document_id, upload, reader)
+            .await?;
+        while let Some(result) = upload_session.next().await {
+            Paging::success_response(result?).await?;
+        }
+
+        let response = job_client.start().send().await?;
+        let response = Paging::success_response(response).await?;
+        let status = response.json().await.ok();
+        Ok(PrintJob { status, ..job })
+    }
+}
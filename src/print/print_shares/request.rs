@@ -0,0 +1,90 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+use crate::print::*;
+
+api_client!(
+    PrintSharesApiClient,
+    PrintSharesIdApiClient,
+    ResourceIdentity::PrintShares
+);
+
+impl PrintSharesApiClient {
+    post!(
+        doc: "Create printerShare",
+        name: create_shares,
+        path: "/shares",
+        body: true
+    );
+    get!(
+        doc: "List shares",
+        name: list_shares,
+        path: "/shares"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_shares_count,
+        path: "/shares/$count"
+    );
+}
+
+impl PrintSharesIdApiClient {
+    api_client_link!(jobs, PrintJobsApiClient);
+    api_client_link_id!(job, PrintJobsIdApiClient);
+
+    delete!(
+        doc: "Delete printerShare",
+        name: delete_shares,
+        path: "/shares/{{RID}}"
+    );
+    get!(
+        doc: "Get printerShare",
+        name: get_shares,
+        path: "/shares/{{RID}}"
+    );
+    patch!(
+        doc: "Update printerShare",
+        name: update_shares,
+        path: "/shares/{{RID}}",
+        body: true
+    );
+    get!(
+        doc: "List allowedGroups for printerShare",
+        name: list_allowed_groups,
+        path: "/shares/{{RID}}/allowedGroups"
+    );
+    post!(
+        doc: "Add allowedGroup to printerShare",
+        name: create_ref_allowed_groups,
+        path: "/shares/{{RID}}/allowedGroups/$ref",
+        body: true
+    );
+    delete!(
+        doc: "Remove allowedGroup from printerShare",
+        name: delete_ref_allowed_groups,
+        path: "/shares/{{RID}}/allowedGroups/{{id}}/$ref",
+        params: group_id
+    );
+    get!(
+        doc: "List allowedUsers for printerShare",
+        name: list_allowed_users,
+        path: "/shares/{{RID}}/allowedUsers"
+    );
+    post!(
+        doc: "Add allowedUser to printerShare",
+        name: create_ref_allowed_users,
+        path: "/shares/{{RID}}/allowedUsers/$ref",
+        body: true
+    );
+    delete!(
+        doc: "Remove allowedUser from printerShare",
+        name: delete_ref_allowed_users,
+        path: "/shares/{{RID}}/allowedUsers/{{id}}/$ref",
+        params: user_id
+    );
+    get!(
+        doc: "Get printer from print",
+        name: get_printer,
+        path: "/shares/{{RID}}/printer"
+    );
+}
@@ -0,0 +1,53 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+use crate::print::*;
+
+api_client!(PrintApiClient, ResourceIdentity::Print);
+
+impl PrintApiClient {
+    api_client_link!(printers, PrintPrintersApiClient);
+    api_client_link_id!(printer, PrintPrintersIdApiClient);
+    api_client_link!(shares, PrintSharesApiClient);
+    api_client_link_id!(share, PrintSharesIdApiClient);
+
+    get!(
+        doc: "Get print",
+        name: get_print,
+        path: "/print"
+    );
+    patch!(
+        doc: "Update print",
+        name: update_print,
+        path: "/print",
+        body: true
+    );
+    get!(
+        doc: "List printServices",
+        name: list_services,
+        path: "/print/services"
+    );
+    get!(
+        doc: "Get printService",
+        name: get_services,
+        path: "/print/services/{{id}}",
+        params: print_service_id
+    );
+    get!(
+        doc: "List printConnectors",
+        name: list_connectors,
+        path: "/print/connectors"
+    );
+    get!(
+        doc: "Get printConnector",
+        name: get_connectors,
+        path: "/print/connectors/{{id}}",
+        params: print_connector_id
+    );
+    delete!(
+        doc: "Delete printConnector",
+        name: delete_connectors,
+        path: "/print/connectors/{{id}}",
+        params: print_connector_id
+    );
+}
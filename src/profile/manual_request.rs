@@ -0,0 +1,46 @@
+use crate::api_default_imports::*;
+use crate::me::MeApiClient;
+use crate::organization::OrganizationIdApiClient;
+use crate::profile::ProfileApiClient;
+use crate::users::UsersIdApiClient;
+
+impl MeApiClient {
+    api_client_link!(profile, ProfileApiClient);
+}
+
+impl UsersIdApiClient {
+    api_client_link!(profile, ProfileApiClient);
+}
+
+impl OrganizationIdApiClient {
+    post!(
+        doc: "Create profileCardProperty",
+        name: create_profile_card_properties,
+        path: "/organization/{{RID}}/settings/profileCardProperties",
+        body: true
+    );
+    get!(
+        doc: "List profileCardProperties",
+        name: list_profile_card_properties,
+        path: "/organization/{{RID}}/settings/profileCardProperties"
+    );
+    delete!(
+        doc: "Delete profileCardProperty",
+        name: delete_profile_card_properties,
+        path: "/organization/{{RID}}/settings/profileCardProperties/{{id}}",
+        params: directory_property_name
+    );
+    get!(
+        doc: "Get profileCardProperty",
+        name: get_profile_card_properties,
+        path: "/organization/{{RID}}/settings/profileCardProperties/{{id}}",
+        params: directory_property_name
+    );
+    patch!(
+        doc: "Update profileCardProperty",
+        name: update_profile_card_properties,
+        path: "/organization/{{RID}}/settings/profileCardProperties/{{id}}",
+        body: true,
+        params: directory_property_name
+    );
+}
@@ -0,0 +1,15 @@
+//! The profile of a user and the profile card properties of the organization.
+//!
+//! These APIs are only available on the beta endpoint. Enable the `beta` feature
+//! and use [GraphClient::beta](crate::GraphClient::beta) to call them.
+
+mod manual_request;
+mod profile_card;
+mod profile_item;
+mod request;
+
+#[allow(unused_imports)]
+pub use manual_request::*;
+pub use profile_card::*;
+pub use profile_item::*;
+pub use request::*;
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+/// A property shown on the profile cards of the organization. Use the name of
+/// a directory property such as `Alias`, or `customAttribute1` through
+/// `customAttribute15` with a display name for each language.
+///
+/// # Example
+/// ```rust,ignore
+/// let property = ProfileCardProperty::new("customAttribute1")
+///     .localization("en-US", "Cost center")
+///     .localization("de", "Kostenstelle");
+///
+/// let response = client
+///     .beta()
+///     .organization(TENANT_ID)
+///     .create_profile_card_properties(&property)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCardProperty {
+    #[serde(rename = "directoryPropertyName")]
+    pub directory_property_name: String,
+    #[serde(default)]
+    pub annotations: Vec<ProfileCardAnnotation>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCardAnnotation {
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(default)]
+    pub localizations: Vec<DisplayNameLocalization>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DisplayNameLocalization {
+    #[serde(rename = "languageTag")]
+    pub language_tag: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+}
+
+impl ProfileCardProperty {
+    pub fn new(directory_property_name: impl ToString) -> ProfileCardProperty {
+        ProfileCardProperty {
+            directory_property_name: directory_property_name.to_string(),
+            annotations: Vec::new(),
+        }
+    }
+
+    /// The display name of the property. The first display name is used as the
+    /// default display name and for the language tag.
+    pub fn localization(
+        mut self,
+        language_tag: impl ToString,
+        display_name: impl ToString,
+    ) -> Self {
+        let localization = DisplayNameLocalization {
+            language_tag: language_tag.to_string(),
+            display_name: display_name.to_string(),
+        };
+        match self.annotations.first_mut() {
+            Some(annotation) => annotation.localizations.push(localization),
+            None => self.annotations.push(ProfileCardAnnotation {
+                display_name: localization.display_name.clone(),
+                localizations: vec![localization],
+            }),
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_profile_card_property() {
+        let property = ProfileCardProperty::new("customAttribute1")
+            .localization("en-US", "Cost center")
+            .localization("de", "Kostenstelle");
+        assert_eq!(
+            serde_json::json!({
+                "directoryPropertyName": "customAttribute1",
+                "annotations": [{
+                    "displayName": "Cost center",
+                    "localizations": [
+                        { "languageTag": "en-US", "displayName": "Cost center" },
+                        { "languageTag": "de", "displayName": "Kostenstelle" }
+                    ]
+                }]
+            }),
+            serde_json::to_value(&property).unwrap()
+        );
+    }
+}
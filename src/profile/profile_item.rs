@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+
+/// Who can see an item of a profile.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AllowedAudiences {
+    Me,
+    Family,
+    Contacts,
+    GroupMembers,
+    Organization,
+    FederatedOrganizations,
+    Everyone,
+    #[serde(other)]
+    UnknownFutureValue,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CompanyDetail {
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub department: Option<String>,
+    #[serde(rename = "officeLocation", skip_serializing_if = "Option::is_none")]
+    pub office_location: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PositionDetail {
+    #[serde(rename = "jobTitle", skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<CompanyDetail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The date the position started such as `2021-04-01`.
+    #[serde(rename = "startMonthYear", skip_serializing_if = "Option::is_none")]
+    pub start_month_year: Option<String>,
+    #[serde(rename = "endMonthYear", skip_serializing_if = "Option::is_none")]
+    pub end_month_year: Option<String>,
+}
+
+/// A workPosition of a profile. Use [WorkPosition::builder] for the body of
+/// `create_positions` and `update_positions`.
+///
+/// # Example
+/// ```rust,ignore
+/// let position = WorkPosition::builder("Senior Engineer", "Contoso")
+///     .department("Platform")
+///     .start_month_year("2021-04-01")
+///     .is_current(true)
+///     .build();
+///
+/// let response = client
+///     .beta()
+///     .me()
+///     .profile()
+///     .create_positions(&position)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WorkPosition {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    pub detail: PositionDetail,
+    #[serde(rename = "isCurrent", skip_serializing_if = "Option::is_none")]
+    pub is_current: Option<bool>,
+    #[serde(rename = "allowedAudiences", skip_serializing_if = "Option::is_none")]
+    pub allowed_audiences: Option<AllowedAudiences>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+}
+
+impl WorkPosition {
+    pub fn builder(job_title: impl ToString, company: impl ToString) -> WorkPositionBuilder {
+        WorkPositionBuilder {
+            position: WorkPosition {
+                detail: PositionDetail {
+                    job_title: Some(job_title.to_string()),
+                    company: Some(CompanyDetail {
+                        display_name: company.to_string(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WorkPositionBuilder {
+    position: WorkPosition,
+}
+
+impl WorkPositionBuilder {
+    pub fn department(mut self, department: impl ToString) -> Self {
+        if let Some(company) = self.position.detail.company.as_mut() {
+            company.department = Some(department.to_string());
+        }
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.position.detail.description = Some(description.to_string());
+        self
+    }
+
+    pub fn start_month_year(mut self, start_month_year: impl ToString) -> Self {
+        self.position.detail.start_month_year = Some(start_month_year.to_string());
+        self
+    }
+
+    pub fn end_month_year(mut self, end_month_year: impl ToString) -> Self {
+        self.position.detail.end_month_year = Some(end_month_year.to_string());
+        self
+    }
+
+    pub fn is_current(mut self, is_current: bool) -> Self {
+        self.position.is_current = Some(is_current);
+        self
+    }
+
+    pub fn allowed_audiences(mut self, allowed_audiences: AllowedAudiences) -> Self {
+        self.position.allowed_audiences = Some(allowed_audiences);
+        self
+    }
+
+    pub fn build(self) -> WorkPosition {
+        self.position
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SkillProficiencyLevel {
+    Elementary,
+    LimitedWorking,
+    GeneralProfessional,
+    AdvancedProfessional,
+    Expert,
+    #[serde(other)]
+    UnknownFutureValue,
+}
+
+/// A skillProficiency of a profile, the body of `create_skills` and `update_skills`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SkillProficiency {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proficiency: Option<SkillProficiencyLevel>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+    #[serde(rename = "allowedAudiences", skip_serializing_if = "Option::is_none")]
+    pub allowed_audiences: Option<AllowedAudiences>,
+}
+
+impl SkillProficiency {
+    pub fn new(
+        display_name: impl ToString,
+        proficiency: SkillProficiencyLevel,
+    ) -> SkillProficiency {
+        SkillProficiency {
+            display_name: display_name.to_string(),
+            proficiency: Some(proficiency),
+            ..Default::default()
+        }
+    }
+
+    pub fn category(mut self, category: impl ToString) -> Self {
+        self.categories.push(category.to_string());
+        self
+    }
+
+    pub fn allowed_audiences(mut self, allowed_audiences: AllowedAudiences) -> Self {
+        self.allowed_audiences = Some(allowed_audiences);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_work_position() {
+        let position = WorkPosition::builder("Senior Engineer", "Contoso")
+            .department("Platform")
+            .is_current(true)
+            .build();
+        assert_eq!(
+            serde_json::json!({
+                "detail": {
+                    "jobTitle": "Senior Engineer",
+                    "company": { "displayName": "Contoso", "department": "Platform" }
+                },
+                "isCurrent": true
+            }),
+            serde_json::to_value(&position).unwrap()
+        );
+    }
+}
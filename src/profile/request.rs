@@ -0,0 +1,129 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(ProfileApiClient, ResourceIdentity::Profile);
+
+impl ProfileApiClient {
+    delete!(
+        doc: "Delete profile",
+        name: delete_profile,
+        path: "/profile"
+    );
+    get!(
+        doc: "Get profile",
+        name: get_profile,
+        path: "/profile"
+    );
+    patch!(
+        doc: "Update the navigation property profile in me",
+        name: update_profile,
+        path: "/profile",
+        body: true
+    );
+    post!(
+        doc: "Create languageProficiency",
+        name: create_languages,
+        path: "/profile/languages",
+        body: true
+    );
+    get!(
+        doc: "List languages",
+        name: list_languages,
+        path: "/profile/languages"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_languages_count,
+        path: "/profile/languages/$count"
+    );
+    delete!(
+        doc: "Delete languageProficiency",
+        name: delete_languages,
+        path: "/profile/languages/{{id}}",
+        params: language_proficiency_id
+    );
+    get!(
+        doc: "Get languageProficiency",
+        name: get_languages,
+        path: "/profile/languages/{{id}}",
+        params: language_proficiency_id
+    );
+    patch!(
+        doc: "Update languageProficiency",
+        name: update_languages,
+        path: "/profile/languages/{{id}}",
+        body: true,
+        params: language_proficiency_id
+    );
+    post!(
+        doc: "Create workPosition",
+        name: create_positions,
+        path: "/profile/positions",
+        body: true
+    );
+    get!(
+        doc: "List positions",
+        name: list_positions,
+        path: "/profile/positions"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_positions_count,
+        path: "/profile/positions/$count"
+    );
+    delete!(
+        doc: "Delete workPosition",
+        name: delete_positions,
+        path: "/profile/positions/{{id}}",
+        params: work_position_id
+    );
+    get!(
+        doc: "Get workPosition",
+        name: get_positions,
+        path: "/profile/positions/{{id}}",
+        params: work_position_id
+    );
+    patch!(
+        doc: "Update workPosition",
+        name: update_positions,
+        path: "/profile/positions/{{id}}",
+        body: true,
+        params: work_position_id
+    );
+    post!(
+        doc: "Create skillProficiency",
+        name: create_skills,
+        path: "/profile/skills",
+        body: true
+    );
+    get!(
+        doc: "List skills",
+        name: list_skills,
+        path: "/profile/skills"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_skills_count,
+        path: "/profile/skills/$count"
+    );
+    delete!(
+        doc: "Delete skillProficiency",
+        name: delete_skills,
+        path: "/profile/skills/{{id}}",
+        params: skill_proficiency_id
+    );
+    get!(
+        doc: "Get skillProficiency",
+        name: get_skills,
+        path: "/profile/skills/{{id}}",
+        params: skill_proficiency_id
+    );
+    patch!(
+        doc: "Update skillProficiency",
+        name: update_skills,
+        path: "/profile/skills/{{id}}",
+        body: true,
+        params: skill_proficiency_id
+    );
+}
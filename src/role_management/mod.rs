@@ -0,0 +1,5 @@
+mod request;
+mod role_management_directory;
+
+pub use request::*;
+pub use role_management_directory::*;
@@ -0,0 +1,63 @@
+use crate::api_default_imports::*;
+use crate::role_management::{
+    RoleManagementDirectoryApiClient, UnifiedRoleAssignment, UnifiedRoleDefinition,
+};
+
+impl RoleManagementDirectoryApiClient {
+    /// Get the role definition with the display name, such as `User Administrator`.
+    pub async fn role_definition_by_name(
+        &self,
+        display_name: &str,
+    ) -> GraphResult<UnifiedRoleDefinition> {
+        let filter = format!("displayName eq '{}'", display_name.replace('\'', "''"));
+        let response = self
+            .list_role_definitions()
+            .filter(&[&filter])
+            .send()
+            .await?;
+        let body: serde_json::Value = Paging::success_response(response).await?.json().await?;
+
+        let definitions: Vec<UnifiedRoleDefinition> =
+            serde_json::from_value(body["value"].clone())?;
+        definitions
+            .into_iter()
+            .next()
+            .ok_or_else(|| GraphFailure::not_found(&format!("role definition {display_name}")))
+    }
+
+    /// Assign the directory role with the display name to the user for the whole
+    /// tenant, resolving the name to the template id of the role.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let assignment = client
+    ///     .role_management()
+    ///     .directory()
+    ///     .assign_role(USER_ID, "Helpdesk Administrator")
+    ///     .await?;
+    ///
+    /// // Remove the assignment.
+    /// client
+    ///     .role_management()
+    ///     .directory()
+    ///     .delete_role_assignments(assignment.id.unwrap_or_default())
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub async fn assign_role(
+        &self,
+        user_id: &str,
+        role_name: &str,
+    ) -> GraphResult<UnifiedRoleAssignment> {
+        let definition = self.role_definition_by_name(role_name).await?;
+        let role_definition_id = definition
+            .assignable_id()
+            .ok_or_else(|| GraphFailure::invalid("role definition id"))?;
+
+        let response = self
+            .create_role_assignments(&UnifiedRoleAssignment::new(user_id, role_definition_id))
+            .send()
+            .await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+}
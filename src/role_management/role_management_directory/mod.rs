@@ -0,0 +1,8 @@
+mod manual_request;
+mod request;
+mod unified_role;
+
+#[allow(unused_imports)]
+pub use manual_request::*;
+pub use request::*;
+pub use unified_role::*;
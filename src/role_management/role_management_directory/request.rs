@@ -0,0 +1,97 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    RoleManagementDirectoryApiClient,
+    ResourceIdentity::RoleManagementDirectory
+);
+
+impl RoleManagementDirectoryApiClient {
+    get!(
+        doc: "Get directory from roleManagement",
+        name: get_directory,
+        path: "/directory"
+    );
+    patch!(
+        doc: "Update the navigation property directory in roleManagement",
+        name: update_directory,
+        path: "/directory",
+        body: true
+    );
+    post!(
+        doc: "Create unifiedRoleAssignment",
+        name: create_role_assignments,
+        path: "/directory/roleAssignments",
+        body: true
+    );
+    get!(
+        doc: "List unifiedRoleAssignments",
+        name: list_role_assignments,
+        path: "/directory/roleAssignments"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_role_assignments_count,
+        path: "/directory/roleAssignments/$count"
+    );
+    delete!(
+        doc: "Delete unifiedRoleAssignment",
+        name: delete_role_assignments,
+        path: "/directory/roleAssignments/{{id}}",
+        params: unified_role_assignment_id
+    );
+    get!(
+        doc: "Get unifiedRoleAssignment",
+        name: get_role_assignments,
+        path: "/directory/roleAssignments/{{id}}",
+        params: unified_role_assignment_id
+    );
+    get!(
+        doc: "Get principal from roleManagement",
+        name: get_role_assignments_principal,
+        path: "/directory/roleAssignments/{{id}}/principal",
+        params: unified_role_assignment_id
+    );
+    get!(
+        doc: "Get roleDefinition from roleManagement",
+        name: get_role_assignments_role_definition,
+        path: "/directory/roleAssignments/{{id}}/roleDefinition",
+        params: unified_role_assignment_id
+    );
+    post!(
+        doc: "Create roleDefinitions",
+        name: create_role_definitions,
+        path: "/directory/roleDefinitions",
+        body: true
+    );
+    get!(
+        doc: "List roleDefinitions",
+        name: list_role_definitions,
+        path: "/directory/roleDefinitions"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_role_definitions_count,
+        path: "/directory/roleDefinitions/$count"
+    );
+    delete!(
+        doc: "Delete unifiedRoleDefinition",
+        name: delete_role_definitions,
+        path: "/directory/roleDefinitions/{{id}}",
+        params: unified_role_definition_id
+    );
+    get!(
+        doc: "Get unifiedRoleDefinition",
+        name: get_role_definitions,
+        path: "/directory/roleDefinitions/{{id}}",
+        params: unified_role_definition_id
+    );
+    patch!(
+        doc: "Update unifiedRoleDefinition",
+        name: update_role_definitions,
+        path: "/directory/roleDefinitions/{{id}}",
+        body: true,
+        params: unified_role_definition_id
+    );
+}
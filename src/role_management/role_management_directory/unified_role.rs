@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnifiedRolePermission {
+    /// Such as `microsoft.directory/applications/credentials/update`.
+    pub allowed_resource_actions: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+}
+
+/// A built-in or custom directory role, and the body used to create or update
+/// a custom role.
+///
+/// # Example
+/// ```rust,ignore
+/// let definition = UnifiedRoleDefinition::new("Application Support Administrator")
+///     .description("Update the credentials of applications")
+///     .allowed_resource_action("microsoft.directory/applications/credentials/update");
+///
+/// let response = client
+///     .role_management()
+///     .directory()
+///     .create_role_definitions(&definition)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnifiedRoleDefinition {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    pub display_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub is_enabled: bool,
+    #[serde(skip_serializing)]
+    pub is_built_in: Option<bool>,
+    /// The id used to assign the role, which is the same as the id of built-in roles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+    #[serde(default)]
+    pub role_permissions: Vec<UnifiedRolePermission>,
+}
+
+impl UnifiedRoleDefinition {
+    pub fn new(display_name: impl ToString) -> UnifiedRoleDefinition {
+        UnifiedRoleDefinition {
+            display_name: display_name.to_string(),
+            is_enabled: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn allowed_resource_action(mut self, action: impl ToString) -> Self {
+        match self.role_permissions.first_mut() {
+            Some(permission) => permission.allowed_resource_actions.push(action.to_string()),
+            None => self.role_permissions.push(UnifiedRolePermission {
+                allowed_resource_actions: vec![action.to_string()],
+                condition: None,
+            }),
+        }
+        self
+    }
+
+    /// The id to use as the role definition id of a role assignment.
+    pub fn assignable_id(&self) -> Option<&str> {
+        self.template_id.as_deref().or(self.id.as_deref())
+    }
+}
+
+/// The assignment of a directory role to a principal, and the body used to
+/// create one.
+///
+/// # Example
+/// ```rust,ignore
+/// let assignment = UnifiedRoleAssignment::new(USER_ID, ROLE_TEMPLATE_ID)
+///     .administrative_unit(ADMINISTRATIVE_UNIT_ID);
+///
+/// let response = client
+///     .role_management()
+///     .directory()
+///     .create_role_assignments(&assignment)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnifiedRoleAssignment {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    /// The id of the user, group or service principal the role is assigned to.
+    pub principal_id: String,
+    pub role_definition_id: String,
+    /// `/` for the whole tenant, or such as `/administrativeUnits/{id}`.
+    pub directory_scope_id: String,
+}
+
+impl UnifiedRoleAssignment {
+    /// Assign the role to the principal for the whole tenant.
+    pub fn new(
+        principal_id: impl ToString,
+        role_definition_id: impl ToString,
+    ) -> UnifiedRoleAssignment {
+        UnifiedRoleAssignment {
+            id: None,
+            principal_id: principal_id.to_string(),
+            role_definition_id: role_definition_id.to_string(),
+            directory_scope_id: "/".into(),
+        }
+    }
+
+    pub fn directory_scope_id(mut self, directory_scope_id: impl ToString) -> Self {
+        self.directory_scope_id = directory_scope_id.to_string();
+        self
+    }
+
+    /// Limit the role to the members of the administrative unit.
+    pub fn administrative_unit(self, administrative_unit_id: &str) -> Self {
+        self.directory_scope_id(format!("/administrativeUnits/{administrative_unit_id}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_role_assignment() {
+        assert_eq!(
+            serde_json::json!({
+                "principalId": "user-id",
+                "roleDefinitionId": "role-id",
+                "directoryScopeId": "/administrativeUnits/unit-id"
+            }),
+            serde_json::to_value(
+                UnifiedRoleAssignment::new("user-id", "role-id").administrative_unit("unit-id")
+            )
+            .unwrap()
+        );
+
+        let definition: UnifiedRoleDefinition = serde_json::from_value(serde_json::json!({
+            "id": "62e90394-69f5-4237-9190-012177145e10",
+            "displayName": "Global Administrator",
+            "isBuiltIn": true,
+            "isEnabled": true,
+            "templateId": "62e90394-69f5-4237-9190-012177145e10",
+            "rolePermissions": [{ "allowedResourceActions": ["microsoft.directory/users/delete"] }]
+        }))
+        .unwrap();
+        assert_eq!(
+            Some("62e90394-69f5-4237-9190-012177145e10"),
+            definition.assignable_id()
+        );
+
+        let custom = UnifiedRoleDefinition::new("Custom")
+            .allowed_resource_action("a")
+            .allowed_resource_action("b");
+        assert_eq!(
+            vec!["a".to_string(), "b".to_string()],
+            custom.role_permissions[0].allowed_resource_actions
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::OffsetDateTime;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertSeverity {
+    Informational,
+    Low,
+    Medium,
+    High,
+    #[serde(other)]
+    UnknownFutureValue,
+}
+
+impl AsRef<str> for AlertSeverity {
+    fn as_ref(&self) -> &str {
+        match self {
+            AlertSeverity::Informational => "informational",
+            AlertSeverity::Low => "low",
+            AlertSeverity::Medium => "medium",
+            AlertSeverity::High => "high",
+            AlertSeverity::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertStatus {
+    New,
+    InProgress,
+    Resolved,
+    #[serde(other)]
+    UnknownFutureValue,
+}
+
+impl AsRef<str> for AlertStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            AlertStatus::New => "new",
+            AlertStatus::InProgress => "inProgress",
+            AlertStatus::Resolved => "resolved",
+            AlertStatus::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// The classification of an alert or incident when it is resolved.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertClassification {
+    Unknown,
+    FalsePositive,
+    TruePositive,
+    InformationalExpectedActivity,
+    #[serde(other)]
+    UnknownFutureValue,
+}
+
+/// The determination of an alert or incident, giving the reason for the classification.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertDetermination {
+    Unknown,
+    Apt,
+    Malware,
+    SecurityPersonnel,
+    SecurityTesting,
+    UnwantedSoftware,
+    Other,
+    MultiStagedAttack,
+    CompromisedAccount,
+    Phishing,
+    MaliciousUserActivity,
+    NotMalicious,
+    NotEnoughDataToValidate,
+    ConfirmedActivity,
+    LineOfBusinessApplication,
+    #[serde(other)]
+    UnknownFutureValue,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AlertComment {
+    pub comment: String,
+    #[serde(rename = "createdByDisplayName", skip_serializing)]
+    pub created_by_display_name: Option<String>,
+    #[serde(
+        rename = "createdDateTime",
+        skip_serializing,
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub created_date_time: Option<OffsetDateTime>,
+}
+
+impl AlertComment {
+    pub fn new(comment: impl ToString) -> AlertComment {
+        AlertComment {
+            comment: comment.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// An alert as returned by `client.security().alerts_v2()`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Alert {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<AlertSeverity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AlertStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<AlertClassification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub determination: Option<AlertDetermination>,
+    #[serde(rename = "assignedTo", skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<String>,
+    #[serde(rename = "incidentId", skip_serializing_if = "Option::is_none")]
+    pub incident_id: Option<String>,
+    #[serde(rename = "serviceSource", skip_serializing_if = "Option::is_none")]
+    pub service_source: Option<String>,
+    #[serde(
+        rename = "createdDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub created_date_time: Option<OffsetDateTime>,
+    #[serde(rename = "alertWebUrl", skip_serializing_if = "Option::is_none")]
+    pub alert_web_url: Option<String>,
+    #[serde(default)]
+    pub comments: Vec<AlertComment>,
+}
+
+/// The body used to update the triage properties of an alert.
+///
+/// # Example
+/// ```rust,ignore
+/// let update = AlertUpdate::builder()
+///     .status(AlertStatus::Resolved)
+///     .classification(AlertClassification::FalsePositive)
+///     .determination(AlertDetermination::NotMalicious)
+///     .build();
+///
+/// let response = client
+///     .security()
+///     .alert_v2(ALERT_ID)
+///     .update_alerts_v2(&update)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AlertUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AlertStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<AlertClassification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub determination: Option<AlertDetermination>,
+    #[serde(rename = "assignedTo", skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<String>,
+}
+
+impl AlertUpdate {
+    pub fn builder() -> AlertUpdateBuilder {
+        AlertUpdateBuilder::default()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct AlertUpdateBuilder {
+    update: AlertUpdate,
+}
+
+impl AlertUpdateBuilder {
+    pub fn status(mut self, status: AlertStatus) -> Self {
+        self.update.status = Some(status);
+        self
+    }
+
+    pub fn classification(mut self, classification: AlertClassification) -> Self {
+        self.update.classification = Some(classification);
+        self
+    }
+
+    pub fn determination(mut self, determination: AlertDetermination) -> Self {
+        self.update.determination = Some(determination);
+        self
+    }
+
+    /// The user principal name of the owner of the alert.
+    pub fn assigned_to(mut self, assigned_to: impl ToString) -> Self {
+        self.update.assigned_to = Some(assigned_to.to_string());
+        self
+    }
+
+    pub fn build(self) -> AlertUpdate {
+        self.update
+    }
+}
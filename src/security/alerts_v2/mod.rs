@@ -0,0 +1,5 @@
+mod alert;
+mod request;
+
+pub use alert::*;
+pub use request::*;
@@ -0,0 +1,53 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    SecurityAlertsV2ApiClient,
+    SecurityAlertsV2IdApiClient,
+    ResourceIdentity::SecurityAlertsV2
+);
+
+impl SecurityAlertsV2ApiClient {
+    post!(
+        doc: "Create new navigation property to alerts_v2 for security",
+        name: create_alerts_v2,
+        path: "/alerts_v2",
+        body: true
+    );
+    get!(
+        doc: "List alerts_v2",
+        name: list_alerts_v2,
+        path: "/alerts_v2"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_alerts_v2_count,
+        path: "/alerts_v2/$count"
+    );
+}
+
+impl SecurityAlertsV2IdApiClient {
+    delete!(
+        doc: "Delete navigation property alerts_v2 for security",
+        name: delete_alerts_v2,
+        path: "/alerts_v2/{{RID}}"
+    );
+    get!(
+        doc: "Get alert",
+        name: get_alerts_v2,
+        path: "/alerts_v2/{{RID}}"
+    );
+    patch!(
+        doc: "Update alert",
+        name: update_alerts_v2,
+        path: "/alerts_v2/{{RID}}",
+        body: true
+    );
+    post!(
+        doc: "Create comment for alert",
+        name: create_comments,
+        path: "/alerts_v2/{{RID}}/comments",
+        body: true
+    );
+}
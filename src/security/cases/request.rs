@@ -0,0 +1,28 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+use crate::security::*;
+
+api_client!(SecurityCasesApiClient, ResourceIdentity::SecurityCases);
+
+impl SecurityCasesApiClient {
+    api_client_link!(ediscovery_cases, EdiscoveryCasesApiClient);
+    api_client_link_id!(ediscovery_case, EdiscoveryCasesIdApiClient);
+
+    delete!(
+        doc: "Delete navigation property cases for security",
+        name: delete_cases,
+        path: "/cases"
+    );
+    get!(
+        doc: "Get cases from security",
+        name: get_cases,
+        path: "/cases"
+    );
+    patch!(
+        doc: "Update the navigation property cases in security",
+        name: update_cases,
+        path: "/cases",
+        body: true
+    );
+}
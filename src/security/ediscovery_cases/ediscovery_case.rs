@@ -0,0 +1,237 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::datetime::OffsetDateTime;
+
+/// The body used to create or update an ediscoveryCase and the case returned in the response.
+///
+/// # Example
+/// ```rust,ignore
+/// let case = EdiscoveryCase::builder("CONTOSO LITIGATION-005")
+///     .description("Project Bazooka")
+///     .external_id("324516")
+///     .build();
+///
+/// let response = client
+///     .security()
+///     .cases()
+///     .ediscovery_cases()
+///     .create_ediscovery_cases(&case)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EdiscoveryCase {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "externalId", skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing)]
+    pub status: Option<String>,
+    #[serde(
+        rename = "createdDateTime",
+        skip_serializing,
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub created_date_time: Option<OffsetDateTime>,
+    #[serde(
+        rename = "closedDateTime",
+        skip_serializing,
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub closed_date_time: Option<OffsetDateTime>,
+}
+
+impl EdiscoveryCase {
+    pub fn builder(display_name: impl ToString) -> EdiscoveryCaseBuilder {
+        EdiscoveryCaseBuilder {
+            case: EdiscoveryCase {
+                display_name: display_name.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct EdiscoveryCaseBuilder {
+    case: EdiscoveryCase,
+}
+
+impl EdiscoveryCaseBuilder {
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.case.description = Some(description.to_string());
+        self
+    }
+
+    /// The case number or other id of the case in an external system.
+    pub fn external_id(mut self, external_id: impl ToString) -> Self {
+        self.case.external_id = Some(external_id.to_string());
+        self
+    }
+
+    pub fn build(self) -> EdiscoveryCase {
+        self.case
+    }
+}
+
+/// The body used to create or update an ediscoverySearch.
+///
+/// # Example
+/// ```rust,ignore
+/// let search = EdiscoverySearch::builder("My search")
+///     .content_query("(Author=\"edisons\")")
+///     .data_source_scopes("allTenantMailboxes")
+///     .build();
+///
+/// let response = client
+///     .security()
+///     .cases()
+///     .ediscovery_case(CASE_ID)
+///     .searches()
+///     .create_searches(&search)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EdiscoverySearch {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The KQL query of the search.
+    #[serde(rename = "contentQuery", skip_serializing_if = "Option::is_none")]
+    pub content_query: Option<String>,
+    #[serde(rename = "dataSourceScopes", skip_serializing_if = "Option::is_none")]
+    pub data_source_scopes: Option<String>,
+}
+
+impl EdiscoverySearch {
+    pub fn builder(display_name: impl ToString) -> EdiscoverySearchBuilder {
+        EdiscoverySearchBuilder {
+            search: EdiscoverySearch {
+                display_name: display_name.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct EdiscoverySearchBuilder {
+    search: EdiscoverySearch,
+}
+
+impl EdiscoverySearchBuilder {
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.search.description = Some(description.to_string());
+        self
+    }
+
+    pub fn content_query(mut self, content_query: impl ToString) -> Self {
+        self.search.content_query = Some(content_query.to_string());
+        self
+    }
+
+    /// Search all tenant sources, such as `allTenantMailboxes` or `allTenantSites`,
+    /// in addition to the custodian sources. Multiple scopes are comma separated.
+    pub fn data_source_scopes(mut self, data_source_scopes: impl ToString) -> Self {
+        self.search.data_source_scopes = Some(data_source_scopes.to_string());
+        self
+    }
+
+    pub fn build(self) -> EdiscoverySearch {
+        self.search
+    }
+}
+
+/// A long running caseOperation such as applying a hold, estimating search
+/// statistics or adding to a review set.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CaseOperation {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// One of `notStarted`, `submissionFailed`, `running`, `succeeded`,
+    /// `partiallySucceeded` or `failed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(rename = "percentProgress", skip_serializing_if = "Option::is_none")]
+    pub percent_progress: Option<i32>,
+    #[serde(
+        rename = "createdDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub created_date_time: Option<OffsetDateTime>,
+    #[serde(
+        rename = "completedDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub completed_date_time: Option<OffsetDateTime>,
+    #[serde(rename = "resultInfo", skip_serializing_if = "Option::is_none")]
+    pub result_info: Option<serde_json::Value>,
+}
+
+impl CaseOperation {
+    /// Operations that have succeeded or partially succeeded.
+    pub fn is_succeeded(&self) -> bool {
+        matches!(
+            self.status.as_deref(),
+            Some("succeeded") | Some("partiallySucceeded")
+        )
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(
+            self.status.as_deref(),
+            Some("failed") | Some("submissionFailed")
+        )
+    }
+
+    /// The id of the operation in the Location header of an accepted response such as
+    /// `/security/cases/ediscoveryCases('{case-id}')/operations('{operation-id}')`.
+    pub fn id_from_location(location: &Url) -> Option<String> {
+        let segment = location.path_segments()?.next_back()?;
+        let id = match (segment.find("('"), segment.strip_suffix("')")) {
+            (Some(start), Some(segment)) => &segment[start + 2..],
+            _ => segment,
+        };
+        Some(id.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn case_operation_status_and_location() {
+        let operation: CaseOperation = serde_json::from_value(serde_json::json!({
+            "id": "2ad2da7c7dbd44b3b0fe8c1a4a8f8a34",
+            "action": "estimateStatistics",
+            "status": "partiallySucceeded",
+            "percentProgress": 100
+        }))
+        .unwrap();
+        assert!(operation.is_succeeded());
+        assert!(!operation.is_failed());
+
+        let location = Url::parse("https://graph.microsoft.com/v1.0/security/cases/ediscoveryCases('b0073e4e')/operations('2ad2da7c')").unwrap();
+        assert_eq!(
+            Some("2ad2da7c".to_string()),
+            CaseOperation::id_from_location(&location)
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use crate::api_default_imports::*;
+use crate::security::{CaseOperation, EdiscoveryCasesIdApiClient};
+
+impl EdiscoveryCasesIdApiClient {
+    /// Poll the case operation every `poll_interval` until it has succeeded and return
+    /// the operation. Returns an error if the operation failed or has not completed
+    /// before `timeout`.
+    ///
+    /// Actions such as `apply_hold`, `estimate_statistics` and `add_to_review_set` return
+    /// 202 Accepted with the operation in the Location header.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let case = client.security().cases().ediscovery_case(CASE_ID);
+    ///
+    /// let response = case.search(SEARCH_ID).estimate_statistics().send_typed().await?;
+    /// if let TypedResponse::Accepted(accepted) = response {
+    ///     let operation_id = accepted
+    ///         .location()
+    ///         .and_then(CaseOperation::id_from_location)
+    ///         .unwrap();
+    ///     let operation = case
+    ///         .wait_for_operation(&operation_id, Duration::from_secs(5), Duration::from_secs(600))
+    ///         .await?;
+    ///     println!("{:#?}", operation.result_info);
+    /// }
+    /// ```
+    pub async fn wait_for_operation(
+        &self,
+        operation_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<CaseOperation> {
+        let location = self.get_operations(operation_id).url();
+        let body = AcceptedResponse::from_location(self.client.clone(), location)
+            .wait_for_completion(poll_interval, timeout)
+            .await?;
+        Ok(serde_json::from_value(body)?)
+    }
+}
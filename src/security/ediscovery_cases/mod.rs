@@ -0,0 +1,8 @@
+mod ediscovery_case;
+mod manual_request;
+mod request;
+
+pub use ediscovery_case::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
+pub use request::*;
@@ -0,0 +1,154 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+use crate::security::*;
+
+api_client!(
+    EdiscoveryCasesApiClient,
+    EdiscoveryCasesIdApiClient,
+    ResourceIdentity::EdiscoveryCases
+);
+
+impl EdiscoveryCasesApiClient {
+    post!(
+        doc: "Create ediscoveryCase",
+        name: create_ediscovery_cases,
+        path: "/ediscoveryCases",
+        body: true
+    );
+    get!(
+        doc: "List ediscoveryCases",
+        name: list_ediscovery_cases,
+        path: "/ediscoveryCases"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_ediscovery_cases_count,
+        path: "/ediscoveryCases/$count"
+    );
+}
+
+impl EdiscoveryCasesIdApiClient {
+    api_client_link!(custodians, EdiscoveryCasesCustodiansApiClient);
+    api_client_link_id!(custodian, EdiscoveryCasesCustodiansIdApiClient);
+    api_client_link!(searches, EdiscoveryCasesSearchesApiClient);
+    api_client_link_id!(search, EdiscoveryCasesSearchesIdApiClient);
+    api_client_link!(review_sets, EdiscoveryCasesReviewSetsApiClient);
+    api_client_link_id!(review_set, EdiscoveryCasesReviewSetsIdApiClient);
+
+    delete!(
+        doc: "Delete ediscoveryCase",
+        name: delete_ediscovery_cases,
+        path: "/ediscoveryCases/{{RID}}"
+    );
+    get!(
+        doc: "Get ediscoveryCase",
+        name: get_ediscovery_cases,
+        path: "/ediscoveryCases/{{RID}}"
+    );
+    patch!(
+        doc: "Update ediscoveryCase",
+        name: update_ediscovery_cases,
+        path: "/ediscoveryCases/{{RID}}",
+        body: true
+    );
+    post!(
+        doc: "Invoke action close",
+        name: close,
+        path: "/ediscoveryCases/{{RID}}/microsoft.graph.security.close"
+    );
+    post!(
+        doc: "Invoke action reopen",
+        name: reopen,
+        path: "/ediscoveryCases/{{RID}}/microsoft.graph.security.reopen"
+    );
+    post!(
+        doc: "Create ediscoveryNoncustodialDataSource",
+        name: create_noncustodial_data_sources,
+        path: "/ediscoveryCases/{{RID}}/noncustodialDataSources",
+        body: true
+    );
+    get!(
+        doc: "List noncustodialDataSources",
+        name: list_noncustodial_data_sources,
+        path: "/ediscoveryCases/{{RID}}/noncustodialDataSources"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_noncustodial_data_sources_count,
+        path: "/ediscoveryCases/{{RID}}/noncustodialDataSources/$count"
+    );
+    get!(
+        doc: "Get noncustodialDataSources from security",
+        name: get_noncustodial_data_sources,
+        path: "/ediscoveryCases/{{RID}}/noncustodialDataSources/{{id}}",
+        params: ediscovery_noncustodial_data_source_id
+    );
+    get!(
+        doc: "List caseOperations",
+        name: list_operations,
+        path: "/ediscoveryCases/{{RID}}/operations"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_operations_count,
+        path: "/ediscoveryCases/{{RID}}/operations/$count"
+    );
+    get!(
+        doc: "Get caseOperation",
+        name: get_operations,
+        path: "/ediscoveryCases/{{RID}}/operations/{{id}}",
+        params: case_operation_id
+    );
+    get!(
+        doc: "Get ediscoveryCaseSettings",
+        name: get_settings,
+        path: "/ediscoveryCases/{{RID}}/settings"
+    );
+    patch!(
+        doc: "Update ediscoveryCaseSettings",
+        name: update_settings,
+        path: "/ediscoveryCases/{{RID}}/settings",
+        body: true
+    );
+    post!(
+        doc: "Invoke action resetToDefault",
+        name: reset_to_default,
+        path: "/ediscoveryCases/{{RID}}/settings/microsoft.graph.security.resetToDefault"
+    );
+    post!(
+        doc: "Create ediscoveryReviewTag",
+        name: create_tags,
+        path: "/ediscoveryCases/{{RID}}/tags",
+        body: true
+    );
+    get!(
+        doc: "List tags",
+        name: list_tags,
+        path: "/ediscoveryCases/{{RID}}/tags"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_tags_count,
+        path: "/ediscoveryCases/{{RID}}/tags/$count"
+    );
+    delete!(
+        doc: "Delete ediscoveryReviewTag",
+        name: delete_tags,
+        path: "/ediscoveryCases/{{RID}}/tags/{{id}}",
+        params: ediscovery_review_tag_id
+    );
+    get!(
+        doc: "Get ediscoveryReviewTag",
+        name: get_tags,
+        path: "/ediscoveryCases/{{RID}}/tags/{{id}}",
+        params: ediscovery_review_tag_id
+    );
+    patch!(
+        doc: "Update ediscoveryReviewTag",
+        name: update_tags,
+        path: "/ediscoveryCases/{{RID}}/tags/{{id}}",
+        body: true,
+        params: ediscovery_review_tag_id
+    );
+}
@@ -0,0 +1,148 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    EdiscoveryCasesCustodiansApiClient,
+    EdiscoveryCasesCustodiansIdApiClient,
+    ResourceIdentity::EdiscoveryCasesCustodians
+);
+
+impl EdiscoveryCasesCustodiansApiClient {
+    post!(
+        doc: "Create ediscoveryCustodian",
+        name: create_custodians,
+        path: "/custodians",
+        body: true
+    );
+    get!(
+        doc: "List ediscoveryCustodian objects",
+        name: list_custodians,
+        path: "/custodians"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_custodians_count,
+        path: "/custodians/$count"
+    );
+    post!(
+        doc: "Invoke action applyHold",
+        name: apply_hold,
+        path: "/custodians/microsoft.graph.security.applyHold",
+        body: true
+    );
+    post!(
+        doc: "Invoke action removeHold",
+        name: remove_hold,
+        path: "/custodians/microsoft.graph.security.removeHold",
+        body: true
+    );
+}
+
+impl EdiscoveryCasesCustodiansIdApiClient {
+    get!(
+        doc: "Get ediscoveryCustodian",
+        name: get_custodians,
+        path: "/custodians/{{RID}}"
+    );
+    patch!(
+        doc: "Update the navigation property custodians in security",
+        name: update_custodians,
+        path: "/custodians/{{RID}}",
+        body: true
+    );
+    post!(
+        doc: "Invoke action activate",
+        name: activate,
+        path: "/custodians/{{RID}}/microsoft.graph.security.activate"
+    );
+    post!(
+        doc: "Invoke action applyHold",
+        name: apply_hold,
+        path: "/custodians/{{RID}}/microsoft.graph.security.applyHold"
+    );
+    post!(
+        doc: "Invoke action release",
+        name: release,
+        path: "/custodians/{{RID}}/microsoft.graph.security.release"
+    );
+    post!(
+        doc: "Invoke action removeHold",
+        name: remove_hold,
+        path: "/custodians/{{RID}}/microsoft.graph.security.removeHold"
+    );
+    post!(
+        doc: "Invoke action updateIndex",
+        name: update_index,
+        path: "/custodians/{{RID}}/microsoft.graph.security.updateIndex"
+    );
+    post!(
+        doc: "Create custodian siteSource",
+        name: create_site_sources,
+        path: "/custodians/{{RID}}/siteSources",
+        body: true
+    );
+    get!(
+        doc: "List siteSources",
+        name: list_site_sources,
+        path: "/custodians/{{RID}}/siteSources"
+    );
+    get!(
+        doc: "Get siteSource",
+        name: get_site_sources,
+        path: "/custodians/{{RID}}/siteSources/{{id}}",
+        params: site_source_id
+    );
+    delete!(
+        doc: "Delete siteSource",
+        name: delete_site_sources,
+        path: "/custodians/{{RID}}/siteSources/{{id}}",
+        params: site_source_id
+    );
+    post!(
+        doc: "Create custodian unifiedGroupSource",
+        name: create_unified_group_sources,
+        path: "/custodians/{{RID}}/unifiedGroupSources",
+        body: true
+    );
+    get!(
+        doc: "List unifiedGroupSources",
+        name: list_unified_group_sources,
+        path: "/custodians/{{RID}}/unifiedGroupSources"
+    );
+    get!(
+        doc: "Get unifiedGroupSource",
+        name: get_unified_group_sources,
+        path: "/custodians/{{RID}}/unifiedGroupSources/{{id}}",
+        params: unified_group_source_id
+    );
+    delete!(
+        doc: "Delete unifiedGroupSource",
+        name: delete_unified_group_sources,
+        path: "/custodians/{{RID}}/unifiedGroupSources/{{id}}",
+        params: unified_group_source_id
+    );
+    post!(
+        doc: "Create custodian userSource",
+        name: create_user_sources,
+        path: "/custodians/{{RID}}/userSources",
+        body: true
+    );
+    get!(
+        doc: "List userSources",
+        name: list_user_sources,
+        path: "/custodians/{{RID}}/userSources"
+    );
+    get!(
+        doc: "Get userSource",
+        name: get_user_sources,
+        path: "/custodians/{{RID}}/userSources/{{id}}",
+        params: user_source_id
+    );
+    delete!(
+        doc: "Delete userSource",
+        name: delete_user_sources,
+        path: "/custodians/{{RID}}/userSources/{{id}}",
+        params: user_source_id
+    );
+}
@@ -0,0 +1,108 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    EdiscoveryCasesReviewSetsApiClient,
+    EdiscoveryCasesReviewSetsIdApiClient,
+    ResourceIdentity::EdiscoveryCasesReviewSets
+);
+
+impl EdiscoveryCasesReviewSetsApiClient {
+    post!(
+        doc: "Create reviewSets",
+        name: create_review_sets,
+        path: "/reviewSets",
+        body: true
+    );
+    get!(
+        doc: "List reviewSets",
+        name: list_review_sets,
+        path: "/reviewSets"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_review_sets_count,
+        path: "/reviewSets/$count"
+    );
+}
+
+impl EdiscoveryCasesReviewSetsIdApiClient {
+    delete!(
+        doc: "Delete navigation property reviewSets for security",
+        name: delete_review_sets,
+        path: "/reviewSets/{{RID}}"
+    );
+    get!(
+        doc: "Get ediscoveryReviewSet",
+        name: get_review_sets,
+        path: "/reviewSets/{{RID}}"
+    );
+    patch!(
+        doc: "Update the navigation property reviewSets in security",
+        name: update_review_sets,
+        path: "/reviewSets/{{RID}}",
+        body: true
+    );
+    post!(
+        doc: "Invoke action addToReviewSet",
+        name: add_to_review_set,
+        path: "/reviewSets/{{RID}}/microsoft.graph.security.addToReviewSet",
+        body: true
+    );
+    post!(
+        doc: "Invoke action export",
+        name: export,
+        path: "/reviewSets/{{RID}}/microsoft.graph.security.export",
+        body: true
+    );
+    post!(
+        doc: "Create ediscoveryReviewSetQuery",
+        name: create_queries,
+        path: "/reviewSets/{{RID}}/queries",
+        body: true
+    );
+    get!(
+        doc: "List queries",
+        name: list_queries,
+        path: "/reviewSets/{{RID}}/queries"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_queries_count,
+        path: "/reviewSets/{{RID}}/queries/$count"
+    );
+    delete!(
+        doc: "Delete ediscoveryReviewSetQuery",
+        name: delete_queries,
+        path: "/reviewSets/{{RID}}/queries/{{id}}",
+        params: ediscovery_review_set_query_id
+    );
+    get!(
+        doc: "Get ediscoveryReviewSetQuery",
+        name: get_queries,
+        path: "/reviewSets/{{RID}}/queries/{{id}}",
+        params: ediscovery_review_set_query_id
+    );
+    patch!(
+        doc: "Update ediscoveryReviewSetQuery",
+        name: update_queries,
+        path: "/reviewSets/{{RID}}/queries/{{id}}",
+        body: true,
+        params: ediscovery_review_set_query_id
+    );
+    post!(
+        doc: "Invoke action applyTags",
+        name: apply_tags,
+        path: "/reviewSets/{{RID}}/queries/{{id}}/microsoft.graph.security.applyTags",
+        body: true,
+        params: ediscovery_review_set_query_id
+    );
+    post!(
+        doc: "Invoke action export",
+        name: export_queries,
+        path: "/reviewSets/{{RID}}/queries/{{id}}/microsoft.graph.security.export",
+        body: true,
+        params: ediscovery_review_set_query_id
+    );
+}
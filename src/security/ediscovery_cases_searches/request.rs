@@ -0,0 +1,96 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    EdiscoveryCasesSearchesApiClient,
+    EdiscoveryCasesSearchesIdApiClient,
+    ResourceIdentity::EdiscoveryCasesSearches
+);
+
+impl EdiscoveryCasesSearchesApiClient {
+    post!(
+        doc: "Create ediscoverySearch",
+        name: create_searches,
+        path: "/searches",
+        body: true
+    );
+    get!(
+        doc: "List searches",
+        name: list_searches,
+        path: "/searches"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_searches_count,
+        path: "/searches/$count"
+    );
+}
+
+impl EdiscoveryCasesSearchesIdApiClient {
+    delete!(
+        doc: "Delete ediscoverySearch",
+        name: delete_searches,
+        path: "/searches/{{RID}}"
+    );
+    get!(
+        doc: "Get ediscoverySearch",
+        name: get_searches,
+        path: "/searches/{{RID}}"
+    );
+    patch!(
+        doc: "Update ediscoverySearch",
+        name: update_searches,
+        path: "/searches/{{RID}}",
+        body: true
+    );
+    post!(
+        doc: "Add additional sources",
+        name: create_additional_sources,
+        path: "/searches/{{RID}}/additionalSources",
+        body: true
+    );
+    get!(
+        doc: "List additionalSources",
+        name: list_additional_sources,
+        path: "/searches/{{RID}}/additionalSources"
+    );
+    get!(
+        doc: "List custodianSources",
+        name: list_custodian_sources,
+        path: "/searches/{{RID}}/custodianSources"
+    );
+    post!(
+        doc: "Add custodian sources",
+        name: create_ref_custodian_sources,
+        path: "/searches/{{RID}}/custodianSources/$ref",
+        body: true
+    );
+    delete!(
+        doc: "Remove custodian sources",
+        name: delete_ref_custodian_sources,
+        path: "/searches/{{RID}}/custodianSources/{{id}}/$ref",
+        params: data_source_id
+    );
+    get!(
+        doc: "List lastEstimateStatisticsOperation",
+        name: get_last_estimate_statistics_operation,
+        path: "/searches/{{RID}}/lastEstimateStatisticsOperation"
+    );
+    post!(
+        doc: "Invoke action estimateStatistics",
+        name: estimate_statistics,
+        path: "/searches/{{RID}}/microsoft.graph.security.estimateStatistics"
+    );
+    post!(
+        doc: "Invoke action purgeData",
+        name: purge_data,
+        path: "/searches/{{RID}}/microsoft.graph.security.purgeData",
+        body: true
+    );
+    get!(
+        doc: "List noncustodialSources",
+        name: list_noncustodial_sources,
+        path: "/searches/{{RID}}/noncustodialSources"
+    );
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::OffsetDateTime;
+
+use crate::security::{
+    Alert, AlertClassification, AlertComment, AlertDetermination, AlertSeverity,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IncidentStatus {
+    Active,
+    Resolved,
+    InProgress,
+    Redirected,
+    AwaitingAction,
+    #[serde(other)]
+    UnknownFutureValue,
+}
+
+impl AsRef<str> for IncidentStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            IncidentStatus::Active => "active",
+            IncidentStatus::Resolved => "resolved",
+            IncidentStatus::InProgress => "inProgress",
+            IncidentStatus::Redirected => "redirected",
+            IncidentStatus::AwaitingAction => "awaitingAction",
+            IncidentStatus::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// An incident as returned by `client.security().incidents()`. The alerts
+/// are only included when requested with `$expand=alerts`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Incident {
+    pub id: String,
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<AlertSeverity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IncidentStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<AlertClassification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub determination: Option<AlertDetermination>,
+    #[serde(rename = "assignedTo", skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<String>,
+    #[serde(
+        rename = "createdDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub created_date_time: Option<OffsetDateTime>,
+    #[serde(
+        rename = "lastUpdateDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub last_update_date_time: Option<OffsetDateTime>,
+    #[serde(rename = "incidentWebUrl", skip_serializing_if = "Option::is_none")]
+    pub incident_web_url: Option<String>,
+    #[serde(rename = "customTags", default)]
+    pub custom_tags: Vec<String>,
+    #[serde(default)]
+    pub comments: Vec<AlertComment>,
+    #[serde(default)]
+    pub alerts: Vec<Alert>,
+}
+
+/// The body used to update the triage properties of an incident.
+///
+/// # Example
+/// ```rust,ignore
+/// let update = IncidentUpdate::builder()
+///     .status(IncidentStatus::Active)
+///     .assigned_to("analyst@contoso.com")
+///     .custom_tag("phishing-campaign")
+///     .build();
+///
+/// let response = client
+///     .security()
+///     .incident(INCIDENT_ID)
+///     .update_incidents(&update)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IncidentUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IncidentStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<AlertClassification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub determination: Option<AlertDetermination>,
+    #[serde(rename = "assignedTo", skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<String>,
+    /// Replaces the custom tags of the incident.
+    #[serde(rename = "customTags", skip_serializing_if = "Option::is_none")]
+    pub custom_tags: Option<Vec<String>>,
+}
+
+impl IncidentUpdate {
+    pub fn builder() -> IncidentUpdateBuilder {
+        IncidentUpdateBuilder::default()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct IncidentUpdateBuilder {
+    update: IncidentUpdate,
+}
+
+impl IncidentUpdateBuilder {
+    pub fn status(mut self, status: IncidentStatus) -> Self {
+        self.update.status = Some(status);
+        self
+    }
+
+    pub fn classification(mut self, classification: AlertClassification) -> Self {
+        self.update.classification = Some(classification);
+        self
+    }
+
+    pub fn determination(mut self, determination: AlertDetermination) -> Self {
+        self.update.determination = Some(determination);
+        self
+    }
+
+    /// The user principal name of the owner of the incident.
+    pub fn assigned_to(mut self, assigned_to: impl ToString) -> Self {
+        self.update.assigned_to = Some(assigned_to.to_string());
+        self
+    }
+
+    pub fn custom_tag(mut self, tag: impl ToString) -> Self {
+        self.update
+            .custom_tags
+            .get_or_insert_with(Vec::new)
+            .push(tag.to_string());
+        self
+    }
+
+    pub fn build(self) -> IncidentUpdate {
+        self.update
+    }
+}
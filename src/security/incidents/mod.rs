@@ -0,0 +1,5 @@
+mod incident;
+mod request;
+
+pub use incident::*;
+pub use request::*;
@@ -0,0 +1,63 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+
+api_client!(
+    SecurityIncidentsApiClient,
+    SecurityIncidentsIdApiClient,
+    ResourceIdentity::SecurityIncidents
+);
+
+impl SecurityIncidentsApiClient {
+    post!(
+        doc: "Create new navigation property to incidents for security",
+        name: create_incidents,
+        path: "/incidents",
+        body: true
+    );
+    get!(
+        doc: "List incidents",
+        name: list_incidents,
+        path: "/incidents"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_incidents_count,
+        path: "/incidents/$count"
+    );
+}
+
+impl SecurityIncidentsIdApiClient {
+    delete!(
+        doc: "Delete navigation property incidents for security",
+        name: delete_incidents,
+        path: "/incidents/{{RID}}"
+    );
+    get!(
+        doc: "Get incident",
+        name: get_incidents,
+        path: "/incidents/{{RID}}"
+    );
+    patch!(
+        doc: "Update incident",
+        name: update_incidents,
+        path: "/incidents/{{RID}}",
+        body: true
+    );
+    get!(
+        doc: "Get alerts from security",
+        name: list_alerts,
+        path: "/incidents/{{RID}}/alerts"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_alerts_count,
+        path: "/incidents/{{RID}}/alerts/$count"
+    );
+    post!(
+        doc: "Create comment for incident",
+        name: create_comments,
+        path: "/incidents/{{RID}}/comments",
+        body: true
+    );
+}
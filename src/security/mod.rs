@@ -0,0 +1,19 @@
+mod alerts_v2;
+mod cases;
+mod ediscovery_cases;
+mod ediscovery_cases_custodians;
+mod ediscovery_cases_review_sets;
+mod ediscovery_cases_searches;
+mod incidents;
+mod request;
+mod triage;
+
+pub use alerts_v2::*;
+pub use cases::*;
+pub use ediscovery_cases::*;
+pub use ediscovery_cases_custodians::*;
+pub use ediscovery_cases_review_sets::*;
+pub use ediscovery_cases_searches::*;
+pub use incidents::*;
+pub use request::*;
+pub use triage::*;
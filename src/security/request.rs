@@ -0,0 +1,26 @@
+// GENERATED CODE
+
+use crate::api_default_imports::*;
+use crate::security::*;
+
+api_client!(SecurityApiClient, ResourceIdentity::Security);
+
+impl SecurityApiClient {
+    api_client_link!(alerts_v2, SecurityAlertsV2ApiClient);
+    api_client_link_id!(alert_v2, SecurityAlertsV2IdApiClient);
+    api_client_link!(cases, SecurityCasesApiClient);
+    api_client_link!(incidents, SecurityIncidentsApiClient);
+    api_client_link_id!(incident, SecurityIncidentsIdApiClient);
+
+    get!(
+        doc: "Get security",
+        name: get_security,
+        path: "/security"
+    );
+    patch!(
+        doc: "Update security",
+        name: update_security,
+        path: "/security",
+        body: true
+    );
+}
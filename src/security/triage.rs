@@ -0,0 +1,287 @@
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+
+use crate::api_default_imports::*;
+use crate::datetime::{format_iso8601, OffsetDateTime};
+use crate::security::{AlertComment, AlertSeverity};
+use crate::GraphClient;
+
+/// Builds the `$filter` query for listing alerts and incidents. Values given to
+/// the same method are combined with `or` and each method is combined with `and`.
+///
+/// # Example
+/// ```rust,ignore
+/// let filter = SecurityFilter::new()
+///     .severity(&[AlertSeverity::High, AlertSeverity::Medium])
+///     .status(&[AlertStatus::New])
+///     .created_after(datetime!(2023-09-01 00:00 UTC));
+///
+/// let response = client
+///     .security()
+///     .alerts_v2()
+///     .list_alerts_v2()
+///     .filter(&[&filter.to_string()])
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SecurityFilter {
+    clauses: Vec<String>,
+}
+
+impl SecurityFilter {
+    pub fn new() -> SecurityFilter {
+        SecurityFilter::default()
+    }
+
+    pub fn severity(self, severities: &[AlertSeverity]) -> Self {
+        self.any_eq("severity", severities)
+    }
+
+    /// Filter by [AlertStatus](crate::security::AlertStatus) for alerts or
+    /// [IncidentStatus](crate::security::IncidentStatus) for incidents.
+    pub fn status<S: AsRef<str>>(self, statuses: &[S]) -> Self {
+        self.any_eq("status", statuses)
+    }
+
+    /// The user principal name of the owner.
+    pub fn assigned_to(self, assigned_to: &str) -> Self {
+        self.any_eq("assignedTo", &[assigned_to])
+    }
+
+    /// The product that created the alert such as `microsoftDefenderForEndpoint`.
+    pub fn service_source(self, service_source: &str) -> Self {
+        self.any_eq("serviceSource", &[service_source])
+    }
+
+    /// Created at or after the date and time.
+    pub fn created_after(mut self, date_time: OffsetDateTime) -> Self {
+        self.clauses
+            .push(format!("createdDateTime ge {}", format_iso8601(&date_time)));
+        self
+    }
+
+    fn any_eq<S: AsRef<str>>(mut self, property: &str, values: &[S]) -> Self {
+        let values: Vec<String> = values
+            .iter()
+            .map(|value| format!("{property} eq '{}'", value.as_ref().replace('\'', "''")))
+            .collect();
+        match values.len() {
+            0 => {}
+            1 => self.clauses.extend(values),
+            _ => self.clauses.push(format!("({})", values.join(" or "))),
+        }
+        self
+    }
+}
+
+impl Display for SecurityFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.clauses.join(" and "))
+    }
+}
+
+/// A request for an alert or incident that failed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TriageFailure {
+    pub id: String,
+    pub status: u16,
+    pub error: Option<ErrorMessage>,
+}
+
+/// The result of each alert or incident request sent by [SecurityTriage].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TriageReport {
+    pub succeeded: Vec<String>,
+    pub failed: Vec<TriageFailure>,
+}
+
+impl TriageReport {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// The ids of the alerts or incidents that failed, to be sent again.
+    pub fn failed_ids(&self) -> Vec<String> {
+        self.failed
+            .iter()
+            .map(|failure| failure.id.clone())
+            .collect()
+    }
+}
+
+/// Update and comment on many alerts or incidents in $batch requests of 20.
+/// Throttled requests are sent again after the Retry-After duration.
+///
+/// # Example
+/// ```rust,ignore
+/// let update = AlertUpdate::builder()
+///     .status(AlertStatus::Resolved)
+///     .classification(AlertClassification::FalsePositive)
+///     .build();
+///
+/// let triage = SecurityTriage::new(&client);
+/// let report = triage.update_alerts(&alert_ids, &update).await?;
+/// triage
+///     .comment_alerts(&report.succeeded, "Resolved by automation")
+///     .await?;
+///
+/// for failure in report.failed.iter() {
+///     println!("{} failed with {}", failure.id, failure.status);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SecurityTriage {
+    client: GraphClient,
+}
+
+impl SecurityTriage {
+    pub fn new(client: &GraphClient) -> SecurityTriage {
+        SecurityTriage {
+            client: client.clone(),
+        }
+    }
+
+    /// Update each alert with the body, such as an [AlertUpdate](crate::security::AlertUpdate).
+    pub async fn update_alerts<T: AsRef<str>, B: Serialize>(
+        &self,
+        alert_ids: &[T],
+        body: &B,
+    ) -> GraphResult<TriageReport> {
+        self.send("PATCH", "/security/alerts_v2/{id}", alert_ids, body)
+            .await
+    }
+
+    /// Update each incident with the body, such as an [IncidentUpdate](crate::security::IncidentUpdate).
+    pub async fn update_incidents<T: AsRef<str>, B: Serialize>(
+        &self,
+        incident_ids: &[T],
+        body: &B,
+    ) -> GraphResult<TriageReport> {
+        self.send("PATCH", "/security/incidents/{id}", incident_ids, body)
+            .await
+    }
+
+    pub async fn comment_alerts<T: AsRef<str>>(
+        &self,
+        alert_ids: &[T],
+        comment: &str,
+    ) -> GraphResult<TriageReport> {
+        self.send(
+            "POST",
+            "/security/alerts_v2/{id}/comments",
+            alert_ids,
+            &AlertComment::new(comment),
+        )
+        .await
+    }
+
+    pub async fn comment_incidents<T: AsRef<str>>(
+        &self,
+        incident_ids: &[T],
+        comment: &str,
+    ) -> GraphResult<TriageReport> {
+        self.send(
+            "POST",
+            "/security/incidents/{id}/comments",
+            incident_ids,
+            &AlertComment::new(comment),
+        )
+        .await
+    }
+
+    async fn send<T: AsRef<str>, B: Serialize>(
+        &self,
+        method: &str,
+        url: &str,
+        ids: &[T],
+        body: &B,
+    ) -> GraphResult<TriageReport> {
+        let body = serde_json::to_value(body)?;
+        let mut responses = self
+            .client
+            .batch_client()
+            .send_all_requests(batch_requests(method, url, ids, &body))
+            .await?;
+
+        let mut report = TriageReport::default();
+        for (index, id) in ids.iter().enumerate() {
+            let response = responses.remove(&index.to_string()).unwrap_or_default();
+            if response.is_success() {
+                report.succeeded.push(id.as_ref().to_string());
+            } else {
+                report.failed.push(TriageFailure {
+                    id: id.as_ref().to_string(),
+                    status: response.status,
+                    error: response.error(),
+                });
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+// The $batch requests with one request for each id. The index of the id is
+// used as the id of the request.
+fn batch_requests<T: AsRef<str>>(
+    method: &str,
+    url: &str,
+    ids: &[T],
+    body: &serde_json::Value,
+) -> Vec<serde_json::Value> {
+    ids.iter()
+        .enumerate()
+        .map(|(index, id)| {
+            serde_json::json!({
+                "id": index.to_string(),
+                "method": method,
+                "url": url.replace("{id}", id.as_ref()),
+                "headers": { "Content-Type": "application/json" },
+                "body": body
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::security::{AlertStatus, AlertUpdate};
+
+    #[test]
+    fn security_filter() {
+        let filter = SecurityFilter::new()
+            .severity(&[AlertSeverity::High, AlertSeverity::Medium])
+            .status(&[AlertStatus::New])
+            .assigned_to("o'brien@contoso.com")
+            .created_after(time::macros::datetime!(2023-09-01 00:00 UTC));
+        assert_eq!(
+            "(severity eq 'high' or severity eq 'medium') and status eq 'new' and assignedTo eq 'o''brien@contoso.com' and createdDateTime ge 2023-09-01T00:00:00Z",
+            filter.to_string()
+        );
+        assert_eq!("", SecurityFilter::new().to_string());
+    }
+
+    #[test]
+    fn batch_request_for_each_id() {
+        let update = AlertUpdate::builder().status(AlertStatus::Resolved).build();
+        let requests = batch_requests(
+            "PATCH",
+            "/security/alerts_v2/{id}",
+            &["da637", "da638"],
+            &serde_json::to_value(update).unwrap(),
+        );
+        assert_eq!(
+            serde_json::json!({
+                "id": "1",
+                "method": "PATCH",
+                "url": "/security/alerts_v2/da638",
+                "headers": { "Content-Type": "application/json" },
+                "body": { "status": "resolved" }
+            }),
+            requests[1]
+        );
+    }
+}
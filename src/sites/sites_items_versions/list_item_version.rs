@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::OffsetDateTime;
+
+/// A listItemVersion as returned by `list_versions` of a list item, newest first.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ListItemVersion {
+    /// The version label such as `2.0`.
+    pub id: String,
+    #[serde(
+        rename = "lastModifiedDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub last_modified_date_time: Option<OffsetDateTime>,
+    #[serde(rename = "lastModifiedBy", skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published: Option<serde_json::Value>,
+    /// The values of the list item columns in this version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<serde_json::Value>,
+}
@@ -0,0 +1,27 @@
+use crate::api_default_imports::*;
+use crate::drives::all_versions;
+use crate::sites::{ListItemVersion, SitesItemsIdApiClient};
+
+impl SitesItemsIdApiClient {
+    /// Every version of the list item, newest first.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let item = client.site(SITE_ID).list(LIST_ID).item(ITEM_ID);
+    ///
+    /// let versions = item.list_item_versions().await?;
+    /// if let Some(previous) = versions.get(1) {
+    ///     item.restore_item_version(&previous.id).await?;
+    /// }
+    /// ```
+    pub async fn list_item_versions(&self) -> GraphResult<Vec<ListItemVersion>> {
+        all_versions(self.versions().list_versions()).await
+    }
+
+    /// Restore a previous version of the list item as the current version.
+    pub async fn restore_item_version(&self, version_id: &str) -> GraphResult<()> {
+        let response = self.version(version_id).restore_version().send().await?;
+        Paging::success_response(response).await?;
+        Ok(())
+    }
+}
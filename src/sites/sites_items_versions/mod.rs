@@ -1,3 +1,8 @@
+mod list_item_version;
+mod manual_request;
 mod request;
 
+pub use list_item_version::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -0,0 +1,26 @@
+use crate::api_default_imports::*;
+use crate::datetime::Expiration;
+use crate::subscriptions::{Subscription, SubscriptionsIdApiClient};
+
+impl SubscriptionsIdApiClient {
+    /// Renew the subscription, validating the expiration against the maximum lifetime
+    /// of the subscribed resource.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let subscription = client
+    ///     .subscription(SUBSCRIPTION_ID)
+    ///     .renew(Duration::from_secs(2 * 24 * 60 * 60))
+    ///     .await?;
+    /// ```
+    pub async fn renew(&self, expiration: impl Into<Expiration>) -> GraphResult<Subscription> {
+        let response = self.get_subscription().send().await?;
+        let subscription: Subscription = Paging::success_response(response).await?.json().await?;
+        let body = Subscription::renewal(
+            subscription.resource.as_deref().unwrap_or_default(),
+            expiration,
+        )?;
+        let response = self.update_subscription(&body).send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+}
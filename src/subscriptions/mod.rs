@@ -1,3 +1,8 @@
+mod manual_request;
 mod request;
+mod subscription;
 
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
+pub use subscription::*;
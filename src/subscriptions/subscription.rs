@@ -0,0 +1,313 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api_default_imports::*;
+use crate::datetime::{Expiration, OffsetDateTime};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ChangeType {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl AsRef<str> for ChangeType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ChangeType::Created => "created",
+            ChangeType::Updated => "updated",
+            ChangeType::Deleted => "deleted",
+        }
+    }
+}
+
+/// A subscription to change notifications. Use [Subscription::builder] for the body
+/// of `create_subscription`.
+///
+/// The expiration is validated against the maximum lifetime of the resource
+/// documented by Graph, see [Subscription::max_lifetime].
+///
+/// # Example
+/// ```rust,ignore
+/// use std::time::Duration;
+///
+/// let body = Subscription::builder("me/mailFolders('Inbox')/messages", "https://webhook.example.com/api/notify")
+///     .change_type(ChangeType::Created)
+///     .expiration(Duration::from_secs(24 * 60 * 60))
+///     .client_state("secretClientValue")
+///     .build()?;
+///
+/// let subscription: Subscription = client
+///     .subscriptions()
+///     .create_subscription(&body)
+///     .send()
+///     .await?
+///     .json()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Subscription {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<String>,
+    #[serde(rename = "changeType", skip_serializing_if = "Option::is_none")]
+    pub change_type: Option<String>,
+    #[serde(rename = "notificationUrl", skip_serializing_if = "Option::is_none")]
+    pub notification_url: Option<String>,
+    #[serde(
+        rename = "lifecycleNotificationUrl",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lifecycle_notification_url: Option<String>,
+    #[serde(
+        rename = "expirationDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub expiration_date_time: Option<OffsetDateTime>,
+    #[serde(rename = "clientState", skip_serializing_if = "Option::is_none")]
+    pub client_state: Option<String>,
+    #[serde(
+        rename = "includeResourceData",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub include_resource_data: Option<bool>,
+    #[serde(
+        rename = "encryptionCertificate",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub encryption_certificate: Option<String>,
+    #[serde(
+        rename = "encryptionCertificateId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub encryption_certificate_id: Option<String>,
+    #[serde(rename = "applicationId", skip_serializing)]
+    pub application_id: Option<String>,
+    #[serde(rename = "creatorId", skip_serializing)]
+    pub creator_id: Option<String>,
+}
+
+impl Subscription {
+    /// The resource to watch, such as `me/mailFolders('Inbox')/messages`, and the
+    /// url that receives the notifications.
+    pub fn builder(
+        resource: impl ToString,
+        notification_url: impl ToString,
+    ) -> SubscriptionBuilder {
+        SubscriptionBuilder {
+            subscription: Subscription {
+                resource: Some(resource.to_string()),
+                notification_url: Some(notification_url.to_string()),
+                ..Default::default()
+            },
+            change_types: Vec::new(),
+            expiration: None,
+        }
+    }
+
+    /// The body used to renew a subscription for the resource with `update_subscription`.
+    #[allow(clippy::result_large_err)]
+    pub fn renewal(resource: &str, expiration: impl Into<Expiration>) -> GraphResult<Subscription> {
+        let expiration = expiration.into().resolve(
+            OffsetDateTime::now_utc(),
+            Subscription::max_lifetime(resource),
+        )?;
+        Ok(Subscription {
+            expiration_date_time: Some(expiration),
+            ..Default::default()
+        })
+    }
+
+    /// The maximum lifetime of a subscription to the resource as documented by Graph,
+    /// or `None` when the resource is not known.
+    pub fn max_lifetime(resource: &str) -> Option<time::Duration> {
+        let resource = resource.trim_start_matches('/').to_lowercase();
+        let path = resource.split('?').next().unwrap_or_default();
+        // Segments without key segments, `mailFolders('Inbox')` becomes `mailfolders`.
+        let segments: Vec<&str> = path
+            .split('/')
+            .map(|segment| segment.split('(').next().unwrap_or_default())
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        let has = |name: &str| segments.contains(&name);
+
+        let minutes = if has("presences") {
+            60
+        } else if has("alerts") {
+            43200
+        } else if has("callrecords")
+            || has("conversations")
+            || has("todo")
+            || segments.first() == Some(&"print")
+        {
+            4230
+        } else if has("chats")
+            || has("teams")
+            || has("channels")
+            || has("getallmessages")
+            || has("onlinemeetings")
+        {
+            4320
+        } else if has("messages") || has("events") || has("contacts") {
+            10080
+        } else if has("drive") || has("drives") || has("root") || has("lists") {
+            42300
+        } else if has("users") || has("groups") {
+            41760
+        } else {
+            return None;
+        };
+        Some(time::Duration::minutes(minutes))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SubscriptionBuilder {
+    subscription: Subscription,
+    change_types: Vec<ChangeType>,
+    expiration: Option<Expiration>,
+}
+
+impl SubscriptionBuilder {
+    pub fn change_type(mut self, change_type: ChangeType) -> Self {
+        if !self.change_types.contains(&change_type) {
+            self.change_types.push(change_type);
+        }
+        self
+    }
+
+    /// When the subscription expires, either a date and time or a duration from
+    /// the time the body is built. Default is the maximum lifetime of the resource.
+    pub fn expiration(mut self, expiration: impl Into<Expiration>) -> Self {
+        self.expiration = Some(expiration.into());
+        self
+    }
+
+    /// The value sent in the `clientState` of each notification.
+    pub fn client_state(mut self, client_state: impl ToString) -> Self {
+        self.subscription.client_state = Some(client_state.to_string());
+        self
+    }
+
+    pub fn lifecycle_notification_url(mut self, url: impl ToString) -> Self {
+        self.subscription.lifecycle_notification_url = Some(url.to_string());
+        self
+    }
+
+    /// Include the resource data in notifications encrypted with the base64 encoded
+    /// public key certificate.
+    pub fn include_resource_data(
+        mut self,
+        encryption_certificate: impl ToString,
+        encryption_certificate_id: impl ToString,
+    ) -> Self {
+        self.subscription.include_resource_data = Some(true);
+        self.subscription.encryption_certificate = Some(encryption_certificate.to_string());
+        self.subscription.encryption_certificate_id = Some(encryption_certificate_id.to_string());
+        self
+    }
+
+    /// Build the body, failing when no change type is set or the expiration is
+    /// not within the maximum lifetime of the resource.
+    #[allow(clippy::result_large_err)]
+    pub fn build(self) -> GraphResult<Subscription> {
+        self.build_at(OffsetDateTime::now_utc())
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn build_at(mut self, now: OffsetDateTime) -> GraphResult<Subscription> {
+        if self.change_types.is_empty() {
+            return Err(GraphFailure::invalid("subscription change type"));
+        }
+        let resource = self.subscription.resource.clone().unwrap_or_default();
+        let max_lifetime = Subscription::max_lifetime(&resource);
+        let expiration = self
+            .expiration
+            .or_else(|| max_lifetime.map(Expiration::After))
+            .ok_or_else(|| GraphFailure::invalid("subscription expiration"))?;
+
+        self.subscription.expiration_date_time = Some(expiration.resolve(now, max_lifetime)?);
+        self.subscription.change_type = Some(
+            self.change_types
+                .iter()
+                .map(|change_type| change_type.as_ref())
+                .collect::<Vec<&str>>()
+                .join(","),
+        );
+        Ok(self.subscription)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn subscription_max_lifetime() {
+        let minutes =
+            |resource: &str| Subscription::max_lifetime(resource).map(|d| d.whole_minutes());
+        assert_eq!(Some(10080), minutes("me/mailFolders('Inbox')/messages"));
+        assert_eq!(Some(10080), minutes("/users/{id}/events"));
+        assert_eq!(Some(42300), minutes("/me/drive/root"));
+        assert_eq!(Some(41760), minutes("users"));
+        assert_eq!(Some(4320), minutes("/teams/{id}/channels/{id}/messages"));
+        assert_eq!(Some(4230), minutes("communications/callRecords"));
+        assert_eq!(
+            Some(43200),
+            minutes("security/alerts?$filter=status eq 'New'")
+        );
+        assert_eq!(
+            Some(60),
+            minutes("/communications/presences?$filter=id in ('1')")
+        );
+        assert_eq!(None, minutes("/unknown"));
+    }
+
+    #[test]
+    fn build_subscription_with_duration() {
+        let now = datetime!(2024-03-20 08:00 UTC);
+        let subscription = Subscription::builder("me/messages", "https://example.com/notify")
+            .change_type(ChangeType::Created)
+            .change_type(ChangeType::Updated)
+            .expiration(std::time::Duration::from_secs(24 * 60 * 60))
+            .client_state("secret")
+            .build_at(now)
+            .unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "resource": "me/messages",
+                "changeType": "created,updated",
+                "notificationUrl": "https://example.com/notify",
+                "expirationDateTime": "2024-03-21T08:00:00Z",
+                "clientState": "secret"
+            }),
+            serde_json::to_value(&subscription).unwrap()
+        );
+
+        let subscription = Subscription::builder("me/messages", "https://example.com/notify")
+            .change_type(ChangeType::Created)
+            .build_at(now)
+            .unwrap();
+        assert_eq!(
+            Some(datetime!(2024-03-27 08:00 UTC)),
+            subscription.expiration_date_time
+        );
+
+        assert!(
+            Subscription::builder("me/messages", "https://example.com/notify")
+                .change_type(ChangeType::Created)
+                .expiration(time::Duration::days(8))
+                .build_at(now)
+                .is_err()
+        );
+        assert!(
+            Subscription::builder("/unknown", "https://example.com/notify")
+                .change_type(ChangeType::Created)
+                .build_at(now)
+                .is_err()
+        );
+    }
+}
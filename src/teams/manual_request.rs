@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use crate::api_default_imports::*;
+use crate::teams::{
+    NewTeam, NewTeamBuilder, TeamsApiClient, TeamsAsyncOperation, TeamsIdApiClient,
+};
+
+impl TeamsApiClient {
+    /// A [NewTeam] using the `standard` template that binds the template and owners
+    /// with the host and api version of the client.
+    pub fn new_team(&self, display_name: impl ToString) -> NewTeamBuilder {
+        NewTeam::builder(display_name).base_url(&self.resource_config.url)
+    }
+
+    /// Create a team and poll the teamsAsyncOperation every `poll_interval` until the
+    /// team has been created. The id of the team is in
+    /// [TeamsAsyncOperation::target_resource_id]. Returns an error if the operation
+    /// failed or has not completed before `timeout`.
+    pub async fn create_team_and_wait<B: BodyExt>(
+        &self,
+        body: B,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<TeamsAsyncOperation> {
+        let response = self.create_team(body).send_typed().await?;
+        wait_for_team_operation(response, poll_interval, timeout).await
+    }
+}
+
+impl TeamsIdApiClient {
+    /// Clone the team and poll the teamsAsyncOperation every `poll_interval` until
+    /// the new team has been created. The id of the new team is in
+    /// [TeamsAsyncOperation::target_resource_id]. Returns an error if the operation
+    /// failed or has not completed before `timeout`.
+    pub async fn clone_and_wait<B: BodyExt>(
+        &self,
+        body: B,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<TeamsAsyncOperation> {
+        let response = self.clone(body).send_typed().await?;
+        wait_for_team_operation(response, poll_interval, timeout).await
+    }
+}
+
+// Waits for the teamsAsyncOperation in the Location header of the 202 Accepted response.
+async fn wait_for_team_operation(
+    response: TypedResponse,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> GraphResult<TeamsAsyncOperation> {
+    match response {
+        TypedResponse::Accepted(accepted) => {
+            let body = accepted.wait_for_completion(poll_interval, timeout).await?;
+            Ok(serde_json::from_value(body)?)
+        }
+        _ => Err(GraphFailure::not_found(
+            "No teamsAsyncOperation Location header found",
+        )),
+    }
+}
@@ -1,13 +1,18 @@
+mod manual_request;
 mod primary_channel;
 mod request;
 mod schedule;
 mod shared_with_teams;
+mod team;
 mod teams_members;
 mod teams_tags;
 
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use primary_channel::*;
 pub use request::*;
 pub use schedule::*;
 pub use shared_with_teams::*;
+pub use team::*;
 pub use teams_members::*;
 pub use teams_tags::*;
@@ -1,3 +1,5 @@
 mod request;
+mod shared_with_channel_team_info;
 
 pub use request::*;
+pub use shared_with_channel_team_info::*;
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// A sharedWithChannelTeamInfo, a team a shared channel is shared with, as
+/// returned by `list_shared_with_teams`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SharedWithChannelTeamInfo {
+    pub id: String,
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(rename = "tenantId", skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    /// Whether the team is the host of the shared channel.
+    #[serde(rename = "isHostTeam", default)]
+    pub is_host_team: bool,
+}
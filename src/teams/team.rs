@@ -0,0 +1,307 @@
+use serde::{Deserialize, Serialize, Serializer};
+use url::Url;
+
+use crate::client::odata_bind_url;
+use crate::teams::AadUserConversationMember;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TeamVisibility {
+    Private,
+    Public,
+}
+
+/// The body of `create_team` for a team created from a template.
+///
+/// Teams are created asynchronously. Use `create_team_and_wait` to wait until
+/// the team has been created.
+///
+/// The template and owners are bound with the global Microsoft Graph service unless
+/// the builder is created with `client.teams().new_team` or given the url of the
+/// client with [NewTeamBuilder::base_url].
+///
+/// # Example
+/// ```rust,ignore
+/// let team = client
+///     .teams()
+///     .new_team("Contoso Launch")
+///     .template("standard")
+///     .description("Planning the product launch")
+///     .visibility(TeamVisibility::Private)
+///     .owner(USER_ID)
+///     .channel("Marketing")
+///     .build();
+///
+/// let operation = client
+///     .teams()
+///     .create_team_and_wait(&team, Duration::from_secs(5), Duration::from_secs(300))
+///     .await?;
+/// println!("{:?}", operation.target_resource_id);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NewTeam {
+    #[serde(rename = "template@odata.bind")]
+    pub template_bind: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<TeamVisibility>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<AadUserConversationMember>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channels: Vec<serde_json::Value>,
+}
+
+impl NewTeam {
+    /// A team using the `standard` template.
+    pub fn builder(display_name: impl ToString) -> NewTeamBuilder {
+        NewTeamBuilder {
+            team: NewTeam {
+                display_name: display_name.to_string(),
+                ..Default::default()
+            },
+            template_id: "standard".into(),
+            owners: Vec::new(),
+            base_url: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct NewTeamBuilder {
+    team: NewTeam,
+    template_id: String,
+    owners: Vec<String>,
+    base_url: Option<Url>,
+}
+
+impl NewTeamBuilder {
+    /// The id of the team template such as `standard`, `educationClass` or
+    /// `com.microsoft.teams.template.ManageAProject`.
+    pub fn template(mut self, template_id: &str) -> Self {
+        self.template_id = template_id.to_string();
+        self
+    }
+
+    /// The base url, such as the url of the client, whose host and api version are
+    /// used to bind the template and owners.
+    pub fn base_url(mut self, base_url: &Url) -> Self {
+        self.base_url = Some(base_url.clone());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.team.description = Some(description.to_string());
+        self
+    }
+
+    pub fn visibility(mut self, visibility: TeamVisibility) -> Self {
+        self.team.visibility = Some(visibility);
+        self
+    }
+
+    /// Add the user as an owner of the team. An owner is required when the team
+    /// is created with application permissions.
+    pub fn owner(mut self, user_id: &str) -> Self {
+        self.owners.push(user_id.to_string());
+        self
+    }
+
+    /// Add a standard channel in addition to the channels of the template.
+    pub fn channel(mut self, display_name: impl ToString) -> Self {
+        self.team.channels.push(serde_json::json!({
+            "displayName": display_name.to_string()
+        }));
+        self
+    }
+
+    pub fn build(self) -> NewTeam {
+        let base_url = self.base_url.as_ref();
+        let mut team = self.team;
+        team.template_bind =
+            odata_bind_url(base_url, &format!("teamsTemplates('{}')", self.template_id));
+        team.members = self
+            .owners
+            .iter()
+            .map(|user_id| AadUserConversationMember::bind(user_id, base_url).owner())
+            .collect();
+        team
+    }
+}
+
+/// The parts of a team copied by `clone`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TeamClonePart {
+    Apps,
+    Tabs,
+    Settings,
+    Channels,
+    Members,
+}
+
+impl AsRef<str> for TeamClonePart {
+    fn as_ref(&self) -> &str {
+        match self {
+            TeamClonePart::Apps => "apps",
+            TeamClonePart::Tabs => "tabs",
+            TeamClonePart::Settings => "settings",
+            TeamClonePart::Channels => "channels",
+            TeamClonePart::Members => "members",
+        }
+    }
+}
+
+fn serialize_parts<S: Serializer>(
+    parts: &[TeamClonePart],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let parts: Vec<&str> = parts.iter().map(|part| part.as_ref()).collect();
+    serializer.serialize_str(&parts.join(","))
+}
+
+/// The body of `clone` for a team.
+///
+/// # Example
+/// ```rust,ignore
+/// let body = CloneTeam::new("Library Assist", "libassist")
+///     .description("Self help community for library")
+///     .parts(&[TeamClonePart::Apps, TeamClonePart::Tabs, TeamClonePart::Channels]);
+///
+/// let operation = client
+///     .team(TEAM_ID)
+///     .clone_and_wait(&body, Duration::from_secs(5), Duration::from_secs(300))
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CloneTeam {
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(rename = "mailNickname")]
+    pub mail_nickname: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<TeamVisibility>,
+    #[serde(rename = "partsToClone", serialize_with = "serialize_parts")]
+    pub parts_to_clone: Vec<TeamClonePart>,
+}
+
+impl CloneTeam {
+    /// Clone every part of the team.
+    pub fn new(display_name: impl ToString, mail_nickname: impl ToString) -> CloneTeam {
+        CloneTeam {
+            display_name: display_name.to_string(),
+            mail_nickname: mail_nickname.to_string(),
+            description: None,
+            classification: None,
+            visibility: None,
+            parts_to_clone: vec![
+                TeamClonePart::Apps,
+                TeamClonePart::Tabs,
+                TeamClonePart::Settings,
+                TeamClonePart::Channels,
+                TeamClonePart::Members,
+            ],
+        }
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn classification(mut self, classification: impl ToString) -> Self {
+        self.classification = Some(classification.to_string());
+        self
+    }
+
+    pub fn visibility(mut self, visibility: TeamVisibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Only clone the given parts of the team.
+    pub fn parts(mut self, parts: &[TeamClonePart]) -> Self {
+        self.parts_to_clone = parts.to_vec();
+        self
+    }
+}
+
+/// A teamsAsyncOperation returned while a team is created, cloned or archived.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TeamsAsyncOperation {
+    pub id: String,
+    #[serde(rename = "operationType", skip_serializing_if = "Option::is_none")]
+    pub operation_type: Option<String>,
+    /// One of `invalid`, `notStarted`, `inProgress`, `succeeded` or `failed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// The id of the team that was created.
+    #[serde(rename = "targetResourceId", skip_serializing_if = "Option::is_none")]
+    pub target_resource_id: Option<String>,
+    #[serde(
+        rename = "targetResourceLocation",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub target_resource_location: Option<String>,
+    #[serde(rename = "attemptsCount", skip_serializing_if = "Option::is_none")]
+    pub attempts_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<serde_json::Value>,
+}
+
+impl TeamsAsyncOperation {
+    pub fn is_succeeded(&self) -> bool {
+        self.status.as_deref() == Some("succeeded")
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self.status.as_deref(), Some("failed") | Some("invalid"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_new_team_and_clone() {
+        let team = NewTeam::builder("Contoso Launch").owner("user-id").build();
+        assert_eq!(
+            serde_json::json!({
+                "template@odata.bind": "https://graph.microsoft.com/v1.0/teamsTemplates('standard')",
+                "displayName": "Contoso Launch",
+                "members": [{
+                    "@odata.type": "#microsoft.graph.aadUserConversationMember",
+                    "roles": ["owner"],
+                    "user@odata.bind": "https://graph.microsoft.com/v1.0/users('user-id')"
+                }]
+            }),
+            serde_json::to_value(&team).unwrap()
+        );
+
+        let team = NewTeam::builder("Contoso Launch")
+            .base_url(&Url::parse("https://graph.microsoft.us/v1.0").unwrap())
+            .template("educationClass")
+            .build();
+        assert_eq!(
+            "https://graph.microsoft.us/v1.0/teamsTemplates('educationClass')",
+            team.template_bind
+        );
+
+        let clone = CloneTeam::new("Library Assist", "libassist")
+            .parts(&[TeamClonePart::Apps, TeamClonePart::Channels]);
+        assert_eq!(
+            serde_json::json!({
+                "displayName": "Library Assist",
+                "mailNickname": "libassist",
+                "partsToClone": "apps,channels"
+            }),
+            serde_json::to_value(&clone).unwrap()
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::client::odata_bind_url;
+use crate::datetime::OffsetDateTime;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConversationMemberRole {
+    Owner,
+    Guest,
+    #[serde(other)]
+    UnknownFutureValue,
+}
+
+/// The body used to add a user to a team or channel with `create_members`.
+/// A member without roles is a regular member.
+///
+/// Members of a shared channel from another tenant need the id of their tenant.
+///
+/// [AadUserConversationMember::new] binds the user with the global Microsoft Graph
+/// service. Use [AadUserConversationMember::with_base_url] with the url of the client
+/// for national clouds.
+///
+/// # Example
+/// ```rust,ignore
+/// let member = AadUserConversationMember::with_base_url(USER_ID, client.url()).owner();
+///
+/// let member = client
+///     .team(TEAM_ID)
+///     .channel(CHANNEL_ID)
+///     .add_member(&member)
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AadUserConversationMember {
+    #[serde(rename = "@odata.type")]
+    pub odata_type: String,
+    #[serde(default)]
+    pub roles: Vec<ConversationMemberRole>,
+    #[serde(rename = "user@odata.bind")]
+    pub user_bind: String,
+    #[serde(rename = "tenantId", skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+}
+
+impl AadUserConversationMember {
+    pub fn new(user_id: &str) -> AadUserConversationMember {
+        AadUserConversationMember::bind(user_id, None)
+    }
+
+    /// A member bound with the host and api version of the base url, such as the
+    /// url of the client.
+    pub fn with_base_url(user_id: &str, base_url: &Url) -> AadUserConversationMember {
+        AadUserConversationMember::bind(user_id, Some(base_url))
+    }
+
+    pub(crate) fn bind(user_id: &str, base_url: Option<&Url>) -> AadUserConversationMember {
+        AadUserConversationMember {
+            odata_type: "#microsoft.graph.aadUserConversationMember".into(),
+            roles: Vec::new(),
+            user_bind: odata_bind_url(base_url, &format!("users('{user_id}')")),
+            tenant_id: None,
+        }
+    }
+
+    pub fn owner(self) -> Self {
+        self.role(ConversationMemberRole::Owner)
+    }
+
+    pub fn role(mut self, role: ConversationMemberRole) -> Self {
+        if !self.roles.contains(&role) {
+            self.roles.push(role);
+        }
+        self
+    }
+
+    /// The tenant of a user from another organization added to a shared channel.
+    pub fn tenant_id(mut self, tenant_id: impl ToString) -> Self {
+        self.tenant_id = Some(tenant_id.to_string());
+        self
+    }
+}
+
+/// A conversationMember of a team or channel as returned by `list_members`
+/// and `create_members`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ConversationMember {
+    pub id: String,
+    #[serde(default)]
+    pub roles: Vec<ConversationMemberRole>,
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(rename = "userId", skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(rename = "tenantId", skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    #[serde(
+        rename = "visibleHistoryStartDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub visible_history_start_date_time: Option<OffsetDateTime>,
+}
+
+impl ConversationMember {
+    pub fn is_owner(&self) -> bool {
+        self.roles.contains(&ConversationMemberRole::Owner)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_conversation_member() {
+        let member = AadUserConversationMember::new("user-id")
+            .owner()
+            .owner()
+            .tenant_id("tenant-id");
+        assert_eq!(
+            serde_json::json!({
+                "@odata.type": "#microsoft.graph.aadUserConversationMember",
+                "roles": ["owner"],
+                "user@odata.bind": "https://graph.microsoft.com/v1.0/users('user-id')",
+                "tenantId": "tenant-id"
+            }),
+            serde_json::to_value(&member).unwrap()
+        );
+
+        let base_url = Url::parse("https://graph.microsoft.us/v1.0?$select=id").unwrap();
+        assert_eq!(
+            "https://graph.microsoft.us/v1.0/users('user-id')",
+            AadUserConversationMember::with_base_url("user-id", &base_url).user_bind
+        );
+
+        let member: ConversationMember = serde_json::from_value(serde_json::json!({
+            "id": "member-id",
+            "roles": ["owner", "externalRole"],
+            "displayName": "Adele Vance",
+            "userId": "user-id"
+        }))
+        .unwrap();
+        assert!(member.is_owner());
+        assert_eq!(ConversationMemberRole::UnknownFutureValue, member.roles[1]);
+    }
+}
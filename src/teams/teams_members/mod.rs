@@ -1,3 +1,5 @@
+mod conversation_member;
 mod request;
 
+pub use conversation_member::*;
 pub use request::*;
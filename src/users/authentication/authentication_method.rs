@@ -0,0 +1,331 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api_default_imports::*;
+use crate::datetime::{Expiration, OffsetDateTime};
+
+/// The minimum lifetime of a temporary access pass in minutes.
+pub const TEMPORARY_ACCESS_PASS_MIN_LIFETIME: i64 = 10;
+
+/// The maximum lifetime of a temporary access pass in minutes (30 days).
+pub const TEMPORARY_ACCESS_PASS_MAX_LIFETIME: i64 = 43200;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PhoneType {
+    #[serde(rename = "mobile")]
+    Mobile,
+    #[serde(rename = "alternateMobile")]
+    AlternateMobile,
+    #[serde(rename = "office")]
+    Office,
+}
+
+/// A phoneAuthenticationMethod. Use [PhoneMethod::new] for the body of
+/// `create_phone_methods` and `update_phone_methods`.
+///
+/// # Example
+/// ```rust,ignore
+/// let response = client
+///     .user(USER_ID)
+///     .authentication()
+///     .create_phone_methods(&PhoneMethod::new("+1 2065555555", PhoneType::Mobile))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PhoneMethod {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "phoneNumber")]
+    pub phone_number: String,
+    #[serde(rename = "phoneType", skip_serializing_if = "Option::is_none")]
+    pub phone_type: Option<PhoneType>,
+    #[serde(rename = "smsSignInState", skip_serializing)]
+    pub sms_sign_in_state: Option<String>,
+}
+
+impl PhoneMethod {
+    /// The phone number in the format `+{country code} {number}x{extension}`.
+    pub fn new(phone_number: impl ToString, phone_type: PhoneType) -> PhoneMethod {
+        PhoneMethod {
+            phone_number: phone_number.to_string(),
+            phone_type: Some(phone_type),
+            ..Default::default()
+        }
+    }
+}
+
+/// A temporaryAccessPassAuthenticationMethod. Use [TemporaryAccessPassMethod::builder]
+/// for the body of `create_temporary_access_pass_methods`.
+///
+/// The pass is only returned in [TemporaryAccessPassMethod::temporary_access_pass]
+/// of the response to the create request.
+///
+/// # Example
+/// ```rust,ignore
+/// let body = TemporaryAccessPassMethod::builder()
+///     .expiration(Duration::from_secs(60 * 60))?
+///     .is_usable_once(true)
+///     .build();
+///
+/// let method: TemporaryAccessPassMethod = client
+///     .user(USER_ID)
+///     .authentication()
+///     .create_temporary_access_pass_methods(&body)
+///     .send()
+///     .await?
+///     .json()
+///     .await?;
+///
+/// println!("{:?}", method.temporary_access_pass);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TemporaryAccessPassMethod {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(
+        rename = "startDateTime",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub start_date_time: Option<OffsetDateTime>,
+    #[serde(rename = "lifetimeInMinutes", skip_serializing_if = "Option::is_none")]
+    pub lifetime_in_minutes: Option<i32>,
+    #[serde(rename = "isUsableOnce", skip_serializing_if = "Option::is_none")]
+    pub is_usable_once: Option<bool>,
+    #[serde(rename = "temporaryAccessPass", skip_serializing)]
+    pub temporary_access_pass: Option<String>,
+    #[serde(rename = "isUsable", skip_serializing)]
+    pub is_usable: Option<bool>,
+    #[serde(rename = "methodUsabilityReason", skip_serializing)]
+    pub method_usability_reason: Option<String>,
+    #[serde(
+        rename = "createdDateTime",
+        skip_serializing,
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub created_date_time: Option<OffsetDateTime>,
+}
+
+impl TemporaryAccessPassMethod {
+    pub fn builder() -> TemporaryAccessPassMethodBuilder {
+        TemporaryAccessPassMethodBuilder::default()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TemporaryAccessPassMethodBuilder {
+    method: TemporaryAccessPassMethod,
+}
+
+impl TemporaryAccessPassMethodBuilder {
+    /// The date and time the pass becomes usable.
+    /// Default is the time the pass is created.
+    pub fn start_date_time(mut self, start_date_time: OffsetDateTime) -> Self {
+        self.method.start_date_time = Some(start_date_time);
+        self
+    }
+
+    /// The lifetime of the pass from 10 to 43200 minutes (30 days).
+    pub fn lifetime_in_minutes(mut self, lifetime_in_minutes: i32) -> Self {
+        self.method.lifetime_in_minutes = Some(lifetime_in_minutes);
+        self
+    }
+
+    /// Set the lifetime of the pass from an expiration, either a date and time or a
+    /// duration from the start of the pass. Set [start_date_time](Self::start_date_time)
+    /// first when the pass does not start when it is created.
+    ///
+    /// Fails when the lifetime is not within 10 to 43200 minutes.
+    #[allow(clippy::result_large_err)]
+    pub fn expiration(self, expiration: impl Into<Expiration>) -> GraphResult<Self> {
+        let start = self
+            .method
+            .start_date_time
+            .unwrap_or_else(OffsetDateTime::now_utc);
+        self.expiration_from(start, expiration.into())
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn expiration_from(self, start: OffsetDateTime, expiration: Expiration) -> GraphResult<Self> {
+        let expiration = expiration.resolve(
+            start,
+            Some(time::Duration::minutes(TEMPORARY_ACCESS_PASS_MAX_LIFETIME)),
+        )?;
+        let lifetime = (expiration - start).whole_minutes();
+        if lifetime < TEMPORARY_ACCESS_PASS_MIN_LIFETIME {
+            return Err(GraphFailure::invalid(&format!(
+                "temporary access pass lifetime of {lifetime} minutes is less than {TEMPORARY_ACCESS_PASS_MIN_LIFETIME} minutes"
+            )));
+        }
+        Ok(self.lifetime_in_minutes(lifetime as i32))
+    }
+
+    /// Whether the pass can only be used once.
+    pub fn is_usable_once(mut self, is_usable_once: bool) -> Self {
+        self.method.is_usable_once = Some(is_usable_once);
+        self
+    }
+
+    pub fn build(self) -> TemporaryAccessPassMethod {
+        self.method
+    }
+}
+
+/// A fido2AuthenticationMethod such as a security key.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Fido2Method {
+    pub id: String,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    pub model: Option<String>,
+    #[serde(rename = "aaGuid")]
+    pub aa_guid: Option<String>,
+    #[serde(rename = "attestationLevel")]
+    pub attestation_level: Option<String>,
+    #[serde(
+        rename = "createdDateTime",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub created_date_time: Option<OffsetDateTime>,
+}
+
+/// An authentication method of a user as returned by `list_methods` using
+/// the `@odata.type` of the method.
+///
+/// # Example
+/// ```rust,ignore
+/// let body: serde_json::Value = client
+///     .user(USER_ID)
+///     .authentication()
+///     .list_methods()
+///     .send()
+///     .await?
+///     .json()
+///     .await?;
+///
+/// let methods: Vec<AuthenticationMethod> = serde_json::from_value(body["value"].clone())?;
+/// for method in methods {
+///     if let AuthenticationMethod::Fido2(key) = method {
+///         println!("{:?}", key.display_name);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@odata.type")]
+pub enum AuthenticationMethod {
+    #[serde(rename = "#microsoft.graph.fido2AuthenticationMethod")]
+    Fido2(Fido2Method),
+    #[serde(rename = "#microsoft.graph.phoneAuthenticationMethod")]
+    Phone(PhoneMethod),
+    #[serde(rename = "#microsoft.graph.temporaryAccessPassAuthenticationMethod")]
+    TemporaryAccessPass(TemporaryAccessPassMethod),
+    #[serde(rename = "#microsoft.graph.passwordAuthenticationMethod")]
+    Password,
+    #[serde(rename = "#microsoft.graph.emailAuthenticationMethod")]
+    Email,
+    #[serde(rename = "#microsoft.graph.microsoftAuthenticatorAuthenticationMethod")]
+    MicrosoftAuthenticator,
+    #[serde(rename = "#microsoft.graph.softwareOathAuthenticationMethod")]
+    SoftwareOath,
+    #[serde(rename = "#microsoft.graph.windowsHelloForBusinessAuthenticationMethod")]
+    WindowsHelloForBusiness,
+    #[serde(other)]
+    Other,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn authentication_methods_by_odata_type() {
+        let methods: Vec<AuthenticationMethod> = serde_json::from_value(serde_json::json!([
+            {
+                "@odata.type": "#microsoft.graph.fido2AuthenticationMethod",
+                "id": "1",
+                "displayName": "Red Key",
+                "model": "NFC Key",
+                "aaGuid": "2fc0579f-8113-47ea-b116-bb5a8db9202a"
+            },
+            {
+                "@odata.type": "#microsoft.graph.phoneAuthenticationMethod",
+                "id": "2",
+                "phoneNumber": "+1 2065555555",
+                "phoneType": "mobile",
+                "smsSignInState": "notAllowedByPolicy"
+            },
+            {
+                "@odata.type": "#microsoft.graph.passwordAuthenticationMethod",
+                "id": "3"
+            },
+            {
+                "@odata.type": "#microsoft.graph.platformCredentialAuthenticationMethod",
+                "id": "4"
+            }
+        ]))
+        .unwrap();
+
+        assert!(
+            matches!(&methods[0], AuthenticationMethod::Fido2(key) if key.display_name.as_deref() == Some("Red Key"))
+        );
+        assert!(
+            matches!(&methods[1], AuthenticationMethod::Phone(phone) if phone.phone_type == Some(PhoneType::Mobile))
+        );
+        assert_eq!(AuthenticationMethod::Password, methods[2]);
+        assert_eq!(AuthenticationMethod::Other, methods[3]);
+    }
+
+    #[test]
+    fn serialize_temporary_access_pass() {
+        let body = TemporaryAccessPassMethod::builder()
+            .lifetime_in_minutes(60)
+            .is_usable_once(true)
+            .build();
+        assert_eq!(
+            serde_json::json!({
+                "lifetimeInMinutes": 60,
+                "isUsableOnce": true
+            }),
+            serde_json::to_value(&body).unwrap()
+        );
+        assert_eq!(
+            serde_json::json!({
+                "phoneNumber": "+1 2065555555",
+                "phoneType": "mobile"
+            }),
+            serde_json::to_value(PhoneMethod::new("+1 2065555555", PhoneType::Mobile)).unwrap()
+        );
+    }
+
+    #[test]
+    fn temporary_access_pass_expiration() {
+        let start = time::macros::datetime!(2024-03-20 08:00 UTC);
+        let body = TemporaryAccessPassMethod::builder()
+            .expiration_from(
+                start,
+                Expiration::from(std::time::Duration::from_secs(2 * 60 * 60)),
+            )
+            .unwrap()
+            .build();
+        assert_eq!(Some(120), body.lifetime_in_minutes);
+
+        let body = TemporaryAccessPassMethod::builder()
+            .expiration_from(
+                start,
+                Expiration::from(time::macros::datetime!(2024-03-21 08:00 UTC)),
+            )
+            .unwrap()
+            .build();
+        assert_eq!(Some(1440), body.lifetime_in_minutes);
+
+        assert!(TemporaryAccessPassMethod::builder()
+            .expiration_from(start, Expiration::from(time::Duration::minutes(5)))
+            .is_err());
+        assert!(TemporaryAccessPassMethod::builder()
+            .expiration_from(start, Expiration::from(time::Duration::days(31)))
+            .is_err());
+    }
+}
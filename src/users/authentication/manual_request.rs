@@ -0,0 +1,10 @@
+use crate::api_default_imports::*;
+use crate::users::AuthenticationApiClient;
+
+impl AuthenticationApiClient {
+    get!(
+        doc: "List fido2Methods",
+        name: list_fido_2_methods,
+        path: "/authentication/fido2Methods"
+    );
+}
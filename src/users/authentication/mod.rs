@@ -1,3 +1,8 @@
+mod authentication_method;
+mod manual_request;
 mod request;
 
+pub use authentication_method::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -0,0 +1,443 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::api_default_imports::*;
+use crate::batch::{BATCH_MAX_REQUESTS, MAX_THROTTLE_RETRIES};
+use crate::GraphClient;
+
+/// The request made for a [BulkOperation].
+#[derive(Clone, Debug, PartialEq)]
+pub enum UserOperation {
+    Create(serde_json::Value),
+    Update {
+        user_id: String,
+        body: serde_json::Value,
+    },
+    Delete {
+        user_id: String,
+    },
+}
+
+/// A create, update, or delete user operation sent as part of a $batch request.
+///
+/// The id identifies the operation in the [BulkReport] and is used to declare
+/// dependencies between operations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BulkOperation {
+    pub id: String,
+    pub operation: UserOperation,
+    pub depends_on: Vec<String>,
+}
+
+impl BulkOperation {
+    #[allow(clippy::result_large_err)]
+    pub fn create_user<B: Serialize>(id: impl ToString, body: &B) -> GraphResult<BulkOperation> {
+        Ok(BulkOperation {
+            id: id.to_string(),
+            operation: UserOperation::Create(serde_json::to_value(body)?),
+            depends_on: Vec::new(),
+        })
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn update_user<B: Serialize>(
+        id: impl ToString,
+        user_id: impl ToString,
+        body: &B,
+    ) -> GraphResult<BulkOperation> {
+        Ok(BulkOperation {
+            id: id.to_string(),
+            operation: UserOperation::Update {
+                user_id: user_id.to_string(),
+                body: serde_json::to_value(body)?,
+            },
+            depends_on: Vec::new(),
+        })
+    }
+
+    pub fn delete_user(id: impl ToString, user_id: impl ToString) -> BulkOperation {
+        BulkOperation {
+            id: id.to_string(),
+            operation: UserOperation::Delete {
+                user_id: user_id.to_string(),
+            },
+            depends_on: Vec::new(),
+        }
+    }
+
+    /// Only run this operation after the operation with the given id has succeeded.
+    /// The operation fails with status 424 (Failed Dependency) without being sent
+    /// if the other operation fails.
+    pub fn depends_on(mut self, id: impl ToString) -> BulkOperation {
+        self.depends_on.push(id.to_string());
+        self
+    }
+
+    fn batch_request(&self, depends_on: Vec<&String>) -> serde_json::Value {
+        let mut request = match &self.operation {
+            UserOperation::Create(body) => serde_json::json!({
+                "method": "POST",
+                "url": "/users",
+                "headers": { "Content-Type": "application/json" },
+                "body": body
+            }),
+            UserOperation::Update { user_id, body } => serde_json::json!({
+                "method": "PATCH",
+                "url": format!("/users/{user_id}"),
+                "headers": { "Content-Type": "application/json" },
+                "body": body
+            }),
+            UserOperation::Delete { user_id } => serde_json::json!({
+                "method": "DELETE",
+                "url": format!("/users/{user_id}")
+            }),
+        };
+
+        request["id"] = serde_json::json!(self.id);
+        if !depends_on.is_empty() {
+            request["dependsOn"] = serde_json::json!(depends_on);
+        }
+        request
+    }
+}
+
+/// The response to a successful operation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BulkSuccess {
+    pub id: String,
+    pub status: u16,
+    pub body: Option<serde_json::Value>,
+}
+
+/// A failed operation. The operation is included so that it can be sent again.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BulkFailure {
+    pub operation: BulkOperation,
+    pub status: u16,
+    pub error: Option<ErrorMessage>,
+}
+
+/// The result of each operation sent by [BulkOperations].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BulkReport {
+    pub succeeded: Vec<BulkSuccess>,
+    pub failed: Vec<BulkFailure>,
+}
+
+impl BulkReport {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// The operations that failed, to be sent again.
+    pub fn failed_operations(&self) -> Vec<BulkOperation> {
+        self.failed
+            .iter()
+            .map(|failure| failure.operation.clone())
+            .collect()
+    }
+}
+
+struct PendingOperation {
+    operation: BulkOperation,
+    retries: usize,
+}
+
+/// Create, update, and delete users in $batch requests of 20 operations.
+///
+/// Operations are sent in the order given. An operation that depends on another
+/// operation is sent in the same batch with `dependsOn` set or in a later batch once
+/// the other operation has succeeded. Requests are limited to the configured rate and
+/// operations that are throttled are sent again after the Retry-After duration.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::users::{BulkOperation, BulkOperations};
+///
+/// let operations = vec![
+///     BulkOperation::create_user("1", &serde_json::json!({
+///         "accountEnabled": true,
+///         "displayName": "Adele Vance",
+///         "mailNickname": "AdeleV",
+///         "userPrincipalName": "AdeleV@contoso.onmicrosoft.com",
+///         "passwordProfile": { "password": "..." }
+///     }))?,
+///     BulkOperation::update_user("2", USER_ID, &serde_json::json!({ "jobTitle": "Manager" }))?,
+///     BulkOperation::delete_user("3", OTHER_USER_ID).depends_on("2"),
+/// ];
+///
+/// let report = BulkOperations::new(&client)
+///     .requests_per_second(10.0)
+///     .send(operations)
+///     .await?;
+///
+/// for failure in report.failed.iter() {
+///     println!("{} failed with {}", failure.operation.id, failure.status);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BulkOperations {
+    client: GraphClient,
+    requests_per_second: Option<f64>,
+}
+
+impl BulkOperations {
+    pub fn new(client: &GraphClient) -> BulkOperations {
+        BulkOperations {
+            client: client.clone(),
+            requests_per_second: None,
+        }
+    }
+
+    /// Limit the number of operations sent per second. Each operation in a
+    /// batch counts as one request.
+    pub fn requests_per_second(mut self, requests_per_second: f64) -> BulkOperations {
+        if requests_per_second > 0.0 {
+            self.requests_per_second = Some(requests_per_second);
+        }
+        self
+    }
+
+    /// Send the operations and report the result of each one.
+    ///
+    /// An error is returned when the operation ids are not unique, an operation depends
+    /// on an id that is not in the operations, or a $batch request fails.
+    pub async fn send<I: IntoIterator<Item = BulkOperation>>(
+        &self,
+        operations: I,
+    ) -> GraphResult<BulkReport> {
+        let mut pending = pending_operations(operations)?;
+        let mut completed: HashMap<String, bool> = HashMap::new();
+        let mut report = BulkReport::default();
+        let mut wait = Duration::ZERO;
+
+        while !pending.is_empty() {
+            let batch = next_batch(&mut pending, &mut completed, &mut report);
+            if batch.is_empty() {
+                continue;
+            }
+
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+            wait = self
+                .requests_per_second
+                .map(|rate| Duration::from_secs_f64(batch.len() as f64 / rate))
+                .unwrap_or_default();
+
+            let batch_ids: HashSet<&String> = batch.iter().map(|p| &p.operation.id).collect();
+            let requests: Vec<serde_json::Value> = batch
+                .iter()
+                .map(|p| {
+                    let depends_on = p
+                        .operation
+                        .depends_on
+                        .iter()
+                        .filter(|id| batch_ids.contains(id))
+                        .collect();
+                    p.operation.batch_request(depends_on)
+                })
+                .collect();
+
+            let mut responses = self.client.batch_client().send_requests(&requests).await?;
+
+            let mut throttled = Vec::new();
+            for mut pending_operation in batch {
+                let response = responses
+                    .remove(&pending_operation.operation.id)
+                    .unwrap_or_default();
+
+                if let Some(retry_after) = response
+                    .throttle_retry_after()
+                    .filter(|_| pending_operation.retries < MAX_THROTTLE_RETRIES)
+                {
+                    wait = wait.max(retry_after);
+                    pending_operation.retries += 1;
+                    throttled.push(pending_operation);
+                    continue;
+                }
+
+                let id = pending_operation.operation.id.clone();
+                let status = response.status;
+                let body = Some(response.body).filter(|body| !body.is_null());
+                if (200..300).contains(&status) {
+                    completed.insert(id.clone(), true);
+                    report.succeeded.push(BulkSuccess { id, status, body });
+                } else {
+                    completed.insert(id, false);
+                    report.failed.push(BulkFailure {
+                        operation: pending_operation.operation,
+                        status,
+                        error: body.and_then(|body| serde_json::from_value(body).ok()),
+                    });
+                }
+            }
+
+            for pending_operation in throttled.into_iter().rev() {
+                pending.push_front(pending_operation);
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn pending_operations<I: IntoIterator<Item = BulkOperation>>(
+    operations: I,
+) -> GraphResult<VecDeque<PendingOperation>> {
+    let pending: VecDeque<PendingOperation> = operations
+        .into_iter()
+        .map(|operation| PendingOperation {
+            operation,
+            retries: 0,
+        })
+        .collect();
+
+    let mut ids = HashSet::new();
+    for pending_operation in pending.iter() {
+        if !ids.insert(pending_operation.operation.id.as_str()) {
+            return Err(GraphFailure::invalid(&format!(
+                "duplicate bulk operation id {}",
+                pending_operation.operation.id
+            )));
+        }
+    }
+
+    for pending_operation in pending.iter() {
+        if let Some(id) = pending_operation
+            .operation
+            .depends_on
+            .iter()
+            .find(|id| !ids.contains(id.as_str()))
+        {
+            return Err(GraphFailure::invalid(&format!(
+                "bulk operation {} depends on unknown operation {id}",
+                pending_operation.operation.id
+            )));
+        }
+    }
+
+    Ok(pending)
+}
+
+// Take the next operations to send, up to the batch limit. Operations whose
+// dependencies failed are reported as failed and operations whose dependencies
+// have not been sent yet are left pending.
+fn next_batch(
+    pending: &mut VecDeque<PendingOperation>,
+    completed: &mut HashMap<String, bool>,
+    report: &mut BulkReport,
+) -> Vec<PendingOperation> {
+    let mut batch: Vec<PendingOperation> = Vec::new();
+    let mut deferred = VecDeque::new();
+
+    while let Some(pending_operation) = pending.pop_front() {
+        if batch.len() == BATCH_MAX_REQUESTS {
+            deferred.push_back(pending_operation);
+            continue;
+        }
+
+        let depends_on = &pending_operation.operation.depends_on;
+        if depends_on
+            .iter()
+            .any(|id| completed.get(id).copied() == Some(false))
+        {
+            completed.insert(pending_operation.operation.id.clone(), false);
+            report.failed.push(BulkFailure {
+                operation: pending_operation.operation,
+                status: 424,
+                error: None,
+            });
+            continue;
+        }
+
+        let ready = depends_on.iter().all(|id| {
+            completed.get(id).copied() == Some(true) || batch.iter().any(|p| &p.operation.id == id)
+        });
+        if ready {
+            batch.push(pending_operation);
+        } else {
+            deferred.push_back(pending_operation);
+        }
+    }
+
+    // Operations that depend on each other in a cycle can never be sent.
+    if batch.is_empty() {
+        for pending_operation in deferred.drain(..) {
+            completed.insert(pending_operation.operation.id.clone(), false);
+            report.failed.push(BulkFailure {
+                operation: pending_operation.operation,
+                status: 424,
+                error: None,
+            });
+        }
+    }
+
+    *pending = deferred;
+    batch
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn operations(count: usize) -> Vec<BulkOperation> {
+        (0..count)
+            .map(|i| BulkOperation::delete_user(i, format!("user-{i}")))
+            .collect()
+    }
+
+    #[test]
+    fn batches_of_twenty() {
+        let mut pending = pending_operations(operations(45)).unwrap();
+        let mut completed = HashMap::new();
+        let mut report = BulkReport::default();
+
+        assert_eq!(
+            20,
+            next_batch(&mut pending, &mut completed, &mut report).len()
+        );
+        assert_eq!(
+            20,
+            next_batch(&mut pending, &mut completed, &mut report).len()
+        );
+        assert_eq!(
+            5,
+            next_batch(&mut pending, &mut completed, &mut report).len()
+        );
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn dependencies() {
+        let mut operations = operations(3);
+        operations[1] = operations[1].clone().depends_on("0");
+        operations[2] = operations[2].clone().depends_on("1");
+        let mut pending = pending_operations(operations).unwrap();
+        let mut completed = HashMap::new();
+        let mut report = BulkReport::default();
+
+        let batch = next_batch(&mut pending, &mut completed, &mut report);
+        assert_eq!(3, batch.len());
+        let request = batch[1].operation.batch_request(vec![&"0".to_string()]);
+        assert_eq!(serde_json::json!(["0"]), request["dependsOn"]);
+
+        let mut pending = pending_operations(vec![
+            BulkOperation::delete_user("a", "user-a"),
+            BulkOperation::delete_user("b", "user-b").depends_on("a"),
+        ])
+        .unwrap();
+        completed.insert("a".into(), false);
+        pending.pop_front();
+        assert!(next_batch(&mut pending, &mut completed, &mut report).is_empty());
+        assert_eq!(424, report.failed[0].status);
+
+        assert!(pending_operations(vec![
+            BulkOperation::delete_user("a", "user-a").depends_on("missing")
+        ])
+        .is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use futures::TryStreamExt;
+
+use crate::api_default_imports::*;
+use crate::teams::{AadUserConversationMember, ConversationMember, SharedWithChannelTeamInfo};
+use crate::users::ChannelsIdApiClient;
+
+impl ChannelsIdApiClient {
+    /// Add a user to a private or shared channel. The user must already be a
+    /// member of the team, or of the tenant given by
+    /// [AadUserConversationMember::tenant_id] for a shared channel.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let member = client
+    ///     .team(TEAM_ID)
+    ///     .channel(CHANNEL_ID)
+    ///     .add_member(&AadUserConversationMember::with_base_url(USER_ID, client.url()).owner())
+    ///     .await?;
+    /// ```
+    pub async fn add_member(
+        &self,
+        member: &AadUserConversationMember,
+    ) -> GraphResult<ConversationMember> {
+        let response = self.members().create_members(member).send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+
+    /// Remove the member using the id of the conversationMember, not the id of the user.
+    pub async fn remove_member(&self, membership_id: &str) -> GraphResult<()> {
+        let response = self.member(membership_id).delete_members().send().await?;
+        Paging::success_response(response).await?;
+        Ok(())
+    }
+
+    /// The members of the channel from every page of the response.
+    pub async fn list_channel_members(&self) -> GraphResult<Vec<ConversationMember>> {
+        self.members()
+            .list_members()
+            .paging()
+            .stream_items::<ConversationMember>()?
+            .try_collect()
+            .await
+    }
+
+    /// The teams a shared channel is shared with, including the host team, from
+    /// every page of the response.
+    pub async fn list_shared_with_team_info(&self) -> GraphResult<Vec<SharedWithChannelTeamInfo>> {
+        self.shared_with_teams()
+            .list_shared_with_teams()
+            .paging()
+            .stream_items::<SharedWithChannelTeamInfo>()?
+            .try_collect()
+            .await
+    }
+
+    /// Provision an email address for the channel and return the address.
+    pub async fn provision_email_address(&self) -> GraphResult<String> {
+        let response = self.provision_email().send().await?;
+        let body: serde_json::Value = Paging::success_response(response).await?.json().await?;
+        body["email"]
+            .as_str()
+            .map(|email| email.to_string())
+            .ok_or_else(|| GraphFailure::not_found("No email in provisionEmail response"))
+    }
+}
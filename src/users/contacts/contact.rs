@@ -0,0 +1,295 @@
+use serde::{Deserialize, Serialize};
+
+use crate::collection::Collection;
+use crate::datetime::OffsetDateTime;
+
+use crate::users::EmailAddress;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PhysicalAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(rename = "postalCode", skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    #[serde(rename = "countryOrRegion", skip_serializing_if = "Option::is_none")]
+    pub country_or_region: Option<String>,
+}
+
+impl PhysicalAddress {
+    pub fn street(mut self, street: impl ToString) -> Self {
+        self.street = Some(street.to_string());
+        self
+    }
+
+    pub fn city(mut self, city: impl ToString) -> Self {
+        self.city = Some(city.to_string());
+        self
+    }
+
+    pub fn state(mut self, state: impl ToString) -> Self {
+        self.state = Some(state.to_string());
+        self
+    }
+
+    pub fn postal_code(mut self, postal_code: impl ToString) -> Self {
+        self.postal_code = Some(postal_code.to_string());
+        self
+    }
+
+    pub fn country_or_region(mut self, country_or_region: impl ToString) -> Self {
+        self.country_or_region = Some(country_or_region.to_string());
+        self
+    }
+}
+
+/// An Outlook contact. Use [Contact::builder] for the body of `create_contacts`
+/// and `update_contacts`.
+///
+/// Contacts returned by a delta query that were deleted only have an id and
+/// [Contact::is_removed] returns true.
+///
+/// # Example
+/// ```rust,ignore
+/// let contact = Contact::builder("Pavel", "Bansky")
+///     .email("pavelb@contoso.onmicrosoft.com", "Pavel Bansky")
+///     .business_phone("+1 732 555 0102")
+///     .business_address(PhysicalAddress::default().city("Redmond").state("WA"))
+///     .build();
+///
+/// let response = client.me().contacts().create_contacts(&contact).send().await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(rename = "givenName", skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surname: Option<String>,
+    #[serde(rename = "nickName", skip_serializing_if = "Option::is_none")]
+    pub nick_name: Option<String>,
+    #[serde(rename = "companyName", skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub department: Option<String>,
+    #[serde(rename = "jobTitle", skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(
+        rename = "emailAddresses",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub email_addresses: Vec<EmailAddress>,
+    #[serde(
+        rename = "businessPhones",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub business_phones: Vec<String>,
+    #[serde(rename = "homePhones", default, skip_serializing_if = "Vec::is_empty")]
+    pub home_phones: Vec<String>,
+    #[serde(rename = "mobilePhone", skip_serializing_if = "Option::is_none")]
+    pub mobile_phone: Option<String>,
+    #[serde(rename = "businessAddress", skip_serializing_if = "Option::is_none")]
+    pub business_address: Option<PhysicalAddress>,
+    #[serde(rename = "homeAddress", skip_serializing_if = "Option::is_none")]
+    pub home_address: Option<PhysicalAddress>,
+    #[serde(rename = "otherAddress", skip_serializing_if = "Option::is_none")]
+    pub other_address: Option<PhysicalAddress>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub birthday: Option<OffsetDateTime>,
+    #[serde(rename = "personalNotes", skip_serializing_if = "Option::is_none")]
+    pub personal_notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+    #[serde(rename = "parentFolderId", skip_serializing)]
+    pub parent_folder_id: Option<String>,
+    #[serde(rename = "changeKey", skip_serializing)]
+    pub change_key: Option<String>,
+    #[serde(
+        rename = "lastModifiedDateTime",
+        skip_serializing,
+        default,
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub last_modified_date_time: Option<OffsetDateTime>,
+    #[serde(rename = "@removed", skip_serializing)]
+    pub removed: Option<serde_json::Value>,
+}
+
+impl Contact {
+    pub fn builder(given_name: impl ToString, surname: impl ToString) -> ContactBuilder {
+        ContactBuilder {
+            contact: Contact {
+                given_name: Some(given_name.to_string()),
+                surname: Some(surname.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Whether the contact was deleted. Only set for contacts returned by a delta query.
+    pub fn is_removed(&self) -> bool {
+        self.removed.is_some()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ContactBuilder {
+    contact: Contact,
+}
+
+impl ContactBuilder {
+    pub fn display_name(mut self, display_name: impl ToString) -> Self {
+        self.contact.display_name = Some(display_name.to_string());
+        self
+    }
+
+    pub fn nick_name(mut self, nick_name: impl ToString) -> Self {
+        self.contact.nick_name = Some(nick_name.to_string());
+        self
+    }
+
+    pub fn company_name(mut self, company_name: impl ToString) -> Self {
+        self.contact.company_name = Some(company_name.to_string());
+        self
+    }
+
+    pub fn department(mut self, department: impl ToString) -> Self {
+        self.contact.department = Some(department.to_string());
+        self
+    }
+
+    pub fn job_title(mut self, job_title: impl ToString) -> Self {
+        self.contact.job_title = Some(job_title.to_string());
+        self
+    }
+
+    /// Add an email address. A contact can have up to three email addresses.
+    pub fn email(mut self, address: impl ToString, name: impl ToString) -> Self {
+        self.contact.email_addresses.push(EmailAddress {
+            address: address.to_string(),
+            name: Some(name.to_string()),
+        });
+        self
+    }
+
+    pub fn business_phone(mut self, phone: impl ToString) -> Self {
+        self.contact.business_phones.push(phone.to_string());
+        self
+    }
+
+    pub fn home_phone(mut self, phone: impl ToString) -> Self {
+        self.contact.home_phones.push(phone.to_string());
+        self
+    }
+
+    pub fn mobile_phone(mut self, phone: impl ToString) -> Self {
+        self.contact.mobile_phone = Some(phone.to_string());
+        self
+    }
+
+    pub fn business_address(mut self, address: PhysicalAddress) -> Self {
+        self.contact.business_address = Some(address);
+        self
+    }
+
+    pub fn home_address(mut self, address: PhysicalAddress) -> Self {
+        self.contact.home_address = Some(address);
+        self
+    }
+
+    pub fn other_address(mut self, address: PhysicalAddress) -> Self {
+        self.contact.other_address = Some(address);
+        self
+    }
+
+    pub fn birthday(mut self, birthday: OffsetDateTime) -> Self {
+        self.contact.birthday = Some(birthday);
+        self
+    }
+
+    pub fn personal_notes(mut self, personal_notes: impl ToString) -> Self {
+        self.contact.personal_notes = Some(personal_notes.to_string());
+        self
+    }
+
+    pub fn category(mut self, category: impl ToString) -> Self {
+        self.contact.categories.push(category.to_string());
+        self
+    }
+
+    pub fn build(self) -> Contact {
+        self.contact
+    }
+}
+
+/// A contactFolder as returned by `list_contact_folders` and the contact folder delta query.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ContactFolder {
+    pub id: String,
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(rename = "parentFolderId", skip_serializing_if = "Option::is_none")]
+    pub parent_folder_id: Option<String>,
+    #[serde(rename = "@removed", skip_serializing_if = "Option::is_none")]
+    pub removed: Option<serde_json::Value>,
+}
+
+impl ContactFolder {
+    pub fn is_removed(&self) -> bool {
+        self.removed.is_some()
+    }
+}
+
+/// The changes from a delta query and the delta link used to request the
+/// changes after this sync.
+pub type ContactsDelta<T> = Collection<T>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_contact_and_delta() {
+        let contact = Contact::builder("Pavel", "Bansky")
+            .email("pavelb@contoso.onmicrosoft.com", "Pavel Bansky")
+            .business_phone("+1 732 555 0102")
+            .business_address(PhysicalAddress::default().city("Redmond").state("WA"))
+            .build();
+        assert_eq!(
+            serde_json::json!({
+                "givenName": "Pavel",
+                "surname": "Bansky",
+                "emailAddresses": [
+                    { "address": "pavelb@contoso.onmicrosoft.com", "name": "Pavel Bansky" }
+                ],
+                "businessPhones": ["+1 732 555 0102"],
+                "businessAddress": { "city": "Redmond", "state": "WA" }
+            }),
+            serde_json::to_value(&contact).unwrap()
+        );
+
+        let delta: ContactsDelta<Contact> = serde_json::from_value(serde_json::json!({
+            "value": [
+                { "id": "1", "givenName": "Pavel" },
+                { "id": "2", "@removed": { "reason": "deleted" } }
+            ],
+            "@odata.deltaLink": "https://graph.microsoft.com/v1.0/me/contacts/delta?$deltatoken=abc"
+        }))
+        .unwrap();
+        assert!(!delta.value[0].is_removed());
+        assert!(delta.value[1].is_removed());
+        assert_eq!(Some("abc".to_string()), delta.delta_token());
+    }
+}
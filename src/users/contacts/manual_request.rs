@@ -0,0 +1,70 @@
+use serde::de::DeserializeOwned;
+
+use crate::api_default_imports::*;
+use crate::users::{
+    Contact, ContactFolder, ContactFoldersApiClient, ContactsApiClient, ContactsDelta,
+};
+
+impl ContactsApiClient {
+    /// Get the contacts created, updated or deleted since the delta token of a
+    /// previous sync, or all contacts when there is no delta token. Store
+    /// [ContactsDelta::delta_token] of the result for the next sync.
+    ///
+    /// Works for the contacts of the default folder and of a contact folder.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let delta = client.me().contacts().sync_contacts(None).await?;
+    /// for contact in delta.value.iter() {
+    ///     println!("{:?} {:?}", contact.id, contact.display_name);
+    /// }
+    ///
+    /// // Later, get only the changes.
+    /// let delta = client
+    ///     .me()
+    ///     .contact_folder(FOLDER_ID)
+    ///     .contacts()
+    ///     .sync_contacts(delta.delta_token().as_deref())
+    ///     .await?;
+    /// ```
+    pub async fn sync_contacts(
+        &self,
+        delta_token: Option<&str>,
+    ) -> GraphResult<ContactsDelta<Contact>> {
+        sync_delta(self.delta(), delta_token).await
+    }
+}
+
+impl ContactFoldersApiClient {
+    /// Get the contact folders created, updated or deleted since the delta token
+    /// of a previous sync, or all contact folders when there is no delta token.
+    pub async fn sync_contact_folders(
+        &self,
+        delta_token: Option<&str>,
+    ) -> GraphResult<ContactsDelta<ContactFolder>> {
+        sync_delta(self.delta(), delta_token).await
+    }
+}
+
+async fn sync_delta<T: DeserializeOwned>(
+    mut request: RequestHandler,
+    delta_token: Option<&str>,
+) -> GraphResult<ContactsDelta<T>> {
+    if let Some(delta_token) = delta_token {
+        request = request.append_query_pair("$deltatoken", delta_token);
+    }
+
+    let mut delta = ContactsDelta {
+        value: Vec::new(),
+        next_link: None,
+        delta_link: None,
+    };
+    for page in request.paging().json::<ContactsDelta<T>>().await? {
+        let page = page.into_body()?;
+        delta.value.extend(page.value);
+        if page.delta_link.is_some() {
+            delta.delta_link = page.delta_link;
+        }
+    }
+    Ok(delta)
+}
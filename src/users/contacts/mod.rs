@@ -1,3 +1,8 @@
+mod contact;
+mod manual_request;
 mod request;
 
+pub use contact::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+use crate::users::{Recipient, TimeSlot};
+
+/// The body of the accept, decline and tentativelyAccept actions of an event.
+///
+/// # Example
+/// ```rust,ignore
+/// use time::macros::datetime;
+///
+/// let response = EventResponse::new()
+///     .comment("I have a conflict, can we meet later?")
+///     .propose_new_time(TimeSlot::new(
+///         DateTimeTimeZone::new(datetime!(2024-03-20 15:00), "Pacific Standard Time"),
+///         DateTimeTimeZone::new(datetime!(2024-03-20 16:00), "Pacific Standard Time"),
+///     ));
+///
+/// client
+///     .me()
+///     .event(EVENT_ID)
+///     .tentatively_accept_event(&response)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EventResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Whether to send a response to the organizer. Defaults to true.
+    #[serde(rename = "sendResponse")]
+    pub send_response: bool,
+    /// A new time for the meeting proposed to the organizer. Only used by decline
+    /// and tentativelyAccept, and requires `send_response` to be true.
+    #[serde(rename = "proposedNewTime", skip_serializing_if = "Option::is_none")]
+    pub proposed_new_time: Option<TimeSlot>,
+}
+
+impl EventResponse {
+    pub fn new() -> EventResponse {
+        EventResponse {
+            comment: None,
+            send_response: true,
+            proposed_new_time: None,
+        }
+    }
+
+    pub fn comment(mut self, comment: impl ToString) -> Self {
+        self.comment = Some(comment.to_string());
+        self
+    }
+
+    pub fn send_response(mut self, send_response: bool) -> Self {
+        self.send_response = send_response;
+        self
+    }
+
+    pub fn propose_new_time(mut self, time_slot: TimeSlot) -> Self {
+        self.proposed_new_time = Some(time_slot);
+        self
+    }
+}
+
+impl Default for EventResponse {
+    fn default() -> Self {
+        EventResponse::new()
+    }
+}
+
+/// The body of the forward action of an event.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EventForward {
+    #[serde(rename = "toRecipients")]
+    pub to_recipients: Vec<Recipient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl EventForward {
+    pub fn new(address: impl ToString) -> EventForward {
+        EventForward {
+            to_recipients: vec![Recipient::new(address)],
+            comment: None,
+        }
+    }
+
+    pub fn to(mut self, address: impl ToString) -> Self {
+        self.to_recipients.push(Recipient::new(address));
+        self
+    }
+
+    pub fn recipient(mut self, recipient: Recipient) -> Self {
+        self.to_recipients.push(recipient);
+        self
+    }
+
+    pub fn comment(mut self, comment: impl ToString) -> Self {
+        self.comment = Some(comment.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datetime::DateTimeTimeZone;
+    use time::macros::datetime;
+
+    #[test]
+    fn serialize_event_response() {
+        let response = EventResponse::new()
+            .comment("Can we meet later?")
+            .propose_new_time(TimeSlot::new(
+                DateTimeTimeZone::new(datetime!(2024-03-20 15:00), "UTC"),
+                DateTimeTimeZone::new(datetime!(2024-03-20 16:00), "UTC"),
+            ));
+        assert_eq!(
+            serde_json::json!({
+                "comment": "Can we meet later?",
+                "sendResponse": true,
+                "proposedNewTime": {
+                    "start": { "dateTime": "2024-03-20T15:00:00", "timeZone": "UTC" },
+                    "end": { "dateTime": "2024-03-20T16:00:00", "timeZone": "UTC" }
+                }
+            }),
+            serde_json::to_value(&response).unwrap()
+        );
+
+        let forward = EventForward::new("adele@contoso.com").comment("FYI");
+        assert_eq!(
+            serde_json::json!({
+                "toRecipients": [{ "emailAddress": { "address": "adele@contoso.com" } }],
+                "comment": "FYI"
+            }),
+            serde_json::to_value(&forward).unwrap()
+        );
+    }
+}
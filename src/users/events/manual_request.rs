@@ -0,0 +1,38 @@
+use crate::api_default_imports::*;
+use crate::users::*;
+
+impl EventsIdApiClient {
+    /// Accept the event. A proposed new time on the response is not sent because
+    /// it can only be proposed when declining or tentatively accepting.
+    pub fn accept_event(&self, response: &EventResponse) -> RequestHandler {
+        self.accept(&EventResponse {
+            proposed_new_time: None,
+            ..response.clone()
+        })
+    }
+
+    /// Decline the event, optionally proposing a new time to the organizer.
+    pub fn decline_event(&self, response: &EventResponse) -> RequestHandler {
+        self.decline(response)
+    }
+
+    /// Tentatively accept the event, optionally proposing a new time to the organizer.
+    pub fn tentatively_accept_event(&self, response: &EventResponse) -> RequestHandler {
+        self.tentatively_accept(response)
+    }
+
+    /// Forward the meeting request to the recipients.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// client
+    ///     .me()
+    ///     .event(EVENT_ID)
+    ///     .forward_event(&EventForward::new("adele@contoso.com").comment("FYI"))
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn forward_event(&self, forward: &EventForward) -> RequestHandler {
+        self.forward(forward)
+    }
+}
@@ -1,3 +1,8 @@
+mod event_response;
+mod manual_request;
 mod request;
 
+pub use event_response::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
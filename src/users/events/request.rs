@@ -32,6 +32,8 @@ impl EventsApiClient {
 impl EventsIdApiClient {
     api_client_link_id!(instance, EventsInstancesIdApiClient);
     api_client_link!(instances, EventsInstancesApiClient);
+    api_client_link_id!(attachment, UsersAttachmentsIdApiClient);
+    api_client_link!(attachments, UsersAttachmentsApiClient);
 
     delete!(
         doc: "Delete navigation property events for users",
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::users::EmailAddress;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InferenceClassificationType {
+    Focused,
+    Other,
+}
+
+/// An inferenceClassificationOverride that always classifies the messages from a
+/// sender as Focused or Other.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InferenceClassificationOverride {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "classifyAs")]
+    pub classify_as: InferenceClassificationType,
+    #[serde(rename = "senderEmailAddress")]
+    pub sender_email_address: EmailAddress,
+}
+
+impl InferenceClassificationOverride {
+    pub fn new(
+        sender_address: impl ToString,
+        classify_as: InferenceClassificationType,
+    ) -> InferenceClassificationOverride {
+        InferenceClassificationOverride {
+            id: None,
+            classify_as,
+            sender_email_address: EmailAddress {
+                address: sender_address.to_string(),
+                name: None,
+            },
+        }
+    }
+
+    pub fn focused(sender_address: impl ToString) -> InferenceClassificationOverride {
+        InferenceClassificationOverride::new(sender_address, InferenceClassificationType::Focused)
+    }
+
+    pub fn other(sender_address: impl ToString) -> InferenceClassificationOverride {
+        InferenceClassificationOverride::new(sender_address, InferenceClassificationType::Other)
+    }
+}
@@ -0,0 +1,47 @@
+use futures::TryStreamExt;
+
+use crate::api_default_imports::*;
+use crate::users::{InferenceClassificationApiClient, InferenceClassificationOverride};
+
+impl InferenceClassificationApiClient {
+    /// The senders whose messages are always classified as Focused or Other, from
+    /// every page of the response.
+    pub async fn list_classification_overrides(
+        &self,
+    ) -> GraphResult<Vec<InferenceClassificationOverride>> {
+        self.list_overrides()
+            .paging()
+            .stream_items::<InferenceClassificationOverride>()?
+            .try_collect()
+            .await
+    }
+
+    /// Always classify messages from the sender as Focused or Other. If an override
+    /// already exists for the sender it is updated.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// client
+    ///     .me()
+    ///     .inference_classification()
+    ///     .override_sender(&InferenceClassificationOverride::focused("randiw@contoso.com"))
+    ///     .await?;
+    /// ```
+    pub async fn override_sender(
+        &self,
+        classification_override: &InferenceClassificationOverride,
+    ) -> GraphResult<InferenceClassificationOverride> {
+        let response = self
+            .create_overrides(classification_override)
+            .send()
+            .await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+
+    /// Remove the override so the messages from the sender are classified automatically.
+    pub async fn remove_classification_override(&self, override_id: &str) -> GraphResult<()> {
+        let response = self.delete_overrides(override_id).send().await?;
+        Paging::success_response(response).await?;
+        Ok(())
+    }
+}
@@ -1,3 +1,8 @@
+mod classification_override;
+mod manual_request;
 mod request;
 
+pub use classification_override::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -1,3 +1,5 @@
+mod models;
 mod request;
 
+pub use models::*;
 pub use request::*;
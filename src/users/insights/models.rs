@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::collection::Collection;
+use crate::datetime::OffsetDateTime;
+
+/// An email address of a person ranked by relevance to the signed in user.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoredEmailAddress {
+    pub address: Option<String>,
+    /// Higher values mean the address is more relevant to the user.
+    pub relevance_score: Option<f64>,
+    pub selection_likelihood: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonType {
+    pub class: Option<String>,
+    pub subclass: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Phone {
+    #[serde(rename = "type")]
+    pub phone_type: Option<String>,
+    pub number: Option<String>,
+}
+
+/// A person returned from `/me/people` or `/users/{id}/people`, ordered by
+/// relevance to the user.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub id: Option<String>,
+    pub display_name: Option<String>,
+    pub given_name: Option<String>,
+    pub surname: Option<String>,
+    pub birthday: Option<String>,
+    pub person_notes: Option<String>,
+    pub is_favorite: Option<bool>,
+    pub job_title: Option<String>,
+    pub company_name: Option<String>,
+    pub yomi_company: Option<String>,
+    pub department: Option<String>,
+    pub office_location: Option<String>,
+    pub profession: Option<String>,
+    pub user_principal_name: Option<String>,
+    pub im_address: Option<String>,
+    #[serde(default)]
+    pub scored_email_addresses: Vec<ScoredEmailAddress>,
+    #[serde(default)]
+    pub phones: Vec<Phone>,
+    pub person_type: Option<PersonType>,
+}
+
+impl Person {
+    /// The email address with the highest relevance score.
+    pub fn most_relevant_email(&self) -> Option<&ScoredEmailAddress> {
+        self.scored_email_addresses.iter().max_by(|a, b| {
+            a.relevance_score
+                .unwrap_or_default()
+                .total_cmp(&b.relevance_score.unwrap_or_default())
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceVisualization {
+    pub title: Option<String>,
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    pub media_type: Option<String>,
+    pub preview_image_url: Option<String>,
+    pub preview_text: Option<String>,
+    pub container_web_url: Option<String>,
+    pub container_display_name: Option<String>,
+    pub container_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceReference {
+    pub web_url: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+}
+
+/// A document trending around the user.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trending {
+    pub id: Option<String>,
+    /// How much the document is trending. Higher values mean the document is
+    /// more relevant to the user.
+    #[serde(default)]
+    pub weight: f64,
+    pub resource_visualization: Option<ResourceVisualization>,
+    pub resource_reference: Option<ResourceReference>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageDetails {
+    #[serde(default, with = "crate::datetime::iso8601::option")]
+    pub last_accessed_date_time: Option<OffsetDateTime>,
+    #[serde(default, with = "crate::datetime::iso8601::option")]
+    pub last_modified_date_time: Option<OffsetDateTime>,
+}
+
+/// A document viewed or modified by the user.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsedInsight {
+    pub id: Option<String>,
+    pub last_used: Option<UsageDetails>,
+    pub resource_visualization: Option<ResourceVisualization>,
+    pub resource_reference: Option<ResourceReference>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsightIdentity {
+    pub id: Option<String>,
+    pub display_name: Option<String>,
+    pub address: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharingDetail {
+    pub shared_by: Option<InsightIdentity>,
+    #[serde(default, with = "crate::datetime::iso8601::option")]
+    pub shared_date_time: Option<OffsetDateTime>,
+    pub sharing_subject: Option<String>,
+    pub sharing_type: Option<String>,
+    pub sharing_reference: Option<ResourceReference>,
+}
+
+/// A document shared with or by the user.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedInsight {
+    pub id: Option<String>,
+    pub last_shared: Option<SharingDetail>,
+    #[serde(default)]
+    pub sharing_history: Vec<SharingDetail>,
+    pub resource_visualization: Option<ResourceVisualization>,
+    pub resource_reference: Option<ResourceReference>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_people() {
+        let people: Collection<Person> = serde_json::from_value(serde_json::json!({
+            "@odata.nextLink": "https://graph.microsoft.com/v1.0/me/people?$skip=10",
+            "value": [{
+                "id": "8CE6E1DE-CB84-4BF5-971D-D3ECF452E2B5",
+                "displayName": "Lorrie Frye",
+                "scoredEmailAddresses": [
+                    { "address": "lorrief@contoso.com", "relevanceScore": 8.0 },
+                    { "address": "lorrie@contoso.com", "relevanceScore": 20.0 }
+                ],
+                "personType": { "class": "Person", "subclass": "OrganizationUser" }
+            }]
+        }))
+        .unwrap();
+
+        assert!(people.next_link.is_some());
+        let person = &people.value[0];
+        assert_eq!(Some("Lorrie Frye".into()), person.display_name);
+        assert_eq!(
+            Some("lorrie@contoso.com".into()),
+            person.most_relevant_email().unwrap().address
+        );
+    }
+
+    #[test]
+    fn deserialize_trending() {
+        let trending: Collection<Trending> = serde_json::from_value(serde_json::json!({
+            "value": [{
+                "id": "id",
+                "weight": 0.6,
+                "resourceVisualization": { "title": "Report", "type": "Word" },
+                "resourceReference": { "webUrl": "https://contoso.com/report.docx" }
+            }]
+        }))
+        .unwrap();
+
+        let item = &trending.value[0];
+        assert_eq!(0.6, item.weight);
+        assert_eq!(
+            Some("Word".into()),
+            item.resource_visualization.as_ref().unwrap().resource_type
+        );
+    }
+}
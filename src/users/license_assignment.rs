@@ -0,0 +1,257 @@
+use std::sync::{Arc, Mutex};
+
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::api_default_imports::*;
+use crate::GraphClient;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ServicePlanInfo {
+    #[serde(rename = "servicePlanId")]
+    pub service_plan_id: String,
+    #[serde(rename = "servicePlanName")]
+    pub service_plan_name: String,
+    #[serde(rename = "provisioningStatus", skip_serializing_if = "Option::is_none")]
+    pub provisioning_status: Option<String>,
+    #[serde(rename = "appliesTo", skip_serializing_if = "Option::is_none")]
+    pub applies_to: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LicenseUnitsDetail {
+    #[serde(default)]
+    pub enabled: i64,
+    #[serde(default)]
+    pub suspended: i64,
+    #[serde(default)]
+    pub warning: i64,
+}
+
+/// A subscribedSku of the tenant.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SubscribedSku {
+    #[serde(rename = "skuId")]
+    pub sku_id: String,
+    #[serde(rename = "skuPartNumber")]
+    pub sku_part_number: String,
+    #[serde(rename = "capabilityStatus", skip_serializing_if = "Option::is_none")]
+    pub capability_status: Option<String>,
+    #[serde(rename = "consumedUnits", default)]
+    pub consumed_units: i64,
+    #[serde(rename = "prepaidUnits", skip_serializing_if = "Option::is_none")]
+    pub prepaid_units: Option<LicenseUnitsDetail>,
+    #[serde(rename = "servicePlans", default)]
+    pub service_plans: Vec<ServicePlanInfo>,
+}
+
+impl SubscribedSku {
+    /// The id of the service plan with the given name, such as `EXCHANGE_S_ENTERPRISE`.
+    pub fn service_plan_id(&self, service_plan_name: &str) -> Option<&str> {
+        self.service_plans
+            .iter()
+            .find(|plan| {
+                plan.service_plan_name
+                    .eq_ignore_ascii_case(service_plan_name)
+            })
+            .map(|plan| plan.service_plan_id.as_str())
+    }
+
+    /// Number of enabled units that have not been assigned.
+    pub fn available_units(&self) -> i64 {
+        self.prepaid_units
+            .as_ref()
+            .map(|units| units.enabled - self.consumed_units)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AssignedLicense {
+    #[serde(rename = "skuId")]
+    pub sku_id: String,
+    #[serde(rename = "disabledPlans", default)]
+    pub disabled_plans: Vec<String>,
+}
+
+/// The user returned by the assignLicense action.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LicensedUser {
+    pub id: String,
+    #[serde(rename = "userPrincipalName", skip_serializing_if = "Option::is_none")]
+    pub user_principal_name: Option<String>,
+    #[serde(rename = "assignedLicenses", default)]
+    pub assigned_licenses: Vec<AssignedLicense>,
+}
+
+/// Assign and remove user licenses using SKU part numbers and service plan names,
+/// such as `ENTERPRISEPACK` and `SWAY`, instead of their ids.
+///
+/// The subscribed SKUs of the tenant are requested once and cached. Use
+/// [LicenseManager::refresh_skus] to request them again.
+///
+/// # Example
+/// ```rust,ignore
+/// let licenses = LicenseManager::new(&client);
+///
+/// let user = licenses
+///     .assign_license(USER_ID, "ENTERPRISEPACK", &["SWAY", "YAMMER_ENTERPRISE"])
+///     .await?;
+/// println!("{:#?}", user.assigned_licenses);
+///
+/// licenses.remove_license(USER_ID, "ENTERPRISEPACK").await?;
+/// ```
+pub struct LicenseManager {
+    client: GraphClient,
+    skus: Mutex<Option<Arc<Vec<SubscribedSku>>>>,
+}
+
+impl LicenseManager {
+    pub fn new(client: &GraphClient) -> LicenseManager {
+        LicenseManager {
+            client: client.clone(),
+            skus: Mutex::new(None),
+        }
+    }
+
+    /// The subscribed SKUs of the tenant, requested on first use.
+    pub async fn skus(&self) -> GraphResult<Arc<Vec<SubscribedSku>>> {
+        if let Some(skus) = self.skus.lock().unwrap().as_ref() {
+            return Ok(skus.clone());
+        }
+        self.refresh_skus().await
+    }
+
+    /// Request the subscribed SKUs of the tenant and replace the cached SKUs.
+    pub async fn refresh_skus(&self) -> GraphResult<Arc<Vec<SubscribedSku>>> {
+        let skus: Vec<SubscribedSku> = self
+            .client
+            .subscribed_skus()
+            .list_subscribed_sku()
+            .paging()
+            .stream_items::<SubscribedSku>()?
+            .try_collect()
+            .await?;
+        let skus = Arc::new(skus);
+        *self.skus.lock().unwrap() = Some(skus.clone());
+        Ok(skus)
+    }
+
+    /// Get the subscribed SKU with the part number, such as `ENTERPRISEPACK`.
+    pub async fn sku(&self, sku_part_number: &str) -> GraphResult<SubscribedSku> {
+        find_sku(&self.skus().await?, sku_part_number).cloned()
+    }
+
+    /// Assign the license for the SKU part number to the user with the service
+    /// plans given by name disabled.
+    pub async fn assign_license<T: AsRef<str>>(
+        &self,
+        user_id: &str,
+        sku_part_number: &str,
+        disabled_plans: &[T],
+    ) -> GraphResult<LicensedUser> {
+        let skus = self.skus().await?;
+        let body = assign_license_body(find_sku(&skus, sku_part_number)?, disabled_plans)?;
+        self.send(user_id, &body).await
+    }
+
+    /// Remove the license for the SKU part number from the user.
+    pub async fn remove_license(
+        &self,
+        user_id: &str,
+        sku_part_number: &str,
+    ) -> GraphResult<LicensedUser> {
+        let skus = self.skus().await?;
+        let sku = find_sku(&skus, sku_part_number)?;
+        let body = serde_json::json!({
+            "addLicenses": [],
+            "removeLicenses": [sku.sku_id]
+        });
+        self.send(user_id, &body).await
+    }
+
+    async fn send(&self, user_id: &str, body: &serde_json::Value) -> GraphResult<LicensedUser> {
+        let response = self
+            .client
+            .user(user_id)
+            .assign_license(body)
+            .send()
+            .await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn find_sku<'a>(
+    skus: &'a [SubscribedSku],
+    sku_part_number: &str,
+) -> GraphResult<&'a SubscribedSku> {
+    skus.iter()
+        .find(|sku| sku.sku_part_number.eq_ignore_ascii_case(sku_part_number))
+        .ok_or_else(|| {
+            GraphFailure::not_found(&format!("subscribed sku not found: {sku_part_number}"))
+        })
+}
+
+// The assignLicense body adding the SKU with the service plans given by name disabled.
+#[allow(clippy::result_large_err)]
+fn assign_license_body<T: AsRef<str>>(
+    sku: &SubscribedSku,
+    disabled_plans: &[T],
+) -> GraphResult<serde_json::Value> {
+    let disabled_plans = disabled_plans
+        .iter()
+        .map(|name| {
+            sku.service_plan_id(name.as_ref()).ok_or_else(|| {
+                GraphFailure::not_found(&format!(
+                    "service plan {} not found in subscribed sku {}",
+                    name.as_ref(),
+                    sku.sku_part_number
+                ))
+            })
+        })
+        .collect::<GraphResult<Vec<&str>>>()?;
+
+    Ok(serde_json::json!({
+        "addLicenses": [{
+            "skuId": sku.sku_id,
+            "disabledPlans": disabled_plans
+        }],
+        "removeLicenses": []
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn assign_license_body_resolves_names() {
+        let skus: Vec<SubscribedSku> = serde_json::from_value(serde_json::json!([{
+            "skuId": "6fd2c87f-b296-42f0-b197-1e91e994b900",
+            "skuPartNumber": "ENTERPRISEPACK",
+            "consumedUnits": 14,
+            "prepaidUnits": { "enabled": 25, "suspended": 0, "warning": 0 },
+            "servicePlans": [
+                { "servicePlanId": "a23b959c-7ce8-4e57-9140-b90eb88a9e97", "servicePlanName": "SWAY" },
+                { "servicePlanId": "7547a3fe-08ee-4ccb-b430-5077c5041653", "servicePlanName": "YAMMER_ENTERPRISE" }
+            ]
+        }]))
+        .unwrap();
+
+        let sku = find_sku(&skus, "enterprisepack").unwrap();
+        assert_eq!(11, sku.available_units());
+        assert_eq!(
+            serde_json::json!({
+                "addLicenses": [{
+                    "skuId": "6fd2c87f-b296-42f0-b197-1e91e994b900",
+                    "disabledPlans": ["a23b959c-7ce8-4e57-9140-b90eb88a9e97"]
+                }],
+                "removeLicenses": []
+            }),
+            assign_license_body(sku, &["SWAY"]).unwrap()
+        );
+        assert!(assign_license_body(sku, &["UNKNOWN"]).is_err());
+        assert!(find_sku(&skus, "SPE_E5").is_err());
+    }
+}
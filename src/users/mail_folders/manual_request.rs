@@ -0,0 +1,33 @@
+use futures::TryStreamExt;
+
+use crate::api_default_imports::*;
+use crate::users::{MailFoldersIdApiClient, MessageRule};
+
+impl MailFoldersIdApiClient {
+    /// The message rules of the folder from every page of the response. Rules are
+    /// only supported for the inbox.
+    pub async fn list_rules(&self) -> GraphResult<Vec<MessageRule>> {
+        self.list_message_rules()
+            .paging()
+            .stream_items::<MessageRule>()?
+            .try_collect()
+            .await
+    }
+
+    pub async fn create_rule(&self, rule: &MessageRule) -> GraphResult<MessageRule> {
+        let response = self.create_message_rules(rule).send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+
+    /// Update the properties of the rule that are set.
+    pub async fn update_rule(&self, rule_id: &str, rule: &MessageRule) -> GraphResult<MessageRule> {
+        let response = self.update_message_rules(rule_id, rule).send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+
+    pub async fn delete_rule(&self, rule_id: &str) -> GraphResult<()> {
+        let response = self.delete_message_rules(rule_id).send().await?;
+        Paging::success_response(response).await?;
+        Ok(())
+    }
+}
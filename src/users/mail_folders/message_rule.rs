@@ -0,0 +1,307 @@
+use serde::{Deserialize, Serialize};
+
+use crate::users::{Importance, Recipient};
+
+/// The conditions of a message rule or its exceptions. All conditions that are
+/// set must match.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MessageRulePredicates {
+    #[serde(
+        rename = "fromAddresses",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub from_addresses: Vec<Recipient>,
+    #[serde(
+        rename = "sentToAddresses",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub sent_to_addresses: Vec<Recipient>,
+    #[serde(
+        rename = "subjectContains",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub subject_contains: Vec<String>,
+    #[serde(
+        rename = "bodyContains",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub body_contains: Vec<String>,
+    #[serde(
+        rename = "senderContains",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub sender_contains: Vec<String>,
+    #[serde(rename = "importance", skip_serializing_if = "Option::is_none")]
+    pub importance: Option<Importance>,
+    #[serde(rename = "hasAttachments", skip_serializing_if = "Option::is_none")]
+    pub has_attachments: Option<bool>,
+    #[serde(rename = "sentToMe", skip_serializing_if = "Option::is_none")]
+    pub sent_to_me: Option<bool>,
+    #[serde(rename = "sentOnlyToMe", skip_serializing_if = "Option::is_none")]
+    pub sent_only_to_me: Option<bool>,
+    #[serde(rename = "sentCcMe", skip_serializing_if = "Option::is_none")]
+    pub sent_cc_me: Option<bool>,
+}
+
+impl MessageRulePredicates {
+    pub fn from_address(mut self, address: impl ToString) -> Self {
+        self.from_addresses.push(Recipient::new(address));
+        self
+    }
+
+    pub fn sent_to_address(mut self, address: impl ToString) -> Self {
+        self.sent_to_addresses.push(Recipient::new(address));
+        self
+    }
+
+    pub fn subject_contains(mut self, text: impl ToString) -> Self {
+        self.subject_contains.push(text.to_string());
+        self
+    }
+
+    pub fn body_contains(mut self, text: impl ToString) -> Self {
+        self.body_contains.push(text.to_string());
+        self
+    }
+
+    pub fn sender_contains(mut self, text: impl ToString) -> Self {
+        self.sender_contains.push(text.to_string());
+        self
+    }
+
+    pub fn importance(mut self, importance: Importance) -> Self {
+        self.importance = Some(importance);
+        self
+    }
+
+    pub fn has_attachments(mut self, has_attachments: bool) -> Self {
+        self.has_attachments = Some(has_attachments);
+        self
+    }
+
+    pub fn sent_to_me(mut self, sent_to_me: bool) -> Self {
+        self.sent_to_me = Some(sent_to_me);
+        self
+    }
+
+    pub fn sent_only_to_me(mut self, sent_only_to_me: bool) -> Self {
+        self.sent_only_to_me = Some(sent_only_to_me);
+        self
+    }
+
+    pub fn sent_cc_me(mut self, sent_cc_me: bool) -> Self {
+        self.sent_cc_me = Some(sent_cc_me);
+        self
+    }
+}
+
+/// The actions taken on a message that matches the conditions of a rule.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MessageRuleActions {
+    #[serde(rename = "moveToFolder", skip_serializing_if = "Option::is_none")]
+    pub move_to_folder: Option<String>,
+    #[serde(rename = "copyToFolder", skip_serializing_if = "Option::is_none")]
+    pub copy_to_folder: Option<String>,
+    #[serde(rename = "forwardTo", default, skip_serializing_if = "Vec::is_empty")]
+    pub forward_to: Vec<Recipient>,
+    #[serde(rename = "redirectTo", default, skip_serializing_if = "Vec::is_empty")]
+    pub redirect_to: Vec<Recipient>,
+    #[serde(
+        rename = "assignCategories",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub assign_categories: Vec<String>,
+    #[serde(rename = "markAsRead", skip_serializing_if = "Option::is_none")]
+    pub mark_as_read: Option<bool>,
+    #[serde(rename = "markImportance", skip_serializing_if = "Option::is_none")]
+    pub mark_importance: Option<Importance>,
+    #[serde(rename = "delete", skip_serializing_if = "Option::is_none")]
+    pub delete: Option<bool>,
+    #[serde(
+        rename = "stopProcessingRules",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub stop_processing_rules: Option<bool>,
+}
+
+impl MessageRuleActions {
+    /// Move the message to the folder with the id.
+    pub fn move_to_folder(mut self, folder_id: impl ToString) -> Self {
+        self.move_to_folder = Some(folder_id.to_string());
+        self
+    }
+
+    /// Copy the message to the folder with the id.
+    pub fn copy_to_folder(mut self, folder_id: impl ToString) -> Self {
+        self.copy_to_folder = Some(folder_id.to_string());
+        self
+    }
+
+    pub fn forward_to(mut self, address: impl ToString) -> Self {
+        self.forward_to.push(Recipient::new(address));
+        self
+    }
+
+    pub fn redirect_to(mut self, address: impl ToString) -> Self {
+        self.redirect_to.push(Recipient::new(address));
+        self
+    }
+
+    pub fn assign_category(mut self, category: impl ToString) -> Self {
+        self.assign_categories.push(category.to_string());
+        self
+    }
+
+    pub fn mark_as_read(mut self) -> Self {
+        self.mark_as_read = Some(true);
+        self
+    }
+
+    pub fn mark_importance(mut self, importance: Importance) -> Self {
+        self.mark_importance = Some(importance);
+        self
+    }
+
+    /// Move the message to the Deleted Items folder.
+    pub fn delete(mut self) -> Self {
+        self.delete = Some(true);
+        self
+    }
+
+    /// Do not evaluate the rules with a higher sequence after this rule.
+    pub fn stop_processing_rules(mut self) -> Self {
+        self.stop_processing_rules = Some(true);
+        self
+    }
+}
+
+/// An inbox messageRule. Use [MessageRule::builder] for the body of
+/// `create_message_rules` and `update_message_rules`.
+///
+/// # Example
+/// ```rust,ignore
+/// let rule = MessageRule::builder("From partner", 2)
+///     .conditions(MessageRulePredicates::default().from_address("danas@contoso.com"))
+///     .actions(
+///         MessageRuleActions::default()
+///             .forward_to("alexw@contoso.com")
+///             .stop_processing_rules(),
+///     )
+///     .build();
+///
+/// let rule = client.me().mail_folder("inbox").create_rule(&rule).await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MessageRule {
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The order the rule is run in relative to the other rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<i32>,
+    #[serde(rename = "isEnabled", skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<MessageRulePredicates>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exceptions: Option<MessageRulePredicates>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<MessageRuleActions>,
+    #[serde(rename = "hasError", skip_serializing)]
+    pub has_error: Option<bool>,
+    #[serde(rename = "isReadOnly", skip_serializing)]
+    pub is_read_only: Option<bool>,
+}
+
+impl MessageRule {
+    /// An enabled rule with the name and sequence.
+    pub fn builder(display_name: impl ToString, sequence: i32) -> MessageRuleBuilder {
+        MessageRuleBuilder {
+            rule: MessageRule {
+                display_name: Some(display_name.to_string()),
+                sequence: Some(sequence),
+                is_enabled: Some(true),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MessageRuleBuilder {
+    rule: MessageRule,
+}
+
+impl MessageRuleBuilder {
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.rule.is_enabled = Some(is_enabled);
+        self
+    }
+
+    pub fn conditions(mut self, conditions: MessageRulePredicates) -> Self {
+        self.rule.conditions = Some(conditions);
+        self
+    }
+
+    /// Messages that match the exceptions are not processed by the rule.
+    pub fn exceptions(mut self, exceptions: MessageRulePredicates) -> Self {
+        self.rule.exceptions = Some(exceptions);
+        self
+    }
+
+    pub fn actions(mut self, actions: MessageRuleActions) -> Self {
+        self.rule.actions = Some(actions);
+        self
+    }
+
+    pub fn build(self) -> MessageRule {
+        self.rule
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_message_rule() {
+        let rule = MessageRule::builder("From partner", 2)
+            .conditions(
+                MessageRulePredicates::default()
+                    .from_address("danas@contoso.com")
+                    .has_attachments(true),
+            )
+            .actions(
+                MessageRuleActions::default()
+                    .forward_to("alexw@contoso.com")
+                    .mark_importance(Importance::High)
+                    .stop_processing_rules(),
+            )
+            .build();
+
+        assert_eq!(
+            serde_json::json!({
+                "displayName": "From partner",
+                "sequence": 2,
+                "isEnabled": true,
+                "conditions": {
+                    "fromAddresses": [{ "emailAddress": { "address": "danas@contoso.com" } }],
+                    "hasAttachments": true
+                },
+                "actions": {
+                    "forwardTo": [{ "emailAddress": { "address": "alexw@contoso.com" } }],
+                    "markImportance": "High",
+                    "stopProcessingRules": true
+                }
+            }),
+            serde_json::to_value(&rule).unwrap()
+        );
+    }
+}
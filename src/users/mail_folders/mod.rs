@@ -1,3 +1,8 @@
+mod manual_request;
+mod message_rule;
 mod request;
 
+#[allow(unused_imports)]
+pub use manual_request::*;
+pub use message_rule::*;
 pub use request::*;
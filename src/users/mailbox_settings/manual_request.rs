@@ -0,0 +1,30 @@
+use crate::api_default_imports::*;
+use crate::users::{AutomaticRepliesSetting, MailboxSettings, MailboxSettingsApiClient};
+
+impl MailboxSettingsApiClient {
+    /// Get the mailbox settings of the user.
+    pub async fn get_settings(&self) -> GraphResult<MailboxSettings> {
+        let response = self.get_mailbox_settings().send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+
+    /// Update the settings that are set and return the updated settings.
+    pub async fn update_settings(
+        &self,
+        settings: &MailboxSettings,
+    ) -> GraphResult<MailboxSettings> {
+        let response = self.update_mailbox_settings(settings).send().await?;
+        Ok(Paging::success_response(response).await?.json().await?)
+    }
+
+    /// Enable, schedule or disable the automatic replies of the user.
+    pub async fn set_automatic_replies(
+        &self,
+        automatic_replies: &AutomaticRepliesSetting,
+    ) -> GraphResult<MailboxSettings> {
+        let settings = MailboxSettings::builder()
+            .automatic_replies(automatic_replies.clone())
+            .build();
+        self.update_settings(&settings).await
+    }
+}
@@ -1,3 +1,8 @@
+mod manual_request;
 mod request;
+mod settings;
 
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
+pub use settings::*;
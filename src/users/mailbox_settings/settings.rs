@@ -0,0 +1,314 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::datetime::DateTimeTimeZone;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AutomaticRepliesStatus {
+    Disabled,
+    AlwaysEnabled,
+    Scheduled,
+}
+
+/// The senders outside of the organization that receive the external reply.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExternalAudienceScope {
+    None,
+    ContactsOnly,
+    All,
+}
+
+/// The out of office settings of a mailbox.
+///
+/// # Example
+/// ```rust,ignore
+/// let replies = AutomaticRepliesSetting::scheduled(
+///     DateTimeTimeZone::new(datetime!(2024-03-20 08:00), "Pacific Standard Time"),
+///     DateTimeTimeZone::new(datetime!(2024-03-28 17:00), "Pacific Standard Time"),
+/// )
+/// .internal_reply_message("I'm out of the office until March 28.")
+/// .external_reply_message("I'm out of the office until March 28.")
+/// .external_audience(ExternalAudienceScope::ContactsOnly);
+///
+/// client
+///     .user(USER_ID)
+///     .mailbox_settings()
+///     .set_automatic_replies(&replies)
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AutomaticRepliesSetting {
+    pub status: AutomaticRepliesStatus,
+    #[serde(rename = "externalAudience", skip_serializing_if = "Option::is_none")]
+    pub external_audience: Option<ExternalAudienceScope>,
+    #[serde(
+        rename = "scheduledStartDateTime",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub scheduled_start_date_time: Option<DateTimeTimeZone>,
+    #[serde(
+        rename = "scheduledEndDateTime",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub scheduled_end_date_time: Option<DateTimeTimeZone>,
+    #[serde(
+        rename = "internalReplyMessage",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub internal_reply_message: Option<String>,
+    #[serde(
+        rename = "externalReplyMessage",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub external_reply_message: Option<String>,
+}
+
+impl AutomaticRepliesSetting {
+    fn new(status: AutomaticRepliesStatus) -> AutomaticRepliesSetting {
+        AutomaticRepliesSetting {
+            status,
+            external_audience: None,
+            scheduled_start_date_time: None,
+            scheduled_end_date_time: None,
+            internal_reply_message: None,
+            external_reply_message: None,
+        }
+    }
+
+    pub fn disabled() -> AutomaticRepliesSetting {
+        AutomaticRepliesSetting::new(AutomaticRepliesStatus::Disabled)
+    }
+
+    pub fn always_enabled() -> AutomaticRepliesSetting {
+        AutomaticRepliesSetting::new(AutomaticRepliesStatus::AlwaysEnabled)
+    }
+
+    /// Automatic replies that are only sent between the start and end.
+    pub fn scheduled(start: DateTimeTimeZone, end: DateTimeTimeZone) -> AutomaticRepliesSetting {
+        let mut setting = AutomaticRepliesSetting::new(AutomaticRepliesStatus::Scheduled);
+        setting.scheduled_start_date_time = Some(start);
+        setting.scheduled_end_date_time = Some(end);
+        setting
+    }
+
+    /// The reply sent to senders in the organization. The message can contain html.
+    pub fn internal_reply_message(mut self, message: impl ToString) -> Self {
+        self.internal_reply_message = Some(message.to_string());
+        self
+    }
+
+    /// The reply sent to senders outside of the organization. The message can contain html.
+    pub fn external_reply_message(mut self, message: impl ToString) -> Self {
+        self.external_reply_message = Some(message.to_string());
+        self
+    }
+
+    pub fn external_audience(mut self, external_audience: ExternalAudienceScope) -> Self {
+        self.external_audience = Some(external_audience);
+        self
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DayOfWeek {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TimeZoneBase {
+    pub name: String,
+}
+
+/// The days and hours of the week the user works.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WorkingHours {
+    #[serde(rename = "daysOfWeek")]
+    pub days_of_week: Vec<DayOfWeek>,
+    /// The time of day the user starts working such as `08:00:00.0000000`.
+    #[serde(rename = "startTime")]
+    pub start_time: String,
+    #[serde(rename = "endTime")]
+    pub end_time: String,
+    #[serde(rename = "timeZone", skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<TimeZoneBase>,
+}
+
+impl WorkingHours {
+    /// Monday to Friday from `start_time` to `end_time` such as `08:00:00` and `17:00:00`.
+    pub fn weekdays(start_time: impl ToString, end_time: impl ToString) -> WorkingHours {
+        WorkingHours {
+            days_of_week: vec![
+                DayOfWeek::Monday,
+                DayOfWeek::Tuesday,
+                DayOfWeek::Wednesday,
+                DayOfWeek::Thursday,
+                DayOfWeek::Friday,
+            ],
+            start_time: start_time.to_string(),
+            end_time: end_time.to_string(),
+            time_zone: None,
+        }
+    }
+
+    pub fn days(mut self, days_of_week: &[DayOfWeek]) -> Self {
+        self.days_of_week = days_of_week.to_vec();
+        self
+    }
+
+    pub fn time_zone(mut self, name: impl ToString) -> Self {
+        self.time_zone = Some(TimeZoneBase {
+            name: name.to_string(),
+        });
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LocaleInfo {
+    /// The language and country such as `en-US`.
+    pub locale: String,
+    #[serde(rename = "displayName", skip_serializing)]
+    pub display_name: Option<String>,
+}
+
+/// The mailboxSettings of a user. Use [MailboxSettings::builder] for the body of
+/// `update_mailbox_settings` to only update the settings that are set.
+///
+/// # Example
+/// ```rust,ignore
+/// let settings = MailboxSettings::builder()
+///     .time_zone("Pacific Standard Time")
+///     .language("en-US")
+///     .working_hours(WorkingHours::weekdays("08:00:00", "17:00:00").time_zone("Pacific Standard Time"))
+///     .build();
+///
+/// let settings = client
+///     .user(USER_ID)
+///     .mailbox_settings()
+///     .update_settings(&settings)
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MailboxSettings {
+    #[serde(
+        rename = "automaticRepliesSetting",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub automatic_replies_setting: Option<AutomaticRepliesSetting>,
+    #[serde(rename = "workingHours", skip_serializing_if = "Option::is_none")]
+    pub working_hours: Option<WorkingHours>,
+    #[serde(rename = "timeZone", skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<LocaleInfo>,
+    #[serde(rename = "dateFormat", skip_serializing_if = "Option::is_none")]
+    pub date_format: Option<String>,
+    #[serde(rename = "timeFormat", skip_serializing_if = "Option::is_none")]
+    pub time_format: Option<String>,
+    #[serde(rename = "archiveFolder", skip_serializing)]
+    pub archive_folder: Option<String>,
+    #[serde(rename = "userPurpose", skip_serializing)]
+    pub user_purpose: Option<String>,
+}
+
+impl MailboxSettings {
+    pub fn builder() -> MailboxSettingsBuilder {
+        MailboxSettingsBuilder::default()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MailboxSettingsBuilder {
+    settings: MailboxSettings,
+}
+
+impl MailboxSettingsBuilder {
+    pub fn automatic_replies(mut self, automatic_replies: AutomaticRepliesSetting) -> Self {
+        self.settings.automatic_replies_setting = Some(automatic_replies);
+        self
+    }
+
+    pub fn working_hours(mut self, working_hours: WorkingHours) -> Self {
+        self.settings.working_hours = Some(working_hours);
+        self
+    }
+
+    /// The default time zone of the mailbox such as `Pacific Standard Time`.
+    pub fn time_zone(mut self, time_zone: impl ToString) -> Self {
+        self.settings.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    /// The locale of the mailbox such as `en-US`.
+    pub fn language(mut self, locale: impl ToString) -> Self {
+        self.settings.language = Some(LocaleInfo {
+            locale: locale.to_string(),
+            display_name: None,
+        });
+        self
+    }
+
+    pub fn date_format(mut self, date_format: impl ToString) -> Self {
+        self.settings.date_format = Some(date_format.to_string());
+        self
+    }
+
+    pub fn time_format(mut self, time_format: impl ToString) -> Self {
+        self.settings.time_format = Some(time_format.to_string());
+        self
+    }
+
+    pub fn build(self) -> MailboxSettings {
+        self.settings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn serialize_mailbox_settings() {
+        let settings = MailboxSettings::builder()
+            .automatic_replies(
+                AutomaticRepliesSetting::scheduled(
+                    DateTimeTimeZone::new(datetime!(2024-03-20 08:00), "UTC"),
+                    DateTimeTimeZone::new(datetime!(2024-03-28 17:00), "UTC"),
+                )
+                .internal_reply_message("Out of office")
+                .external_audience(ExternalAudienceScope::ContactsOnly),
+            )
+            .working_hours(WorkingHours::weekdays("08:00:00", "17:00:00").time_zone("UTC"))
+            .language("en-US")
+            .build();
+
+        assert_eq!(
+            serde_json::json!({
+                "automaticRepliesSetting": {
+                    "status": "scheduled",
+                    "externalAudience": "contactsOnly",
+                    "scheduledStartDateTime": { "dateTime": "2024-03-20T08:00:00", "timeZone": "UTC" },
+                    "scheduledEndDateTime": { "dateTime": "2024-03-28T17:00:00", "timeZone": "UTC" },
+                    "internalReplyMessage": "Out of office"
+                },
+                "workingHours": {
+                    "daysOfWeek": ["monday", "tuesday", "wednesday", "thursday", "friday"],
+                    "startTime": "08:00:00",
+                    "endTime": "17:00:00",
+                    "timeZone": { "name": "UTC" }
+                },
+                "language": { "locale": "en-US" }
+            }),
+            serde_json::to_value(&settings).unwrap()
+        );
+    }
+}
@@ -0,0 +1,549 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api_default_imports::*;
+use crate::datetime::DateTimeTimeZone;
+use crate::me::MeApiClient;
+use crate::users::{DefaultCalendarApiClient, EmailAddress, UsersIdApiClient, WorkingHours};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AttendeeType {
+    Required,
+    Optional,
+    Resource,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AttendeeBase {
+    #[serde(rename = "type")]
+    pub attendee_type: AttendeeType,
+    #[serde(rename = "emailAddress")]
+    pub email_address: EmailAddress,
+}
+
+impl AttendeeBase {
+    pub fn required(address: impl ToString) -> AttendeeBase {
+        AttendeeBase::new(AttendeeType::Required, address)
+    }
+
+    pub fn optional(address: impl ToString) -> AttendeeBase {
+        AttendeeBase::new(AttendeeType::Optional, address)
+    }
+
+    /// A resource such as a meeting room.
+    pub fn resource(address: impl ToString) -> AttendeeBase {
+        AttendeeBase::new(AttendeeType::Resource, address)
+    }
+
+    fn new(attendee_type: AttendeeType, address: impl ToString) -> AttendeeBase {
+        AttendeeBase {
+            attendee_type,
+            email_address: EmailAddress {
+                address: address.to_string(),
+                name: None,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TimeSlot {
+    pub start: DateTimeTimeZone,
+    pub end: DateTimeTimeZone,
+}
+
+impl TimeSlot {
+    pub fn new(start: DateTimeTimeZone, end: DateTimeTimeZone) -> TimeSlot {
+        TimeSlot { start, end }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActivityDomain {
+    /// Only during the work hours of the attendees.
+    Work,
+    /// Only outside the work hours of the attendees.
+    Personal,
+    /// At any time of the day.
+    Unrestricted,
+    Unknown,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TimeConstraint {
+    #[serde(rename = "activityDomain", skip_serializing_if = "Option::is_none")]
+    pub activity_domain: Option<ActivityDomain>,
+    #[serde(rename = "timeSlots")]
+    pub time_slots: Vec<TimeSlot>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LocationConstraintItem {
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(
+        rename = "locationEmailAddress",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub location_email_address: Option<String>,
+    /// Check the availability of the location, such as a room.
+    #[serde(rename = "resolveAvailability", default)]
+    pub resolve_availability: bool,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LocationConstraint {
+    #[serde(rename = "isRequired", default)]
+    pub is_required: bool,
+    #[serde(rename = "suggestLocation", default)]
+    pub suggest_location: bool,
+    #[serde(default)]
+    pub locations: Vec<LocationConstraintItem>,
+}
+
+/// The body of findMeetingTimes.
+///
+/// # Example
+/// ```rust,ignore
+/// use time::macros::datetime;
+///
+/// let find = FindMeetingTimes::new(time::Duration::hours(1))
+///     .attendee(AttendeeBase::required("alex@contoso.com"))
+///     .time_slot(TimeSlot::new(
+///         DateTimeTimeZone::new(datetime!(2024-03-20 09:00), "Pacific Standard Time"),
+///         DateTimeTimeZone::new(datetime!(2024-03-20 17:00), "Pacific Standard Time"),
+///     ))
+///     .room("Conf Room 100", "cf100@contoso.com")
+///     .max_candidates(5);
+///
+/// let result = client.me().find_meeting_time_suggestions(&find).await?;
+/// for suggestion in result.meeting_time_suggestions {
+///     println!("{:?}", suggestion.meeting_time_slot);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FindMeetingTimes {
+    #[serde(default)]
+    pub attendees: Vec<AttendeeBase>,
+    #[serde(rename = "timeConstraint", skip_serializing_if = "Option::is_none")]
+    pub time_constraint: Option<TimeConstraint>,
+    #[serde(rename = "locationConstraint", skip_serializing_if = "Option::is_none")]
+    pub location_constraint: Option<LocationConstraint>,
+    /// The length of the meeting as an ISO 8601 duration such as `PT1H30M`.
+    #[serde(rename = "meetingDuration")]
+    pub meeting_duration: String,
+    #[serde(rename = "maxCandidates", skip_serializing_if = "Option::is_none")]
+    pub max_candidates: Option<i32>,
+    #[serde(rename = "isOrganizerOptional", default)]
+    pub is_organizer_optional: bool,
+    #[serde(rename = "returnSuggestionReasons", default)]
+    pub return_suggestion_reasons: bool,
+    /// The percentage of attendees that must be available, from 0 to 100.
+    #[serde(
+        rename = "minimumAttendeePercentage",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub minimum_attendee_percentage: Option<f64>,
+}
+
+impl FindMeetingTimes {
+    pub fn new(meeting_duration: time::Duration) -> FindMeetingTimes {
+        FindMeetingTimes {
+            attendees: Vec::new(),
+            time_constraint: None,
+            location_constraint: None,
+            meeting_duration: format_duration(meeting_duration),
+            max_candidates: None,
+            is_organizer_optional: false,
+            return_suggestion_reasons: false,
+            minimum_attendee_percentage: None,
+        }
+    }
+
+    pub fn attendee(mut self, attendee: AttendeeBase) -> Self {
+        self.attendees.push(attendee);
+        self
+    }
+
+    /// Suggest times within the time slot. Can be called more than once.
+    pub fn time_slot(mut self, time_slot: TimeSlot) -> Self {
+        self.time_constraint
+            .get_or_insert_with(|| TimeConstraint {
+                activity_domain: None,
+                time_slots: Vec::new(),
+            })
+            .time_slots
+            .push(time_slot);
+        self
+    }
+
+    pub fn activity_domain(mut self, activity_domain: ActivityDomain) -> Self {
+        self.time_constraint
+            .get_or_insert_with(|| TimeConstraint {
+                activity_domain: None,
+                time_slots: Vec::new(),
+            })
+            .activity_domain = Some(activity_domain);
+        self
+    }
+
+    /// Suggest times when the room is available. Can be called more than once.
+    pub fn room(mut self, display_name: impl ToString, email_address: impl ToString) -> Self {
+        self.location_constraint
+            .get_or_insert_with(LocationConstraint::default)
+            .locations
+            .push(LocationConstraintItem {
+                display_name: display_name.to_string(),
+                location_email_address: Some(email_address.to_string()),
+                resolve_availability: true,
+            });
+        self
+    }
+
+    pub fn location_constraint(mut self, location_constraint: LocationConstraint) -> Self {
+        self.location_constraint = Some(location_constraint);
+        self
+    }
+
+    pub fn max_candidates(mut self, max_candidates: i32) -> Self {
+        self.max_candidates = Some(max_candidates);
+        self
+    }
+
+    pub fn is_organizer_optional(mut self, is_organizer_optional: bool) -> Self {
+        self.is_organizer_optional = is_organizer_optional;
+        self
+    }
+
+    pub fn return_suggestion_reasons(mut self, return_suggestion_reasons: bool) -> Self {
+        self.return_suggestion_reasons = return_suggestion_reasons;
+        self
+    }
+
+    pub fn minimum_attendee_percentage(mut self, percentage: f64) -> Self {
+        self.minimum_attendee_percentage = Some(percentage);
+        self
+    }
+}
+
+// The duration in ISO 8601 format, such as `PT1H30M`.
+fn format_duration(duration: time::Duration) -> String {
+    let minutes = duration.whole_minutes().max(0);
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("PT{minutes}M"),
+        (hours, 0) => format!("PT{hours}H"),
+        (hours, minutes) => format!("PT{hours}H{minutes}M"),
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FreeBusyStatus {
+    Free,
+    Tentative,
+    Busy,
+    Oof,
+    WorkingElsewhere,
+    #[serde(other)]
+    Unknown,
+}
+
+impl FreeBusyStatus {
+    /// The status of a digit of an availabilityView.
+    pub fn from_availability_view(value: char) -> FreeBusyStatus {
+        match value {
+            '0' => FreeBusyStatus::Free,
+            '1' => FreeBusyStatus::Tentative,
+            '2' => FreeBusyStatus::Busy,
+            '3' => FreeBusyStatus::Oof,
+            '4' => FreeBusyStatus::WorkingElsewhere,
+            _ => FreeBusyStatus::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    #[serde(rename = "displayName", default)]
+    pub display_name: Option<String>,
+    #[serde(rename = "locationEmailAddress", default)]
+    pub location_email_address: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AttendeeAvailability {
+    pub attendee: AttendeeBase,
+    pub availability: FreeBusyStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MeetingTimeSuggestion {
+    /// The likelihood that all attendees attend, from 0 to 100.
+    #[serde(default)]
+    pub confidence: f64,
+    #[serde(default)]
+    pub order: i32,
+    #[serde(rename = "organizerAvailability")]
+    pub organizer_availability: Option<FreeBusyStatus>,
+    #[serde(rename = "suggestionReason", default)]
+    pub suggestion_reason: Option<String>,
+    #[serde(rename = "meetingTimeSlot")]
+    pub meeting_time_slot: TimeSlot,
+    #[serde(rename = "attendeeAvailability", default)]
+    pub attendee_availability: Vec<AttendeeAvailability>,
+    #[serde(default)]
+    pub locations: Vec<Location>,
+}
+
+/// The response of findMeetingTimes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MeetingTimeSuggestionsResult {
+    #[serde(rename = "meetingTimeSuggestions", default)]
+    pub meeting_time_suggestions: Vec<MeetingTimeSuggestion>,
+    /// Why there are no suggestions, such as `attendeesUnavailable`. Empty when
+    /// there are suggestions.
+    #[serde(rename = "emptySuggestionsReason", default)]
+    pub empty_suggestions_reason: Option<String>,
+}
+
+/// The body of getSchedule.
+///
+/// # Example
+/// ```rust,ignore
+/// use time::macros::datetime;
+///
+/// let request = GetSchedule::new(
+///     &["alex@contoso.com", "cf100@contoso.com"],
+///     DateTimeTimeZone::new(datetime!(2024-03-20 09:00), "Pacific Standard Time"),
+///     DateTimeTimeZone::new(datetime!(2024-03-20 17:00), "Pacific Standard Time"),
+/// )
+/// .availability_view_interval(15);
+///
+/// let schedules = client.me().default_calendar().get_schedule_information(&request).await?;
+/// for schedule in schedules {
+///     for slot in schedule.availability_slots(&request) {
+///         println!("{} {:?}", schedule.schedule_id, slot);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GetSchedule {
+    /// The email addresses of users, distribution lists or resources.
+    pub schedules: Vec<String>,
+    #[serde(rename = "startTime")]
+    pub start_time: DateTimeTimeZone,
+    #[serde(rename = "endTime")]
+    pub end_time: DateTimeTimeZone,
+    /// The length in minutes of each time slot of the availabilityView. Defaults to 30.
+    #[serde(
+        rename = "availabilityViewInterval",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub availability_view_interval: Option<i64>,
+}
+
+impl GetSchedule {
+    pub fn new<T: ToString>(
+        schedules: &[T],
+        start_time: DateTimeTimeZone,
+        end_time: DateTimeTimeZone,
+    ) -> GetSchedule {
+        GetSchedule {
+            schedules: schedules.iter().map(|s| s.to_string()).collect(),
+            start_time,
+            end_time,
+            availability_view_interval: None,
+        }
+    }
+
+    pub fn availability_view_interval(mut self, minutes: i64) -> Self {
+        self.availability_view_interval = Some(minutes);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleItem {
+    pub status: FreeBusyStatus,
+    pub start: DateTimeTimeZone,
+    pub end: DateTimeTimeZone,
+    #[serde(default)]
+    pub subject: Option<String>,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(rename = "isPrivate", default)]
+    pub is_private: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FreeBusyError {
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(rename = "responseCode", default)]
+    pub response_code: Option<String>,
+}
+
+/// A span of the availabilityView with the same status.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AvailabilitySlot {
+    pub status: FreeBusyStatus,
+    pub start: DateTimeTimeZone,
+    pub end: DateTimeTimeZone,
+}
+
+/// The availability of one of the schedules of a getSchedule request.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleInformation {
+    #[serde(rename = "scheduleId")]
+    pub schedule_id: String,
+    /// One digit for each time slot of the request, such as `0022`. See
+    /// [ScheduleInformation::availability_slots].
+    #[serde(rename = "availabilityView", default)]
+    pub availability_view: String,
+    #[serde(rename = "scheduleItems", default)]
+    pub schedule_items: Vec<ScheduleItem>,
+    #[serde(rename = "workingHours", default)]
+    pub working_hours: Option<WorkingHours>,
+    /// Set when the availability of the schedule could not be returned.
+    #[serde(default)]
+    pub error: Option<FreeBusyError>,
+}
+
+impl ScheduleInformation {
+    pub fn availability(&self) -> Vec<FreeBusyStatus> {
+        self.availability_view
+            .chars()
+            .map(FreeBusyStatus::from_availability_view)
+            .collect()
+    }
+
+    /// Decode the availabilityView into slots using the start time and interval of
+    /// the request, joining adjacent time slots with the same status.
+    pub fn availability_slots(&self, request: &GetSchedule) -> Vec<AvailabilitySlot> {
+        let interval = time::Duration::minutes(request.availability_view_interval.unwrap_or(30));
+        let time_at = |index: usize| {
+            DateTimeTimeZone::new(
+                request.start_time.date_time + interval * index as i32,
+                &request.start_time.time_zone,
+            )
+        };
+
+        let mut slots: Vec<AvailabilitySlot> = Vec::new();
+        for (index, status) in self.availability().into_iter().enumerate() {
+            match slots.last_mut() {
+                Some(slot) if slot.status == status => slot.end = time_at(index + 1),
+                _ => slots.push(AvailabilitySlot {
+                    status,
+                    start: time_at(index),
+                    end: time_at(index + 1),
+                }),
+            }
+        }
+        slots
+    }
+
+    /// The slots of the availabilityView that are free.
+    pub fn free_slots(&self, request: &GetSchedule) -> Vec<AvailabilitySlot> {
+        self.availability_slots(request)
+            .into_iter()
+            .filter(|slot| slot.status == FreeBusyStatus::Free)
+            .collect()
+    }
+}
+
+async fn find_meeting_time_suggestions(
+    request: RequestHandler,
+) -> GraphResult<MeetingTimeSuggestionsResult> {
+    let response = request.send().await?;
+    Ok(Paging::success_response(response).await?.json().await?)
+}
+
+impl UsersIdApiClient {
+    /// Suggest meeting times and locations for the user as the organizer.
+    pub async fn find_meeting_time_suggestions(
+        &self,
+        body: &FindMeetingTimes,
+    ) -> GraphResult<MeetingTimeSuggestionsResult> {
+        find_meeting_time_suggestions(self.find_meeting_times(body)).await
+    }
+}
+
+impl MeApiClient {
+    /// Suggest meeting times and locations for the signed in user as the organizer.
+    pub async fn find_meeting_time_suggestions(
+        &self,
+        body: &FindMeetingTimes,
+    ) -> GraphResult<MeetingTimeSuggestionsResult> {
+        find_meeting_time_suggestions(self.find_meeting_times(body)).await
+    }
+}
+
+impl DefaultCalendarApiClient {
+    /// The free/busy availability of the users, distribution lists or resources
+    /// of the request.
+    pub async fn get_schedule_information(
+        &self,
+        body: &GetSchedule,
+    ) -> GraphResult<Vec<ScheduleInformation>> {
+        let response = self.get_schedule(body).send().await?;
+        let body: serde_json::Value = Paging::success_response(response).await?.json().await?;
+        Ok(serde_json::from_value(body["value"].clone())?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn find_meeting_times_body() {
+        let find = FindMeetingTimes::new(time::Duration::minutes(90))
+            .attendee(AttendeeBase::required("alex@contoso.com"))
+            .time_slot(TimeSlot::new(
+                DateTimeTimeZone::new(datetime!(2024-03-20 09:00), "Pacific Standard Time"),
+                DateTimeTimeZone::new(datetime!(2024-03-20 17:00), "Pacific Standard Time"),
+            ))
+            .room("Conf Room 100", "cf100@contoso.com");
+        let body = serde_json::to_value(&find).unwrap();
+
+        assert_eq!("PT1H30M", body["meetingDuration"]);
+        assert_eq!("required", body["attendees"][0]["type"]);
+        assert_eq!(
+            "alex@contoso.com",
+            body["attendees"][0]["emailAddress"]["address"]
+        );
+        assert_eq!(
+            "2024-03-20T09:00:00",
+            body["timeConstraint"]["timeSlots"][0]["start"]["dateTime"]
+        );
+        assert_eq!(
+            true,
+            body["locationConstraint"]["locations"][0]["resolveAvailability"]
+        );
+    }
+
+    #[test]
+    fn decode_availability_view() {
+        let request = GetSchedule::new(
+            &["alex@contoso.com"],
+            DateTimeTimeZone::new(datetime!(2024-03-20 09:00), "Pacific Standard Time"),
+            DateTimeTimeZone::new(datetime!(2024-03-20 11:00), "Pacific Standard Time"),
+        )
+        .availability_view_interval(30);
+        let schedule: ScheduleInformation = serde_json::from_value(serde_json::json!({
+            "scheduleId": "alex@contoso.com",
+            "availabilityView": "0220",
+            "scheduleItems": []
+        }))
+        .unwrap();
+
+        let slots = schedule.availability_slots(&request);
+        assert_eq!(3, slots.len());
+        assert_eq!(FreeBusyStatus::Busy, slots[1].status);
+        assert_eq!(datetime!(2024-03-20 09:30), slots[1].start.date_time);
+        assert_eq!(datetime!(2024-03-20 10:30), slots[1].end.date_time);
+        assert_eq!(2, schedule.free_slots(&request).len());
+    }
+}
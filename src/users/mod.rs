@@ -1,6 +1,7 @@
 mod activities;
 mod app_role_assignments;
 mod authentication;
+mod bulk_operations;
 mod calendar_groups;
 mod calendar_view;
 mod calendars;
@@ -19,11 +20,13 @@ mod followed_sites;
 mod inference_classification;
 mod insights;
 mod joined_teams;
+mod license_assignment;
 mod license_details;
 mod mail_folders;
 mod mailbox_settings;
 mod managed_app_registrations;
 mod managed_devices;
+mod meeting_times;
 mod member_of;
 mod onenote;
 mod onenote_notebooks;
@@ -34,6 +37,7 @@ mod online_meetings;
 mod outlook;
 mod owned_devices;
 mod owned_objects;
+mod photo_batch;
 mod photos;
 mod presence;
 mod registered_devices;
@@ -51,6 +55,7 @@ mod users_messages;
 pub use activities::*;
 pub use app_role_assignments::*;
 pub use authentication::*;
+pub use bulk_operations::*;
 pub use calendar_groups::*;
 pub use calendar_view::*;
 pub use calendars::*;
@@ -69,11 +74,13 @@ pub use followed_sites::*;
 pub use inference_classification::*;
 pub use insights::*;
 pub use joined_teams::*;
+pub use license_assignment::*;
 pub use license_details::*;
 pub use mail_folders::*;
 pub use mailbox_settings::*;
 pub use managed_app_registrations::*;
 pub use managed_devices::*;
+pub use meeting_times::*;
 pub use member_of::*;
 pub use onenote::*;
 pub use onenote_notebooks::*;
@@ -84,6 +91,7 @@ pub use online_meetings::*;
 pub use outlook::*;
 pub use owned_devices::*;
 pub use owned_objects::*;
+pub use photo_batch::*;
 pub use photos::*;
 pub use presence::*;
 pub use registered_devices::*;
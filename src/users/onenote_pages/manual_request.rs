@@ -0,0 +1,74 @@
+use crate::api_default_imports::*;
+use crate::users::*;
+use graph_http::api_impl::BodyRead;
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+
+impl OnenotePagesApiClient {
+    /// Create a page from a body in memory, such as a [String] of HTML or a multipart
+    /// body, with the content type of the body, such as `text/html` or
+    /// `multipart/form-data; boundary=PartBoundary`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let page = OnenotePageHtml::new("Meeting notes").paragraph("Agenda");
+    ///
+    /// let response = client
+    ///     .me()
+    ///     .onenote()
+    ///     .section(SECTION_ID)
+    ///     .pages()
+    ///     .create_page(page.to_string(), "text/html")?
+    ///     .send()
+    ///     .await?;
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn create_page<B: BodyExt>(
+        &self,
+        body: B,
+        content_type: &str,
+    ) -> GraphResult<RequestHandler> {
+        let content_type = HeaderValue::from_str(content_type)
+            .map_err(|_| GraphFailure::invalid("content type"))?;
+        Ok(self.create_pages(body).header(CONTENT_TYPE, content_type))
+    }
+
+    /// Create a page from a reader, such as a [tokio::fs::File] or the body of
+    /// another request, with the content type of the body.
+    pub async fn create_page_from_async_read<R: tokio::io::AsyncReadExt + Unpin>(
+        &self,
+        reader: R,
+        content_type: &str,
+    ) -> GraphResult<RequestHandler> {
+        let body = BodyRead::from_async_read(reader).await?;
+        self.create_page(body, content_type)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Graph;
+
+    #[test]
+    fn create_page_content_type() {
+        let client = Graph::new("token");
+        let mut request = client
+            .me()
+            .onenote()
+            .section("section-id")
+            .pages()
+            .create_page(String::from("<html></html>"), "text/html")
+            .unwrap();
+
+        assert_eq!(
+            Some(&HeaderValue::from_static("text/html")),
+            request.headers_mut().get(CONTENT_TYPE)
+        );
+        assert!(client
+            .me()
+            .onenote()
+            .pages()
+            .create_page(String::new(), "text/html\n")
+            .is_err());
+    }
+}
@@ -1,3 +1,8 @@
+mod manual_request;
+mod page_html;
 mod request;
 
+#[allow(unused_imports)]
+pub use manual_request::*;
+pub use page_html::*;
 pub use request::*;
@@ -0,0 +1,187 @@
+use std::fmt::{Display, Formatter};
+
+use crate::datetime::{format_iso8601, OffsetDateTime};
+
+/// The HTML of a OneNote page used as the body of create pages.
+///
+/// Text is escaped. Images and files that are sent as parts of a multipart request
+/// are referenced by the name of the part, such as `name:imageBlock1`, and the
+/// names of the parts used by the page are returned from [OnenotePageHtml::part_names].
+///
+/// [Create OneNote pages](https://learn.microsoft.com/en-us/graph/onenote-create-page)
+///
+/// # Example
+/// ```rust,ignore
+/// let page = OnenotePageHtml::new("Meeting notes")
+///     .created(OffsetDateTime::now_utc())
+///     .heading("Agenda")
+///     .paragraph("Review the <draft> budget")
+///     .image_url("https://example.com/chart.png", "Budget chart");
+///
+/// let response = client
+///     .me()
+///     .onenote()
+///     .section(SECTION_ID)
+///     .pages()
+///     .create_page(page.to_string(), "text/html")?
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OnenotePageHtml {
+    title: String,
+    created: Option<OffsetDateTime>,
+    body: Vec<String>,
+    part_names: Vec<String>,
+}
+
+impl OnenotePageHtml {
+    pub fn new(title: impl AsRef<str>) -> OnenotePageHtml {
+        OnenotePageHtml {
+            title: escape(title.as_ref()),
+            ..Default::default()
+        }
+    }
+
+    /// The creation time shown on the page. Defaults to the time the page is created.
+    pub fn created(mut self, created: OffsetDateTime) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn heading(mut self, text: impl AsRef<str>) -> Self {
+        self.body
+            .push(format!("<h1>{}</h1>", escape(text.as_ref())));
+        self
+    }
+
+    pub fn paragraph(mut self, text: impl AsRef<str>) -> Self {
+        self.body.push(format!("<p>{}</p>", escape(text.as_ref())));
+        self
+    }
+
+    /// Add HTML to the body as is. The HTML is not escaped or validated.
+    pub fn html(mut self, html: impl ToString) -> Self {
+        self.body.push(html.to_string());
+        self
+    }
+
+    /// An image that OneNote downloads from a public url.
+    pub fn image_url(mut self, url: impl AsRef<str>, alt: impl AsRef<str>) -> Self {
+        self.body.push(format!(
+            "<img src=\"{}\" alt=\"{}\" />",
+            escape(url.as_ref()),
+            escape(alt.as_ref())
+        ));
+        self
+    }
+
+    /// An image sent as the part with the given name in a multipart request.
+    pub fn image_part(mut self, part_name: impl AsRef<str>, alt: impl AsRef<str>) -> Self {
+        let part_name = self.add_part(part_name.as_ref());
+        self.body.push(format!(
+            "<img src=\"name:{part_name}\" alt=\"{}\" />",
+            escape(alt.as_ref())
+        ));
+        self
+    }
+
+    /// A file attachment sent as the part with the given name in a multipart request.
+    /// The file is shown on the page with the file name as an icon.
+    pub fn object_part(
+        mut self,
+        part_name: impl AsRef<str>,
+        file_name: impl AsRef<str>,
+        content_type: impl AsRef<str>,
+    ) -> Self {
+        let part_name = self.add_part(part_name.as_ref());
+        self.body.push(format!(
+            "<object data-attachment=\"{}\" data=\"name:{part_name}\" type=\"{}\" />",
+            escape(file_name.as_ref()),
+            escape(content_type.as_ref())
+        ));
+        self
+    }
+
+    /// The names of the multipart request parts referenced by the page. The part
+    /// with the HTML of the page has to be named `Presentation`.
+    pub fn part_names(&self) -> &[String] {
+        &self.part_names
+    }
+
+    fn add_part(&mut self, part_name: &str) -> String {
+        let part_name = escape(part_name);
+        if !self.part_names.contains(&part_name) {
+            self.part_names.push(part_name.clone());
+        }
+        part_name
+    }
+}
+
+impl Display for OnenotePageHtml {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "<!DOCTYPE html>")?;
+        writeln!(f, "<html>")?;
+        writeln!(f, "<head>")?;
+        writeln!(f, "<title>{}</title>", self.title)?;
+        if let Some(created) = self.created.as_ref() {
+            writeln!(
+                f,
+                "<meta name=\"created\" content=\"{}\" />",
+                format_iso8601(created)
+            )?;
+        }
+        writeln!(f, "</head>")?;
+        writeln!(f, "<body>")?;
+        for element in self.body.iter() {
+            writeln!(f, "{element}")?;
+        }
+        writeln!(f, "</body>")?;
+        write!(f, "</html>")
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn page_html() {
+        let page = OnenotePageHtml::new("Notes & ideas")
+            .created(datetime!(2024-03-20 08:00 UTC))
+            .paragraph("Review the <draft>")
+            .image_part("imageBlock1", "Chart")
+            .object_part("fileBlock1", "budget.pdf", "application/pdf")
+            .image_part("imageBlock1", "Chart again");
+
+        assert_eq!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<title>Notes &amp; ideas</title>\n\
+            <meta name=\"created\" content=\"2024-03-20T08:00:00Z\" />\n</head>\n<body>\n\
+            <p>Review the &lt;draft&gt;</p>\n\
+            <img src=\"name:imageBlock1\" alt=\"Chart\" />\n\
+            <object data-attachment=\"budget.pdf\" data=\"name:fileBlock1\" type=\"application/pdf\" />\n\
+            <img src=\"name:imageBlock1\" alt=\"Chart again\" />\n</body>\n</html>",
+            page.to_string()
+        );
+        assert_eq!(
+            vec!["imageBlock1".to_string(), "fileBlock1".to_string()],
+            page.part_names()
+        );
+    }
+}
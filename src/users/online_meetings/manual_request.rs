@@ -0,0 +1,14 @@
+use crate::api_default_imports::*;
+use crate::users::*;
+
+impl OnlineMeetingsApiClient {
+    /// Create the meeting with the external id, or get it when it already exists.
+    /// The request is marked as idempotent so that it is sent again when throttled
+    /// if [RequestHandler::retry_throttled] is set.
+    pub fn create_or_get_online_meeting(
+        &self,
+        meeting: &CreateOrGetOnlineMeeting,
+    ) -> RequestHandler {
+        self.create_or_get(meeting).idempotent()
+    }
+}
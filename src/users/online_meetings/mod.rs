@@ -1,3 +1,8 @@
+mod manual_request;
+mod online_meeting;
 mod request;
 
+#[allow(unused_imports)]
+pub use manual_request::*;
+pub use online_meeting::*;
 pub use request::*;
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::datetime::OffsetDateTime;
+
+/// The body of createOrGet. A meeting is only created the first time the external
+/// id is used by the organizer, so the request can be sent again safely.
+///
+/// # Example
+/// ```rust,ignore
+/// let meeting = CreateOrGetOnlineMeeting::new("7eb8263f-d0e0-4149-bb1c-1f0476083c56")
+///     .subject("Weekly sync")
+///     .start_date_time(start)
+///     .end_date_time(start + time::Duration::minutes(30));
+///
+/// let response = client
+///     .me()
+///     .online_meetings()
+///     .create_or_get_online_meeting(&meeting)
+///     .retry_throttled(3)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrGetOnlineMeeting {
+    /// An id chosen by the caller that identifies the meeting, such as the id of
+    /// the meeting in another system.
+    pub external_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub start_date_time: Option<OffsetDateTime>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::datetime::iso8601::option"
+    )]
+    pub end_date_time: Option<OffsetDateTime>,
+}
+
+impl CreateOrGetOnlineMeeting {
+    pub fn new(external_id: impl ToString) -> CreateOrGetOnlineMeeting {
+        CreateOrGetOnlineMeeting {
+            external_id: external_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn subject(mut self, subject: impl ToString) -> Self {
+        self.subject = Some(subject.to_string());
+        self
+    }
+
+    pub fn start_date_time(mut self, start_date_time: OffsetDateTime) -> Self {
+        self.start_date_time = Some(start_date_time);
+        self
+    }
+
+    pub fn end_date_time(mut self, end_date_time: OffsetDateTime) -> Self {
+        self.end_date_time = Some(end_date_time);
+        self
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::{HashMap, HashSet};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::api_default_imports::*;
+use crate::batch::BatchResponse;
+use crate::GraphClient;
+
+/// The result of one photo request in a $batch response.
+#[derive(Clone, Debug, PartialEq)]
+enum PhotoResponse {
+    Photo(Vec<u8>),
+    NoPhoto,
+    Throttled,
+    Failed(u16, Option<ErrorMessage>),
+}
+
+/// Get the profile photos of many users in $batch requests of 20 photos, such as
+/// for the avatars of a people picker.
+///
+/// Users without a photo are returned as `None` instead of failing the request.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::users::UserPhotos;
+///
+/// let photos = UserPhotos::new(&client)
+///     .size("48x48")
+///     .get_photos(&["adele@contoso.com", USER_ID])
+///     .await?;
+///
+/// for (user_id, photo) in photos.iter() {
+///     match photo {
+///         Some(bytes) => println!("{user_id}: {} bytes", bytes.len()),
+///         None => println!("{user_id}: no photo"),
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UserPhotos {
+    client: GraphClient,
+    size: Option<String>,
+}
+
+impl UserPhotos {
+    pub fn new(client: &GraphClient) -> UserPhotos {
+        UserPhotos {
+            client: client.clone(),
+            size: None,
+        }
+    }
+
+    /// Request the photo of the given size, such as `48x48` or `120x120`, instead
+    /// of the largest photo.
+    pub fn size(mut self, size: impl ToString) -> UserPhotos {
+        self.size = Some(size.to_string());
+        self
+    }
+
+    /// The photo of each user by id or user principal name. Throttled requests are
+    /// sent again after the Retry-After duration. An error is returned when a photo
+    /// fails with any status other than 404 (Not Found).
+    pub async fn get_photos<T: AsRef<str>>(
+        &self,
+        user_ids: &[T],
+    ) -> GraphResult<HashMap<String, Option<Vec<u8>>>> {
+        let mut seen = HashSet::new();
+        let user_ids: Vec<&str> = user_ids
+            .iter()
+            .map(|user_id| user_id.as_ref())
+            .filter(|user_id| seen.insert(*user_id))
+            .collect();
+
+        let requests: Vec<serde_json::Value> = user_ids
+            .iter()
+            .enumerate()
+            .map(|(index, user_id)| photo_request(index, user_id, self.size.as_deref()))
+            .collect();
+        let mut responses = self
+            .client
+            .batch_client()
+            .send_all_requests(requests)
+            .await?;
+
+        let mut photos = HashMap::new();
+        for (index, user_id) in user_ids.into_iter().enumerate() {
+            let response = responses.remove(&index.to_string()).unwrap_or_default();
+            match photo_response(&response) {
+                PhotoResponse::Photo(bytes) => {
+                    photos.insert(user_id.to_string(), Some(bytes));
+                }
+                PhotoResponse::NoPhoto => {
+                    photos.insert(user_id.to_string(), None);
+                }
+                PhotoResponse::Throttled => {
+                    return Err(GraphFailure::invalid(&format!(
+                        "photo request for user {user_id} was throttled"
+                    )));
+                }
+                PhotoResponse::Failed(_, Some(error)) => return Err(error.into()),
+                PhotoResponse::Failed(status, None) => {
+                    return Err(GraphFailure::invalid(&format!(
+                        "photo request for user {user_id} failed with status {status}"
+                    )));
+                }
+            }
+        }
+
+        Ok(photos)
+    }
+}
+
+fn photo_request(index: usize, user_id: &str, size: Option<&str>) -> serde_json::Value {
+    let url = match size {
+        Some(size) => format!("/users/{user_id}/photos/{size}/$value"),
+        None => format!("/users/{user_id}/photo/$value"),
+    };
+    serde_json::json!({
+        "id": index.to_string(),
+        "method": "GET",
+        "url": url
+    })
+}
+
+// Binary content in a $batch response is returned as a base64 encoded string.
+fn photo_response(response: &BatchResponse) -> PhotoResponse {
+    match response.status {
+        200..=299 => match response
+            .body
+            .as_str()
+            .and_then(|body| STANDARD.decode(body).ok())
+        {
+            Some(bytes) => PhotoResponse::Photo(bytes),
+            None => PhotoResponse::NoPhoto,
+        },
+        404 => PhotoResponse::NoPhoto,
+        _ if response.throttle_retry_after().is_some() => PhotoResponse::Throttled,
+        status => PhotoResponse::Failed(status, response.error()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn photo_responses() {
+        assert_eq!(
+            "/users/adele@contoso.com/photos/48x48/$value",
+            photo_request(0, "adele@contoso.com", Some("48x48"))["url"]
+        );
+
+        assert_eq!(
+            PhotoResponse::Photo(vec![0xff, 0xd8, 0xff]),
+            photo_response(&BatchResponse::from_value(&serde_json::json!({
+                "id": "0",
+                "status": 200,
+                "headers": { "Content-Type": "image/jpeg" },
+                "body": "/9j/"
+            })))
+        );
+        assert_eq!(
+            PhotoResponse::NoPhoto,
+            photo_response(&BatchResponse::from_value(&serde_json::json!({
+                "id": "1",
+                "status": 404,
+                "body": { "error": { "code": "ImageNotFound", "message": "" } }
+            })))
+        );
+        assert_eq!(
+            PhotoResponse::Throttled,
+            photo_response(&BatchResponse::from_value(&serde_json::json!({
+                "id": "2",
+                "status": 429,
+                "headers": { "Retry-After": "5" }
+            })))
+        );
+    }
+}
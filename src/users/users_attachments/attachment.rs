@@ -0,0 +1,134 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use graph_error::GraphResult;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttachmentType {
+    /// A file such as a document or image.
+    File,
+    /// An email message, event or contact attached to a message or event.
+    Item,
+    /// A link to a file, such as a file stored in OneDrive.
+    Reference,
+    /// Any other type, such as the `@odata.type` of a task file attachment.
+    Other(String),
+}
+
+/// An attachment of a message or event.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    #[serde(rename = "@odata.type", default)]
+    pub odata_type: String,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub content_type: Option<String>,
+    /// The size of the attachment in bytes.
+    #[serde(default)]
+    pub size: i64,
+    #[serde(default)]
+    pub is_inline: bool,
+    /// The base64 encoded content of a file attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_bytes: Option<String>,
+    /// The url of the file of a reference attachment, when returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+}
+
+impl Attachment {
+    pub fn attachment_type(&self) -> AttachmentType {
+        match self.odata_type.as_str() {
+            "#microsoft.graph.fileAttachment" => AttachmentType::File,
+            "#microsoft.graph.itemAttachment" => AttachmentType::Item,
+            "#microsoft.graph.referenceAttachment" => AttachmentType::Reference,
+            odata_type => AttachmentType::Other(odata_type.to_string()),
+        }
+    }
+
+    /// The decoded content of a file attachment, or `None` when the attachment
+    /// has no contentBytes.
+    #[allow(clippy::result_large_err)]
+    pub fn decode_content_bytes(&self) -> GraphResult<Option<Vec<u8>>> {
+        match self.content_bytes.as_deref() {
+            Some(content_bytes) => Ok(Some(STANDARD.decode(content_bytes)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The content of an attachment returned by `get_attachment_content`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttachmentContent {
+    /// The content of a file attachment. The contentBytes of the attachment are
+    /// cleared after they are decoded.
+    File {
+        attachment: Attachment,
+        content: Vec<u8>,
+    },
+    /// The MIME content of an item attachment, such as an `.eml` message.
+    Item {
+        attachment: Attachment,
+        mime_content: Vec<u8>,
+    },
+    /// A reference attachment has no content. The file is at the source url.
+    Reference { attachment: Attachment },
+}
+
+impl AttachmentContent {
+    pub fn attachment(&self) -> &Attachment {
+        match self {
+            AttachmentContent::File { attachment, .. }
+            | AttachmentContent::Item { attachment, .. }
+            | AttachmentContent::Reference { attachment } => attachment,
+        }
+    }
+
+    /// The bytes of a file or item attachment.
+    pub fn content(&self) -> Option<&[u8]> {
+        match self {
+            AttachmentContent::File { content, .. } => Some(content),
+            AttachmentContent::Item { mime_content, .. } => Some(mime_content),
+            AttachmentContent::Reference { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decode_file_attachment() {
+        let attachment: Attachment = serde_json::from_value(serde_json::json!({
+            "@odata.type": "#microsoft.graph.fileAttachment",
+            "id": "AAMkAGUzY5QKjAAA=",
+            "name": "notes.txt",
+            "contentType": "text/plain",
+            "size": 11,
+            "isInline": false,
+            "contentBytes": "aGVsbG8gd29ybGQ="
+        }))
+        .unwrap();
+
+        assert_eq!(AttachmentType::File, attachment.attachment_type());
+        assert_eq!(
+            Some(b"hello world".to_vec()),
+            attachment.decode_content_bytes().unwrap()
+        );
+
+        let attachment: Attachment = serde_json::from_value(serde_json::json!({
+            "@odata.type": "#microsoft.graph.itemAttachment",
+            "id": "AAMkAGUzY5QKjAAB=",
+            "name": "Meeting notes"
+        }))
+        .unwrap();
+        assert_eq!(AttachmentType::Item, attachment.attachment_type());
+        assert_eq!(None, attachment.decode_content_bytes().unwrap());
+    }
+}
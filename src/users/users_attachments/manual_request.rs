@@ -0,0 +1,91 @@
+use crate::api_default_imports::*;
+use crate::users::{Attachment, AttachmentContent, AttachmentType, UsersAttachmentsApiClient};
+use futures::StreamExt;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+impl UsersAttachmentsApiClient {
+    /// Get an attachment of a message or event with its content. The contentBytes of
+    /// a file attachment are decoded, the MIME content of an item attachment is
+    /// requested from `/$value`, and a reference attachment is returned without content.
+    ///
+    /// The content is read into memory. Use [UsersAttachmentsApiClient::download_attachment_content]
+    /// to stream large attachments.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let attachments = client.me().message(MESSAGE_ID).attachments();
+    /// let content = attachments.get_attachment_content(ATTACHMENT_ID).await?;
+    ///
+    /// if let Some(bytes) = content.content() {
+    ///     let name = content.attachment().name.clone().unwrap_or_default();
+    ///     tokio::fs::write(name, bytes).await?;
+    /// }
+    /// ```
+    pub async fn get_attachment_content(&self, id: &str) -> GraphResult<AttachmentContent> {
+        let client = self.id(id);
+        let response = client.get_attachments().send().await?;
+        let mut attachment: Attachment = Paging::success_response(response).await?.json().await?;
+
+        match attachment.attachment_type() {
+            AttachmentType::Reference => Ok(AttachmentContent::Reference { attachment }),
+            AttachmentType::Item => {
+                let response = client.get_attachments_content().send().await?;
+                let mime_content = Paging::success_response(response).await?.bytes().await?;
+                Ok(AttachmentContent::Item {
+                    attachment,
+                    mime_content: mime_content.to_vec(),
+                })
+            }
+            _ => {
+                let content = match attachment.decode_content_bytes()? {
+                    Some(content) => content,
+                    None => {
+                        let response = client.get_attachments_content().send().await?;
+                        Paging::success_response(response)
+                            .await?
+                            .bytes()
+                            .await?
+                            .to_vec()
+                    }
+                };
+                attachment.content_bytes = None;
+                Ok(AttachmentContent::File {
+                    attachment,
+                    content,
+                })
+            }
+        }
+    }
+
+    /// Stream the raw content of a file attachment, or the MIME content of an item
+    /// attachment, from `/$value` into the writer. Returns the number of bytes written.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut file = tokio::fs::File::create("./report.pdf").await?;
+    /// client
+    ///     .me()
+    ///     .message(MESSAGE_ID)
+    ///     .attachments()
+    ///     .download_attachment_content(ATTACHMENT_ID, &mut file)
+    ///     .await?;
+    /// ```
+    pub async fn download_attachment_content<W: AsyncWrite + Unpin>(
+        &self,
+        id: &str,
+        mut writer: W,
+    ) -> GraphResult<u64> {
+        let response = self.id(id).get_attachments_content().send().await?;
+        let response = Paging::success_response(response).await?;
+
+        let mut written = 0;
+        let mut body = response.bytes_stream();
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk?;
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+}
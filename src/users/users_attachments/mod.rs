@@ -1,3 +1,8 @@
+mod attachment;
+mod manual_request;
 mod request;
 
+pub use attachment::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
@@ -0,0 +1,365 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EmailAddress {
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Recipient {
+    #[serde(rename = "emailAddress")]
+    pub email_address: EmailAddress,
+}
+
+impl Recipient {
+    pub fn new(address: impl ToString) -> Recipient {
+        Recipient {
+            email_address: EmailAddress {
+                address: address.to_string(),
+                name: None,
+            },
+        }
+    }
+
+    pub fn with_name(address: impl ToString, name: impl ToString) -> Recipient {
+        Recipient {
+            email_address: EmailAddress {
+                address: address.to_string(),
+                name: Some(name.to_string()),
+            },
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum BodyType {
+    #[serde(rename = "Text")]
+    Text,
+    #[serde(rename = "HTML")]
+    Html,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Importance {
+    #[serde(rename = "Low")]
+    Low,
+    #[serde(rename = "Normal")]
+    Normal,
+    #[serde(rename = "High")]
+    High,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ItemBody {
+    #[serde(rename = "contentType")]
+    pub content_type: BodyType,
+    pub content: String,
+}
+
+/// A file attachment added to a draft message.
+///
+/// Graph limits attachments added this way to 3 MB. Use an attachment upload
+/// session for larger files.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FileAttachment {
+    #[serde(rename = "@odata.type")]
+    odata_type: String,
+    pub name: String,
+    #[serde(rename = "contentType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Base64 encoded contents of the file.
+    #[serde(rename = "contentBytes")]
+    pub content_bytes: String,
+    #[serde(rename = "isInline")]
+    pub is_inline: bool,
+}
+
+impl FileAttachment {
+    pub fn new(name: impl ToString, content: impl AsRef<[u8]>) -> FileAttachment {
+        FileAttachment {
+            odata_type: "#microsoft.graph.fileAttachment".into(),
+            name: name.to_string(),
+            content_type: None,
+            content_bytes: STANDARD.encode(content),
+            is_inline: false,
+        }
+    }
+
+    pub fn content_type(mut self, content_type: impl ToString) -> FileAttachment {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    pub fn inline(mut self, is_inline: bool) -> FileAttachment {
+        self.is_inline = is_inline;
+        self
+    }
+}
+
+/// The message body sent when creating or updating a draft. Attachments are
+/// not part of the serialized message and are added to the draft separately.
+///
+/// # Example
+/// ```rust
+/// use graph_rs_sdk::users::{DraftMessage, FileAttachment, Importance};
+///
+/// let draft = DraftMessage::builder()
+///     .subject("Quarterly report")
+///     .html_body("<p>The report is attached.</p>")
+///     .to("adele@contoso.com")
+///     .cc("alex@contoso.com")
+///     .importance(Importance::High)
+///     .attachment(FileAttachment::new("report.txt", "report contents"))
+///     .build();
+///
+/// assert_eq!(1, draft.attachments().len());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DraftMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<ItemBody>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub importance: Option<Importance>,
+    #[serde(rename = "toRecipients")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub to_recipients: Vec<Recipient>,
+    #[serde(rename = "ccRecipients")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub cc_recipients: Vec<Recipient>,
+    #[serde(rename = "bccRecipients")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub bcc_recipients: Vec<Recipient>,
+    #[serde(rename = "replyTo")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub reply_to: Vec<Recipient>,
+    /// The Internet Message-ID header of the message, such as `<id@contoso.com>`.
+    #[serde(rename = "internetMessageId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internet_message_id: Option<String>,
+    #[serde(skip)]
+    attachments: Vec<FileAttachment>,
+}
+
+impl DraftMessage {
+    pub fn builder() -> DraftMessageBuilder {
+        DraftMessageBuilder::default()
+    }
+
+    pub fn attachments(&self) -> &[FileAttachment] {
+        self.attachments.as_slice()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DraftMessageBuilder {
+    message: DraftMessage,
+}
+
+impl DraftMessageBuilder {
+    pub fn subject(mut self, subject: impl ToString) -> Self {
+        self.message.subject = Some(subject.to_string());
+        self
+    }
+
+    pub fn text_body(mut self, content: impl ToString) -> Self {
+        self.message.body = Some(ItemBody {
+            content_type: BodyType::Text,
+            content: content.to_string(),
+        });
+        self
+    }
+
+    pub fn html_body(mut self, content: impl ToString) -> Self {
+        self.message.body = Some(ItemBody {
+            content_type: BodyType::Html,
+            content: content.to_string(),
+        });
+        self
+    }
+
+    pub fn importance(mut self, importance: Importance) -> Self {
+        self.message.importance = Some(importance);
+        self
+    }
+
+    pub fn to(mut self, address: impl ToString) -> Self {
+        self.message.to_recipients.push(Recipient::new(address));
+        self
+    }
+
+    pub fn cc(mut self, address: impl ToString) -> Self {
+        self.message.cc_recipients.push(Recipient::new(address));
+        self
+    }
+
+    pub fn bcc(mut self, address: impl ToString) -> Self {
+        self.message.bcc_recipients.push(Recipient::new(address));
+        self
+    }
+
+    pub fn reply_to(mut self, address: impl ToString) -> Self {
+        self.message.reply_to.push(Recipient::new(address));
+        self
+    }
+
+    pub fn recipient(mut self, recipient: Recipient) -> Self {
+        self.message.to_recipients.push(recipient);
+        self
+    }
+
+    pub fn attachment(mut self, attachment: FileAttachment) -> Self {
+        self.message.attachments.push(attachment);
+        self
+    }
+
+    /// Set the Message-ID of the message, such as `<id@contoso.com>`. A message sent
+    /// with an id chosen by the client can be sent again without sending a duplicate.
+    pub fn internet_message_id(mut self, internet_message_id: impl ToString) -> Self {
+        self.message.internet_message_id = Some(internet_message_id.to_string());
+        self
+    }
+
+    pub fn build(self) -> DraftMessage {
+        self.message
+    }
+}
+
+/// The body of sendMail. Unlike a draft, the attachments of the message are sent
+/// with the message.
+///
+/// # Example
+/// ```rust,ignore
+/// let message = DraftMessage::builder()
+///     .subject("Order confirmation")
+///     .text_body("Your order has shipped.")
+///     .to("adele@contoso.com")
+///     .internet_message_id(format!("<order-{ORDER_ID}@contoso.com>"))
+///     .build();
+///
+/// client
+///     .me()
+///     .send_message(&SendMail::new(message))
+///     .retry_throttled(3)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SendMail {
+    pub message: DraftMessage,
+    pub save_to_sent_items: bool,
+}
+
+impl SendMail {
+    pub fn new(message: DraftMessage) -> SendMail {
+        SendMail {
+            message,
+            save_to_sent_items: true,
+        }
+    }
+
+    pub fn save_to_sent_items(mut self, save_to_sent_items: bool) -> Self {
+        self.save_to_sent_items = save_to_sent_items;
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct SendMailMessage<'a> {
+    #[serde(flatten)]
+    message: &'a DraftMessage,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    attachments: &'a [FileAttachment],
+}
+
+impl Serialize for SendMail {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct SendMailBody<'a> {
+            message: SendMailMessage<'a>,
+            #[serde(rename = "saveToSentItems")]
+            save_to_sent_items: bool,
+        }
+
+        SendMailBody {
+            message: SendMailMessage {
+                message: &self.message,
+                attachments: self.message.attachments(),
+            },
+            save_to_sent_items: self.save_to_sent_items,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_draft_message() {
+        let draft = DraftMessage::builder()
+            .subject("subject")
+            .text_body("body")
+            .to("user@contoso.com")
+            .attachment(FileAttachment::new("file.txt", "content"))
+            .build();
+
+        let value = serde_json::to_value(&draft).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "subject": "subject",
+                "body": {
+                    "contentType": "Text",
+                    "content": "body"
+                },
+                "toRecipients": [{
+                    "emailAddress": {
+                        "address": "user@contoso.com"
+                    }
+                }]
+            }),
+            value
+        );
+    }
+
+    #[test]
+    fn serialize_send_mail() {
+        let message = DraftMessage::builder()
+            .subject("subject")
+            .to("user@contoso.com")
+            .internet_message_id("<order-1@contoso.com>")
+            .attachment(FileAttachment::new("file.txt", "content"))
+            .build();
+
+        let value = serde_json::to_value(SendMail::new(message).save_to_sent_items(false)).unwrap();
+        assert_eq!(false, value["saveToSentItems"]);
+        assert_eq!(
+            "<order-1@contoso.com>",
+            value["message"]["internetMessageId"]
+        );
+        assert_eq!("file.txt", value["message"]["attachments"][0]["name"]);
+    }
+
+    #[test]
+    fn serialize_file_attachment() {
+        let attachment = FileAttachment::new("file.txt", "content").content_type("text/plain");
+        let value = serde_json::to_value(&attachment).unwrap();
+        assert_eq!(
+            "#microsoft.graph.fileAttachment",
+            value["@odata.type"].as_str().unwrap()
+        );
+        assert_eq!("Y29udGVudA==", value["contentBytes"].as_str().unwrap());
+        assert_eq!("text/plain", value["contentType"].as_str().unwrap());
+    }
+}
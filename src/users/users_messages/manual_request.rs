@@ -0,0 +1,87 @@
+use crate::api_default_imports::*;
+use crate::me::MeApiClient;
+use crate::users::*;
+
+impl UsersMessagesApiClient {
+    /// Create a draft message. Attachments on the [DraftMessage] are not
+    /// included and must be added using [UsersMessagesIdApiClient::add_attachment].
+    pub fn create_draft(&self, draft: &DraftMessage) -> RequestHandler {
+        self.create_messages(draft)
+    }
+
+    /// Create a draft message, add each of its attachments, and send it.
+    ///
+    /// Returns the id of the message that was sent.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let draft = DraftMessage::builder()
+    ///     .subject("Quarterly report")
+    ///     .text_body("The report is attached.")
+    ///     .to("adele@contoso.com")
+    ///     .attachment(FileAttachment::new("report.csv", bytes))
+    ///     .build();
+    ///
+    /// let message_id = client.me().messages().send_new_draft(&draft).await?;
+    /// ```
+    pub async fn send_new_draft(&self, draft: &DraftMessage) -> GraphResult<String> {
+        let response = self.create_draft(draft).send().await?;
+        let body: serde_json::Value = Paging::success_response(response).await?.json().await?;
+        let message_id = body["id"]
+            .as_str()
+            .ok_or_else(|| GraphFailure::not_found("No id found in response body"))?;
+
+        let message = self.id(message_id);
+        for attachment in draft.attachments() {
+            let response = message.add_attachment(attachment).send().await?;
+            Paging::success_response(response).await?;
+        }
+
+        let response = message.send_draft().send().await?;
+        Paging::success_response(response).await?;
+        Ok(message_id.to_string())
+    }
+}
+
+impl UsersMessagesIdApiClient {
+    /// Add a file attachment to a draft message.
+    pub fn add_attachment(&self, attachment: &FileAttachment) -> RequestHandler {
+        self.attachments().create_attachments(attachment)
+    }
+
+    /// Update the properties of a draft message.
+    pub fn update_draft(&self, draft: &DraftMessage) -> RequestHandler {
+        self.update_messages(draft)
+    }
+
+    /// Send a previously created draft message.
+    pub fn send_draft(&self) -> RequestHandler {
+        self.send()
+    }
+}
+
+// A message with a client supplied Message-ID is only delivered once, so sendMail
+// can be sent again when throttled.
+fn send_mail_request(request: RequestHandler, mail: &SendMail) -> RequestHandler {
+    if mail.message.internet_message_id.is_some() {
+        request.idempotent()
+    } else {
+        request
+    }
+}
+
+impl UsersIdApiClient {
+    /// Send the message with its attachments. The request is marked as idempotent
+    /// when the message has an internetMessageId.
+    pub fn send_message(&self, mail: &SendMail) -> RequestHandler {
+        send_mail_request(self.send_mail(mail), mail)
+    }
+}
+
+impl MeApiClient {
+    /// Send the message with its attachments. The request is marked as idempotent
+    /// when the message has an internetMessageId.
+    pub fn send_message(&self, mail: &SendMail) -> RequestHandler {
+        send_mail_request(self.send_mail(mail), mail)
+    }
+}
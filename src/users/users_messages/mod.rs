@@ -1,3 +1,8 @@
+mod draft_message;
+mod manual_request;
 mod request;
 
+pub use draft_message::*;
+#[allow(unused_imports)]
+pub use manual_request::*;
 pub use request::*;
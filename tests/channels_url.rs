@@ -1,3 +1,4 @@
+use graph_rs_sdk::chats::ChatMessageReaction;
 use graph_rs_sdk::*;
 
 static RID: &str = "T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x";
@@ -61,3 +62,23 @@ fn teams_channel_request() {
             .path()
     );
 }
+
+#[test]
+fn chat_messages_delta_and_reactions() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        format!("/v1.0/chats/{RID}/messages/delta()"),
+        client.chat(RID).messages_delta().url().path()
+    );
+
+    assert_eq!(
+        format!("/v1.0/chats/{RID}/messages/{ID}/setReaction"),
+        client
+            .chat(RID)
+            .message(ID)
+            .set_reaction(&ChatMessageReaction::like())
+            .url()
+            .path()
+    );
+}
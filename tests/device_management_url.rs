@@ -0,0 +1,54 @@
+use graph_rs_sdk::Graph;
+
+static ID: &str = "662009bc-7732-4f6f-8726-25883518b33e";
+
+#[test]
+fn virtual_endpoint_cloud_pcs() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        "/v1.0/deviceManagement/virtualEndpoint/cloudPCs".to_string(),
+        client
+            .device_management()
+            .virtual_endpoint()
+            .cloud_pcs()
+            .list_cloud_pcs()
+            .url()
+            .path()
+    );
+    assert_eq!(
+        format!("/v1.0/deviceManagement/virtualEndpoint/cloudPCs/{ID}/resize"),
+        client
+            .device_management()
+            .virtual_endpoint()
+            .cloud_pc(ID)
+            .resize(&String::new())
+            .url()
+            .path()
+    );
+}
+
+#[test]
+fn virtual_endpoint_provisioning_policies() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        format!("/v1.0/deviceManagement/virtualEndpoint/provisioningPolicies/{ID}/assign"),
+        client
+            .device_management()
+            .virtual_endpoint()
+            .provisioning_policy(ID)
+            .assign(&String::new())
+            .url()
+            .path()
+    );
+    assert_eq!(
+        "/v1.0/deviceManagement/virtualEndpoint/servicePlans".to_string(),
+        client
+            .device_management()
+            .virtual_endpoint()
+            .list_service_plans()
+            .url()
+            .path()
+    );
+}
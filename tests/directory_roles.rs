@@ -31,3 +31,57 @@ fn directory_role() {
             .path()
     );
 }
+
+#[test]
+fn directory_role_members() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        format!("/v1.0/directoryRoles/{}/members/$ref", ID_VEC[0]),
+        client
+            .directory_role(ID_VEC[0].as_str())
+            .add_member(ID_VEC[1].as_str())
+            .url()
+            .path()
+    );
+
+    assert_eq!(
+        format!(
+            "/v1.0/directoryRoles/{}/members/{}/$ref",
+            ID_VEC[0], ID_VEC[1]
+        ),
+        client
+            .directory_role(ID_VEC[0].as_str())
+            .remove_member(ID_VEC[1].as_str())
+            .url()
+            .path()
+    );
+}
+
+#[test]
+fn role_management_directory() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        "/v1.0/roleManagement/directory/roleAssignments".to_string(),
+        client
+            .role_management()
+            .directory()
+            .list_role_assignments()
+            .url()
+            .path()
+    );
+
+    assert_eq!(
+        format!(
+            "/v1.0/roleManagement/directory/roleDefinitions/{}",
+            ID_VEC[0]
+        ),
+        client
+            .role_management()
+            .directory()
+            .get_role_definitions(ID_VEC[0].as_str())
+            .url()
+            .path()
+    );
+}
@@ -0,0 +1,46 @@
+use graph_rs_sdk::Graph;
+
+static ID: &str = "b0073e4e-4184-41c6-9eb7-8c8cc3e2288b";
+
+#[test]
+fn external_connections() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        "/v1.0/external/connections".to_string(),
+        client
+            .external()
+            .connections()
+            .list_connections()
+            .url()
+            .path()
+    );
+    assert_eq!(
+        "/v1.0/external/connections/contosohr/schema".to_string(),
+        client
+            .external()
+            .connection("contosohr")
+            .get_schema()
+            .url()
+            .path()
+    );
+    assert_eq!(
+        format!("/v1.0/external/connections/contosohr/operations/{ID}"),
+        client
+            .external()
+            .connection("contosohr")
+            .get_operations(ID)
+            .url()
+            .path()
+    );
+    assert_eq!(
+        format!("/v1.0/external/connections/contosohr/items/{ID}"),
+        client
+            .external()
+            .connection("contosohr")
+            .item(ID)
+            .update_items(&String::new())
+            .url()
+            .path()
+    );
+}
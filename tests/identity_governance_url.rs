@@ -224,6 +224,44 @@ fn entitlement_management_catalogs_url() {
     );
 }
 
+#[test]
+fn entitlement_management_resources_url() {
+    assert_eq!(
+        client()
+            .entitlement_management()
+            .create_resource_requests(&serde_json::json!({}))
+            .url()
+            .path(),
+        "/v1.0/identityGovernance/entitlementManagement/resourceRequests"
+    );
+
+    assert_eq!(
+        client()
+            .entitlement_management()
+            .catalog(ID_VEC[0].as_str())
+            .list_resources()
+            .url()
+            .path(),
+        format!(
+            "/v1.0/identityGovernance/entitlementManagement/catalogs/{}/resources",
+            ID_VEC[0]
+        )
+    );
+
+    assert_eq!(
+        client()
+            .entitlement_management()
+            .access_package(ID_VEC[0].as_str())
+            .list_resource_role_scopes()
+            .url()
+            .path(),
+        format!(
+            "/v1.0/identityGovernance/entitlementManagement/accessPackages/{}/resourceRoleScopes",
+            ID_VEC[0]
+        )
+    );
+}
+
 #[test]
 fn connected_organization_url() {
     assert_eq!(
@@ -305,3 +343,27 @@ fn connected_organization_url() {
         )
     );
 }
+
+#[test]
+fn lifecycle_workflows_url() {
+    assert_eq!(
+        client()
+            .lifecycle_workflows()
+            .list_task_definitions()
+            .url()
+            .path(),
+        "/v1.0/identityGovernance/lifecycleWorkflows/taskDefinitions"
+    );
+    assert_eq!(
+        client()
+            .lifecycle_workflows()
+            .workflow(ID_VEC[0].as_str())
+            .list_run_user_processing_results(ID_VEC[1].as_str())
+            .url()
+            .path(),
+        format!(
+            "/v1.0/identityGovernance/lifecycleWorkflows/workflows/{}/runs/{}/userProcessingResults",
+            ID_VEC[0], ID_VEC[1]
+        )
+    );
+}
@@ -0,0 +1,35 @@
+use graph_rs_sdk::*;
+
+#[test]
+fn rooms_url() {
+    let client = Graph::new("");
+    assert_eq!(
+        client.places().list_rooms().url().path(),
+        "/v1.0/places/graph.room"
+    );
+    assert_eq!(
+        client.places().list_room_lists().url().path(),
+        "/v1.0/places/graph.roomList"
+    );
+}
+
+#[test]
+fn room_list_rooms_url() {
+    let client = Graph::new("");
+    assert_eq!(
+        client
+            .place("bldg2@contoso.com")
+            .list_room_list_rooms()
+            .url()
+            .path(),
+        "/v1.0/places/bldg2@contoso.com/graph.roomList/rooms"
+    );
+    assert_eq!(
+        client
+            .place("bldg2@contoso.com")
+            .get_room_list_rooms("cf200@contoso.com")
+            .url()
+            .path(),
+        "/v1.0/places/bldg2@contoso.com/graph.roomList/rooms/cf200@contoso.com"
+    );
+}
@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate lazy_static;
+
+use graph_rs_sdk::print::PrintApiClient;
+use graph_rs_sdk::*;
+use test_tools::common::TestTools;
+
+lazy_static! {
+    static ref ID_VEC: Vec<String> = TestTools::random_strings(3, 20);
+}
+
+fn client() -> PrintApiClient {
+    Graph::new("").print()
+}
+
+#[test]
+fn printers_url() {
+    assert_eq!(
+        client().printers().list_printers().url().path(),
+        "/v1.0/print/printers"
+    );
+    assert_eq!(
+        client()
+            .printer(ID_VEC[0].as_str())
+            .get_printers()
+            .url()
+            .path(),
+        format!("/v1.0/print/printers/{}", ID_VEC[0])
+    );
+}
+
+#[test]
+fn share_jobs_url() {
+    let job = client().share(ID_VEC[0].as_str()).job(ID_VEC[1].as_str());
+    assert_eq!(
+        job.start().url().path(),
+        format!("/v1.0/print/shares/{}/jobs/{}/start", ID_VEC[0], ID_VEC[1])
+    );
+    assert_eq!(
+        job.create_upload_session(ID_VEC[2].as_str(), &serde_json::json!({}))
+            .url()
+            .path(),
+        format!(
+            "/v1.0/print/shares/{}/jobs/{}/documents/{}/createUploadSession",
+            ID_VEC[0], ID_VEC[1], ID_VEC[2]
+        )
+    );
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "beta")]
+
+use graph_rs_sdk::*;
+
+static ID: &str = "b!CbtYWrofwUGBJWnaJkNwoNrBLp_kC3RKklSXPwrdeP3yH8_qmH9xT5Y6RODPNfYI";
+
+#[test]
+fn profile_positions_and_skills() {
+    let mut client = Graph::new("");
+    client.use_beta();
+
+    assert_eq!(
+        "/beta/me/profile/positions".to_string(),
+        client.me().profile().list_positions().url().path()
+    );
+
+    assert_eq!(
+        format!("/beta/users/{ID}/profile/skills/{ID}"),
+        client.user(ID).profile().get_skills(ID).url().path()
+    );
+}
+
+#[test]
+fn organization_profile_card_properties() {
+    let mut client = Graph::new("");
+    client.use_beta();
+
+    assert_eq!(
+        format!("/beta/organization/{ID}/settings/profileCardProperties/customAttribute1"),
+        client
+            .organization(ID)
+            .get_profile_card_properties("customAttribute1")
+            .url()
+            .path()
+    );
+}
@@ -0,0 +1,88 @@
+use graph_rs_sdk::Graph;
+
+static ID: &str = "b0073e4e-4184-41c6-9eb7-8c8cc3e2288b";
+
+#[test]
+fn ediscovery_cases() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        "/v1.0/security/cases/ediscoveryCases".to_string(),
+        client
+            .security()
+            .cases()
+            .ediscovery_cases()
+            .list_ediscovery_cases()
+            .url()
+            .path()
+    );
+    assert_eq!(
+        format!("/v1.0/security/cases/ediscoveryCases/{ID}"),
+        client
+            .security()
+            .cases()
+            .ediscovery_case(ID)
+            .get_ediscovery_cases()
+            .url()
+            .path()
+    );
+    assert_eq!(
+        format!("/v1.0/security/cases/ediscoveryCases/{ID}/operations/{ID}"),
+        client
+            .security()
+            .cases()
+            .ediscovery_case(ID)
+            .get_operations(ID)
+            .url()
+            .path()
+    );
+}
+
+#[test]
+fn ediscovery_case_custodians_searches_review_sets() {
+    let client = Graph::new("");
+    let case = client.security().cases().ediscovery_case(ID);
+
+    assert_eq!(
+        format!("/v1.0/security/cases/ediscoveryCases/{ID}/custodians/microsoft.graph.security.applyHold"),
+        case.custodians().apply_hold(&String::new()).url().path()
+    );
+    assert_eq!(
+        format!("/v1.0/security/cases/ediscoveryCases/{ID}/custodians/{ID}/microsoft.graph.security.release"),
+        case.custodian(ID).release().url().path()
+    );
+    assert_eq!(
+        format!("/v1.0/security/cases/ediscoveryCases/{ID}/searches/{ID}/microsoft.graph.security.estimateStatistics"),
+        case.search(ID).estimate_statistics().url().path()
+    );
+    assert_eq!(
+        format!("/v1.0/security/cases/ediscoveryCases/{ID}/reviewSets/{ID}/microsoft.graph.security.addToReviewSet"),
+        case.review_set(ID)
+            .add_to_review_set(&String::new())
+            .url()
+            .path()
+    );
+}
+
+#[test]
+fn alerts_and_incidents() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        "/v1.0/security/alerts_v2".to_string(),
+        client.security().alerts_v2().list_alerts_v2().url().path()
+    );
+    assert_eq!(
+        format!("/v1.0/security/alerts_v2/{ID}/comments"),
+        client
+            .security()
+            .alert_v2(ID)
+            .create_comments(&String::new())
+            .url()
+            .path()
+    );
+    assert_eq!(
+        format!("/v1.0/security/incidents/{ID}/alerts"),
+        client.security().incident(ID).list_alerts().url().path()
+    );
+}